@@ -10,3 +10,8 @@ import Foundation
 func testCallSwiftFromRustByNameAttribute() -> RustString {
     return "StringFromSwift".intoRustString()
 }
+
+func swift_split_position(position: UInt64, x: inout UInt32, y: inout UInt32) {
+    x = UInt32(position >> 32)
+    y = UInt32(position & 0xFFFFFFFF)
+}
@@ -55,3 +55,51 @@ func swift_negate_bool(arg: Bool) -> Bool {
 func swift_reflect_null(arg: ()) -> () {
     arg
 }
+
+func swift_reflect_duration(arg: TimeInterval) -> TimeInterval {
+    arg
+}
+
+func swift_reflect_system_time(arg: Date) -> Date {
+    arg
+}
+
+func swift_reflect_chrono_date_time(arg: Date) -> Date {
+    arg
+}
+
+func swift_reflect_offset_date_time(arg: Date) -> Date {
+    arg
+}
+
+func swift_reflect_speed(arg: Measurement<UnitSpeed>) -> Measurement<UnitSpeed> {
+    arg
+}
+
+func swift_reflect_uuid(arg: UUID) -> UUID {
+    arg
+}
+
+func swift_reflect_socket_addr(arg: RustSocketAddr) -> RustSocketAddr {
+    arg
+}
+
+func swift_reflect_range_u32(arg: Range<UInt32>) -> Range<UInt32> {
+    arg
+}
+
+func swift_reflect_range_inclusive_i64(arg: ClosedRange<Int64>) -> ClosedRange<Int64> {
+    arg
+}
+
+func swift_reflect_json(arg: Any) -> Any {
+    arg
+}
+
+func swift_hash_symbols_multiply(lhs: UInt32, rhs: UInt32) -> UInt32 {
+    lhs * rhs
+}
+
+func swift_reflection_multiply(lhs: UInt32, rhs: UInt32) -> UInt32 {
+    lhs * rhs
+}
@@ -15,3 +15,8 @@ func rust_calls_struct_repr_struct_one_primitive_field(arg: StructReprStructWith
     arg
 }
 
+func rust_calls_struct_repr_class(arg: StructReprClass) -> StructReprClass {
+    arg.count += 1
+    return arg
+}
+
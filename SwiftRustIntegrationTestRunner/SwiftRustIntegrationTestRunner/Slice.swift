@@ -0,0 +1,12 @@
+//
+//  Slice.swift
+//  SwiftRustIntegrationTestRunner
+//
+
+import Foundation
+
+func swift_fill_slice(buffer: UnsafeMutableBufferPointer<Float>, value: Float) {
+    for index in buffer.indices {
+        buffer[index] = value
+    }
+}
@@ -14,3 +14,7 @@ func create_swift_string() -> String {
 func reflect_rust_string(arg: RustString) -> RustString {
     arg
 }
+
+func swift_path_is_absolute(arg: String) -> Bool {
+    arg.hasPrefix("/")
+}
@@ -18,4 +18,24 @@ final class ArgumentAttributesTest: XCTestCase {
         XCTAssertEqual(test_argument_label(someArg: 10, 100), 110)
     }
 
+    /// Verify that `#[swift_bridge(out)]` arguments are `inout` parameters in Swift.
+    func testOutArguments() throws {
+        let values: [Int32] = [3, -8, 12, 5]
+        var min: Int32 = 0
+        var max: Int32 = 0
+
+        let found = values.withUnsafeBufferPointer { buffer in
+            test_out_arguments(buffer, &min, &max)
+        }
+
+        XCTAssertTrue(found)
+        XCTAssertEqual(min, -8)
+        XCTAssertEqual(max, 12)
+    }
+
+    /// Verify that Rust can pass `#[swift_bridge(out)]` arguments to Swift.
+    func testRustCallsSwiftOutArguments() throws {
+        test_rust_calls_swift_out_arguments()
+    }
+
 }
@@ -0,0 +1,40 @@
+//
+//  ArrayTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+class ArrayTests: XCTestCase {
+    /// Verify that we can pass a fixed-size array to Rust and get it back.
+    func testReflectArray() throws {
+        let bytes: (UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8,
+                    UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8) =
+            (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15)
+        let reflected = rust_reflect_array_u8(bytes)
+
+        XCTAssertEqual(reflected.0, 0)
+        XCTAssertEqual(reflected.7, 7)
+        XCTAssertEqual(reflected.15, 15)
+    }
+
+    /// Verify that Rust receives the array's elements in order.
+    func testReverseArray() throws {
+        let reversed = rust_reverse_array_i32((1, 2, 3))
+
+        XCTAssertEqual(reversed.0, 3)
+        XCTAssertEqual(reversed.1, 2)
+        XCTAssertEqual(reversed.2, 1)
+    }
+
+    /// Verify that a shared struct can have a fixed-size array field.
+    func testSharedStructArrayField() throws {
+        let color = rust_reflect_array_test_color(rust_make_array_test_color(0.1, 0.2, 0.3, 1.0))
+
+        XCTAssertEqual(color.rgba.0, 0.1)
+        XCTAssertEqual(color.rgba.1, 0.2)
+        XCTAssertEqual(color.rgba.2, 0.3)
+        XCTAssertEqual(color.rgba.3, 1.0)
+    }
+}
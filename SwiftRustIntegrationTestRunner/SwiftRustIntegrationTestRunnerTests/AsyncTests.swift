@@ -40,6 +40,12 @@ class AsyncTests: XCTestCase {
         XCTAssertEqual(num, 567)
     }
 
+    /// Verify that we can call an async Rust initializer
+    func testSwiftCallsRustAsyncInitializer() async throws {
+        let value = await AsyncInitTestType(25)
+        XCTAssertEqual(value.value(), 25)
+    }
+
     
     /// Verify that we can pass and return a Result<OpaqueRust, OpaqueRust> to an async Rust function
     func testSwiftCallsRustAsyncFnReflectResultOpaqueRust() async throws {
@@ -191,5 +197,59 @@ class AsyncTests: XCTestCase {
             XCTAssertEqual(error.val(), 111)
         }
     }
+
+    /// Verify that we can pull the chunks of a Rust `ByteStream` one at a time.
+    func testRustByteStreamNextChunk() throws {
+        let stream = rust_make_byte_stream(3, 1024)
+
+        for idx: UInt8 in 0..<3 {
+            let chunk = stream.nextChunk()!
+            XCTAssertEqual(chunk.count, 1024)
+            XCTAssertTrue(chunk.allSatisfy { $0 == idx })
+        }
+        XCTAssertNil(stream.nextChunk())
+    }
+
+    /// Verify that we can read a Rust `ByteStream` whose chunks are written from another thread
+    /// using `for await`.
+    func testRustByteStreamAsyncSequence() async throws {
+        var chunks: [Data] = []
+        for await chunk in rust_make_byte_stream_from_writer(5) {
+            chunks.append(chunk)
+        }
+
+        XCTAssertEqual(chunks, (0..<5).map { Data([UInt8($0)]) })
+    }
+
+    /// Verify that we can pass a `RustByteStream` back to Rust.
+    func testPassRustByteStreamToRust() throws {
+        let stream = rust_make_byte_stream(4, 10)
+        _ = stream.nextChunk()
+
+        XCTAssertEqual(rust_byte_stream_len(stream), 30)
+    }
+
+    /// Verify that we can pull the events of a Rust `Watch` one at a time.
+    func testRustWatchNextEvent() throws {
+        let watch = rust_make_watch(3)
+
+        for sequence: UInt32 in 0..<3 {
+            let event = watch.nextEvent()!
+            XCTAssertEqual(event.sequence, sequence)
+            XCTAssertEqual(event.removed, sequence % 2 == 1)
+        }
+        XCTAssertNil(watch.nextEvent())
+    }
+
+    /// Verify that we can read a Rust `Watch` whose events are sent from another thread using
+    /// `for await`.
+    func testRustWatchAsyncSequence() async throws {
+        var sequences: [UInt32] = []
+        for await event in rust_make_watch_from_notifier(5) {
+            sequences.append(event.sequence)
+        }
+
+        XCTAssertEqual(sequences, [0, 1, 2, 3, 4])
+    }
 }
 
@@ -0,0 +1,51 @@
+//
+//  BTreeMapTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+class BTreeMapTests: XCTestCase {
+    /// Verify that we iterate over the entries of a BTreeMap in ascending key order.
+    func testIterateInSortedOrder() throws {
+        let map = rust_make_btree_map_string_u32()
+        XCTAssertEqual(map.len(), 3)
+
+        var entries: [(String, UInt32)] = []
+        for (key, value) in map {
+            entries.append((key.as_str().toString(), value))
+        }
+
+        XCTAssertEqual(entries.map { $0.0 }, ["brightness", "contrast", "volume"])
+        XCTAssertEqual(entries.map { $0.1 }, [3, 5, 7])
+    }
+
+    /// Verify that we iterate over integer keys in ascending order.
+    func testIntegerKeysInSortedOrder() throws {
+        let map = rust_make_btree_map_u8_string()
+
+        XCTAssertEqual(Array(map.keys()), [10, 20, 30])
+        XCTAssertEqual(map.values().map { $0.as_str().toString() }, ["ten", "twenty", "thirty"])
+    }
+
+    /// Verify that we can look up values by key.
+    func testGet() throws {
+        let stringKeys = rust_make_btree_map_string_u32()
+        XCTAssertEqual(stringKeys.get(key: "contrast"), 5)
+        XCTAssertEqual(stringKeys.get(key: "volume"), 7)
+        XCTAssertNil(stringKeys.get(key: "hue"))
+
+        let intKeys = rust_make_btree_map_u8_string()
+        XCTAssertEqual(intKeys.get(key: 20)?.as_str().toString(), "twenty")
+        XCTAssertNil(intKeys.get(key: 25))
+    }
+
+    /// Verify that we can pass a BTreeMap back to Rust.
+    func testReflectBTreeMap() throws {
+        let map = rust_reflect_btree_map_string_u32(rust_make_btree_map_string_u32())
+
+        XCTAssertEqual(map.keys().map { $0.as_str().toString() }, ["brightness", "contrast", "volume"])
+        XCTAssertEqual(Array(map.values()), [3, 5, 7])
+    }
+}
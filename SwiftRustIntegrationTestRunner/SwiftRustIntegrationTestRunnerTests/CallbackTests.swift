@@ -14,4 +14,101 @@ class CallbackTests: XCTestCase {
     func testRustCallsSwift() throws {
         test_callbacks_rust_calls_swift()
     }
+    
+    /// Verify that Rust can hold on to a Swift closure and call it more than once.
+    func testRustStoresSwiftClosure() throws {
+        let stored = CallbackTestStoredFn({ val in "value: \(val)".intoRustString() })
+        
+        XCTAssertEqual(stored.format(1).toString(), "value: 1")
+        XCTAssertEqual(stored.format(2).toString(), "value: 2")
+    }
+    
+    /// Verify that a Swift closure that Rust was holding on to gets released when Rust drops it.
+    func testRustReleasesStoredSwiftClosure() throws {
+        let stored = CallbackTestStoredFn({ val in "\(val)".intoRustString() })
+        
+        weak var weakTracker: ReleaseTracker?
+        do {
+            let tracker = ReleaseTracker()
+            weakTracker = tracker
+            stored.set_formatter({ val in "\(tracker.prefix)\(val)".intoRustString() })
+        }
+        XCTAssertNotNil(weakTracker)
+        XCTAssertEqual(stored.format(3).toString(), "tracked 3")
+        
+        stored.set_formatter({ val in "\(val)".intoRustString() })
+        XCTAssertNil(weakTracker)
+    }
+    
+    /// Verify that Rust can complete a Swift completion handler that was passed as a
+    /// `Box<dyn FnOnce>`.
+    func testRustCallsSwiftCompletionHandler() throws {
+        let fetcher = CallbackTestFetcher()
+        
+        var value: String?
+        fetcher.fetch("key".intoRustString()) { result in
+            switch result {
+            case .Ok(let ok):
+                value = ok.toString()
+            case .Err(_):
+                XCTFail()
+            }
+        }
+        XCTAssertEqual(value, "value for key")
+        
+        var error: String?
+        fetcher.fetch("".intoRustString()) { result in
+            switch result {
+            case .Ok(_):
+                XCTFail()
+            case .Err(let err):
+                error = err.toString()
+            }
+        }
+        XCTAssertEqual(error, "empty key")
+    }
+    
+    /// Verify that a Swift completion handler is released when Rust drops it without calling it.
+    func testRustReleasesUncalledSwiftCompletionHandler() throws {
+        let fetcher = CallbackTestFetcher()
+        
+        weak var weakTracker: ReleaseTracker?
+        do {
+            let tracker = ReleaseTracker()
+            weakTracker = tracker
+            fetcher.fetch_and_drop_callback { _ in
+                _ = tracker
+                XCTFail()
+            }
+        }
+        XCTAssertNil(weakTracker)
+    }
+    
+    /// Verify that Rust can hold on to a Swift completion handler and call it later, after which
+    /// the completion handler gets released.
+    func testRustCallsStoredSwiftCompletionHandler() throws {
+        let fetcher = CallbackTestFetcher()
+        
+        weak var weakTracker: ReleaseTracker?
+        var value: String?
+        do {
+            let tracker = ReleaseTracker()
+            weakTracker = tracker
+            fetcher.fetch_later { result in
+                if case .Ok(let ok) = result {
+                    value = tracker.prefix + ok.toString()
+                }
+            }
+        }
+        XCTAssertNotNil(weakTracker)
+        XCTAssertNil(value)
+        
+        fetcher.complete_pending_fetch("later".intoRustString())
+        XCTAssertEqual(value, "tracked later")
+        XCTAssertNil(weakTracker)
+    }
+}
+
+private class ReleaseTracker {
+    let prefix = "tracked "
 }
@@ -0,0 +1,35 @@
+//
+//  CharTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+class CharTests: XCTestCase {
+    /// Verify that we can pass a char to Rust and get it back.
+    func testReflectChar() throws {
+        for scalar: Unicode.Scalar in ["a", "é", "🦀"] {
+            XCTAssertEqual(rust_reflect_char(scalar), scalar)
+        }
+    }
+
+    /// Verify that we can receive an Option<char> from Rust.
+    func testOptionChar() throws {
+        XCTAssertEqual(rust_next_char("a"), "b")
+        // U+D7FF is followed by the surrogate range, which does not contain any Unicode scalars.
+        XCTAssertNil(rust_next_char("\u{D7FF}"))
+    }
+
+    /// Verify that a shared struct can have a char field.
+    func testSharedStructCharField() throws {
+        let val = rust_reflect_char_test_struct(CharTestStruct(letter: "z"))
+
+        XCTAssertEqual(val.letter, "z")
+    }
+
+    /// Verify that Rust can pass a char to Swift and get it back.
+    func testRustCallsSwiftReflectChar() throws {
+        test_rust_calls_swift_reflect_char()
+    }
+}
@@ -26,5 +26,15 @@ class FunctionAttributeGetTests: XCTestCase {
         let val = SomeTypeGet()
         XCTAssertEqual(val.my_opt_static_str()!.toString(), "world")
     }
+
+    /// Verify that a reference that is returned by a `swift_bridge(get(&...))` accessor keeps
+    /// its parent alive.
+    func testGetRefKeepsParentAlive() throws {
+        var parent: SomeTypeGet? = SomeTypeGet()
+        let child = parent!.child()
+        parent = nil
+
+        XCTAssertEqual(child.value(), 456)
+    }
 }
 
@@ -34,6 +34,12 @@ class FunctionAttributeIdentifiableTests: XCTestCase {
             XCTAssertEqual(verifyIsIdentifiable(IdentifiableStr()).id(), rustStr)
         })
     }
+
+    /// Verify that the `swift_bridge(Identifiable = "...")` type attribute uses the named method
+    /// as the `id`.
+    func testIdentifiableTypeAttribute() throws {
+        XCTAssertEqual(verifyIsIdentifiable(IdentifiableTypeAttribute()).id, 456)
+    }
 }
 
 func verifyIsIdentifiable<T: Identifiable>(_ arg: T) -> T {
@@ -0,0 +1,45 @@
+//
+//  FunctionAttributeStoreTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+import Combine
+@testable import SwiftRustIntegrationTestRunner
+
+/// Tests the #[swift_bridge(Store(state))] and #[swift_bridge(Store(dispatch))] attributes.
+class FunctionAttributeStoreTests: XCTestCase {
+    /// Verify that dispatching an action publishes the new state.
+    func testDispatchPublishesState() throws {
+        let store = StoreTestCounterStore(StoreTestCounter())
+        XCTAssertEqual(store.state.count, 0)
+
+        var published: [UInt32] = []
+        let subscription = store.$state.sink { state in
+            published.append(state.count)
+        }
+
+        store.dispatch(.Increment)
+        store.dispatch(.Add(5))
+        XCTAssertEqual(store.state.count, 6)
+        XCTAssertEqual(store.state.history_len, 2)
+
+        store.dispatch(.Reset)
+        XCTAssertEqual(published, [0, 1, 6, 0])
+
+        subscription.cancel()
+    }
+
+    /// Verify that we can publish state changes that were made from Rust.
+    func testRefresh() throws {
+        let store = StoreTestCounterStore(StoreTestCounter())
+        store.dispatch(.Add(3))
+
+        store.inner.reset_from_rust()
+        XCTAssertEqual(store.state.count, 3)
+
+        store.refresh()
+        XCTAssertEqual(store.state.count, 0)
+        XCTAssertEqual(store.state.history_len, 0)
+    }
+}
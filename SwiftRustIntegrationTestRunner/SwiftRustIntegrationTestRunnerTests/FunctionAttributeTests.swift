@@ -24,4 +24,66 @@ class FunctionAttributeTests: XCTestCase {
     func testSwiftNameAttribute() throws {
         XCTAssertEqual(testCallRustFromSwiftByNameAttribute().toString(), "StringFromRust")
     }
+
+    /// Verify that the `swift_bridge(compress = "lz4")` attribute works.
+    func testCompressAttribute() throws {
+        let bytes: Data = rust_compressed_bytes(1000)
+        XCTAssertEqual(bytes, Data((0..<1000).map { UInt8(truncatingIfNeeded: $0) }))
+
+        XCTAssertEqual(rust_compressed_bytes(0), Data())
+
+        let string: String = rust_compressed_string(100)
+        XCTAssertEqual(string, String(repeating: "hello, world! ", count: 100))
+    }
+
+    /// Verify that `swift_bridge(init)` functions that return a `Result` or an `Option` become
+    /// throwing and failable initializers.
+    func testFailableInitializers() throws {
+        XCTAssertEqual(try FailableInitTestType(5).value(), 5)
+        XCTAssertThrowsError(try FailableInitTestType(0)) { error in
+            XCTAssertEqual((error as! RustString).toString(), "value must not be zero")
+        }
+
+        XCTAssertEqual(FailableInitTestType(evenValue: 4)?.value(), 4)
+        XCTAssertNil(FailableInitTestType(evenValue: 3))
+    }
+
+    /// Verify that the guard that a `scoped` function returns is dropped once the closure
+    /// returns or throws.
+    func testScopedAttribute() throws {
+        struct Rollback: Error {}
+
+        let database = ScopedTestDatabase()
+
+        let rows: UInt32 = database.withTransaction { transaction in
+            XCTAssertEqual(database.open_transactions(), 1)
+            transaction.insert(5)
+            return transaction.pending_rows()
+        }
+        XCTAssertEqual(rows, 1)
+        XCTAssertEqual(database.open_transactions(), 0)
+        XCTAssertEqual(database.committed_rows(), 1)
+
+        XCTAssertThrowsError(try database.withTransaction { transaction in
+            transaction.insert(6)
+            throw Rollback()
+        })
+        XCTAssertEqual(database.open_transactions(), 0)
+        XCTAssertEqual(database.committed_rows(), 2)
+    }
+
+    /// Verify that a `scoped` method that returns a reference passes the borrowed value to the
+    /// closure.
+    func testScopedBorrowAttribute() throws {
+        let database = ScopedTestDatabase()
+
+        database.withSchemaMut { schema in
+            schema.migrate()
+        }
+        let version: UInt32 = database.withSchema { schema in
+            schema.version()
+        }
+
+        XCTAssertEqual(version, 2)
+    }
 }
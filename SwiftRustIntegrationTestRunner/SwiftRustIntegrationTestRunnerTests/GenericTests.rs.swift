@@ -32,5 +32,18 @@ class GenericTests: XCTestCase {
         let val = new_generic_with_inner_opaque_type()
         let _: GenericWithOpaqueRustInnerTy<InnerTy> = reflect_generic_with_inner_opaque_type(val)
     }
+    
+    /// Verify that each instantiation of a generic opaque Rust type that isn't declared using
+    /// `declare_generic` gets its own Swift class.
+    func testGenericOpaqueRustTypeOwnSwiftClass() {
+        let numbers = GenericCacheU32()
+        numbers.insert(1)
+        numbers.insert(2)
+        XCTAssertEqual(numbers.len(), 2)
+        
+        let strings = GenericCacheString()
+        strings.insert("hello")
+        XCTAssertEqual(strings.len(), 1)
+    }
 }
 
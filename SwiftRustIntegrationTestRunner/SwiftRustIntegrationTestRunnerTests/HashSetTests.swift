@@ -0,0 +1,88 @@
+//
+//  HashSetTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+class HashSetTests: XCTestCase {
+    func testRustHashSetU64InsertContainsRemove() throws {
+        let set = RustHashSet<UInt64>()
+        XCTAssertEqual(set.len(), 0)
+
+        XCTAssertTrue(set.insert(value: 5))
+        XCTAssertFalse(set.insert(value: 5))
+        XCTAssertEqual(set.len(), 1)
+
+        XCTAssertTrue(set.contains(value: 5))
+        XCTAssertFalse(set.contains(value: 6))
+
+        XCTAssertTrue(set.remove(value: 5))
+        XCTAssertFalse(set.contains(value: 5))
+        XCTAssertEqual(set.len(), 0)
+    }
+
+    func testRustHashSetU64ToSet() throws {
+        let set = RustHashSet<UInt64>()
+        set.insert(value: 1)
+        set.insert(value: 2)
+
+        XCTAssertEqual(set.toSet(), [1, 2])
+    }
+
+    /// Verify that a HashSet<u64> can be used as an argument and return type for
+    /// extern "Rust" functions.
+    func testReflectHashSetU64() throws {
+        let set = RustHashSet<UInt64>()
+        set.insert(value: 10)
+        set.insert(value: 20)
+
+        let reflected = rust_reflect_hash_set_u64(set)
+        XCTAssertEqual(reflected.len(), 2)
+        XCTAssertEqual(reflected.toSet(), [10, 20])
+    }
+
+    /// Verify that a HashSet<String> can be used as an argument and return type for
+    /// extern "Rust" functions.
+    func testReflectHashSetString() throws {
+        let set = RustHashSet<RustString>()
+        set.insert(value: RustString("hello"))
+
+        let reflected = rust_reflect_hash_set_string(set)
+        XCTAssertEqual(reflected.len(), 1)
+        XCTAssertTrue(reflected.contains(value: RustString("hello")))
+        XCTAssertFalse(reflected.contains(value: RustString("world")))
+    }
+
+    /// Verify that Rust deduplicates the values before they reach Swift.
+    func testRustMakeHashSetString() throws {
+        let set = rust_make_hash_set_string()
+
+        XCTAssertEqual(set.len(), 2)
+        XCTAssertEqual(set.toSet(), ["hello", "world"])
+    }
+
+    func testReflectOptionHashSet() throws {
+        let set = RustHashSet<UInt64>()
+        set.insert(value: 5)
+
+        let reflected = rust_reflect_option_hash_set_u64(set)
+        XCTAssertEqual(reflected!.len(), 1)
+        XCTAssertTrue(reflected!.contains(value: 5))
+
+        XCTAssertNil(rust_reflect_option_hash_set_u64(nil))
+    }
+
+    func testRustHashSetIterator() throws {
+        let set = RustHashSet<UInt64>()
+        set.insert(value: 3)
+        set.insert(value: 4)
+
+        var sum: UInt64 = 0
+        for val in set {
+            sum += val
+        }
+        XCTAssertEqual(sum, 7)
+    }
+}
@@ -0,0 +1,64 @@
+//
+//  ImageBufferTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+import CoreGraphics
+import CoreVideo
+@testable import SwiftRustIntegrationTestRunner
+
+class ImageBufferTests: XCTestCase {
+    /// Verify that Swift can read the metadata and pixels of an image that Rust created.
+    func testRustCreatedImageBuffer() throws {
+        let image = rust_make_image_buffer(3, 2)
+
+        XCTAssertEqual(image.width, 3)
+        XCTAssertEqual(image.height, 2)
+        XCTAssertEqual(image.bytesPerRow, 12)
+        XCTAssertEqual(image.pixelFormat, .rgba8)
+        image.withUnsafeBytes { bytes in
+            XCTAssertEqual(Array(bytes.prefix(4)), [255, 0, 0, 255])
+        }
+    }
+
+    /// Verify that the images made from a buffer outlive the `RustImageBuffer`.
+    func testMakeImagesFromImageBuffer() throws {
+        var cgImage: CGImage?
+        var pixelBuffer: CVPixelBuffer?
+        do {
+            let image = rust_make_image_buffer(4, 4)
+            cgImage = image.makeCGImage()
+            pixelBuffer = image.makeCVPixelBuffer()
+        }
+
+        XCTAssertEqual(cgImage!.width, 4)
+        XCTAssertEqual(cgImage!.dataProvider!.data! as Data, Data([255, 0, 0, 255].repeated(16)))
+        XCTAssertEqual(CVPixelBufferGetHeight(pixelBuffer!), 4)
+    }
+
+    /// Verify that Swift can create an image buffer and pass it to Rust.
+    func testSwiftCreatedImageBuffer() throws {
+        let bytes: [UInt8] = [1, 2, 3, 0, 4, 5, 6, 0]
+        let image = bytes.withUnsafeBytes { bytes in
+            RustImageBuffer(width: 3, height: 2, bytesPerRow: 4, pixelFormat: .gray8, bytes: bytes)
+        }!
+
+        XCTAssertEqual(rust_image_buffer_byte_sum(image), 21)
+
+        XCTAssertNil(bytes.withUnsafeBytes { bytes in
+            RustImageBuffer(width: 3, height: 3, bytesPerRow: 4, pixelFormat: .gray8, bytes: bytes)
+        })
+    }
+
+    func testOptionImageBuffer() throws {
+        XCTAssertEqual(rust_reflect_option_image_buffer(rust_make_image_buffer(1, 1))!.width, 1)
+        XCTAssertNil(rust_reflect_option_image_buffer(nil))
+    }
+}
+
+private extension Array {
+    func repeated(_ count: Int) -> [Element] {
+        Array([[Element]](repeating: self, count: count).joined())
+    }
+}
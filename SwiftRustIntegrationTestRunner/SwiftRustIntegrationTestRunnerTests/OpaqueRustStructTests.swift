@@ -90,6 +90,52 @@ class OpaqueRustStructTests: XCTestCase {
         }
     }
 
+    func testOpaqueRustTypeImplComparable() throws {
+        let values = [RustComparableType(3), RustComparableType(1), RustComparableType(2)]
+
+        XCTAssertEqual(values.sorted().map { $0.value() }, [1, 2, 3])
+        XCTAssertEqual(values.min()?.value(), 1)
+        XCTAssert(RustComparableType(1) < RustComparableType(2))
+        XCTAssertFalse(RustComparableType(2) < RustComparableType(2))
+        XCTAssert(RustComparableType(2) <= RustComparableType(2))
+    }
+
+    func testOpaqueRustTypeImplClone() throws {
+        let original = RustCloneType(5)
+        let copy = original.copy()
+
+        copy.set_value(10)
+
+        XCTAssertEqual(original.value(), 5)
+        XCTAssertEqual(copy.value(), 10)
+    }
+
+    func testOpaqueRustTypeImplCustomStringConvertible() throws {
+        let value = RustDisplayType(1, 4)
+
+        XCTAssertEqual(value.description, "v1.4")
+        XCTAssertEqual("\(value)", "v1.4")
+        XCTAssertEqual(String(describing: value), "v1.4")
+    }
+
+    func testOpaqueRustTypeImplCustomDebugStringConvertible() throws {
+        let value = RustDebugType("sync", 3)
+
+        XCTAssertEqual(value.debugDescription, #"RustDebugType { name: "sync", retries: 3 }"#)
+        XCTAssertEqual(String(reflecting: value), #"RustDebugType { name: "sync", retries: 3 }"#)
+    }
+
+    func testOpaqueRustTypeImplError() throws {
+        XCTAssertEqual(try rust_func_parse_port("8080"), 8080)
+
+        do {
+            let _ = try rust_func_parse_port("http")
+            XCTFail("Expected an error")
+        } catch let error as RustErrorType {
+            XCTAssertEqual(error.localizedDescription, "invalid port: http")
+        }
+    }
+
     func testOpaqueRustTypeImplHashable() throws {
         XCTContext.runActivity(named: "Same hash value"){
             _ in
@@ -130,5 +176,123 @@ class OpaqueRustStructTests: XCTestCase {
             }
         }
     }
-}
 
+    /// Verify that Swift and Rust can share ownership of an `Arc<T>`.
+    func testArcSharedOwnership() throws {
+        let counter = rust_make_arc_test_counter(5)
+        XCTAssertEqual(counter.value(), 5)
+
+        // One reference held by `counter` and one held by the argument.
+        XCTAssertEqual(rust_arc_test_counter_strong_count(counter), 2)
+
+        do {
+            let clone = counter.clone()
+            XCTAssertEqual(clone.value(), 5)
+            XCTAssertEqual(rust_arc_test_counter_strong_count(counter), 3)
+        }
+
+        XCTAssertEqual(rust_arc_test_counter_strong_count(counter), 2)
+    }
+
+    /// Verify that we can return an `Option<Arc<T>>` from Rust.
+    func testOptionArc() throws {
+        XCTAssertEqual(rust_maybe_arc_test_counter(true)!.value(), 1)
+        XCTAssertNil(rust_maybe_arc_test_counter(false))
+    }
+
+    /// Verify that we can use the methods of an impl block that is annotated with
+    /// `#[swift_bridge::export]`.
+    func testExportedImpl() throws {
+        let counter = ExportedImplTestCounter.new(5)
+        counter.increment()
+
+        XCTAssertEqual(counter.count(), 6)
+        XCTAssertEqual(counter.describe().toString(), "count: 6")
+        XCTAssertEqual(rust_double_exported_impl_counter(counter), 12)
+    }
+
+    /// Verify that we can look up the functions of a bridge module that is annotated with
+    /// `#[swift_bridge(module_table = "...")]` at runtime and call them.
+    func testModuleTable() throws {
+        // The Rust library is statically linked into the test runner, so we look up the table
+        // in the current process.
+        let table = RustModuleTable(handle: dlopen(nil, RTLD_NOW), name: "integration_tests")!
+
+        XCTAssertEqual(table.names.count, 4)
+        XCTAssertNil(RustModuleTable(handle: dlopen(nil, RTLD_NOW), name: "does_not_exist"))
+
+        typealias Add = @convention(c) (UInt32, UInt32) -> UInt32
+        let add = table.function("__swift_bridge__$rust_module_table_add", as: Add.self)!
+        XCTAssertEqual(add(2, 3), 5)
+
+        typealias New = @convention(c) () -> UnsafeMutableRawPointer
+        typealias Increment = @convention(c) (UnsafeMutableRawPointer) -> UInt32
+        typealias Free = @convention(c) (UnsafeMutableRawPointer) -> Void
+        let new = table.function("__swift_bridge__$ModuleTableTestCounter$new", as: New.self)!
+        let increment = table.function(
+            "__swift_bridge__$ModuleTableTestCounter$increment", as: Increment.self
+        )!
+        let free = table.function("__swift_bridge__$ModuleTableTestCounter$_free", as: Free.self)!
+
+        let counter = new()
+        XCTAssertEqual(increment(counter), 1)
+        XCTAssertEqual(increment(counter), 2)
+        free(counter)
+    }
+
+    /// Verify that we can enumerate the types and functions of a bridge module that is annotated
+    /// with `#[swift_bridge(reflection = "...")]` and see how often its functions were called.
+    func testReflection() throws {
+        let reflection = RustReflection.reflection_tests
+
+        XCTAssertEqual(reflection.name, "reflection_tests")
+        XCTAssertEqual(reflection.types.map { $0.name }, ["ReflectionTestCounter"])
+        XCTAssertEqual(reflection.types[0].kind, .opaqueRust)
+        XCTAssertEqual(
+            reflection.methods(of: "ReflectionTestCounter").map { $0.name }, ["new", "increment"]
+        )
+
+        let add = reflection.functions.first { $0.name == "rust_reflection_add" }!
+        XCTAssertNil(add.typeName)
+        XCTAssertTrue(add.isRust)
+        XCTAssertEqual(add.linkName, "__swift_bridge__$rust_reflection_add")
+
+        let callsBefore = add.callCount
+        XCTAssertEqual(rust_reflection_add(2, 3), 5)
+        XCTAssertEqual(rust_reflection_add(4, 5), 9)
+        XCTAssertEqual(add.callCount, callsBefore + 2)
+
+        let counter = ReflectionTestCounter()
+        counter.increment()
+        let increment = reflection.methods(of: "ReflectionTestCounter").first {
+            $0.name == "increment"
+        }!
+        XCTAssertGreaterThanOrEqual(increment.callCount, 1)
+
+        test_rust_calls_swift_with_reflection()
+    }
+
+    /// Verify that we can call into and out of a bridge module that is annotated with
+    /// `#[swift_bridge(hash_symbols)]`.
+    func testHashSymbols() throws {
+        let greeter = HashSymbolsTestGreeter("hashed")
+        XCTAssertEqual(greeter.greet().toString(), "Hello, hashed")
+
+        XCTAssertEqual(rust_hash_symbols_add(2, 3), 5)
+
+        test_rust_calls_swift_with_hashed_symbols()
+
+        // The readable link names are no longer exported.
+        XCTAssertNil(dlsym(dlopen(nil, RTLD_NOW), "__swift_bridge__$rust_hash_symbols_add"))
+    }
+
+    /// Verify that we can use the types and functions of a bridge module whose items were read
+    /// from other files using `#[swift_bridge(include = "...")]`.
+    func testModuleInclude() throws {
+        let counter = IncludedCounter()
+        XCTAssertEqual(counter.increment(), 1)
+        XCTAssertEqual(counter.increment(), 2)
+
+        XCTAssertEqual(rust_included_counter_total(counter, IncludedTally(amount: 5)), 7)
+    }
+}
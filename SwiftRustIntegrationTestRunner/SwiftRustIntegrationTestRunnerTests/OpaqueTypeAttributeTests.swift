@@ -42,7 +42,59 @@ class OpaqueTypeAttributeTests: XCTestCase {
         XCTAssert(AlreadyDeclaredCopyTypeTest.an_associated_function())
     }
 
-    
+    /// Verify that a type that uses `#[swift_bridge(drop_queue = "DispatchQueue.main")]` is dropped
+    /// on the main thread even when its last Swift reference is released on another thread.
+    /// See crates/swift-integration-tests/src/opaque_type_attributes/drop_queue.rs
+    func testExternRustDropQueueOpaqueRustType() throws {
+        let dropCount = drop_queue_test_type_drop_count()
+
+        var val: DropQueueTestType? = DropQueueTestType()
+        let released = expectation(description: "Released on a background thread")
+        DispatchQueue.global().async {
+            val = nil
+            released.fulfill()
+        }
+        wait(for: [released], timeout: 5)
+
+        let dropped = expectation(for: NSPredicate { _, _ in
+            drop_queue_test_type_drop_count() == dropCount + 1
+        }, evaluatedWith: nil)
+        wait(for: [dropped], timeout: 5)
+
+        XCTAssertFalse(drop_queue_test_type_dropped_on_other_thread())
+    }
+
+    /// Verify that the same Rust object is always seen by Swift as the same class instance when
+    /// the type uses `#[swift_bridge(identity_map)]`.
+    /// See crates/swift-integration-tests/src/opaque_type_attributes/identity_map.rs
+    func testExternRustIdentityMapOpaqueRustType() throws {
+        let first = rust_identity_map_test_type_shared()
+        let second = rust_identity_map_test_type_shared()
+        XCTAssert(first === second)
+        XCTAssertEqual(first.num(), 5)
+
+        let val = IdentityMapTestType(10)
+        XCTAssert(val.share() === val)
+        XCTAssert(val !== first)
+    }
+
+    /// Verify that a type that uses `#[swift_bridge(Sendable)]` can be used from more than one
+    /// task at a time.
+    /// See crates/swift-integration-tests/src/opaque_type_attributes/sendable.rs
+    func testExternRustSendableOpaqueRustType() async throws {
+        let val = SendableTestType()
+
+        await withTaskGroup(of: Void.self) { group in
+            for _ in 0..<10 {
+                group.addTask {
+                    val.increment()
+                }
+            }
+        }
+
+        XCTAssertEqual(val.count(), 10)
+    }
+
     func testPerformanceExample() throws {
         // This is an example of a performance test case.
         self.measure {
@@ -89,6 +89,22 @@ class OptionTests: XCTestCase {
 
         XCTAssertNil(rust_reflect_option_vector_rust_type(nil))
     }
+
+    /// Verify that an empty slice is not mistaken for `nil`.
+    func testSwiftCallRustWithOptionSlice() throws {
+        let bytes: [UInt8] = [1, 2, 3]
+        bytes.withUnsafeBufferPointer { buffer in
+            let reflected = rust_reflect_option_slice_u8(buffer)!
+            XCTAssertEqual(Array(reflected), [1, 2, 3])
+        }
+
+        let empty: [UInt8] = []
+        empty.withUnsafeBufferPointer { buffer in
+            XCTAssertEqual(rust_reflect_option_slice_u8(buffer)!.count, 0)
+        }
+
+        XCTAssertNil(rust_reflect_option_slice_u8(nil))
+    }
     
     func testSwiftCallRustWithOptionOpaqueRustType() throws {
         let val = OptTestOpaqueRustType(123)
@@ -131,7 +147,8 @@ class OptionTests: XCTestCase {
             u8: 123, i8: 123, u16: 123, i16: 123,
             u32: 123, i32: 123, u64: 123, i64: 123,
             usize: 123, isize: 123, f32: 123.4, f64: 123.4,
-            boolean: true
+            boolean: true, string: "hello".intoRustString(),
+            option_struct: OptionStruct(field: 123), option_enum: .Variant2
         )
         let reflected = rust_reflect_struct_with_option_fields(val)
         XCTAssertEqual(reflected.u8, 123)
@@ -147,6 +164,9 @@ class OptionTests: XCTestCase {
         XCTAssertEqual(reflected.f32, 123.4)
         XCTAssertEqual(reflected.f64, 123.4)
         XCTAssertEqual(reflected.boolean, true)
+        XCTAssertEqual(reflected.string!.toString(), "hello")
+        XCTAssertEqual(reflected.option_struct!.field, 123)
+        XCTAssertEqual(reflected.option_enum, .Variant2)
     }
  
     func testStructWithOptionFieldsNone() {
@@ -154,7 +174,7 @@ class OptionTests: XCTestCase {
             u8: nil, i8: nil, u16: nil, i16: nil,
             u32: nil, i32: nil, u64: nil, i64: nil,
             usize: nil, isize: nil, f32: nil, f64: nil,
-            boolean: nil
+            boolean: nil, string: nil, option_struct: nil, option_enum: nil
         )
         let reflected = rust_reflect_struct_with_option_fields(val)
         XCTAssertEqual(reflected.i8, nil)
@@ -169,6 +189,9 @@ class OptionTests: XCTestCase {
         XCTAssertEqual(reflected.f32, nil)
         XCTAssertEqual(reflected.f64, nil)
         XCTAssertEqual(reflected.boolean, nil)
+        XCTAssertNil(reflected.string)
+        XCTAssertNil(reflected.option_struct)
+        XCTAssertNil(reflected.option_enum)
     }
     
     func testEnumWhereVariantsHaveNoData() {
@@ -0,0 +1,78 @@
+//
+//  PixelBufferTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+import CoreVideo
+@testable import SwiftRustIntegrationTestRunner
+
+class PixelBufferTests: XCTestCase {
+    /// Verify that Rust can lock a pixel buffer and read its pixels.
+    func testRustReadsPixelBuffer() throws {
+        let buffer = makeBGRAPixelBuffer(width: 4, height: 3, value: 2)
+
+        XCTAssertEqual(rust_pixel_buffer_byte_sum(buffer), byteSum(buffer))
+        XCTAssertEqual(byteSum(buffer), UInt64(CVPixelBufferGetBytesPerRow(buffer) * 3 * 2))
+    }
+
+    /// Verify that Rust can write to a pixel buffer and hand the same buffer back to Swift.
+    func testRustWritesPixelBuffer() throws {
+        let buffer = makeBGRAPixelBuffer(width: 2, height: 2, value: 0)
+
+        let returned = rust_fill_pixel_buffer(buffer, 7)
+
+        XCTAssertTrue(returned === buffer)
+        XCTAssertEqual(byteSum(buffer), UInt64(CVPixelBufferGetBytesPerRow(buffer) * 2 * 7))
+    }
+
+    /// Verify that Rust can read every plane of a planar pixel buffer.
+    func testRustReadsPlanarPixelBuffer() throws {
+        var buffer: CVPixelBuffer?
+        CVPixelBufferCreate(kCFAllocatorDefault, 4, 4, kCVPixelFormatType_420YpCbCr8BiPlanarFullRange, nil, &buffer)
+        let planar = rust_fill_pixel_buffer(buffer!, 1)
+
+        XCTAssertEqual(rust_pixel_buffer_byte_sum(planar), byteSum(planar))
+    }
+
+    func testOptionPixelBuffer() throws {
+        let buffer = makeBGRAPixelBuffer(width: 1, height: 1, value: 0)
+
+        XCTAssertTrue(rust_reflect_option_pixel_buffer(buffer) === buffer)
+        XCTAssertNil(rust_reflect_option_pixel_buffer(nil))
+    }
+}
+
+private func makeBGRAPixelBuffer(width: Int, height: Int, value: UInt8) -> CVPixelBuffer {
+    var buffer: CVPixelBuffer?
+    CVPixelBufferCreate(kCFAllocatorDefault, width, height, kCVPixelFormatType_32BGRA, nil, &buffer)
+    let pixelBuffer = buffer!
+
+    CVPixelBufferLockBaseAddress(pixelBuffer, [])
+    memset(CVPixelBufferGetBaseAddress(pixelBuffer), Int32(value), CVPixelBufferGetBytesPerRow(pixelBuffer) * height)
+    CVPixelBufferUnlockBaseAddress(pixelBuffer, [])
+
+    return pixelBuffer
+}
+
+/// Sum every byte of every plane, including the padding at the end of each row.
+private func byteSum(_ buffer: CVPixelBuffer) -> UInt64 {
+    CVPixelBufferLockBaseAddress(buffer, .readOnly)
+    defer { CVPixelBufferUnlockBaseAddress(buffer, .readOnly) }
+
+    var planes: [(UnsafeMutableRawPointer?, Int)] = []
+    if CVPixelBufferIsPlanar(buffer) {
+        for plane in 0..<CVPixelBufferGetPlaneCount(buffer) {
+            planes.append((
+                CVPixelBufferGetBaseAddressOfPlane(buffer, plane),
+                CVPixelBufferGetBytesPerRowOfPlane(buffer, plane) * CVPixelBufferGetHeightOfPlane(buffer, plane)
+            ))
+        }
+    } else {
+        planes.append((CVPixelBufferGetBaseAddress(buffer), CVPixelBufferGetBytesPerRow(buffer) * CVPixelBufferGetHeight(buffer)))
+    }
+
+    return planes.reduce(0) { sum, plane in
+        sum + UnsafeRawBufferPointer(start: plane.0, count: plane.1).reduce(0) { $0 + UInt64($1) }
+    }
+}
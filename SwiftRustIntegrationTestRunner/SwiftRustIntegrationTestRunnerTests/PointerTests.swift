@@ -37,4 +37,32 @@ class PointerTests: XCTestCase {
     func testRustCallSwiftUInt8() throws {
         rust_run_u8_pointer_tests()
     }
+
+    func testSwiftCallRustOptionPointer() throws {
+        var value: UInt8 = 5
+        let pointer = UnsafeRawPointer(&value)
+
+        XCTAssertEqual(rust_echo_option_const_c_void(pointer), pointer)
+        XCTAssertNil(rust_echo_option_const_c_void(nil))
+
+        withUnsafeMutablePointer(to: &value) { pointer in
+            XCTAssertEqual(rust_echo_option_mut_u8(pointer), pointer)
+        }
+        XCTAssertNil(rust_echo_option_mut_u8(nil))
+    }
+
+    func testRustCallSwiftOptionPointer() throws {
+        rust_run_option_pointer_tests()
+    }
+
+    func testSharedStructPointerFields() throws {
+        let value = [1, 2, 3]
+        let texture = UnsafeMutableRawPointer(mutating: value)
+
+        let handles = rust_reflect_texture_handles(
+            TextureHandles(texture: texture, fallback: nil)
+        )
+        XCTAssertEqual(handles.texture, texture)
+        XCTAssertNil(handles.fallback)
+    }
 }
@@ -30,5 +30,278 @@ class PrimitiveTests: XCTestCase {
         XCTAssertEqual(rust_negate_bool(true), false);
         XCTAssertEqual(rust_negate_bool(false), true);
     }
-}
 
+    /// Verify that we can pass a Duration to Rust as a TimeInterval and get it back.
+    func testReflectDuration() throws {
+        XCTAssertEqual(rust_reflect_duration(0), 0)
+        XCTAssertEqual(rust_reflect_duration(1.5), 1.5)
+        XCTAssertEqual(rust_double_duration(0.25), 0.5)
+    }
+
+    /// Verify that we can pass an Option<Duration> to Rust and get it back.
+    func testReflectOptionDuration() throws {
+        XCTAssertEqual(rust_reflect_option_duration(2.5), 2.5)
+        XCTAssertNil(rust_reflect_option_duration(nil))
+    }
+
+    /// Verify that a shared struct can have a Duration field.
+    func testSharedStructDurationField() throws {
+        let val = rust_reflect_duration_test_struct(DurationTestStruct(timeout: 30))
+
+        XCTAssertEqual(val.timeout, 30)
+    }
+
+    /// Verify that Rust can pass a Duration to Swift and get it back.
+    func testRustCallsSwiftReflectDuration() throws {
+        test_rust_calls_swift_reflect_duration()
+    }
+
+    /// Verify that we can pass a SystemTime to Rust as a Date and get it back, including dates
+    /// before the Unix epoch.
+    func testReflectSystemTime() throws {
+        let dates = [
+            Date(timeIntervalSince1970: 0),
+            Date(timeIntervalSince1970: 1_650_000_000.5),
+            Date(timeIntervalSince1970: -1.5),
+        ]
+        for date in dates {
+            XCTAssertEqual(rust_reflect_system_time(date), date)
+        }
+
+        XCTAssertEqual(
+            rust_system_time_add_seconds(Date(timeIntervalSince1970: 10), 5),
+            Date(timeIntervalSince1970: 15)
+        )
+    }
+
+    /// Verify that we can pass an Option<SystemTime> to Rust and get it back.
+    func testReflectOptionSystemTime() throws {
+        let date = Date(timeIntervalSince1970: 100)
+
+        XCTAssertEqual(rust_reflect_option_system_time(date), date)
+        XCTAssertNil(rust_reflect_option_system_time(nil))
+    }
+
+    /// Verify that a shared struct can have a SystemTime field.
+    func testSharedStructSystemTimeField() throws {
+        let date = Date(timeIntervalSince1970: 1_000)
+        let val = rust_reflect_system_time_test_struct(SystemTimeTestStruct(created_at: date))
+
+        XCTAssertEqual(val.created_at, date)
+    }
+
+    /// Verify that Rust can pass a SystemTime to Swift and get it back.
+    func testRustCallsSwiftReflectSystemTime() throws {
+        test_rust_calls_swift_reflect_system_time()
+    }
+
+    /// Verify that we can pass a Date to Rust as a chrono DateTime<Utc> or a time OffsetDateTime
+    /// and get it back.
+    func testReflectDateTimes() throws {
+        let dates = [
+            Date(timeIntervalSince1970: 0),
+            Date(timeIntervalSince1970: 1_650_000_000.5),
+            Date(timeIntervalSince1970: -1.5),
+        ]
+        for date in dates {
+            XCTAssertEqual(rust_reflect_chrono_date_time(date), date)
+            XCTAssertEqual(rust_reflect_offset_date_time(date), date)
+        }
+
+        XCTAssertEqual(
+            rust_chrono_date_time_rfc3339(Date(timeIntervalSince1970: 1_650_000_000)).toString(),
+            "2022-04-15T05:20:00+00:00"
+        )
+        XCTAssertEqual(
+            rust_offset_date_time_unix_timestamp(Date(timeIntervalSince1970: 1_650_000_000)),
+            1_650_000_000
+        )
+    }
+
+    /// Verify that we can pass an optional chrono DateTime<Utc> or time OffsetDateTime to Rust
+    /// and get it back.
+    func testReflectOptionDateTimes() throws {
+        let date = Date(timeIntervalSince1970: 100)
+
+        XCTAssertEqual(rust_reflect_option_chrono_date_time(date), date)
+        XCTAssertNil(rust_reflect_option_chrono_date_time(nil))
+        XCTAssertEqual(rust_reflect_option_offset_date_time(date), date)
+        XCTAssertNil(rust_reflect_option_offset_date_time(nil))
+    }
+
+    /// Verify that a shared struct can have chrono DateTime<Utc> and time OffsetDateTime fields.
+    func testSharedStructDateTimeFields() throws {
+        let date = Date(timeIntervalSince1970: 1_000)
+        let val = rust_reflect_date_time_test_struct(DateTimeTestStruct(chrono: date, time: date))
+
+        XCTAssertEqual(val.chrono, date)
+        XCTAssertEqual(val.time, date)
+    }
+
+    /// Verify that Rust can pass a chrono DateTime<Utc> and a time OffsetDateTime to Swift and get
+    /// them back.
+    func testRustCallsSwiftReflectDateTimes() throws {
+        test_rust_calls_swift_reflect_date_times()
+    }
+
+    /// Verify that we can pass a measurement to Rust and get it back in the same unit.
+    func testReflectMeasurement() throws {
+        let length = Measurement(value: 2.5, unit: UnitLength.kilometers)
+        XCTAssertEqual(rust_reflect_length(length), length)
+        XCTAssertEqual(rust_reflect_length(length).unit, UnitLength.kilometers)
+
+        let celsius = rust_temperature_in_celsius(Measurement(value: 300, unit: UnitTemperature.kelvin))
+        XCTAssertEqual(celsius.unit, UnitTemperature.celsius)
+        XCTAssertEqual(celsius.value, 26.85, accuracy: 0.000001)
+    }
+
+    /// Verify that a measurement whose unit Rust does not know about is converted to the base unit.
+    func testMeasurementWithUnknownUnit() throws {
+        let length = rust_reflect_length(Measurement(value: 1, unit: UnitLength.furlongs))
+
+        XCTAssertEqual(length.unit, UnitLength.meters)
+        XCTAssertEqual(length.value, 201.168, accuracy: 0.000001)
+    }
+
+    /// Verify that we can pass an optional measurement to Rust and get it back.
+    func testReflectOptionMeasurement() throws {
+        let mass = Measurement(value: 70, unit: UnitMass.kilograms)
+
+        XCTAssertEqual(rust_reflect_option_mass(mass), mass)
+        XCTAssertNil(rust_reflect_option_mass(nil))
+    }
+
+    /// Verify that a shared struct can have a measurement field.
+    func testSharedStructMeasurementField() throws {
+        let temperature = Measurement(value: 98.6, unit: UnitTemperature.fahrenheit)
+        let val = rust_reflect_measurement_test_struct(MeasurementTestStruct(body_temperature: temperature))
+
+        XCTAssertEqual(val.body_temperature, temperature)
+    }
+
+    /// Verify that Rust can pass a measurement to Swift and get it back.
+    func testRustCallsSwiftReflectMeasurement() throws {
+        test_rust_calls_swift_reflect_speed()
+    }
+
+    /// Verify that we can pass a UUID to Rust and get back the same bytes.
+    func testReflectUuid() throws {
+        let uuid = UUID(uuidString: "67E55044-10B1-426F-9247-BB680E5FE0C8")!
+
+        XCTAssertEqual(rust_reflect_uuid(uuid), uuid)
+        XCTAssertEqual(rust_uuid_string(uuid).toString(), "67e55044-10b1-426f-9247-bb680e5fe0c8")
+    }
+
+    /// Verify that we can pass an optional UUID to Rust and get it back.
+    func testReflectOptionUuid() throws {
+        let uuid = UUID()
+
+        XCTAssertEqual(rust_reflect_option_uuid(uuid), uuid)
+        XCTAssertNil(rust_reflect_option_uuid(nil))
+    }
+
+    /// Verify that a shared struct can have a UUID field.
+    func testSharedStructUuidField() throws {
+        let uuid = UUID()
+        let val = rust_reflect_uuid_test_struct(UuidTestStruct(id: uuid))
+
+        XCTAssertEqual(val.id, uuid)
+    }
+
+    /// Verify that Rust can pass a UUID to Swift and get it back.
+    func testRustCallsSwiftReflectUuid() throws {
+        test_rust_calls_swift_reflect_uuid()
+    }
+
+    /// Verify that we can pass IPv4 and IPv6 addresses to Rust and get them back.
+    func testReflectIpAddr() throws {
+        let v4 = RustIpAddr("192.168.1.20")!
+        let v6 = RustIpAddr("2001:db8::1")!
+
+        XCTAssertEqual(rust_reflect_ip_addr(v4), v4)
+        XCTAssertEqual(rust_reflect_ip_addr(v6), v6)
+        XCTAssertEqual(v4.octets, [192, 168, 1, 20])
+        XCTAssertEqual(v6.description, "2001:db8::1")
+        XCTAssertNil(RustIpAddr("not an ip address"))
+    }
+
+    /// Verify that we can pass a socket address to Rust, including its IPv6 scope ID, and get it
+    /// back.
+    func testReflectSocketAddr() throws {
+        let v4 = RustSocketAddr(ip: RustIpAddr("127.0.0.1")!, port: 8080)
+        let v6 = RustSocketAddr(ip: RustIpAddr("fe80::1")!, port: 22, scopeId: 3)
+
+        XCTAssertEqual(rust_reflect_socket_addr(v4), v4)
+        XCTAssertEqual(rust_reflect_socket_addr(v6), v6)
+        XCTAssertEqual(rust_socket_addr_string(v4).toString(), v4.description)
+        XCTAssertEqual(rust_socket_addr_string(v6).toString(), "[fe80::1%3]:22")
+
+        XCTAssertEqual(rust_reflect_option_socket_addr(v4), v4)
+        XCTAssertNil(rust_reflect_option_socket_addr(nil))
+    }
+
+    /// Verify that a shared struct can have IpAddr and SocketAddr fields.
+    func testSharedStructNetAddrFields() throws {
+        let ip = RustIpAddr("::1")!
+        let socket = RustSocketAddr(ip: RustIpAddr("10.0.0.1")!, port: 53)
+        let val = rust_reflect_net_addr_test_struct(NetAddrTestStruct(ip: ip, socket: socket))
+
+        XCTAssertEqual(val.ip, ip)
+        XCTAssertEqual(val.socket, socket)
+    }
+
+    /// Verify that Rust can pass a SocketAddr to Swift and get it back.
+    func testRustCallsSwiftReflectSocketAddr() throws {
+        test_rust_calls_swift_reflect_socket_addr()
+    }
+
+    /// Verify that a module annotated with `net_addrs_as_strings` passes its addresses as strings.
+    func testNetAddrsAsStrings() throws {
+        XCTAssertTrue(rust_ip_addr_string_is_loopback("127.0.0.1"))
+        XCTAssertFalse(rust_ip_addr_string_is_loopback("192.168.1.20"))
+        XCTAssertEqual(rust_socket_addr_string_with_port("[::1]:80", 443), "[::1]:443")
+
+        XCTAssertEqual(rust_reflect_option_ip_addr_string("::1"), "::1")
+        XCTAssertNil(rust_reflect_option_ip_addr_string(nil as String?))
+    }
+
+    /// Verify that we can pass Range and ClosedRange values to Rust and get them back.
+    func testReflectRanges() throws {
+        XCTAssertEqual(rust_reflect_range_u32(5..<10), 5..<10)
+        XCTAssertEqual(rust_reflect_range_u32(3..<3), 3..<3)
+        XCTAssertEqual(rust_reflect_range_inclusive_i64(-3...3), -3...3)
+        XCTAssertEqual(rust_reflect_range_inclusive_i64(Int64.min...Int64.max), Int64.min...Int64.max)
+
+        XCTAssertEqual(rust_page_range(2, 25), 50..<75)
+    }
+
+    /// Verify that a Rust range whose start is greater than its end becomes an empty Swift range
+    /// instead of crashing.
+    func testReversedRanges() throws {
+        XCTAssertEqual(rust_reversed_range_u32(), 5..<5)
+        XCTAssertTrue(rust_reversed_range_u32().isEmpty)
+        XCTAssertEqual(rust_empty_range_inclusive_i64(), 1...1)
+    }
+
+    /// Verify that a shared struct can have Range and ClosedRange fields.
+    func testSharedStructRangeFields() throws {
+        let val = rust_reflect_range_test_struct(RangeTestStruct(rows: 100..<200, pages: 1...4))
+
+        XCTAssertEqual(val.rows, 100..<200)
+        XCTAssertEqual(val.pages, 1...4)
+    }
+
+    /// Verify that Rust can pass ranges to Swift and get them back.
+    func testRustCallsSwiftReflectRanges() throws {
+        test_rust_calls_swift_reflect_ranges()
+    }
+
+    /// Verify that constants in the bridge module have the same values in Swift as in Rust.
+    func testBridgeModuleConstants() throws {
+        XCTAssertEqual(CONSTANT_TEST_BUFFER_SIZE, rust_constant_test_buffer_size())
+        XCTAssertEqual(CONSTANT_TEST_OFFSET, -10)
+        XCTAssertEqual(CONSTANT_TEST_RATIO, 0.25)
+        XCTAssertTrue(CONSTANT_TEST_ENABLED)
+        XCTAssertEqual(CONSTANT_TEST_PROTOCOL, rust_constant_test_protocol().toString())
+    }
+}
@@ -193,6 +193,38 @@ class ResultTests: XCTestCase {
         }
     }
 
+    /// Verify that we can pass a Result<SharedStruct, TransparentEnum> to Rust and receive it back
+    func testResultSharedStructTransparentEnum() throws {
+        XCTContext.runActivity(named: "Should return a shared struct") {
+            _ in
+            do {
+                let value = try rust_func_reflect_result_shared_struct_transparent_enum(.Ok(ResultTestSharedStruct(data: 123)))
+                XCTAssertEqual(value.data, 123)
+            } catch {
+                XCTFail()
+            }
+        }
+
+        XCTContext.runActivity(named: "Should throw an error") {
+            _ in
+            do {
+                let _ = try rust_func_reflect_result_shared_struct_transparent_enum(.Err(.NamedField(data: -123)))
+                XCTFail("The function should have returned an error.")
+            } catch let error as ResultTransparentEnum {
+                switch error {
+                case .NamedField(let data):
+                    XCTAssertEqual(data, -123)
+                case .UnnamedFields(_, _):
+                    XCTFail()
+                case .NoFields:
+                    XCTFail()
+                }
+            } catch {
+                XCTFail()
+            }
+        }
+    }
+
     /// Verify that we can receive a Result<Vec<>, OpaqueRust> from Rust
     func testSwiftCallRustResultVecUInt32Rust() throws {
         let vec = try! rust_func_return_result_of_vec_u32()
@@ -0,0 +1,37 @@
+//
+//  SerdeJsonTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+class SerdeJsonTests: XCTestCase {
+    /// Verify that JSON objects, arrays and fragments can be passed to Rust and back.
+    func testReflectJson() throws {
+        let config: [String: Any] = ["name": "sync", "enabled": true, "retries": 3, "tags": ["a", "b"]]
+        let reflected = rust_reflect_json(config) as! [String: Any]
+
+        XCTAssertEqual(reflected["name"] as? String, "sync")
+        XCTAssertEqual(reflected["enabled"] as? Bool, true)
+        XCTAssertEqual(reflected["retries"] as? Int, 3)
+        XCTAssertEqual(reflected["tags"] as? [String], ["a", "b"])
+
+        XCTAssertEqual(rust_reflect_json("a string") as? String, "a string")
+        XCTAssertEqual(rust_reflect_json(1.5) as? Double, 1.5)
+        XCTAssert(rust_reflect_json(NSNull()) is NSNull)
+    }
+
+    /// Verify that Rust receives the JSON that Swift serialized.
+    func testRustReadsJson() throws {
+        XCTAssertEqual(rust_json_to_string(["key": [1, 2]]).toString(), #"{"key":[1,2]}"#)
+
+        XCTAssertEqual(rust_json_get(["key": "value"], "key") as? String, "value")
+        XCTAssertNil(rust_json_get(["key": "value"], "missing"))
+    }
+
+    /// Verify that Rust can pass JSON to Swift and get it back.
+    func testRustCallsSwiftReflectJson() throws {
+        test_rust_calls_swift_reflect_json()
+    }
+}
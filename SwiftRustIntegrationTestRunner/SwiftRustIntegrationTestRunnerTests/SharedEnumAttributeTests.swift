@@ -42,5 +42,11 @@ class SharedEnumAttributeTests: XCTestCase {
         XCTAssertEqual(debugString, "Variant")
     }
 
+    /// Verify that Rust and Swift agree on the discriminants of an enum that uses the
+    /// `discriminant_check` attribute.
+    func testSharedEnumDiscriminantCheck() throws {
+        DiscriminantCheckEnum.assertDiscriminantsMatchRust()
+    }
+
 }
 
@@ -32,6 +32,34 @@ class SharedEnumTests: XCTestCase {
         }
     }
 
+    /// Verify that explicit discriminants are kept when an enum crosses the bridge.
+    func testEnumWithDiscriminants() {
+        XCTAssertEqual(reflect_enum_with_discriminants(.North), .North)
+        XCTAssertEqual(reflect_enum_with_discriminants(.Below), .Below)
+
+        XCTAssertEqual(enum_with_discriminants_as_i32(.North), 1)
+        XCTAssertEqual(enum_with_discriminants_as_i32(.South), 2)
+        XCTAssertEqual(enum_with_discriminants_as_i32(.Below), -1)
+    }
+
+    /// Verify that we can pass an enum whose variants carry floats, and that has derives.
+    func testEnumWithFloatData() {
+        switch scale_enum_with_float_data(.Circle(radius: 1.5), 2) {
+        case .Circle(let radius):
+            XCTAssertEqual(radius, 3)
+        default:
+            XCTFail()
+        }
+
+        switch scale_enum_with_float_data(.Rect(w: 1, h: 2), 3) {
+        case .Rect(let w, let h):
+            XCTAssertEqual(w, 3)
+            XCTAssertEqual(h, 6)
+        default:
+            XCTFail()
+        }
+    }
+
     func testEnumWithUnnamedData() {
         let enumWithUnnamedData1 = EnumWithUnnamedData.TwoFields(create_string("hello"), OpaqueRustForEnumTest())
         switch reflect_enum_with_unnamed_data(enumWithUnnamedData1) {
@@ -34,5 +34,197 @@ class SharedStructAttributeTests: XCTestCase {
     func testSharedStructAlreadyDeclared() throws {
         test_rust_calls_swift_already_declared_struct()
     }
-}
 
+    /// Verify that we can pass a struct whose fields were renamed using `swift_name` between Swift and Rust.
+    /// See crates/swift-integration-tests/src/struct_attributes/swift_name.rs
+    func testSharedStructFieldSwiftName() throws {
+        let val = rust_reflect_struct_with_renamed_fields(StructWithRenamedFields(userId: 123, isAdmin: true))
+
+        XCTAssertEqual(val.userId, 123)
+        XCTAssertEqual(val.isAdmin, true)
+    }
+
+    /// Verify that Swift can leave out the fields of a shared struct that have default values.
+    /// See crates/swift-integration-tests/src/struct_attributes/field_default.rs
+    func testSharedStructFieldDefaults() throws {
+        let config = rust_reflect_field_default_config(FieldDefaultTestConfig(retries: 3))
+        XCTAssertEqual(config.port, 8080)
+        XCTAssertEqual(config.gain, -0.5)
+        XCTAssertEqual(config.verbose, true)
+        XCTAssertEqual(config.retries, 3)
+
+        let custom = FieldDefaultTestConfig(port: 9000, retries: 1)
+        XCTAssertEqual(custom.port, 9000)
+        XCTAssertEqual(custom.gain, -0.5)
+    }
+
+    /// Verify that we can compute the changed fields of a diffable struct in Rust and apply only
+    /// those fields in Swift.
+    /// See crates/swift-integration-tests/src/struct_attributes/diffable.rs
+    func testSharedStructDiffable() throws {
+        var state = DiffableStructTest(count: 1, is_enabled: false, ratio: 0.5)
+        let update = DiffableStructTest(count: 2, is_enabled: false, ratio: 0.75)
+
+        let changedFields = DiffableStructTest.ChangedFields(
+            rawValue: rust_diffable_struct_changed_fields(update, state)
+        )
+        XCTAssertEqual(changedFields, [.count, .ratio])
+
+        state.apply(update, changedFields: [.count])
+        XCTAssertEqual(state.count, 2)
+        XCTAssertEqual(state.ratio, 0.5)
+
+        state.apply(update, changedFields: changedFields)
+        XCTAssertEqual(state.ratio, 0.75)
+    }
+
+    /// Verify that Rust can convert a shared struct to and from the type that it mirrors.
+    /// See crates/swift-integration-tests/src/struct_attributes/mirror.rs
+    func testSharedStructMirror() throws {
+        let val = rust_increment_mirrored_struct(MirrorStructTest(name: "hello".intoRustString(), count: 1))
+
+        XCTAssertEqual(val.name.toString(), "hello")
+        XCTAssertEqual(val.count, 2)
+    }
+
+    /// Verify that aligned and packed shared structs have the same layout in Rust and Swift.
+    /// See crates/swift-integration-tests/src/struct_attributes/layout.rs
+    func testSharedStructLayout() throws {
+        XCTAssertEqual(MemoryLayout<__swift_bridge__$AlignedStructTest>.size, Int(rust_aligned_struct_ffi_size()))
+        XCTAssertEqual(MemoryLayout<__swift_bridge__$AlignedStructTest>.alignment, 16)
+        XCTAssertEqual(MemoryLayout<__swift_bridge__$PackedStructTest>.size, Int(rust_packed_struct_ffi_size()))
+        XCTAssertEqual(MemoryLayout<__swift_bridge__$PackedStructTest>.size, 5)
+
+        let aligned = rust_reflect_aligned_struct(AlignedStructTest(x: 1.5, y: 2.5))
+        XCTAssertEqual(aligned.x, 1.5)
+        XCTAssertEqual(aligned.y, 2.5)
+
+        let packed = rust_reflect_packed_struct(PackedStructTest(tag: 7, value: 123456))
+        XCTAssertEqual(packed.tag, 7)
+        XCTAssertEqual(packed.value, 123456)
+    }
+
+    /// Verify that we can pass each instantiation of a generic shared struct between Swift and Rust.
+    /// See crates/swift-integration-tests/src/struct_attributes/instantiate.rs
+    func testGenericSharedStructInstantiations() throws {
+        let u32Pair: GenericPairTest<UInt32> = rust_swap_u32_pair(GenericPairTest(first: 1, second: 2, tag: 3))
+        XCTAssertEqual(u32Pair.first, 2)
+        XCTAssertEqual(u32Pair.second, 1)
+        XCTAssertEqual(u32Pair.tag, 3)
+
+        let f64Pair: GenericPairTest<Double> = rust_swap_f64_pair(GenericPairTest(first: 1.5, second: 2.5, tag: 4))
+        XCTAssertEqual(f64Pair.first, 2.5)
+        XCTAssertEqual(f64Pair.second, 1.5)
+        XCTAssertEqual(f64Pair.tag, 4)
+    }
+
+    /// Verify that Rust and Swift encode `little_endian` structs as the same bytes.
+    /// See crates/swift-integration-tests/src/struct_attributes/little_endian.rs
+    func testLittleEndianStruct() throws {
+        let value = LittleEndianStructTest(kind: 1, len: 0x01020304, offset: -2, scale: 1.5)
+
+        let swiftBytes = value.toLittleEndianBytes()
+        let rustBytes = rust_encode_little_endian_struct(value)
+        XCTAssertEqual(swiftBytes.count, LittleEndianStructTest.littleEndianByteCount)
+        XCTAssertEqual(swiftBytes, Array(rustBytes))
+        XCTAssertEqual(Array(swiftBytes[1..<5]), [4, 3, 2, 1])
+
+        let rustBytesVec = RustVec<UInt8>()
+        for byte in swiftBytes {
+            rustBytesVec.push(value: byte)
+        }
+        let decoded = rust_decode_little_endian_struct(rustBytesVec)
+        XCTAssertEqual(decoded.len, 0x01020304)
+        XCTAssertEqual(decoded.offset, -2)
+        XCTAssertEqual(decoded.scale, 1.5)
+
+        let swiftDecoded = LittleEndianStructTest(littleEndianBytes: swiftBytes)!
+        XCTAssertEqual(swiftDecoded.kind, 1)
+        XCTAssertEqual(swiftDecoded.scale, 1.5)
+        XCTAssertNil(LittleEndianStructTest(littleEndianBytes: [1, 2, 3]))
+    }
+
+    /// Verify that structs with `#[swift_bridge(derive(Equatable, Hashable))]` can be compared and
+    /// used as `Set` elements in Swift, and are compared the same way in Rust.
+    /// See crates/swift-integration-tests/src/struct_attributes/derive.rs
+    func testSharedStructDeriveEquatableHashable() throws {
+        XCTAssertEqual(StructDeriveEquatable(field: 1), StructDeriveEquatable(field: 1))
+        XCTAssertNotEqual(StructDeriveEquatable(field: 1), StructDeriveEquatable(field: 2))
+        XCTAssertTrue(rust_equatable_structs_are_equal(StructDeriveEquatable(field: 3), StructDeriveEquatable(field: 3)))
+
+        let values = [
+            StructDeriveHashable(id: 1, nested: StructDeriveEquatableHashable(field: 1)),
+            StructDeriveHashable(id: 1, nested: StructDeriveEquatableHashable(field: 1)),
+            StructDeriveHashable(id: 1, nested: StructDeriveEquatableHashable(field: 2)),
+        ]
+        XCTAssertEqual(Set(values).count, 2)
+
+        var counts: [StructDeriveHashable: Int] = [:]
+        for value in values {
+            counts[value, default: 0] += 1
+        }
+        XCTAssertEqual(counts[values[0]], 2)
+
+        let vec = RustVec<StructDeriveHashable>()
+        for value in values {
+            vec.push(value: value)
+        }
+        XCTAssertEqual(rust_count_unique_hashable_structs(vec), 2)
+    }
+
+    /// Verify that a struct with `#[swift_bridge(Sendable)]` can be passed to another task.
+    /// See crates/swift-integration-tests/src/struct_attributes/sendable.rs
+    func testSharedStructSendable() async throws {
+        let value = SendableTestStruct(name: "hello".intoRustString(), count: 3)
+
+        let reflected = await Task.detached {
+            rust_reflect_sendable_struct(value)
+        }.value
+
+        XCTAssertEqual(reflected.name.toString(), "hello")
+        XCTAssertEqual(reflected.count, 3)
+    }
+
+    /// Verify that a struct with `#[swift_bridge(derive(Codable))]` can be encoded and decoded,
+    /// using the Rust field names as its keys.
+    /// See crates/swift-integration-tests/src/struct_attributes/derive.rs
+    func testSharedStructDeriveCodable() throws {
+        let value = rust_make_codable_struct()
+
+        let json = try JSONEncoder().encode(value)
+        let object = try JSONSerialization.jsonObject(with: json) as! [String: Any]
+        XCTAssertEqual(object["id"] as? UInt32, 7)
+        XCTAssertEqual(object["is_visible"] as? Bool, true)
+
+        let decoded = try JSONDecoder().decode(StructDeriveCodable.self, from: json)
+        XCTAssertEqual(decoded, value)
+    }
+
+    /// Verify that a struct's `Codable` conformance and its serde implementation agree, by
+    /// encoding it in Swift, decoding and re-encoding it with serde in Rust, then decoding it in
+    /// Swift.
+    /// See crates/swift-integration-tests/src/struct_attributes/derive.rs
+    func testSharedStructCodableAndSerdeAgree() throws {
+        let values = [
+            StructDeriveCodableSerde(id: 1, offset: -5, score: 2.5, isVisible: true, parent_id: 3),
+            StructDeriveCodableSerde(id: 2, offset: 0, score: 0, isVisible: false, parent_id: nil),
+        ]
+
+        for value in values {
+            XCTAssertEqual(try StructDeriveCodableSerde.serdeJsonRoundTrip(value), value)
+        }
+    }
+
+    /// Verify that we can pass types that were registered using `#[swift_bridge::bridged]`
+    /// between Swift and Rust.
+    /// See crates/swift-integration-tests/src/bridged_types.rs
+    func testBridgedTypes() throws {
+        let user = rust_reflect_bridged_struct(BridgedTypeTestUser(name: "Swift".intoRustString(), age: 7))
+        XCTAssertEqual(user.name.toString(), "Swift")
+        XCTAssertEqual(user.age, 7)
+
+        XCTAssertEqual(rust_make_bridged_struct().name.toString(), "Rust")
+
+        XCTAssertEqual(rust_reflect_bridged_enum(.Member), .Member)
+    }
+}
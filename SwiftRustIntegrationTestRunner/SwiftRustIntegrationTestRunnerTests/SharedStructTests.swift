@@ -42,4 +42,93 @@ class SharedStructTests: XCTestCase {
         XCTAssertEqual(val._0, reflected._0)
         XCTAssertEqual(val._1, reflected._1)
     }
+    
+    /// Verify that a shared struct can have a `&'static str` field.
+    func testStructWithStaticStrField() {
+        let entry = rust_error_catalog_entry(404)
+
+        XCTAssertEqual(entry.code, 404)
+        XCTAssertEqual(entry.message.toString(), "Not Found")
+    }
+
+    /// Verify that a shared struct can have a `String` field.
+    func testStructWithStringField() {
+        let val = swift_calls_rust_struct_with_string_field(
+            StructReprStructWithStringField(id: 1, name: "hello".intoRustString())
+        )
+
+        XCTAssertEqual(val.id, 2)
+        XCTAssertEqual(val.name.toString(), "HELLO")
+    }
+
+    func testStructWithVecFields() {
+        let vertices = RustVec<StructReprStructVertex>()
+        vertices.push(value: StructReprStructVertex(x: 1, y: 2))
+        vertices.push(value: StructReprStructVertex(x: 3, y: 4))
+
+        let ids = RustVec<UInt32>()
+        ids.push(value: 10)
+
+        let val = swift_calls_rust_struct_with_vec_fields(
+            StructReprStructWithVecFields(vertices: vertices, ids: ids)
+        )
+
+        XCTAssertEqual(val.vertices.len(), 2)
+        XCTAssertEqual(val.vertices.get(index: 0)!.x, 3)
+        XCTAssertEqual(val.vertices.get(index: 1)!.y, 2)
+        XCTAssertEqual(val.ids.len(), 2)
+        XCTAssertEqual(val.ids.get(index: 1), 2)
+    }
+
+    /// Verify that a `swift_repr = "class"` struct's fields are read and written in place, and
+    /// that it can be passed to and returned from Rust.
+    func testStructReprClass() {
+        let val = StructReprClass(count: 1, name: "hello".intoRustString())
+        XCTAssertEqual(val.count, 1)
+        XCTAssertEqual(val.name.toString(), "hello")
+
+        val.count = 5
+        XCTAssertEqual(val.count, 5)
+
+        let returned = swift_calls_rust_struct_repr_class(val)
+        XCTAssertEqual(returned.count, 6)
+        XCTAssertEqual(returned.name.toString(), "hello world")
+    }
+
+    /// Verify that a shared struct can contain other shared structs.
+    func testNestedStructs() {
+        let line = StructReprStructLine(
+            start: StructReprStructPoint(x: 1, y: 2),
+            end: StructReprStructPoint(x: 3, y: 4)
+        )
+
+        let reversed = swift_calls_rust_reverse_line(line)
+
+        XCTAssertEqual(reversed.start.x, 3)
+        XCTAssertEqual(reversed.start.y, 4)
+        XCTAssertEqual(reversed.end.x, 1)
+        XCTAssertEqual(reversed.end.y, 2)
+    }
+
+    /// Verify that we can lazily read the rows of a Rust `Cursor` across several batches.
+    func testRustCursorRows() {
+        let cursor = rust_make_cursor(5, 2)
+
+        let first = cursor.nextRow()!
+        XCTAssertEqual(first.id, 0)
+        XCTAssertEqual(first.name.toString(), "row 0")
+
+        XCTAssertEqual(cursor.map { $0.id }, [1, 2, 3, 4])
+        XCTAssertNil(cursor.nextRow())
+    }
+
+    /// Verify that we can read a Rust `Cursor` whose rows are fetched one page at a time.
+    func testRustCursorFromPages() {
+        var names: [String] = []
+        for row in rust_make_cursor_from_pages(2) {
+            names.append(row.name.toString())
+        }
+
+        XCTAssertEqual(names, ["row 0", "row 1", "row 2", "row 3", "row 4", "row 5"])
+    }
 }
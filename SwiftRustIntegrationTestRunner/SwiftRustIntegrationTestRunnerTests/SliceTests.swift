@@ -0,0 +1,54 @@
+//
+//  SliceTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+@testable import SwiftRustIntegrationTestRunner
+
+class SliceTests: XCTestCase {
+    /// Verify that Rust can write into a Swift array through a `&mut [u8]`.
+    func testRustMutatesSliceInPlace() throws {
+        var values: [UInt8] = [1, 2, 3]
+
+        values.withUnsafeMutableBufferPointer { buffer in
+            rust_double_values_in_place(buffer)
+        }
+
+        XCTAssertEqual(values, [2, 4, 6])
+    }
+
+    /// Verify that empty slices can be passed in both directions, including an empty buffer with a
+    /// nil base address.
+    func testEmptySlice() throws {
+        XCTAssertEqual(rust_slice_len(UnsafeBufferPointer(start: nil, count: 0)), 0)
+
+        let empty: [UInt8] = []
+        empty.withUnsafeBufferPointer { buffer in
+            XCTAssertEqual(rust_slice_len(buffer), 0)
+        }
+
+        XCTAssertEqual(rust_make_empty_slice().count, 0)
+    }
+
+    /// Verify that Swift can write into a Rust array through a `&mut [f32]`.
+    func testRustCallsSwiftFillSlice() throws {
+        test_rust_calls_swift_fill_slice()
+    }
+
+    /// Verify that we can receive a slice of opaque Rust types from Rust.
+    func testReturnSliceOfOpaqueRustTypes() throws {
+        let values = rust_make_slice_of_opaque_rust_types()
+
+        XCTAssertEqual(values.map { $0.val() }, [1, 2, 3])
+    }
+
+    /// Verify that we can pass the contents of a RustVec to Rust as a slice of opaque Rust types.
+    func testPassSliceOfOpaqueRustTypes() throws {
+        let vec = RustVec<SliceTestOpaqueRustType>()
+        vec.push(value: SliceTestOpaqueRustType(5))
+        vec.push(value: SliceTestOpaqueRustType(10))
+
+        XCTAssertEqual(rust_sum_slice_of_opaque_rust_types(vec), 15)
+    }
+}
@@ -37,7 +37,52 @@ class StringTests: XCTestCase {
         let trimmed: RustStr = rustString.trim()
         XCTAssertEqual(trimmed.len, 5)
     }
+
+    /// Verify that Swift can index into and iterate over a RustString without splitting a
+    /// multi-byte character.
+    func testRustStringCharacterAccess() throws {
+        // "é" is 2 bytes and "👋" is 4 bytes.
+        let rustString: RustString = create_string("héllo👋")
+
+        XCTAssertEqual(rustString.len(), 10)
+        XCTAssertEqual(rustString.char_count(), 6)
+
+        XCTAssert(rustString.is_char_boundary(1))
+        XCTAssertFalse(rustString.is_char_boundary(2))
+        XCTAssert(rustString.is_char_boundary(10))
+
+        XCTAssertEqual(rustString.get(1, 3)?.toString(), "é")
+        XCTAssertNil(rustString.get(1, 2))
+        XCTAssertNil(rustString.get(6, 11))
+
+        XCTAssertEqual(rustString.unicodeScalar(at: 1), "é")
+        XCTAssertNil(rustString.unicodeScalar(at: 2))
+        XCTAssertEqual(rustString.unicodeScalar(at: 6), "👋")
+        XCTAssertNil(rustString.unicodeScalar(at: 10))
+
+        XCTAssertEqual(Array(rustString.unicodeScalars), Array("héllo👋".unicodeScalars))
+    }
     
+    /// Verify that Rust receives the UTF-8 bytes of a Swift String that is passed as a &str,
+    /// including empty strings and strings that are not natively stored as UTF-8.
+    func testSwiftStringToRustStr() throws {
+        XCTAssertEqual(rust_str_byte_len(""), 0)
+        XCTAssertEqual(rust_str_byte_len("héllo"), 6)
+        XCTAssertEqual(rust_str_byte_len(NSString(string: "bridged") as String), 7)
+
+        XCTAssertEqual(create_string("").toString(), "")
+        XCTAssertEqual(create_string("héllo").toString(), "héllo")
+    }
+
+    /// Verify that a String that was created by a Rust function can be used by Swift as a
+    /// RustString and turned into a Swift String.
+    func testRustFunctionReturnsString() throws {
+        let rustString: RustString = rust_make_string()
+
+        XCTAssertEqual(rustString.len(), 10)
+        XCTAssertEqual(rustString.toString(), "hello 🦀")
+    }
+
     func testRustStringToString() throws {
         let string = "hi"
 
@@ -67,5 +112,84 @@ class StringTests: XCTestCase {
             XCTAssertNotEqual(foo.as_str(), bar.as_str())
         }
     }
-}
 
+    /// Verify that we can pass a PathBuf to Rust as a String or a file URL and get it back.
+    func testReflectPathBuf() throws {
+        XCTAssertEqual(rust_reflect_path_buf("/tmp/file.txt"), "/tmp/file.txt")
+        XCTAssertEqual(
+            rust_reflect_path_buf(URL(fileURLWithPath: "/tmp/file.txt")),
+            "/tmp/file.txt"
+        )
+    }
+
+    /// Verify that we can pass a &Path to Rust.
+    func testRefPath() throws {
+        XCTAssertEqual(rust_path_file_name("/tmp/file.txt"), "file.txt")
+        XCTAssertNil(rust_path_file_name("/"))
+        XCTAssertEqual(rust_join_path("/tmp", "file.txt"), "/tmp/file.txt")
+    }
+
+    /// Verify that we can pass an OsString to Rust and get it back.
+    func testReflectOsString() throws {
+        XCTAssertEqual(rust_reflect_os_string("hello 🦀"), "hello 🦀")
+    }
+
+    /// Verify that a shared struct can have a PathBuf field.
+    func testSharedStructPathBufField() throws {
+        let val = rust_reflect_path_test_struct(PathTestStruct(path: "/tmp/file.txt"))
+
+        XCTAssertEqual(val.path, "/tmp/file.txt")
+    }
+
+    /// Verify that Rust can pass a PathBuf to Swift.
+    func testRustCallsSwiftPathIsAbsolute() throws {
+        test_rust_calls_swift_path_is_absolute()
+    }
+
+    /// Verify that we can return both borrowed and owned `Cow<str>`s from Rust.
+    func testReturnCowStr() throws {
+        XCTAssertEqual(rust_cow_str_greeting(false, "Swift"), "hi")
+        XCTAssertEqual(rust_cow_str_greeting(true, "Swift"), "Good day, Swift")
+    }
+
+    /// Verify that a method can return a `Cow<str>` that borrows from `self`.
+    func testMethodReturnsCowStr() throws {
+        let greeter = CowStrGreeter("Rust")
+
+        XCTAssertEqual(greeter.name(), "Rust")
+    }
+
+    /// Verify that we can return an `Option<Cow<str>>` from Rust.
+    func testReturnOptionCowStr() throws {
+        XCTAssertEqual(rust_option_cow_str(true), "borrowed")
+        XCTAssertEqual(rust_option_cow_str(false), "owned")
+        XCTAssertNil(rust_option_cow_str(nil))
+    }
+
+    /// Verify that a module with `#[swift_bridge(utf8 = "lossy")]` replaces invalid UTF-8 in the
+    /// `&str`s that it receives.
+    func testLossyUtf8() throws {
+        let bytes: [UInt8] = [0x68, 0x69, 0xFF]
+        let string = bytes.withUnsafeBufferPointer { buffer in
+            rust_lossy_str_to_string(
+                RustStr(start: UnsafeMutablePointer(mutating: buffer.baseAddress!), len: UInt(buffer.count))
+            ).toString()
+        }
+
+        XCTAssertEqual(string, "hi\u{FFFD}")
+    }
+
+    /// Verify that a module with `#[swift_bridge(utf8 = "lossy")]` replaces invalid UTF-8 in the
+    /// `Option<&str>`s that it receives.
+    func testLossyUtf8Option() throws {
+        let bytes: [UInt8] = [0x68, 0x69, 0xFF]
+        let string = bytes.withUnsafeBufferPointer { buffer in
+            rust_lossy_optional_str_to_string(
+                RustStr(start: UnsafeMutablePointer(mutating: buffer.baseAddress!), len: UInt(buffer.count))
+            )?.toString()
+        }
+
+        XCTAssertEqual(string, "hi\u{FFFD}")
+        XCTAssertNil(rust_lossy_optional_str_to_string(nil as RustStr?))
+    }
+}
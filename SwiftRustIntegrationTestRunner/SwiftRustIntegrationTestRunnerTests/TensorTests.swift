@@ -0,0 +1,59 @@
+//
+//  TensorTests.swift
+//  SwiftRustIntegrationTestRunnerTests
+//
+
+import XCTest
+import CoreML
+@testable import SwiftRustIntegrationTestRunner
+
+class TensorTests: XCTestCase {
+    /// Verify that Swift can read the shape, strides and elements of a tensor that Rust created.
+    func testRustCreatedTensor() throws {
+        let tensor = rust_make_tensor(2, 3)
+
+        XCTAssertEqual(tensor.shape, [2, 3])
+        XCTAssertEqual(tensor.strides, [3, 1])
+        XCTAssertEqual(tensor.dataType, .float32)
+        XCTAssertEqual(tensor.withUnsafeBufferPointer(of: Float.self) { Array($0) }, [0, 1, 2, 3, 4, 5])
+        XCTAssertNil(tensor.withUnsafeBufferPointer(of: Double.self) { Array($0) })
+    }
+
+    /// Verify that an `MLMultiArray` made from a tensor points at its elements and outlives the
+    /// `RustTensor`.
+    func testMakeMLMultiArrayFromTensor() throws {
+        var array: MLMultiArray?
+        do {
+            let tensor = rust_make_tensor(2, 2)
+            array = try tensor.makeMLMultiArray()
+        }
+
+        XCTAssertEqual(array!.shape, [2, 2])
+        XCTAssertEqual(array!.dataType, .float32)
+        XCTAssertEqual(array![[1, 0] as [NSNumber]].floatValue, 2)
+    }
+
+    /// Verify that an `MLMultiArray` can be passed to Rust, including one with padded rows.
+    func testSwiftCreatedTensor() throws {
+        let array = try MLMultiArray(shape: [2, 3], dataType: .double)
+        for idx in 0..<6 {
+            array[idx] = NSNumber(value: idx)
+        }
+        XCTAssertEqual(rust_tensor_sum(RustTensor(array)!), 15)
+
+        let padded: [Int32] = [1, 2, 3, 0, 4, 5, 6, 0]
+        let tensor = padded.withUnsafeBytes { bytes in
+            RustTensor(shape: [2, 3], strides: [4, 1], dataType: .int32, bytes: bytes)
+        }!
+        XCTAssertEqual(rust_tensor_sum(tensor), 21)
+
+        XCTAssertNil(padded.withUnsafeBytes { bytes in
+            RustTensor(shape: [3, 3], strides: [4, 1], dataType: .int32, bytes: bytes)
+        })
+    }
+
+    func testOptionTensor() throws {
+        XCTAssertEqual(rust_reflect_option_tensor(rust_make_tensor(1, 4))!.shape, [1, 4])
+        XCTAssertNil(rust_reflect_option_tensor(nil))
+    }
+}
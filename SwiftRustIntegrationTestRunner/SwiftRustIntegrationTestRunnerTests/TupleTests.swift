@@ -34,6 +34,12 @@ final class TupleTest: XCTestCase {
             XCTAssertEqual(tuple.1, 123)
             XCTAssertEqual(tuple.2, true)
         }
+        XCTContext.runActivity(named: "Verify that a method can return a tuple.") {
+            _ in
+            let minMax = TupleTestOpaqueRustType(5).min_max(-3)
+            XCTAssertEqual(minMax.0, -3)
+            XCTAssertEqual(minMax.1, 5)
+        }
     }
     
     /// Verify that Rust can call Swift functions that accept and return Tuples.
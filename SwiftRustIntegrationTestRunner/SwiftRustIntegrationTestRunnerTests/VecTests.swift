@@ -54,7 +54,72 @@ class VecTests: XCTestCase {
         }
         XCTAssertEqual(iterations, 2)
     }
-    
+
+    /// Verify that slicing a RustVec returns a view that shares the RustVec's indices and memory.
+    func testRustVecSlice() throws {
+        let vec = RustVec<UInt32>()
+        for value: UInt32 in [10, 20, 30, 40, 50] {
+            vec.push(value: value)
+        }
+
+        let slice: RustVecSlice<UInt32> = vec[1..<4]
+        XCTAssertEqual(slice.count, 3)
+        XCTAssertEqual(slice.startIndex, 1)
+        XCTAssertEqual(slice[1], 20)
+        XCTAssertEqual(Array(slice), [20, 30, 40])
+        XCTAssertEqual(Array(slice[2..<4]), [30, 40])
+        XCTAssertEqual(Array(vec.dropFirst(3)), [40, 50])
+
+        let sum = slice.withUnsafeBufferPointer { buffer in
+            buffer.reduce(0, +)
+        }
+        XCTAssertEqual(sum, 90)
+    }
+
+    /// Verify that a RustVec can be sorted and searched in Rust using a Swift comparator.
+    func testRustVecSortAndSearch() throws {
+        let vec = RustVec<Int32>()
+        for value: Int32 in [30, -10, 50, 20, 40] {
+            vec.push(value: value)
+        }
+
+        let descending: RustVec<Int32> = vec.sorted(by: >)
+        XCTAssertEqual(Array(descending), [50, 40, 30, 20, -10])
+        XCTAssertEqual(Array(vec), [30, -10, 50, 20, 40])
+
+        vec.sort()
+        XCTAssertEqual(Array(vec), [-10, 20, 30, 40, 50])
+
+        let found = vec.binarySearch(40)
+        XCTAssertTrue(found.found)
+        XCTAssertEqual(found.index, 3)
+
+        let missing = vec.binarySearch(25)
+        XCTAssertFalse(missing.found)
+        XCTAssertEqual(missing.index, 2)
+
+        let foundDescending = descending.binarySearch(20, by: >)
+        XCTAssertTrue(foundDescending.found)
+        XCTAssertEqual(foundDescending.index, 3)
+
+        XCTAssertTrue(vec.contains(-10))
+        XCTAssertFalse(vec.contains(0))
+    }
+
+    /// Verify that sorting with a comparator that isn't a strict weak ordering doesn't crash.
+    func testRustVecSortWithInconsistentComparator() throws {
+        let vec = RustVec<Float>()
+        for value: Float in [3, .nan, 1, .nan, 2, 5, .nan, 4] {
+            vec.push(value: value)
+        }
+
+        vec.sort()
+        XCTAssertEqual(vec.len(), 8)
+
+        let sorted: RustVec<Float> = vec.sorted(by: { _, _ in true })
+        XCTAssertEqual(sorted.len(), 8)
+    }
+
     func testVecOfOpaqueRustTypeLen() throws {
         let vec = RustVec<ARustTypeInsideVecT>()
         XCTAssertEqual(vec.len(), 0)
@@ -124,6 +189,42 @@ class VecTests: XCTestCase {
         XCTAssertEqual(RustVec<Double>().len(), 0);
     }
 
+    /// Verify that we can pass a Vec<Vec<u8>> to and from Rust.
+    func testRustVecOfRustVec() throws {
+        let chunk = RustVec<UInt8>()
+        chunk.push(value: 1)
+        chunk.push(value: 2)
+
+        let vec = RustVec<RustVec<UInt8>>()
+        vec.push(value: chunk)
+        vec.push(value: RustVec<UInt8>())
+
+        let reflected = rust_reflect_vec_of_vec_u8(vec)
+        XCTAssertEqual(reflected.len(), 2)
+        XCTAssertEqual(reflected.get(index: 0)!.len(), 2)
+        XCTAssertEqual(reflected.get(index: 0)!.get(index: 1), 2)
+
+        XCTAssertEqual(reflected.pop()!.len(), 0)
+        XCTAssertEqual(reflected.pop()!.pop(), 2)
+        XCTAssertNil(reflected.pop())
+    }
+
+    /// Verify that we can pass a Vec<Option<String>> to and from Rust.
+    func testRustVecOfOptionRustString() throws {
+        let vec = RustVec<Optional<RustString>>()
+        vec.push(value: "hello".intoRustString())
+        vec.push(value: nil)
+
+        let reflected = rust_reflect_vec_of_option_string(vec)
+        XCTAssertEqual(reflected.len(), 2)
+        XCTAssertEqual(reflected.get(index: 0)!!.as_str().toString(), "hello")
+        XCTAssertNil(reflected.get(index: 1)!)
+
+        XCTAssertNil(reflected.pop()!)
+        XCTAssertEqual(reflected.pop()!!.toString(), "hello")
+        XCTAssertNil(reflected.pop())
+    }
+
     /// Verify that Rust can pass `RustVec`s to and receive `RustVec`s from Swift.
     func testRustCallsSwiftRustVecFunctions() {
         run_vec_tests()
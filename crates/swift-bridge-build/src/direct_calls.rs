@@ -0,0 +1,256 @@
+/// A generated C header whose scalar-only function declarations were moved into Swift.
+pub(crate) struct DirectCalls {
+    /// `@_silgen_name` declarations that Swift calls instead of the C declarations.
+    pub swift: String,
+    /// The declarations that could not be moved, such as typedefs and functions that take or
+    /// return a struct.
+    pub c_header: String,
+}
+
+impl DirectCalls {
+    /// Whether or not the remaining C header still declares anything, or only holds comments and
+    /// includes.
+    pub fn c_header_is_needed(&self) -> bool {
+        self.c_header.lines().any(|line| {
+            let trimmed = line.trim();
+            !trimmed.is_empty() && !trimmed.starts_with("//") && !trimmed.starts_with('#')
+        })
+    }
+}
+
+/// Move every function declaration in a generated C header whose arguments and return value are
+/// integers, floats, bools or pointers into a Swift `@_silgen_name` declaration of the same
+/// symbol.
+///
+/// `@_silgen_name` declares a function that uses the Swift calling convention instead of the C
+/// calling convention that Rust exports. On the arm64 and x86_64 Apple platforms the two only
+/// differ in the registers that Swift adds for `self`, errors and async contexts, which these
+/// declarations never use, so integers, floats, bools and pointers are passed the same way.
+/// Structs are lowered differently, so a function that takes or returns one is left in the header.
+/// Other platforms are not supported.
+///
+/// Each function is declared on its own line, so we look at every line that ends with `);` and
+/// isn't a typedef or preprocessor directive.
+pub(crate) fn split_c_header(c_header: &str) -> DirectCalls {
+    let mut direct_calls = DirectCalls {
+        swift: "".to_string(),
+        c_header: "".to_string(),
+    };
+
+    for line in c_header.lines() {
+        let trimmed = line.trim();
+        let is_fn_declaration =
+            trimmed.ends_with(");") && !trimmed.starts_with("typedef") && !trimmed.starts_with('#');
+
+        match is_fn_declaration
+            .then(|| swift_declaration(trimmed))
+            .flatten()
+        {
+            Some(declaration) => {
+                direct_calls.swift += &declaration;
+                direct_calls.swift += "\n";
+            }
+            None => {
+                direct_calls.c_header += line;
+                direct_calls.c_header += "\n";
+            }
+        }
+    }
+
+    direct_calls
+}
+
+/// `uint32_t __swift_bridge__$add(uint32_t lhs, uint32_t rhs);` becomes
+/// `@_silgen_name("__swift_bridge__$add") func __swift_bridge__$add(_: UInt32, _: UInt32) -> UInt32`
+fn swift_declaration(c_declaration: &str) -> Option<String> {
+    let c_declaration = c_declaration.strip_suffix(");")?;
+    let (ret_and_name, args) = c_declaration.split_once('(')?;
+    // Function pointer arguments have their own parentheses.
+    if args.contains('(') || args.contains(')') {
+        return None;
+    }
+
+    let (ret, name) = ret_and_name.trim().rsplit_once(' ')?;
+
+    let mut swift_args = vec![];
+    if args.trim() != "void" && !args.trim().is_empty() {
+        for arg in args.split(',') {
+            let (ty, _arg_name) = arg.trim().rsplit_once(' ')?;
+            swift_args.push(format!("_: {}", swift_type(ty)?));
+        }
+    }
+
+    let swift_ret = match ret.trim() {
+        "void" => "".to_string(),
+        ret => format!(" -> {}", swift_type(ret)?),
+    };
+
+    Some(format!(
+        r#"@_silgen_name("{name}")
+func {name}({args}){ret}"#,
+        name = name,
+        args = swift_args.join(", "),
+        ret = swift_ret
+    ))
+}
+
+/// The type that Swift imports a C scalar or pointer type as.
+fn swift_type(c_type: &str) -> Option<String> {
+    let c_type = c_type.trim();
+
+    if let Some(pointee) = c_type.strip_suffix('*') {
+        let pointee = pointee.trim();
+        let (is_const, pointee) = match pointee.strip_prefix("const ") {
+            Some(pointee) => (true, pointee.trim()),
+            None => (false, pointee),
+        };
+        // Swift does not allow an implicitly unwrapped optional as a generic argument.
+        if pointee.ends_with('*') {
+            return None;
+        }
+
+        let pointer = match (pointee, is_const) {
+            ("void", true) => "UnsafeRawPointer".to_string(),
+            ("void", false) => "UnsafeMutableRawPointer".to_string(),
+            (pointee, true) => format!("UnsafePointer<{}>", swift_type(pointee)?),
+            (pointee, false) => format!("UnsafeMutablePointer<{}>", swift_type(pointee)?),
+        };
+        // C pointers without nullability annotations are imported as implicitly unwrapped
+        // optionals, which the generated Swift code relies on.
+        return Some(format!("{}!", pointer));
+    }
+
+    let swift_type = match c_type {
+        "uint8_t" => "UInt8",
+        "uint16_t" => "UInt16",
+        "uint32_t" => "UInt32",
+        "uint64_t" => "UInt64",
+        "uintptr_t" => "UInt",
+        "int8_t" => "Int8",
+        "int16_t" => "Int16",
+        "int32_t" => "Int32",
+        "int64_t" => "Int64",
+        "intptr_t" => "Int",
+        "bool" => "Bool",
+        "float" => "Float",
+        "double" => "Double",
+        _ => return None,
+    };
+    Some(swift_type.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify that a function with `void` arguments and a `void` return value is moved into Swift.
+    #[test]
+    fn void_args_and_return() {
+        let direct_calls = split_c_header("void __swift_bridge__$run(void);\n");
+
+        assert_eq!(
+            direct_calls.swift,
+            "@_silgen_name(\"__swift_bridge__$run\")\nfunc __swift_bridge__$run()\n"
+        );
+        assert_eq!(direct_calls.c_header, "");
+    }
+
+    /// Verify that scalar arguments and return values are converted to their Swift types.
+    #[test]
+    fn scalar_args_and_return() {
+        let direct_calls =
+            split_c_header("uint32_t __swift_bridge__$add(uint32_t lhs, double rhs, bool flag);");
+
+        assert_eq!(
+            direct_calls.swift,
+            "@_silgen_name(\"__swift_bridge__$add\")\nfunc __swift_bridge__$add(_: UInt32, _: Double, _: Bool) -> UInt32\n"
+        );
+    }
+
+    /// Verify that const and non-const `void*` and `T*` are converted to Swift pointers.
+    #[test]
+    fn pointers() {
+        assert_eq!(swift_type("void*").unwrap(), "UnsafeMutableRawPointer!");
+        assert_eq!(swift_type("const void*").unwrap(), "UnsafeRawPointer!");
+        assert_eq!(
+            swift_type("uint8_t*").unwrap(),
+            "UnsafeMutablePointer<UInt8>!"
+        );
+        assert_eq!(
+            swift_type("const uint8_t *").unwrap(),
+            "UnsafePointer<UInt8>!"
+        );
+
+        let direct_calls =
+            split_c_header("void* __swift_bridge__$Foo$new(const uint8_t* bytes, uintptr_t len);");
+        assert_eq!(
+            direct_calls.swift,
+            "@_silgen_name(\"__swift_bridge__$Foo$new\")\nfunc __swift_bridge__$Foo$new(_: UnsafePointer<UInt8>!, _: UInt) -> UnsafeMutableRawPointer!\n"
+        );
+    }
+
+    /// Verify that a function that takes a pointer to a pointer stays in the header.
+    #[test]
+    fn pointer_to_pointer_stays_in_header() {
+        let c_header = "void __swift_bridge__$fill(uint8_t** out);\n";
+
+        let direct_calls = split_c_header(c_header);
+
+        assert_eq!(direct_calls.swift, "");
+        assert_eq!(direct_calls.c_header, c_header);
+    }
+
+    /// Verify that functions that take or return a struct stay in the header.
+    #[test]
+    fn struct_args_stay_in_header() {
+        let c_header = r#"void __swift_bridge__$take(struct __swift_bridge__$SomeStruct arg);
+struct __swift_bridge__$SomeStruct __swift_bridge__$give(void);
+"#;
+
+        let direct_calls = split_c_header(c_header);
+
+        assert_eq!(direct_calls.swift, "");
+        assert_eq!(direct_calls.c_header, c_header);
+    }
+
+    /// Verify that a function that takes a function pointer stays in the header.
+    #[test]
+    fn function_pointer_args_stay_in_header() {
+        let c_header = "void __swift_bridge__$call(void* cb, void (*free)(void*));\n";
+
+        let direct_calls = split_c_header(c_header);
+
+        assert_eq!(direct_calls.swift, "");
+        assert_eq!(direct_calls.c_header, c_header);
+    }
+
+    /// Verify that typedefs and preprocessor lines stay in the header.
+    #[test]
+    fn typedef_and_preprocessor_lines_stay_in_header() {
+        let c_header = r#"#include <stdint.h>
+typedef struct __swift_bridge__$SomeStruct { uint8_t field; } __swift_bridge__$SomeStruct;
+typedef void (*__swift_bridge__$Callback)(void);
+#define SOME_MACRO(x) (x);
+"#;
+
+        let direct_calls = split_c_header(c_header);
+
+        assert_eq!(direct_calls.swift, "");
+        assert_eq!(direct_calls.c_header, c_header);
+        assert!(direct_calls.c_header_is_needed());
+    }
+
+    /// Verify that the header is not needed once only comments, includes and blank lines remain.
+    #[test]
+    fn header_not_needed_if_only_comments_and_includes_remain() {
+        let direct_calls = split_c_header(
+            r#"// File automatically generated by swift-bridge.
+#include <stdint.h>
+
+uint8_t __swift_bridge__$one(void);
+"#,
+        );
+
+        assert!(!direct_calls.c_header_is_needed());
+    }
+}
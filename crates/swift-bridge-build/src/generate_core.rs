@@ -1,44 +1,267 @@
 use crate::generate_core::boxed_fn_support::{
     C_CALLBACK_SUPPORT_NO_ARGS_NO_RETURN, SWIFT_CALLBACK_SUPPORT_NO_ARGS_NO_RETURN,
+    SWIFT_CLOSURE_SUPPORT,
 };
+use crate::generate_core::byte_stream_support::{C_BYTE_STREAM_SUPPORT, SWIFT_BYTE_STREAM_SUPPORT};
+use crate::generate_core::compression_support::SWIFT_COMPRESSION_SUPPORT;
+use crate::generate_core::cursor_support::{C_CURSOR_SUPPORT, SWIFT_CURSOR_SUPPORT};
+use crate::generate_core::formatting_support::SWIFT_FORMATTING_SUPPORT;
+use crate::generate_core::hash_set_support::{c_hash_set_support, swift_hash_set_support};
+use crate::generate_core::image_buffer_support::{
+    C_IMAGE_BUFFER_SUPPORT, SWIFT_IMAGE_BUFFER_SUPPORT,
+};
+use crate::generate_core::keychain_support::SWIFT_KEYCHAIN_SUPPORT;
+use crate::generate_core::measurement_support::{C_MEASUREMENT_SUPPORT, SWIFT_MEASUREMENT_SUPPORT};
+use crate::generate_core::nested_vec_support::{c_nested_vec_support, swift_nested_vec_support};
 use crate::generate_core::option_support::{
     swift_option_primitive_support, C_OPTION_PRIMITIVE_SUPPORT,
 };
+use crate::generate_core::pixel_buffer_support::{
+    C_PIXEL_BUFFER_SUPPORT, SWIFT_PIXEL_BUFFER_SUPPORT,
+};
+use crate::generate_core::range_support::{swift_range_support, C_RANGE_SUPPORT};
+use crate::generate_core::reachability_support::{
+    C_REACHABILITY_SUPPORT, SWIFT_REACHABILITY_SUPPORT,
+};
 use crate::generate_core::result_support::{C_RESULT_SUPPORT, SWIFT_RUST_RESULT};
+use crate::generate_core::serde_json_support::SWIFT_SERDE_JSON_SUPPORT;
+use crate::generate_core::tensor_support::{C_TENSOR_SUPPORT, SWIFT_TENSOR_SUPPORT};
+use crate::generate_core::url_session_support::{C_URL_SESSION_SUPPORT, SWIFT_URL_SESSION_SUPPORT};
+use crate::generate_core::user_defaults_support::SWIFT_USER_DEFAULTS_SUPPORT;
+use crate::generate_core::uuid_support::{C_UUID_SUPPORT, SWIFT_UUID_SUPPORT};
+use crate::generate_core::vec_sort_support::{c_vec_sort_support, swift_vec_sort_support};
+use crate::generate_core::watch_support::SWIFT_WATCH_SUPPORT;
+use crate::DebugChecks;
 use std::path::Path;
 
-const RUST_STRING_SWIFT: &'static str = include_str!("./generate_core/rust_string.swift");
-const RUST_STRING_C: &'static str = include_str!("./generate_core/rust_string.c.h");
+const RUST_STRING_SWIFT: &str = include_str!("./generate_core/rust_string.swift");
+const RUST_STRING_C: &str = include_str!("./generate_core/rust_string.c.h");
 
-const STRING_SWIFT: &'static str = include_str!("./generate_core/string.swift");
-const RUST_VEC_SWIFT: &'static str = include_str!("./generate_core/rust_vec.swift");
+const STRING_SWIFT: &str = include_str!("./generate_core/string.swift");
+const DURATION_SWIFT: &str = include_str!("./generate_core/duration.swift");
+const SYSTEM_TIME_SWIFT: &str = include_str!("./generate_core/system_time.swift");
+const NET_SWIFT: &str = include_str!("./generate_core/net.swift");
+const MODULE_TABLE_SWIFT: &str = include_str!("./generate_core/module_table.swift");
+const REFLECTION_SWIFT: &str = include_str!("./generate_core/reflection.swift");
+const RUST_VEC_SWIFT: &str = include_str!("./generate_core/rust_vec.swift");
+const RUST_BTREE_MAP_SWIFT: &str = include_str!("./generate_core/rust_btree_map.swift");
+const IDENTITY_MAP_SWIFT: &str = include_str!("./generate_core/identity_map.swift");
+const BRIDGE_ERROR_SWIFT: &str = include_str!("./generate_core/bridge_error.swift");
 
 mod boxed_fn_support;
+mod byte_stream_support;
+mod compression_support;
+mod cursor_support;
+mod formatting_support;
+mod hash_set_support;
+mod image_buffer_support;
+mod keychain_support;
+mod measurement_support;
+mod nested_vec_support;
 mod option_support;
+mod pixel_buffer_support;
+mod range_support;
+mod reachability_support;
 mod result_support;
+mod serde_json_support;
+mod tensor_support;
+mod url_session_support;
+mod user_defaults_support;
+mod uuid_support;
+mod vec_sort_support;
+mod watch_support;
 
-pub(super) fn write_core_swift_and_c(out_dir: &Path) {
+/// Optional parts of the core Swift and C code that are only generated when requested.
+#[derive(Default)]
+pub(super) struct CoreSupport {
+    /// See `GeneratedCode::with_keychain_support`.
+    pub keychain: bool,
+    /// See `GeneratedCode::with_user_defaults_support`.
+    pub user_defaults: bool,
+    /// See `GeneratedCode::with_url_session_support`.
+    pub url_session: bool,
+    /// See `GeneratedCode::with_reachability_support`.
+    pub reachability: bool,
+    /// See `GeneratedCode::with_measurement_support`.
+    pub measurement: bool,
+    /// See `GeneratedCode::with_formatting_support`.
+    pub formatting: bool,
+    /// See `GeneratedCode::with_image_buffer_support`.
+    pub image_buffer: bool,
+    /// See `GeneratedCode::with_pixel_buffer_support`.
+    pub pixel_buffer: bool,
+    /// See `GeneratedCode::with_serde_json_support`.
+    pub serde_json: bool,
+    /// See `GeneratedCode::with_tensor_support`.
+    pub tensor: bool,
+    /// See `GeneratedCode::with_uuid_support`.
+    pub uuid: bool,
+    /// See `GeneratedCode::with_byte_stream_support`.
+    pub byte_stream: bool,
+    /// See `GeneratedCode::with_compression_support`.
+    pub compression: bool,
+    /// See `GeneratedCode::with_watch_support`.
+    pub watch: bool,
+    /// See `GeneratedCode::with_cursor_support`.
+    pub cursor: bool,
+    /// See `GeneratedCode::with_debug_checks`.
+    pub debug_checks: DebugChecks,
+}
+
+pub(super) fn write_core_swift_and_c(out_dir: &Path, core_support: &CoreSupport) {
     let core_swift_out = out_dir.join("SwiftBridgeCore.swift");
     let mut swift = core_swift();
     swift += "\n";
-    swift += &RUST_STRING_SWIFT;
+    swift += RUST_STRING_SWIFT;
+    swift += "\n";
+    swift += SWIFT_CALLBACK_SUPPORT_NO_ARGS_NO_RETURN;
     swift += "\n";
-    swift += &SWIFT_CALLBACK_SUPPORT_NO_ARGS_NO_RETURN;
+    swift += SWIFT_CLOSURE_SUPPORT;
     swift += "\n";
-    swift += &SWIFT_RUST_RESULT;
+    swift += SWIFT_RUST_RESULT;
     swift += "\n";
     swift += &swift_option_primitive_support();
+    swift += "\n";
+    swift += DURATION_SWIFT;
+    swift += "\n";
+    swift += SYSTEM_TIME_SWIFT;
+    swift += "\n";
+    swift += NET_SWIFT;
+    swift += "\n";
+    swift += &swift_range_support();
+    swift += "\n";
+    swift += MODULE_TABLE_SWIFT;
+    swift += "\n";
+    swift += REFLECTION_SWIFT;
+    swift += "\n";
+    swift += &swift_hash_set_support();
+    swift += "\n";
+    swift += &swift_nested_vec_support();
+    swift += "\n";
+    swift += &swift_vec_sort_support();
+    swift += "\n";
+    swift += RUST_BTREE_MAP_SWIFT;
+    swift += "\n";
+    swift += IDENTITY_MAP_SWIFT;
+    swift += "\n";
+    swift += BRIDGE_ERROR_SWIFT;
+    swift += "\n";
+    swift += &swift_debug_check_support(core_support.debug_checks);
+
+    if core_support.keychain {
+        swift += "\n";
+        swift += SWIFT_KEYCHAIN_SUPPORT;
+    }
+    if core_support.user_defaults {
+        swift += "\n";
+        swift += SWIFT_USER_DEFAULTS_SUPPORT;
+    }
+    if core_support.url_session {
+        swift += "\n";
+        swift += SWIFT_URL_SESSION_SUPPORT;
+    }
+    if core_support.reachability {
+        swift += "\n";
+        swift += SWIFT_REACHABILITY_SUPPORT;
+    }
+    if core_support.measurement {
+        swift += "\n";
+        swift += SWIFT_MEASUREMENT_SUPPORT;
+    }
+    if core_support.formatting {
+        swift += "\n";
+        swift += SWIFT_FORMATTING_SUPPORT;
+    }
+    if core_support.image_buffer {
+        swift += "\n";
+        swift += SWIFT_IMAGE_BUFFER_SUPPORT;
+    }
+    if core_support.pixel_buffer {
+        swift += "\n";
+        swift += SWIFT_PIXEL_BUFFER_SUPPORT;
+    }
+    if core_support.serde_json {
+        swift += "\n";
+        swift += SWIFT_SERDE_JSON_SUPPORT;
+    }
+    if core_support.tensor {
+        swift += "\n";
+        swift += SWIFT_TENSOR_SUPPORT;
+    }
+    if core_support.uuid {
+        swift += "\n";
+        swift += SWIFT_UUID_SUPPORT;
+    }
+    if core_support.byte_stream {
+        swift += "\n";
+        swift += SWIFT_BYTE_STREAM_SUPPORT;
+    }
+    if core_support.compression {
+        swift += "\n";
+        swift += SWIFT_COMPRESSION_SUPPORT;
+    }
+    if core_support.watch {
+        swift += "\n";
+        swift += SWIFT_WATCH_SUPPORT;
+    }
+    if core_support.cursor {
+        swift += "\n";
+        swift += SWIFT_CURSOR_SUPPORT;
+    }
 
     std::fs::write(core_swift_out, swift).unwrap();
 
     let core_c_header_out = out_dir.join("SwiftBridgeCore.h");
     let mut c_header = core_c_header().to_string();
     c_header += "\n";
-    c_header += &RUST_STRING_C;
+    c_header += RUST_STRING_C;
     c_header += "\n";
-    c_header += &C_CALLBACK_SUPPORT_NO_ARGS_NO_RETURN;
+    c_header += C_CALLBACK_SUPPORT_NO_ARGS_NO_RETURN;
     c_header += "\n";
-    c_header += &C_RESULT_SUPPORT;
+    c_header += C_RESULT_SUPPORT;
+    c_header += "\n";
+    c_header += &c_hash_set_support();
+    c_header += "\n";
+    c_header += &c_nested_vec_support();
+    c_header += "\n";
+    c_header += &c_vec_sort_support();
+
+    if core_support.url_session {
+        c_header += "\n";
+        c_header += C_URL_SESSION_SUPPORT;
+    }
+    if core_support.reachability {
+        c_header += "\n";
+        c_header += C_REACHABILITY_SUPPORT;
+    }
+    if core_support.measurement {
+        c_header += "\n";
+        c_header += C_MEASUREMENT_SUPPORT;
+    }
+    if core_support.image_buffer {
+        c_header += "\n";
+        c_header += C_IMAGE_BUFFER_SUPPORT;
+    }
+    if core_support.pixel_buffer {
+        c_header += "\n";
+        c_header += C_PIXEL_BUFFER_SUPPORT;
+    }
+    if core_support.tensor {
+        c_header += "\n";
+        c_header += C_TENSOR_SUPPORT;
+    }
+    if core_support.uuid {
+        c_header += "\n";
+        c_header += C_UUID_SUPPORT;
+    }
+    if core_support.byte_stream {
+        c_header += "\n";
+        c_header += C_BYTE_STREAM_SUPPORT;
+    }
+    if core_support.cursor {
+        c_header += "\n";
+        c_header += C_CURSOR_SUPPORT;
+    }
 
     std::fs::write(core_c_header_out, c_header).unwrap();
 }
@@ -70,8 +293,8 @@ fn core_swift() -> String {
         core_swift += &conform_to_vectorizable(swift_ty, rust_ty);
     }
 
-    core_swift += &generic_freer();
-    core_swift += &generic_copy_type_ffi_repr();
+    core_swift += generic_freer();
+    core_swift += generic_copy_type_ffi_repr();
 
     core_swift
 }
@@ -79,13 +302,38 @@ fn core_swift() -> String {
 fn core_c_header() -> String {
     let mut header = r#"#include <stdint.h>
 #include <stdbool.h> 
+#define __SWIFT_BRIDGE_STRINGIFY_(x) #x
+#define __SWIFT_BRIDGE_STRINGIFY(x) __SWIFT_BRIDGE_STRINGIFY_(x)
+#define __SWIFT_BRIDGE_SYMBOL(name) __asm__(__SWIFT_BRIDGE_STRINGIFY(__USER_LABEL_PREFIX__) name)
 typedef struct RustStr { uint8_t* const start; uintptr_t len; } RustStr;
+// `start` can be NULL or dangling when `len` is 0. A NULL `start` with a non-zero `len` is an error.
 typedef struct __private__FfiSlice { void* const start; uintptr_t len; } __private__FfiSlice;
+typedef struct __private__OptionFfiSlice { __private__FfiSlice val; bool is_some; } __private__OptionFfiSlice;
+typedef struct __private__FfiBTreeMap { void* keys; void* values; } __private__FfiBTreeMap;
+typedef struct __private__Duration { uint64_t secs; uint32_t nanos; } __private__Duration;
+typedef struct __private__OptionDuration { __private__Duration val; bool is_some; } __private__OptionDuration;
+typedef struct __private__SystemTime { int64_t secs; uint32_t nanos; } __private__SystemTime;
+typedef struct __private__OptionSystemTime { __private__SystemTime val; bool is_some; } __private__OptionSystemTime;
+typedef struct __private__IpAddr { uint8_t octets[16]; bool is_v6; } __private__IpAddr;
+typedef struct __private__OptionIpAddr { __private__IpAddr val; bool is_some; } __private__OptionIpAddr;
+typedef struct __private__SocketAddr { __private__IpAddr ip; uint16_t port; uint32_t flowinfo; uint32_t scope_id; } __private__SocketAddr;
+typedef struct __private__OptionSocketAddr { __private__SocketAddr val; bool is_some; } __private__OptionSocketAddr;
+typedef struct __private__CowStr { uint8_t* start; uintptr_t len; uintptr_t capacity; bool is_owned; } __private__CowStr;
+typedef struct __private__ModuleTableEntry { const char* name; const void* symbol; } __private__ModuleTableEntry;
+typedef struct __private__ModuleTable { const __private__ModuleTableEntry* entries; uintptr_t len; } __private__ModuleTable;
+typedef struct __private__ReflectedType { const char* name; uint64_t id; uint8_t kind; } __private__ReflectedType;
+typedef struct __private__ReflectedFunction { const char* name; const char* type_name; const char* link_name; bool is_rust; uint64_t calls; } __private__ReflectedFunction;
+typedef struct __private__ModuleReflection { const char* name; const __private__ReflectedType* types; uintptr_t types_len; const __private__ReflectedFunction* functions; uintptr_t functions_len; } __private__ModuleReflection;
+uint64_t __swift_bridge__$reflected_function$call_count(const __private__ReflectedFunction* function);
 void* __swift_bridge__null_pointer(void);
+void __swift_bridge__$pointer_slice$free(struct __private__FfiSlice slice);
+void __swift_bridge__$bridge_error$invalid_enum_discriminant(struct RustStr enum_name, int64_t discriminant);
+void __swift_bridge__$bridge_error$null_slice(uintptr_t len);
 
 "#
     .to_string();
-    header += &C_OPTION_PRIMITIVE_SUPPORT;
+    header += C_OPTION_PRIMITIVE_SUPPORT;
+    header += C_RANGE_SUPPORT;
 
     for (rust_ty, c_ty) in vec![
         ("u8", "uint8_t"),
@@ -212,3 +460,25 @@ fn generic_copy_type_ffi_repr() -> &'static str {
 protocol SwiftBridgeGenericCopyTypeFfiRepr {}
 "#
 }
+
+/// Runs the checks that the generated Swift code only needs while debugging, such as
+/// `assertDiscriminantsMatchRust()`.
+///
+/// Every debug-only check in the generated code goes through this function, so its body decides
+/// whether or not they make it into the compiled binary.
+fn swift_debug_check_support(debug_checks: DebugChecks) -> String {
+    let body = match debug_checks {
+        DebugChecks::Always => "    check()\n",
+        DebugChecks::DebugBuildsOnly => "#if DEBUG\n    check()\n#endif\n",
+        DebugChecks::Never => "",
+    };
+
+    format!(
+        r#"
+@inline(__always)
+func __swift_bridge__debug_check(_ check: () -> ()) {{
+{body}}}
+"#,
+        body = body
+    )
+}
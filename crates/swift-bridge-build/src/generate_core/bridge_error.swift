@@ -0,0 +1,15 @@
+/// Let the Rust `swift_bridge::bridge_error` handler know that Swift received an enum that it
+/// could not convert, and then crash.
+func __swift_bridge__invalidEnumDiscriminant(_ enumName: String, _ discriminant: Int64) -> Never {
+    enumName.toRustStr({ enumNameAsRustStr in
+        __swift_bridge__$bridge_error$invalid_enum_discriminant(enumNameAsRustStr, discriminant)
+    })
+    fatalError("Received \(enumName) with discriminant \(discriminant), which is not one of its variants")
+}
+
+/// Let the Rust `swift_bridge::bridge_error` handler know that Swift received a slice with a null
+/// start and a non-zero length, and then crash.
+func __swift_bridge__nullSlice(_ len: UInt) -> Never {
+    __swift_bridge__$bridge_error$null_slice(len)
+    fatalError("Received a slice with a null start and a length of \(len)")
+}
@@ -0,0 +1,13 @@
+//! Support for the `swift_bridge::byte_stream` module, which is enabled by the swift-bridge
+//! crate's `byte-stream` feature.
+//!
+//! The corresponding Rust code can be found in src/byte_stream.rs
+
+pub const SWIFT_BYTE_STREAM_SUPPORT: &str = include_str!("./byte_stream_support.swift");
+
+pub const C_BYTE_STREAM_SUPPORT: &str = r#"
+typedef struct __private__ByteChunk { void* chunk; const uint8_t* start; uintptr_t len; } __private__ByteChunk;
+struct __private__ByteChunk __swift_bridge__$ByteStream$next(void* stream);
+void __swift_bridge__$ByteStream$_free(void* stream);
+void __swift_bridge__$ByteChunk$_free(void* chunk);
+"#;
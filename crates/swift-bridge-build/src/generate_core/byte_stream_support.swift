@@ -0,0 +1,62 @@
+import Foundation
+
+/// A Rust `swift_bridge::byte_stream::ByteStream`.
+///
+/// Chunks can be pulled one at a time with `nextChunk()` or read with `for await`. Every chunk is
+/// a `Data` that points at the bytes that Rust allocated instead of copying them.
+///
+/// A `RustByteStream` must not be read from more than one thread at a time.
+public class RustByteStream {
+    var ptr: UnsafeMutableRawPointer
+    var isOwned: Bool = true
+
+    public init(ptr: UnsafeMutableRawPointer) {
+        self.ptr = ptr
+    }
+
+    deinit {
+        if isOwned {
+            __swift_bridge__$ByteStream$_free(ptr)
+        }
+    }
+
+    /// Returns the next chunk, or `nil` once the stream has ended.
+    ///
+    /// Blocks until Rust produces the chunk.
+    public func nextChunk() -> Data? {
+        let chunk = __swift_bridge__$ByteStream$next(ptr)
+        guard let owner = chunk.chunk else {
+            return nil
+        }
+
+        return Data(
+            bytesNoCopy: UnsafeMutableRawPointer(mutating: chunk.start),
+            count: Int(chunk.len),
+            deallocator: .custom { _, _ in
+                __swift_bridge__$ByteChunk$_free(owner)
+            }
+        )
+    }
+}
+
+extension RustByteStream: AsyncSequence {
+    public typealias Element = Data
+
+    public func makeAsyncIterator() -> RustByteStreamAsyncIterator {
+        RustByteStreamAsyncIterator(stream: self)
+    }
+}
+
+/// Reads a `RustByteStream` without blocking the task that is awaiting the next chunk.
+public struct RustByteStreamAsyncIterator: AsyncIteratorProtocol {
+    let stream: RustByteStream
+
+    public mutating func next() async -> Data? {
+        let stream = self.stream
+        return await withCheckedContinuation { continuation in
+            DispatchQueue.global().async {
+                continuation.resume(returning: stream.nextChunk())
+            }
+        }
+    }
+}
@@ -0,0 +1,6 @@
+//! Support for the `#[swift_bridge(compress = "...")]` function attribute, which needs the
+//! swift-bridge crate's `compression` feature.
+//!
+//! The corresponding Rust code can be found in src/compression_support.rs
+
+pub const SWIFT_COMPRESSION_SUPPORT: &str = include_str!("./compression_support.swift");
@@ -0,0 +1,30 @@
+import Compression
+import Foundation
+
+/// Decompress the bytes that a `#[swift_bridge(compress = "lz4")]` function returned.
+///
+/// Rust prefixes the LZ4 block with the decompressed length as a little-endian `UInt32`.
+public func __swift_bridge__decompressLz4(_ compressed: RustVec<UInt8>) -> Data {
+    let bytes = UnsafeBufferPointer(start: compressed.as_ptr(), count: compressed.len())
+    precondition(bytes.count >= 4, "The compressed bytes are missing their length prefix.")
+
+    let len = Int(bytes[0]) | Int(bytes[1]) << 8 | Int(bytes[2]) << 16 | Int(bytes[3]) << 24
+    var decompressed = Data(count: len)
+    if len == 0 {
+        return decompressed
+    }
+
+    let written = decompressed.withUnsafeMutableBytes { destination in
+        compression_decode_buffer(
+            destination.bindMemory(to: UInt8.self).baseAddress!,
+            len,
+            bytes.baseAddress! + 4,
+            bytes.count - 4,
+            nil,
+            COMPRESSION_LZ4_RAW
+        )
+    }
+    precondition(written == len, "Failed to decompress the LZ4 bytes that Rust returned.")
+
+    return decompressed
+}
@@ -0,0 +1,11 @@
+//! Support for the `swift_bridge::cursor` module, which is enabled by the swift-bridge crate's
+//! `cursor` feature.
+//!
+//! The corresponding Rust code can be found in src/cursor.rs
+
+pub const SWIFT_CURSOR_SUPPORT: &str = include_str!("./cursor_support.swift");
+
+pub const C_CURSOR_SUPPORT: &str = r#"
+typedef struct __private__CursorBatch { void* batch; const void* start; uintptr_t len; } __private__CursorBatch;
+void __swift_bridge__$CursorBatch$_free(void* batch);
+"#;
@@ -0,0 +1,78 @@
+/// A Rust `swift_bridge::cursor::Cursor<T>`, where `T` is a shared struct.
+///
+/// Rows are read lazily with `for row in cursor` or `nextRow()`, while Rust hands them over one
+/// batch at a time. Dropping the `RustCursor` drops the Rust `Cursor`, which stops fetching rows.
+///
+/// A `RustCursor` must not be read from more than one thread at a time.
+public class RustCursor<Row> {
+    var ptr: UnsafeMutableRawPointer
+    var isOwned: Bool = true
+    let nextBatchFn: (UnsafeMutableRawPointer) -> [Row]
+    let freeFn: (UnsafeMutableRawPointer) -> Void
+    var batch: [Row] = []
+    var batchIndex: Int = 0
+    var hasEnded: Bool = false
+
+    public init(
+        ptr: UnsafeMutableRawPointer,
+        nextBatch: @escaping (UnsafeMutableRawPointer) -> [Row],
+        free: @escaping (UnsafeMutableRawPointer) -> Void
+    ) {
+        self.ptr = ptr
+        self.nextBatchFn = nextBatch
+        self.freeFn = free
+    }
+
+    deinit {
+        if isOwned {
+            freeFn(ptr)
+        }
+    }
+
+    /// Returns the next row, or `nil` once the cursor has ended.
+    ///
+    /// Fetches the next batch from Rust once every row of the current batch has been read.
+    public func nextRow() -> Row? {
+        if batchIndex == batch.count {
+            if hasEnded {
+                return nil
+            }
+
+            batch = nextBatchFn(ptr)
+            batchIndex = 0
+            if batch.isEmpty {
+                hasEnded = true
+                return nil
+            }
+        }
+
+        let row = batch[batchIndex]
+        batchIndex += 1
+        return row
+    }
+
+    /// Converts a batch that Rust handed over into Swift rows, then frees the batch.
+    public static func readBatch<FfiRow>(
+        _ batch: __private__CursorBatch,
+        _ intoSwiftRepr: (FfiRow) -> Row
+    ) -> [Row] {
+        guard let owner = batch.batch else {
+            return []
+        }
+        defer {
+            __swift_bridge__$CursorBatch$_free(owner)
+        }
+
+        let rows = UnsafeBufferPointer(
+            start: batch.start!.assumingMemoryBound(to: FfiRow.self),
+            count: Int(batch.len)
+        )
+        return rows.map(intoSwiftRepr)
+    }
+}
+
+extension RustCursor: Sequence, IteratorProtocol {
+    public func next() -> Row? {
+        nextRow()
+    }
+}
@@ -0,0 +1,54 @@
+extension __private__Duration {
+    /// A Rust `Duration` cannot be negative, so negative and non-finite intervals are rejected.
+    init(_ timeInterval: TimeInterval) {
+        precondition(
+            timeInterval >= 0 && timeInterval.isFinite,
+            "A TimeInterval must be finite and non-negative in order to be converted into a Rust Duration"
+        )
+
+        let secs = timeInterval.rounded(.down)
+        // The nanoseconds can round up to a full second, which Rust carries over into the seconds.
+        let nanos = ((timeInterval - secs) * 1_000_000_000).rounded()
+
+        self.init(secs: UInt64(secs), nanos: UInt32(nanos))
+    }
+
+    func toTimeInterval() -> TimeInterval {
+        TimeInterval(secs) + TimeInterval(nanos) / 1_000_000_000
+    }
+}
+
+extension __private__OptionDuration {
+    init(_ val: Optional<TimeInterval>) {
+        if let val = val {
+            self.init(val: __private__Duration(val), is_some: true)
+        } else {
+            self.init(val: __private__Duration(secs: 0, nanos: 0), is_some: false)
+        }
+    }
+
+    func intoSwiftRepr() -> Optional<TimeInterval> {
+        if is_some {
+            return val.toTimeInterval()
+        } else {
+            return nil
+        }
+    }
+}
+
+#if compiler(>=5.7)
+@available(macOS 13.0, iOS 16.0, tvOS 16.0, watchOS 9.0, *)
+extension Duration {
+    /// Create a `Duration` from a `TimeInterval` that was received from Rust.
+    public init(timeInterval: TimeInterval) {
+        let secs = timeInterval.rounded(.down)
+        self = .seconds(Int64(secs)) + .nanoseconds(Int64(((timeInterval - secs) * 1_000_000_000).rounded()))
+    }
+
+    /// The `TimeInterval` to pass to a Rust function that takes a `Duration`.
+    public var timeInterval: TimeInterval {
+        let (seconds, attoseconds) = components
+        return TimeInterval(seconds) + TimeInterval(attoseconds) / 1_000_000_000_000_000_000
+    }
+}
+#endif
@@ -0,0 +1,6 @@
+//! Support for the `swift_bridge::formatting` module, which is enabled by the swift-bridge crate's
+//! `formatting` feature.
+//!
+//! The corresponding Rust code can be found in src/formatting.rs
+
+pub const SWIFT_FORMATTING_SUPPORT: &str = include_str!("./formatting_support.swift");
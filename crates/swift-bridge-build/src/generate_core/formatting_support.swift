@@ -0,0 +1,61 @@
+@_cdecl("__swift_bridge__$formatting$format_number")
+func __swift_bridge__formatting_format_number (_ value: Double, _ style: UInt8, _ locale: RustStr) -> UnsafeMutableRawPointer {
+    let formatter = NumberFormatter()
+    formatter.locale = __swift_bridge__formatting_locale(locale)
+    switch style {
+    case 0: formatter.numberStyle = .decimal
+    case 1: formatter.numberStyle = .percent
+    case 2: formatter.numberStyle = .scientific
+    case 3: formatter.numberStyle = .spellOut
+    case 4: formatter.numberStyle = .ordinal
+    default: fatalError("Unknown number style \(style). Regenerate the Swift code.")
+    }
+
+    return __swift_bridge__formatting_into_rust_string(formatter.string(from: NSNumber(value: value)) ?? "\(value)")
+}
+
+@_cdecl("__swift_bridge__$formatting$format_currency")
+func __swift_bridge__formatting_format_currency (_ value: Double, _ currencyCode: RustStr, _ locale: RustStr) -> UnsafeMutableRawPointer {
+    let formatter = NumberFormatter()
+    formatter.locale = __swift_bridge__formatting_locale(locale)
+    formatter.numberStyle = .currency
+    formatter.currencyCode = currencyCode.toString()
+
+    return __swift_bridge__formatting_into_rust_string(formatter.string(from: NSNumber(value: value)) ?? "\(value)")
+}
+
+@_cdecl("__swift_bridge__$formatting$format_date")
+func __swift_bridge__formatting_format_date (_ time: __private__SystemTime, _ dateStyle: UInt8, _ timeStyle: UInt8, _ locale: RustStr) -> UnsafeMutableRawPointer {
+    let formatter = DateFormatter()
+    formatter.locale = __swift_bridge__formatting_locale(locale)
+    formatter.dateStyle = __swift_bridge__formatting_date_style(dateStyle)
+    formatter.timeStyle = __swift_bridge__formatting_date_style(timeStyle)
+
+    return __swift_bridge__formatting_into_rust_string(formatter.string(from: time.toDate()))
+}
+
+/// Rust sends an empty identifier for the current locale.
+func __swift_bridge__formatting_locale (_ identifier: RustStr) -> Locale {
+    if identifier.len == 0 {
+        return Locale.current
+    } else {
+        return Locale(identifier: identifier.toString())
+    }
+}
+
+func __swift_bridge__formatting_date_style (_ style: UInt8) -> DateFormatter.Style {
+    switch style {
+    case 0: return .none
+    case 1: return .short
+    case 2: return .medium
+    case 3: return .long
+    case 4: return .full
+    default: fatalError("Unknown date style \(style). Regenerate the Swift code.")
+    }
+}
+
+func __swift_bridge__formatting_into_rust_string (_ string: String) -> UnsafeMutableRawPointer {
+    let rustString = RustString(string)
+    rustString.isOwned = false
+    return rustString.ptr
+}
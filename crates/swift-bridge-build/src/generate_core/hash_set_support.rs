@@ -0,0 +1,103 @@
+//! Support for `HashSet<T>` where `T` is a primitive or a `String`.
+//!
+//! The corresponding Rust code can be found in src/std_bridge/rust_hash_set.rs
+
+const RUST_HASH_SET_SWIFT: &str = include_str!("./rust_hash_set.swift");
+
+/// The (Swift type, Rust type, C type) of every primitive that can be stored in a `HashSet<T>`.
+const HASH_SET_PRIMITIVES: [(&str, &str, &str); 11] = [
+    ("UInt8", "u8", "uint8_t"),
+    ("UInt16", "u16", "uint16_t"),
+    ("UInt32", "u32", "uint32_t"),
+    ("UInt64", "u64", "uint64_t"),
+    ("UInt", "usize", "uintptr_t"),
+    //
+    ("Int8", "i8", "int8_t"),
+    ("Int16", "i16", "int16_t"),
+    ("Int32", "i32", "int32_t"),
+    ("Int64", "i64", "int64_t"),
+    ("Int", "isize", "intptr_t"),
+    //
+    ("Bool", "bool", "bool"),
+];
+
+pub(super) fn swift_hash_set_support() -> String {
+    let mut swift = RUST_HASH_SET_SWIFT.to_string();
+
+    for (swift_ty, rust_ty, _) in HASH_SET_PRIMITIVES {
+        swift += &conform_to_rust_hash_set_element(swift_ty, rust_ty);
+    }
+
+    swift
+}
+
+pub(super) fn c_hash_set_support() -> String {
+    let mut header = "".to_string();
+
+    for (_, rust_ty, c_ty) in HASH_SET_PRIMITIVES {
+        header += &hash_set_of_primitive_headers(rust_ty, c_ty);
+    }
+
+    header += r#"
+void* __swift_bridge__$HashSet_String$new(void);
+void __swift_bridge__$HashSet_String$_free(void* const set);
+uintptr_t __swift_bridge__$HashSet_String$len(void* const set);
+bool __swift_bridge__$HashSet_String$insert(void* const set, void* val);
+bool __swift_bridge__$HashSet_String$remove(void* const set, void* val);
+bool __swift_bridge__$HashSet_String$contains(void* const set, void* val);
+void* __swift_bridge__$HashSet_String$to_vec(void* const set);
+"#;
+
+    header
+}
+
+/// Headers for HashSet<T> where T is a primitive such as u8, i32, bool
+fn hash_set_of_primitive_headers(rust_ty: &str, c_ty: &str) -> String {
+    format!(
+        r#"
+void* __swift_bridge__$HashSet_{rust_ty}$new(void);
+void __swift_bridge__$HashSet_{rust_ty}$_free(void* const set);
+uintptr_t __swift_bridge__$HashSet_{rust_ty}$len(void* const set);
+bool __swift_bridge__$HashSet_{rust_ty}$insert(void* const set, {c_ty} val);
+bool __swift_bridge__$HashSet_{rust_ty}$remove(void* const set, {c_ty} val);
+bool __swift_bridge__$HashSet_{rust_ty}$contains(void* const set, {c_ty} val);
+void* __swift_bridge__$HashSet_{rust_ty}$to_vec(void* const set);
+"#
+    )
+}
+
+fn conform_to_rust_hash_set_element(swift_ty: &str, rust_ty: &str) -> String {
+    format!(
+        r#"
+extension {swift_ty}: RustHashSetElement {{
+    public static func hashSetOfSelfNew() -> UnsafeMutableRawPointer {{
+        __swift_bridge__$HashSet_{rust_ty}$new()
+    }}
+
+    public static func hashSetOfSelfFree(hashSetPtr: UnsafeMutableRawPointer) {{
+        __swift_bridge__$HashSet_{rust_ty}$_free(hashSetPtr)
+    }}
+
+    public static func hashSetOfSelfLen(hashSetPtr: UnsafeMutableRawPointer) -> UInt {{
+        __swift_bridge__$HashSet_{rust_ty}$len(hashSetPtr)
+    }}
+
+    public static func hashSetOfSelfInsert(hashSetPtr: UnsafeMutableRawPointer, value: Self) -> Bool {{
+        __swift_bridge__$HashSet_{rust_ty}$insert(hashSetPtr, value)
+    }}
+
+    public static func hashSetOfSelfRemove(hashSetPtr: UnsafeMutableRawPointer, value: Self) -> Bool {{
+        __swift_bridge__$HashSet_{rust_ty}$remove(hashSetPtr, value)
+    }}
+
+    public static func hashSetOfSelfContains(hashSetPtr: UnsafeMutableRawPointer, value: Self) -> Bool {{
+        __swift_bridge__$HashSet_{rust_ty}$contains(hashSetPtr, value)
+    }}
+
+    public static func hashSetOfSelfToVec(hashSetPtr: UnsafeMutableRawPointer) -> UnsafeMutableRawPointer {{
+        __swift_bridge__$HashSet_{rust_ty}$to_vec(hashSetPtr)
+    }}
+}}
+"#
+    )
+}
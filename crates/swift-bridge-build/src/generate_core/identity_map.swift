@@ -0,0 +1,59 @@
+/// Maps the identity of a Rust object to the Swift class instance that wraps it.
+///
+/// Used by opaque Rust types that are annotated with `#[swift_bridge(identity_map)]`, so that the
+/// same Rust object is always seen by Swift as the same class instance.
+///
+/// Only weak references to the wrappers are held, so a wrapper is still deinitialized once Swift
+/// no longer uses it.
+public class __private__IdentityMap<T: AnyObject> {
+    private var wrappers: [UInt: __private__WeakRef<T>] = [:]
+    private let lock = NSLock()
+
+    public init() {}
+
+    /// Get the live wrapper for the Rust object with the given identity, or track the wrapper
+    /// returned by `makeWrapper` if there isn't one.
+    ///
+    /// `isNew` is false if an existing wrapper was returned.
+    public func wrapper(for identity: UInt, existingIsValid: (T) -> Bool, makeWrapper: () -> T) -> (wrapper: T, isNew: Bool) {
+        lock.lock()
+        defer { lock.unlock() }
+
+        if let existing = wrappers[identity]?.value, existingIsValid(existing) {
+            return (existing, false)
+        }
+
+        let wrapper = makeWrapper()
+        wrappers[identity] = __private__WeakRef(wrapper)
+        return (wrapper, true)
+    }
+
+    /// Track a wrapper that was created by a Swift initializer, unless the Rust object already
+    /// has a live wrapper.
+    public func insert(_ identity: UInt, _ wrapper: T) {
+        lock.lock()
+        defer { lock.unlock() }
+
+        if wrappers[identity]?.value == nil {
+            wrappers[identity] = __private__WeakRef(wrapper)
+        }
+    }
+
+    /// Stop tracking the identity if its wrapper has been deinitialized.
+    public func removeReleased(_ identity: UInt) {
+        lock.lock()
+        defer { lock.unlock() }
+
+        if let entry = wrappers[identity], entry.value == nil {
+            wrappers.removeValue(forKey: identity)
+        }
+    }
+}
+
+public class __private__WeakRef<T: AnyObject> {
+    public weak var value: T?
+
+    public init(_ value: T) {
+        self.value = value
+    }
+}
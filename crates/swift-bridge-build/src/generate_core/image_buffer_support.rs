@@ -0,0 +1,13 @@
+//! Support for the `swift_bridge::image_buffer` module, which is enabled by the swift-bridge
+//! crate's `image-buffer` feature.
+//!
+//! The corresponding Rust code can be found in src/image_buffer.rs
+
+pub const SWIFT_IMAGE_BUFFER_SUPPORT: &str = include_str!("./image_buffer_support.swift");
+
+pub const C_IMAGE_BUFFER_SUPPORT: &str = r#"
+typedef struct __private__ImageBufferInfo { const uint8_t* bytes; uintptr_t len; uint32_t width; uint32_t height; uintptr_t bytes_per_row; uint8_t pixel_format; } __private__ImageBufferInfo;
+struct __private__ImageBufferInfo __swift_bridge__$ImageBuffer$info(void* image);
+void* __swift_bridge__$ImageBuffer$new(uint32_t width, uint32_t height, uintptr_t bytes_per_row, uint8_t pixel_format, struct __private__FfiSlice bytes);
+void __swift_bridge__$ImageBuffer$_free(void* image);
+"#;
@@ -0,0 +1,156 @@
+import CoreGraphics
+import CoreVideo
+
+/// How a pixel of a `RustImageBuffer` is laid out in memory.
+public enum RustImagePixelFormat: UInt8 {
+    /// Red, green, blue and alpha bytes, where the colors are not premultiplied by the alpha.
+    case rgba8 = 0
+    /// Red, green, blue and alpha bytes, where the colors are premultiplied by the alpha.
+    case rgba8Premultiplied = 1
+    /// Blue, green, red and alpha bytes, where the colors are premultiplied by the alpha.
+    case bgra8Premultiplied = 2
+    /// A single luminance byte.
+    case gray8 = 3
+
+    var bitsPerPixel: Int {
+        switch self {
+        case .rgba8, .rgba8Premultiplied, .bgra8Premultiplied:
+            return 32
+        case .gray8:
+            return 8
+        }
+    }
+
+    var bitmapInfo: CGBitmapInfo {
+        switch self {
+        case .rgba8:
+            return CGBitmapInfo(rawValue: CGImageAlphaInfo.last.rawValue)
+        case .rgba8Premultiplied:
+            return CGBitmapInfo(rawValue: CGImageAlphaInfo.premultipliedLast.rawValue)
+        case .bgra8Premultiplied:
+            return CGBitmapInfo(rawValue: CGImageAlphaInfo.premultipliedFirst.rawValue | CGBitmapInfo.byteOrder32Little.rawValue)
+        case .gray8:
+            return CGBitmapInfo(rawValue: CGImageAlphaInfo.none.rawValue)
+        }
+    }
+
+    var cvPixelFormat: OSType {
+        switch self {
+        case .rgba8, .rgba8Premultiplied:
+            return kCVPixelFormatType_32RGBA
+        case .bgra8Premultiplied:
+            return kCVPixelFormatType_32BGRA
+        case .gray8:
+            return kCVPixelFormatType_OneComponent8
+        }
+    }
+}
+
+/// A Rust `swift_bridge::image_buffer::ImageBuffer`.
+///
+/// The images that are made from a `RustImageBuffer` point at its pixels instead of copying them,
+/// and keep the `RustImageBuffer` alive until they are released.
+public class RustImageBuffer {
+    var ptr: UnsafeMutableRawPointer
+    var isOwned: Bool = true
+
+    public let width: Int
+    public let height: Int
+    public let bytesPerRow: Int
+    public let pixelFormat: RustImagePixelFormat
+    let bytes: UnsafeRawBufferPointer
+
+    public init(ptr: UnsafeMutableRawPointer) {
+        self.ptr = ptr
+
+        let info = __swift_bridge__$ImageBuffer$info(ptr)
+        width = Int(info.width)
+        height = Int(info.height)
+        bytesPerRow = Int(info.bytes_per_row)
+        pixelFormat = RustImagePixelFormat(rawValue: info.pixel_format)!
+        bytes = UnsafeRawBufferPointer(start: info.bytes, count: Int(info.len))
+    }
+
+    /// Copy pixels into a new Rust `ImageBuffer`.
+    ///
+    /// Returns `nil` if there are fewer than `bytesPerRow * height` bytes or a row cannot hold
+    /// `width` pixels.
+    public convenience init?(width: Int, height: Int, bytesPerRow: Int, pixelFormat: RustImagePixelFormat, bytes: UnsafeRawBufferPointer) {
+        let slice = __private__FfiSlice(start: UnsafeMutableRawPointer(mutating: bytes.baseAddress), len: UInt(bytes.count))
+        guard let ptr = __swift_bridge__$ImageBuffer$new(UInt32(width), UInt32(height), UInt(bytesPerRow), pixelFormat.rawValue, slice) else {
+            return nil
+        }
+        self.init(ptr: ptr)
+    }
+
+    deinit {
+        if isOwned {
+            __swift_bridge__$ImageBuffer$_free(ptr)
+        }
+    }
+
+    /// Read the pixels, row by row.
+    public func withUnsafeBytes<R>(_ body: (UnsafeRawBufferPointer) throws -> R) rethrows -> R {
+        try body(bytes)
+    }
+
+    /// A `CGImage` that points at the buffer's pixels.
+    public func makeCGImage() -> CGImage? {
+        guard let baseAddress = bytes.baseAddress else {
+            return nil
+        }
+
+        let colorSpace = pixelFormat == .gray8 ? CGColorSpaceCreateDeviceGray() : CGColorSpaceCreateDeviceRGB()
+        let info = Unmanaged.passRetained(self).toOpaque()
+        guard let provider = CGDataProvider(dataInfo: info, data: baseAddress, size: bytes.count, releaseData: { info, _, _ in
+            Unmanaged<RustImageBuffer>.fromOpaque(info!).release()
+        }) else {
+            Unmanaged<RustImageBuffer>.fromOpaque(info).release()
+            return nil
+        }
+
+        return CGImage(
+            width: width,
+            height: height,
+            bitsPerComponent: 8,
+            bitsPerPixel: pixelFormat.bitsPerPixel,
+            bytesPerRow: bytesPerRow,
+            space: colorSpace,
+            bitmapInfo: pixelFormat.bitmapInfo,
+            provider: provider,
+            decode: nil,
+            shouldInterpolate: false,
+            intent: .defaultIntent
+        )
+    }
+
+    /// A `CVPixelBuffer` that points at the buffer's pixels.
+    public func makeCVPixelBuffer() -> CVPixelBuffer? {
+        guard let baseAddress = bytes.baseAddress else {
+            return nil
+        }
+
+        let info = Unmanaged.passRetained(self).toOpaque()
+        var pixelBuffer: CVPixelBuffer?
+        let status = CVPixelBufferCreateWithBytes(
+            kCFAllocatorDefault,
+            width,
+            height,
+            pixelFormat.cvPixelFormat,
+            UnsafeMutableRawPointer(mutating: baseAddress),
+            bytesPerRow,
+            { info, _ in
+                Unmanaged<RustImageBuffer>.fromOpaque(info!).release()
+            },
+            info,
+            nil,
+            &pixelBuffer
+        )
+        if status != kCVReturnSuccess {
+            Unmanaged<RustImageBuffer>.fromOpaque(info).release()
+            return nil
+        }
+
+        return pixelBuffer
+    }
+}
@@ -0,0 +1,6 @@
+//! Support for the `swift_bridge::keychain` module, which is enabled by the swift-bridge crate's
+//! `keychain` feature.
+//!
+//! The corresponding Rust code can be found in src/keychain.rs
+
+pub const SWIFT_KEYCHAIN_SUPPORT: &str = include_str!("./keychain_support.swift");
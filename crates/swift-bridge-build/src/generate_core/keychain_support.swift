@@ -0,0 +1,52 @@
+import Security
+
+@_cdecl("__swift_bridge__$keychain$get")
+func __swift_bridge__keychain_get (_ service: RustStr, _ account: RustStr) -> UnsafeMutableRawPointer? {
+    var query = __swift_bridge__keychain_query(service, account)
+    query[kSecReturnData as String] = true
+    query[kSecMatchLimit as String] = kSecMatchLimitOne
+
+    var item: CFTypeRef?
+    guard SecItemCopyMatching(query as CFDictionary, &item) == errSecSuccess, let data = item as? Data else {
+        return nil
+    }
+
+    let vec = RustVec<UInt8>()
+    for byte in data {
+        vec.push(value: byte)
+    }
+    vec.isOwned = false
+    return vec.ptr
+}
+
+@_cdecl("__swift_bridge__$keychain$set")
+func __swift_bridge__keychain_set (_ service: RustStr, _ account: RustStr, _ value: __private__FfiSlice) -> Int32 {
+    let query = __swift_bridge__keychain_query(service, account)
+    let data = Data(bytes: value.start, count: Int(value.len))
+
+    let status = SecItemUpdate(query as CFDictionary, [kSecValueData as String: data] as CFDictionary)
+    if status != errSecItemNotFound {
+        return status
+    }
+
+    var newItem = query
+    newItem[kSecValueData as String] = data
+    return SecItemAdd(newItem as CFDictionary, nil)
+}
+
+@_cdecl("__swift_bridge__$keychain$delete")
+func __swift_bridge__keychain_delete (_ service: RustStr, _ account: RustStr) -> Int32 {
+    let status = SecItemDelete(__swift_bridge__keychain_query(service, account) as CFDictionary)
+    if status == errSecItemNotFound {
+        return errSecSuccess
+    }
+    return status
+}
+
+func __swift_bridge__keychain_query (_ service: RustStr, _ account: RustStr) -> [String: Any] {
+    [
+        kSecClass as String: kSecClassGenericPassword,
+        kSecAttrService as String: service.toString(),
+        kSecAttrAccount as String: account.toString(),
+    ]
+}
@@ -0,0 +1,11 @@
+//! Support for the `swift_bridge::measurement` module, which is enabled by the swift-bridge
+//! crate's `measurement` feature.
+//!
+//! The corresponding Rust code can be found in src/measurement.rs
+
+pub const SWIFT_MEASUREMENT_SUPPORT: &str = include_str!("./measurement_support.swift");
+
+pub const C_MEASUREMENT_SUPPORT: &str = r#"
+typedef struct __private__Measurement { double value; uint8_t unit; } __private__Measurement;
+typedef struct __private__OptionMeasurement { struct __private__Measurement val; bool is_some; } __private__OptionMeasurement;
+"#;
@@ -0,0 +1,43 @@
+import Foundation
+
+// The units of each measurement type, in the same order as the variants of the corresponding
+// Rust enum in src/measurement.rs. A unit is passed over FFI as its index in the list.
+let __private__lengthUnits: [UnitLength] = [.meters, .kilometers, .centimeters, .millimeters, .miles, .yards, .feet, .inches]
+let __private__massUnits: [UnitMass] = [.kilograms, .grams, .milligrams, .pounds, .ounces]
+let __private__temperatureUnits: [UnitTemperature] = [.kelvin, .celsius, .fahrenheit]
+let __private__energyUnits: [UnitEnergy] = [.joules, .kilojoules, .kilocalories, .kilowattHours]
+let __private__speedUnits: [UnitSpeed] = [.metersPerSecond, .kilometersPerHour, .milesPerHour, .knots]
+
+extension __private__Measurement {
+    /// A unit that Rust does not know about is converted to the first unit in the list, which is
+    /// the base unit of the dimension.
+    init<U: Dimension>(_ measurement: Measurement<U>, _ units: [U]) {
+        if let index = units.firstIndex(of: measurement.unit) {
+            self.init(value: measurement.value, unit: UInt8(index))
+        } else {
+            self.init(value: measurement.converted(to: units[0]).value, unit: 0)
+        }
+    }
+
+    func intoMeasurement<U: Dimension>(_ units: [U]) -> Measurement<U> {
+        Measurement(value: value, unit: units[Int(unit)])
+    }
+}
+
+extension __private__OptionMeasurement {
+    init<U: Dimension>(_ val: Optional<Measurement<U>>, _ units: [U]) {
+        if let val = val {
+            self.init(val: __private__Measurement(val, units), is_some: true)
+        } else {
+            self.init(val: __private__Measurement(value: 0, unit: 0), is_some: false)
+        }
+    }
+
+    func intoSwiftRepr<U: Dimension>(_ units: [U]) -> Optional<Measurement<U>> {
+        if is_some {
+            return val.intoMeasurement(units)
+        } else {
+            return nil
+        }
+    }
+}
@@ -0,0 +1,46 @@
+/// The functions that a dynamically loaded Rust library exports for a bridge module that is
+/// annotated with `#[swift_bridge(module_table = "...")]`.
+///
+/// ```
+/// let handle = dlopen("libmy_plugin.dylib", RTLD_NOW)
+/// let table = RustModuleTable(handle: handle, name: "my_plugin")!
+///
+/// typealias SomeFunction = @convention(c) (UInt32) -> UInt32
+/// let someFunction = table.function("__swift_bridge__$some_function", as: SomeFunction.self)!
+/// ```
+public struct RustModuleTable {
+    let symbols: [String: UnsafeRawPointer]
+
+    /// Look up the module table in a library that was opened using `dlopen`.
+    ///
+    /// Returns nil if the library does not export a module table with the given name.
+    public init?(handle: UnsafeMutableRawPointer?, name: String) {
+        guard let accessor = dlsym(handle, "__swift_bridge__$\(name)$module_table") else {
+            return nil
+        }
+        typealias ModuleTableAccessor = @convention(c) () -> __private__ModuleTable
+        let table = unsafeBitCast(accessor, to: ModuleTableAccessor.self)()
+
+        var symbols: [String: UnsafeRawPointer] = [:]
+        for idx in 0..<Int(table.len) {
+            let entry = table.entries[idx]
+            symbols[String(cString: entry.name)] = entry.symbol
+        }
+        self.symbols = symbols
+    }
+
+    /// The names of all of the functions in the table.
+    public var names: [String] {
+        Array(symbols.keys)
+    }
+
+    /// Get a function from the table as a `@convention(c)` function type.
+    ///
+    /// The function type must match the function's declaration in the generated C header.
+    public func function<F>(_ name: String, as type: F.Type) -> F? {
+        guard let symbol = symbols[name] else {
+            return nil
+        }
+        return unsafeBitCast(symbol, to: type)
+    }
+}
@@ -0,0 +1,194 @@
+/// A type whose `RustVec` can be stored in a `RustVec`, such as the `UInt8` in a
+/// `RustVec<RustVec<UInt8>>` (a Rust `Vec<Vec<u8>>`).
+public protocol VecOfVecElement: Vectorizable {
+    static func vecOfVecOfSelfNew() -> UnsafeMutableRawPointer
+
+    static func vecOfVecOfSelfFree(vecPtr: UnsafeMutableRawPointer)
+
+    static func vecOfVecOfSelfPush(vecPtr: UnsafeMutableRawPointer, value: UnsafeMutableRawPointer)
+
+    static func vecOfVecOfSelfPop(vecPtr: UnsafeMutableRawPointer) -> UnsafeMutableRawPointer?
+
+    static func vecOfVecOfSelfGet(vecPtr: UnsafeMutableRawPointer, index: UInt) -> UnsafeMutableRawPointer?
+
+    static func vecOfVecOfSelfGetMut(vecPtr: UnsafeMutableRawPointer, index: UInt) -> UnsafeMutableRawPointer?
+
+    static func vecOfVecOfSelfAsPtr(vecPtr: UnsafeMutableRawPointer) -> UnsafeMutableRawPointer
+
+    static func vecOfVecOfSelfLen(vecPtr: UnsafeMutableRawPointer) -> UInt
+}
+
+extension RustVec: Vectorizable where T: VecOfVecElement {
+    /// The inner `RustVec`s that are returned by `get` borrow from the outer `RustVec`, so they must
+    /// not outlive it.
+    public typealias SelfRef = RustVec<T>
+    public typealias SelfRefMut = RustVec<T>
+
+    public static func vecOfSelfNew() -> UnsafeMutableRawPointer {
+        T.vecOfVecOfSelfNew()
+    }
+
+    public static func vecOfSelfFree(vecPtr: UnsafeMutableRawPointer) {
+        T.vecOfVecOfSelfFree(vecPtr: vecPtr)
+    }
+
+    public static func vecOfSelfPush(vecPtr: UnsafeMutableRawPointer, value: RustVec<T>) {
+        T.vecOfVecOfSelfPush(vecPtr: vecPtr, value: {value.isOwned = false; return value.ptr;}())
+    }
+
+    public static func vecOfSelfPop(vecPtr: UnsafeMutableRawPointer) -> Optional<Self> {
+        if let pointer = T.vecOfVecOfSelfPop(vecPtr: vecPtr) {
+            return (RustVec<T>(ptr: pointer) as! Self)
+        } else {
+            return nil
+        }
+    }
+
+    public static func vecOfSelfGet(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<RustVec<T>> {
+        if let pointer = T.vecOfVecOfSelfGet(vecPtr: vecPtr, index: index) {
+            return borrowedRustVec(pointer)
+        } else {
+            return nil
+        }
+    }
+
+    public static func vecOfSelfGetMut(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<RustVec<T>> {
+        if let pointer = T.vecOfVecOfSelfGetMut(vecPtr: vecPtr, index: index) {
+            return borrowedRustVec(pointer)
+        } else {
+            return nil
+        }
+    }
+
+    public static func vecOfSelfAsPtr(vecPtr: UnsafeMutableRawPointer) -> UnsafePointer<RustVec<T>> {
+        UnsafePointer<RustVec<T>>(OpaquePointer(T.vecOfVecOfSelfAsPtr(vecPtr: vecPtr)))
+    }
+
+    public static func vecOfSelfLen(vecPtr: UnsafeMutableRawPointer) -> UInt {
+        T.vecOfVecOfSelfLen(vecPtr: vecPtr)
+    }
+
+    static func borrowedRustVec(_ pointer: UnsafeMutableRawPointer) -> RustVec<T> {
+        let vec = RustVec<T>(ptr: pointer)
+        vec.isOwned = false
+        return vec
+    }
+}
+
+/// A type whose `Optional` can be stored in a `RustVec`, such as the `RustString` in a
+/// `RustVec<Optional<RustString>>` (a Rust `Vec<Option<String>>`).
+public protocol VecOfOptionElement: Vectorizable {
+    static func vecOfOptionOfSelfNew() -> UnsafeMutableRawPointer
+
+    static func vecOfOptionOfSelfFree(vecPtr: UnsafeMutableRawPointer)
+
+    static func vecOfOptionOfSelfPush(vecPtr: UnsafeMutableRawPointer, value: Optional<Self>)
+
+    /// Only called when the Vec is not empty, so `nil` means that the last element was `None`.
+    static func vecOfOptionOfSelfPop(vecPtr: UnsafeMutableRawPointer) -> Optional<Self>
+
+    /// Only called with an index that is in bounds, so `nil` means that the element is `None`.
+    static func vecOfOptionOfSelfGet(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<SelfRef>
+
+    /// Only called with an index that is in bounds, so `nil` means that the element is `None`.
+    static func vecOfOptionOfSelfGetMut(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<SelfRefMut>
+
+    static func vecOfOptionOfSelfAsPtr(vecPtr: UnsafeMutableRawPointer) -> UnsafeMutableRawPointer
+
+    static func vecOfOptionOfSelfLen(vecPtr: UnsafeMutableRawPointer) -> UInt
+}
+
+extension Optional: Vectorizable where Wrapped: VecOfOptionElement {
+    public typealias SelfRef = Optional<Wrapped.SelfRef>
+    public typealias SelfRefMut = Optional<Wrapped.SelfRefMut>
+
+    public static func vecOfSelfNew() -> UnsafeMutableRawPointer {
+        Wrapped.vecOfOptionOfSelfNew()
+    }
+
+    public static func vecOfSelfFree(vecPtr: UnsafeMutableRawPointer) {
+        Wrapped.vecOfOptionOfSelfFree(vecPtr: vecPtr)
+    }
+
+    public static func vecOfSelfPush(vecPtr: UnsafeMutableRawPointer, value: Optional<Wrapped>) {
+        Wrapped.vecOfOptionOfSelfPush(vecPtr: vecPtr, value: value)
+    }
+
+    public static func vecOfSelfPop(vecPtr: UnsafeMutableRawPointer) -> Optional<Optional<Wrapped>> {
+        if Wrapped.vecOfOptionOfSelfLen(vecPtr: vecPtr) == 0 {
+            return nil
+        }
+        return .some(Wrapped.vecOfOptionOfSelfPop(vecPtr: vecPtr))
+    }
+
+    public static func vecOfSelfGet(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<Optional<Wrapped.SelfRef>> {
+        if index >= Wrapped.vecOfOptionOfSelfLen(vecPtr: vecPtr) {
+            return nil
+        }
+        return .some(Wrapped.vecOfOptionOfSelfGet(vecPtr: vecPtr, index: index))
+    }
+
+    public static func vecOfSelfGetMut(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<Optional<Wrapped.SelfRefMut>> {
+        if index >= Wrapped.vecOfOptionOfSelfLen(vecPtr: vecPtr) {
+            return nil
+        }
+        return .some(Wrapped.vecOfOptionOfSelfGetMut(vecPtr: vecPtr, index: index))
+    }
+
+    public static func vecOfSelfAsPtr(vecPtr: UnsafeMutableRawPointer) -> UnsafePointer<Optional<Wrapped.SelfRef>> {
+        UnsafePointer<Optional<Wrapped.SelfRef>>(OpaquePointer(Wrapped.vecOfOptionOfSelfAsPtr(vecPtr: vecPtr)))
+    }
+
+    public static func vecOfSelfLen(vecPtr: UnsafeMutableRawPointer) -> UInt {
+        Wrapped.vecOfOptionOfSelfLen(vecPtr: vecPtr)
+    }
+}
+
+extension RustString: VecOfOptionElement {
+    public static func vecOfOptionOfSelfNew() -> UnsafeMutableRawPointer {
+        __swift_bridge__$Vec_Option_String$new()
+    }
+
+    public static func vecOfOptionOfSelfFree(vecPtr: UnsafeMutableRawPointer) {
+        __swift_bridge__$Vec_Option_String$_free(vecPtr)
+    }
+
+    public static func vecOfOptionOfSelfPush(vecPtr: UnsafeMutableRawPointer, value: Optional<RustString>) {
+        __swift_bridge__$Vec_Option_String$push(vecPtr, { if let val = value { val.isOwned = false; return val.ptr } else { return nil } }())
+    }
+
+    public static func vecOfOptionOfSelfPop(vecPtr: UnsafeMutableRawPointer) -> Optional<Self> {
+        let pointer = __swift_bridge__$Vec_Option_String$pop(vecPtr)
+        if pointer == nil {
+            return nil
+        } else {
+            return (RustString(ptr: pointer!) as! Self)
+        }
+    }
+
+    public static func vecOfOptionOfSelfGet(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<RustStringRef> {
+        let pointer = __swift_bridge__$Vec_Option_String$get(vecPtr, index)
+        if pointer == nil {
+            return nil
+        } else {
+            return RustStringRef(ptr: pointer!)
+        }
+    }
+
+    public static func vecOfOptionOfSelfGetMut(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<RustStringRefMut> {
+        let pointer = __swift_bridge__$Vec_Option_String$get_mut(vecPtr, index)
+        if pointer == nil {
+            return nil
+        } else {
+            return RustStringRefMut(ptr: pointer!)
+        }
+    }
+
+    public static func vecOfOptionOfSelfAsPtr(vecPtr: UnsafeMutableRawPointer) -> UnsafeMutableRawPointer {
+        __swift_bridge__$Vec_Option_String$as_ptr(vecPtr)
+    }
+
+    public static func vecOfOptionOfSelfLen(vecPtr: UnsafeMutableRawPointer) -> UInt {
+        __swift_bridge__$Vec_Option_String$len(vecPtr)
+    }
+}
@@ -0,0 +1,172 @@
+//! Support for one level of `Vec` nesting, i.e. `Vec<Vec<T>>` where `T` is a primitive and
+//! `Vec<Option<T>>` where `T` is a primitive or a `String`.
+//!
+//! The corresponding Rust code can be found in src/std_bridge/rust_vec.rs
+
+const NESTED_VEC_SWIFT: &str = include_str!("./nested_vec.swift");
+
+/// The (Swift type, Rust type, C type, C Option type) of every primitive that can be stored in a
+/// `Vec<Vec<T>>` or a `Vec<Option<T>>`.
+const NESTED_VEC_PRIMITIVES: [(&str, &str, &str, &str); 13] = [
+    ("UInt8", "u8", "uint8_t", "__private__OptionU8"),
+    ("UInt16", "u16", "uint16_t", "__private__OptionU16"),
+    ("UInt32", "u32", "uint32_t", "__private__OptionU32"),
+    ("UInt64", "u64", "uint64_t", "__private__OptionU64"),
+    ("UInt", "usize", "uintptr_t", "__private__OptionUsize"),
+    //
+    ("Int8", "i8", "int8_t", "__private__OptionI8"),
+    ("Int16", "i16", "int16_t", "__private__OptionI16"),
+    ("Int32", "i32", "int32_t", "__private__OptionI32"),
+    ("Int64", "i64", "int64_t", "__private__OptionI64"),
+    ("Int", "isize", "intptr_t", "__private__OptionIsize"),
+    //
+    ("Bool", "bool", "bool", "__private__OptionBool"),
+    //
+    ("Float", "f32", "float", "__private__OptionF32"),
+    ("Double", "f64", "double", "__private__OptionF64"),
+];
+
+pub(super) fn swift_nested_vec_support() -> String {
+    let mut swift = NESTED_VEC_SWIFT.to_string();
+
+    for (swift_ty, rust_ty, _, _) in NESTED_VEC_PRIMITIVES {
+        swift += &conform_to_vec_of_vec_element(swift_ty, rust_ty);
+        swift += &conform_to_vec_of_option_element(swift_ty, rust_ty);
+    }
+
+    swift
+}
+
+pub(super) fn c_nested_vec_support() -> String {
+    let mut header = "".to_string();
+
+    for (_, rust_ty, _, c_option_ty) in NESTED_VEC_PRIMITIVES {
+        header += &vec_of_vec_headers(rust_ty);
+        header += &vec_of_option_headers(rust_ty, c_option_ty);
+    }
+
+    header += r#"
+void* __swift_bridge__$Vec_Option_String$new(void);
+void __swift_bridge__$Vec_Option_String$_free(void* const vec);
+uintptr_t __swift_bridge__$Vec_Option_String$len(void* const vec);
+void __swift_bridge__$Vec_Option_String$push(void* const vec, void* val);
+void* __swift_bridge__$Vec_Option_String$pop(void* const vec);
+void* __swift_bridge__$Vec_Option_String$get(void* const vec, uintptr_t index);
+void* __swift_bridge__$Vec_Option_String$get_mut(void* const vec, uintptr_t index);
+void* __swift_bridge__$Vec_Option_String$as_ptr(void* const vec);
+"#;
+
+    header
+}
+
+/// Headers for Vec<Vec<T>> where T is a primitive such as u8, i32, bool
+fn vec_of_vec_headers(rust_ty: &str) -> String {
+    format!(
+        r#"
+void* __swift_bridge__$Vec_Vec_{rust_ty}$new(void);
+void __swift_bridge__$Vec_Vec_{rust_ty}$_free(void* const vec);
+uintptr_t __swift_bridge__$Vec_Vec_{rust_ty}$len(void* const vec);
+void __swift_bridge__$Vec_Vec_{rust_ty}$push(void* const vec, void* val);
+void* __swift_bridge__$Vec_Vec_{rust_ty}$pop(void* const vec);
+void* __swift_bridge__$Vec_Vec_{rust_ty}$get(void* const vec, uintptr_t index);
+void* __swift_bridge__$Vec_Vec_{rust_ty}$get_mut(void* const vec, uintptr_t index);
+void* __swift_bridge__$Vec_Vec_{rust_ty}$as_ptr(void* const vec);
+"#
+    )
+}
+
+/// Headers for Vec<Option<T>> where T is a primitive such as u8, i32, bool
+fn vec_of_option_headers(rust_ty: &str, c_option_ty: &str) -> String {
+    format!(
+        r#"
+void* __swift_bridge__$Vec_Option_{rust_ty}$new(void);
+void __swift_bridge__$Vec_Option_{rust_ty}$_free(void* const vec);
+uintptr_t __swift_bridge__$Vec_Option_{rust_ty}$len(void* const vec);
+void __swift_bridge__$Vec_Option_{rust_ty}$push(void* const vec, {c_option_ty} val);
+{c_option_ty} __swift_bridge__$Vec_Option_{rust_ty}$pop(void* const vec);
+{c_option_ty} __swift_bridge__$Vec_Option_{rust_ty}$get(void* const vec, uintptr_t index);
+{c_option_ty} __swift_bridge__$Vec_Option_{rust_ty}$get_mut(void* const vec, uintptr_t index);
+void* __swift_bridge__$Vec_Option_{rust_ty}$as_ptr(void* const vec);
+"#
+    )
+}
+
+fn conform_to_vec_of_vec_element(swift_ty: &str, rust_ty: &str) -> String {
+    format!(
+        r#"
+extension {swift_ty}: VecOfVecElement {{
+    public static func vecOfVecOfSelfNew() -> UnsafeMutableRawPointer {{
+        __swift_bridge__$Vec_Vec_{rust_ty}$new()
+    }}
+
+    public static func vecOfVecOfSelfFree(vecPtr: UnsafeMutableRawPointer) {{
+        __swift_bridge__$Vec_Vec_{rust_ty}$_free(vecPtr)
+    }}
+
+    public static func vecOfVecOfSelfPush(vecPtr: UnsafeMutableRawPointer, value: UnsafeMutableRawPointer) {{
+        __swift_bridge__$Vec_Vec_{rust_ty}$push(vecPtr, value)
+    }}
+
+    public static func vecOfVecOfSelfPop(vecPtr: UnsafeMutableRawPointer) -> UnsafeMutableRawPointer? {{
+        __swift_bridge__$Vec_Vec_{rust_ty}$pop(vecPtr)
+    }}
+
+    public static func vecOfVecOfSelfGet(vecPtr: UnsafeMutableRawPointer, index: UInt) -> UnsafeMutableRawPointer? {{
+        __swift_bridge__$Vec_Vec_{rust_ty}$get(vecPtr, index)
+    }}
+
+    public static func vecOfVecOfSelfGetMut(vecPtr: UnsafeMutableRawPointer, index: UInt) -> UnsafeMutableRawPointer? {{
+        __swift_bridge__$Vec_Vec_{rust_ty}$get_mut(vecPtr, index)
+    }}
+
+    public static func vecOfVecOfSelfAsPtr(vecPtr: UnsafeMutableRawPointer) -> UnsafeMutableRawPointer {{
+        __swift_bridge__$Vec_Vec_{rust_ty}$as_ptr(vecPtr)
+    }}
+
+    public static func vecOfVecOfSelfLen(vecPtr: UnsafeMutableRawPointer) -> UInt {{
+        __swift_bridge__$Vec_Vec_{rust_ty}$len(vecPtr)
+    }}
+}}
+"#
+    )
+}
+
+fn conform_to_vec_of_option_element(swift_ty: &str, rust_ty: &str) -> String {
+    format!(
+        r#"
+extension {swift_ty}: VecOfOptionElement {{
+    public static func vecOfOptionOfSelfNew() -> UnsafeMutableRawPointer {{
+        __swift_bridge__$Vec_Option_{rust_ty}$new()
+    }}
+
+    public static func vecOfOptionOfSelfFree(vecPtr: UnsafeMutableRawPointer) {{
+        __swift_bridge__$Vec_Option_{rust_ty}$_free(vecPtr)
+    }}
+
+    public static func vecOfOptionOfSelfPush(vecPtr: UnsafeMutableRawPointer, value: Optional<Self>) {{
+        __swift_bridge__$Vec_Option_{rust_ty}$push(vecPtr, value.intoFfiRepr())
+    }}
+
+    public static func vecOfOptionOfSelfPop(vecPtr: UnsafeMutableRawPointer) -> Optional<Self> {{
+        __swift_bridge__$Vec_Option_{rust_ty}$pop(vecPtr).intoSwiftRepr()
+    }}
+
+    public static func vecOfOptionOfSelfGet(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<Self> {{
+        __swift_bridge__$Vec_Option_{rust_ty}$get(vecPtr, index).intoSwiftRepr()
+    }}
+
+    public static func vecOfOptionOfSelfGetMut(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<Self> {{
+        __swift_bridge__$Vec_Option_{rust_ty}$get_mut(vecPtr, index).intoSwiftRepr()
+    }}
+
+    public static func vecOfOptionOfSelfAsPtr(vecPtr: UnsafeMutableRawPointer) -> UnsafeMutableRawPointer {{
+        __swift_bridge__$Vec_Option_{rust_ty}$as_ptr(vecPtr)
+    }}
+
+    public static func vecOfOptionOfSelfLen(vecPtr: UnsafeMutableRawPointer) -> UInt {{
+        __swift_bridge__$Vec_Option_{rust_ty}$len(vecPtr)
+    }}
+}}
+"#
+    )
+}
@@ -0,0 +1,139 @@
+/// A Rust `std::net::IpAddr`.
+public struct RustIpAddr: Hashable, CustomStringConvertible {
+    /// The address's bytes in network order. 4 bytes for an IPv4 address and 16 bytes for an IPv6
+    /// address.
+    public let octets: [UInt8]
+
+    /// Create an IP address from its 4 (IPv4) or 16 (IPv6) bytes in network order.
+    public init?(octets: [UInt8]) {
+        guard octets.count == 4 || octets.count == 16 else {
+            return nil
+        }
+        self.octets = octets
+    }
+
+    /// Parse an IP address such as `"127.0.0.1"` or `"::1"`.
+    public init?(_ string: String) {
+        var v4 = in_addr()
+        var v6 = in6_addr()
+
+        if inet_pton(AF_INET, string, &v4) == 1 {
+            self.octets = withUnsafeBytes(of: &v4) { Array($0) }
+        } else if inet_pton(AF_INET6, string, &v6) == 1 {
+            self.octets = withUnsafeBytes(of: &v6) { Array($0) }
+        } else {
+            return nil
+        }
+    }
+
+    public var isIPv4: Bool {
+        octets.count == 4
+    }
+
+    public var isIPv6: Bool {
+        octets.count == 16
+    }
+
+    public var description: String {
+        var buffer = [CChar](repeating: 0, count: Int(INET6_ADDRSTRLEN))
+        let family = isIPv4 ? AF_INET : AF_INET6
+        octets.withUnsafeBytes {
+            _ = inet_ntop(family, $0.baseAddress, &buffer, socklen_t(buffer.count))
+        }
+        return String(cString: buffer)
+    }
+}
+
+/// A Rust `std::net::SocketAddr`.
+public struct RustSocketAddr: Hashable, CustomStringConvertible {
+    public var ip: RustIpAddr
+    public var port: UInt16
+    /// The IPv6 flow info. Always 0 for an IPv4 address.
+    public var flowInfo: UInt32
+    /// The IPv6 scope ID. Always 0 for an IPv4 address.
+    public var scopeId: UInt32
+
+    public init(ip: RustIpAddr, port: UInt16, flowInfo: UInt32 = 0, scopeId: UInt32 = 0) {
+        self.ip = ip
+        self.port = port
+        self.flowInfo = flowInfo
+        self.scopeId = scopeId
+    }
+
+    public var description: String {
+        if ip.isIPv4 {
+            return "\(ip):\(port)"
+        } else if scopeId != 0 {
+            return "[\(ip)%\(scopeId)]:\(port)"
+        } else {
+            return "[\(ip)]:\(port)"
+        }
+    }
+}
+
+extension __private__IpAddr {
+    init(_ addr: RustIpAddr) {
+        self.init()
+        self.is_v6 = addr.isIPv6
+        withUnsafeMutableBytes(of: &self.octets) { octets in
+            octets.copyBytes(from: addr.octets)
+        }
+    }
+
+    func intoSwiftRepr() -> RustIpAddr {
+        var octets = self.octets
+        let count = is_v6 ? 16 : 4
+        return withUnsafeBytes(of: &octets) { RustIpAddr(octets: Array($0.prefix(count)))! }
+    }
+}
+
+extension __private__OptionIpAddr {
+    init(_ val: Optional<RustIpAddr>) {
+        if let val = val {
+            self.init(val: __private__IpAddr(val), is_some: true)
+        } else {
+            self.init(val: __private__IpAddr(), is_some: false)
+        }
+    }
+
+    func intoSwiftRepr() -> Optional<RustIpAddr> {
+        if is_some {
+            return val.intoSwiftRepr()
+        } else {
+            return nil
+        }
+    }
+}
+
+extension __private__SocketAddr {
+    init(_ addr: RustSocketAddr) {
+        self.init(
+            ip: __private__IpAddr(addr.ip),
+            port: addr.port,
+            flowinfo: addr.flowInfo,
+            scope_id: addr.scopeId
+        )
+    }
+
+    func intoSwiftRepr() -> RustSocketAddr {
+        RustSocketAddr(ip: ip.intoSwiftRepr(), port: port, flowInfo: flowinfo, scopeId: scope_id)
+    }
+}
+
+extension __private__OptionSocketAddr {
+    init(_ val: Optional<RustSocketAddr>) {
+        if let val = val {
+            self.init(val: __private__SocketAddr(val), is_some: true)
+        } else {
+            self.init(val: __private__SocketAddr(), is_some: false)
+        }
+    }
+
+    func intoSwiftRepr() -> Optional<RustSocketAddr> {
+        if is_some {
+            return val.intoSwiftRepr()
+        } else {
+            return nil
+        }
+    }
+}
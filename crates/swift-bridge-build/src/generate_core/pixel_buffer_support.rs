@@ -0,0 +1,11 @@
+//! Support for the `swift_bridge::pixel_buffer` module, which is enabled by the swift-bridge
+//! crate's `pixel-buffer` feature.
+//!
+//! The corresponding Rust code can be found in src/pixel_buffer.rs
+
+pub const SWIFT_PIXEL_BUFFER_SUPPORT: &str = include_str!("./pixel_buffer_support.swift");
+
+pub const C_PIXEL_BUFFER_SUPPORT: &str = r#"
+typedef struct __private__PixelBufferInfo { uintptr_t width; uintptr_t height; uint32_t pixel_format; uintptr_t plane_count; } __private__PixelBufferInfo;
+typedef struct __private__PixelBufferPlane { uint8_t* base_address; uintptr_t width; uintptr_t height; uintptr_t bytes_per_row; } __private__PixelBufferPlane;
+"#;
@@ -0,0 +1,58 @@
+import CoreVideo
+
+@_cdecl("__swift_bridge__$PixelBuffer$retain")
+func __swift_bridge__PixelBuffer_retain (_ buffer: UnsafeMutableRawPointer) {
+    let _ = Unmanaged<CVPixelBuffer>.fromOpaque(buffer).retain()
+}
+
+@_cdecl("__swift_bridge__$PixelBuffer$release")
+func __swift_bridge__PixelBuffer_release (_ buffer: UnsafeMutableRawPointer) {
+    Unmanaged<CVPixelBuffer>.fromOpaque(buffer).release()
+}
+
+@_cdecl("__swift_bridge__$PixelBuffer$info")
+func __swift_bridge__PixelBuffer_info (_ buffer: UnsafeMutableRawPointer) -> __private__PixelBufferInfo {
+    let buffer = Unmanaged<CVPixelBuffer>.fromOpaque(buffer).takeUnretainedValue()
+
+    return __private__PixelBufferInfo(
+        width: UInt(CVPixelBufferGetWidth(buffer)),
+        height: UInt(CVPixelBufferGetHeight(buffer)),
+        pixel_format: CVPixelBufferGetPixelFormatType(buffer),
+        plane_count: UInt(CVPixelBufferGetPlaneCount(buffer))
+    )
+}
+
+@_cdecl("__swift_bridge__$PixelBuffer$lock")
+func __swift_bridge__PixelBuffer_lock (_ buffer: UnsafeMutableRawPointer, _ readOnly: Bool) -> Int32 {
+    let buffer = Unmanaged<CVPixelBuffer>.fromOpaque(buffer).takeUnretainedValue()
+    return CVPixelBufferLockBaseAddress(buffer, readOnly ? .readOnly : [])
+}
+
+@_cdecl("__swift_bridge__$PixelBuffer$unlock")
+func __swift_bridge__PixelBuffer_unlock (_ buffer: UnsafeMutableRawPointer, _ readOnly: Bool) -> Int32 {
+    let buffer = Unmanaged<CVPixelBuffer>.fromOpaque(buffer).takeUnretainedValue()
+    return CVPixelBufferUnlockBaseAddress(buffer, readOnly ? .readOnly : [])
+}
+
+/// A buffer that is not planar is treated as having a single plane.
+@_cdecl("__swift_bridge__$PixelBuffer$plane")
+func __swift_bridge__PixelBuffer_plane (_ buffer: UnsafeMutableRawPointer, _ index: UInt) -> __private__PixelBufferPlane {
+    let buffer = Unmanaged<CVPixelBuffer>.fromOpaque(buffer).takeUnretainedValue()
+    let index = Int(index)
+
+    if CVPixelBufferIsPlanar(buffer) {
+        return __private__PixelBufferPlane(
+            base_address: CVPixelBufferGetBaseAddressOfPlane(buffer, index)?.assumingMemoryBound(to: UInt8.self),
+            width: UInt(CVPixelBufferGetWidthOfPlane(buffer, index)),
+            height: UInt(CVPixelBufferGetHeightOfPlane(buffer, index)),
+            bytes_per_row: UInt(CVPixelBufferGetBytesPerRowOfPlane(buffer, index))
+        )
+    } else {
+        return __private__PixelBufferPlane(
+            base_address: CVPixelBufferGetBaseAddress(buffer)?.assumingMemoryBound(to: UInt8.self),
+            width: UInt(CVPixelBufferGetWidth(buffer)),
+            height: UInt(CVPixelBufferGetHeight(buffer)),
+            bytes_per_row: UInt(CVPixelBufferGetBytesPerRow(buffer))
+        )
+    }
+}
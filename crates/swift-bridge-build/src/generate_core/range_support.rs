@@ -0,0 +1,62 @@
+/// The integer types that `Range<T>` and `RangeInclusive<T>` can be bridged for.
+const RANGE_TYPES: [(&str, &str); 10] = [
+    ("U8", "UInt8"),
+    ("I8", "Int8"),
+    ("U16", "UInt16"),
+    ("I16", "Int16"),
+    ("U32", "UInt32"),
+    ("I32", "Int32"),
+    ("U64", "UInt64"),
+    ("I64", "Int64"),
+    ("Usize", "UInt"),
+    ("Isize", "Int"),
+];
+
+pub(super) fn swift_range_support() -> String {
+    let mut all = "".to_string();
+
+    for (suffix, inner_ty) in RANGE_TYPES {
+        let range_ffi_ty = format!("__private__Range{suffix}");
+
+        all += &format!(
+            r#"
+extension {range_ffi_ty} {{
+    init(_ range: Range<{inner_ty}>) {{
+        self.init(start: range.lowerBound, end: range.upperBound)
+    }}
+
+    init(_ range: ClosedRange<{inner_ty}>) {{
+        self.init(start: range.lowerBound, end: range.upperBound)
+    }}
+
+    // Rust allows a range whose start is greater than its end, such as `5..3`. Swift does not,
+    // so the end is clamped to the start.
+    func intoRange() -> Range<{inner_ty}> {{
+        start..<max(start, end)
+    }}
+
+    // Swift has no empty `ClosedRange`, so an empty `RangeInclusive` such as `1..=0` becomes
+    // `start...start`.
+    func intoClosedRange() -> ClosedRange<{inner_ty}> {{
+        start...max(start, end)
+    }}
+}}
+"#
+        );
+    }
+
+    all
+}
+
+pub(super) const C_RANGE_SUPPORT: &str = r#"
+typedef struct __private__RangeU8 { uint8_t start; uint8_t end; } __private__RangeU8;
+typedef struct __private__RangeI8 { int8_t start; int8_t end; } __private__RangeI8;
+typedef struct __private__RangeU16 { uint16_t start; uint16_t end; } __private__RangeU16;
+typedef struct __private__RangeI16 { int16_t start; int16_t end; } __private__RangeI16;
+typedef struct __private__RangeU32 { uint32_t start; uint32_t end; } __private__RangeU32;
+typedef struct __private__RangeI32 { int32_t start; int32_t end; } __private__RangeI32;
+typedef struct __private__RangeU64 { uint64_t start; uint64_t end; } __private__RangeU64;
+typedef struct __private__RangeI64 { int64_t start; int64_t end; } __private__RangeI64;
+typedef struct __private__RangeUsize { uintptr_t start; uintptr_t end; } __private__RangeUsize;
+typedef struct __private__RangeIsize { intptr_t start; intptr_t end; } __private__RangeIsize;
+"#;
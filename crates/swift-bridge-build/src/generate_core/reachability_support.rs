@@ -0,0 +1,11 @@
+//! Support for the `swift_bridge::reachability` module, which is enabled by the swift-bridge
+//! crate's `reachability` feature.
+//!
+//! The corresponding Rust code can be found in src/reachability.rs
+
+pub const SWIFT_REACHABILITY_SUPPORT: &str = include_str!("./reachability_support.swift");
+
+pub const C_REACHABILITY_SUPPORT: &str = r#"
+typedef struct __private__FfiPathUpdate { uint8_t status; bool is_expensive; bool is_constrained; bool uses_wifi; bool uses_cellular; bool uses_wired_ethernet; } __private__FfiPathUpdate;
+void __swift_bridge__$reachability$on_update(void* on_update, struct __private__FfiPathUpdate update);
+"#;
@@ -0,0 +1,52 @@
+import Network
+
+class __private__PathMonitor {
+    let monitor = NWPathMonitor()
+    let queue = DispatchQueue(label: "swift-bridge.reachability")
+}
+
+@_cdecl("__swift_bridge__$reachability$start")
+func __swift_bridge__reachability_start (_ onUpdate: UnsafeMutableRawPointer) -> UnsafeMutableRawPointer {
+    let pathMonitor = __private__PathMonitor()
+
+    pathMonitor.monitor.pathUpdateHandler = { path in
+        let status: UInt8
+        switch path.status {
+        case .satisfied:
+            status = 0
+        case .requiresConnection:
+            status = 2
+        default:
+            status = 1
+        }
+
+        var isConstrained = false
+        if #available(macOS 10.15, iOS 13.0, tvOS 13.0, watchOS 6.0, *) {
+            isConstrained = path.isConstrained
+        }
+
+        __swift_bridge__$reachability$on_update(onUpdate, __private__FfiPathUpdate(
+            status: status,
+            is_expensive: path.isExpensive,
+            is_constrained: isConstrained,
+            uses_wifi: path.usesInterfaceType(.wifi),
+            uses_cellular: path.usesInterfaceType(.cellular),
+            uses_wired_ethernet: path.usesInterfaceType(.wiredEthernet)
+        ))
+    }
+    pathMonitor.monitor.start(queue: pathMonitor.queue)
+
+    return Unmanaged.passRetained(pathMonitor).toOpaque()
+}
+
+@_cdecl("__swift_bridge__$reachability$cancel")
+func __swift_bridge__reachability_cancel (_ pathMonitor: UnsafeMutableRawPointer) {
+    let pathMonitor = Unmanaged<__private__PathMonitor>.fromOpaque(pathMonitor).takeRetainedValue()
+
+    // Updates are delivered on the monitor's queue, so once this returns no more updates will be
+    // sent to Rust.
+    pathMonitor.queue.sync {
+        pathMonitor.monitor.pathUpdateHandler = nil
+        pathMonitor.monitor.cancel()
+    }
+}
@@ -0,0 +1,88 @@
+/// The types and functions of a bridge module that is annotated with
+/// `#[swift_bridge(reflection = "...")]`.
+///
+/// ```
+/// for function in RustReflection.my_module.functions {
+///     print("\(function.name) was called \(function.callCount) times")
+/// }
+/// ```
+public struct RustReflection {
+    /// The name that was given to the `reflection` attribute.
+    public let name: String
+    /// All of the types that the module declares.
+    public let types: [RustReflectedType]
+    /// All of the functions and methods that the module declares, in the order that they were
+    /// declared.
+    public let functions: [RustReflectedFunction]
+
+    public init(_ module: UnsafePointer<__private__ModuleReflection>) {
+        let module = module.pointee
+        self.name = String(cString: module.name)
+
+        var types: [RustReflectedType] = []
+        for idx in 0..<Int(module.types_len) {
+            types.append(RustReflectedType(module.types[idx]))
+        }
+        self.types = types
+
+        var functions: [RustReflectedFunction] = []
+        for idx in 0..<Int(module.functions_len) {
+            functions.append(RustReflectedFunction(module.functions + idx))
+        }
+        self.functions = functions
+    }
+
+    /// The methods of one of the module's types.
+    public func methods(of typeName: String) -> [RustReflectedFunction] {
+        functions.filter { $0.typeName == typeName }
+    }
+}
+
+/// A type that is declared in a bridge module.
+public struct RustReflectedType {
+    public enum Kind: UInt8 {
+        case opaqueRust = 0
+        case opaqueSwift = 1
+        case sharedStruct = 2
+        case sharedEnum = 3
+    }
+
+    /// The type's name.
+    public let name: String
+    /// A hash of the type's name that is the same in Rust and Swift, and across builds.
+    public let id: UInt64
+    public let kind: Kind
+
+    init(_ type: __private__ReflectedType) {
+        self.name = String(cString: type.name)
+        self.id = type.id
+        self.kind = Kind(rawValue: type.kind)!
+    }
+}
+
+/// A function or method that is declared in a bridge module.
+public struct RustReflectedFunction {
+    let ptr: UnsafePointer<__private__ReflectedFunction>
+
+    /// The function's name.
+    public let name: String
+    /// The name of the type that the function is a method of, or nil for a freestanding function.
+    public let typeName: String?
+    /// The symbol that the function is exported or imported as.
+    public let linkName: String
+    /// Whether the function is implemented in Rust, or in Swift.
+    public let isRust: Bool
+
+    init(_ ptr: UnsafePointer<__private__ReflectedFunction>) {
+        self.ptr = ptr
+        self.name = String(cString: ptr.pointee.name)
+        self.typeName = ptr.pointee.type_name.map { String(cString: $0) }
+        self.linkName = String(cString: ptr.pointee.link_name)
+        self.isRust = ptr.pointee.is_rust
+    }
+
+    /// The number of times that the function has been called through the bridge.
+    public var callCount: UInt64 {
+        __swift_bridge__$reflected_function$call_count(ptr)
+    }
+}
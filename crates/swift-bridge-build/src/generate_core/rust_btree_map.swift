@@ -0,0 +1,102 @@
+/// A Rust `BTreeMap<K, V>`.
+///
+/// The keys and values are stored in two `RustVec`s in ascending key order, so iterating over the
+/// map yields the entries in the same order that Rust would.
+public class RustBTreeMap<K: Vectorizable, V: Vectorizable> {
+    var keysVec: RustVec<K>
+    var valuesVec: RustVec<V>
+
+    public init(ffi: __private__FfiBTreeMap) {
+        keysVec = RustVec(ptr: ffi.keys)
+        valuesVec = RustVec(ptr: ffi.values)
+    }
+
+    /// Rust returns a UInt, but we cast to an Int because many Swift APIs such as
+    /// `ForEach(0..rustBTreeMap.len())` expect Int.
+    public func len() -> Int {
+        keysVec.len()
+    }
+
+    /// The keys in ascending order.
+    public func keys() -> RustVec<K> {
+        keysVec
+    }
+
+    /// The values, in the same order as their keys.
+    public func values() -> RustVec<V> {
+        valuesVec
+    }
+
+    func intoFfiRepr() -> __private__FfiBTreeMap {
+        keysVec.isOwned = false
+        valuesVec.isOwned = false
+        return __private__FfiBTreeMap(keys: keysVec.ptr, values: valuesVec.ptr)
+    }
+
+    /// Binary search for the index of a key, given a function that orders a stored key relative
+    /// to the key that we are looking for.
+    func indexOfKey(_ compare: (K.SelfRef) -> Int) -> Int? {
+        var low = 0
+        var high = len() - 1
+
+        while low <= high {
+            let mid = low + (high - low) / 2
+            let ordering = compare(keysVec[mid])
+
+            if ordering == 0 {
+                return mid
+            } else if ordering < 0 {
+                low = mid + 1
+            } else {
+                high = mid - 1
+            }
+        }
+
+        return nil
+    }
+}
+
+extension RustBTreeMap where K.SelfRef: Comparable {
+    public func get(key: K.SelfRef) -> Optional<V.SelfRef> {
+        indexOfKey({ $0 < key ? -1 : ($0 == key ? 0 : 1) }).map({ valuesVec[$0] })
+    }
+}
+
+extension RustBTreeMap where K == RustString {
+    /// Rust orders `String` keys by their UTF-8 bytes, so we compare bytes instead of using
+    /// Swift's `String` ordering.
+    public func get(key: String) -> Optional<V.SelfRef> {
+        let key = Array(key.utf8)
+
+        return indexOfKey({ stored in
+            let stored = stored.as_str().toBufferPointer()
+            if stored.elementsEqual(key) {
+                return 0
+            }
+            return stored.lexicographicallyPrecedes(key) ? -1 : 1
+        }).map({ valuesVec[$0] })
+    }
+}
+
+extension RustBTreeMap: Sequence {
+    public func makeIterator() -> RustBTreeMapIterator<K, V> {
+        RustBTreeMapIterator(self)
+    }
+}
+
+public struct RustBTreeMapIterator<K: Vectorizable, V: Vectorizable>: IteratorProtocol {
+    var rustBTreeMap: RustBTreeMap<K, V>
+    var index: UInt = 0
+
+    init (_ rustBTreeMap: RustBTreeMap<K, V>) {
+        self.rustBTreeMap = rustBTreeMap
+    }
+
+    public mutating func next() -> (key: K.SelfRef, value: V.SelfRef)? {
+        guard let key = rustBTreeMap.keysVec.get(index: index), let value = rustBTreeMap.valuesVec.get(index: index) else {
+            return nil
+        }
+        index += 1
+        return (key: key, value: value)
+    }
+}
@@ -0,0 +1,110 @@
+public class RustHashSet<T: RustHashSetElement> {
+    var ptr: UnsafeMutableRawPointer
+    var isOwned: Bool = true
+
+    public init(ptr: UnsafeMutableRawPointer) {
+        self.ptr = ptr
+    }
+
+    public init() {
+        ptr = T.hashSetOfSelfNew()
+        isOwned = true
+    }
+
+    /// Returns `true` if the value was not already present in the set.
+    @discardableResult
+    public func insert(value: T) -> Bool {
+        T.hashSetOfSelfInsert(hashSetPtr: ptr, value: value)
+    }
+
+    /// Returns `true` if the value was present in the set.
+    @discardableResult
+    public func remove(value: T) -> Bool {
+        T.hashSetOfSelfRemove(hashSetPtr: ptr, value: value)
+    }
+
+    public func contains(value: T) -> Bool {
+        T.hashSetOfSelfContains(hashSetPtr: ptr, value: value)
+    }
+
+    /// Rust returns a UInt, but we cast to an Int because many Swift APIs such as
+    /// `ForEach(0..rustHashSet.len())` expect Int.
+    public func len() -> Int {
+        Int(T.hashSetOfSelfLen(hashSetPtr: ptr))
+    }
+
+    /// Copy the set's elements into a new `RustVec`, in an unspecified order.
+    public func toRustVec() -> RustVec<T> {
+        RustVec(ptr: T.hashSetOfSelfToVec(hashSetPtr: ptr))
+    }
+
+    deinit {
+        if isOwned {
+            T.hashSetOfSelfFree(hashSetPtr: ptr)
+        }
+    }
+}
+
+extension RustHashSet: Sequence {
+    public func makeIterator() -> RustVecIterator<T> {
+        return self.toRustVec().makeIterator()
+    }
+}
+
+extension RustHashSet where T: Hashable, T.SelfRef == T {
+    public func toSet() -> Set<T> {
+        Set(self.toRustVec())
+    }
+}
+
+extension RustHashSet where T == RustString {
+    public func toSet() -> Set<String> {
+        Set(self.toRustVec().map { $0.as_str().toString() })
+    }
+}
+
+public protocol RustHashSetElement: Vectorizable {
+    static func hashSetOfSelfNew() -> UnsafeMutableRawPointer
+
+    static func hashSetOfSelfFree(hashSetPtr: UnsafeMutableRawPointer)
+
+    static func hashSetOfSelfLen(hashSetPtr: UnsafeMutableRawPointer) -> UInt
+
+    static func hashSetOfSelfInsert(hashSetPtr: UnsafeMutableRawPointer, value: Self) -> Bool
+
+    static func hashSetOfSelfRemove(hashSetPtr: UnsafeMutableRawPointer, value: Self) -> Bool
+
+    static func hashSetOfSelfContains(hashSetPtr: UnsafeMutableRawPointer, value: Self) -> Bool
+
+    static func hashSetOfSelfToVec(hashSetPtr: UnsafeMutableRawPointer) -> UnsafeMutableRawPointer
+}
+
+extension RustString: RustHashSetElement {
+    public static func hashSetOfSelfNew() -> UnsafeMutableRawPointer {
+        __swift_bridge__$HashSet_String$new()
+    }
+
+    public static func hashSetOfSelfFree(hashSetPtr: UnsafeMutableRawPointer) {
+        __swift_bridge__$HashSet_String$_free(hashSetPtr)
+    }
+
+    public static func hashSetOfSelfLen(hashSetPtr: UnsafeMutableRawPointer) -> UInt {
+        __swift_bridge__$HashSet_String$len(hashSetPtr)
+    }
+
+    public static func hashSetOfSelfInsert(hashSetPtr: UnsafeMutableRawPointer, value: RustString) -> Bool {
+        __swift_bridge__$HashSet_String$insert(hashSetPtr, {value.isOwned = false; return value.ptr;}())
+    }
+
+    public static func hashSetOfSelfRemove(hashSetPtr: UnsafeMutableRawPointer, value: RustString) -> Bool {
+        __swift_bridge__$HashSet_String$remove(hashSetPtr, value.ptr)
+    }
+
+    public static func hashSetOfSelfContains(hashSetPtr: UnsafeMutableRawPointer, value: RustString) -> Bool {
+        __swift_bridge__$HashSet_String$contains(hashSetPtr, value.ptr)
+    }
+
+    public static func hashSetOfSelfToVec(hashSetPtr: UnsafeMutableRawPointer) -> UnsafeMutableRawPointer {
+        __swift_bridge__$HashSet_String$to_vec(hashSetPtr)
+    }
+}
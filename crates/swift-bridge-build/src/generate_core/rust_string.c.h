@@ -16,4 +16,8 @@ void* __swift_bridge__$RustString$new_with_str(struct RustStr str);
 uintptr_t __swift_bridge__$RustString$len(void* self);
 struct RustStr __swift_bridge__$RustString$as_str(void* self);
 struct RustStr __swift_bridge__$RustString$trim(void* self);
+bool __swift_bridge__$RustString$is_char_boundary(void* self, uintptr_t index);
+uintptr_t __swift_bridge__$RustString$char_count(void* self);
+struct RustStr __swift_bridge__$RustString$get(void* self, uintptr_t start, uintptr_t end);
 bool __swift_bridge__$RustStr$partial_eq(struct RustStr lhs, struct RustStr rhs);
+void __swift_bridge__$CowStr$free(struct __private__CowStr cow);
@@ -46,6 +46,18 @@ extension RustStringRef {
     public func trim() -> RustStr {
         __swift_bridge__$RustString$trim(ptr)
     }
+
+    public func is_char_boundary(_ index: UInt) -> Bool {
+        __swift_bridge__$RustString$is_char_boundary(ptr, index)
+    }
+
+    public func char_count() -> UInt {
+        __swift_bridge__$RustString$char_count(ptr)
+    }
+
+    public func get(_ start: UInt, _ end: UInt) -> Optional<RustStr> {
+        { let val = __swift_bridge__$RustString$get(ptr, start, end); if val.start != nil { return val; } else { return nil; } }()
+    }
 }
 extension RustString: Vectorizable {
     public static func vecOfSelfNew() -> UnsafeMutableRawPointer {
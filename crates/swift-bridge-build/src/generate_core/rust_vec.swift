@@ -63,6 +63,7 @@ public struct RustVecIterator<T: Vectorizable>: IteratorProtocol {
 
 extension RustVec: Collection {
     public typealias Index = Int
+    public typealias SubSequence = RustVecSlice<T>
 
     public func index(after i: Int) -> Int {
         i + 1
@@ -72,6 +73,11 @@ extension RustVec: Collection {
         self.get(index: UInt(position))!
     }
 
+    public subscript(bounds: Range<Int>) -> RustVecSlice<T> {
+        precondition(bounds.lowerBound >= 0 && bounds.upperBound <= self.len(), "Range out of bounds")
+        return RustVecSlice(base: self, bounds: bounds)
+    }
+
     public var startIndex: Int {
         0
     }
@@ -83,12 +89,81 @@ extension RustVec: Collection {
 
 extension RustVec: RandomAccessCollection {}
 
+/// A view of a range of a `RustVec`'s elements, such as `rustVec[2..<5]`.
+///
+/// The view reads from the same allocation as the `RustVec`, so slicing does not copy any elements.
+/// Like a Swift `Slice`, the view shares its indices with the `RustVec` and keeps the `RustVec` alive.
+public struct RustVecSlice<T: Vectorizable> {
+    public let base: RustVec<T>
+    public let startIndex: Int
+    public let endIndex: Int
+
+    init(base: RustVec<T>, bounds: Range<Int>) {
+        self.base = base
+        self.startIndex = bounds.lowerBound
+        self.endIndex = bounds.upperBound
+    }
+
+    /// Call `body` with a buffer that points at the view's elements inside of the `RustVec`'s allocation.
+    public func withUnsafeBufferPointer<R>(_ body: (UnsafeBufferPointer<T.SelfRef>) throws -> R) rethrows -> R {
+        try body(UnsafeBufferPointer(start: base.as_ptr() + startIndex, count: endIndex - startIndex))
+    }
+}
+
+extension RustVecSlice: Collection {
+    public typealias Index = Int
+    public typealias SubSequence = RustVecSlice<T>
+
+    public func index(after i: Int) -> Int {
+        i + 1
+    }
+
+    public subscript(position: Int) -> T.SelfRef {
+        precondition(position >= startIndex && position < endIndex, "Index out of range")
+        return base[position]
+    }
+
+    public subscript(bounds: Range<Int>) -> RustVecSlice<T> {
+        precondition(bounds.lowerBound >= startIndex && bounds.upperBound <= endIndex, "Range out of bounds")
+        return RustVecSlice(base: base, bounds: bounds)
+    }
+}
+
+extension RustVecSlice: RandomAccessCollection {}
+
 extension UnsafeBufferPointer {
     func toFfiSlice () -> __private__FfiSlice {
         __private__FfiSlice(start: UnsafeMutablePointer(mutating: self.baseAddress), len: UInt(self.count))
     }
 }
 
+extension UnsafeMutableBufferPointer {
+    func toFfiSlice () -> __private__FfiSlice {
+        __private__FfiSlice(start: self.baseAddress, len: UInt(self.count))
+    }
+}
+
+extension __private__FfiSlice {
+    /// The start of the slice, or nil if the slice is empty. Crashes if a non-empty slice has a null start.
+    func checkedStart () -> UnsafeMutableRawPointer? {
+        if self.len == 0 {
+            return nil
+        }
+        guard let start = self.start else {
+            __swift_bridge__nullSlice(self.len)
+        }
+        return start
+    }
+
+    func toUnsafeBufferPointer<T> (_ type: T.Type) -> UnsafeBufferPointer<T> {
+        UnsafeBufferPointer(start: self.checkedStart()?.assumingMemoryBound(to: type), count: Int(self.len))
+    }
+
+    func toUnsafeMutableBufferPointer<T> (_ type: T.Type) -> UnsafeMutableBufferPointer<T> {
+        UnsafeMutableBufferPointer(start: self.checkedStart()?.assumingMemoryBound(to: type), count: Int(self.len))
+    }
+}
+
 public protocol Vectorizable {
     associatedtype SelfRef
     associatedtype SelfRefMut
@@ -0,0 +1,6 @@
+//! Support for the `swift_bridge::serde_json` module, which is enabled by the swift-bridge
+//! crate's `serde-json` feature.
+//!
+//! The corresponding Rust code can be found in src/serde_json.rs
+
+pub const SWIFT_SERDE_JSON_SUPPORT: &str = include_str!("./serde_json_support.swift");
@@ -0,0 +1,22 @@
+import Foundation
+
+/// Serialize a value that `JSONSerialization` understands, such as a `[String: Any]`, into a
+/// `RustString` that Rust parses into a `serde_json::Value`.
+func __private__jsonIntoRustString(_ value: Any) -> UnsafeMutableRawPointer {
+    // Wrapping the value in an array lets `isValidJSONObject` check strings, numbers and `NSNull`.
+    precondition(
+        JSONSerialization.isValidJSONObject([value]),
+        "A value must be serializable by JSONSerialization in order to be converted into a Rust serde_json::Value"
+    )
+
+    let data = try! JSONSerialization.data(withJSONObject: value, options: [.fragmentsAllowed])
+    let rustString = String(decoding: data, as: UTF8.self).intoRustString()
+    rustString.isOwned = false
+    return rustString.ptr
+}
+
+/// Parse the `RustString` that Rust serialized a `serde_json::Value` into.
+func __private__jsonFromRustString(_ ptr: UnsafeMutableRawPointer) -> Any {
+    let json = RustString(ptr: ptr).toString()
+    return try! JSONSerialization.jsonObject(with: Data(json.utf8), options: [.fragmentsAllowed])
+}
@@ -9,6 +9,21 @@ extension RustString {
     }
 }
 
+extension RustStringRef {
+    /// The string's Unicode scalars, decoded without copying the string into Swift.
+    ///
+    /// The sequence must not outlive this string.
+    public var unicodeScalars: RustStrUnicodeScalars {
+        self.as_str().unicodeScalars
+    }
+
+    /// The Unicode scalar that starts at the byte `index`, or `nil` if `index` is out of bounds or
+    /// in the middle of a scalar.
+    public func unicodeScalar(at index: UInt) -> Unicode.Scalar? {
+        self.as_str().unicodeScalar(at: index)
+    }
+}
+
 extension RustStr {
     func toBufferPointer() -> UnsafeBufferPointer<UInt8> {
         let bytes = UnsafeBufferPointer(start: self.start, count: Int(self.len))
@@ -20,6 +35,27 @@ extension RustStr {
         return String(bytes: bytes, encoding: .utf8)!
     }
 }
+extension __private__CowStr {
+    /// Copy the string into Swift, freeing it afterwards if Rust handed over an owned `String`.
+    func toString() -> String {
+        let bytes = UnsafeBufferPointer(start: self.start, count: Int(self.len))
+        let string = String(bytes: bytes, encoding: .utf8)!
+
+        if self.is_owned {
+            __swift_bridge__$CowStr$free(self)
+        }
+
+        return string
+    }
+
+    func toOptionalString() -> Optional<String> {
+        if self.start == nil {
+            return nil
+        } else {
+            return self.toString()
+        }
+    }
+}
 extension RustStr: Identifiable {
     public var id: String {
         self.toString()
@@ -30,6 +66,54 @@ extension RustStr: Equatable {
         return __swift_bridge__$RustStr$partial_eq(lhs, rhs);
     }
 }
+extension RustStr {
+    /// The string's Unicode scalars, decoded one at a time from the UTF-8 bytes that Rust owns.
+    ///
+    /// The sequence must not outlive the Rust string that it borrows from.
+    public var unicodeScalars: RustStrUnicodeScalars {
+        RustStrUnicodeScalars(bytes: self.toBufferPointer())
+    }
+
+    /// The Unicode scalar that starts at the byte `index`, or `nil` if `index` is out of bounds or
+    /// in the middle of a scalar.
+    public func unicodeScalar(at index: UInt) -> Unicode.Scalar? {
+        let bytes = self.toBufferPointer()
+        guard index < UInt(bytes.count), !UTF8.isContinuation(bytes[Int(index)]) else {
+            return nil
+        }
+
+        var iterator = UnsafeBufferPointer(rebasing: bytes[Int(index)...]).makeIterator()
+        var decoder = UTF8()
+        guard case .scalarValue(let scalar) = decoder.decode(&iterator) else {
+            return nil
+        }
+        return scalar
+    }
+}
+
+/// The Unicode scalars of a `RustStr`, decoded lazily so that Swift can iterate over Rust-owned
+/// text without copying it into a Swift `String`.
+public struct RustStrUnicodeScalars: Sequence {
+    let bytes: UnsafeBufferPointer<UInt8>
+
+    public func makeIterator() -> Iterator {
+        Iterator(bytes: bytes.makeIterator())
+    }
+
+    public struct Iterator: IteratorProtocol {
+        var bytes: UnsafeBufferPointer<UInt8>.Iterator
+        var decoder = UTF8()
+
+        public mutating func next() -> Unicode.Scalar? {
+            switch decoder.decode(&bytes) {
+            case .scalarValue(let scalar):
+                return scalar
+            case .emptyInput, .error:
+                return nil
+            }
+        }
+    }
+}
 
 public protocol IntoRustString {
     func intoRustString() -> RustString;
@@ -57,6 +141,13 @@ extension RustString: IntoRustString {
     }
 }
 
+/// Allows a file `URL` to be passed to Rust functions that take a `PathBuf`, `&Path` or `OsString`.
+extension URL: IntoRustString {
+    public func intoRustString() -> RustString {
+        RustString(self.path)
+    }
+}
+
 /// If the String is Some:
 ///   Safely get a scoped pointer to the String and then call the callback with a RustStr
 ///   that uses that pointer.
@@ -75,18 +166,26 @@ func optionalStringIntoRustString<S: IntoRustString>(_ string: Optional<S>) -> R
 extension String: ToRustStr {
     /// Safely get a scoped pointer to the String and then call the callback with a RustStr
     /// that uses that pointer.
+    ///
+    /// Native Swift strings are already stored as contiguous UTF-8, so this does not copy or
+    /// allocate. Strings that are not (such as a bridged `NSString`) are first made contiguous.
     public func toRustStr<T> (_ withUnsafeRustStr: (RustStr) -> T) -> T {
-        return self.utf8CString.withUnsafeBufferPointer({ bufferPtr in
+        var string = self
+        return string.withUTF8({ bufferPtr in
             let rustStr = RustStr(
-                start: UnsafeMutableRawPointer(mutating: bufferPtr.baseAddress!).assumingMemoryBound(to: UInt8.self),
-                // Subtract 1 because of the null termination character at the end
-                len: UInt(bufferPtr.count - 1)
+                // An empty string might not have a buffer, but Rust needs a non-null pointer.
+                // A null pointer is used to represent `None`.
+                start: UnsafeMutablePointer(mutating: bufferPtr.baseAddress ?? __private__emptyUtf8Ptr),
+                len: UInt(bufferPtr.count)
             )
             return withUnsafeRustStr(rustStr)
         })
     }
 }
 
+/// A non-null, well aligned pointer that is used as the start of empty strings.
+let __private__emptyUtf8Ptr = UnsafePointer<UInt8>(bitPattern: 1)!
+
 extension RustStr: ToRustStr {
     public func toRustStr<T> (_ withUnsafeRustStr: (RustStr) -> T) -> T {
         return withUnsafeRustStr(self)
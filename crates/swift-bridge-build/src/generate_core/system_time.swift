@@ -0,0 +1,38 @@
+extension __private__SystemTime {
+    init(_ date: Date) {
+        let timeInterval = date.timeIntervalSince1970
+        precondition(
+            timeInterval.isFinite,
+            "A Date must be finite in order to be converted into a Rust SystemTime"
+        )
+
+        // Rounding the seconds down keeps the nanoseconds positive for dates before 1970.
+        let secs = timeInterval.rounded(.down)
+        // The nanoseconds can round up to a full second, which Rust carries over into the seconds.
+        let nanos = ((timeInterval - secs) * 1_000_000_000).rounded()
+
+        self.init(secs: Int64(secs), nanos: UInt32(nanos))
+    }
+
+    func toDate() -> Date {
+        Date(timeIntervalSince1970: TimeInterval(secs) + TimeInterval(nanos) / 1_000_000_000)
+    }
+}
+
+extension __private__OptionSystemTime {
+    init(_ val: Optional<Date>) {
+        if let val = val {
+            self.init(val: __private__SystemTime(val), is_some: true)
+        } else {
+            self.init(val: __private__SystemTime(secs: 0, nanos: 0), is_some: false)
+        }
+    }
+
+    func intoSwiftRepr() -> Optional<Date> {
+        if is_some {
+            return val.toDate()
+        } else {
+            return nil
+        }
+    }
+}
@@ -0,0 +1,13 @@
+//! Support for the `swift_bridge::tensor` module, which is enabled by the swift-bridge crate's
+//! `tensor` feature.
+//!
+//! The corresponding Rust code can be found in src/tensor.rs
+
+pub const SWIFT_TENSOR_SUPPORT: &str = include_str!("./tensor_support.swift");
+
+pub const C_TENSOR_SUPPORT: &str = r#"
+typedef struct __private__TensorInfo { const void* data; uintptr_t len; uintptr_t rank; const uintptr_t* shape; const uintptr_t* strides; uint8_t data_type; } __private__TensorInfo;
+struct __private__TensorInfo __swift_bridge__$Tensor$info(void* tensor);
+void* __swift_bridge__$Tensor$new(uint8_t data_type, struct __private__FfiSlice shape, struct __private__FfiSlice strides, struct __private__FfiSlice bytes);
+void __swift_bridge__$Tensor$_free(void* tensor);
+"#;
@@ -0,0 +1,155 @@
+import CoreML
+
+/// The type of a `RustTensor`'s elements.
+public enum RustTensorDataType: UInt8 {
+    /// `Float`
+    case float32 = 0
+    /// `Double`
+    case float64 = 1
+    /// `Int32`
+    case int32 = 2
+
+    public init?(_ dataType: MLMultiArrayDataType) {
+        switch dataType {
+        case .float32:
+            self = .float32
+        case .double:
+            self = .float64
+        case .int32:
+            self = .int32
+        default:
+            return nil
+        }
+    }
+
+    public var mlMultiArrayDataType: MLMultiArrayDataType {
+        switch self {
+        case .float32:
+            return .float32
+        case .float64:
+            return .double
+        case .int32:
+            return .int32
+        }
+    }
+
+    public var sizeInBytes: Int {
+        switch self {
+        case .float32, .int32:
+            return 4
+        case .float64:
+            return 8
+        }
+    }
+}
+
+/// A Rust `swift_bridge::tensor::Tensor`.
+///
+/// The `MLMultiArray`s that are made from a `RustTensor` point at its elements instead of copying
+/// them, and keep the `RustTensor` alive until they are released.
+public class RustTensor {
+    var ptr: UnsafeMutableRawPointer
+    var isOwned: Bool = true
+
+    /// The number of elements in each dimension.
+    public let shape: [Int]
+    /// The number of elements from one index of each dimension to the next.
+    public let strides: [Int]
+    public let dataType: RustTensorDataType
+    let data: UnsafeRawPointer
+    let count: Int
+
+    public init(ptr: UnsafeMutableRawPointer) {
+        self.ptr = ptr
+
+        let info = __swift_bridge__$Tensor$info(ptr)
+        let rank = Int(info.rank)
+        shape = UnsafeBufferPointer(start: info.shape, count: rank).map { Int($0) }
+        strides = UnsafeBufferPointer(start: info.strides, count: rank).map { Int($0) }
+        dataType = RustTensorDataType(rawValue: info.data_type)!
+        data = info.data
+        count = Int(info.len)
+    }
+
+    /// Copy elements into a new Rust `Tensor`.
+    ///
+    /// Returns `nil` if there is not one stride per dimension or the bytes end before the last
+    /// element that the shape and strides point to.
+    public convenience init?(shape: [Int], strides: [Int], dataType: RustTensorDataType, bytes: UnsafeRawBufferPointer) {
+        let shape = shape.map { UInt($0) }
+        let strides = strides.map { UInt($0) }
+
+        let ptr: UnsafeMutableRawPointer? = shape.withUnsafeBufferPointer { shape in
+            strides.withUnsafeBufferPointer { strides in
+                __swift_bridge__$Tensor$new(
+                    dataType.rawValue,
+                    __private__FfiSlice(start: UnsafeMutableRawPointer(mutating: shape.baseAddress), len: UInt(shape.count)),
+                    __private__FfiSlice(start: UnsafeMutableRawPointer(mutating: strides.baseAddress), len: UInt(strides.count)),
+                    __private__FfiSlice(start: UnsafeMutableRawPointer(mutating: bytes.baseAddress), len: UInt(bytes.count))
+                )
+            }
+        }
+        guard let ptr = ptr else {
+            return nil
+        }
+        self.init(ptr: ptr)
+    }
+
+    /// Copy an `MLMultiArray`'s elements into a new Rust `Tensor`, keeping its shape and strides.
+    ///
+    /// Returns `nil` if the array's elements are not `Float`s, `Double`s or `Int32`s.
+    public convenience init?(_ array: MLMultiArray) {
+        guard let dataType = RustTensorDataType(array.dataType) else {
+            return nil
+        }
+
+        let shape = array.shape.map { $0.intValue }
+        let strides = array.strides.map { $0.intValue }
+        var count = 0
+        if !shape.contains(0) {
+            count = 1 + zip(shape, strides).map { ($0 - 1) * $1 }.reduce(0, +)
+        }
+
+        let bytes = UnsafeRawBufferPointer(start: array.dataPointer, count: count * dataType.sizeInBytes)
+        self.init(shape: shape, strides: strides, dataType: dataType, bytes: bytes)
+    }
+
+    deinit {
+        if isOwned {
+            __swift_bridge__$Tensor$_free(ptr)
+        }
+    }
+
+    /// Read the elements, laid out according to the strides.
+    public func withUnsafeBytes<R>(_ body: (UnsafeRawBufferPointer) throws -> R) rethrows -> R {
+        try body(UnsafeRawBufferPointer(start: data, count: count * dataType.sizeInBytes))
+    }
+
+    /// Read the elements, laid out according to the strides, such as in order to pass them to vDSP.
+    ///
+    /// Returns `nil` if the elements are not of type `T`.
+    public func withUnsafeBufferPointer<T, R>(of type: T.Type, _ body: (UnsafeBufferPointer<T>) throws -> R) rethrows -> R? {
+        switch dataType {
+        case .float32 where T.self == Float.self, .float64 where T.self == Double.self, .int32 where T.self == Int32.self:
+            break
+        default:
+            return nil
+        }
+
+        return try body(UnsafeBufferPointer(start: data.assumingMemoryBound(to: T.self), count: count))
+    }
+
+    /// An `MLMultiArray` that points at the tensor's elements.
+    public func makeMLMultiArray() throws -> MLMultiArray {
+        let owner = self
+        return try MLMultiArray(
+            dataPointer: UnsafeMutableRawPointer(mutating: data),
+            shape: shape.map { NSNumber(value: $0) },
+            dataType: dataType.mlMultiArrayDataType,
+            strides: strides.map { NSNumber(value: $0) },
+            deallocator: { _ in
+                withExtendedLifetime(owner) {}
+            }
+        )
+    }
+}
@@ -0,0 +1,15 @@
+//! Support for the `swift_bridge::url_session` module, which is enabled by the swift-bridge
+//! crate's `url-session` feature.
+//!
+//! The corresponding Rust code can be found in src/url_session.rs
+
+pub const SWIFT_URL_SESSION_SUPPORT: &str = include_str!("./url_session_support.swift");
+
+pub const C_URL_SESSION_SUPPORT: &str = r#"
+typedef struct __private__FfiHttpHeader { struct RustStr name; struct RustStr value; } __private__FfiHttpHeader;
+void* __swift_bridge__$url_session$response_new(uint16_t status);
+void __swift_bridge__$url_session$response_push_header(void* response, struct RustStr name, struct RustStr value);
+void __swift_bridge__$url_session$response_set_body(void* response, struct __private__FfiSlice body);
+void __swift_bridge__$url_session$on_response(void* on_complete, void* response);
+void __swift_bridge__$url_session$on_error(void* on_complete, struct RustStr message);
+"#;
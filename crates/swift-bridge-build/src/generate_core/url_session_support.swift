@@ -0,0 +1,53 @@
+@_cdecl("__swift_bridge__$url_session$send")
+func __swift_bridge__url_session_send (_ method: RustStr, _ url: RustStr, _ headers: __private__FfiSlice, _ body: __private__FfiSlice, _ onComplete: UnsafeMutableRawPointer) {
+    guard let requestUrl = URL(string: url.toString()) else {
+        __swift_bridge__url_session_on_error(onComplete, "Invalid URL: \(url.toString())")
+        return
+    }
+
+    var request = URLRequest(url: requestUrl)
+    request.httpMethod = method.toString()
+
+    if headers.len > 0 {
+        let headers = UnsafeBufferPointer(start: headers.start.assumingMemoryBound(to: __private__FfiHttpHeader.self), count: Int(headers.len))
+        for header in headers {
+            request.addValue(header.value.toString(), forHTTPHeaderField: header.name.toString())
+        }
+    }
+    if body.len > 0 {
+        request.httpBody = Data(bytes: body.start, count: Int(body.len))
+    }
+
+    URLSession.shared.dataTask(with: request) { data, response, error in
+        if let error = error {
+            __swift_bridge__url_session_on_error(onComplete, error.localizedDescription)
+            return
+        }
+        guard let response = response as? HTTPURLResponse else {
+            __swift_bridge__url_session_on_error(onComplete, "Received a response that was not an HTTP response")
+            return
+        }
+
+        let rustResponse = __swift_bridge__$url_session$response_new(UInt16(response.statusCode))
+        for (name, value) in response.allHeaderFields {
+            "\(name)".toRustStr({ name in
+                "\(value)".toRustStr({ value in
+                    __swift_bridge__$url_session$response_push_header(rustResponse, name, value)
+                })
+            })
+        }
+        if let data = data, data.count > 0 {
+            data.withUnsafeBytes({ bytes in
+                __swift_bridge__$url_session$response_set_body(rustResponse, bytes.bindMemory(to: UInt8.self).toFfiSlice())
+            })
+        }
+
+        __swift_bridge__$url_session$on_response(onComplete, rustResponse)
+    }.resume()
+}
+
+func __swift_bridge__url_session_on_error (_ onComplete: UnsafeMutableRawPointer, _ message: String) {
+    message.toRustStr({ message in
+        __swift_bridge__$url_session$on_error(onComplete, message)
+    })
+}
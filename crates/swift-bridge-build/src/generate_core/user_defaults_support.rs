@@ -0,0 +1,6 @@
+//! Support for the `swift_bridge::user_defaults` module, which is enabled by the swift-bridge
+//! crate's `user-defaults` feature.
+//!
+//! The corresponding Rust code can be found in src/user_defaults.rs
+
+pub const SWIFT_USER_DEFAULTS_SUPPORT: &str = include_str!("./user_defaults_support.swift");
@@ -0,0 +1,69 @@
+@_cdecl("__swift_bridge__$user_defaults$get_bool")
+func __swift_bridge__user_defaults_get_bool (_ key: RustStr) -> __private__OptionBool {
+    (UserDefaults.standard.object(forKey: key.toString()) as? Bool).intoFfiRepr()
+}
+
+@_cdecl("__swift_bridge__$user_defaults$set_bool")
+func __swift_bridge__user_defaults_set_bool (_ key: RustStr, _ value: Bool) {
+    UserDefaults.standard.set(value, forKey: key.toString())
+}
+
+@_cdecl("__swift_bridge__$user_defaults$get_i64")
+func __swift_bridge__user_defaults_get_i64 (_ key: RustStr) -> __private__OptionI64 {
+    (UserDefaults.standard.object(forKey: key.toString()) as? Int64).intoFfiRepr()
+}
+
+@_cdecl("__swift_bridge__$user_defaults$set_i64")
+func __swift_bridge__user_defaults_set_i64 (_ key: RustStr, _ value: Int64) {
+    UserDefaults.standard.set(value, forKey: key.toString())
+}
+
+@_cdecl("__swift_bridge__$user_defaults$get_f64")
+func __swift_bridge__user_defaults_get_f64 (_ key: RustStr) -> __private__OptionF64 {
+    (UserDefaults.standard.object(forKey: key.toString()) as? Double).intoFfiRepr()
+}
+
+@_cdecl("__swift_bridge__$user_defaults$set_f64")
+func __swift_bridge__user_defaults_set_f64 (_ key: RustStr, _ value: Double) {
+    UserDefaults.standard.set(value, forKey: key.toString())
+}
+
+@_cdecl("__swift_bridge__$user_defaults$get_string")
+func __swift_bridge__user_defaults_get_string (_ key: RustStr) -> UnsafeMutableRawPointer? {
+    guard let value = UserDefaults.standard.string(forKey: key.toString()) else {
+        return nil
+    }
+
+    let rustString = RustString(value)
+    rustString.isOwned = false
+    return rustString.ptr
+}
+
+@_cdecl("__swift_bridge__$user_defaults$set_string")
+func __swift_bridge__user_defaults_set_string (_ key: RustStr, _ value: RustStr) {
+    UserDefaults.standard.set(value.toString(), forKey: key.toString())
+}
+
+@_cdecl("__swift_bridge__$user_defaults$get_data")
+func __swift_bridge__user_defaults_get_data (_ key: RustStr) -> UnsafeMutableRawPointer? {
+    guard let data = UserDefaults.standard.data(forKey: key.toString()) else {
+        return nil
+    }
+
+    let vec = RustVec<UInt8>()
+    for byte in data {
+        vec.push(value: byte)
+    }
+    vec.isOwned = false
+    return vec.ptr
+}
+
+@_cdecl("__swift_bridge__$user_defaults$set_data")
+func __swift_bridge__user_defaults_set_data (_ key: RustStr, _ value: __private__FfiSlice) {
+    UserDefaults.standard.set(Data(bytes: value.start, count: Int(value.len)), forKey: key.toString())
+}
+
+@_cdecl("__swift_bridge__$user_defaults$remove")
+func __swift_bridge__user_defaults_remove (_ key: RustStr) {
+    UserDefaults.standard.removeObject(forKey: key.toString())
+}
@@ -0,0 +1,11 @@
+//! Support for the `swift_bridge::uuid` module, which is enabled by the swift-bridge crate's
+//! `uuid` feature.
+//!
+//! The corresponding Rust code can be found in src/uuid.rs
+
+pub const SWIFT_UUID_SUPPORT: &str = include_str!("./uuid_support.swift");
+
+pub const C_UUID_SUPPORT: &str = r#"
+typedef struct __private__Uuid { uint8_t bytes[16]; } __private__Uuid;
+typedef struct __private__OptionUuid { struct __private__Uuid val; bool is_some; } __private__OptionUuid;
+"#;
@@ -0,0 +1,30 @@
+import Foundation
+
+// Swift imports the `uint8_t bytes[16]` field as a tuple of 16 `UInt8`s, which is a `uuid_t`.
+extension __private__Uuid {
+    init(_ uuid: UUID) {
+        self.init(bytes: uuid.uuid)
+    }
+
+    func toUUID() -> UUID {
+        UUID(uuid: bytes)
+    }
+}
+
+extension __private__OptionUuid {
+    init(_ val: Optional<UUID>) {
+        if let val = val {
+            self.init(val: __private__Uuid(val), is_some: true)
+        } else {
+            self.init(val: __private__Uuid(bytes: (0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0)), is_some: false)
+        }
+    }
+
+    func intoSwiftRepr() -> Optional<UUID> {
+        if is_some {
+            return val.toUUID()
+        } else {
+            return nil
+        }
+    }
+}
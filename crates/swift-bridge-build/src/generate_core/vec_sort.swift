@@ -0,0 +1,64 @@
+/// A type whose `RustVec` can be sorted and searched in Rust, such as the `UInt32` in a
+/// `RustVec<UInt32>`.
+///
+/// The comparator closures are called from Rust, so the elements never get copied into Swift.
+public protocol RustVecSortable: Vectorizable {
+    static func vecOfSelfSortBy(vecPtr: UnsafeMutableRawPointer, areInIncreasingOrder: (Self, Self) -> Bool)
+
+    static func vecOfSelfSortedBy(vecPtr: UnsafeMutableRawPointer, areInIncreasingOrder: (Self, Self) -> Bool) -> UnsafeMutableRawPointer
+
+    static func vecOfSelfBinarySearchBy(vecPtr: UnsafeMutableRawPointer, value: Self, areInIncreasingOrder: (Self, Self) -> Bool) -> (found: Bool, index: UInt)
+
+    static func vecOfSelfContains(vecPtr: UnsafeMutableRawPointer, value: Self) -> Bool
+}
+
+extension RustVec where T: RustVecSortable {
+    /// Sort the `RustVec` in place. The sort is stable.
+    public func sort(by areInIncreasingOrder: (T, T) -> Bool) {
+        T.vecOfSelfSortBy(vecPtr: ptr, areInIncreasingOrder: areInIncreasingOrder)
+    }
+
+    /// Returns a sorted copy of the `RustVec`. The copy is allocated by Rust.
+    public func sorted(by areInIncreasingOrder: (T, T) -> Bool) -> RustVec<T> {
+        RustVec(ptr: T.vecOfSelfSortedBy(vecPtr: ptr, areInIncreasingOrder: areInIncreasingOrder))
+    }
+
+    /// Search a `RustVec` that is sorted by `areInIncreasingOrder` for `value`.
+    ///
+    /// If the value is found `index` is the position of a matching element, otherwise it is the
+    /// position where the value could be inserted while keeping the `RustVec` sorted.
+    public func binarySearch(_ value: T, by areInIncreasingOrder: (T, T) -> Bool) -> (found: Bool, index: Int) {
+        let result = T.vecOfSelfBinarySearchBy(vecPtr: ptr, value: value, areInIncreasingOrder: areInIncreasingOrder)
+        return (found: result.found, index: Int(result.index))
+    }
+
+    public func contains(_ value: T) -> Bool {
+        T.vecOfSelfContains(vecPtr: ptr, value: value)
+    }
+}
+
+extension RustVec where T: RustVecSortable & Comparable {
+    public func sort() {
+        self.sort(by: <)
+    }
+
+    public func sorted() -> RustVec<T> {
+        self.sorted(by: <)
+    }
+
+    /// Search a `RustVec` that is sorted in increasing order for `value`.
+    public func binarySearch(_ value: T) -> (found: Bool, index: Int) {
+        self.binarySearch(value, by: <)
+    }
+}
+
+/// Call `body` with a pointer to `areInIncreasingOrder`, which gets passed to Rust as the context
+/// of a comparator callback.
+func __swift_bridge__withComparatorContext<T, R>(_ areInIncreasingOrder: (T, T) -> Bool, _ body: (UnsafeMutableRawPointer) -> R) -> R {
+    withoutActuallyEscaping(areInIncreasingOrder) { areInIncreasingOrder in
+        var areInIncreasingOrder = areInIncreasingOrder
+        return withUnsafeMutablePointer(to: &areInIncreasingOrder) { context in
+            body(UnsafeMutableRawPointer(context))
+        }
+    }
+}
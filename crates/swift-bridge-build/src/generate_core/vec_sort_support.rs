@@ -0,0 +1,99 @@
+//! Sorting and searching a `Vec<T>` where `T` is a primitive, using a comparator closure that
+//! Swift passes to Rust.
+//!
+//! The corresponding Rust code can be found in src/std_bridge/rust_vec.rs
+
+const VEC_SORT_SWIFT: &str = include_str!("./vec_sort.swift");
+
+/// The (Swift type, Rust type, C type) of every primitive whose `Vec<T>` can be sorted and
+/// searched.
+const VEC_SORT_PRIMITIVES: [(&str, &str, &str); 13] = [
+    ("UInt8", "u8", "uint8_t"),
+    ("UInt16", "u16", "uint16_t"),
+    ("UInt32", "u32", "uint32_t"),
+    ("UInt64", "u64", "uint64_t"),
+    ("UInt", "usize", "uintptr_t"),
+    //
+    ("Int8", "i8", "int8_t"),
+    ("Int16", "i16", "int16_t"),
+    ("Int32", "i32", "int32_t"),
+    ("Int64", "i64", "int64_t"),
+    ("Int", "isize", "intptr_t"),
+    //
+    ("Bool", "bool", "bool"),
+    //
+    ("Float", "f32", "float"),
+    ("Double", "f64", "double"),
+];
+
+pub(super) fn swift_vec_sort_support() -> String {
+    let mut swift = VEC_SORT_SWIFT.to_string();
+
+    for (swift_ty, rust_ty, _) in VEC_SORT_PRIMITIVES {
+        swift += &conform_to_rust_vec_sortable(swift_ty, rust_ty);
+    }
+
+    swift
+}
+
+pub(super) fn c_vec_sort_support() -> String {
+    let mut header = "".to_string();
+
+    for (_, rust_ty, c_ty) in VEC_SORT_PRIMITIVES {
+        header += &vec_sort_headers(rust_ty, c_ty);
+    }
+
+    header
+}
+
+/// Headers for sorting and searching a Vec<T> where T is a primitive such as u8, i32, bool
+fn vec_sort_headers(rust_ty: &str, c_ty: &str) -> String {
+    format!(
+        r#"
+void __swift_bridge__$Vec_{rust_ty}$sort_by(void* const vec, void* context, bool (*are_in_increasing_order)(void*, {c_ty}, {c_ty}));
+void* __swift_bridge__$Vec_{rust_ty}$sorted_by(void* const vec, void* context, bool (*are_in_increasing_order)(void*, {c_ty}, {c_ty}));
+bool __swift_bridge__$Vec_{rust_ty}$binary_search_by(void* const vec, {c_ty} value, void* context, bool (*are_in_increasing_order)(void*, {c_ty}, {c_ty}), uintptr_t* index);
+bool __swift_bridge__$Vec_{rust_ty}$contains(void* const vec, {c_ty} value);
+"#
+    )
+}
+
+// A `@convention(c)` closure can't capture generic parameters, so every primitive gets its own
+// comparator callback that calls the Swift closure that `context` points to.
+fn conform_to_rust_vec_sortable(swift_ty: &str, rust_ty: &str) -> String {
+    format!(
+        r#"
+extension {swift_ty}: RustVecSortable {{
+    public static func vecOfSelfSortBy(vecPtr: UnsafeMutableRawPointer, areInIncreasingOrder: ({swift_ty}, {swift_ty}) -> Bool) {{
+        __swift_bridge__withComparatorContext(areInIncreasingOrder) {{ context in
+            __swift_bridge__$Vec_{rust_ty}$sort_by(vecPtr, context, {{ context, lhs, rhs in
+                context!.assumingMemoryBound(to: (({swift_ty}, {swift_ty}) -> Bool).self).pointee(lhs, rhs)
+            }})
+        }}
+    }}
+
+    public static func vecOfSelfSortedBy(vecPtr: UnsafeMutableRawPointer, areInIncreasingOrder: ({swift_ty}, {swift_ty}) -> Bool) -> UnsafeMutableRawPointer {{
+        __swift_bridge__withComparatorContext(areInIncreasingOrder) {{ context in
+            __swift_bridge__$Vec_{rust_ty}$sorted_by(vecPtr, context, {{ context, lhs, rhs in
+                context!.assumingMemoryBound(to: (({swift_ty}, {swift_ty}) -> Bool).self).pointee(lhs, rhs)
+            }})
+        }}
+    }}
+
+    public static func vecOfSelfBinarySearchBy(vecPtr: UnsafeMutableRawPointer, value: {swift_ty}, areInIncreasingOrder: ({swift_ty}, {swift_ty}) -> Bool) -> (found: Bool, index: UInt) {{
+        var index: UInt = 0
+        let found = __swift_bridge__withComparatorContext(areInIncreasingOrder) {{ context in
+            __swift_bridge__$Vec_{rust_ty}$binary_search_by(vecPtr, value, context, {{ context, lhs, rhs in
+                context!.assumingMemoryBound(to: (({swift_ty}, {swift_ty}) -> Bool).self).pointee(lhs, rhs)
+            }}, &index)
+        }}
+        return (found: found, index: index)
+    }}
+
+    public static func vecOfSelfContains(vecPtr: UnsafeMutableRawPointer, value: {swift_ty}) -> Bool {{
+        __swift_bridge__$Vec_{rust_ty}$contains(vecPtr, value)
+    }}
+}}
+"#
+    )
+}
@@ -0,0 +1,6 @@
+//! Support for the `swift_bridge::watch` module, which is enabled by the swift-bridge crate's
+//! `watch` feature.
+//!
+//! The corresponding Rust code can be found in src/watch.rs
+
+pub const SWIFT_WATCH_SUPPORT: &str = include_str!("./watch_support.swift");
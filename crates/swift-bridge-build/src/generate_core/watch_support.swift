@@ -0,0 +1,59 @@
+import Foundation
+
+/// A Rust `swift_bridge::watch::Watch<T>`, where `T` is a shared struct.
+///
+/// Events can be pulled one at a time with `nextEvent()` or read with `for await`. Dropping the
+/// `RustWatch` drops the Rust `Watch`, which stops the watcher that was producing its events.
+///
+/// A `RustWatch` must not be read from more than one thread at a time.
+public class RustWatch<Event> {
+    var ptr: UnsafeMutableRawPointer
+    var isOwned: Bool = true
+    let nextFn: (UnsafeMutableRawPointer) -> Event?
+    let freeFn: (UnsafeMutableRawPointer) -> Void
+
+    public init(
+        ptr: UnsafeMutableRawPointer,
+        next: @escaping (UnsafeMutableRawPointer) -> Event?,
+        free: @escaping (UnsafeMutableRawPointer) -> Void
+    ) {
+        self.ptr = ptr
+        self.nextFn = next
+        self.freeFn = free
+    }
+
+    deinit {
+        if isOwned {
+            freeFn(ptr)
+        }
+    }
+
+    /// Returns the next event, or `nil` once the watch has ended.
+    ///
+    /// Blocks until Rust produces the event.
+    public func nextEvent() -> Event? {
+        nextFn(ptr)
+    }
+}
+
+extension RustWatch: AsyncSequence {
+    public typealias Element = Event
+
+    public func makeAsyncIterator() -> RustWatchAsyncIterator<Event> {
+        RustWatchAsyncIterator(watch: self)
+    }
+}
+
+/// Reads a `RustWatch` without blocking the task that is awaiting the next event.
+public struct RustWatchAsyncIterator<Event>: AsyncIteratorProtocol {
+    let watch: RustWatch<Event>
+
+    public mutating func next() async -> Event? {
+        let watch = self.watch
+        return await withCheckedContinuation { continuation in
+            DispatchQueue.global().async {
+                continuation.resume(returning: watch.nextEvent())
+            }
+        }
+    }
+}
@@ -1,17 +1,23 @@
-//! Parse Rust source files for #\[swift_bridge::bridge\] modules and then generate the
-//! corresponding C header files and Swift files.
+//! Parse Rust source files for #\[swift_bridge::bridge\] modules, #\[swift_bridge::bridged\]
+//! types and #\[swift_bridge::export\] impl blocks and then generate the corresponding C header
+//! files and Swift files.
 
 #![deny(missing_docs)]
 
 mod package;
-use crate::generate_core::write_core_swift_and_c;
+use crate::generate_core::{write_core_swift_and_c, CoreSupport};
 pub use package::*;
 use std::path::Path;
-use swift_bridge_ir::{CodegenConfig, SwiftBridgeModule};
+use swift_bridge_ir::{
+    BridgedItem, CodegenConfig, ExportedImpl, SwiftBridgeModule, SwiftCodeAndCHeader,
+};
+pub use swift_bridge_ir::{HashedSymbol, PublicApiReport, UnbridgeableItem, UnbridgeableReason};
 use syn::__private::ToTokens;
-use syn::{File, Item};
+use syn::{Attribute, File, Item};
 
+mod direct_calls;
 mod generate_core;
+mod objc_header;
 
 /// Parse rust sources files for `#\[swift_bridge::bridge\]` headers and generate the corresponding
 /// Swift files.
@@ -24,7 +30,7 @@ pub fn parse_bridges(
         let rust_file: &Path = rust_file.as_ref();
 
         let file = std::fs::read_to_string(rust_file).unwrap();
-        let gen = match parse_file_contents(&file) {
+        let generated = match parse_file_contents(&file) {
             Ok(generated) => generated,
             Err(e) => {
                 // TODO: Return an error...
@@ -38,35 +44,343 @@ Error while parsing {:?}
             }
         };
 
-        generated_code.generated.push(gen);
+        generated_code.generated.extend(generated);
     }
 
     generated_code
 }
 
+/// Scan the public API of a crate's Rust source files and report which functions, methods and
+/// types could not be bridged automatically, and why.
+///
+/// This can be used to estimate how much wrapper code a library will need before bridging it.
+///
+/// ```no_run
+/// let report = swift_bridge_build::report_unbridgeable_api(vec!["src/lib.rs"]);
+/// println!("{}", report);
+/// ```
+pub fn report_unbridgeable_api(
+    rust_source_files: impl IntoIterator<Item = impl AsRef<Path>>,
+) -> PublicApiReport {
+    let mut files = vec![];
+
+    for rust_file in rust_source_files.into_iter() {
+        let rust_file: &Path = rust_file.as_ref();
+
+        let contents = std::fs::read_to_string(rust_file).unwrap();
+        let file: File = match syn::parse_str(&contents) {
+            Ok(file) => file,
+            Err(e) => panic!(
+                r#"
+Error while parsing {:?}
+{}
+"#,
+                rust_file, e
+            ),
+        };
+
+        files.push(file);
+    }
+
+    PublicApiReport::new(&files).unwrap()
+}
+
 /// Generated Swift files and C headers.
 pub struct GeneratedCode {
     generated: Vec<GeneratedFromSwiftBridgeModule>,
+    core_support: CoreSupport,
+    objc_header: Option<ObjcShimAnnotation>,
+    direct_calls: bool,
+    output_groups: Vec<OutputGroup>,
+}
+
+/// A file that the code for some of the bridged types is written to.
+///
+/// See [`GeneratedCode::with_output_group`].
+struct OutputGroup {
+    name: String,
+    types: Vec<String>,
 }
 
 impl GeneratedCode {
     fn new() -> Self {
-        GeneratedCode { generated: vec![] }
+        GeneratedCode {
+            generated: vec![],
+            core_support: CoreSupport::default(),
+            objc_header: None,
+            direct_calls: false,
+            output_groups: vec![],
+        }
+    }
+}
+
+/// How the raw FFI functions are annotated in the header that is written by
+/// [`GeneratedCode::with_objc_header`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ObjcShimAnnotation {
+    /// Like `NS_REFINED_FOR_SWIFT`. Swift imports the functions with a `__` prefix, which hides
+    /// them from code completion.
+    RefinedForSwift,
+    /// Like `NS_SWIFT_UNAVAILABLE`. Swift cannot call the functions.
+    SwiftUnavailable,
+}
+
+/// Which builds the debug-only checks in the generated Swift code are compiled into.
+///
+/// See [`GeneratedCode::with_debug_checks`].
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum DebugChecks {
+    /// Run the checks in every build. This is the default.
+    #[default]
+    Always,
+    /// Only run the checks when the Swift code is compiled with the `DEBUG` compilation condition,
+    /// which Xcode and Swift Package Manager set for debug builds. In other builds the checks
+    /// compile to nothing.
+    DebugBuildsOnly,
+    /// Never run the checks.
+    Never,
+}
+
+impl GeneratedCode {
+    /// Include the Swift implementation of the `swift_bridge::keychain` module in the generated
+    /// SwiftBridgeCore.swift file.
+    ///
+    /// This is needed when the swift-bridge crate's `keychain` feature is enabled.
+    /// The generated Swift code imports the `Security` framework.
+    pub fn with_keychain_support(mut self) -> Self {
+        self.core_support.keychain = true;
+        self
+    }
+
+    /// Include the Swift implementation of the `swift_bridge::user_defaults` module in the
+    /// generated SwiftBridgeCore.swift file.
+    ///
+    /// This is needed when the swift-bridge crate's `user-defaults` feature is enabled.
+    pub fn with_user_defaults_support(mut self) -> Self {
+        self.core_support.user_defaults = true;
+        self
+    }
+
+    /// Include the Swift implementation of the `swift_bridge::url_session` module in the
+    /// generated SwiftBridgeCore.swift file.
+    ///
+    /// This is needed when the swift-bridge crate's `url-session` feature is enabled.
+    pub fn with_url_session_support(mut self) -> Self {
+        self.core_support.url_session = true;
+        self
+    }
+
+    /// Include the Swift implementation of the `swift_bridge::reachability` module in the
+    /// generated SwiftBridgeCore.swift file.
+    ///
+    /// This is needed when the swift-bridge crate's `reachability` feature is enabled.
+    /// The generated Swift code imports the `Network` framework.
+    pub fn with_reachability_support(mut self) -> Self {
+        self.core_support.reachability = true;
+        self
+    }
+
+    /// Include the Swift and C code that converts the types in the `swift_bridge::measurement`
+    /// module to and from Foundation `Measurement`s.
+    ///
+    /// This is needed when the swift-bridge crate's `measurement` feature is enabled.
+    pub fn with_measurement_support(mut self) -> Self {
+        self.core_support.measurement = true;
+        self
+    }
+
+    /// Include the Swift implementation of the `swift_bridge::formatting` module in the
+    /// generated SwiftBridgeCore.swift file.
+    ///
+    /// This is needed when the swift-bridge crate's `formatting` feature is enabled.
+    pub fn with_formatting_support(mut self) -> Self {
+        self.core_support.formatting = true;
+        self
+    }
+
+    /// Include the Swift and C code that lets Swift use a `swift_bridge::image_buffer::ImageBuffer`
+    /// as a `RustImageBuffer`.
+    ///
+    /// This is needed when the swift-bridge crate's `image-buffer` feature is enabled.
+    /// The generated Swift code imports the `CoreGraphics` and `CoreVideo` frameworks.
+    pub fn with_image_buffer_support(mut self) -> Self {
+        self.core_support.image_buffer = true;
+        self
+    }
+
+    /// Include the Swift implementation of the `swift_bridge::pixel_buffer` module in the
+    /// generated SwiftBridgeCore.swift file.
+    ///
+    /// This is needed when the swift-bridge crate's `pixel-buffer` feature is enabled.
+    /// The generated Swift code imports the `CoreVideo` framework.
+    pub fn with_pixel_buffer_support(mut self) -> Self {
+        self.core_support.pixel_buffer = true;
+        self
+    }
+
+    /// Include the Swift code that converts a `serde_json::Value` to and from the `Any` that
+    /// `JSONSerialization` produces.
+    ///
+    /// This is needed when the swift-bridge crate's `serde-json` feature is enabled.
+    pub fn with_serde_json_support(mut self) -> Self {
+        self.core_support.serde_json = true;
+        self
+    }
+
+    /// Include the Swift and C code that lets Swift use a `swift_bridge::tensor::Tensor` as a
+    /// `RustTensor`.
+    ///
+    /// This is needed when the swift-bridge crate's `tensor` feature is enabled.
+    /// The generated Swift code imports the `CoreML` framework.
+    pub fn with_tensor_support(mut self) -> Self {
+        self.core_support.tensor = true;
+        self
+    }
+
+    /// Include the Swift and C code that lets Swift read a `swift_bridge::byte_stream::ByteStream`
+    /// as a `RustByteStream`.
+    ///
+    /// This is needed when the swift-bridge crate's `byte-stream` feature is enabled.
+    pub fn with_byte_stream_support(mut self) -> Self {
+        self.core_support.byte_stream = true;
+        self
+    }
+
+    /// Include the Swift code that decompresses the values that
+    /// `#[swift_bridge(compress = "...")]` functions return.
+    ///
+    /// This is needed when the swift-bridge crate's `compression` feature is enabled.
+    /// The generated Swift code imports the `Compression` framework.
+    pub fn with_compression_support(mut self) -> Self {
+        self.core_support.compression = true;
+        self
+    }
+
+    /// Include the Swift and C code that converts a `uuid::Uuid` to and from a Foundation `UUID`.
+    ///
+    /// This is needed when the swift-bridge crate's `uuid` feature is enabled.
+    pub fn with_uuid_support(mut self) -> Self {
+        self.core_support.uuid = true;
+        self
+    }
+
+    /// Include the Swift code that lets Swift read a `swift_bridge::watch::Watch<T>` as a
+    /// `RustWatch<T>`.
+    ///
+    /// This is needed when the swift-bridge crate's `watch` feature is enabled.
+    pub fn with_watch_support(mut self) -> Self {
+        self.core_support.watch = true;
+        self
+    }
+
+    /// Include the Swift and C code that lets Swift read a `swift_bridge::cursor::Cursor<T>` as a
+    /// `RustCursor<T>`.
+    ///
+    /// This is needed when the swift-bridge crate's `cursor` feature is enabled.
+    pub fn with_cursor_support(mut self) -> Self {
+        self.core_support.cursor = true;
+        self
+    }
+
+    /// Also write a `{crate_name}-ObjC.h` header for Objective-C consumers, where every function
+    /// declaration is annotated so that Swift users are steered towards the generated Swift
+    /// wrappers.
+    ///
+    /// The generated Swift code calls the unannotated functions, so it must still be compiled
+    /// against the regular `{crate_name}.h` header.
+    pub fn with_objc_header(mut self, annotation: ObjcShimAnnotation) -> Self {
+        self.objc_header = Some(annotation);
+        self
+    }
+
+    /// Declare the Rust functions that Swift calls in the generated Swift file using
+    /// `@_silgen_name`, instead of in the generated `{crate_name}.h` header.
+    ///
+    /// `@_silgen_name` functions use the Swift calling convention, which only passes integers,
+    /// floats, bools and pointers the same way as C on the arm64 and x86_64 Apple platforms.
+    /// So only functions whose arguments and return value are all of those types are declared
+    /// this way, and only those platforms are supported. Functions that take or return a struct,
+    /// along with typedefs for shared types, stay in the header.
+    ///
+    /// If nothing is left in the header it is not written, so a Swift-only consumer such as a
+    /// Swift package only needs the `SwiftBridgeCore.h` header.
+    pub fn with_direct_calls(mut self) -> Self {
+        self.direct_calls = true;
+        self
+    }
+
+    /// Write the generated code for some of the bridged types to their own `{group}.swift` and
+    /// `{group}.h` files, instead of to the `{crate_name}.swift` and `{crate_name}.h` files.
+    ///
+    /// `types` are the Rust names of the types, such as `"ApiClient"`. A bridge module's code is
+    /// written to the group of the types that it declares, so a bridge module cannot declare
+    /// types from more than one group. Functions and types that aren't in a group stay in the
+    /// crate's files.
+    ///
+    /// `{crate_name}.h` includes every group's header, in the order that the groups were added. A
+    /// group whose declarations use the types of another group should be added after it.
+    ///
+    /// ```no_run
+    /// swift_bridge_build::parse_bridges(vec!["src/lib.rs"])
+    ///     .with_output_group("Networking", ["ApiClient", "Request"])
+    ///     .with_output_group("Storage", ["Database"])
+    ///     .write_all_concatenated("./generated", "my_crate");
+    /// ```
+    pub fn with_output_group(
+        mut self,
+        group: &str,
+        types: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.output_groups.push(OutputGroup {
+            name: group.to_string(),
+            types: types.into_iter().map(Into::into).collect(),
+        });
+        self
+    }
+
+    /// Choose which builds the debug-only checks in the generated Swift code are compiled into,
+    /// such as the `assertDiscriminantsMatchRust()` method that
+    /// `#[swift_bridge(discriminant_check)]` generates.
+    ///
+    /// All of these checks are controlled from this one setting, which is written to the
+    /// generated SwiftBridgeCore.swift file.
+    pub fn with_debug_checks(mut self, debug_checks: DebugChecks) -> Self {
+        self.core_support.debug_checks = debug_checks;
+        self
     }
 }
 
 impl GeneratedCode {
     /// Write all of the generated Swift to a single Swift file and all of the generated C headers
     /// to a single header file.
+    ///
+    /// The code for the types in an output group is written to the group's own files instead.
+    /// See [`GeneratedCode::with_output_group`].
     pub fn write_all_concatenated(&self, swift_bridge_out_dir: impl AsRef<Path>, crate_name: &str) {
         let swift_bridge_out_dir = swift_bridge_out_dir.as_ref();
 
-        let mut concatenated_swift = "".to_string();
+        let mut ungrouped = GeneratedFiles::default();
+        let mut grouped: Vec<GeneratedFiles> = self
+            .output_groups
+            .iter()
+            .map(|_| GeneratedFiles::default())
+            .collect();
         let mut concatenated_c = "".to_string();
+        let mut symbol_map = "".to_string();
 
         for gen in &self.generated {
-            concatenated_swift += &gen.swift;
+            let files = match self.output_group_index(gen) {
+                Some(idx) => &mut grouped[idx],
+                None => &mut ungrouped,
+            };
+            files.swift += &gen.swift;
+            files.c_header += &gen.c_header;
+
             concatenated_c += &gen.c_header;
+
+            for symbol in &gen.hashed_symbols {
+                symbol_map += &format!("{} {}\n", symbol.hashed, symbol.original);
+            }
         }
 
         let out = swift_bridge_out_dir.join(&crate_name);
@@ -75,14 +389,92 @@ impl GeneratedCode {
             Err(_) => {}
         };
 
-        std::fs::write(out.join(format!("{}.h", crate_name)), concatenated_c).unwrap();
-        std::fs::write(
-            out.join(format!("{}.swift", crate_name)),
-            concatenated_swift,
-        )
-        .unwrap();
+        // Objective-C can't see `@_silgen_name` declarations, so its header declares everything.
+        if let Some(annotation) = self.objc_header {
+            std::fs::write(
+                out.join(format!("{}-ObjC.h", crate_name)),
+                objc_header::annotate_c_header(&concatenated_c, annotation),
+            )
+            .unwrap();
+        }
+
+        if !symbol_map.is_empty() {
+            std::fs::write(out.join(format!("{}.symbols", crate_name)), symbol_map).unwrap();
+        }
+
+        let mut group_includes = "".to_string();
+        for (group, files) in self.output_groups.iter().zip(grouped) {
+            if self.write_swift_and_c_header(&out, &group.name, files) {
+                group_includes += &format!("#include \"{}.h\"\n", group.name);
+            }
+        }
+
+        ungrouped.c_header = format!("{}{}", group_includes, ungrouped.c_header);
+        self.write_swift_and_c_header(&out, crate_name, ungrouped);
+
+        write_core_swift_and_c(swift_bridge_out_dir.as_ref(), &self.core_support);
+    }
+
+    /// Write a `{name}.swift` file and, unless [`GeneratedCode::with_direct_calls`] left it
+    /// empty, a `{name}.h` file.
+    ///
+    /// Returns whether or not the header was written.
+    fn write_swift_and_c_header(&self, out: &Path, name: &str, files: GeneratedFiles) -> bool {
+        let GeneratedFiles {
+            mut swift,
+            c_header,
+        } = files;
+
+        let c_header_out = out.join(format!("{}.h", name));
+        let wrote_c_header = if self.direct_calls {
+            let direct_calls = direct_calls::split_c_header(&c_header);
+            swift = format!("{}\n{}", direct_calls.swift, swift);
+
+            if direct_calls.c_header_is_needed() {
+                std::fs::write(c_header_out, direct_calls.c_header).unwrap();
+                true
+            } else {
+                // Don't leave a header from a previous build for a Swift package to pick up.
+                let _ = std::fs::remove_file(c_header_out);
+                false
+            }
+        } else {
+            std::fs::write(c_header_out, c_header).unwrap();
+            true
+        };
+        std::fs::write(out.join(format!("{}.swift", name)), swift).unwrap();
+
+        wrote_c_header
+    }
+
+    /// The output group that a bridge module's code is written to, if any of the types that it
+    /// declares are in one.
+    fn output_group_index(&self, gen: &GeneratedFromSwiftBridgeModule) -> Option<usize> {
+        let mut group_idx: Option<usize> = None;
+
+        for ty in &gen.declared_types {
+            let idx = match self
+                .output_groups
+                .iter()
+                .position(|group| group.types.contains(ty))
+            {
+                Some(idx) => idx,
+                None => continue,
+            };
 
-        write_core_swift_and_c(swift_bridge_out_dir.as_ref());
+            match group_idx {
+                Some(existing) if existing != idx => panic!(
+                    r#"
+A bridge module declares types from the "{}" and "{}" output groups.
+Declare the types of each output group in their own bridge module.
+"#,
+                    self.output_groups[existing].name, self.output_groups[idx].name
+                ),
+                _ => group_idx = Some(idx),
+            }
+        }
+
+        group_idx
     }
 
     /// Concatenate all of the generated Swift code into one file.
@@ -108,13 +500,10 @@ impl GeneratedCode {
     }
 }
 
-fn parse_file_contents(file: &str) -> syn::Result<GeneratedFromSwiftBridgeModule> {
+fn parse_file_contents(file: &str) -> syn::Result<Vec<GeneratedFromSwiftBridgeModule>> {
     let file: File = syn::parse_str(file)?;
 
-    let mut generated = GeneratedFromSwiftBridgeModule {
-        c_header: "".to_string(),
-        swift: "".to_string(),
-    };
+    let mut generated = vec![];
 
     for item in file.items {
         match item {
@@ -127,24 +516,31 @@ fn parse_file_contents(file: &str) -> syn::Result<GeneratedFromSwiftBridgeModule
                 }) {
                     let module: SwiftBridgeModule = syn::parse2(module.to_token_stream())?;
 
-                    let config = CodegenConfig {
-                        crate_feature_lookup: Box::new(|feature_name| {
-                            let normalized_feature_name = feature_name.replace("-", "_");
-                            let normalized_feature_name = normalized_feature_name.to_uppercase();
-
-                            let env_var_name = format!("CARGO_FEATURE_{}", normalized_feature_name);
-                            std::env::var(env_var_name).is_ok()
-                        }),
-                    };
-                    let swift_and_c = module.generate_swift_code_and_c_header(config);
+                    let swift_and_c = module.generate_swift_code_and_c_header(codegen_config());
+                    generated.push(GeneratedFromSwiftBridgeModule::new(swift_and_c));
+                }
+            }
+            Item::Struct(_) | Item::Enum(_)
+                if item_attrs(&item).iter().any(|a| {
+                    let attrib = a.path.to_token_stream().to_string();
+                    attrib == "swift_bridge :: bridged" || attrib == "swift_bridge_macro :: bridged"
+                }) =>
+            {
+                let bridged: BridgedItem = syn::parse2(item.to_token_stream())?;
 
-                    generated.c_header += &swift_and_c.c_header;
-                    generated.c_header += "\n\n";
+                let swift_and_c = bridged.generate_swift_code_and_c_header(codegen_config());
+                generated.push(GeneratedFromSwiftBridgeModule::new(swift_and_c));
+            }
+            Item::Impl(_)
+                if item_attrs(&item).iter().any(|a| {
+                    let attrib = a.path.to_token_stream().to_string();
+                    attrib == "swift_bridge :: export" || attrib == "swift_bridge_macro :: export"
+                }) =>
+            {
+                let exported: ExportedImpl = syn::parse2(item.to_token_stream())?;
 
-                    let swift = &swift_and_c.swift;
-                    generated.swift += &swift;
-                    generated.swift += "\n\n";
-                }
+                let swift_and_c = exported.generate_swift_code_and_c_header(codegen_config());
+                generated.push(GeneratedFromSwiftBridgeModule::new(swift_and_c));
             }
             _ => {}
         }
@@ -153,8 +549,49 @@ fn parse_file_contents(file: &str) -> syn::Result<GeneratedFromSwiftBridgeModule
     Ok(generated)
 }
 
+fn codegen_config() -> CodegenConfig {
+    CodegenConfig {
+        crate_feature_lookup: Box::new(|feature_name| {
+            let normalized_feature_name = feature_name.replace("-", "_");
+            let normalized_feature_name = normalized_feature_name.to_uppercase();
+
+            let env_var_name = format!("CARGO_FEATURE_{}", normalized_feature_name);
+            std::env::var(env_var_name).is_ok()
+        }),
+    }
+}
+
+fn item_attrs(item: &Item) -> &[Attribute] {
+    match item {
+        Item::Struct(item_struct) => &item_struct.attrs,
+        Item::Enum(item_enum) => &item_enum.attrs,
+        Item::Impl(item_impl) => &item_impl.attrs,
+        _ => &[],
+    }
+}
+
 #[derive(Debug)]
 struct GeneratedFromSwiftBridgeModule {
     c_header: String,
     swift: String,
+    hashed_symbols: Vec<HashedSymbol>,
+    declared_types: Vec<String>,
+}
+
+impl GeneratedFromSwiftBridgeModule {
+    fn new(swift_and_c: SwiftCodeAndCHeader) -> Self {
+        GeneratedFromSwiftBridgeModule {
+            c_header: swift_and_c.c_header + "\n\n",
+            swift: swift_and_c.swift + "\n\n",
+            hashed_symbols: swift_and_c.hashed_symbols,
+            declared_types: swift_and_c.declared_types,
+        }
+    }
+}
+
+/// The generated Swift and C header that get written to one `.swift` file and one `.h` file.
+#[derive(Default)]
+struct GeneratedFiles {
+    swift: String,
+    c_header: String,
 }
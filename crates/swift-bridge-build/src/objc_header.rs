@@ -0,0 +1,128 @@
+use crate::ObjcShimAnnotation;
+
+/// Annotate every function declaration in a generated C header so that Swift steers users
+/// towards the generated Swift wrappers.
+///
+/// Each function is declared on its own line, so we annotate every line that ends with `);` and
+/// isn't a typedef or preprocessor directive.
+pub(crate) fn annotate_c_header(c_header: &str, annotation: ObjcShimAnnotation) -> String {
+    let macro_name = annotation.macro_name();
+
+    let mut annotated = format!(
+        r#"#ifndef {macro_name}
+#define {macro_name} {attribute}
+#endif
+"#,
+        macro_name = macro_name,
+        attribute = annotation.attribute()
+    );
+
+    for line in c_header.lines() {
+        let trimmed = line.trim();
+        let is_fn_declaration =
+            trimmed.ends_with(");") && !trimmed.starts_with("typedef") && !trimmed.starts_with('#');
+
+        if is_fn_declaration {
+            annotated += &trimmed[..trimmed.len() - 1];
+            annotated += " ";
+            annotated += macro_name;
+            annotated += ";";
+        } else {
+            annotated += line;
+        }
+        annotated += "\n";
+    }
+
+    annotated
+}
+
+impl ObjcShimAnnotation {
+    fn macro_name(&self) -> &'static str {
+        match self {
+            ObjcShimAnnotation::RefinedForSwift => "SWIFT_BRIDGE_REFINED_FOR_SWIFT",
+            ObjcShimAnnotation::SwiftUnavailable => "SWIFT_BRIDGE_SWIFT_UNAVAILABLE",
+        }
+    }
+
+    /// The same attributes that Foundation's `NS_REFINED_FOR_SWIFT` and `NS_SWIFT_UNAVAILABLE`
+    /// macros expand to, so that the header does not need to import Foundation.
+    fn attribute(&self) -> &'static str {
+        match self {
+            ObjcShimAnnotation::RefinedForSwift => "__attribute__((swift_private))",
+            ObjcShimAnnotation::SwiftUnavailable => {
+                r#"__attribute__((availability(swift, unavailable, message="Use the generated Swift wrapper instead.")))"#
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify that every function declaration is annotated.
+    #[test]
+    fn annotates_function_declarations() {
+        let c_header = r#"void* __swift_bridge__$Foo$new(void);
+    uint32_t __swift_bridge__$add(uint32_t lhs, uint32_t rhs);
+"#;
+
+        let annotated = annotate_c_header(c_header, ObjcShimAnnotation::RefinedForSwift);
+
+        assert!(annotated
+            .contains("void* __swift_bridge__$Foo$new(void) SWIFT_BRIDGE_REFINED_FOR_SWIFT;\n"));
+        assert!(annotated.contains(
+            "uint32_t __swift_bridge__$add(uint32_t lhs, uint32_t rhs) SWIFT_BRIDGE_REFINED_FOR_SWIFT;\n"
+        ));
+    }
+
+    /// Verify that typedefs, preprocessor directives and blank lines are not annotated.
+    #[test]
+    fn does_not_annotate_typedef_preprocessor_or_blank_lines() {
+        let c_header = r#"#include <stdint.h>
+
+typedef void (*__swift_bridge__$Callback)(void);
+#define SOME_MACRO(x) (x);
+"#;
+
+        let annotated = annotate_c_header(c_header, ObjcShimAnnotation::SwiftUnavailable);
+
+        assert!(annotated.ends_with(c_header));
+    }
+
+    /// Verify that the header defines the macro for `ObjcShimAnnotation::RefinedForSwift`.
+    #[test]
+    fn refined_for_swift_annotation() {
+        let annotated = annotate_c_header(
+            "void __swift_bridge__$run(void);",
+            ObjcShimAnnotation::RefinedForSwift,
+        );
+
+        assert_eq!(
+            annotated,
+            r#"#ifndef SWIFT_BRIDGE_REFINED_FOR_SWIFT
+#define SWIFT_BRIDGE_REFINED_FOR_SWIFT __attribute__((swift_private))
+#endif
+void __swift_bridge__$run(void) SWIFT_BRIDGE_REFINED_FOR_SWIFT;
+"#
+        );
+    }
+
+    /// Verify that the header defines the macro for `ObjcShimAnnotation::SwiftUnavailable`.
+    #[test]
+    fn swift_unavailable_annotation() {
+        let annotated = annotate_c_header(
+            "void __swift_bridge__$run(void);",
+            ObjcShimAnnotation::SwiftUnavailable,
+        );
+
+        assert_eq!(
+            annotated,
+            r#"#ifndef SWIFT_BRIDGE_SWIFT_UNAVAILABLE
+#define SWIFT_BRIDGE_SWIFT_UNAVAILABLE __attribute__((availability(swift, unavailable, message="Use the generated Swift wrapper instead.")))
+#endif
+void __swift_bridge__$run(void) SWIFT_BRIDGE_SWIFT_UNAVAILABLE;
+"#
+        );
+    }
+}
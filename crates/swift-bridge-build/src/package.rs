@@ -18,6 +18,11 @@ pub struct CreatePackageConfig {
     pub out_dir: PathBuf,
     /// The name for the Swift package
     pub package_name: String,
+    /// Compile the Swift package's code so that it can be optimized together with the Rust
+    /// libraries when the final app is linked.
+    ///
+    /// The Rust libraries in `paths` must have been built using [`CrossLanguageLto::rustflags`].
+    pub lto: Option<CrossLanguageLto>,
 }
 
 impl CreatePackageConfig {
@@ -33,6 +38,71 @@ impl CreatePackageConfig {
             paths,
             out_dir,
             package_name,
+            lto: None,
+        }
+    }
+
+    /// Set the kind of cross-language link-time optimization to compile the package with.
+    pub fn with_lto(mut self, lto: CrossLanguageLto) -> Self {
+        self.lto = Some(lto);
+        self
+    }
+}
+
+/// Link-time optimization across the Rust and Swift code.
+///
+/// Normally the Rust static library holds machine code, so the linker can't inline a small Rust
+/// function into the Swift code that calls it. When both sides are compiled to LLVM bitcode the
+/// linker can optimize them as a single program, removing unused code and inlining across the
+/// FFI boundary.
+///
+/// The bitcode from `rustc` is read by the LLVM that ships with Xcode, so the Rust toolchain's LLVM
+/// version (`rustc -vV`) must not be newer than Xcode's (`swiftc --version`).
+///
+/// ```no_run
+/// # use std::collections::HashMap;
+/// # use std::path::PathBuf;
+/// use swift_bridge_build::{CreatePackageConfig, CrossLanguageLto};
+///
+/// // Build the Rust libraries with
+/// // `RUSTFLAGS="-Clinker-plugin-lto" cargo build --release --target ...`
+/// assert_eq!(CrossLanguageLto::Thin.rustflags(), &["-Clinker-plugin-lto"]);
+///
+/// # let paths = HashMap::new();
+/// swift_bridge_build::create_package(
+///     CreatePackageConfig::new(
+///         PathBuf::from("./generated"),
+///         paths,
+///         PathBuf::from("MySwiftPackage"),
+///         "MySwiftPackage".to_string(),
+///     )
+///     .with_lto(CrossLanguageLto::Thin),
+/// );
+/// ```
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum CrossLanguageLto {
+    /// ThinLTO, which links much faster than full LTO and inlines most of the same calls.
+    Thin,
+    /// Full LTO, which optimizes all of the code as a single module.
+    Full,
+}
+
+impl CrossLanguageLto {
+    /// The flags to pass to `rustc`, i.e. using `RUSTFLAGS`, when building the Rust static
+    /// libraries.
+    ///
+    /// This makes the static libraries hold LLVM bitcode instead of machine code, which both
+    /// kinds of LTO can use.
+    pub fn rustflags(&self) -> &'static [&'static str] {
+        &["-Clinker-plugin-lto"]
+    }
+
+    /// The flags to pass to `swiftc` when building the Swift code that calls into Rust, i.e.
+    /// using Xcode's `OTHER_SWIFT_FLAGS` build setting.
+    pub fn swiftc_flags(&self) -> &'static [&'static str] {
+        match self {
+            CrossLanguageLto::Thin => &["-lto=llvm-thin"],
+            CrossLanguageLto::Full => &["-lto=llvm-full"],
         }
     }
 }
@@ -154,32 +224,34 @@ fn gen_xcframework(output_dir: &Path, config: &CreatePackageConfig) {
             }
         })
         .expect("Couldn't find project directory inside of generated directory");
+    // Projects built with `GeneratedCode::with_direct_calls` might not have a header.
     let bridge_project_header_dir = fs::read_dir(&bridge_project_dir)
         .expect("Couldn't read generated directory")
         .find_map(|file| {
             let file = file.unwrap().path();
-            if file.extension().unwrap() == "h" {
+            if file.extension() == Some("h".as_ref()) {
                 Some(file)
             } else {
                 None
             }
-        })
-        .expect("Couldn't find project's header file");
-    fs::copy(
-        &bridge_project_header_dir,
-        &include_dir.join(&bridge_project_header_dir.file_name().unwrap()),
-    )
-    .expect("Couldn't copy project's header file");
-    writeln!(
-        modulemap_file,
-        "    header \"{}\"",
-        bridge_project_header_dir
-            .file_name()
-            .unwrap()
-            .to_str()
-            .unwrap()
-    )
-    .expect("Couldn't write to modulemap");
+        });
+    if let Some(bridge_project_header_dir) = bridge_project_header_dir {
+        fs::copy(
+            &bridge_project_header_dir,
+            &include_dir.join(&bridge_project_header_dir.file_name().unwrap()),
+        )
+        .expect("Couldn't copy project's header file");
+        writeln!(
+            modulemap_file,
+            "    header \"{}\"",
+            bridge_project_header_dir
+                .file_name()
+                .unwrap()
+                .to_str()
+                .unwrap()
+        )
+        .expect("Couldn't write to modulemap");
+    }
     writeln!(modulemap_file, "    export *\n}}").expect("Couldn't write to modulemap");
 
     // Copy libraries
@@ -311,6 +383,17 @@ fn gen_package(output_dir: &Path, config: &CreatePackageConfig) {
 
     // Generate Package.swift
     let package_name = &config.package_name;
+    let swift_settings = match config.lto {
+        Some(lto) => format!(
+            ",\n\t\t\tswiftSettings: [.unsafeFlags([{}])]",
+            lto.swiftc_flags()
+                .iter()
+                .map(|flag| format!("\"{}\"", flag))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        None => "".to_string(),
+    };
     let package_swift = format!(
         r#"// swift-tools-version:5.5.0
 import PackageDescription
@@ -329,7 +412,7 @@ let package = Package(
 		),
 		.target(
 			name: "{package_name}",
-			dependencies: ["RustXcframework"])
+			dependencies: ["RustXcframework"]{swift_settings})
 	]
 )
 	"#
@@ -100,4 +100,12 @@ fn create_package_command() -> Command<'static> {
                 .required(true)
                 .help("The name for the Swift Package"),
         )
+        .arg(
+            Arg::new("lto")
+                .long("lto")
+                .takes_value(true)
+                .possible_values(["thin", "full"])
+                .value_name("KIND")
+                .help("Compile the Swift Package for cross-language LTO with Rust libraries built using -Clinker-plugin-lto"),
+        )
 }
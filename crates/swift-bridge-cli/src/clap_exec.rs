@@ -1,7 +1,7 @@
 use clap::ArgMatches;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
-use swift_bridge_build::{create_package, ApplePlatform, CreatePackageConfig};
+use swift_bridge_build::{create_package, ApplePlatform, CreatePackageConfig, CrossLanguageLto};
 
 /// Executes the correct function depending on the cli input
 pub fn handle_matches(matches: ArgMatches) {
@@ -24,6 +24,11 @@ fn handle_create_package(matches: &ArgMatches) {
         paths: HashMap::new(),
         out_dir: out_dir.to_path_buf(),
         package_name: name.to_string(),
+        lto: match matches.value_of("lto") {
+            Some("thin") => Some(CrossLanguageLto::Thin),
+            Some("full") => Some(CrossLanguageLto::Full),
+            _ => None,
+        },
     };
 
     for platform in ApplePlatform::ALL {
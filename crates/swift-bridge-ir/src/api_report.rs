@@ -0,0 +1,537 @@
+use crate::bridged_type::{pat_type_pat_is_self, BridgedType};
+use crate::exported_impl::replace_self_type;
+use crate::parse::TypeDeclarations;
+use crate::SwiftBridgeModule;
+use proc_macro2::Ident;
+use quote::{quote, ToTokens};
+use std::fmt::{Display, Formatter};
+use syn::{
+    File, FnArg, ForeignItem, ImplItem, Item, ItemMod, ReturnType, Signature, Type, Visibility,
+};
+
+/// A report of which items in a crate's public API could be bridged automatically, and why the
+/// others could not.
+///
+/// Public structs and enums are treated as opaque Rust types, and public functions and inherent
+/// methods are checked the same way that `#[swift_bridge::export]` checks an impl block's methods.
+///
+/// ```no_run,ignore
+/// let file: syn::File = syn::parse_str(&std::fs::read_to_string("src/lib.rs")?)?;
+/// let report = PublicApiReport::new(&[file])?;
+///
+/// println!("{}", report);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublicApiReport {
+    /// The public items that could be bridged automatically.
+    pub bridgeable: Vec<String>,
+    /// The public items that could not be bridged automatically.
+    pub unbridgeable: Vec<UnbridgeableItem>,
+}
+
+/// A public item that could not be bridged automatically.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnbridgeableItem {
+    /// The path to the item, such as `some_function`, `SomeType` or `SomeType::some_method`.
+    pub path: String,
+    /// Why the item could not be bridged.
+    pub reason: UnbridgeableReason,
+}
+
+/// Why a public item could not be bridged automatically.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UnbridgeableReason {
+    /// `pub struct Cache<T>`
+    /// Generic types need to be declared in a bridge module with concrete generic arguments.
+    GenericType,
+    /// `pub trait Plugin`
+    Trait,
+    /// `pub fn parse<T: FromStr>(..)`
+    GenericFunction,
+    /// `pub unsafe fn from_raw(..)`
+    UnsafeFunction,
+    /// `pub extern "C" fn callback(..)`
+    ExternAbi,
+    /// `pub fn load(options: HashMap<String, Value>)`
+    UnsupportedArgument {
+        /// The name of the argument.
+        name: String,
+        /// The argument's type.
+        ty: String,
+    },
+    /// `pub fn load() -> Box<dyn Any>`
+    UnsupportedReturnType {
+        /// The return type.
+        ty: String,
+    },
+}
+
+impl PublicApiReport {
+    /// Scan the public items of the given files.
+    ///
+    /// All of the files should belong to the same crate so that a function that uses a type from
+    /// another file is recognized as bridgeable.
+    pub fn new(files: &[File]) -> syn::Result<Self> {
+        let mut scanner = Scanner::default();
+        for file in files {
+            scanner.collect_types(&file.items);
+        }
+
+        let types = scanner.type_declarations()?;
+
+        let mut report = PublicApiReport {
+            bridgeable: vec![],
+            unbridgeable: vec![],
+        };
+        for file in files {
+            scanner.report_items(&file.items, "", &types, &mut report);
+        }
+
+        Ok(report)
+    }
+
+    /// Whether or not every public item can be bridged automatically.
+    pub fn is_fully_bridgeable(&self) -> bool {
+        self.unbridgeable.is_empty()
+    }
+}
+
+impl Display for PublicApiReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{} of {} public items can be bridged automatically.",
+            self.bridgeable.len(),
+            self.bridgeable.len() + self.unbridgeable.len()
+        )?;
+
+        if !self.unbridgeable.is_empty() {
+            writeln!(f)?;
+            writeln!(f, "Could not bridge:")?;
+            for item in &self.unbridgeable {
+                writeln!(f, "  {}: {}", item.path, item.reason)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for UnbridgeableReason {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UnbridgeableReason::GenericType => {
+                write!(
+                    f,
+                    "generic types must be declared with concrete generic arguments"
+                )
+            }
+            UnbridgeableReason::Trait => write!(f, "traits cannot be bridged"),
+            UnbridgeableReason::GenericFunction => {
+                write!(f, "generic functions cannot be bridged")
+            }
+            UnbridgeableReason::UnsafeFunction => write!(f, "unsafe functions cannot be bridged"),
+            UnbridgeableReason::ExternAbi => {
+                write!(f, "functions with an explicit ABI cannot be bridged")
+            }
+            UnbridgeableReason::UnsupportedArgument { name, ty } => {
+                write!(f, "argument `{}` has unsupported type `{}`", name, ty)
+            }
+            UnbridgeableReason::UnsupportedReturnType { ty } => {
+                write!(f, "unsupported return type `{}`", ty)
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct Scanner {
+    /// Types that can be referred to by the public functions and methods.
+    type_idents: Vec<Ident>,
+}
+
+impl Scanner {
+    fn collect_types(&mut self, items: &[Item]) {
+        for item in items {
+            match item {
+                Item::Struct(item_struct) if item_struct.generics.params.is_empty() => {
+                    self.type_idents.push(item_struct.ident.clone());
+                }
+                Item::Enum(item_enum) if item_enum.generics.params.is_empty() => {
+                    self.type_idents.push(item_enum.ident.clone());
+                }
+                Item::Mod(module) => {
+                    if let Some((_, items)) = &module.content {
+                        if is_bridge_module(module) {
+                            self.collect_bridge_module_types(items);
+                        } else {
+                            self.collect_types(items);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Types that are declared inside of a `#[swift_bridge::bridge]` module.
+    fn collect_bridge_module_types(&mut self, items: &[Item]) {
+        for item in items {
+            match item {
+                Item::Struct(item_struct) => self.type_idents.push(item_struct.ident.clone()),
+                Item::Enum(item_enum) => self.type_idents.push(item_enum.ident.clone()),
+                Item::ForeignMod(foreign_mod) => {
+                    for foreign_item in &foreign_mod.items {
+                        if let ForeignItem::Type(foreign_ty) = foreign_item {
+                            self.type_idents.push(foreign_ty.ident.clone());
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn type_declarations(&mut self) -> syn::Result<TypeDeclarations> {
+        self.type_idents.sort();
+        self.type_idents.dedup();
+        // A type that shares a name with a built-in type, such as `Result`, cannot be declared.
+        let empty = TypeDeclarations::default();
+        self.type_idents
+            .retain(|ident| BridgedType::new_with_str(&ident.to_string(), &empty).is_none());
+
+        let type_idents = &self.type_idents;
+        let module: SwiftBridgeModule = syn::parse2(quote! {
+            mod __swift_bridge__api_report {
+                extern "Rust" {
+                    #(type #type_idents;)*
+                }
+            }
+        })?;
+
+        Ok(module.types)
+    }
+
+    fn report_items(
+        &self,
+        items: &[Item],
+        prefix: &str,
+        types: &TypeDeclarations,
+        report: &mut PublicApiReport,
+    ) {
+        for item in items {
+            match item {
+                Item::Struct(item_struct) if is_pub(&item_struct.vis) => {
+                    let path = format!("{}{}", prefix, item_struct.ident);
+                    report_type(path, item_struct.generics.params.is_empty(), report);
+                }
+                Item::Enum(item_enum) if is_pub(&item_enum.vis) => {
+                    let path = format!("{}{}", prefix, item_enum.ident);
+                    report_type(path, item_enum.generics.params.is_empty(), report);
+                }
+                Item::Trait(item_trait) if is_pub(&item_trait.vis) => {
+                    report.unbridgeable.push(UnbridgeableItem {
+                        path: format!("{}{}", prefix, item_trait.ident),
+                        reason: UnbridgeableReason::Trait,
+                    });
+                }
+                Item::Fn(item_fn) if is_pub(&item_fn.vis) => {
+                    let path = format!("{}{}", prefix, item_fn.sig.ident);
+                    report_fn(path, &item_fn.sig, types, report);
+                }
+                Item::Impl(item_impl) => {
+                    self.report_impl(item_impl, prefix, types, report);
+                }
+                Item::Mod(module) if is_pub(&module.vis) && !is_bridge_module(module) => {
+                    if let Some((_, items)) = &module.content {
+                        let prefix = format!("{}{}::", prefix, module.ident);
+                        self.report_items(items, &prefix, types, report);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn report_impl(
+        &self,
+        item_impl: &syn::ItemImpl,
+        prefix: &str,
+        types: &TypeDeclarations,
+        report: &mut PublicApiReport,
+    ) {
+        // Trait impls and impls on generic types are not a part of the bridgeable API.
+        if item_impl.trait_.is_some() || !item_impl.generics.params.is_empty() {
+            return;
+        }
+        let ident = match item_impl.self_ty.as_ref() {
+            Type::Path(path) if path.qself.is_none() => match path.path.get_ident() {
+                Some(ident) => ident,
+                None => return,
+            },
+            _ => return,
+        };
+        if !self.type_idents.contains(ident) {
+            return;
+        }
+
+        for impl_item in &item_impl.items {
+            let method = match impl_item {
+                ImplItem::Method(method) if is_pub(&method.vis) => method,
+                _ => continue,
+            };
+
+            let path = format!("{}{}::{}", prefix, ident, method.sig.ident);
+            match syn::parse2(replace_self_type(method.sig.to_token_stream(), ident)) {
+                Ok(sig) => report_fn(path, &sig, types, report),
+                Err(_) => continue,
+            }
+        }
+    }
+}
+
+fn report_type(path: String, is_generic_free: bool, report: &mut PublicApiReport) {
+    if is_generic_free {
+        report.bridgeable.push(path);
+    } else {
+        report.unbridgeable.push(UnbridgeableItem {
+            path,
+            reason: UnbridgeableReason::GenericType,
+        });
+    }
+}
+
+fn report_fn(
+    path: String,
+    sig: &Signature,
+    types: &TypeDeclarations,
+    report: &mut PublicApiReport,
+) {
+    match unbridgeable_fn_reason(sig, types) {
+        Some(reason) => report.unbridgeable.push(UnbridgeableItem { path, reason }),
+        None => report.bridgeable.push(path),
+    }
+}
+
+fn unbridgeable_fn_reason(sig: &Signature, types: &TypeDeclarations) -> Option<UnbridgeableReason> {
+    if !sig.generics.params.is_empty() {
+        return Some(UnbridgeableReason::GenericFunction);
+    }
+    if sig.unsafety.is_some() {
+        return Some(UnbridgeableReason::UnsafeFunction);
+    }
+    if sig.abi.is_some() {
+        return Some(UnbridgeableReason::ExternAbi);
+    }
+
+    for arg in sig.inputs.iter() {
+        if let FnArg::Typed(pat_ty) = arg {
+            if pat_type_pat_is_self(pat_ty) {
+                continue;
+            }
+            if BridgedType::new_with_type(&pat_ty.ty, types).is_none() {
+                return Some(UnbridgeableReason::UnsupportedArgument {
+                    name: pat_ty.pat.to_token_stream().to_string(),
+                    ty: pat_ty.ty.to_token_stream().to_string(),
+                });
+            }
+        }
+    }
+
+    if BridgedType::new_with_return_type(&sig.output, types).is_none() {
+        let ty = match &sig.output {
+            ReturnType::Type(_, ty) => ty.to_token_stream().to_string(),
+            ReturnType::Default => "()".to_string(),
+        };
+        return Some(UnbridgeableReason::UnsupportedReturnType { ty });
+    }
+
+    None
+}
+
+fn is_pub(vis: &Visibility) -> bool {
+    matches!(vis, Visibility::Public(_))
+}
+
+fn is_bridge_module(module: &ItemMod) -> bool {
+    module.attrs.iter().any(|attr| {
+        let attr = attr.path.to_token_stream().to_string();
+        attr == "swift_bridge :: bridge" || attr == "swift_bridge_macro :: bridge"
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(code: &str) -> PublicApiReport {
+        PublicApiReport::new(&[syn::parse_str(code).unwrap()]).unwrap()
+    }
+
+    /// Verify that we report public functions, types and methods that can be bridged.
+    #[test]
+    fn reports_bridgeable_items() {
+        let report = report(
+            r#"
+            pub struct Counter {
+                count: u32,
+            }
+
+            impl Counter {
+                pub fn new() -> Self {
+                    Counter { count: 0 }
+                }
+
+                pub fn count(&self) -> u32 {
+                    self.count
+                }
+
+                fn reset(&mut self) {}
+            }
+
+            pub fn make_counter(start: u32) -> Counter {
+                Counter { count: start }
+            }
+
+            fn private_function(_: std::ops::RangeFrom<u32>) {}
+            "#,
+        );
+
+        assert_eq!(
+            report.bridgeable,
+            vec!["Counter", "Counter::new", "Counter::count", "make_counter"]
+        );
+        assert!(report.is_fully_bridgeable());
+    }
+
+    /// Verify that we report why public items could not be bridged.
+    #[test]
+    fn reports_unbridgeable_items() {
+        let report = report(
+            r#"
+            pub struct Cache<T>(T);
+
+            pub trait Plugin {}
+
+            pub fn parse<T: std::str::FromStr>(text: &str) -> Option<T> {
+                text.parse().ok()
+            }
+
+            pub unsafe fn from_raw(ptr: *mut u8) {}
+
+            pub extern "C" fn callback() {}
+
+            pub fn load(path: &str, range: std::ops::RangeFrom<u32>) {}
+
+            pub fn as_any() -> Box<dyn std::any::Any> {
+                Box::new(())
+            }
+            "#,
+        );
+
+        assert!(report.bridgeable.is_empty());
+        assert_eq!(
+            report.unbridgeable,
+            vec![
+                UnbridgeableItem {
+                    path: "Cache".to_string(),
+                    reason: UnbridgeableReason::GenericType,
+                },
+                UnbridgeableItem {
+                    path: "Plugin".to_string(),
+                    reason: UnbridgeableReason::Trait,
+                },
+                UnbridgeableItem {
+                    path: "parse".to_string(),
+                    reason: UnbridgeableReason::GenericFunction,
+                },
+                UnbridgeableItem {
+                    path: "from_raw".to_string(),
+                    reason: UnbridgeableReason::UnsafeFunction,
+                },
+                UnbridgeableItem {
+                    path: "callback".to_string(),
+                    reason: UnbridgeableReason::ExternAbi,
+                },
+                UnbridgeableItem {
+                    path: "load".to_string(),
+                    reason: UnbridgeableReason::UnsupportedArgument {
+                        name: "range".to_string(),
+                        ty: "std :: ops :: RangeFrom < u32 >".to_string(),
+                    },
+                },
+                UnbridgeableItem {
+                    path: "as_any".to_string(),
+                    reason: UnbridgeableReason::UnsupportedReturnType {
+                        ty: "Box < dyn std :: any :: Any >".to_string(),
+                    },
+                },
+            ]
+        );
+    }
+
+    /// Verify that we scan public modules and use the types that are declared in other files and
+    /// in bridge modules.
+    #[test]
+    fn scans_modules_and_multiple_files() {
+        let lib = syn::parse_str(
+            r#"
+            #[swift_bridge::bridge]
+            mod ffi {
+                struct Point {
+                    x: f64,
+                    y: f64,
+                }
+            }
+
+            pub mod geometry {
+                pub fn distance(a: Point, b: &Shape) -> f64 {
+                    0.
+                }
+            }
+
+            mod private {
+                pub fn hidden(_: std::ops::RangeFrom<u32>) {}
+            }
+            "#,
+        )
+        .unwrap();
+        let shape = syn::parse_str(
+            r#"
+            pub struct Shape;
+
+            pub struct String;
+            "#,
+        )
+        .unwrap();
+
+        let report = PublicApiReport::new(&[lib, shape]).unwrap();
+
+        assert_eq!(
+            report.bridgeable,
+            vec!["geometry::distance", "Shape", "String"]
+        );
+        assert!(report.is_fully_bridgeable());
+    }
+
+    /// Verify that we render a human readable report.
+    #[test]
+    fn display_report() {
+        let report = report(
+            r#"
+            pub fn ok() {}
+
+            pub fn generic<T>(_val: T) {}
+            "#,
+        );
+
+        assert_eq!(
+            report.to_string(),
+            r#"1 of 2 public items can be bridged automatically.
+
+Could not bridge:
+  generic: generic functions cannot be bridged
+"#
+        );
+    }
+}
@@ -1,4 +1,5 @@
-use proc_macro2::Ident;
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
 use syn::parse::{Parse, ParseStream};
 use syn::LitStr;
 use syn::Token;
@@ -34,3 +35,141 @@ impl Parse for CfgAttr {
         }
     }
 }
+
+/// A `#[swift_bridge(...)]` attribute on a bridge module.
+///
+/// ```no_run,ignore
+/// #[swift_bridge::bridge]
+/// #[swift_bridge(module_table = "my_plugin")]
+/// mod ffi {
+/// }
+/// ```
+pub enum ModuleAttr {
+    /// #\[swift_bridge(module_table = "...")\]
+    /// Export a table of the module's function pointers so that the Rust library can be loaded
+    /// at runtime using `dlopen`.
+    ModuleTable(LitStr),
+    /// #\[swift_bridge(hash_symbols)\]
+    /// Use short hashes instead of the `__swift_bridge__$...` link names of the module's
+    /// functions.
+    HashSymbols,
+    /// #\[swift_bridge(net_addrs_as_strings)\]
+    /// Pass the module's `IpAddr`s and `SocketAddr`s to and from Swift as a `String`.
+    NetAddrsAsStrings,
+    /// #\[swift_bridge(reflection = "...")\]
+    /// Generate a registry of the module's types and functions that can be queried at runtime
+    /// from Rust and Swift.
+    Reflection(LitStr),
+    /// #\[swift_bridge(include = "...")\]
+    /// Add the items in other files, such as `"src/ffi/*.rs"`, to the module.
+    Include(LitStr),
+    /// #\[swift_bridge(utf8 = "...")\]
+    /// How the module's `&str`s that come from Swift are checked for valid UTF-8.
+    Utf8(Utf8Validation),
+}
+
+/// How a `&str` that Rust receives from Swift is converted from its bytes.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum Utf8Validation {
+    /// `utf8 = "validate"`
+    /// Invalid UTF-8 is reported as a `swift_bridge::bridge_error::BridgeError`.
+    #[default]
+    Validate,
+    /// `utf8 = "lossy"`
+    /// Invalid UTF-8 is replaced with U+FFFD REPLACEMENT CHARACTER.
+    Lossy,
+    /// `utf8 = "unchecked"`
+    /// The bytes are trusted to be valid UTF-8 without checking them.
+    Unchecked,
+}
+
+impl Utf8Validation {
+    /// Convert a `swift_bridge::string::RustStr` expression to a `&str`.
+    ///
+    /// A lossy conversion borrows a temporary `Cow<str>`, which lives until the end of the
+    /// statement that uses the `&str`, such as the call to the user's function.
+    pub(crate) fn convert_rust_str(&self, rust_str: &TokenStream) -> TokenStream {
+        match self {
+            Utf8Validation::Validate => quote! { #rust_str.to_str() },
+            Utf8Validation::Lossy => quote! { &*#rust_str.to_cow_lossy() },
+            Utf8Validation::Unchecked => quote! { unsafe { #rust_str.to_str_unchecked() } },
+        }
+    }
+
+    /// Convert a `swift_bridge::string::RustStr` expression, whose `start` is null for `None`, to
+    /// an `Option<&str>`.
+    pub(crate) fn convert_optional_rust_str(&self, rust_str: &TokenStream) -> TokenStream {
+        match self {
+            Utf8Validation::Lossy => quote! { #rust_str.to_optional_cow_lossy().as_deref() },
+            _ => {
+                let to_str = self.convert_rust_str(rust_str);
+                quote! {
+                    if #rust_str.start.is_null() { None } else { Some(#to_str) }
+                }
+            }
+        }
+    }
+}
+
+/// The `...` in a bridge module's `#[swift_bridge(...)]` attribute.
+pub struct ModuleAttrs {
+    pub attrs: Vec<ModuleAttr>,
+}
+
+impl Parse for ModuleAttrs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        syn::parenthesized!(content in input);
+
+        let attrs =
+            syn::punctuated::Punctuated::<ModuleAttr, Token![,]>::parse_terminated(&content)?;
+
+        Ok(ModuleAttrs {
+            attrs: attrs.into_iter().collect(),
+        })
+    }
+}
+
+impl Parse for ModuleAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let key: Ident = input.parse()?;
+
+        let attr = match key.to_string().as_str() {
+            "module_table" => {
+                input.parse::<Token![=]>()?;
+                ModuleAttr::ModuleTable(input.parse()?)
+            }
+            "hash_symbols" => ModuleAttr::HashSymbols,
+            "net_addrs_as_strings" => ModuleAttr::NetAddrsAsStrings,
+            "reflection" => {
+                input.parse::<Token![=]>()?;
+                ModuleAttr::Reflection(input.parse()?)
+            }
+            "include" => {
+                input.parse::<Token![=]>()?;
+                ModuleAttr::Include(input.parse()?)
+            }
+            "utf8" => {
+                input.parse::<Token![=]>()?;
+                let value: LitStr = input.parse()?;
+                let validation = match value.value().as_str() {
+                    "validate" => Utf8Validation::Validate,
+                    "lossy" => Utf8Validation::Lossy,
+                    "unchecked" => Utf8Validation::Unchecked,
+                    _ => {
+                        return Err(syn::Error::new(
+                            value.span(),
+                            r#"Invalid value. Must be "validate", "lossy" or "unchecked"."#,
+                        ));
+                    }
+                };
+                ModuleAttr::Utf8(validation)
+            }
+            _ => {
+                return Err(syn::Error::new(key.span(), "Unknown module attribute."));
+            }
+        };
+
+        Ok(attr)
+    }
+}
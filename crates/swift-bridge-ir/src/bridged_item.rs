@@ -0,0 +1,393 @@
+use crate::bridged_type::BridgedType;
+use crate::codegen::{CodegenConfig, SwiftCodeAndCHeader};
+use crate::SwiftBridgeModule;
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote, ToTokens};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Attribute, Field, Fields, Item, ItemMod, Meta, NestedMeta, Token};
+
+/// A plain Rust struct or enum that is annotated with `#[swift_bridge::bridged]`.
+///
+/// The type gets registered as a shared type without needing to be re-declared inside of a bridge
+/// module. Bridge modules that use the type declare it with `#[swift_bridge(already_declared)]`.
+///
+/// ```no_run,ignore
+/// #[swift_bridge::bridged]
+/// #[swift_bridge(swift_repr = "struct")]
+/// pub struct User {
+///     name: String,
+///     age: u8,
+/// }
+///
+/// #[swift_bridge::bridge]
+/// mod ffi {
+///     #[swift_bridge(already_declared)]
+///     struct User;
+///
+///     extern "Rust" {
+///         fn current_user() -> User;
+///     }
+/// }
+/// ```
+pub struct BridgedItem {
+    /// The struct or enum as it was written, minus any `#[swift_bridge(...)]` attributes.
+    item: Item,
+    ident: Ident,
+    /// A bridge module that declares the type as a shared type.
+    module: SwiftBridgeModule,
+}
+
+impl BridgedItem {
+    /// Generate the corresponding Swift code and C header for the type.
+    pub fn generate_swift_code_and_c_header(&self, config: CodegenConfig) -> SwiftCodeAndCHeader {
+        self.module.generate_swift_code_and_c_header(config)
+    }
+}
+
+impl Parse for BridgedItem {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut item: Item = input.parse()?;
+
+        // The declaration that we hand to the bridge module parser, which only understands the
+        // `#[swift_bridge(...)]` attributes and some derives.
+        let declaration = match &mut item {
+            Item::Struct(item_struct) => {
+                if !item_struct.generics.params.is_empty() {
+                    return Err(syn::Error::new_spanned(
+                        &item_struct.generics,
+                        "#[swift_bridge::bridged] types cannot be generic.",
+                    ));
+                }
+
+                let mut declaration = item_struct.clone();
+                declaration.attrs = declaration_attributes(&item_struct.attrs, &["Copy", "Clone"])?;
+                declaration.vis = syn::Visibility::Inherited;
+                Item::Struct(declaration)
+            }
+            Item::Enum(item_enum) => {
+                if !item_enum.generics.params.is_empty() {
+                    return Err(syn::Error::new_spanned(
+                        &item_enum.generics,
+                        "#[swift_bridge::bridged] types cannot be generic.",
+                    ));
+                }
+
+                let mut declaration = item_enum.clone();
+                declaration.attrs = declaration_attributes(&item_enum.attrs, &["Debug"])?;
+                declaration.vis = syn::Visibility::Inherited;
+                Item::Enum(declaration)
+            }
+            other => {
+                return Err(syn::Error::new_spanned(
+                    other,
+                    "#[swift_bridge::bridged] can only be used on a struct or an enum.",
+                ));
+            }
+        };
+
+        let ident = match &mut item {
+            Item::Struct(item_struct) => {
+                item_struct
+                    .attrs
+                    .retain(|attr| !is_swift_bridge_attribute(attr));
+                for field in item_struct.fields.iter_mut() {
+                    field.attrs.retain(|attr| !is_swift_bridge_attribute(attr));
+                }
+                item_struct.ident.clone()
+            }
+            Item::Enum(item_enum) => {
+                item_enum
+                    .attrs
+                    .retain(|attr| !is_swift_bridge_attribute(attr));
+                item_enum.ident.clone()
+            }
+            _ => unreachable!(),
+        };
+
+        let mod_name = format_ident!("__swift_bridge__bridged_{}", ident);
+        let module: SwiftBridgeModule = syn::parse2(quote! {
+            mod #mod_name {
+                #declaration
+            }
+        })?;
+
+        // The bridge module only knows about this one type, so its fields cannot use other shared
+        // types.
+        let fields: Vec<&Field> = match &item {
+            Item::Struct(item_struct) => item_struct.fields.iter().collect(),
+            Item::Enum(item_enum) => item_enum
+                .variants
+                .iter()
+                .flat_map(|variant| variant.fields.iter())
+                .collect(),
+            _ => unreachable!(),
+        };
+        for field in fields {
+            if BridgedType::new_with_type(&field.ty, &module.types).is_none() {
+                return Err(syn::Error::new_spanned(
+                    &field.ty,
+                    "The fields of a #[swift_bridge::bridged] type must have types that are built in to swift-bridge.",
+                ));
+            }
+        }
+
+        Ok(BridgedItem {
+            item,
+            ident,
+            module,
+        })
+    }
+}
+
+impl ToTokens for BridgedItem {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let item = &self.item;
+        let ident = &self.ident;
+
+        // The bridge module would declare the type itself, so we swap its declaration for an
+        // import of the type that the user wrote.
+        let mut module: ItemMod = syn::parse2(self.module.to_token_stream()).unwrap();
+        if let Some((_, items)) = module.content.as_mut() {
+            for module_item in items.iter_mut() {
+                let is_declaration = match module_item {
+                    Item::Struct(item_struct) => &item_struct.ident == ident,
+                    Item::Enum(item_enum) => &item_enum.ident == ident,
+                    _ => false,
+                };
+
+                if is_declaration {
+                    *module_item = syn::parse_quote! { use super::#ident; };
+                }
+            }
+        }
+
+        // Vec support for enums without data copies values out of the `Vec`.
+        let maybe_assert_copy = match item {
+            Item::Enum(item_enum)
+                if item_enum
+                    .variants
+                    .iter()
+                    .all(|variant| matches!(variant.fields, Fields::Unit)) =>
+            {
+                let swift_bridge_path = &self.module.swift_bridge_path;
+                quote! {
+                    const _: () = {
+                        fn _assert_copy() {
+                            #swift_bridge_path::copy_support::assert_copy::<#ident>();
+                        }
+                    };
+                }
+            }
+            _ => quote! {},
+        };
+
+        let t = quote! {
+            #item
+
+            #module
+
+            #maybe_assert_copy
+        };
+        t.to_tokens(tokens);
+    }
+}
+
+fn is_swift_bridge_attribute(attr: &Attribute) -> bool {
+    attr.path.is_ident("swift_bridge")
+}
+
+/// Keep the `#[swift_bridge(...)]` attributes and the derives that the bridge module parser
+/// understands.
+fn declaration_attributes(
+    attrs: &[Attribute],
+    supported_derives: &[&str],
+) -> syn::Result<Vec<Attribute>> {
+    let mut declaration_attrs = vec![];
+    let mut derives: Punctuated<NestedMeta, Token![,]> = Punctuated::new();
+
+    for attr in attrs {
+        if is_swift_bridge_attribute(attr) {
+            declaration_attrs.push(attr.clone());
+        } else if attr.path.is_ident("derive") {
+            if let Meta::List(meta_list) = attr.parse_meta()? {
+                for derive in meta_list.nested {
+                    let is_supported = match &derive {
+                        NestedMeta::Meta(meta) => supported_derives
+                            .iter()
+                            .any(|supported| meta.path().is_ident(supported)),
+                        NestedMeta::Lit(_) => false,
+                    };
+
+                    if is_supported {
+                        derives.push(derive);
+                    }
+                }
+            }
+        }
+    }
+
+    if !derives.is_empty() {
+        declaration_attrs.push(syn::parse_quote! { #[derive(#derives)] });
+    }
+
+    Ok(declaration_attrs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::assert_tokens_contain;
+
+    fn parse_bridged_item(tokens: TokenStream) -> BridgedItem {
+        syn::parse2(tokens).unwrap()
+    }
+
+    /// Verify that we keep the user's struct and generate its FFI representation without
+    /// re-declaring the struct.
+    #[test]
+    fn bridged_struct() {
+        let bridged = parse_bridged_item(quote! {
+            /// A user.
+            #[derive(Debug, Clone, PartialEq)]
+            #[swift_bridge(swift_repr = "struct")]
+            pub struct User {
+                name: String,
+                age: u8,
+            }
+        });
+        let tokens = bridged.to_token_stream();
+
+        assert_tokens_contain(
+            &tokens,
+            &quote! {
+                /// A user.
+                #[derive(Debug, Clone, PartialEq)]
+                pub struct User {
+                    name: String,
+                    age: u8,
+                }
+            },
+        );
+        assert_tokens_contain(
+            &tokens,
+            &quote! {
+                use super::User;
+            },
+        );
+        assert_tokens_contain(
+            &tokens,
+            &quote! {
+                #[repr(C)]
+                #[doc(hidden)]
+                pub struct __swift_bridge__User {
+                    name: *mut swift_bridge::string::RustString,
+                    age: u8
+                }
+                impl swift_bridge::SharedStruct for User {
+                    type FfiRepr = __swift_bridge__User;
+                }
+            },
+        );
+        assert!(!tokens.to_string().contains("swift_bridge (swift_repr"));
+    }
+
+    /// Verify that we generate the Swift struct and C header for a bridged struct.
+    #[test]
+    fn bridged_struct_swift_and_c_header() {
+        let bridged = parse_bridged_item(quote! {
+            #[swift_bridge(swift_repr = "struct")]
+            struct User {
+                age: u8,
+            }
+        });
+        let generated =
+            bridged.generate_swift_code_and_c_header(CodegenConfig::no_features_enabled());
+
+        assert!(generated.swift.contains("public struct User {"));
+        assert!(generated.c_header.contains(
+            "typedef struct __swift_bridge__$User { uint8_t age; } __swift_bridge__$User;"
+        ));
+    }
+
+    /// Verify that we remove the `swift_name` attributes from a bridged struct's fields and use
+    /// them in the generated C header.
+    #[test]
+    fn bridged_struct_field_swift_name() {
+        let bridged = parse_bridged_item(quote! {
+            #[swift_bridge(swift_repr = "struct")]
+            pub struct User {
+                #[swift_bridge(swift_name = "userAge")]
+                user_age: u8,
+            }
+        });
+        let tokens = bridged.to_token_stream();
+
+        assert_tokens_contain(
+            &tokens,
+            &quote! {
+                pub struct User {
+                    user_age: u8,
+                }
+            },
+        );
+
+        let generated =
+            bridged.generate_swift_code_and_c_header(CodegenConfig::no_features_enabled());
+        assert!(generated.c_header.contains(
+            "typedef struct __swift_bridge__$User { uint8_t userAge; } __swift_bridge__$User;"
+        ));
+    }
+
+    /// Verify that we assert that a bridged enum without data is `Copy`.
+    #[test]
+    fn bridged_enum_asserts_copy() {
+        let bridged = parse_bridged_item(quote! {
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            pub enum Direction {
+                Up,
+                Down,
+            }
+        });
+        let tokens = bridged.to_token_stream();
+
+        assert_tokens_contain(
+            &tokens,
+            &quote! {
+                swift_bridge::copy_support::assert_copy::<Direction>();
+            },
+        );
+        assert_tokens_contain(
+            &tokens,
+            &quote! {
+                impl swift_bridge::SharedEnum for Direction {
+                    type FfiRepr = __swift_bridge__Direction;
+                }
+            },
+        );
+    }
+
+    /// Verify that we return an error if a field's type is not built in to swift-bridge.
+    #[test]
+    fn error_if_field_type_is_not_built_in() {
+        let result: syn::Result<BridgedItem> = syn::parse2(quote! {
+            #[swift_bridge(swift_repr = "struct")]
+            struct User {
+                role: Role,
+            }
+        });
+
+        assert!(result.is_err());
+    }
+
+    /// Verify that we return an error if the attribute is used on something other than a struct
+    /// or an enum.
+    #[test]
+    fn error_if_not_struct_or_enum() {
+        let result: syn::Result<BridgedItem> = syn::parse2(quote! {
+            fn some_function() {}
+        });
+
+        assert!(result.is_err());
+    }
+}
@@ -5,26 +5,69 @@ use std::str::FromStr;
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::ToTokens;
 use quote::{quote, quote_spanned};
-use syn::{FnArg, Pat, PatType, Path, ReturnType, Type};
+use syn::{FnArg, GenericArgument, Pat, PatType, Path, PathArguments, ReturnType, Type};
 
 pub(crate) use self::bridged_opaque_type::OpaqueForeignType;
-use crate::bridged_type::boxed_fn::BridgeableBoxedFnOnce;
+use crate::bridged_type::boxed_fn::BridgeableBoxedFn;
+use crate::bridged_type::bridgeable_arc::BuiltInArc;
+use crate::bridged_type::bridgeable_array::BuiltInArray;
+use crate::bridged_type::bridgeable_btree_map::BuiltInBTreeMap;
+use crate::bridged_type::bridgeable_byte_stream::BuiltInByteStream;
+use crate::bridged_type::bridgeable_char::BuiltInChar;
+use crate::bridged_type::bridgeable_cow_str::BuiltInCowStr;
+use crate::bridged_type::bridgeable_cursor::BuiltInCursor;
+use crate::bridged_type::bridgeable_date_time::BuiltInDateTime;
+use crate::bridged_type::bridgeable_duration::BuiltInDuration;
+use crate::bridged_type::bridgeable_hash_set::BuiltInHashSet;
+use crate::bridged_type::bridgeable_image_buffer::BuiltInImageBuffer;
+use crate::bridged_type::bridgeable_measurement::BuiltInMeasurement;
+use crate::bridged_type::bridgeable_mut_ref::BuiltInMutRef;
+use crate::bridged_type::bridgeable_net_addr::BuiltInNetAddr;
+use crate::bridged_type::bridgeable_path::BuiltInPath;
+use crate::bridged_type::bridgeable_pixel_buffer::BuiltInPixelBuffer;
 use crate::bridged_type::bridgeable_pointer::{BuiltInPointer, Pointee, PointerKind};
+use crate::bridged_type::bridgeable_range::BuiltInRange;
 use crate::bridged_type::bridgeable_result::BuiltInResult;
+use crate::bridged_type::bridgeable_serde_json_value::BuiltInSerdeJsonValue;
 use crate::bridged_type::bridgeable_string::BridgedString;
+use crate::bridged_type::bridgeable_system_time::BuiltInSystemTime;
+use crate::bridged_type::bridgeable_tensor::BuiltInTensor;
+use crate::bridged_type::bridgeable_uuid::BuiltInUuid;
+use crate::bridged_type::bridgeable_watch::BuiltInWatch;
 use crate::bridged_type::built_in_tuple::BuiltInTuple;
 
-use crate::parse::{HostLang, TypeDeclaration, TypeDeclarations};
+use crate::parse::{HostLang, TypeDeclarations};
 
 use self::bridged_option::BridgedOption;
 pub(crate) use self::shared_enum::{DeriveAttrs, EnumVariant, SharedEnum};
 pub(crate) use self::shared_struct::{SharedStruct, StructFields, StructSwiftRepr};
 
 pub(crate) mod boxed_fn;
+pub(crate) mod bridgeable_arc;
+pub(crate) mod bridgeable_array;
+pub(crate) mod bridgeable_btree_map;
+mod bridgeable_byte_stream;
+mod bridgeable_char;
+pub(crate) mod bridgeable_cow_str;
+mod bridgeable_cursor;
+mod bridgeable_date_time;
+mod bridgeable_duration;
+mod bridgeable_hash_set;
+mod bridgeable_image_buffer;
+pub(crate) mod bridgeable_measurement;
+pub(crate) mod bridgeable_mut_ref;
+mod bridgeable_net_addr;
+mod bridgeable_path;
+mod bridgeable_pixel_buffer;
 mod bridgeable_pointer;
+pub(crate) mod bridgeable_range;
 mod bridgeable_result;
-pub mod bridgeable_str;
+mod bridgeable_serde_json_value;
 pub mod bridgeable_string;
+mod bridgeable_system_time;
+mod bridgeable_tensor;
+mod bridgeable_uuid;
+mod bridgeable_watch;
 pub mod bridged_opaque_type;
 mod bridged_option;
 mod built_in_primitive;
@@ -285,24 +328,6 @@ pub(crate) trait BridgeableType: Debug {
     ///  of checking the type.
     fn contains_ref_string_recursive(&self) -> bool;
 
-    /// Parse the type from a `FnArg`.
-    fn from_fn_arg(
-        fn_arg: &FnArg,
-        _associated_type: &Option<TypeDeclaration>,
-        types: &TypeDeclarations,
-    ) -> Option<Self>
-    where
-        Self: Sized,
-    {
-        match fn_arg {
-            FnArg::Receiver(_) => {
-                //
-                todo!()
-            }
-            FnArg::Typed(ty) => Self::from_type(ty.ty.deref(), types),
-        }
-    }
-
     /// Whether or not this type is annotated with `#[swift_bridge(Copy(..))]`
     fn has_swift_bridge_copy_annotation(&self) -> bool;
 
@@ -326,6 +351,54 @@ pub(crate) fn bridgeable_type_from_token_stream_str(
     if BridgedString::can_parse_token_stream_str(tokens) {
         return BridgedString::parse_token_stream_str(tokens, types).map(|o| Box::new(o) as _);
     }
+    // A declared type, such as a `type Uuid;` that wraps `uuid::Uuid`, takes precedence over a
+    // built-in type with the same name.
+    if types.get(tokens).is_some() {
+        return OpaqueForeignType::parse_token_stream_str(tokens, types).map(|o| Box::new(o) as _);
+    }
+    if BuiltInChar::can_parse_token_stream_str(tokens) {
+        return BuiltInChar::parse_token_stream_str(tokens, types).map(|o| Box::new(o) as _);
+    }
+    if BuiltInPath::can_parse_token_stream_str(tokens) {
+        return BuiltInPath::parse_token_stream_str(tokens, types).map(|o| Box::new(o) as _);
+    }
+    if BuiltInDuration::can_parse_token_stream_str(tokens) {
+        return BuiltInDuration::parse_token_stream_str(tokens, types).map(|o| Box::new(o) as _);
+    }
+    if BuiltInSystemTime::can_parse_token_stream_str(tokens) {
+        return BuiltInSystemTime::parse_token_stream_str(tokens, types).map(|o| Box::new(o) as _);
+    }
+    if BuiltInDateTime::can_parse_token_stream_str(tokens) {
+        return BuiltInDateTime::parse_token_stream_str(tokens, types).map(|o| Box::new(o) as _);
+    }
+    if BuiltInNetAddr::can_parse_token_stream_str(tokens) {
+        return BuiltInNetAddr::parse_token_stream_str(tokens, types).map(|o| Box::new(o) as _);
+    }
+    if BuiltInCowStr::can_parse_token_stream_str(tokens) {
+        return BuiltInCowStr::parse_token_stream_str(tokens, types).map(|o| Box::new(o) as _);
+    }
+    if BuiltInMeasurement::can_parse_token_stream_str(tokens) {
+        return BuiltInMeasurement::parse_token_stream_str(tokens, types).map(|o| Box::new(o) as _);
+    }
+    if BuiltInImageBuffer::can_parse_token_stream_str(tokens) {
+        return BuiltInImageBuffer::parse_token_stream_str(tokens, types).map(|o| Box::new(o) as _);
+    }
+    if BuiltInPixelBuffer::can_parse_token_stream_str(tokens) {
+        return BuiltInPixelBuffer::parse_token_stream_str(tokens, types).map(|o| Box::new(o) as _);
+    }
+    if BuiltInSerdeJsonValue::can_parse_token_stream_str(tokens) {
+        return BuiltInSerdeJsonValue::parse_token_stream_str(tokens, types)
+            .map(|o| Box::new(o) as _);
+    }
+    if BuiltInTensor::can_parse_token_stream_str(tokens) {
+        return BuiltInTensor::parse_token_stream_str(tokens, types).map(|o| Box::new(o) as _);
+    }
+    if BuiltInUuid::can_parse_token_stream_str(tokens) {
+        return BuiltInUuid::parse_token_stream_str(tokens, types).map(|o| Box::new(o) as _);
+    }
+    if BuiltInByteStream::can_parse_token_stream_str(tokens) {
+        return BuiltInByteStream::parse_token_stream_str(tokens, types).map(|o| Box::new(o) as _);
+    }
 
     OpaqueForeignType::parse_token_stream_str(tokens, types).map(|o| Box::new(o) as _)
 }
@@ -386,7 +459,7 @@ pub(crate) enum StdLibType {
     /// &str
     Str,
     Vec(BuiltInVec),
-    BoxedFnOnce(BridgeableBoxedFnOnce),
+    BoxedFn(BridgeableBoxedFn),
     Option(BridgedOption),
     Result(BuiltInResult),
     Tuple(BuiltInTuple),
@@ -402,10 +475,52 @@ pub(crate) enum TypePosition {
     SwiftCallsRustAsyncOnCompleteReturnTy,
 }
 
-/// &[T]
+/// &[T] or &mut [T]
 #[derive(Debug)]
 pub(crate) struct BuiltInRefSlice {
     pub ty: Box<BridgedType>,
+    pub mutable: bool,
+    /// Set when the elements are an opaque Rust type, such as `&[SomeType]`.
+    ///
+    /// Swift can't index into a buffer of opaque Rust types, so Rust passes these slices to Swift
+    /// as an array of pointers to the elements, which Swift wraps in `SomeTypeRef`s.
+    /// Swift passes these slices to Rust using the buffer of a `RustVec<SomeType>`.
+    pub opaque_rust_element: Option<OpaqueForeignType>,
+}
+
+impl BridgedType {
+    /// Whether or not this type, if it is an `&[OpaqueRustType]` or an `Option` of one, can be
+    /// used in a function whose body is written in the given language.
+    ///
+    /// Slices of opaque Rust types can only be passed to and from `extern "Rust"` functions, and
+    /// can't be wrapped in an `Option`.
+    pub(crate) fn is_supported_opaque_rust_slice(&self, func_host_lang: HostLang) -> bool {
+        match self {
+            BridgedType::StdLib(StdLibType::RefSlice(slice)) => {
+                slice.opaque_rust_element.is_none() || func_host_lang.is_rust()
+            }
+            BridgedType::StdLib(StdLibType::Option(opt)) => !matches!(
+                opt.ty.deref(),
+                BridgedType::StdLib(StdLibType::RefSlice(slice))
+                    if slice.opaque_rust_element.is_some()
+            ),
+            _ => true,
+        }
+    }
+}
+
+impl BuiltInRefSlice {
+    /// `SomeType` -> `SomeTypeRef`, or `SomeTypeRefMut` for a `&mut [SomeType]`.
+    fn opaque_element_ref_swift_type(
+        &self,
+        opaque: &OpaqueForeignType,
+        types: &TypeDeclarations,
+    ) -> String {
+        let mut reference = opaque.clone();
+        reference.reference = true;
+        reference.mutable = self.mutable;
+        reference.to_swift_type(TypePosition::FnReturn(HostLang::Rust), types)
+    }
 }
 
 /// Vec<T>
@@ -450,6 +565,40 @@ pub(crate) fn fn_arg_name(fn_arg: &FnArg) -> Option<&Ident> {
     }
 }
 
+/// The first `Option<...>` or `Result<...>` in the type that holds a type that `holds` returns
+/// true for.
+///
+/// `Vec<Option<[u8; 4]>>` -> `Some(Option<[u8; 4]>)` when `holds` is true for arrays.
+pub(crate) fn find_option_or_result_holding(
+    ty: &Type,
+    holds: &dyn Fn(&Type) -> bool,
+) -> Option<Type> {
+    match ty {
+        Type::Path(type_path) => {
+            let segment = type_path.path.segments.last()?;
+            let args = match &segment.arguments {
+                PathArguments::AngleBracketed(args) => args,
+                _ => return None,
+            };
+            let is_option_or_result = segment.ident == "Option" || segment.ident == "Result";
+
+            args.args.iter().find_map(|arg| match arg {
+                GenericArgument::Type(arg) if is_option_or_result && holds(arg) => Some(ty.clone()),
+                GenericArgument::Type(arg) => find_option_or_result_holding(arg, holds),
+                _ => None,
+            })
+        }
+        Type::Reference(reference) => find_option_or_result_holding(&reference.elem, holds),
+        Type::Slice(slice) => find_option_or_result_holding(&slice.elem, holds),
+        Type::Array(array) => find_option_or_result_holding(&array.elem, holds),
+        Type::Tuple(tuple) => tuple
+            .elems
+            .iter()
+            .find_map(|elem| find_option_or_result_holding(elem, holds)),
+        _ => None,
+    }
+}
+
 impl BridgeableType for BridgedType {
     fn is_built_in_type(&self) -> bool {
         !self.is_custom_type()
@@ -524,7 +673,7 @@ impl BridgeableType for BridgedType {
                 _ => None,
             },
             BridgedType::Foreign(_) => None,
-            BridgedType::Bridgeable(_) => None,
+            BridgedType::Bridgeable(ty) => ty.generate_custom_c_ffi_types(types),
         }
     }
 
@@ -609,12 +758,12 @@ impl BridgeableType for BridgedType {
 
     fn convert_ffi_expression_to_rust_type(
         &self,
-        _expression: &TokenStream,
-        _span: Span,
-        _swift_bridge_path: &Path,
-        _types: &TypeDeclarations,
+        expression: &TokenStream,
+        span: Span,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
     ) -> TokenStream {
-        todo!()
+        self.convert_ffi_expression_to_rust_type(expression, span, swift_bridge_path, types)
     }
 
     fn convert_ffi_option_expression_to_rust_type(&self, _expression: &TokenStream) -> TokenStream {
@@ -745,20 +894,44 @@ impl BridgedType {
                 Type::Path(p) => {
                     if let Some(ty) = types.get_with_type_path(p) {
                         Some(ty.to_bridged_type(true, ty_ref.mutability.is_some()))
+                    } else if let Some(mut_ref) = BuiltInMutRef::from_type(ty, types) {
+                        Some(BridgedType::Bridgeable(Box::new(mut_ref)))
                     } else {
                         let path = p.path.to_token_stream().to_string();
                         if path == "str" {
                             return Some(BridgedType::StdLib(StdLibType::Str));
                         }
+                        if let Some(path) = BuiltInPath::from_referenced_str_tokens(&path) {
+                            return Some(BridgedType::Bridgeable(Box::new(path)));
+                        }
 
                         None
                     }
                 }
-                Type::Slice(slice) => Self::new_with_type(&slice.elem, types).map(|ty| {
-                    BridgedType::StdLib(StdLibType::RefSlice(BuiltInRefSlice { ty: Box::new(ty) }))
-                }),
+                Type::Slice(slice) => {
+                    let opaque_rust_element = match slice.elem.deref() {
+                        Type::Path(p) => types
+                            .get_with_type_path(p)
+                            .and_then(|ty| ty.to_opaque_type(false, false))
+                            .filter(|opaque| {
+                                opaque.host_lang.is_rust()
+                                    && !opaque.has_swift_bridge_copy_annotation
+                            }),
+                        _ => None,
+                    };
+
+                    Self::new_with_type(&slice.elem, types).map(|ty| {
+                        BridgedType::StdLib(StdLibType::RefSlice(BuiltInRefSlice {
+                            ty: Box::new(ty),
+                            mutable: ty_ref.mutability.is_some(),
+                            opaque_rust_element,
+                        }))
+                    })
+                }
                 _ => None,
             },
+            Type::Array(array) => BuiltInArray::from_type_array(array, types)
+                .map(|array| BridgedType::Bridgeable(Box::new(array))),
             Type::Tuple(tuple) => {
                 if tuple.elems.len() == 0 {
                     Some(BridgedType::StdLib(StdLibType::Null))
@@ -791,19 +964,41 @@ impl BridgedType {
         let tokens = tokens.replace("\n", " ");
         let tokens = tokens.as_str();
         if tokens.starts_with("Vec < ") {
-            let inner = tokens.trim_start_matches("Vec < ");
-            let inner = inner.trim_end_matches(" >");
+            // Only strip the outer `Vec`, since the inner type might be a `Vec<T>` or an
+            // `Option<T>` as well.
+            let inner = tokens.strip_prefix("Vec < ")?.strip_suffix(" >")?.trim();
 
             let inner = if let Some(declared_ty) = types.get(inner) {
                 declared_ty.to_bridged_type(false, false)
             } else {
-                let inner: Type = syn::parse2(TokenStream::from_str(inner).unwrap()).unwrap();
+                let inner: Type = syn::parse2(TokenStream::from_str(inner).ok()?).ok()?;
                 BridgedType::new_with_type(&inner, types)?
             };
 
             return Some(BridgedType::StdLib(StdLibType::Vec(BuiltInVec {
                 ty: Box::new(inner),
             })));
+        } else if BuiltInArray::can_parse_token_stream_str(tokens) {
+            return BuiltInArray::parse_token_stream_str(tokens, types)
+                .map(|array| BridgedType::Bridgeable(Box::new(array)));
+        } else if BuiltInBTreeMap::can_parse_token_stream_str(tokens) {
+            return BuiltInBTreeMap::from_str_tokens(tokens, types)
+                .map(|btree_map| BridgedType::Bridgeable(Box::new(btree_map)));
+        } else if BuiltInHashSet::can_parse_token_stream_str(tokens) {
+            return BuiltInHashSet::from_str_tokens(tokens, types)
+                .map(|hash_set| BridgedType::Bridgeable(Box::new(hash_set)));
+        } else if BuiltInRange::can_parse_token_stream_str(tokens) {
+            return BuiltInRange::from_str_tokens(tokens, types)
+                .map(|range| BridgedType::Bridgeable(Box::new(range)));
+        } else if BuiltInArc::can_parse_token_stream_str(tokens) {
+            return BuiltInArc::from_str_tokens(tokens, types)
+                .map(|arc| BridgedType::Bridgeable(Box::new(arc)));
+        } else if BuiltInCursor::can_parse_token_stream_str(tokens) {
+            return BuiltInCursor::from_str_tokens(tokens, types)
+                .map(|cursor| BridgedType::Bridgeable(Box::new(cursor)));
+        } else if BuiltInWatch::can_parse_token_stream_str(tokens) {
+            return BuiltInWatch::from_str_tokens(tokens, types)
+                .map(|watch| BridgedType::Bridgeable(Box::new(watch)));
         } else if tokens.starts_with("Option < ") {
             let last_bracket = tokens.rfind(">")?;
 
@@ -827,9 +1022,9 @@ impl BridgedType {
             return Some(BridgedType::StdLib(StdLibType::Result(
                 BuiltInResult::from_str_tokens(&tokens, types)?,
             )));
-        } else if tokens.starts_with("Box < dyn FnOnce") {
-            return Some(BridgedType::StdLib(StdLibType::BoxedFnOnce(
-                BridgeableBoxedFnOnce::from_str_tokens(&tokens, types)?,
+        } else if BridgeableBoxedFn::can_parse_token_stream_str(&tokens) {
+            return Some(BridgedType::StdLib(StdLibType::BoxedFn(
+                BridgeableBoxedFn::from_str_tokens(&tokens, types)?,
             )));
         } else if tokens.starts_with("(") {
             let tuple: Type = syn::parse2(TokenStream::from_str(&tokens).unwrap()).unwrap();
@@ -891,7 +1086,11 @@ impl BridgedType {
                 StdLibType::Pointer(ptr) => ptr.to_rust_type_path(types),
                 StdLibType::RefSlice(ref_slice) => {
                     let ty = ref_slice.ty.to_rust_type_path(types);
-                    quote! { &[#ty]}
+                    if ref_slice.mutable {
+                        quote! { &mut [#ty]}
+                    } else {
+                        quote! { &[#ty]}
+                    }
                 }
                 StdLibType::Str => quote! { &str },
                 StdLibType::Vec(v) => {
@@ -903,11 +1102,11 @@ impl BridgedType {
                     quote! { Option<#ty> }
                 }
                 StdLibType::Result(result) => result.to_rust_type_path(types),
-                StdLibType::BoxedFnOnce(fn_once) => fn_once.to_rust_type_path(types),
+                StdLibType::BoxedFn(fn_once) => fn_once.to_rust_type_path(types),
                 StdLibType::Tuple(tuple) => tuple.to_rust_type_path(types),
             },
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct))) => {
-                let ty_name = &shared_struct.name;
+                let ty_name = shared_struct.rust_type_tokens();
                 if shared_struct.already_declared {
                     quote! {
                         super::#ty_name
@@ -963,10 +1162,14 @@ impl BridgedType {
                     ptr.to_ffi_compatible_rust_type(swift_bridge_path, types)
                 }
                 StdLibType::RefSlice(slice) => {
-                    let ty = slice
-                        .ty
-                        .to_ffi_compatible_rust_type(swift_bridge_path, types);
-                    quote! {#swift_bridge_path::FfiSlice<#ty>}
+                    if slice.opaque_rust_element.is_some() {
+                        quote! {#swift_bridge_path::FfiSlice<*const std::ffi::c_void>}
+                    } else {
+                        let ty = slice
+                            .ty
+                            .to_ffi_compatible_rust_type(swift_bridge_path, types);
+                        quote! {#swift_bridge_path::FfiSlice<#ty>}
+                    }
                 }
                 StdLibType::Str => {
                     quote! {#swift_bridge_path::string::RustStr}
@@ -1025,11 +1228,17 @@ impl BridgedType {
                         StdLibType::Bool => {
                             quote! { #swift_bridge_path::option::OptionBool }
                         }
-                        StdLibType::Pointer(_) => {
-                            todo!("Option<*const T> and Option<*mut T> are not yet supported")
+                        StdLibType::Pointer(ptr) => {
+                            ptr.to_ffi_compatible_rust_type(swift_bridge_path, types)
                         }
-                        StdLibType::RefSlice(_) => {
-                            todo!("Option<&[T]> is not yet supported")
+                        StdLibType::RefSlice(slice) => {
+                            if slice.opaque_rust_element.is_some() {
+                                unreachable!("Option<&[OpaqueRustType]> is rejected while parsing")
+                            }
+                            let ty = slice
+                                .ty
+                                .to_ffi_compatible_rust_type(swift_bridge_path, types);
+                            quote! { #swift_bridge_path::OptionFfiSlice<#ty> }
                         }
                         StdLibType::Str => {
                             quote! { #swift_bridge_path::string::RustStr }
@@ -1044,7 +1253,7 @@ impl BridgedType {
                         StdLibType::Result(_) => {
                             todo!("Option<Result<T, E>> is not yet supported")
                         }
-                        StdLibType::BoxedFnOnce(_) => {
+                        StdLibType::BoxedFn(_) => {
                             todo!("Support Box<dyn FnOnce(A, B) -> C>")
                         }
                         StdLibType::Tuple(_) => todo!(),
@@ -1065,7 +1274,7 @@ impl BridgedType {
                 StdLibType::Result(result) => {
                     result.to_ffi_compatible_rust_type(swift_bridge_path, types)
                 }
-                StdLibType::BoxedFnOnce(fn_once) => fn_once.to_ffi_compatible_rust_type(types),
+                StdLibType::BoxedFn(fn_once) => fn_once.to_ffi_compatible_rust_type(types),
                 StdLibType::Tuple(tuple) => {
                     tuple.to_ffi_compatible_rust_type(swift_bridge_path, types)
                 }
@@ -1132,10 +1341,28 @@ impl BridgedType {
                         TypePosition::FnArg(func_host_lang, _)
                         | TypePosition::FnReturn(func_host_lang) => {
                             if func_host_lang.is_swift() {
+                                if slice.opaque_rust_element.is_some() {
+                                    unreachable!(
+                                        "&[OpaqueRustType] in extern \"Swift\" functions is rejected while parsing"
+                                    )
+                                }
                                 "__private__FfiSlice".to_string()
+                            } else if let Some(opaque) = &slice.opaque_rust_element {
+                                match type_pos {
+                                    TypePosition::FnArg(_, _) => format!(
+                                        "RustVec<{}>",
+                                        opaque
+                                            .to_swift_type(TypePosition::SharedStructField, types)
+                                    ),
+                                    _ => format!(
+                                        "[{}]",
+                                        slice.opaque_element_ref_swift_type(opaque, types)
+                                    ),
+                                }
                             } else {
                                 format!(
-                                    "UnsafeBufferPointer<{}>",
+                                    "Unsafe{}BufferPointer<{}>",
+                                    if slice.mutable { "Mutable" } else { "" },
                                     slice.ty.to_swift_type(type_pos, types)
                                 )
                             }
@@ -1167,7 +1394,13 @@ impl BridgedType {
                 StdLibType::Vec(ty) => match type_pos {
                     TypePosition::FnArg(func_host_lang, _) => {
                         if func_host_lang.is_rust() {
-                            format!("RustVec<{}>", ty.ty.to_swift_type(type_pos, types))
+                            // The elements are owned Rust values, such as a `RustString`, even
+                            // when the Vec is an argument.
+                            format!(
+                                "RustVec<{}>",
+                                ty.ty
+                                    .to_swift_type(TypePosition::FnReturn(func_host_lang), types)
+                            )
                         } else {
                             "UnsafeMutableRawPointer".to_string()
                         }
@@ -1185,23 +1418,26 @@ impl BridgedType {
                 },
                 StdLibType::Option(opt) => opt.to_swift_type(type_pos, types),
                 StdLibType::Result(result) => result.to_swift_type(type_pos, types),
-                StdLibType::BoxedFnOnce(boxed_fn) => boxed_fn.to_swift_type().to_string(),
+                StdLibType::BoxedFn(boxed_fn) => boxed_fn.to_swift_type(type_pos, types),
                 StdLibType::Tuple(tuple) => tuple.to_swift_type(type_pos, types),
             },
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct))) => {
+                let ffi_name = if shared_struct.is_class() {
+                    "UnsafeMutableRawPointer".to_string()
+                } else {
+                    shared_struct.ffi_name_string()
+                };
                 match type_pos {
                     TypePosition::FnArg(func_host_lang, _)
                     | TypePosition::FnReturn(func_host_lang) => {
                         if func_host_lang.is_rust() {
-                            shared_struct.swift_name_string()
+                            shared_struct.swift_type_string(types)
                         } else {
-                            shared_struct.ffi_name_string()
+                            ffi_name
                         }
                     }
-                    TypePosition::SharedStructField => shared_struct.swift_name_string(),
-                    TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
-                        shared_struct.ffi_name_string()
-                    }
+                    TypePosition::SharedStructField => shared_struct.swift_type_string(types),
+                    TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => ffi_name,
                 }
             }
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Enum(shared_enum))) => {
@@ -1257,13 +1493,17 @@ impl BridgedType {
                 StdLibType::Str => "struct RustStr".to_string(),
                 StdLibType::Null => "void".to_string(),
                 StdLibType::Vec(_) => "void*".to_string(),
-                StdLibType::Option(opt) => opt.to_c(),
+                StdLibType::Option(opt) => opt.to_c(types),
                 StdLibType::Result(result) => result.to_c(types).to_string(),
-                StdLibType::BoxedFnOnce(_) => "void*".to_string(),
+                StdLibType::BoxedFn(_) => "void*".to_string(),
                 StdLibType::Tuple(tuple) => tuple.to_c_type(types),
             },
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct))) => {
-                format!("struct {}", shared_struct.ffi_name_string())
+                if shared_struct.is_class() {
+                    "void*".to_string()
+                } else {
+                    format!("struct {}", shared_struct.ffi_name_string())
+                }
             }
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Enum(shared_enum))) => {
                 format!("struct {}", shared_enum.ffi_name_string())
@@ -1340,9 +1580,19 @@ impl BridgedType {
                         #expression
                     }
                 }
-                StdLibType::RefSlice(_) => {
-                    quote! {
-                        #swift_bridge_path::FfiSlice::from_slice( #expression )
+                StdLibType::RefSlice(slice) => {
+                    if slice.opaque_rust_element.is_some() {
+                        quote! {
+                            #swift_bridge_path::FfiSlice::from_opaque_slice( #expression )
+                        }
+                    } else if slice.mutable {
+                        quote! {
+                            #swift_bridge_path::FfiSlice::from_mut_slice( #expression )
+                        }
+                    } else {
+                        quote! {
+                            #swift_bridge_path::FfiSlice::from_slice( #expression )
+                        }
                     }
                 }
                 StdLibType::Str => {
@@ -1362,7 +1612,7 @@ impl BridgedType {
                     types,
                     span,
                 ),
-                StdLibType::BoxedFnOnce(fn_once) => {
+                StdLibType::BoxedFn(fn_once) => {
                     fn_once.convert_rust_value_to_ffi_compatible_value(expression, types)
                 }
                 StdLibType::Tuple(tuple) => tuple.convert_rust_expression_to_ffi_type(
@@ -1428,11 +1678,23 @@ impl BridgedType {
                 StdLibType::Pointer(_) => {
                     quote_spanned! {span=> #value }
                 }
-                StdLibType::RefSlice(_reference) => {
-                    quote_spanned! {span=> #value.as_slice() }
+                StdLibType::RefSlice(reference) => {
+                    if reference.opaque_rust_element.is_some() {
+                        let ty = reference.ty.to_rust_type_path(types);
+                        if reference.mutable {
+                            quote_spanned! {span=> #value.as_opaque_mut_slice::<#ty>() }
+                        } else {
+                            quote_spanned! {span=> #value.as_opaque_slice::<#ty>() }
+                        }
+                    } else if reference.mutable {
+                        quote_spanned! {span=> #value.as_mut_slice() }
+                    } else {
+                        quote_spanned! {span=> #value.as_slice() }
+                    }
                 }
                 StdLibType::Str => {
-                    quote_spanned! {span=> #value.to_str() }
+                    let to_str = types.utf8_validation().convert_rust_str(value);
+                    quote_spanned! {span=> #to_str }
                 }
                 StdLibType::Vec(_) => {
                     quote_spanned! {span=>
@@ -1440,12 +1702,12 @@ impl BridgedType {
                     }
                 }
                 StdLibType::Option(bridged_option) => {
-                    bridged_option.convert_ffi_expression_to_rust_type(value)
+                    bridged_option.convert_ffi_expression_to_rust_type(value, types)
                 }
                 StdLibType::Result(result) => {
                     result.convert_ffi_value_to_rust_value(value, span, swift_bridge_path, types)
                 }
-                StdLibType::BoxedFnOnce(_) => {
+                StdLibType::BoxedFn(_) => {
                     todo!("Support Box<dyn FnOnce(A, B) -> C>")
                 }
                 StdLibType::Tuple(tuple) => {
@@ -1526,24 +1788,33 @@ impl BridgedType {
                         PointerKind::Mut => expression.to_string(),
                     },
                 },
+                StdLibType::RefSlice(ty) if ty.opaque_rust_element.is_some() => {
+                    let opaque = ty.opaque_rust_element.as_ref().unwrap();
+                    format!(
+                        "let slice = {value}; defer {{ __swift_bridge__$pointer_slice$free(slice) }}; return slice.toUnsafeBufferPointer(UnsafeMutableRawPointer.self).map {{ {ref_ty}(ptr: $0) }};",
+                        value = expression,
+                        ref_ty = ty.opaque_element_ref_swift_type(opaque, types),
+                    )
+                }
                 StdLibType::RefSlice(ty) => {
                     format!(
-                        "let slice = {value}; return UnsafeBufferPointer(start: slice.start.assumingMemoryBound(to: {ty}.self), count: Int(slice.len));",
+                        "{value}.toUnsafe{maybe_mutable}BufferPointer({ty}.self)",
                         value = expression,
-                        ty = ty.ty.to_swift_type(type_pos,types)
-                       )
+                        maybe_mutable = if ty.mutable { "Mutable" } else { "" },
+                        ty = ty.ty.to_swift_type(type_pos, types)
+                    )
                 }
                 StdLibType::Str => expression.to_string(),
                 StdLibType::Vec(_ty) => {
                     format!("RustVec(ptr: {})", expression)
                 }
-                StdLibType::Option(opt) => opt.convert_ffi_expression_to_swift_type(expression),
+                StdLibType::Option(opt) => {
+                    opt.convert_ffi_expression_to_swift_type(expression, types)
+                }
                 StdLibType::Result(result) => {
                     result.convert_ffi_value_to_swift_value(expression, type_pos, types)
                 }
-                StdLibType::BoxedFnOnce(fn_once) => {
-                    fn_once.convert_ffi_value_to_swift_value(type_pos)
-                }
+                StdLibType::BoxedFn(fn_once) => fn_once.convert_ffi_value_to_swift_value(type_pos),
                 StdLibType::Tuple(tuple) => {
                     tuple.convert_ffi_expression_to_swift_type(expression, type_pos, types)
                 }
@@ -1589,8 +1860,15 @@ impl BridgedType {
                 | StdLibType::F32
                 | StdLibType::F64
                 | StdLibType::Bool => expression.to_string(),
-                StdLibType::RefSlice(_) => {
-                    format!("{}.toFfiSlice()", expression)
+                StdLibType::RefSlice(slice) => {
+                    if slice.opaque_rust_element.is_some() {
+                        format!(
+                            "{{ let vec = {}; return __private__FfiSlice(start: UnsafeMutableRawPointer(mutating: vec.as_ptr()), len: UInt(vec.len())) }}()",
+                            expression
+                        )
+                    } else {
+                        format!("{}.toFfiSlice()", expression)
+                    }
                 }
                 StdLibType::Pointer(ptr) => match &ptr.pointee {
                     Pointee::BuiltIn(_) => expression.to_string(),
@@ -1604,7 +1882,11 @@ impl BridgedType {
                             }
                         }
                         TypePosition::SharedStructField => {
-                            todo!("Pointers in shared struct fields are not yet supported")
+                            if ptr.kind == PointerKind::Const {
+                                format!("UnsafeMutableRawPointer(mutating: {})", expression)
+                            } else {
+                                expression.to_string()
+                            }
                         }
                         TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
                             unimplemented!()
@@ -1620,9 +1902,7 @@ impl BridgedType {
                             expression.to_string()
                         }
                     }
-                    TypePosition::SharedStructField => {
-                        todo!("&str in shared struct fields is not yet supported")
-                    }
+                    TypePosition::SharedStructField => expression.to_string(),
                     TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
                         unimplemented!()
                     }
@@ -1639,8 +1919,8 @@ impl BridgedType {
                 StdLibType::Result(result) => {
                     result.convert_swift_expression_to_ffi_compatible(expression, types, type_pos)
                 }
-                StdLibType::BoxedFnOnce(_) => {
-                    todo!("Support Box<dyn FnOnce(A, B) -> C>")
+                StdLibType::BoxedFn(boxed_fn) => {
+                    boxed_fn.convert_swift_value_to_ffi_value(expression)
                 }
                 StdLibType::Tuple(tuple) => {
                     tuple.convert_swift_expression_to_ffi_type(expression, types, type_pos)
@@ -1707,6 +1987,10 @@ impl BridgedType {
                 StdLibType::RefSlice(slice) => slice.ty.to_c_include(types),
                 StdLibType::Vec(_vec) => Some(vec!["stdint.h"]),
                 StdLibType::Tuple(tuple) => tuple.to_c_include(types),
+                StdLibType::Option(opt) => match opt.ty.deref() {
+                    BridgedType::StdLib(StdLibType::Pointer(_)) => opt.ty.to_c_include(types),
+                    _ => None,
+                },
                 _ => None,
             },
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(_shared_struct))) => {
@@ -1751,12 +2035,19 @@ impl BridgedType {
                     rust: quote! { bool },
                     swift: "bool".into(),
                 },
-                StdLibType::Pointer(_) => {
-                    todo!("Support Option<*const T> and Option<*mut T>")
-                }
-                StdLibType::RefSlice(_) => {
-                    todo!("Support Option<&[T]>")
-                }
+                StdLibType::Pointer(ptr) => UnusedOptionNoneValue {
+                    rust: match ptr.kind {
+                        PointerKind::Const => quote! { std::ptr::null() },
+                        PointerKind::Mut => quote! { std::ptr::null_mut() },
+                    },
+                    swift: "nil".into(),
+                },
+                StdLibType::RefSlice(_) => UnusedOptionNoneValue {
+                    rust: quote! {
+                        #swift_bridge_path::FfiSlice { start: std::ptr::null(), len: 0 }
+                    },
+                    swift: "__private__FfiSlice(start: nil, len: 0)".into(),
+                },
                 StdLibType::Str => {
                     UnusedOptionNoneValue {
                         rust: quote! {
@@ -1768,16 +2059,17 @@ impl BridgedType {
                         swift: "TODO_SWIFT_OPTIONAL_STR_SUPPORT".to_string(),
                     }
                 }
-                StdLibType::Vec(_) => {
-                    todo!("Support Option<Vec<T>>")
-                }
+                StdLibType::Vec(_) => UnusedOptionNoneValue {
+                    rust: quote! { std::ptr::null_mut() },
+                    swift: "nil".into(),
+                },
                 StdLibType::Option(_) => {
                     todo!("Support nested Option<Option<T>>")
                 }
                 StdLibType::Result(_) => {
                     todo!("Result<T, E> is not yet supported")
                 }
-                StdLibType::BoxedFnOnce(_) => {
+                StdLibType::BoxedFn(_) => {
                     todo!("Support Box<dyn FnOnce(A, B) -> C>")
                 }
                 StdLibType::Tuple(_tuple) => todo!(),
@@ -1805,7 +2097,9 @@ impl BridgedType {
         match self {
             BridgedType::Bridgeable(b) => b.contains_owned_string_recursive(types),
             BridgedType::StdLib(stdlib_type) => match stdlib_type {
-                StdLibType::Vec(inner) => inner.ty.contains_owned_string_recursive(types),
+                // A `Vec<String>` is passed as a `RustVec<RustString>`, so unlike a `String`
+                // argument there is no `GenericIntoRustString` to declare on the Swift function.
+                StdLibType::Vec(_) => false,
                 StdLibType::Option(inner) => inner.ty.contains_owned_string_recursive(types),
                 StdLibType::Result(inner) => {
                     inner.ok_ty.contains_owned_string_recursive(types)
@@ -1851,7 +2145,7 @@ impl BridgedType {
     pub fn rust_expression_into(&self, expression: &TokenStream) -> TokenStream {
         match self {
             BridgedType::Foreign(CustomBridgedType::Shared(SharedType::Struct(shared_struct))) => {
-                let struct_name = &shared_struct.name;
+                let struct_name = shared_struct.rust_type_tokens();
 
                 let maybe_super = if shared_struct.already_declared {
                     quote! { super:: }
@@ -1892,10 +2186,12 @@ impl BridgedType {
                 StdLibType::U8 => "U8".to_string(),
                 StdLibType::U16 => "U16".to_string(),
                 StdLibType::U32 => "U32".to_string(),
+                StdLibType::U64 => "U64".to_string(),
                 StdLibType::Usize => "UInt".to_string(),
                 StdLibType::I8 => "I8".to_string(),
                 StdLibType::I16 => "I16".to_string(),
                 StdLibType::I32 => "I32".to_string(),
+                StdLibType::I64 => "I64".to_string(),
                 StdLibType::Isize => "Int".to_string(),
                 StdLibType::Bool => "Bool".to_string(),
                 StdLibType::F32 => "F32".to_string(),
@@ -1942,8 +2238,30 @@ mod tests {
 
         let parsed = BridgedType::new_with_str(tokens, &TypeDeclarations::default()).unwrap();
         match parsed {
-            BridgedType::StdLib(StdLibType::BoxedFnOnce(_)) => {}
+            BridgedType::StdLib(StdLibType::BoxedFn(_)) => {}
             _ => panic!(),
         };
     }
+
+    /// Verify that we only strip the outer `Vec`'s bracket when parsing a nested `Vec` from a
+    /// string, and that a malformed `Vec` is not parsed instead of causing a panic.
+    #[test]
+    fn parses_nested_vec_from_str() {
+        let types = TypeDeclarations::default();
+
+        let parsed =
+            BridgedType::new_with_str("Result < String , Vec < Vec < u8 > > >", &types).unwrap();
+        match parsed {
+            BridgedType::StdLib(StdLibType::Result(result)) => {
+                assert_eq!(
+                    result.err_ty.to_rust_type_path(&types).to_string(),
+                    "Vec < Vec < u8 > >"
+                );
+            }
+            _ => panic!(),
+        };
+
+        assert!(BridgedType::new_with_str("Vec < Vec < u8", &types).is_none());
+        assert!(BridgedType::new_with_str("Vec < u8 , >", &types).is_none());
+    }
 }
@@ -10,15 +10,36 @@ use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
 use syn::{Path, Type};
 
-/// Box<dyn FnOnce(A, B, C) -> ()>
+/// Box<dyn FnOnce(A, B, C) -> D> or Box<dyn Fn(A, B, C) -> D>
 #[derive(Debug)]
-pub(crate) struct BridgeableBoxedFnOnce {
+pub(crate) struct BridgeableBoxedFn {
+    /// Whether this is a `dyn Fn` or a `dyn FnOnce`.
+    pub kind: BoxedFnKind,
     /// The functions parameters.
     pub params: Vec<BridgedType>,
     /// The functions return type.
     pub ret: Box<BridgedType>,
 }
 
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum BoxedFnKind {
+    /// `Box<dyn Fn(A) -> B>`
+    ///
+    /// Only supported when passing a Swift closure to Rust.
+    Fn,
+    /// `Box<dyn FnOnce(A) -> B>`
+    FnOnce,
+}
+
+impl BoxedFnKind {
+    fn to_tokens(self) -> TokenStream {
+        match self {
+            BoxedFnKind::Fn => quote! { Fn },
+            BoxedFnKind::FnOnce => quote! { FnOnce },
+        }
+    }
+}
+
 /// example: Vec<SomeType, AnotherType, u32>
 pub(crate) struct FunctionArguments(pub Vec<Type>);
 impl Parse for FunctionArguments {
@@ -28,13 +49,14 @@ impl Parse for FunctionArguments {
     }
 }
 
-impl BridgeableBoxedFnOnce {
+impl BridgeableBoxedFn {
     pub fn does_not_have_params_or_return(&self) -> bool {
         self.params.is_empty() && self.ret.is_null()
     }
 
     /// Box<dyn FnOnce(A, B) -> C>
     pub fn to_rust_type_path(&self, types: &TypeDeclarations) -> TokenStream {
+        let kind = self.kind.to_tokens();
         let args: Vec<TokenStream> = self
             .params
             .iter()
@@ -42,7 +64,7 @@ impl BridgeableBoxedFnOnce {
             .collect();
         let ret = &self.ret.to_rust_type_path(types);
         quote! {
-            Box<dyn FnOnce(#(#args),*) -> #ret>
+            Box<dyn #kind(#(#args),*) -> #ret>
         }
     }
 
@@ -51,6 +73,10 @@ impl BridgeableBoxedFnOnce {
         expression: &TokenStream,
         types: &TypeDeclarations,
     ) -> TokenStream {
+        if self.kind == BoxedFnKind::Fn {
+            unreachable!("Box<dyn Fn> is only supported as an extern \"Rust\" function argument")
+        }
+
         let args: Vec<TokenStream> = self
             .params
             .iter()
@@ -188,8 +214,46 @@ impl BridgeableBoxedFnOnce {
         args
     }
 
-    pub fn to_swift_type(&self) -> &'static str {
-        "UnsafeMutableRawPointer"
+    pub fn to_swift_type(&self, type_pos: TypePosition, types: &TypeDeclarations) -> String {
+        match type_pos {
+            TypePosition::FnArg(HostLang::Rust, _) => {
+                format!("@escaping {}", self.to_swift_closure_type(types))
+            }
+            _ => "UnsafeMutableRawPointer".to_string(),
+        }
+    }
+
+    /// Box<dyn Fn(u8, SomeType) -> String> becomes:
+    /// "(UInt8, SomeType) -> RustString"
+    ///
+    /// The closure's parameters are values that Rust gives to Swift, so they have the same types
+    /// as values that are returned from Rust.
+    pub fn to_swift_closure_type(&self, types: &TypeDeclarations) -> String {
+        let params: Vec<String> = self
+            .params
+            .iter()
+            .map(|ty| match ty {
+                BridgedType::StdLib(StdLibType::Result(result)) => {
+                    result.to_swift_rust_result_type(types)
+                }
+                _ => ty.to_swift_type(TypePosition::FnReturn(HostLang::Rust), types),
+            })
+            .collect();
+
+        let ret = if self.ret.is_null() {
+            "()".to_string()
+        } else {
+            self.ret
+                .to_swift_type(TypePosition::FnReturn(HostLang::Rust), types)
+        };
+
+        format!("({}) -> {}", params.join(", "), ret)
+    }
+
+    /// Box<dyn Fn(u8)> becomes:
+    /// "Unmanaged.passRetained(__private__SwiftCallback(callback)).toOpaque()"
+    pub fn convert_swift_value_to_ffi_value(&self, expression: &str) -> String {
+        format!("Unmanaged.passRetained(__private__SwiftCallback({expression})).toOpaque()")
     }
 
     pub fn convert_ffi_value_to_swift_value(&self, type_pos: TypePosition) -> String {
@@ -240,12 +304,45 @@ impl BridgeableBoxedFnOnce {
     }
 }
 
-impl BridgeableBoxedFnOnce {
+impl BridgeableBoxedFn {
+    /// Box < dyn FnOnce (A) -> B >
+    ///   OR
+    /// Box < dyn Fn (A) -> B >
+    pub fn can_parse_token_stream_str(string: &str) -> bool {
+        string.starts_with("Box < dyn FnOnce")
+            || string.starts_with("Box < dyn Fn (")
+            || string.starts_with("Box < dyn Fn(")
+    }
+
+    /// Whether or not the type is, or contains, a `Box<dyn Fn>`.
+    /// `Box<dyn Fn>` is only supported as the type of an `extern "Rust"` function's argument.
+    pub fn type_contains_boxed_dyn_fn(ty: &Type) -> bool {
+        ty.to_token_stream()
+            .to_string()
+            .split(|c: char| !c.is_alphanumeric() && c != '_')
+            .any(|word| word == "Fn")
+    }
+
+    /// Whether or not the type is a `Box<dyn Fn>`, as opposed to something that contains one such
+    /// as an `Option<Box<dyn Fn>>`.
+    pub fn type_is_boxed_dyn_fn(ty: &Type) -> bool {
+        let tokens = ty.to_token_stream().to_string();
+        tokens.starts_with("Box < dyn Fn (") || tokens.starts_with("Box < dyn Fn(")
+    }
+
     pub fn from_str_tokens(string: &str, types: &TypeDeclarations) -> Option<Self> {
         // ( A , B , C ) -> D >
         //   OR
         // ( A , B , C ) >
-        let signature = string.trim_start_matches("Box < dyn FnOnce");
+        let kind = if string.starts_with("Box < dyn FnOnce") {
+            BoxedFnKind::FnOnce
+        } else {
+            BoxedFnKind::Fn
+        };
+        let signature = match kind {
+            BoxedFnKind::Fn => string.trim_start_matches("Box < dyn Fn"),
+            BoxedFnKind::FnOnce => string.trim_start_matches("Box < dyn FnOnce"),
+        };
 
         let open_parens = signature.find("(").unwrap();
         let closing_parens = signature.find(")").unwrap();
@@ -276,7 +373,8 @@ impl BridgeableBoxedFnOnce {
             args_bridged_tys.push(BridgedType::new_with_type(&arg, types)?);
         }
 
-        return Some(BridgeableBoxedFnOnce {
+        return Some(BridgeableBoxedFn {
+            kind,
             params: args_bridged_tys,
             ret: Box::new(ret),
         });
@@ -293,7 +391,7 @@ mod tests {
         let tokens = quote! {Box<dyn FnOnce()>}.to_token_stream().to_string();
 
         assert!(
-            BridgeableBoxedFnOnce::from_str_tokens(&tokens, &TypeDeclarations::default())
+            BridgeableBoxedFn::from_str_tokens(&tokens, &TypeDeclarations::default())
                 .unwrap()
                 .ret
                 .is_null()
@@ -308,7 +406,7 @@ mod tests {
             .to_string();
 
         assert!(matches!(
-            *BridgeableBoxedFnOnce::from_str_tokens(&tokens, &TypeDeclarations::default())
+            *BridgeableBoxedFn::from_str_tokens(&tokens, &TypeDeclarations::default())
                 .unwrap()
                 .ret,
             BridgedType::StdLib(StdLibType::U8)
@@ -323,7 +421,7 @@ mod tests {
             .to_string();
 
         assert!(
-            BridgeableBoxedFnOnce::from_str_tokens(&tokens, &TypeDeclarations::default())
+            BridgeableBoxedFn::from_str_tokens(&tokens, &TypeDeclarations::default())
                 .unwrap()
                 .ret
                 .is_null(),
@@ -339,13 +437,37 @@ mod tests {
         let tokens = "Box < dyn FnOnce() -> () >";
 
         assert!(
-            BridgeableBoxedFnOnce::from_str_tokens(tokens, &TypeDeclarations::default())
+            BridgeableBoxedFn::from_str_tokens(tokens, &TypeDeclarations::default())
                 .unwrap()
                 .ret
                 .is_null(),
         );
     }
 
+    /// Verify that we can parse a `Box<dyn Fn>` and tell it apart from a `Box<dyn FnOnce>`.
+    #[test]
+    fn boxed_fn_from_string() {
+        let tests = vec![
+            (quote! {Box<dyn Fn(u32) -> u8>}, BoxedFnKind::Fn),
+            (quote! {Box<dyn FnOnce(u32) -> u8>}, BoxedFnKind::FnOnce),
+        ];
+
+        for (test, kind) in tests {
+            let tokens = test.to_token_stream().to_string();
+            assert!(BridgeableBoxedFn::can_parse_token_stream_str(&tokens));
+
+            let boxed_fn =
+                BridgeableBoxedFn::from_str_tokens(&tokens, &TypeDeclarations::default()).unwrap();
+            assert_eq!(boxed_fn.kind, kind);
+            assert_eq!(boxed_fn.params.len(), 1);
+            assert!(matches!(*boxed_fn.ret, BridgedType::StdLib(StdLibType::U8)));
+        }
+
+        assert!(!BridgeableBoxedFn::can_parse_token_stream_str(
+            "Box < dyn FnMut (u32) >"
+        ));
+    }
+
     /// Verify that we can parse a boxed fn that has a comma after the FnOnce.
     /// rustfmt adds a trailing comma when it puts a long function signature on its own line.
     #[test]
@@ -364,7 +486,7 @@ mod tests {
             let tokens = test.to_token_stream().to_string();
 
             assert!(
-                BridgeableBoxedFnOnce::from_str_tokens(&tokens, &TypeDeclarations::default())
+                BridgeableBoxedFn::from_str_tokens(&tokens, &TypeDeclarations::default())
                     .unwrap()
                     .ret
                     .is_null(),
@@ -0,0 +1,465 @@
+use crate::bridged_type::{
+    BridgeableType, BuiltInResult, CFfiStruct, OnlyEncoding, TypePosition, UnusedOptionNoneValue,
+};
+use crate::parse::TypeDeclarations;
+use crate::SWIFT_BRIDGE_PREFIX;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{quote, quote_spanned};
+use std::collections::HashSet;
+use syn::{GenericArgument, Path, PathArguments, Type};
+
+/// Arc<T>, where T is an opaque Rust type.
+///
+/// An `Arc<T>` crosses the FFI boundary as a `*const T` that owns one strong reference.
+/// On the Swift side it is wrapped in a `{T}Arc` class whose `deinit` gives the strong reference
+/// back to Rust and whose `clone()` method creates a new one.
+#[derive(Debug)]
+pub(crate) struct BuiltInArc {
+    pub ty: Ident,
+}
+
+impl BuiltInArc {
+    /// Parse an `Arc<T>` from a stringified token stream such as "Arc < SomeType >".
+    ///
+    /// Returns `None` if `T` is not a non-generic opaque Rust type.
+    pub fn from_str_tokens(tokens: &str, types: &TypeDeclarations) -> Option<Self> {
+        let inner = tokens.trim_start_matches("std :: sync :: ");
+        let inner = inner.trim_start_matches("Arc < ");
+        let inner = inner.trim_end_matches(" >");
+
+        let opaque = types.get(inner)?.as_opaque()?;
+        if !opaque.host_lang.is_rust()
+            || opaque.attributes.copy.is_some()
+            || !opaque.generics.is_empty()
+        {
+            return None;
+        }
+
+        Some(BuiltInArc {
+            ty: opaque.ty.clone(),
+        })
+    }
+
+    /// Collect the names of the opaque Rust types that are held in an `Arc<T>` anywhere within
+    /// the given type, such as `Arc<SomeType>` or `Option<Arc<SomeType>>`.
+    pub fn collect_pointee_names(ty: &Type, types: &TypeDeclarations, names: &mut HashSet<String>) {
+        match ty {
+            Type::Path(path) => {
+                if let Some(arc) = Self::from_type(ty, types) {
+                    names.insert(arc.ty.to_string());
+                    return;
+                }
+
+                for segment in path.path.segments.iter() {
+                    if let PathArguments::AngleBracketed(args) = &segment.arguments {
+                        for arg in args.args.iter() {
+                            if let GenericArgument::Type(ty) = arg {
+                                Self::collect_pointee_names(ty, types, names);
+                            }
+                        }
+                    }
+                }
+            }
+            Type::Tuple(tuple) => {
+                for ty in tuple.elems.iter() {
+                    Self::collect_pointee_names(ty, types, names);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// The name of the generated Swift class that holds a strong reference.
+    ///
+    /// `Arc<SomeType>` -> `SomeTypeArc`
+    pub fn swift_class_name(&self) -> String {
+        format!("{}Arc", self.ty)
+    }
+
+    /// The C FFI link name of the function that increments the strong count.
+    ///
+    /// `Arc<SomeType>` -> `__swift_bridge__$SomeType$_arc_clone`
+    pub fn clone_ffi_name(&self) -> String {
+        format!("{}${}$_arc_clone", SWIFT_BRIDGE_PREFIX, self.ty)
+    }
+}
+
+impl BridgeableType for BuiltInArc {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&BuiltInResult> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        true
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        None
+    }
+
+    fn generate_custom_c_ffi_types(&self, _types: &TypeDeclarations) -> Option<CFfiStruct> {
+        None
+    }
+
+    fn to_rust_type_path(&self, _types: &TypeDeclarations) -> TokenStream {
+        let ty = &self.ty;
+        quote! { std::sync::Arc<super::#ty> }
+    }
+
+    fn to_swift_type(&self, type_pos: TypePosition, _types: &TypeDeclarations) -> String {
+        match type_pos {
+            TypePosition::FnArg(func_host_lang, _) | TypePosition::FnReturn(func_host_lang) => {
+                if func_host_lang.is_rust() {
+                    self.swift_class_name()
+                } else {
+                    "UnsafeMutableRawPointer".to_string()
+                }
+            }
+            TypePosition::SharedStructField => self.swift_class_name(),
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                "UnsafeMutableRawPointer?".to_string()
+            }
+        }
+    }
+
+    fn to_c_type(&self, _types: &TypeDeclarations) -> String {
+        "void*".to_string()
+    }
+
+    fn to_c_include(&self, _types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        None
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        let ty = &self.ty;
+        quote! { *const super::#ty }
+    }
+
+    fn to_ffi_compatible_option_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        self.to_ffi_compatible_rust_type(swift_bridge_path, types)
+    }
+
+    fn to_ffi_compatible_option_swift_type(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> String {
+        "UnsafeMutableRawPointer?".to_string()
+    }
+
+    fn to_ffi_compatible_option_c_type(&self) -> String {
+        "void*".to_string()
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+        _span: Span,
+    ) -> TokenStream {
+        quote! {
+            std::sync::Arc::into_raw(#expression)
+        }
+    }
+
+    fn convert_option_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
+        quote! {
+            if let Some(val) = #expression {
+                std::sync::Arc::into_raw(val)
+            } else {
+                std::ptr::null()
+            }
+        }
+    }
+
+    // The Swift instance keeps its own strong reference, so we create a new one to hand over.
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!(
+            "{clone}({value}.ptr)",
+            clone = self.clone_ffi_name(),
+            value = expression
+        )
+    }
+
+    fn convert_option_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!(
+            "{{ if let val = {expression} {{ return {clone}(val.ptr) }} else {{ return nil }} }}()",
+            expression = expression,
+            clone = self.clone_ffi_name()
+        )
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        span: Span,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote_spanned! {span=>
+            unsafe { std::sync::Arc::from_raw(#expression) }
+        }
+    }
+
+    fn convert_ffi_option_expression_to_rust_type(&self, expression: &TokenStream) -> TokenStream {
+        quote! {
+            if #expression.is_null() {
+                None
+            } else {
+                Some(unsafe { std::sync::Arc::from_raw(#expression) } )
+            }
+        }
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        type_pos: TypePosition,
+        _types: &TypeDeclarations,
+    ) -> String {
+        match type_pos {
+            TypePosition::FnArg(_, _)
+            | TypePosition::FnReturn(_)
+            | TypePosition::SharedStructField => {
+                format!("{}(ptr: {})", self.swift_class_name(), expression)
+            }
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                format!("{}(ptr: {}!)", self.swift_class_name(), expression)
+            }
+        }
+    }
+
+    fn convert_ffi_option_expression_to_swift_type(&self, expression: &str) -> String {
+        format!(
+            "{{ let val = {expression}; if val != nil {{ return {class}(ptr: val!) }} else {{ return nil }} }}()",
+            expression = expression,
+            class = self.swift_class_name()
+        )
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        ok_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        let ty = &self.ty;
+        quote! {
+            unsafe { std::sync::Arc::from_raw(#ok_ffi_value.ok_or_err as *const super::#ty) }
+        }
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        err_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        let ty = &self.ty;
+        quote! {
+            unsafe { std::sync::Arc::from_raw(#err_ffi_value.ok_or_err as *const super::#ty) }
+        }
+    }
+
+    fn unused_option_none_val(&self, _swift_bridge_path: &Path) -> UnusedOptionNoneValue {
+        UnusedOptionNoneValue {
+            rust: quote! { std::ptr::null() },
+            swift: "nil".to_string(),
+        }
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        tokens.starts_with("Arc < ") || tokens.starts_with("std :: sync :: Arc < ")
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match ty {
+            Type::Path(path) => {
+                let tokens = quote! { #path }.to_string();
+                if !Self::can_parse_token_stream_str(&tokens) {
+                    return None;
+                }
+                Self::parse_token_stream_str(&tokens, types)
+            }
+            _ => None,
+        }
+    }
+
+    fn parse_token_stream_str(tokens: &str, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        Self::from_str_tokens(tokens, types)
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, _types: &TypeDeclarations) -> String {
+        format!("Arc{}", self.ty)
+    }
+}
+
+/// The Rust functions that the `{T}Arc` Swift class uses to manage its strong reference.
+pub(crate) fn generate_arc_rust_support(ty_name: &Ident) -> TokenStream {
+    let free_link_name = format!("{}${}$_arc_free", SWIFT_BRIDGE_PREFIX, ty_name);
+    let free_fn_name = Ident::new(
+        &format!("{}{}__arc_free", SWIFT_BRIDGE_PREFIX, ty_name),
+        ty_name.span(),
+    );
+    let clone_link_name = format!("{}${}$_arc_clone", SWIFT_BRIDGE_PREFIX, ty_name);
+    let clone_fn_name = Ident::new(
+        &format!("{}{}__arc_clone", SWIFT_BRIDGE_PREFIX, ty_name),
+        ty_name.span(),
+    );
+
+    quote! {
+        #[export_name = #free_link_name]
+        pub extern "C" fn #free_fn_name (this: *const super::#ty_name) {
+            unsafe { std::sync::Arc::decrement_strong_count(this) }
+        }
+
+        #[export_name = #clone_link_name]
+        pub extern "C" fn #clone_fn_name (this: *const super::#ty_name) -> *const super::#ty_name {
+            unsafe { std::sync::Arc::increment_strong_count(this) };
+            this
+        }
+    }
+}
+
+/// The C declarations of the functions that the `{T}Arc` Swift class uses to manage its strong
+/// reference.
+pub(crate) fn generate_arc_c_support(ty_name: &str) -> String {
+    format!(
+        r#"void __swift_bridge__${ty_name}$_arc_free(void* self);
+void* __swift_bridge__${ty_name}$_arc_clone(void* self);"#,
+        ty_name = ty_name
+    )
+}
+
+/// The `{T}Arc` Swift class, which can call the `&self` methods of the type.
+pub(crate) fn generate_arc_swift_class(ty_name: &str) -> String {
+    format!(
+        r#"public class {ty_name}Arc: {ty_name}Ref {{
+    public override init(ptr: UnsafeMutableRawPointer) {{
+        super.init(ptr: ptr)
+    }}
+
+    deinit {{
+        __swift_bridge__${ty_name}$_arc_free(ptr)
+    }}
+
+    public func clone() -> {ty_name}Arc {{
+        {ty_name}Arc(ptr: __swift_bridge__${ty_name}$_arc_clone(ptr))
+    }}
+}}"#,
+        ty_name = ty_name
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::parse_ok;
+
+    /// Verify that we parse an `Arc` of an opaque Rust type.
+    #[test]
+    fn parse_arc_of_opaque_rust_type() {
+        let module = parse_ok(quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+                }
+            }
+        });
+
+        for tokens in ["Arc < SomeType >", "std :: sync :: Arc < SomeType >"] {
+            assert!(
+                BuiltInArc::from_str_tokens(tokens, &module.types).is_some(),
+                "{}",
+                tokens
+            );
+        }
+    }
+
+    /// Verify that we do not parse an `Arc` of a type that is not an opaque Rust type.
+    #[test]
+    fn does_not_parse_arc_of_unsupported_types() {
+        let module = parse_ok(quote! {
+            mod ffi {
+                extern "Swift" {
+                    type SwiftType;
+                }
+            }
+        });
+
+        for tokens in ["Arc < u8 >", "Arc < SwiftType >", "Arc < UnknownType >"] {
+            assert!(
+                BuiltInArc::from_str_tokens(tokens, &module.types).is_none(),
+                "{}",
+                tokens
+            );
+        }
+    }
+}
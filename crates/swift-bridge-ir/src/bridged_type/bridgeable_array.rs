@@ -0,0 +1,369 @@
+use crate::bridged_type::{
+    find_option_or_result_holding, BridgeableType, BridgedType, BuiltInResult, CFfiStruct,
+    OnlyEncoding, StdLibType, TypePosition, UnusedOptionNoneValue,
+};
+use crate::parse::TypeDeclarations;
+use crate::SWIFT_BRIDGE_PREFIX;
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{Expr, Lit, Path, Type, TypeArray};
+
+/// [T; N]
+///
+/// Passed over FFI as a `swift_bridge::FfiArray<T, N>`, which has the same layout as the C struct
+/// `struct __swift_bridge__$array$T$N { T _0[N]; }`.
+///
+/// Swift imports a C array as a tuple, so `[u8; 4]` is seen by Swift as a `(UInt8, UInt8, UInt8,
+/// UInt8)`.
+#[derive(Debug)]
+pub(crate) struct BuiltInArray {
+    pub ty: Box<BridgedType>,
+    pub len: usize,
+}
+
+impl BuiltInArray {
+    /// The `Option<[T; N]>`, `Result<[T; N], E>` or `Result<T, [T; N]>` in the type, if any.
+    /// Arrays can't yet be held by an `Option` or a `Result`.
+    pub fn find_in_option_or_result(ty: &Type) -> Option<Type> {
+        find_option_or_result_holding(ty, &|held| matches!(held, Type::Array(_)))
+    }
+
+    /// Returns `None` if the element type is not supported or the length is not an integer
+    /// literal.
+    pub fn from_type_array(array: &TypeArray, types: &TypeDeclarations) -> Option<Self> {
+        let len = match &array.len {
+            Expr::Lit(lit) => match &lit.lit {
+                Lit::Int(int) => int.base10_parse::<usize>().ok()?,
+                _ => return None,
+            },
+            _ => return None,
+        };
+        if len == 0 {
+            return None;
+        }
+
+        let ty = BridgedType::new_with_type(&array.elem, types)?;
+        if !Self::is_supported_element(&ty) {
+            return None;
+        }
+
+        Some(BuiltInArray {
+            ty: Box::new(ty),
+            len,
+        })
+    }
+
+    /// Only primitives are supported since a C array's elements can't need to be converted when
+    /// crossing the FFI boundary.
+    fn is_supported_element(ty: &BridgedType) -> bool {
+        matches!(
+            ty,
+            BridgedType::StdLib(
+                StdLibType::U8
+                    | StdLibType::I8
+                    | StdLibType::U16
+                    | StdLibType::I16
+                    | StdLibType::U32
+                    | StdLibType::I32
+                    | StdLibType::U64
+                    | StdLibType::I64
+                    | StdLibType::Usize
+                    | StdLibType::Isize
+                    | StdLibType::F32
+                    | StdLibType::F64
+                    | StdLibType::Bool
+            )
+        )
+    }
+
+    /// `[u8; 16]` -> `__swift_bridge__$array$U8$16`
+    fn ffi_name(&self, types: &TypeDeclarations) -> String {
+        format!(
+            "{}$array${}${}",
+            SWIFT_BRIDGE_PREFIX,
+            self.ty.to_alpha_numeric_underscore_name(types),
+            self.len
+        )
+    }
+
+    /// `[u8; 3]` -> `(UInt8, UInt8, UInt8)`
+    fn to_swift_tuple_signature(&self, type_pos: TypePosition, types: &TypeDeclarations) -> String {
+        let elem = self.ty.to_swift_type(type_pos, types);
+        format!("({})", vec![elem; self.len].join(", "))
+    }
+}
+
+impl BridgeableType for BuiltInArray {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&BuiltInResult> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        false
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        None
+    }
+
+    fn generate_custom_c_ffi_types(&self, types: &TypeDeclarations) -> Option<CFfiStruct> {
+        let ffi_name = self.ffi_name(types);
+        let c_decl = format!(
+            "typedef struct {ffi_name} {{ {elem} _0[{len}]; }} {ffi_name};",
+            elem = self.ty.to_c(types),
+            len = self.len
+        );
+
+        Some(CFfiStruct {
+            c_ffi_type: c_decl,
+            fields: vec![],
+        })
+    }
+
+    fn to_rust_type_path(&self, types: &TypeDeclarations) -> TokenStream {
+        let ty = self.ty.to_rust_type_path(types);
+        let len = self.len;
+        quote! { [#ty; #len] }
+    }
+
+    fn to_swift_type(&self, type_pos: TypePosition, types: &TypeDeclarations) -> String {
+        match type_pos {
+            TypePosition::FnArg(host_lang, _) | TypePosition::FnReturn(host_lang) => {
+                if host_lang.is_rust() {
+                    self.to_swift_tuple_signature(type_pos, types)
+                } else {
+                    self.ffi_name(types)
+                }
+            }
+            TypePosition::SharedStructField => self.to_swift_tuple_signature(type_pos, types),
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => self.ffi_name(types),
+        }
+    }
+
+    fn to_c_type(&self, types: &TypeDeclarations) -> String {
+        format!("struct {}", self.ffi_name(types))
+    }
+
+    fn to_c_include(&self, types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        self.ty.to_c_include(types)
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let ty = self.ty.to_rust_type_path(types);
+        let len = self.len;
+        quote! { #swift_bridge_path::FfiArray<#ty, #len> }
+    }
+
+    fn to_ffi_compatible_option_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        unreachable!("Option<[T; N]> is rejected while parsing")
+    }
+
+    fn to_ffi_compatible_option_swift_type(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> String {
+        unreachable!("Option<[T; N]> is rejected while parsing")
+    }
+
+    fn to_ffi_compatible_option_c_type(&self) -> String {
+        unreachable!("Option<[T; N]> is rejected while parsing")
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+        span: Span,
+    ) -> TokenStream {
+        quote_spanned! {span=>
+            #swift_bridge_path::FfiArray(#expression)
+        }
+    }
+
+    fn convert_option_rust_expression_to_ffi_type(
+        &self,
+        _expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
+        unreachable!("Option<[T; N]> is rejected while parsing")
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!("{}(_0: {})", self.ffi_name(types), expression)
+    }
+
+    fn convert_option_swift_expression_to_ffi_type(
+        &self,
+        _expression: &str,
+        _type_pos: TypePosition,
+    ) -> String {
+        unreachable!("Option<[T; N]> is rejected while parsing")
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        span: Span,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote_spanned! {span=>
+            #expression.0
+        }
+    }
+
+    fn convert_ffi_option_expression_to_rust_type(&self, _expression: &TokenStream) -> TokenStream {
+        unreachable!("Option<[T; N]> is rejected while parsing")
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+        _types: &TypeDeclarations,
+    ) -> String {
+        format!("{}._0", expression)
+    }
+
+    fn convert_ffi_option_expression_to_swift_type(&self, _expression: &str) -> String {
+        unreachable!("Option<[T; N]> is rejected while parsing")
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        _ok_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        unreachable!("Result<[T; N], E> is rejected while parsing")
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        _err_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        unreachable!("Result<T, [T; N]> is rejected while parsing")
+    }
+
+    fn unused_option_none_val(&self, _swift_bridge_path: &Path) -> UnusedOptionNoneValue {
+        unreachable!("Option<[T; N]> is rejected while parsing")
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        tokens.starts_with('[')
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match ty {
+            Type::Array(array) => Self::from_type_array(array, types),
+            _ => None,
+        }
+    }
+
+    fn parse_token_stream_str(tokens: &str, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        let ty: Type = syn::parse_str(tokens).ok()?;
+        Self::from_type(&ty, types)
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, types: &TypeDeclarations) -> String {
+        format!(
+            "Array{}x{}",
+            self.ty.to_alpha_numeric_underscore_name(types),
+            self.len
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify that we can parse arrays of primitives.
+    #[test]
+    fn parse_array_of_primitives() {
+        for tokens in ["[u8 ; 16]", "[f32 ; 4]", "[bool ; 1]"] {
+            assert!(
+                BuiltInArray::parse_token_stream_str(tokens, &TypeDeclarations::default())
+                    .is_some(),
+                "{}",
+                tokens
+            );
+        }
+    }
+
+    /// Verify that we do not parse arrays of unsupported element types, empty arrays or arrays
+    /// whose length is not an integer literal.
+    #[test]
+    fn does_not_parse_unsupported_arrays() {
+        for tokens in ["[String ; 2]", "[u8 ; 0]", "[u8 ; LEN]"] {
+            assert!(
+                BuiltInArray::parse_token_stream_str(tokens, &TypeDeclarations::default())
+                    .is_none(),
+                "{}",
+                tokens
+            );
+        }
+    }
+}
@@ -0,0 +1,464 @@
+use crate::bridged_type::{
+    find_option_or_result_holding, BridgeableType, BridgedType, BuiltInResult, CFfiStruct,
+    OnlyEncoding, StdLibType, TypePosition, UnusedOptionNoneValue,
+};
+use crate::parse::{HostLang, TypeDeclarations};
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{GenericArgument, Path, PathArguments, Type};
+
+/// BTreeMap<K, V>
+///
+/// Passed over FFI as a `swift_bridge::btree_map::FfiBTreeMap`, which holds a `Vec<K>` of the keys
+/// and a `Vec<V>` of the values in sorted order.
+#[derive(Debug)]
+pub(crate) struct BuiltInBTreeMap {
+    pub key: Box<BridgedType>,
+    pub value: Box<BridgedType>,
+}
+
+impl BuiltInBTreeMap {
+    /// The `Option<BTreeMap<K, V>>`, `Result<BTreeMap<K, V>, E>` or `Result<T, BTreeMap<K, V>>` in
+    /// the type, if any. A `BTreeMap` can't yet be held by an `Option` or a `Result`.
+    pub fn find_in_option_or_result(ty: &Type) -> Option<Type> {
+        find_option_or_result_holding(ty, &|held| match held {
+            Type::Path(path) => path
+                .path
+                .segments
+                .last()
+                .is_some_and(|segment| segment.ident == "BTreeMap"),
+            _ => false,
+        })
+    }
+
+    /// Parse a `BTreeMap<K, V>` from a stringified token stream such as
+    /// "BTreeMap < String , u32 >".
+    ///
+    /// Returns `None` if the key or value type is not supported.
+    pub fn from_str_tokens(tokens: &str, types: &TypeDeclarations) -> Option<Self> {
+        let ty: Type = syn::parse_str(tokens).ok()?;
+        let path = match ty {
+            Type::Path(path) => path,
+            _ => return None,
+        };
+
+        let last = path.path.segments.last()?;
+        if last.ident != "BTreeMap" {
+            return None;
+        }
+
+        let args = match &last.arguments {
+            PathArguments::AngleBracketed(args) => &args.args,
+            _ => return None,
+        };
+        if args.len() != 2 {
+            return None;
+        }
+
+        let mut generics = args.iter().map(|arg| match arg {
+            GenericArgument::Type(ty) => BridgedType::new_with_type(ty, types),
+            _ => None,
+        });
+        let key = generics.next()??;
+        let value = generics.next()??;
+
+        if !Self::is_supported_key(&key, types) || !Self::is_supported_value(&value, types) {
+            return None;
+        }
+
+        Some(BuiltInBTreeMap {
+            key: Box::new(key),
+            value: Box::new(value),
+        })
+    }
+
+    /// Keys must implement `Ord` and be `Vectorizable` on the Swift side.
+    fn is_supported_key(ty: &BridgedType, types: &TypeDeclarations) -> bool {
+        match ty {
+            BridgedType::StdLib(stdlib_ty) => matches!(
+                stdlib_ty,
+                StdLibType::U8
+                    | StdLibType::I8
+                    | StdLibType::U16
+                    | StdLibType::I16
+                    | StdLibType::U32
+                    | StdLibType::I32
+                    | StdLibType::U64
+                    | StdLibType::I64
+                    | StdLibType::Usize
+                    | StdLibType::Isize
+                    | StdLibType::Bool
+            ),
+            BridgedType::Bridgeable(_) => is_string(ty, types),
+            BridgedType::Foreign(_) => false,
+        }
+    }
+
+    /// Values must be `Vectorizable` on the Swift side.
+    fn is_supported_value(ty: &BridgedType, types: &TypeDeclarations) -> bool {
+        Self::is_supported_key(ty, types)
+            || matches!(
+                ty,
+                BridgedType::StdLib(StdLibType::F32) | BridgedType::StdLib(StdLibType::F64)
+            )
+    }
+
+    /// The type that the keys or values are stored as in the `Vec`s that are passed over FFI.
+    ///
+    /// `String` is stored as a `RustString` since Swift's `RustVec<RustString>` is backed by a
+    /// `Vec<RustString>`.
+    fn vec_element_rust_type(
+        ty: &BridgedType,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        if is_string(ty, types) {
+            quote! { #swift_bridge_path::string::RustString }
+        } else {
+            ty.to_rust_type_path(types)
+        }
+    }
+
+    /// The Swift type of a key or value.
+    ///
+    /// `u64` -> `UInt64`, `String` -> `RustString`.
+    fn element_swift_type(ty: &BridgedType, types: &TypeDeclarations) -> String {
+        // Keys and values always live on the Rust side, so we use the same type that an
+        // extern "Rust" function would return.
+        ty.to_swift_type(TypePosition::FnReturn(HostLang::Rust), types)
+    }
+
+    fn has_string_key_or_value(&self, types: &TypeDeclarations) -> bool {
+        is_string(&self.key, types) || is_string(&self.value, types)
+    }
+}
+
+fn is_string(ty: &BridgedType, types: &TypeDeclarations) -> bool {
+    match ty {
+        BridgedType::Bridgeable(b) => b.to_alpha_numeric_underscore_name(types) == "String",
+        _ => false,
+    }
+}
+
+impl BridgeableType for BuiltInBTreeMap {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&BuiltInResult> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        false
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        None
+    }
+
+    fn generate_custom_c_ffi_types(&self, _types: &TypeDeclarations) -> Option<CFfiStruct> {
+        None
+    }
+
+    fn to_rust_type_path(&self, types: &TypeDeclarations) -> TokenStream {
+        let key = self.key.to_rust_type_path(types);
+        let value = self.value.to_rust_type_path(types);
+        quote! { std::collections::BTreeMap<#key, #value> }
+    }
+
+    fn to_swift_type(&self, type_pos: TypePosition, types: &TypeDeclarations) -> String {
+        let swift_ty = format!(
+            "RustBTreeMap<{}, {}>",
+            Self::element_swift_type(&self.key, types),
+            Self::element_swift_type(&self.value, types)
+        );
+
+        match type_pos {
+            TypePosition::FnArg(func_host_lang, _) | TypePosition::FnReturn(func_host_lang) => {
+                if func_host_lang.is_rust() {
+                    swift_ty
+                } else {
+                    "__private__FfiBTreeMap".to_string()
+                }
+            }
+            TypePosition::SharedStructField => swift_ty,
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                "__private__FfiBTreeMap".to_string()
+            }
+        }
+    }
+
+    fn to_c_type(&self, _types: &TypeDeclarations) -> String {
+        "struct __private__FfiBTreeMap".to_string()
+    }
+
+    fn to_c_include(&self, _types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        None
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote! { #swift_bridge_path::btree_map::FfiBTreeMap }
+    }
+
+    fn to_ffi_compatible_option_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        unreachable!("Option<BTreeMap<K, V>> is rejected while parsing")
+    }
+
+    fn to_ffi_compatible_option_swift_type(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> String {
+        unreachable!("Option<BTreeMap<K, V>> is rejected while parsing")
+    }
+
+    fn to_ffi_compatible_option_c_type(&self) -> String {
+        unreachable!("Option<BTreeMap<K, V>> is rejected while parsing")
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+        _span: Span,
+    ) -> TokenStream {
+        let entries = if self.has_string_key_or_value(types) {
+            let key = if is_string(&self.key, types) {
+                quote! { #swift_bridge_path::string::RustString(key) }
+            } else {
+                quote! { key }
+            };
+            let value = if is_string(&self.value, types) {
+                quote! { #swift_bridge_path::string::RustString(value) }
+            } else {
+                quote! { value }
+            };
+
+            quote! {
+                #expression.into_iter().map(|(key, value)| (#key, #value))
+            }
+        } else {
+            quote! { #expression }
+        };
+
+        quote! {
+            #swift_bridge_path::btree_map::FfiBTreeMap::from_entries(#entries)
+        }
+    }
+
+    fn convert_option_rust_expression_to_ffi_type(
+        &self,
+        _expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
+        unreachable!("Option<BTreeMap<K, V>> is rejected while parsing")
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!("{}.intoFfiRepr()", expression)
+    }
+
+    fn convert_option_swift_expression_to_ffi_type(
+        &self,
+        _expression: &str,
+        _type_pos: TypePosition,
+    ) -> String {
+        unreachable!("Option<BTreeMap<K, V>> is rejected while parsing")
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        span: Span,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let key_ty = Self::vec_element_rust_type(&self.key, swift_bridge_path, types);
+        let value_ty = Self::vec_element_rust_type(&self.value, swift_bridge_path, types);
+
+        let entries = quote_spanned! {span=>
+            unsafe { #swift_bridge_path::btree_map::FfiBTreeMap::into_entries::<#key_ty, #value_ty>(#expression) }
+        };
+        let entries = if self.has_string_key_or_value(types) {
+            let key = if is_string(&self.key, types) {
+                quote! { key.0 }
+            } else {
+                quote! { key }
+            };
+            let value = if is_string(&self.value, types) {
+                quote! { value.0 }
+            } else {
+                quote! { value }
+            };
+
+            quote! { #entries.map(|(key, value)| (#key, #value)) }
+        } else {
+            entries
+        };
+
+        quote! {
+            #entries.collect::<std::collections::BTreeMap<_, _>>()
+        }
+    }
+
+    fn convert_ffi_option_expression_to_rust_type(&self, _expression: &TokenStream) -> TokenStream {
+        unreachable!("Option<BTreeMap<K, V>> is rejected while parsing")
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+        _types: &TypeDeclarations,
+    ) -> String {
+        format!("RustBTreeMap(ffi: {})", expression)
+    }
+
+    fn convert_ffi_option_expression_to_swift_type(&self, _expression: &str) -> String {
+        unreachable!("Option<BTreeMap<K, V>> is rejected while parsing")
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        _ok_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        unreachable!("Result<BTreeMap<K, V>, E> is rejected while parsing")
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        _err_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        unreachable!("Result<T, BTreeMap<K, V>> is rejected while parsing")
+    }
+
+    fn unused_option_none_val(&self, _swift_bridge_path: &Path) -> UnusedOptionNoneValue {
+        unreachable!("Option<BTreeMap<K, V>> is rejected while parsing")
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        tokens.starts_with("BTreeMap < ")
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match ty {
+            Type::Path(path) => {
+                let tokens = quote! { #path }.to_string();
+                Self::parse_token_stream_str(&tokens, types)
+            }
+            _ => None,
+        }
+    }
+
+    fn parse_token_stream_str(tokens: &str, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        Self::from_str_tokens(tokens, types)
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    // A `BTreeMap<String, V>` is passed as a `RustBTreeMap<RustString, V>`, so there is no
+    // `GenericIntoRustString` to declare on the Swift function.
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, types: &TypeDeclarations) -> String {
+        format!(
+            "BTreeMap{}{}",
+            self.key.to_alpha_numeric_underscore_name(types),
+            self.value.to_alpha_numeric_underscore_name(types)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify that we can parse a BTreeMap of supported key and value types.
+    #[test]
+    fn parse_btree_map_of_supported_types() {
+        for tokens in [
+            "BTreeMap < String , u32 >",
+            "BTreeMap < u8 , String >",
+            "BTreeMap < i64 , f64 >",
+        ] {
+            assert!(
+                BuiltInBTreeMap::from_str_tokens(tokens, &TypeDeclarations::default()).is_some(),
+                "{}",
+                tokens
+            );
+        }
+    }
+
+    /// Verify that we do not parse a BTreeMap whose keys do not implement `Ord`, or whose keys
+    /// or values are not supported.
+    #[test]
+    fn does_not_parse_btree_map_of_unsupported_types() {
+        for tokens in [
+            "BTreeMap < f32 , u8 >",
+            "BTreeMap < & str , u8 >",
+            "BTreeMap < u8 , & str >",
+        ] {
+            assert!(
+                BuiltInBTreeMap::from_str_tokens(tokens, &TypeDeclarations::default()).is_none(),
+                "{}",
+                tokens
+            );
+        }
+    }
+}
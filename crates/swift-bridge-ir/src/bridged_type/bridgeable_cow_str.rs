@@ -0,0 +1,275 @@
+use crate::bridged_type::{
+    BridgeableType, BuiltInResult, CFfiStruct, OnlyEncoding, TypePosition, UnusedOptionNoneValue,
+};
+use crate::parse::TypeDeclarations;
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned, ToTokens};
+use syn::{Path, Type};
+
+/// std::borrow::Cow<str>
+///
+/// Can only be returned from Rust functions, where it is seen by Swift as a `String`.
+/// A borrowed string crosses the bridge without allocating, while an owned string's buffer is
+/// freed by Swift after it has been copied.
+#[derive(Debug)]
+pub(crate) struct BuiltInCowStr;
+
+impl BuiltInCowStr {
+    /// Whether or not the type is, or contains, a `Cow<str>`.
+    /// `Cow<str>` is only supported as the return type of an `extern "Rust"` function.
+    pub fn type_contains_cow_str(ty: &Type) -> bool {
+        ty.to_token_stream()
+            .to_string()
+            .split(|c: char| !c.is_alphanumeric() && c != '_')
+            .any(|word| word == "Cow")
+    }
+}
+
+impl BridgeableType for BuiltInCowStr {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&BuiltInResult> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        false
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        None
+    }
+
+    fn generate_custom_c_ffi_types(&self, _types: &TypeDeclarations) -> Option<CFfiStruct> {
+        None
+    }
+
+    fn to_rust_type_path(&self, _types: &TypeDeclarations) -> TokenStream {
+        quote! { std::borrow::Cow<'static, str> }
+    }
+
+    fn to_swift_type(&self, _type_pos: TypePosition, _types: &TypeDeclarations) -> String {
+        "String".to_string()
+    }
+
+    fn to_c_type(&self, _types: &TypeDeclarations) -> String {
+        "struct __private__CowStr".to_string()
+    }
+
+    fn to_c_include(&self, _types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        None
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote! { #swift_bridge_path::string::FfiCowStr }
+    }
+
+    fn to_ffi_compatible_option_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote! { #swift_bridge_path::string::FfiCowStr }
+    }
+
+    fn to_ffi_compatible_option_swift_type(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> String {
+        "__private__CowStr".to_string()
+    }
+
+    fn to_ffi_compatible_option_c_type(&self) -> String {
+        "struct __private__CowStr".to_string()
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+        span: Span,
+    ) -> TokenStream {
+        quote_spanned! {span=>
+            #swift_bridge_path::string::FfiCowStr::from(#expression)
+        }
+    }
+
+    fn convert_option_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+    ) -> TokenStream {
+        quote! {
+            if let Some(val) = #expression {
+                #swift_bridge_path::string::FfiCowStr::from(val)
+            } else {
+                #swift_bridge_path::string::FfiCowStr::none()
+            }
+        }
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        _expression: &str,
+        _types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        unimplemented!("Cow<str> can only be returned from Rust functions")
+    }
+
+    fn convert_option_swift_expression_to_ffi_type(
+        &self,
+        _expression: &str,
+        _type_pos: TypePosition,
+    ) -> String {
+        unimplemented!("Cow<str> can only be returned from Rust functions")
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        _expression: &TokenStream,
+        _span: Span,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        unimplemented!("Cow<str> can only be returned from Rust functions")
+    }
+
+    fn convert_ffi_option_expression_to_rust_type(&self, _expression: &TokenStream) -> TokenStream {
+        unimplemented!("Cow<str> can only be returned from Rust functions")
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+        _types: &TypeDeclarations,
+    ) -> String {
+        format!("{}.toString()", expression)
+    }
+
+    fn convert_ffi_option_expression_to_swift_type(&self, expression: &str) -> String {
+        format!("{}.toOptionalString()", expression)
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        _ok_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!("Support Result<Cow<str>, E>")
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        _err_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!("Support Result<T, Cow<str>>")
+    }
+
+    fn unused_option_none_val(&self, swift_bridge_path: &Path) -> UnusedOptionNoneValue {
+        UnusedOptionNoneValue {
+            rust: quote! {
+                #swift_bridge_path::string::FfiCowStr::none()
+            },
+            swift: "__private__CowStr(start: nil, len: 0, capacity: 0, is_owned: false)".into(),
+        }
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        let tokens = tokens.trim_start_matches("std :: borrow :: ");
+
+        // Cow<str> or Cow<'a, str>
+        tokens == "Cow < str >" || (tokens.starts_with("Cow < '") && tokens.ends_with(", str >"))
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match ty {
+            Type::Path(path) => Self::parse_token_stream_str(
+                path.path.segments.to_token_stream().to_string().as_str(),
+                types,
+            ),
+            _ => None,
+        }
+    }
+
+    fn parse_token_stream_str(tokens: &str, _types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        Self::can_parse_token_stream_str(tokens).then_some(BuiltInCowStr)
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, _types: &TypeDeclarations) -> String {
+        "CowStr".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify that we parse `Cow<str>` with and without a lifetime and its full path.
+    #[test]
+    fn parse_cow_str() {
+        let types = TypeDeclarations::default();
+
+        for tokens in [
+            "Cow < str >",
+            "Cow < 'static , str >",
+            "std :: borrow :: Cow < 'a , str >",
+        ] {
+            assert!(BuiltInCowStr::parse_token_stream_str(tokens, &types).is_some());
+        }
+        assert!(BuiltInCowStr::parse_token_stream_str("Cow < [u8] >", &types).is_none());
+    }
+}
@@ -0,0 +1,463 @@
+use crate::bridged_type::{
+    BridgeableType, BuiltInResult, CFfiStruct, OnlyEncoding, SharedStruct, StructSwiftRepr,
+    TypePosition, UnusedOptionNoneValue,
+};
+use crate::parse::{SharedTypeDeclaration, TypeDeclaration, TypeDeclarations};
+use crate::SWIFT_BRIDGE_PREFIX;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{quote, quote_spanned, ToTokens};
+use syn::{Path, Type};
+
+/// swift_bridge::cursor::Cursor<T>, where T is a shared struct.
+///
+/// Enabled by the swift-bridge crate's `cursor` feature. Passed over FFI as a pointer to a boxed
+/// `Cursor<T>` and seen by Swift as a `RustCursor<T>`, which reads the cursor's rows one batch at a
+/// time using the `{T}$_cursor_next_batch` and `{T}$_cursor_free` functions that are generated
+/// for `T`.
+#[derive(Debug)]
+pub(crate) struct BuiltInCursor {
+    pub row: SharedStruct,
+}
+
+impl BuiltInCursor {
+    /// Parse a `Cursor<T>` from a stringified token stream such as "Cursor < SomeStruct >".
+    ///
+    /// Returns `None` if `T` is not a non-generic shared struct with `swift_repr = "struct"` that
+    /// has at least one field.
+    pub fn from_str_tokens(tokens: &str, types: &TypeDeclarations) -> Option<Self> {
+        let inner = tokens
+            .trim_start_matches("swift_bridge :: ")
+            .trim_start_matches("cursor :: ");
+        let inner = inner.strip_prefix("Cursor < ")?.strip_suffix(" >")?;
+
+        let row = match types.get(inner)? {
+            TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct)) => shared_struct,
+            _ => return None,
+        };
+        if row.swift_repr != StructSwiftRepr::Structure
+            || row.instantiation.is_some()
+            || row.only_encoding().is_some()
+        {
+            return None;
+        }
+
+        Some(BuiltInCursor { row: row.clone() })
+    }
+
+    fn row_rust_type(&self) -> TokenStream {
+        let name = self.row.rust_type_tokens();
+        if self.row.already_declared {
+            quote! { super::#name }
+        } else {
+            name
+        }
+    }
+
+    /// `Cursor<SomeStruct>` -> `__swift_bridge__$SomeStruct$_cursor_next_batch`
+    fn next_batch_ffi_name(&self) -> String {
+        format!(
+            "{}${}$_cursor_next_batch",
+            SWIFT_BRIDGE_PREFIX, self.row.name
+        )
+    }
+
+    /// `Cursor<SomeStruct>` -> `__swift_bridge__$SomeStruct$_cursor_free`
+    fn free_ffi_name(&self) -> String {
+        format!("{}${}$_cursor_free", SWIFT_BRIDGE_PREFIX, self.row.name)
+    }
+
+    fn swift_class_name(&self) -> String {
+        format!("RustCursor<{}>", self.row.swift_name_string())
+    }
+
+    /// Create a `RustCursor<T>` that reads its rows using the functions that were generated
+    /// for `T`.
+    fn swift_class_init(&self, ptr: &str) -> String {
+        format!(
+            "{class}(ptr: {ptr}, nextBatch: {{ {class}.readBatch({next_batch}($0), {{ (row: {ffi_row}) in row.intoSwiftRepr() }}) }}, free: {{ {free}($0) }})",
+            class = self.swift_class_name(),
+            ptr = ptr,
+            next_batch = self.next_batch_ffi_name(),
+            ffi_row = self.row.ffi_name_string(),
+            free = self.free_ffi_name()
+        )
+    }
+}
+
+impl BridgeableType for BuiltInCursor {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&BuiltInResult> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        true
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        let row = self.row_rust_type();
+        let name = &self.row.name;
+
+        let next_batch_link_name = self.next_batch_ffi_name();
+        let next_batch_fn_name = Ident::new(
+            &format!("{}{}__cursor_next_batch", SWIFT_BRIDGE_PREFIX, name),
+            name.span(),
+        );
+        let free_link_name = self.free_ffi_name();
+        let free_fn_name = Ident::new(
+            &format!("{}{}__cursor_free", SWIFT_BRIDGE_PREFIX, name),
+            name.span(),
+        );
+
+        Some(vec![quote! {
+            #[export_name = #next_batch_link_name]
+            pub extern "C" fn #next_batch_fn_name(
+                this: *mut #swift_bridge_path::cursor::Cursor<#row>
+            ) -> #swift_bridge_path::cursor::FfiCursorBatch {
+                let rows: Vec<_> = unsafe { &mut *this }
+                    .next_batch()
+                    .into_iter()
+                    .map(|row| row.into_ffi_repr())
+                    .collect();
+                #swift_bridge_path::cursor::FfiCursorBatch::new(rows)
+            }
+
+            #[export_name = #free_link_name]
+            pub extern "C" fn #free_fn_name(this: *mut #swift_bridge_path::cursor::Cursor<#row>) {
+                drop(unsafe { Box::from_raw(this) });
+            }
+        }])
+    }
+
+    fn generate_custom_c_ffi_types(&self, _types: &TypeDeclarations) -> Option<CFfiStruct> {
+        let c_ffi_type = format!(
+            "struct __private__CursorBatch {next_batch}(void* self);\nvoid {free}(void* self);",
+            next_batch = self.next_batch_ffi_name(),
+            free = self.free_ffi_name()
+        );
+
+        Some(CFfiStruct {
+            c_ffi_type,
+            fields: vec![],
+        })
+    }
+
+    fn to_rust_type_path(&self, _types: &TypeDeclarations) -> TokenStream {
+        let row = self.row_rust_type();
+        quote! { swift_bridge::cursor::Cursor<#row> }
+    }
+
+    fn to_swift_type(&self, type_pos: TypePosition, _types: &TypeDeclarations) -> String {
+        match type_pos {
+            TypePosition::FnArg(func_host_lang, _) | TypePosition::FnReturn(func_host_lang) => {
+                if func_host_lang.is_rust() {
+                    self.swift_class_name()
+                } else {
+                    "UnsafeMutableRawPointer".to_string()
+                }
+            }
+            TypePosition::SharedStructField => self.swift_class_name(),
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                "UnsafeMutableRawPointer?".to_string()
+            }
+        }
+    }
+
+    fn to_c_type(&self, _types: &TypeDeclarations) -> String {
+        "void*".to_string()
+    }
+
+    fn to_c_include(&self, _types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        None
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        let row = self.row_rust_type();
+        quote! { *mut #swift_bridge_path::cursor::Cursor<#row> }
+    }
+
+    fn to_ffi_compatible_option_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        self.to_ffi_compatible_rust_type(swift_bridge_path, types)
+    }
+
+    fn to_ffi_compatible_option_swift_type(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> String {
+        "UnsafeMutableRawPointer?".to_string()
+    }
+
+    fn to_ffi_compatible_option_c_type(&self) -> String {
+        "void*".to_string()
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+        span: Span,
+    ) -> TokenStream {
+        quote_spanned! {span=>
+            Box::into_raw(Box::new( #expression ))
+        }
+    }
+
+    fn convert_option_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
+        quote! {
+            if let Some(val) = #expression {
+                Box::into_raw(Box::new(val))
+            } else {
+                std::ptr::null_mut()
+            }
+        }
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!(
+            "{{ let val = {value}; val.isOwned = false; return val.ptr }}()",
+            value = expression
+        )
+    }
+
+    fn convert_option_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!(
+            "{{ if let val = {expression} {{ val.isOwned = false; return val.ptr }} else {{ return nil }} }}()",
+            expression = expression
+        )
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        span: Span,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote_spanned! {span=>
+            unsafe { * Box::from_raw(#expression) }
+        }
+    }
+
+    fn convert_ffi_option_expression_to_rust_type(&self, expression: &TokenStream) -> TokenStream {
+        quote! {
+            {
+                let val = #expression;
+                if val.is_null() {
+                    None
+                } else {
+                    Some(unsafe { * Box::from_raw(val) })
+                }
+            }
+        }
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        type_pos: TypePosition,
+        _types: &TypeDeclarations,
+    ) -> String {
+        match type_pos {
+            TypePosition::FnArg(_, _)
+            | TypePosition::FnReturn(_)
+            | TypePosition::SharedStructField => self.swift_class_init(expression),
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                self.swift_class_init(&format!("{}!", expression))
+            }
+        }
+    }
+
+    fn convert_ffi_option_expression_to_swift_type(&self, expression: &str) -> String {
+        format!(
+            "{{ let val = {expression}; if val != nil {{ return {init} }} else {{ return nil }} }}()",
+            expression = expression,
+            init = self.swift_class_init("val!")
+        )
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        ok_ffi_value: &TokenStream,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        let row = self.row_rust_type();
+        quote! {
+            unsafe { *Box::from_raw(#ok_ffi_value.ok_or_err as *mut #swift_bridge_path::cursor::Cursor<#row>) }
+        }
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        err_ffi_value: &TokenStream,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        let row = self.row_rust_type();
+        quote! {
+            unsafe { *Box::from_raw(#err_ffi_value.ok_or_err as *mut #swift_bridge_path::cursor::Cursor<#row>) }
+        }
+    }
+
+    fn unused_option_none_val(&self, _swift_bridge_path: &Path) -> UnusedOptionNoneValue {
+        UnusedOptionNoneValue {
+            rust: quote! { std::ptr::null_mut() },
+            swift: "nil".to_string(),
+        }
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        tokens
+            .trim_start_matches("swift_bridge :: ")
+            .trim_start_matches("cursor :: ")
+            .starts_with("Cursor < ")
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match ty {
+            Type::Path(path) => Self::parse_token_stream_str(
+                path.path.segments.to_token_stream().to_string().as_str(),
+                types,
+            ),
+            _ => None,
+        }
+    }
+
+    fn parse_token_stream_str(tokens: &str, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        Self::from_str_tokens(tokens, types)
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, _types: &TypeDeclarations) -> String {
+        format!("Cursor{}", self.row.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::parse_ok;
+
+    /// Verify that we parse a `Cursor` of a shared struct with and without its full path.
+    #[test]
+    fn parse_cursor_of_shared_struct() {
+        let module = parse_ok(quote! {
+            mod ffi {
+                struct SomeRow {
+                    field: u8
+                }
+            }
+        });
+
+        for tokens in [
+            "Cursor < SomeRow >",
+            "cursor :: Cursor < SomeRow >",
+            "swift_bridge :: cursor :: Cursor < SomeRow >",
+        ] {
+            assert!(
+                BuiltInCursor::from_str_tokens(tokens, &module.types).is_some(),
+                "{}",
+                tokens
+            );
+        }
+    }
+
+    /// Verify that we do not parse a `Cursor` of a type that is not a shared struct with fields.
+    #[test]
+    fn does_not_parse_cursor_of_unsupported_types() {
+        let module = parse_ok(quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "class")]
+                struct ClassStruct {
+                    field: u8
+                }
+
+                struct EmptyStruct;
+
+                extern "Rust" {
+                    type SomeType;
+                }
+            }
+        });
+
+        for tokens in [
+            "Cursor < u8 >",
+            "Cursor < SomeType >",
+            "Cursor < ClassStruct >",
+            "Cursor < EmptyStruct >",
+            "Cursor < UnknownType >",
+        ] {
+            assert!(
+                BuiltInCursor::from_str_tokens(tokens, &module.types).is_none(),
+                "{}",
+                tokens
+            );
+        }
+    }
+}
@@ -0,0 +1,341 @@
+use crate::bridged_type::{
+    BridgeableType, BuiltInResult, CFfiStruct, OnlyEncoding, TypePosition, UnusedOptionNoneValue,
+};
+use crate::parse::TypeDeclarations;
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned, ToTokens};
+use syn::{Path, Type};
+
+/// chrono::DateTime<Utc> or time::OffsetDateTime
+///
+/// Passed over FFI the same way as a `std::time::SystemTime` and seen by Swift as a `Date`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) enum BuiltInDateTime {
+    /// chrono::DateTime<Utc>
+    Chrono,
+    /// time::OffsetDateTime
+    Time,
+}
+
+impl BuiltInDateTime {
+    fn rust_type_path(&self) -> TokenStream {
+        match self {
+            BuiltInDateTime::Chrono => {
+                quote! { swift_bridge::chrono::DateTime<swift_bridge::chrono::Utc> }
+            }
+            BuiltInDateTime::Time => quote! { swift_bridge::time::OffsetDateTime },
+        }
+    }
+
+    /// Parse a `DateTime<Utc>` or `OffsetDateTime`, with or without its crate's path.
+    fn from_tokens(tokens: &str) -> Option<Self> {
+        let tokens = tokens.trim_start_matches("swift_bridge :: ");
+
+        if let Some(utc) = tokens
+            .trim_start_matches("chrono :: ")
+            .strip_prefix("DateTime < ")
+            .and_then(|generics| generics.strip_suffix(" >"))
+        {
+            let utc = utc
+                .trim_start_matches("swift_bridge :: ")
+                .trim_start_matches("chrono :: ");
+            return (utc == "Utc").then_some(BuiltInDateTime::Chrono);
+        }
+
+        (tokens.trim_start_matches("time :: ") == "OffsetDateTime").then_some(BuiltInDateTime::Time)
+    }
+}
+
+impl BridgeableType for BuiltInDateTime {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&BuiltInResult> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        false
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        None
+    }
+
+    fn generate_custom_c_ffi_types(&self, _types: &TypeDeclarations) -> Option<CFfiStruct> {
+        None
+    }
+
+    fn to_rust_type_path(&self, _types: &TypeDeclarations) -> TokenStream {
+        self.rust_type_path()
+    }
+
+    fn to_swift_type(&self, type_pos: TypePosition, _types: &TypeDeclarations) -> String {
+        match type_pos {
+            TypePosition::FnArg(host_lang, _) | TypePosition::FnReturn(host_lang) => {
+                if host_lang.is_rust() {
+                    "Date".to_string()
+                } else {
+                    "__private__SystemTime".to_string()
+                }
+            }
+            TypePosition::SharedStructField => "Date".to_string(),
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                "__private__SystemTime".to_string()
+            }
+        }
+    }
+
+    fn to_c_type(&self, _types: &TypeDeclarations) -> String {
+        "struct __private__SystemTime".to_string()
+    }
+
+    fn to_c_include(&self, _types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        None
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote! { #swift_bridge_path::system_time::FfiSystemTime }
+    }
+
+    fn to_ffi_compatible_option_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote! { #swift_bridge_path::system_time::OptionSystemTime }
+    }
+
+    fn to_ffi_compatible_option_swift_type(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> String {
+        "__private__OptionSystemTime".to_string()
+    }
+
+    fn to_ffi_compatible_option_c_type(&self) -> String {
+        "struct __private__OptionSystemTime".to_string()
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+        span: Span,
+    ) -> TokenStream {
+        quote_spanned! {span=>
+            #swift_bridge_path::system_time::FfiSystemTime::from(#expression)
+        }
+    }
+
+    fn convert_option_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+    ) -> TokenStream {
+        let unused_none = self.unused_option_none_val(swift_bridge_path).rust;
+
+        quote! {
+            if let Some(val) = #expression {
+                #swift_bridge_path::system_time::OptionSystemTime {
+                    val: #swift_bridge_path::system_time::FfiSystemTime::from(val),
+                    is_some: true
+                }
+            } else {
+                #swift_bridge_path::system_time::OptionSystemTime { val: #unused_none, is_some: false }
+            }
+        }
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!("__private__SystemTime({})", expression)
+    }
+
+    fn convert_option_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!("__private__OptionSystemTime({})", expression)
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        span: Span,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        let ty = self.rust_type_path();
+        quote_spanned! {span=>
+            <#ty>::from(#expression)
+        }
+    }
+
+    fn convert_ffi_option_expression_to_rust_type(&self, expression: &TokenStream) -> TokenStream {
+        let ty = self.rust_type_path();
+        quote! {
+            {
+                let val = #expression;
+                if val.is_some {
+                    Some(<#ty>::from(val.val))
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+        _types: &TypeDeclarations,
+    ) -> String {
+        format!("{}.toDate()", expression)
+    }
+
+    fn convert_ffi_option_expression_to_swift_type(&self, expression: &str) -> String {
+        format!("{}.intoSwiftRepr()", expression)
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        _ok_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!("Support Result<DateTime, E>")
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        _err_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!("Support Result<T, DateTime>")
+    }
+
+    fn unused_option_none_val(&self, swift_bridge_path: &Path) -> UnusedOptionNoneValue {
+        UnusedOptionNoneValue {
+            rust: quote! {
+                #swift_bridge_path::system_time::FfiSystemTime { secs: 0, nanos: 0 }
+            },
+            swift: "__private__SystemTime(secs: 0, nanos: 0)".into(),
+        }
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        Self::from_tokens(tokens).is_some()
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match ty {
+            Type::Path(path) => Self::parse_token_stream_str(
+                path.path.segments.to_token_stream().to_string().as_str(),
+                types,
+            ),
+            _ => None,
+        }
+    }
+
+    fn parse_token_stream_str(tokens: &str, _types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        Self::from_tokens(tokens)
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, _types: &TypeDeclarations) -> String {
+        match self {
+            BuiltInDateTime::Chrono => "DateTimeUtc".to_string(),
+            BuiltInDateTime::Time => "OffsetDateTime".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify that we parse `DateTime<Utc>` and `OffsetDateTime` with and without their full paths.
+    #[test]
+    fn parse_date_time() {
+        let types = TypeDeclarations::default();
+
+        for tokens in [
+            "DateTime < Utc >",
+            "chrono :: DateTime < chrono :: Utc >",
+            "swift_bridge :: chrono :: DateTime < swift_bridge :: chrono :: Utc >",
+        ] {
+            assert_eq!(
+                BuiltInDateTime::parse_token_stream_str(tokens, &types),
+                Some(BuiltInDateTime::Chrono)
+            );
+        }
+        for tokens in [
+            "OffsetDateTime",
+            "time :: OffsetDateTime",
+            "swift_bridge :: time :: OffsetDateTime",
+        ] {
+            assert_eq!(
+                BuiltInDateTime::parse_token_stream_str(tokens, &types),
+                Some(BuiltInDateTime::Time)
+            );
+        }
+
+        assert!(BuiltInDateTime::parse_token_stream_str("DateTime < Local >", &types).is_none());
+        assert!(BuiltInDateTime::parse_token_stream_str("PrimitiveDateTime", &types).is_none());
+    }
+}
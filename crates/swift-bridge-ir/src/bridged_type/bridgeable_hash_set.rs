@@ -0,0 +1,379 @@
+use crate::bridged_type::{
+    BridgeableType, BridgedType, BuiltInResult, CFfiStruct, OnlyEncoding, StdLibType, TypePosition,
+    UnusedOptionNoneValue,
+};
+use crate::parse::{HostLang, TypeDeclarations};
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
+use std::str::FromStr;
+use syn::{Path, Type};
+
+/// HashSet<T>
+///
+/// The element types that can be stored in a bridged `HashSet<T>` are defined in
+/// `src/std_bridge/rust_hash_set.rs`.
+#[derive(Debug)]
+pub(crate) struct BuiltInHashSet {
+    pub ty: Box<BridgedType>,
+}
+
+impl BuiltInHashSet {
+    /// Parse a `HashSet<T>` from a stringified token stream such as "HashSet < u64 >".
+    ///
+    /// Returns `None` if the element type is not supported.
+    pub fn from_str_tokens(tokens: &str, types: &TypeDeclarations) -> Option<Self> {
+        let inner = tokens.trim_start_matches("HashSet < ");
+        let inner = inner.trim_end_matches(" >");
+
+        let inner: Type = syn::parse2(TokenStream::from_str(inner).ok()?).ok()?;
+        let inner = BridgedType::new_with_type(&inner, types)?;
+
+        if !Self::is_supported_element(&inner, types) {
+            return None;
+        }
+
+        Some(BuiltInHashSet {
+            ty: Box::new(inner),
+        })
+    }
+
+    /// Whether or not the type has a `HashSet<T>` implementation in the `swift_bridge` crate.
+    fn is_supported_element(ty: &BridgedType, types: &TypeDeclarations) -> bool {
+        match ty {
+            BridgedType::StdLib(stdlib_ty) => matches!(
+                stdlib_ty,
+                StdLibType::U8
+                    | StdLibType::I8
+                    | StdLibType::U16
+                    | StdLibType::I16
+                    | StdLibType::U32
+                    | StdLibType::I32
+                    | StdLibType::U64
+                    | StdLibType::I64
+                    | StdLibType::Usize
+                    | StdLibType::Isize
+                    | StdLibType::Bool
+            ),
+            BridgedType::Bridgeable(b) => b.to_alpha_numeric_underscore_name(types) == "String",
+            BridgedType::Foreign(_) => false,
+        }
+    }
+
+    /// The Swift type of the elements in the set.
+    ///
+    /// `u64` -> `UInt64`, `String` -> `RustString`.
+    fn element_swift_type(&self, types: &TypeDeclarations) -> String {
+        // Elements always live on the Rust side, so we use the same type that an extern "Rust"
+        // function would return.
+        self.ty
+            .to_swift_type(TypePosition::FnReturn(HostLang::Rust), types)
+    }
+}
+
+impl BridgeableType for BuiltInHashSet {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&BuiltInResult> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        true
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        None
+    }
+
+    fn generate_custom_c_ffi_types(&self, _types: &TypeDeclarations) -> Option<CFfiStruct> {
+        None
+    }
+
+    fn to_rust_type_path(&self, types: &TypeDeclarations) -> TokenStream {
+        let ty = self.ty.to_rust_type_path(types);
+        quote! { std::collections::HashSet<#ty> }
+    }
+
+    fn to_swift_type(&self, type_pos: TypePosition, types: &TypeDeclarations) -> String {
+        match type_pos {
+            TypePosition::FnArg(func_host_lang, _) | TypePosition::FnReturn(func_host_lang) => {
+                if func_host_lang.is_rust() {
+                    format!("RustHashSet<{}>", self.element_swift_type(types))
+                } else {
+                    "UnsafeMutableRawPointer".to_string()
+                }
+            }
+            TypePosition::SharedStructField => {
+                format!("RustHashSet<{}>", self.element_swift_type(types))
+            }
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                "UnsafeMutableRawPointer?".to_string()
+            }
+        }
+    }
+
+    fn to_c_type(&self, _types: &TypeDeclarations) -> String {
+        "void*".to_string()
+    }
+
+    fn to_c_include(&self, _types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        None
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let ty = self.to_rust_type_path(types);
+        quote! { *mut #ty }
+    }
+
+    fn to_ffi_compatible_option_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        self.to_ffi_compatible_rust_type(swift_bridge_path, types)
+    }
+
+    fn to_ffi_compatible_option_swift_type(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> String {
+        "UnsafeMutableRawPointer?".to_string()
+    }
+
+    fn to_ffi_compatible_option_c_type(&self) -> String {
+        "void*".to_string()
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+        _span: Span,
+    ) -> TokenStream {
+        quote! {
+            Box::into_raw(Box::new( #expression ))
+        }
+    }
+
+    fn convert_option_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
+        quote! {
+            if let Some(val) = #expression {
+                Box::into_raw(Box::new(val))
+            } else {
+                std::ptr::null_mut()
+            }
+        }
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!(
+            "{{ let val = {value}; val.isOwned = false; return val.ptr }}()",
+            value = expression
+        )
+    }
+
+    fn convert_option_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!(
+            "{{ if let val = {expression} {{ val.isOwned = false; return val.ptr }} else {{ return nil }} }}()",
+            expression = expression
+        )
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        span: Span,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote_spanned! {span=>
+            unsafe { * Box::from_raw(#expression) }
+        }
+    }
+
+    fn convert_ffi_option_expression_to_rust_type(&self, expression: &TokenStream) -> TokenStream {
+        // The expression might be a call to a Swift function, so we only evaluate it once.
+        quote! {
+            {
+                let val = #expression;
+                if val.is_null() {
+                    None
+                } else {
+                    Some(unsafe { * Box::from_raw(val) } )
+                }
+            }
+        }
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        type_pos: TypePosition,
+        _types: &TypeDeclarations,
+    ) -> String {
+        match type_pos {
+            TypePosition::FnArg(_, _)
+            | TypePosition::FnReturn(_)
+            | TypePosition::SharedStructField => {
+                format!("RustHashSet(ptr: {})", expression)
+            }
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                format!("RustHashSet(ptr: {}!)", expression)
+            }
+        }
+    }
+
+    fn convert_ffi_option_expression_to_swift_type(&self, expression: &str) -> String {
+        format!(
+            "{{ let val = {expression}; if val != nil {{ return RustHashSet(ptr: val!) }} else {{ return nil }} }}()",
+            expression = expression
+        )
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        ok_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let ty = self.to_rust_type_path(types);
+        quote! {
+            unsafe { *Box::from_raw(#ok_ffi_value.ok_or_err as *mut #ty) }
+        }
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        err_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let ty = self.to_rust_type_path(types);
+        quote! {
+            unsafe { *Box::from_raw(#err_ffi_value.ok_or_err as *mut #ty) }
+        }
+    }
+
+    fn unused_option_none_val(&self, _swift_bridge_path: &Path) -> UnusedOptionNoneValue {
+        UnusedOptionNoneValue {
+            rust: quote! { std::ptr::null_mut() },
+            swift: "nil".to_string(),
+        }
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        tokens.starts_with("HashSet < ")
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match ty {
+            Type::Path(path) => {
+                let tokens = quote! { #path }.to_string();
+                Self::parse_token_stream_str(&tokens, types)
+            }
+            _ => None,
+        }
+    }
+
+    fn parse_token_stream_str(tokens: &str, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        Self::from_str_tokens(tokens, types)
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    // A `HashSet<String>` is passed as a `RustHashSet<RustString>`, so unlike a `String` argument
+    // there is no `GenericIntoRustString` to declare on the Swift function.
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, types: &TypeDeclarations) -> String {
+        format!("HashSet{}", self.ty.to_alpha_numeric_underscore_name(types))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify that we can parse a HashSet of a supported element type.
+    #[test]
+    fn parse_hash_set_of_supported_elements() {
+        for tokens in ["HashSet < u64 >", "HashSet < String >", "HashSet < bool >"] {
+            assert!(
+                BuiltInHashSet::from_str_tokens(tokens, &TypeDeclarations::default()).is_some(),
+                "{}",
+                tokens
+            );
+        }
+    }
+
+    /// Verify that we do not parse a HashSet of an element type that the swift_bridge crate does
+    /// not provide a HashSet implementation for.
+    #[test]
+    fn does_not_parse_hash_set_of_unsupported_elements() {
+        for tokens in ["HashSet < f32 >", "HashSet < & str >"] {
+            assert!(
+                BuiltInHashSet::from_str_tokens(tokens, &TypeDeclarations::default()).is_none(),
+                "{}",
+                tokens
+            );
+        }
+    }
+}
@@ -0,0 +1,367 @@
+use crate::bridged_type::{
+    BridgeableType, BuiltInResult, CFfiStruct, OnlyEncoding, TypePosition, UnusedOptionNoneValue,
+};
+use crate::parse::TypeDeclarations;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{quote, quote_spanned, ToTokens};
+use syn::{Path, Type};
+
+/// swift_bridge::measurement::{Length, Mass, Temperature, Energy, Speed}
+///
+/// Enabled by the swift-bridge crate's `measurement` feature. Passed over FFI as its value and
+/// the index of its unit and seen by Swift as a Foundation `Measurement`.
+#[derive(Debug)]
+pub(crate) struct BuiltInMeasurement {
+    kind: MeasurementKind,
+}
+
+#[derive(Debug, Copy, Clone)]
+enum MeasurementKind {
+    Length,
+    Mass,
+    Temperature,
+    Energy,
+    Speed,
+}
+
+impl MeasurementKind {
+    fn from_name(name: &str) -> Option<Self> {
+        let kind = match name {
+            "Length" => MeasurementKind::Length,
+            "Mass" => MeasurementKind::Mass,
+            "Temperature" => MeasurementKind::Temperature,
+            "Energy" => MeasurementKind::Energy,
+            "Speed" => MeasurementKind::Speed,
+            _ => return None,
+        };
+        Some(kind)
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            MeasurementKind::Length => "Length",
+            MeasurementKind::Mass => "Mass",
+            MeasurementKind::Temperature => "Temperature",
+            MeasurementKind::Energy => "Energy",
+            MeasurementKind::Speed => "Speed",
+        }
+    }
+
+    /// The Foundation `Dimension` subclass, i.e. `UnitLength`.
+    fn swift_unit(&self) -> String {
+        format!("Unit{}", self.name())
+    }
+
+    /// The Swift array that a unit's index over FFI refers to, i.e. `__private__lengthUnits`.
+    /// Defined in crates/swift-bridge-build/src/generate_core/measurement_support.swift
+    fn swift_units(&self) -> String {
+        format!("__private__{}Units", self.name().to_lowercase())
+    }
+}
+
+impl BuiltInMeasurement {
+    /// The names of the measurement types, used to tell whether or not a module uses Foundation.
+    pub fn type_names() -> [&'static str; 5] {
+        ["Length", "Mass", "Temperature", "Energy", "Speed"]
+    }
+}
+
+impl BridgeableType for BuiltInMeasurement {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&BuiltInResult> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        false
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        None
+    }
+
+    fn generate_custom_c_ffi_types(&self, _types: &TypeDeclarations) -> Option<CFfiStruct> {
+        None
+    }
+
+    fn to_rust_type_path(&self, _types: &TypeDeclarations) -> TokenStream {
+        let name = Ident::new(self.kind.name(), Span::call_site());
+        quote! { swift_bridge::measurement::#name }
+    }
+
+    fn to_swift_type(&self, type_pos: TypePosition, _types: &TypeDeclarations) -> String {
+        let measurement = format!("Measurement<{}>", self.kind.swift_unit());
+
+        match type_pos {
+            TypePosition::FnArg(host_lang, _) | TypePosition::FnReturn(host_lang) => {
+                if host_lang.is_rust() {
+                    measurement
+                } else {
+                    "__private__Measurement".to_string()
+                }
+            }
+            TypePosition::SharedStructField => measurement,
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                "__private__Measurement".to_string()
+            }
+        }
+    }
+
+    fn to_c_type(&self, _types: &TypeDeclarations) -> String {
+        "struct __private__Measurement".to_string()
+    }
+
+    fn to_c_include(&self, _types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        None
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote! { #swift_bridge_path::measurement::FfiMeasurement }
+    }
+
+    fn to_ffi_compatible_option_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote! { #swift_bridge_path::measurement::OptionMeasurement }
+    }
+
+    fn to_ffi_compatible_option_swift_type(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> String {
+        "__private__OptionMeasurement".to_string()
+    }
+
+    fn to_ffi_compatible_option_c_type(&self) -> String {
+        "struct __private__OptionMeasurement".to_string()
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+        span: Span,
+    ) -> TokenStream {
+        quote_spanned! {span=>
+            #swift_bridge_path::measurement::FfiMeasurement::from(#expression)
+        }
+    }
+
+    fn convert_option_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+    ) -> TokenStream {
+        let unused_none = self.unused_option_none_val(swift_bridge_path).rust;
+
+        quote! {
+            if let Some(val) = #expression {
+                #swift_bridge_path::measurement::OptionMeasurement {
+                    val: #swift_bridge_path::measurement::FfiMeasurement::from(val),
+                    is_some: true
+                }
+            } else {
+                #swift_bridge_path::measurement::OptionMeasurement { val: #unused_none, is_some: false }
+            }
+        }
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!(
+            "__private__Measurement({}, {})",
+            expression,
+            self.kind.swift_units()
+        )
+    }
+
+    fn convert_option_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!(
+            "__private__OptionMeasurement({}, {})",
+            expression,
+            self.kind.swift_units()
+        )
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        span: Span,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        let name = Ident::new(self.kind.name(), span);
+
+        quote_spanned! {span=>
+            #swift_bridge_path::measurement::#name::from(#expression)
+        }
+    }
+
+    fn convert_ffi_option_expression_to_rust_type(&self, expression: &TokenStream) -> TokenStream {
+        let name = Ident::new(self.kind.name(), Span::call_site());
+
+        quote! {
+            {
+                let val = #expression;
+                if val.is_some {
+                    Some(swift_bridge::measurement::#name::from(val.val))
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+        _types: &TypeDeclarations,
+    ) -> String {
+        format!(
+            "{}.intoMeasurement({})",
+            expression,
+            self.kind.swift_units()
+        )
+    }
+
+    fn convert_ffi_option_expression_to_swift_type(&self, expression: &str) -> String {
+        format!("{}.intoSwiftRepr({})", expression, self.kind.swift_units())
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        _ok_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!("Support Result<Measurement, E>")
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        _err_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!("Support Result<T, Measurement>")
+    }
+
+    fn unused_option_none_val(&self, swift_bridge_path: &Path) -> UnusedOptionNoneValue {
+        UnusedOptionNoneValue {
+            rust: quote! {
+                #swift_bridge_path::measurement::FfiMeasurement { value: 0., unit: 0 }
+            },
+            swift: "__private__Measurement(value: 0, unit: 0)".into(),
+        }
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        let name = tokens
+            .trim_start_matches("swift_bridge :: ")
+            .trim_start_matches("measurement :: ");
+        MeasurementKind::from_name(name).is_some()
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match ty {
+            Type::Path(path) => Self::parse_token_stream_str(
+                path.path.segments.to_token_stream().to_string().as_str(),
+                types,
+            ),
+            _ => None,
+        }
+    }
+
+    fn parse_token_stream_str(tokens: &str, _types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        let name = tokens
+            .trim_start_matches("swift_bridge :: ")
+            .trim_start_matches("measurement :: ");
+        MeasurementKind::from_name(name).map(|kind| BuiltInMeasurement { kind })
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, _types: &TypeDeclarations) -> String {
+        self.kind.name().to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify that we parse the measurement types with and without their full path.
+    #[test]
+    fn parse_measurement() {
+        let types = TypeDeclarations::default();
+
+        for tokens in [
+            "Length",
+            "Temperature",
+            "measurement :: Mass",
+            "swift_bridge :: measurement :: Speed",
+        ] {
+            assert!(BuiltInMeasurement::parse_token_stream_str(tokens, &types).is_some());
+        }
+        assert!(BuiltInMeasurement::parse_token_stream_str("Volume", &types).is_none());
+    }
+}
@@ -0,0 +1,296 @@
+use crate::bridged_type::{
+    BridgeableType, BridgedType, BuiltInResult, CFfiStruct, OnlyEncoding, StdLibType, TypePosition,
+    UnusedOptionNoneValue,
+};
+use crate::parse::TypeDeclarations;
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
+use std::ops::Deref;
+use syn::{Path, Type};
+
+/// `&mut T`, where `T` is a primitive such as `u32` or `bool`.
+///
+/// Used for out parameters, which are annotated with `#[swift_bridge(out)]`.
+/// Passed over FFI as a `*mut T` and seen by Swift as an `inout T` parameter, so a function can
+/// hand back more than one value without allocating a struct.
+#[derive(Debug)]
+pub(crate) struct BuiltInMutRef {
+    pub ty: Box<BridgedType>,
+}
+
+impl BridgeableType for BuiltInMutRef {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&BuiltInResult> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        true
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        None
+    }
+
+    fn generate_custom_c_ffi_types(&self, _types: &TypeDeclarations) -> Option<CFfiStruct> {
+        None
+    }
+
+    fn to_rust_type_path(&self, types: &TypeDeclarations) -> TokenStream {
+        let ty = self.ty.to_rust_type_path(types);
+        quote! { &mut #ty }
+    }
+
+    fn to_swift_type(&self, type_pos: TypePosition, types: &TypeDeclarations) -> String {
+        let ty = self.ty.to_swift_type(type_pos, types);
+        match type_pos {
+            TypePosition::FnArg(host_lang, _) if host_lang.is_rust() => {
+                format!("inout {}", ty)
+            }
+            _ => format!("UnsafeMutablePointer<{}>", ty),
+        }
+    }
+
+    fn to_c_type(&self, types: &TypeDeclarations) -> String {
+        format!("{}*", self.ty.to_c(types))
+    }
+
+    fn to_c_include(&self, types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        self.ty.to_c_include(types)
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let ty = self
+            .ty
+            .to_ffi_compatible_rust_type(swift_bridge_path, types);
+        quote! { *mut #ty }
+    }
+
+    fn to_ffi_compatible_option_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!("Support Option<&mut T>")
+    }
+
+    fn to_ffi_compatible_option_swift_type(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> String {
+        todo!("Support Option<&mut T>")
+    }
+
+    fn to_ffi_compatible_option_c_type(&self) -> String {
+        todo!("Support Option<&mut T>")
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+        span: Span,
+    ) -> TokenStream {
+        let ty = self
+            .ty
+            .to_ffi_compatible_rust_type(swift_bridge_path, types);
+        quote_spanned! {span=>
+            #expression as *mut #ty
+        }
+    }
+
+    fn convert_option_rust_expression_to_ffi_type(
+        &self,
+        _expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
+        todo!("Support Option<&mut T>")
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!("&{}", expression)
+    }
+
+    fn convert_option_swift_expression_to_ffi_type(
+        &self,
+        _expression: &str,
+        _type_pos: TypePosition,
+    ) -> String {
+        todo!("Support Option<&mut T>")
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        span: Span,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote_spanned! {span=>
+            unsafe { &mut *#expression }
+        }
+    }
+
+    fn convert_ffi_option_expression_to_rust_type(&self, _expression: &TokenStream) -> TokenStream {
+        todo!("Support Option<&mut T>")
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+        _types: &TypeDeclarations,
+    ) -> String {
+        format!("&{}.pointee", expression)
+    }
+
+    fn convert_ffi_option_expression_to_swift_type(&self, _expression: &str) -> String {
+        todo!("Support Option<&mut T>")
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        _ok_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!("Support Result<&mut T, E>")
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        _err_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!("Support Result<T, &mut E>")
+    }
+
+    fn unused_option_none_val(&self, _swift_bridge_path: &Path) -> UnusedOptionNoneValue {
+        todo!("Support Option<&mut T>")
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        tokens
+            .strip_prefix("& mut ")
+            .map(|inner| Self::primitive_from_str(inner.trim()).is_some())
+            .unwrap_or(false)
+    }
+
+    fn from_type(ty: &Type, _types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match ty {
+            Type::Reference(ty_ref) if ty_ref.mutability.is_some() => match ty_ref.elem.deref() {
+                Type::Path(path) => {
+                    let inner = quote! { #path }.to_string();
+                    Self::primitive_from_str(&inner).map(|ty| BuiltInMutRef { ty: Box::new(ty) })
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    fn parse_token_stream_str(tokens: &str, _types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        let inner = tokens.strip_prefix("& mut ")?.trim();
+        Self::primitive_from_str(inner).map(|ty| BuiltInMutRef { ty: Box::new(ty) })
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, types: &TypeDeclarations) -> String {
+        format!("MutRef{}", self.ty.to_alpha_numeric_underscore_name(types))
+    }
+}
+
+impl BuiltInMutRef {
+    /// Parse one of the primitives that can be used as an out parameter, such as `u32`.
+    /// `()` is not a valid out parameter.
+    fn primitive_from_str(tokens: &str) -> Option<BridgedType> {
+        let ty = match tokens {
+            "u8" => StdLibType::U8,
+            "i8" => StdLibType::I8,
+            "u16" => StdLibType::U16,
+            "i16" => StdLibType::I16,
+            "u32" => StdLibType::U32,
+            "i32" => StdLibType::I32,
+            "u64" => StdLibType::U64,
+            "i64" => StdLibType::I64,
+            "usize" => StdLibType::Usize,
+            "isize" => StdLibType::Isize,
+            "f32" => StdLibType::F32,
+            "f64" => StdLibType::F64,
+            "bool" => StdLibType::Bool,
+            _ => return None,
+        };
+        Some(BridgedType::StdLib(ty))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify that we only parse mutable references to primitives.
+    #[test]
+    fn parse_mut_ref() {
+        let types = TypeDeclarations::default();
+
+        assert!(BuiltInMutRef::parse_token_stream_str("& mut u32", &types).is_some());
+        assert!(BuiltInMutRef::parse_token_stream_str("& mut bool", &types).is_some());
+        assert!(BuiltInMutRef::parse_token_stream_str("& u32", &types).is_none());
+        assert!(BuiltInMutRef::parse_token_stream_str("& mut String", &types).is_none());
+    }
+}
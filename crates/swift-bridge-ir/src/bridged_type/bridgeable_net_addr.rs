@@ -0,0 +1,505 @@
+use crate::bridged_type::{
+    BridgeableType, BuiltInResult, CFfiStruct, OnlyEncoding, TypePosition, UnusedOptionNoneValue,
+};
+use crate::parse::TypeDeclarations;
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned, ToTokens};
+use syn::{Path, Type};
+
+/// std::net::IpAddr or std::net::SocketAddr
+///
+/// By default these are passed over FFI as small C structs and seen by Swift as a `RustIpAddr` or
+/// a `RustSocketAddr`.
+///
+/// In a module annotated with `#[swift_bridge(net_addrs_as_strings)]` they are passed over FFI as
+/// a `RustString` and seen by Swift as a `String`, the same way as a `PathBuf`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) struct BuiltInNetAddr {
+    pub kind: NetAddrKind,
+    pub as_string: bool,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) enum NetAddrKind {
+    /// std::net::IpAddr
+    IpAddr,
+    /// std::net::SocketAddr
+    SocketAddr,
+}
+
+impl BuiltInNetAddr {
+    fn rust_type_path(&self) -> TokenStream {
+        match self.kind {
+            NetAddrKind::IpAddr => quote! { std::net::IpAddr },
+            NetAddrKind::SocketAddr => quote! { std::net::SocketAddr },
+        }
+    }
+
+    /// The name of the type in `swift_bridge::net`, i.e. `IpAddr` for `FfiIpAddr`.
+    fn name(&self) -> &'static str {
+        match self.kind {
+            NetAddrKind::IpAddr => "IpAddr",
+            NetAddrKind::SocketAddr => "SocketAddr",
+        }
+    }
+
+    fn ffi_struct(&self, swift_bridge_path: &Path) -> TokenStream {
+        let ty = syn::Ident::new(&format!("Ffi{}", self.name()), Span::call_site());
+        quote! { #swift_bridge_path::net::#ty }
+    }
+
+    fn ffi_option_struct(&self, swift_bridge_path: &Path) -> TokenStream {
+        let ty = syn::Ident::new(&format!("Option{}", self.name()), Span::call_site());
+        quote! { #swift_bridge_path::net::#ty }
+    }
+
+    /// Parse the `String` that Swift passed to Rust.
+    fn string_to_rust_type(&self, string: TokenStream) -> TokenStream {
+        let ty = self.rust_type_path();
+        let message = format!("Swift passes a valid {}", self.name());
+        quote! {
+            <#ty as std::str::FromStr>::from_str(&#string).expect(#message)
+        }
+    }
+}
+
+impl BridgeableType for BuiltInNetAddr {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&BuiltInResult> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        self.as_string
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        None
+    }
+
+    fn generate_custom_c_ffi_types(&self, _types: &TypeDeclarations) -> Option<CFfiStruct> {
+        None
+    }
+
+    fn to_rust_type_path(&self, _types: &TypeDeclarations) -> TokenStream {
+        self.rust_type_path()
+    }
+
+    fn to_swift_type(&self, type_pos: TypePosition, _types: &TypeDeclarations) -> String {
+        if self.as_string {
+            return match type_pos {
+                TypePosition::FnArg(func_host_lang, _) => {
+                    if func_host_lang.is_rust() {
+                        "GenericIntoRustString".to_string()
+                    } else {
+                        "UnsafeMutableRawPointer".to_string()
+                    }
+                }
+                TypePosition::FnReturn(func_host_lang) => {
+                    if func_host_lang.is_rust() {
+                        "String".to_string()
+                    } else {
+                        "UnsafeMutableRawPointer".to_string()
+                    }
+                }
+                TypePosition::SharedStructField => "String".to_string(),
+                TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                    "UnsafeMutableRawPointer?".to_string()
+                }
+            };
+        }
+
+        match type_pos {
+            TypePosition::FnArg(host_lang, _) | TypePosition::FnReturn(host_lang) => {
+                if host_lang.is_rust() {
+                    format!("Rust{}", self.name())
+                } else {
+                    format!("__private__{}", self.name())
+                }
+            }
+            TypePosition::SharedStructField => format!("Rust{}", self.name()),
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                format!("__private__{}", self.name())
+            }
+        }
+    }
+
+    fn to_c_type(&self, _types: &TypeDeclarations) -> String {
+        if self.as_string {
+            "void*".to_string()
+        } else {
+            format!("struct __private__{}", self.name())
+        }
+    }
+
+    fn to_c_include(&self, _types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        None
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        if self.as_string {
+            quote! { *mut #swift_bridge_path::string::RustString }
+        } else {
+            self.ffi_struct(swift_bridge_path)
+        }
+    }
+
+    fn to_ffi_compatible_option_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        if self.as_string {
+            self.to_ffi_compatible_rust_type(swift_bridge_path, types)
+        } else {
+            self.ffi_option_struct(swift_bridge_path)
+        }
+    }
+
+    fn to_ffi_compatible_option_swift_type(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> String {
+        if self.as_string {
+            "UnsafeMutableRawPointer?".to_string()
+        } else {
+            format!("__private__Option{}", self.name())
+        }
+    }
+
+    fn to_ffi_compatible_option_c_type(&self) -> String {
+        if self.as_string {
+            "void*".to_string()
+        } else {
+            format!("struct __private__Option{}", self.name())
+        }
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+        span: Span,
+    ) -> TokenStream {
+        if self.as_string {
+            quote_spanned! {span=>
+                #swift_bridge_path::string::RustString(#expression.to_string()).box_into_raw()
+            }
+        } else {
+            let ffi_struct = self.ffi_struct(swift_bridge_path);
+            quote_spanned! {span=>
+                #ffi_struct::from(#expression)
+            }
+        }
+    }
+
+    fn convert_option_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+    ) -> TokenStream {
+        let unused_none = self.unused_option_none_val(swift_bridge_path).rust;
+
+        if self.as_string {
+            return quote! {
+                if let Some(val) = #expression {
+                    #swift_bridge_path::string::RustString(val.to_string()).box_into_raw()
+                } else {
+                    #unused_none
+                }
+            };
+        }
+
+        let ffi_struct = self.ffi_struct(swift_bridge_path);
+        let ffi_option_struct = self.ffi_option_struct(swift_bridge_path);
+        quote! {
+            if let Some(val) = #expression {
+                #ffi_option_struct {
+                    val: #ffi_struct::from(val),
+                    is_some: true
+                }
+            } else {
+                #ffi_option_struct { val: #unused_none, is_some: false }
+            }
+        }
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        if self.as_string {
+            format!(
+                "{{ let rustString = {value}.intoRustString(); rustString.isOwned = false; return rustString.ptr }}()",
+                value = expression
+            )
+        } else {
+            format!("__private__{}({})", self.name(), expression)
+        }
+    }
+
+    fn convert_option_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+    ) -> String {
+        if self.as_string {
+            format!(
+                "{{ if let rustString = optionalStringIntoRustString({expression}) {{ rustString.isOwned = false; return rustString.ptr }} else {{ return nil }} }}()",
+                expression = expression
+            )
+        } else {
+            format!("__private__Option{}({})", self.name(), expression)
+        }
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        span: Span,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        if self.as_string {
+            return self.string_to_rust_type(quote_spanned! {span=>
+                unsafe { Box::from_raw(#expression).0 }
+            });
+        }
+
+        let ty = self.rust_type_path();
+        quote_spanned! {span=>
+            #ty::from(#expression)
+        }
+    }
+
+    fn convert_ffi_option_expression_to_rust_type(&self, expression: &TokenStream) -> TokenStream {
+        if self.as_string {
+            let converted = self.string_to_rust_type(quote! {
+                unsafe { Box::from_raw(#expression).0 }
+            });
+            return quote! {
+                if #expression.is_null() {
+                    None
+                } else {
+                    Some(#converted)
+                }
+            };
+        }
+
+        let ty = self.rust_type_path();
+        quote! {
+            {
+                let val = #expression;
+                if val.is_some {
+                    Some(#ty::from(val.val))
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        type_pos: TypePosition,
+        _types: &TypeDeclarations,
+    ) -> String {
+        if !self.as_string {
+            return format!("{}.intoSwiftRepr()", expression);
+        }
+
+        match type_pos {
+            TypePosition::FnArg(_, _)
+            | TypePosition::FnReturn(_)
+            | TypePosition::SharedStructField => {
+                format!("RustString(ptr: {}).toString()", expression)
+            }
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                format!("RustString(ptr: {}!).toString()", expression)
+            }
+        }
+    }
+
+    fn convert_ffi_option_expression_to_swift_type(&self, expression: &str) -> String {
+        if self.as_string {
+            format!(
+                "{{ let val = {expression}; if val != nil {{ return RustString(ptr: val!).toString() }} else {{ return nil }} }}()",
+                expression = expression
+            )
+        } else {
+            format!("{}.intoSwiftRepr()", expression)
+        }
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        _ok_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!("Support Result<IpAddr, E>")
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        _err_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        todo!("Support Result<T, IpAddr>")
+    }
+
+    fn unused_option_none_val(&self, swift_bridge_path: &Path) -> UnusedOptionNoneValue {
+        if self.as_string {
+            return UnusedOptionNoneValue {
+                rust: quote! {
+                    std::ptr::null::<#swift_bridge_path::string::RustString>() as *mut #swift_bridge_path::string::RustString
+                },
+                swift: "nil".to_string(),
+            };
+        }
+
+        let ip_addr = quote! {
+            #swift_bridge_path::net::FfiIpAddr { octets: [0; 16], is_v6: false }
+        };
+        let rust = match self.kind {
+            NetAddrKind::IpAddr => ip_addr,
+            NetAddrKind::SocketAddr => quote! {
+                #swift_bridge_path::net::FfiSocketAddr {
+                    ip: #ip_addr,
+                    port: 0,
+                    flowinfo: 0,
+                    scope_id: 0
+                }
+            },
+        };
+
+        UnusedOptionNoneValue {
+            rust,
+            swift: format!("__private__{}()", self.name()),
+        }
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        Self::kind_from_tokens(tokens).is_some()
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match ty {
+            Type::Path(path) => Self::parse_token_stream_str(
+                path.path.segments.to_token_stream().to_string().as_str(),
+                types,
+            ),
+            _ => None,
+        }
+    }
+
+    fn parse_token_stream_str(tokens: &str, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        Some(BuiltInNetAddr {
+            kind: Self::kind_from_tokens(tokens)?,
+            as_string: types.net_addrs_as_strings(),
+        })
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        self.as_string
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, _types: &TypeDeclarations) -> String {
+        self.name().to_string()
+    }
+}
+
+impl BuiltInNetAddr {
+    fn kind_from_tokens(tokens: &str) -> Option<NetAddrKind> {
+        match tokens.trim_start_matches("std :: net :: ") {
+            "IpAddr" => Some(NetAddrKind::IpAddr),
+            "SocketAddr" => Some(NetAddrKind::SocketAddr),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify that we parse `IpAddr` and `SocketAddr` with and without their full paths, and that
+    /// the module's `net_addrs_as_strings` attribute is respected.
+    #[test]
+    fn parse_net_addr() {
+        let mut types = TypeDeclarations::default();
+
+        for (tokens, kind) in [
+            ("IpAddr", NetAddrKind::IpAddr),
+            ("std :: net :: IpAddr", NetAddrKind::IpAddr),
+            ("SocketAddr", NetAddrKind::SocketAddr),
+            ("std :: net :: SocketAddr", NetAddrKind::SocketAddr),
+        ] {
+            assert_eq!(
+                BuiltInNetAddr::parse_token_stream_str(tokens, &types),
+                Some(BuiltInNetAddr {
+                    kind,
+                    as_string: false
+                })
+            );
+        }
+        assert!(BuiltInNetAddr::parse_token_stream_str("Ipv4Addr", &types).is_none());
+
+        types.set_net_addrs_as_strings(true);
+        assert!(
+            BuiltInNetAddr::parse_token_stream_str("IpAddr", &types)
+                .unwrap()
+                .as_string
+        );
+    }
+}
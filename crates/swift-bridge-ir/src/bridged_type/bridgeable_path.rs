@@ -0,0 +1,403 @@
+use crate::bridged_type::{
+    BridgeableType, BuiltInResult, CFfiStruct, OnlyEncoding, TypePosition, UnusedOptionNoneValue,
+};
+use crate::TypeDeclarations;
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned, ToTokens};
+use syn::{Path, Type};
+
+/// PathBuf, &Path or OsString.
+///
+/// These cross the bridge as a `RustString` and are seen on the Swift side as a `String`.
+/// Paths that are not valid UTF-8 are lossily converted.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) struct BuiltInPath {
+    pub kind: PathKind,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) enum PathKind {
+    /// std::path::PathBuf
+    PathBuf,
+    /// &std::path::Path
+    RefPath,
+    /// std::ffi::OsString
+    OsString,
+}
+
+impl BuiltInPath {
+    /// Parse a `&Path` from the stringified tokens of the type that is being referenced.
+    pub fn from_referenced_str_tokens(tokens: &str) -> Option<Self> {
+        match tokens {
+            "Path" | "std :: path :: Path" => Some(BuiltInPath {
+                kind: PathKind::RefPath,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Convert a `String` into the Rust type.
+    fn string_to_rust_type(&self, string: TokenStream) -> TokenStream {
+        match self.kind {
+            PathKind::PathBuf => quote! { std::path::PathBuf::from(#string) },
+            PathKind::RefPath => quote! { std::path::Path::new(&#string) },
+            PathKind::OsString => quote! { std::ffi::OsString::from(#string) },
+        }
+    }
+
+    /// Convert the Rust type into a `String`.
+    fn rust_type_to_string(&self, expression: &TokenStream) -> TokenStream {
+        match self.kind {
+            PathKind::PathBuf => quote! {
+                #expression
+                    .into_os_string()
+                    .into_string()
+                    .unwrap_or_else(|os_string| os_string.to_string_lossy().into_owned())
+            },
+            PathKind::RefPath => quote! {
+                #expression.to_string_lossy().into_owned()
+            },
+            PathKind::OsString => quote! {
+                #expression
+                    .into_string()
+                    .unwrap_or_else(|os_string| os_string.to_string_lossy().into_owned())
+            },
+        }
+    }
+}
+
+impl BridgeableType for BuiltInPath {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&BuiltInResult> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        true
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        None
+    }
+
+    fn generate_custom_c_ffi_types(&self, _types: &TypeDeclarations) -> Option<CFfiStruct> {
+        None
+    }
+
+    fn to_rust_type_path(&self, _types: &TypeDeclarations) -> TokenStream {
+        match self.kind {
+            PathKind::PathBuf => quote! { std::path::PathBuf },
+            PathKind::RefPath => quote! { &std::path::Path },
+            PathKind::OsString => quote! { std::ffi::OsString },
+        }
+    }
+
+    fn to_swift_type(&self, type_pos: TypePosition, _types: &TypeDeclarations) -> String {
+        match type_pos {
+            TypePosition::FnArg(func_host_lang, _) => {
+                if func_host_lang.is_rust() {
+                    "GenericIntoRustString".to_string()
+                } else {
+                    "UnsafeMutableRawPointer".to_string()
+                }
+            }
+            TypePosition::FnReturn(func_host_lang) => {
+                if func_host_lang.is_rust() {
+                    "String".to_string()
+                } else {
+                    "UnsafeMutableRawPointer".to_string()
+                }
+            }
+            TypePosition::SharedStructField => "String".to_string(),
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                "UnsafeMutableRawPointer?".to_string()
+            }
+        }
+    }
+
+    fn to_c_type(&self, _types: &TypeDeclarations) -> String {
+        "void*".to_string()
+    }
+
+    fn to_c_include(&self, _types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        None
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        quote! { *mut #swift_bridge_path::string::RustString }
+    }
+
+    fn to_ffi_compatible_option_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        self.to_ffi_compatible_rust_type(swift_bridge_path, types)
+    }
+
+    fn to_ffi_compatible_option_swift_type(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> String {
+        "UnsafeMutableRawPointer?".to_string()
+    }
+
+    fn to_ffi_compatible_option_c_type(&self) -> String {
+        "void*".to_string()
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+        _span: Span,
+    ) -> TokenStream {
+        let string = self.rust_type_to_string(expression);
+        quote! {
+            #swift_bridge_path::string::RustString( #string ).box_into_raw()
+        }
+    }
+
+    fn convert_option_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+    ) -> TokenStream {
+        let string = self.rust_type_to_string(&quote! { val });
+        let unused_none_value = self.unused_option_none_val(swift_bridge_path).rust;
+
+        quote! {
+            if let Some(val) = #expression {
+                #swift_bridge_path::string::RustString( #string ).box_into_raw()
+            } else {
+                #unused_none_value
+            }
+        }
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!(
+            "{{ let rustString = {value}.intoRustString(); rustString.isOwned = false; return rustString.ptr }}()",
+            value = expression
+        )
+    }
+
+    fn convert_option_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!(
+            "{{ if let rustString = optionalStringIntoRustString({expression}) {{ rustString.isOwned = false; return rustString.ptr }} else {{ return nil }} }}()",
+            expression = expression
+        )
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        span: Span,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        self.string_to_rust_type(quote_spanned! {span=>
+            unsafe { Box::from_raw(#expression).0 }
+        })
+    }
+
+    fn convert_ffi_option_expression_to_rust_type(&self, expression: &TokenStream) -> TokenStream {
+        let converted = self.string_to_rust_type(quote! {
+            unsafe { Box::from_raw(#expression).0 }
+        });
+        quote! {
+            if #expression.is_null() {
+                None
+            } else {
+                Some(#converted)
+            }
+        }
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        type_pos: TypePosition,
+        _types: &TypeDeclarations,
+    ) -> String {
+        match type_pos {
+            TypePosition::FnArg(_, _)
+            | TypePosition::FnReturn(_)
+            | TypePosition::SharedStructField => {
+                format!("RustString(ptr: {}).toString()", expression)
+            }
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                format!("RustString(ptr: {}!).toString()", expression)
+            }
+        }
+    }
+
+    fn convert_ffi_option_expression_to_swift_type(&self, expression: &str) -> String {
+        format!(
+            "{{ let val = {expression}; if val != nil {{ return RustString(ptr: val!).toString() }} else {{ return nil }} }}()",
+            expression = expression
+        )
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        ok_ffi_value: &TokenStream,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        self.string_to_rust_type(quote! {
+            unsafe {
+                Box::from_raw(#ok_ffi_value.ok_or_err as *mut #swift_bridge_path::string::RustString).0
+            }
+        })
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        err_ffi_value: &TokenStream,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        self.string_to_rust_type(quote! {
+            unsafe {
+                Box::from_raw(#err_ffi_value.ok_or_err as *mut #swift_bridge_path::string::RustString).0
+            }
+        })
+    }
+
+    fn unused_option_none_val(&self, swift_bridge_path: &Path) -> UnusedOptionNoneValue {
+        UnusedOptionNoneValue {
+            rust: quote! {
+                std::ptr::null::<#swift_bridge_path::string::RustString>() as *mut #swift_bridge_path::string::RustString
+            },
+            swift: "nil".to_string(),
+        }
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        matches!(
+            tokens,
+            "PathBuf" | "std :: path :: PathBuf" | "OsString" | "std :: ffi :: OsString"
+        )
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match ty {
+            Type::Path(path) => Self::parse_token_stream_str(
+                path.path.segments.to_token_stream().to_string().as_str(),
+                types,
+            ),
+            _ => None,
+        }
+    }
+
+    fn parse_token_stream_str(tokens: &str, _types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        let kind = match tokens {
+            "PathBuf" | "std :: path :: PathBuf" => PathKind::PathBuf,
+            "OsString" | "std :: ffi :: OsString" => PathKind::OsString,
+            _ => return None,
+        };
+        Some(BuiltInPath { kind })
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    // Swift functions accept anything that conforms to `IntoRustString`, such as a `String` or a
+    // file `URL`.
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        true
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, _types: &TypeDeclarations) -> String {
+        match self.kind {
+            PathKind::PathBuf => "PathBuf".to_string(),
+            PathKind::RefPath => "Path".to_string(),
+            PathKind::OsString => "OsString".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify that we parse the path types with and without their full paths.
+    #[test]
+    fn parse_path_types() {
+        let types = TypeDeclarations::default();
+
+        for (tokens, kind) in [
+            ("PathBuf", PathKind::PathBuf),
+            ("std :: path :: PathBuf", PathKind::PathBuf),
+            ("OsString", PathKind::OsString),
+            ("std :: ffi :: OsString", PathKind::OsString),
+        ] {
+            assert_eq!(
+                BuiltInPath::parse_token_stream_str(tokens, &types),
+                Some(BuiltInPath { kind }),
+                "{}",
+                tokens
+            );
+        }
+
+        assert_eq!(
+            BuiltInPath::from_referenced_str_tokens("Path"),
+            Some(BuiltInPath {
+                kind: PathKind::RefPath
+            })
+        );
+    }
+}
@@ -0,0 +1,374 @@
+use crate::bridged_type::{
+    find_option_or_result_holding, BridgeableType, BridgedType, BuiltInResult, CFfiStruct,
+    OnlyEncoding, StdLibType, TypePosition, UnusedOptionNoneValue,
+};
+use crate::parse::TypeDeclarations;
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
+use std::str::FromStr;
+use syn::{Path, PathArguments, Type};
+
+/// Range<T> or RangeInclusive<T>, where T is an integer.
+///
+/// Passed over FFI as a `swift_bridge::range::FfiRange<T>` and seen by Swift as a `Range<T>` or a
+/// `ClosedRange<T>`.
+#[derive(Debug)]
+pub(crate) struct BuiltInRange {
+    pub kind: RangeKind,
+    pub ty: Box<BridgedType>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) enum RangeKind {
+    /// std::ops::Range<T>
+    Range,
+    /// std::ops::RangeInclusive<T>
+    Inclusive,
+}
+
+impl BuiltInRange {
+    /// The `Option<Range<T>>`, `Result<Range<T>, E>` or `Result<T, Range<T>>` in the type, if
+    /// any. A `Range` or `RangeInclusive` can't yet be held by an `Option` or a `Result`.
+    pub fn find_in_option_or_result(ty: &Type) -> Option<Type> {
+        find_option_or_result_holding(ty, &|held| match held {
+            Type::Path(path) => path.path.segments.last().is_some_and(|segment| {
+                (segment.ident == "Range" || segment.ident == "RangeInclusive")
+                    && matches!(segment.arguments, PathArguments::AngleBracketed(_))
+            }),
+            _ => false,
+        })
+    }
+
+    /// Parse a `Range<T>` or `RangeInclusive<T>` from a stringified token stream such as
+    /// "Range < u64 >" or "std :: ops :: RangeInclusive < i32 >".
+    ///
+    /// Returns `None` if the element type is not an integer.
+    pub fn from_str_tokens(tokens: &str, types: &TypeDeclarations) -> Option<Self> {
+        let tokens = tokens.trim_start_matches("std :: ops :: ");
+
+        let (kind, inner) = if let Some(inner) = tokens.strip_prefix("RangeInclusive < ") {
+            (RangeKind::Inclusive, inner)
+        } else {
+            (RangeKind::Range, tokens.strip_prefix("Range < ")?)
+        };
+        let inner = inner.strip_suffix(" >")?;
+
+        let inner: Type = syn::parse2(TokenStream::from_str(inner).ok()?).ok()?;
+        let inner = BridgedType::new_with_type(&inner, types)?;
+
+        // Only the integer types have a `__private__Range{T}` C struct.
+        Self::ffi_suffix(&inner)?;
+
+        Some(BuiltInRange {
+            kind,
+            ty: Box::new(inner),
+        })
+    }
+
+    /// The suffix of the `__private__Range{T}` C struct that holds the bounds.
+    fn ffi_suffix(ty: &BridgedType) -> Option<&'static str> {
+        let suffix = match ty {
+            BridgedType::StdLib(StdLibType::U8) => "U8",
+            BridgedType::StdLib(StdLibType::I8) => "I8",
+            BridgedType::StdLib(StdLibType::U16) => "U16",
+            BridgedType::StdLib(StdLibType::I16) => "I16",
+            BridgedType::StdLib(StdLibType::U32) => "U32",
+            BridgedType::StdLib(StdLibType::I32) => "I32",
+            BridgedType::StdLib(StdLibType::U64) => "U64",
+            BridgedType::StdLib(StdLibType::I64) => "I64",
+            BridgedType::StdLib(StdLibType::Usize) => "Usize",
+            BridgedType::StdLib(StdLibType::Isize) => "Isize",
+            _ => return None,
+        };
+        Some(suffix)
+    }
+
+    /// `Range<u32>` -> `__private__RangeU32`
+    fn ffi_name(&self) -> String {
+        format!("__private__Range{}", Self::ffi_suffix(&self.ty).unwrap())
+    }
+
+    /// `Range<u32>` -> `Range<UInt32>`, `RangeInclusive<u32>` -> `ClosedRange<UInt32>`
+    fn to_swift_range(&self, type_pos: TypePosition, types: &TypeDeclarations) -> String {
+        let elem = self.ty.to_swift_type(type_pos, types);
+        match self.kind {
+            RangeKind::Range => format!("Range<{}>", elem),
+            RangeKind::Inclusive => format!("ClosedRange<{}>", elem),
+        }
+    }
+}
+
+impl BridgeableType for BuiltInRange {
+    fn is_built_in_type(&self) -> bool {
+        true
+    }
+
+    fn only_encoding(&self) -> Option<OnlyEncoding> {
+        None
+    }
+
+    fn is_result(&self) -> bool {
+        false
+    }
+
+    fn as_result(&self) -> Option<&BuiltInResult> {
+        None
+    }
+
+    fn is_passed_via_pointer(&self) -> bool {
+        false
+    }
+
+    fn generate_custom_rust_ffi_types(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> Option<Vec<TokenStream>> {
+        None
+    }
+
+    fn generate_custom_c_ffi_types(&self, _types: &TypeDeclarations) -> Option<CFfiStruct> {
+        None
+    }
+
+    fn to_rust_type_path(&self, types: &TypeDeclarations) -> TokenStream {
+        let ty = self.ty.to_rust_type_path(types);
+        match self.kind {
+            RangeKind::Range => quote! { std::ops::Range<#ty> },
+            RangeKind::Inclusive => quote! { std::ops::RangeInclusive<#ty> },
+        }
+    }
+
+    fn to_swift_type(&self, type_pos: TypePosition, types: &TypeDeclarations) -> String {
+        match type_pos {
+            TypePosition::FnArg(host_lang, _) | TypePosition::FnReturn(host_lang) => {
+                if host_lang.is_rust() {
+                    self.to_swift_range(type_pos, types)
+                } else {
+                    self.ffi_name()
+                }
+            }
+            TypePosition::SharedStructField => self.to_swift_range(type_pos, types),
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => self.ffi_name(),
+        }
+    }
+
+    fn to_c_type(&self, _types: &TypeDeclarations) -> String {
+        format!("struct {}", self.ffi_name())
+    }
+
+    fn to_c_include(&self, _types: &TypeDeclarations) -> Option<Vec<&'static str>> {
+        None
+    }
+
+    fn to_ffi_compatible_rust_type(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let ty = self.ty.to_rust_type_path(types);
+        quote! { #swift_bridge_path::range::FfiRange<#ty> }
+    }
+
+    fn to_ffi_compatible_option_rust_type(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        unreachable!("Option<Range<T>> is rejected while parsing")
+    }
+
+    fn to_ffi_compatible_option_swift_type(
+        &self,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> String {
+        unreachable!("Option<Range<T>> is rejected while parsing")
+    }
+
+    fn to_ffi_compatible_option_c_type(&self) -> String {
+        unreachable!("Option<Range<T>> is rejected while parsing")
+    }
+
+    fn convert_rust_expression_to_ffi_type(
+        &self,
+        expression: &TokenStream,
+        swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+        span: Span,
+    ) -> TokenStream {
+        quote_spanned! {span=>
+            #swift_bridge_path::range::FfiRange::from(#expression)
+        }
+    }
+
+    fn convert_option_rust_expression_to_ffi_type(
+        &self,
+        _expression: &TokenStream,
+        _swift_bridge_path: &Path,
+    ) -> TokenStream {
+        unreachable!("Option<Range<T>> is rejected while parsing")
+    }
+
+    fn convert_swift_expression_to_ffi_type(
+        &self,
+        expression: &str,
+        _types: &TypeDeclarations,
+        _type_pos: TypePosition,
+    ) -> String {
+        format!("{}({})", self.ffi_name(), expression)
+    }
+
+    fn convert_option_swift_expression_to_ffi_type(
+        &self,
+        _expression: &str,
+        _type_pos: TypePosition,
+    ) -> String {
+        unreachable!("Option<Range<T>> is rejected while parsing")
+    }
+
+    fn convert_ffi_expression_to_rust_type(
+        &self,
+        expression: &TokenStream,
+        span: Span,
+        _swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let ty = self.to_rust_type_path(types);
+        quote_spanned! {span=>
+            <#ty>::from(#expression)
+        }
+    }
+
+    fn convert_ffi_option_expression_to_rust_type(&self, _expression: &TokenStream) -> TokenStream {
+        unreachable!("Option<Range<T>> is rejected while parsing")
+    }
+
+    fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        _type_pos: TypePosition,
+        _types: &TypeDeclarations,
+    ) -> String {
+        match self.kind {
+            RangeKind::Range => format!("{}.intoRange()", expression),
+            RangeKind::Inclusive => format!("{}.intoClosedRange()", expression),
+        }
+    }
+
+    fn convert_ffi_option_expression_to_swift_type(&self, _expression: &str) -> String {
+        unreachable!("Option<Range<T>> is rejected while parsing")
+    }
+
+    fn convert_ffi_result_ok_value_to_rust_value(
+        &self,
+        _ok_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        unreachable!("Result<Range<T>, E> is rejected while parsing")
+    }
+
+    fn convert_ffi_result_err_value_to_rust_value(
+        &self,
+        _err_ffi_value: &TokenStream,
+        _swift_bridge_path: &Path,
+        _types: &TypeDeclarations,
+    ) -> TokenStream {
+        unreachable!("Result<T, Range<T>> is rejected while parsing")
+    }
+
+    fn unused_option_none_val(&self, _swift_bridge_path: &Path) -> UnusedOptionNoneValue {
+        unreachable!("Option<Range<T>> is rejected while parsing")
+    }
+
+    fn can_parse_token_stream_str(tokens: &str) -> bool
+    where
+        Self: Sized,
+    {
+        let tokens = tokens.trim_start_matches("std :: ops :: ");
+        tokens.starts_with("Range < ") || tokens.starts_with("RangeInclusive < ")
+    }
+
+    fn from_type(ty: &Type, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        match ty {
+            Type::Path(path) => {
+                let tokens = quote! { #path }.to_string();
+                Self::parse_token_stream_str(&tokens, types)
+            }
+            _ => None,
+        }
+    }
+
+    fn parse_token_stream_str(tokens: &str, types: &TypeDeclarations) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        Self::from_str_tokens(tokens, types)
+    }
+
+    fn is_null(&self) -> bool {
+        false
+    }
+
+    fn is_str(&self) -> bool {
+        false
+    }
+
+    fn contains_owned_string_recursive(&self, _types: &TypeDeclarations) -> bool {
+        false
+    }
+
+    fn contains_ref_string_recursive(&self) -> bool {
+        false
+    }
+
+    fn has_swift_bridge_copy_annotation(&self) -> bool {
+        false
+    }
+
+    fn to_alpha_numeric_underscore_name(&self, _types: &TypeDeclarations) -> String {
+        let suffix = Self::ffi_suffix(&self.ty).unwrap();
+        match self.kind {
+            RangeKind::Range => format!("Range{}", suffix),
+            RangeKind::Inclusive => format!("RangeInclusive{}", suffix),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify that we parse ranges of integers, with and without their full paths, and that we do
+    /// not parse ranges of other types.
+    #[test]
+    fn parse_range() {
+        let types = TypeDeclarations::default();
+
+        for (tokens, kind) in [
+            ("Range < u32 >", RangeKind::Range),
+            ("std :: ops :: Range < i64 >", RangeKind::Range),
+            ("RangeInclusive < usize >", RangeKind::Inclusive),
+            ("std :: ops :: RangeInclusive < i8 >", RangeKind::Inclusive),
+        ] {
+            let range = BuiltInRange::parse_token_stream_str(tokens, &types).unwrap();
+            assert_eq!(range.kind, kind, "{}", tokens);
+        }
+
+        for tokens in [
+            "Range < f64 >",
+            "RangeInclusive < String >",
+            "RangeFrom < u32 >",
+        ] {
+            assert!(
+                BuiltInRange::parse_token_stream_str(tokens, &types).is_none(),
+                "{}",
+                tokens
+            );
+        }
+    }
+}
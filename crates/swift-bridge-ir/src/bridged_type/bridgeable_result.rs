@@ -1,4 +1,5 @@
 use crate::bridged_type::{BridgeableType, BridgedType, CFfiStruct, TypePosition};
+use crate::parse::HostLang;
 use crate::{TypeDeclarations, SWIFT_BRIDGE_PREFIX};
 use proc_macro2::{Span, TokenStream};
 use quote::{format_ident, quote, quote_spanned};
@@ -145,6 +146,37 @@ impl BuiltInResult {
         swift_bridge_path: &Path,
         types: &TypeDeclarations,
     ) -> TokenStream {
+        if self.is_custom_result_type() {
+            if self.err_ty.can_be_encoded_with_zero_bytes() {
+                todo!();
+            }
+            let ffi_enum_name = self.to_ffi_compatible_rust_type(swift_bridge_path, types);
+            let convert_err = self.err_ty.convert_ffi_expression_to_rust_type(
+                &quote! { err },
+                span,
+                swift_bridge_path,
+                types,
+            );
+            let ok_arm = if self.ok_ty.can_be_encoded_with_zero_bytes() {
+                quote! { #ffi_enum_name::Ok => std::result::Result::Ok(()) }
+            } else {
+                let convert_ok = self.ok_ty.convert_ffi_expression_to_rust_type(
+                    &quote! { ok },
+                    span,
+                    swift_bridge_path,
+                    types,
+                );
+                quote! { #ffi_enum_name::Ok(ok) => std::result::Result::Ok(#convert_ok) }
+            };
+
+            return quote_spanned! {span=>
+                match #expression {
+                    #ok_arm,
+                    #ffi_enum_name::Err(err) => std::result::Result::Err(#convert_err),
+                }
+            };
+        }
+
         let convert_ok = self.ok_ty.convert_ffi_result_ok_value_to_rust_value(
             expression,
             swift_bridge_path,
@@ -176,6 +208,8 @@ impl BuiltInResult {
     pub fn to_swift_type(&self, type_pos: TypePosition, types: &TypeDeclarations) -> String {
         match type_pos {
             TypePosition::FnReturn(_) => self.ok_ty.to_swift_type(type_pos, types),
+            // A Swift function that Rust calls receives the FFI representation of the `Result`.
+            TypePosition::FnArg(HostLang::Swift, _) => self.to_swift_ffi_type(types),
             TypePosition::FnArg(_, _) | TypePosition::SharedStructField => {
                 format!(
                     "RustResult<{}, {}>",
@@ -183,25 +217,38 @@ impl BuiltInResult {
                     self.err_ty.to_swift_type(type_pos, types),
                 )
             }
-            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
-                if self.err_ty.can_be_encoded_with_zero_bytes() {
-                    todo!()
-                }
-                if self.is_custom_result_type() {
-                    return format!(
-                        "{}${}",
-                        SWIFT_BRIDGE_PREFIX,
-                        self.custom_c_struct_name(types)
-                    );
-                }
-                if self.ok_ty.can_be_encoded_with_zero_bytes() {
-                    return "UnsafeMutableRawPointer?".to_string();
-                }
-                "__private__ResultPtrAndPtr".to_string()
-            }
+            TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => self.to_swift_ffi_type(types),
         }
     }
 
+    fn to_swift_ffi_type(&self, types: &TypeDeclarations) -> String {
+        if self.err_ty.can_be_encoded_with_zero_bytes() {
+            todo!()
+        }
+        if self.is_custom_result_type() {
+            return format!(
+                "{}${}",
+                SWIFT_BRIDGE_PREFIX,
+                self.custom_c_struct_name(types)
+            );
+        }
+        if self.ok_ty.can_be_encoded_with_zero_bytes() {
+            return "UnsafeMutableRawPointer?".to_string();
+        }
+        "__private__ResultPtrAndPtr".to_string()
+    }
+
+    /// The `RustResult<T, E>` that Swift sees when Rust gives it a `Result<T, E>` as a value, such
+    /// as when Rust calls a Swift closure with a `Result` argument.
+    pub fn to_swift_rust_result_type(&self, types: &TypeDeclarations) -> String {
+        let type_pos = TypePosition::FnReturn(HostLang::Rust);
+        format!(
+            "RustResult<{}, {}>",
+            self.ok_ty.to_swift_type(type_pos, types),
+            self.err_ty.to_swift_type(type_pos, types),
+        )
+    }
+
     pub fn convert_ffi_value_to_swift_value(
         &self,
         expression: &str,
@@ -279,6 +326,15 @@ impl BuiltInResult {
             self.err_ty
                 .convert_ffi_expression_to_swift_type("val.ok_or_err!", type_pos, types);
 
+        // A Swift function that receives a `Result` from Rust gets a `RustResult`, as opposed to
+        // a throwing expression.
+        if let TypePosition::FnArg(HostLang::Swift, _) = type_pos {
+            let ty = self.to_swift_rust_result_type(types);
+            return format!(
+                "{{ () -> {ty} in let val = {expression}; if val.is_ok {{ return RustResult.Ok({ok}) }} else {{ return RustResult.Err({err}) }} }}()"
+            );
+        }
+
         format!(
             "try {{ let val = {expression}; if val.is_ok {{ return {ok} }} else {{ throw {err} }} }}()",
             expression = expression,
@@ -299,6 +355,28 @@ impl BuiltInResult {
             .err_ty
             .convert_swift_expression_to_ffi_type("err", types, type_pos);
 
+        if self.is_custom_result_type() {
+            if self.err_ty.can_be_encoded_with_zero_bytes() {
+                todo!();
+            }
+            let c_type = format!(
+                "{}${}",
+                SWIFT_BRIDGE_PREFIX,
+                self.custom_c_struct_name(types)
+            );
+            let (ok_case, ok_payload) = if self.ok_ty.can_be_encoded_with_zero_bytes() {
+                (".Ok", "".to_string())
+            } else {
+                (".Ok(let ok)", format!("ok: {convert_ok}"))
+            };
+            return format!(
+                "{{ switch {val} {{ case {ok_case}: return {c_type}(tag: {c_ok_name}, payload: {c_type}$Fields({ok_payload})) case .Err(let err): return {c_type}(tag: {c_err_name}, payload: {c_type}$Fields(err: {convert_err})) }} }}()",
+                val = expression,
+                c_ok_name = self.c_ok_tag_name(types),
+                c_err_name = self.c_err_tag_name(types),
+            );
+        }
+
         if self.ok_ty.can_be_encoded_with_zero_bytes() {
             format!(
                 "{{ switch {val} {{ case .Ok(let ok): return __private__ResultPtrAndPtr(is_ok: true, ok_or_err: {convert_ok}) case .Err(let err): return __private__ResultPtrAndPtr(is_ok: false, ok_or_err: {convert_err}) }} }}()",
@@ -509,7 +587,8 @@ impl BuiltInResult {
         // A , B >
         let trimmed = string.trim_start_matches("Result < ");
         // A , B
-        let trimmed = trimmed.trim_end_matches(" >");
+        // Only strip the outer `Result`'s bracket, since `B` might be generic as well.
+        let trimmed = trimmed.strip_suffix(" >").unwrap_or(trimmed);
 
         // [A, B]
         let ok_and_err = trimmed.rsplit_once(",")?;
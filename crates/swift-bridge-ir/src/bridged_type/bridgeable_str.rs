@@ -1,2 +0,0 @@
-#[derive(Debug)]
-pub(crate) struct BridgeableStr;
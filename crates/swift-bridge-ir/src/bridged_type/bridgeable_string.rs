@@ -152,7 +152,7 @@ impl BridgeableType for BridgedString {
         type_pos: TypePosition,
     ) -> String {
         match type_pos {
-            TypePosition::FnArg(_func_host_lang, _) => {
+            TypePosition::FnArg(_, _) | TypePosition::SharedStructField => {
                 format!(
                     "{{ if let rustString = optionalStringIntoRustString({expression}) {{ rustString.isOwned = false; return rustString.ptr }} else {{ return nil }} }}()",
                     expression = expression
@@ -161,9 +161,6 @@ impl BridgeableType for BridgedString {
             TypePosition::FnReturn(_) => {
                 todo!("Need to come back and think through what should happen here...")
             }
-            TypePosition::SharedStructField => {
-                todo!("Option<String> fields in structs are not yet supported.")
-            }
             TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
                 unimplemented!()
             }
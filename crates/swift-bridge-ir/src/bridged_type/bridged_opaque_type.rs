@@ -16,7 +16,9 @@ pub(crate) struct OpaqueForeignType {
     pub reference: bool,
     pub mutable: bool,
     pub has_swift_bridge_copy_annotation: bool,
+    pub has_swift_bridge_identity_map_annotation: bool,
     pub generics: OpaqueRustTypeGenerics,
+    pub has_own_swift_class: bool,
 }
 
 impl BridgeableType for OpaqueForeignType {
@@ -74,7 +76,7 @@ impl BridgeableType for OpaqueForeignType {
             match type_pos {
                 TypePosition::FnArg(func_host_lang, _) | TypePosition::FnReturn(func_host_lang) => {
                     if func_host_lang.is_rust() {
-                        let mut class_name = self.ty.to_string();
+                        let mut class_name = self.swift_name();
 
                         if !self.has_swift_bridge_copy_annotation {
                             if self.reference {
@@ -86,6 +88,10 @@ impl BridgeableType for OpaqueForeignType {
                             }
                         }
 
+                        if self.has_own_swift_class {
+                            return class_name;
+                        }
+
                         format!(
                             "{}{}",
                             class_name,
@@ -97,13 +103,17 @@ impl BridgeableType for OpaqueForeignType {
                     }
                 }
                 TypePosition::SharedStructField => {
-                    let class_name = self.ty.to_string();
+                    let class_name = self.swift_name();
                     if !self.has_swift_bridge_copy_annotation {
                         if self.mutable || self.reference {
                             todo!();
                         }
                     }
 
+                    if self.has_own_swift_class {
+                        return class_name;
+                    }
+
                     format!(
                         "{}{}",
                         class_name,
@@ -112,7 +122,11 @@ impl BridgeableType for OpaqueForeignType {
                     )
                 }
                 TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
-                    unimplemented!()
+                    if self.has_swift_bridge_copy_annotation {
+                        self.copy_ffi_repr_type_string()
+                    } else {
+                        "UnsafeMutableRawPointer?".to_string()
+                    }
                 }
             }
         } else {
@@ -174,7 +188,7 @@ impl BridgeableType for OpaqueForeignType {
                         quote! { *const }
                     };
 
-                    quote_spanned! {ty_name.span()=> #ptr super::#ty_name }
+                    quote_spanned! {ty_name.span()=> #ptr super::#ty_name #generics }
                 } else {
                     quote! { *mut super::#ty_name #generics }
                 }
@@ -447,7 +461,7 @@ impl BridgeableType for OpaqueForeignType {
         type_pos: TypePosition,
         _types: &TypeDeclarations,
     ) -> String {
-        let mut ty_name = self.ty.to_string();
+        let mut ty_name = self.swift_name();
 
         if self.reference {
             ty_name += "Ref";
@@ -468,6 +482,17 @@ impl BridgeableType for OpaqueForeignType {
                     TypePosition::FnReturn(fn_host_lang) if fn_host_lang.is_swift() => {
                         format!("Unmanaged.passRetained({expression}).toOpaque()")
                     }
+                    // Swift imports the async callback's `void*` as an optional pointer.
+                    TypePosition::SwiftCallsRustAsyncOnCompleteReturnTy => {
+                        format!("{ty_name}(ptr: {expression}!)")
+                    }
+                    _ if self.has_swift_bridge_identity_map_annotation && !self.reference => {
+                        format!(
+                            "{ty_name}.__swift_bridge__fromIdentityMap(ptr: {value})",
+                            ty_name = ty_name,
+                            value = expression,
+                        )
+                    }
                     _ => {
                         format!(
                             "{ty_name}(ptr: {value})",
@@ -494,6 +519,13 @@ impl BridgeableType for OpaqueForeignType {
                 expression = expression,
                 type_name = type_name
             )
+        } else if self.has_swift_bridge_identity_map_annotation && !self.reference {
+            let type_name = self.swift_name();
+            format!(
+                "{{ let val = {expression}; if val != nil {{ return {type_name}.__swift_bridge__fromIdentityMap(ptr: val!) }} else {{ return nil }} }}()",
+                expression = expression,
+                type_name = type_name
+            )
         } else {
             let type_name = self.swift_name();
             format!(
@@ -637,8 +669,14 @@ impl BridgeableType for OpaqueForeignType {
 }
 
 impl OpaqueForeignType {
+    /// The name of the Swift class or struct for the type, such as "SomeTypeU32" for a
+    /// `type SomeType<u32>` that has its own Swift class.
     pub fn swift_name(&self) -> String {
-        format!("{}", self.ty)
+        if self.has_own_swift_class {
+            format!("{}{}", self.ty, self.generics.capitalized_generics_string())
+        } else {
+            format!("{}", self.ty)
+        }
     }
 
     /// The name of the type used to pass a `#[swift_bridge(Copy(...))]` type over FFI
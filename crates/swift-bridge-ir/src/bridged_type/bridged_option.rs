@@ -1,5 +1,7 @@
+use crate::bridged_type::bridgeable_pointer::{Pointee, PointerKind};
 use crate::bridged_type::built_in_primitive::BuiltInPrimitive;
 use crate::bridged_type::{BridgedType, CustomBridgedType, SharedType, StdLibType, TypePosition};
+use crate::parse::HostLang;
 use crate::parse::TypeDeclarations;
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -76,11 +78,28 @@ impl BridgedOption {
                 StdLibType::Bool => {
                     option_rust_primitive_to_ffi_primitive(quote! {OptionBool}, quote! {false})
                 }
-                StdLibType::Pointer(_) => {
-                    todo!("Support Option<*const T> and Option<*mut T>")
+                StdLibType::Pointer(ptr) => {
+                    let null = match ptr.kind {
+                        PointerKind::Const => quote! { std::ptr::null() },
+                        PointerKind::Mut => quote! { std::ptr::null_mut() },
+                    };
+                    quote! {
+                        if let Some(val) = #expression {
+                            val
+                        } else {
+                            #null
+                        }
+                    }
                 }
-                StdLibType::RefSlice(_) => {
-                    todo!("Support Option<&[T]> and Option<&mut [T]>")
+                StdLibType::RefSlice(slice) => {
+                    if slice.opaque_rust_element.is_some() {
+                        unreachable!("Option<&[OpaqueRustType]> is rejected while parsing")
+                    }
+                    if slice.mutable {
+                        quote! { #swift_bridge_path::OptionFfiSlice::from_mut_slice(#expression) }
+                    } else {
+                        quote! { #swift_bridge_path::OptionFfiSlice::from_slice(#expression) }
+                    }
                 }
                 StdLibType::Str => {
                     quote! {
@@ -106,7 +125,7 @@ impl BridgedOption {
                 StdLibType::Result(_) => {
                     todo!("Support Option<Result<T, E>>")
                 }
-                StdLibType::BoxedFnOnce(_) => {
+                StdLibType::BoxedFn(_) => {
                     todo!("Option<Box<dyn FnOnce(A, B) -> C>> is not yet supported")
                 }
                 StdLibType::Tuple(_) => todo!(),
@@ -129,6 +148,7 @@ impl BridgedOption {
     pub(super) fn convert_ffi_expression_to_rust_type(
         &self,
         expression: &TokenStream,
+        types: &TypeDeclarations,
     ) -> TokenStream {
         match self.ty.deref() {
             BridgedType::Bridgeable(b) => b.convert_ffi_option_expression_to_rust_type(expression),
@@ -161,19 +181,31 @@ impl BridgedOption {
                     }
                 }
                 StdLibType::Pointer(_) => {
-                    todo!("Option<*const T> and Option<*mut T> are not yet supported.")
-                }
-                StdLibType::RefSlice(_) => {
-                    todo!("Option<*const T> and Option<*mut T> are not yet supported.")
-                }
-                StdLibType::Str => {
                     quote! {
-                        if #expression.start.is_null() { None } else { Some(#expression.to_str()) }
+                        {
+                            let val = #expression;
+                            if val.is_null() { None } else { Some(val) }
+                        }
                     }
                 }
+                StdLibType::RefSlice(slice) => {
+                    if slice.mutable {
+                        quote! { #expression.as_mut_slice() }
+                    } else {
+                        quote! { #expression.as_slice() }
+                    }
+                }
+                StdLibType::Str => types
+                    .utf8_validation()
+                    .convert_optional_rust_str(expression),
                 StdLibType::Vec(_) => {
+                    // The expression can be a call to a Swift function, so it must only be
+                    // evaluated once.
                     quote! {
-                        if #expression.is_null() { None } else { Some( unsafe { * Box::from_raw(#expression) } ) }
+                        {
+                            let val = #expression;
+                            if val.is_null() { None } else { Some( unsafe { * Box::from_raw(val) } ) }
+                        }
                     }
                 }
                 StdLibType::Option(_) => {
@@ -182,7 +214,7 @@ impl BridgedOption {
                 StdLibType::Result(_) => {
                     todo!("Option<Result<T, E>> is not yet supported")
                 }
-                StdLibType::BoxedFnOnce(_) => {
+                StdLibType::BoxedFn(_) => {
                     todo!("Option<Box<dyn FnOnce(A, B) -> C>> is not yet supported")
                 }
                 StdLibType::Tuple(_) => todo!(),
@@ -200,7 +232,11 @@ impl BridgedOption {
         }
     }
 
-    pub(super) fn convert_ffi_expression_to_swift_type(&self, expression: &str) -> String {
+    pub(super) fn convert_ffi_expression_to_swift_type(
+        &self,
+        expression: &str,
+        types: &TypeDeclarations,
+    ) -> String {
         match self.ty.deref() {
             BridgedType::Bridgeable(b) => b.convert_ffi_option_expression_to_swift_type(expression),
             BridgedType::StdLib(stdlib_type) => match stdlib_type {
@@ -222,11 +258,20 @@ impl BridgedOption {
                 | StdLibType::Bool => {
                     format!("{expression}.intoSwiftRepr()")
                 }
-                StdLibType::Pointer(_) => {
-                    todo!("Support Option<*const T> and Option<*mut T>")
-                }
-                StdLibType::RefSlice(_) => {
-                    todo!("Support Option<&[T]>")
+                StdLibType::Pointer(ptr) => match (&ptr.pointee, ptr.kind) {
+                    // `void*` is seen by Swift as an `UnsafeMutableRawPointer?`.
+                    (Pointee::Void(_), PointerKind::Const) => {
+                        format!("UnsafeRawPointer({expression})", expression = expression)
+                    }
+                    _ => expression.to_string(),
+                },
+                StdLibType::RefSlice(slice) => {
+                    format!(
+                        "{{ let val = {expression}; if val.is_some {{ return val.val.toUnsafe{maybe_mutable}BufferPointer({ty}.self) }} else {{ return nil }} }}()",
+                        expression = expression,
+                        maybe_mutable = if slice.mutable { "Mutable" } else { "" },
+                        ty = slice.ty.to_swift_type(TypePosition::FnReturn(HostLang::Rust), types)
+                    )
                 }
                 StdLibType::Str => {
                     format!(
@@ -247,7 +292,7 @@ impl BridgedOption {
                 StdLibType::Result(_) => {
                     todo!("Option<Result<T, E>> is not yet supported")
                 }
-                StdLibType::BoxedFnOnce(_) => {
+                StdLibType::BoxedFn(_) => {
                     todo!("Option<Box<dyn FnOnce(A, B) -> C>> is not yet supported")
                 }
                 StdLibType::Tuple(_) => todo!(),
@@ -289,11 +334,26 @@ impl BridgedOption {
                 | StdLibType::Bool => {
                     format!("{expression}.intoFfiRepr()")
                 }
-                StdLibType::Pointer(_) => {
-                    todo!("Option<*const T> and Option<*mut T> are not yet supported")
-                }
+                StdLibType::Pointer(ptr) => match (&ptr.pointee, ptr.kind, type_pos) {
+                    (
+                        Pointee::Void(_),
+                        PointerKind::Const,
+                        TypePosition::FnArg(HostLang::Rust, _)
+                        | TypePosition::FnReturn(HostLang::Rust)
+                        | TypePosition::SharedStructField,
+                    ) => {
+                        format!(
+                            "UnsafeMutableRawPointer(mutating: {expression})",
+                            expression = expression
+                        )
+                    }
+                    _ => expression.to_string(),
+                },
                 StdLibType::RefSlice(_) => {
-                    todo!("Option<&[T]> is not yet supported")
+                    format!(
+                        "{{ if let val = {expression} {{ return __private__OptionFfiSlice(val: val.toFfiSlice(), is_some: true) }} else {{ return __private__OptionFfiSlice(val: __private__FfiSlice(start: nil, len: 0), is_some: false) }} }}()"
+                    , expression = expression
+                    )
                 }
                 StdLibType::Str => {
                     format!("{expression}AsRustStr", expression = expression)
@@ -310,7 +370,7 @@ impl BridgedOption {
                 StdLibType::Result(_) => {
                     todo!("Option<Result<T, E>> is not yet supported")
                 }
-                StdLibType::BoxedFnOnce(_) => {
+                StdLibType::BoxedFn(_) => {
                     todo!("Option<Box<dyn FnOnce(A, B) -> C>> is not yet supported")
                 }
                 StdLibType::Tuple(_) => todo!(),
@@ -359,7 +419,7 @@ impl BridgedOption {
         }
     }
 
-    fn to_ffi_compatible_swift_type(&self, _types: &TypeDeclarations) -> String {
+    fn to_ffi_compatible_swift_type(&self, types: &TypeDeclarations) -> String {
         match self.ty.deref() {
             BridgedType::StdLib(stdlib_type) => match stdlib_type {
                 StdLibType::Null => {
@@ -381,19 +441,17 @@ impl BridgedOption {
                     .unwrap()
                     .to_option_ffi_repr_name()
                     .to_string(),
-                StdLibType::Pointer(_) => {
-                    todo!()
-                }
-                StdLibType::RefSlice(_) => {
-                    todo!()
-                }
+                StdLibType::Pointer(_) => format!(
+                    "{}?",
+                    self.ty
+                        .to_swift_type(TypePosition::FnReturn(HostLang::Swift), types)
+                ),
+                StdLibType::RefSlice(_) => "__private__OptionFfiSlice".to_string(),
                 StdLibType::Str => {
                     todo!()
                 }
-                StdLibType::Vec(_) => {
-                    todo!()
-                }
-                StdLibType::BoxedFnOnce(_) => {
+                StdLibType::Vec(_) => "UnsafeMutableRawPointer?".to_string(),
+                StdLibType::BoxedFn(_) => {
                     todo!()
                 }
                 StdLibType::Option(_) => {
@@ -409,15 +467,17 @@ impl BridgedOption {
             BridgedType::Foreign(_) => {
                 todo!()
             }
-            BridgedType::Bridgeable(_) => {
-                todo!()
+            BridgedType::Bridgeable(b) => {
+                // The Swift type does not depend on the path to the swift_bridge crate.
+                let swift_bridge_path = syn::parse2(quote! { swift_bridge }).unwrap();
+                b.to_ffi_compatible_option_swift_type(&swift_bridge_path, types)
             }
         }
     }
 }
 
 impl BridgedOption {
-    pub fn to_c(&self) -> String {
+    pub fn to_c(&self, types: &TypeDeclarations) -> String {
         match self.ty.deref() {
             BridgedType::Bridgeable(b) => b.to_ffi_compatible_option_c_type(),
             BridgedType::StdLib(stdlib_type) => match stdlib_type {
@@ -437,12 +497,8 @@ impl BridgedOption {
                 StdLibType::F32 => "struct __private__OptionF32".to_string(),
                 StdLibType::F64 => "struct __private__OptionF64".to_string(),
                 StdLibType::Bool => "struct __private__OptionBool".to_string(),
-                StdLibType::Pointer(_) => {
-                    todo!("Option<*const T> and Option<*mut T> are not yet supported")
-                }
-                StdLibType::RefSlice(_) => {
-                    todo!("Option<&[T]> is not yet supported")
-                }
+                StdLibType::Pointer(_) => self.ty.to_c(types),
+                StdLibType::RefSlice(_) => "struct __private__OptionFfiSlice".to_string(),
                 StdLibType::Str => "struct RustStr".to_string(),
                 StdLibType::Vec(_) => "void*".to_string(),
                 StdLibType::Option(_) => {
@@ -451,7 +507,7 @@ impl BridgedOption {
                 StdLibType::Result(_) => {
                     todo!("Option<Result<T, E>> is not yet supported")
                 }
-                StdLibType::BoxedFnOnce(_) => {
+                StdLibType::BoxedFn(_) => {
                     todo!("Option<Box<dyn FnOnce(A, B) -> C>> is not yet supported")
                 }
                 StdLibType::Tuple(_) => todo!(),
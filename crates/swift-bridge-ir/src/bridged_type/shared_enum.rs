@@ -12,6 +12,8 @@ use super::StructFields;
 #[derive(Default, Clone)]
 pub(crate) struct DeriveAttrs {
     pub debug: bool,
+    pub clone: bool,
+    pub partial_eq: bool,
 }
 
 #[derive(Clone)]
@@ -21,6 +23,15 @@ pub(crate) struct SharedEnum {
     pub already_declared: bool,
     pub swift_name: Option<LitStr>,
     pub derive: DeriveAttrs,
+    /// `#[swift_bridge(discriminant_check)]`
+    pub discriminant_check: bool,
+    /// `#[swift_bridge(non_exhaustive)]`
+    pub non_exhaustive: bool,
+    /// `#[swift_bridge(try_from_discriminant)]`
+    pub try_from_discriminant: bool,
+    /// `#[swift_bridge(swift_attr = "@_spi(Internal)")]`
+    /// Swift attributes that are added to the generated Swift enum.
+    pub swift_attrs: Vec<String>,
 }
 
 impl SharedEnum {
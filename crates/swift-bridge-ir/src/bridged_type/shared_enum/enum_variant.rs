@@ -12,6 +12,8 @@ pub(crate) struct EnumVariant {
     pub name: Ident,
     #[allow(unused)]
     pub fields: StructFields,
+    /// An explicit discriminant, such as the `1` in `North = 1`.
+    pub discriminant: Option<i64>,
 }
 
 impl EnumVariant {
@@ -247,6 +249,7 @@ impl Debug for EnumVariant {
         f.debug_struct("EnumVariant")
             .field("name", &self.name.to_string())
             .field("fields", &self.fields)
+            .field("discriminant", &self.discriminant)
             .finish()
     }
 }
@@ -1,16 +1,16 @@
-pub(crate) use self::struct_field::StructField;
 pub(crate) use self::struct_field::StructFields;
 use self::struct_field::UnnamedStructField;
+pub(crate) use self::struct_field::{swift_default_value, StructField};
 use crate::bridged_type::{BridgedType, OnlyEncoding, TypePosition};
-use crate::parse::TypeDeclarations;
+use crate::parse::{SharedTypeDeclaration, TypeDeclaration, TypeDeclarations};
 use crate::SWIFT_BRIDGE_PREFIX;
-use proc_macro2::{Ident, Span, TokenStream};
-use quote::quote;
-use quote::{format_ident, quote_spanned};
+use proc_macro2::{Ident, Literal, Span, TokenStream};
+use quote::quote_spanned;
+use quote::{quote, ToTokens};
 use std::fmt::{Debug, Formatter};
 use std::str::FromStr;
 use syn::spanned::Spanned;
-use syn::{LitStr, Path, Type};
+use syn::{GenericArgument, LitStr, Path, PathArguments, Type};
 
 mod struct_field;
 
@@ -22,7 +22,11 @@ impl UnnamedStructFields {
         let unnamed_fields = types
             .into_iter()
             .enumerate()
-            .map(|(idx, ty)| UnnamedStructField { ty: ty, idx: idx })
+            .map(|(idx, ty)| UnnamedStructField {
+                ty: ty,
+                idx: idx,
+                default: None,
+            })
             .collect();
         Self(unnamed_fields)
     }
@@ -250,13 +254,164 @@ pub(crate) struct SharedStruct {
     pub fields: StructFields,
     pub swift_name: Option<LitStr>,
     pub already_declared: bool,
+    /// `#[swift_bridge(diffable)]`
+    /// Whether or not to generate methods for computing and applying the struct's changed fields.
+    pub diffable: bool,
+    /// `#[swift_bridge(little_endian)]`
+    /// Whether or not to generate methods for encoding the struct as little-endian bytes.
+    pub little_endian: bool,
+    /// `#[swift_bridge(mirror = "crate::models::SomeType")]`
+    /// A type with the same fields that we generate `From` impls to and from.
+    pub mirror: Option<Path>,
     pub derives: StructDerives,
+    /// `#[repr(C, align(N))]`, `#[repr(C, packed)]` or `#[swift_bridge(packed)]`
+    pub layout: StructLayout,
+    /// `#[swift_bridge(instantiate(...))]`
+    /// Set if this is one of the concrete types of a generic struct.
+    pub instantiation: Option<StructInstantiation>,
+    /// `#[swift_bridge(swift_attr = "@_spi(Internal)")]`
+    /// Swift attributes that are added to the generated Swift struct or class.
+    pub swift_attrs: Vec<String>,
+    /// `#[swift_bridge(Sendable)]`
+    /// Whether or not to mark the Swift struct as `Sendable` and assert that the Rust struct is
+    /// `Send + Sync`.
+    pub sendable: bool,
+    /// `#[swift_bridge(serde_json_round_trip)]`
+    /// Whether or not to generate a Swift `serdeJsonRoundTrip` function.
+    pub serde_json_round_trip: bool,
+}
+
+/// One of the concrete types that a generic shared struct is instantiated with.
+///
+/// `#[swift_bridge(instantiate(u32, f64))] struct Pair<T> { ... }` has two instantiations,
+/// `Pair<u32>` and `Pair<f64>`, whose fields have had `T` replaced with `u32` and `f64`.
+#[derive(Clone)]
+pub(crate) struct StructInstantiation {
+    /// `T` in `struct Pair<T>`
+    pub generics: Vec<Ident>,
+    /// `u32` in `Pair<u32>`
+    pub args: Vec<Ident>,
+    /// The fields before the generics were replaced, such as `first: T`.
+    pub generic_fields: StructFields,
+    /// Whether this is the first of the struct's instantiations. The generic Rust and Swift
+    /// structs are only declared once, alongside the first instantiation.
+    pub is_first: bool,
 }
 
 #[derive(Clone)]
 pub(crate) struct StructDerives {
     pub copy: bool,
     pub clone: bool,
+    /// `#[swift_bridge(derive(Equatable))]`
+    pub equatable: bool,
+    /// `#[swift_bridge(derive(Hashable))]`
+    pub hashable: bool,
+    /// `#[swift_bridge(derive(Codable))]`
+    pub codable: bool,
+    /// `Serialize` or `serde::Serialize` in `#[derive(...)]`
+    pub serialize: bool,
+    /// `Deserialize` or `serde::Deserialize` in `#[derive(...)]`
+    pub deserialize: bool,
+    /// The serde derives as they were written, such as `serde::Serialize`.
+    pub serde_paths: Vec<TokenStream>,
+}
+
+/// The alignment and padding of a shared struct's Rust and C representations.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub(crate) struct StructLayout {
+    /// `#[repr(C, align(N))]`
+    pub align: Option<u32>,
+    /// `#[repr(C, packed)]` or `#[swift_bridge(packed)]`
+    pub packed: bool,
+}
+
+impl StructLayout {
+    /// `#[repr(C)]`, `#[repr(C, align(N))]` or `#[repr(C, packed)]`
+    pub(crate) fn to_rust_repr(&self) -> TokenStream {
+        if self.packed {
+            quote! { #[repr(C, packed)] }
+        } else if let Some(align) = self.align {
+            let align = proc_macro2::Literal::u32_unsuffixed(align);
+            quote! { #[repr(C, align(#align))] }
+        } else {
+            quote! { #[repr(C)] }
+        }
+    }
+
+    /// Declare the first of a C struct's fields with `_Alignas(N)`, which raises the alignment of
+    /// the whole struct to match `#[repr(C, align(N))]`.
+    pub(crate) fn align_c_fields(&self, fields: &mut [String]) {
+        if let (Some(align), Some(first)) = (self.align, fields.first_mut()) {
+            *first = format!("_Alignas({}) {}", align, first);
+        }
+    }
+
+    /// Wrap a C struct declaration in `#pragma pack` if the struct is packed.
+    pub(crate) fn wrap_c_declaration(&self, declaration: String) -> String {
+        if self.packed {
+            format!("#pragma pack(push, 1)\n{}\n#pragma pack(pop)", declaration)
+        } else {
+            declaration
+        }
+    }
+}
+
+/// The number of bytes that a field of a `little_endian` struct is encoded as, or `None` if the
+/// field's type does not have a fixed size.
+pub(crate) fn little_endian_field_len(ty: &Type) -> Option<usize> {
+    match ty.to_token_stream().to_string().as_str() {
+        "u8" | "i8" => Some(1),
+        "u16" | "i16" => Some(2),
+        "u32" | "i32" | "f32" => Some(4),
+        "u64" | "i64" | "f64" => Some(8),
+        _ => None,
+    }
+}
+
+/// Whether or not the type holds a shared struct with a `&'static str` field, such as a
+/// `Vec<SomeStruct>` or a struct that has a `SomeStruct` field.
+///
+/// Swift cannot create a `&'static str`, so these can only be passed from Rust to Swift.
+pub(crate) fn holds_struct_with_static_str_field(ty: &Type, types: &TypeDeclarations) -> bool {
+    let holds = |ty: &Type| holds_struct_with_static_str_field(ty, types);
+
+    match ty {
+        Type::Path(path) => {
+            if let Some(TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct))) =
+                types.get_with_type_path(path)
+            {
+                let is_str = |ty: &Type| match ty {
+                    Type::Reference(reference) => {
+                        reference.elem.to_token_stream().to_string() == "str"
+                    }
+                    _ => false,
+                };
+                return shared_struct
+                    .fields
+                    .normalized_fields()
+                    .iter()
+                    .any(|field| is_str(&field.ty) || holds(&field.ty));
+            }
+
+            path.path
+                .segments
+                .iter()
+                .any(|segment| match &segment.arguments {
+                    PathArguments::AngleBracketed(generics) => {
+                        generics.args.iter().any(|arg| match arg {
+                            GenericArgument::Type(ty) => holds(ty),
+                            _ => false,
+                        })
+                    }
+                    _ => false,
+                })
+        }
+        Type::Reference(reference) => holds(&reference.elem),
+        Type::Slice(slice) => holds(&slice.elem),
+        Type::Array(array) => holds(&array.elem),
+        Type::Tuple(tuple) => tuple.elems.iter().any(holds),
+        _ => false,
+    }
 }
 
 impl SharedStruct {
@@ -267,15 +422,70 @@ impl SharedStruct {
         }
     }
 
+    /// The struct's Swift type, such as `SomeStruct` or `Pair<UInt32>`.
+    pub(crate) fn swift_type_string(&self, types: &TypeDeclarations) -> String {
+        let name = self.swift_name_string();
+        match &self.instantiation {
+            Some(instantiation) => {
+                let args: Vec<String> = instantiation
+                    .args
+                    .iter()
+                    .map(|arg| {
+                        BridgedType::new_with_str(&arg.to_string(), types)
+                            .unwrap()
+                            .to_swift_type(TypePosition::SharedStructField, types)
+                    })
+                    .collect();
+                format!("{}<{}>", name, args.join(", "))
+            }
+            None => name,
+        }
+    }
+
+    /// The struct's Rust type, such as `SomeStruct` or `Pair<u32>`.
+    pub(crate) fn rust_type_tokens(&self) -> TokenStream {
+        let name = &self.name;
+        match &self.instantiation {
+            Some(instantiation) => {
+                let args = &instantiation.args;
+                quote! { #name<#(#args),*> }
+            }
+            None => quote! { #name },
+        }
+    }
+
+    /// "$u32$f64" for `Pair<u32, f64>` when the separator is `$`.
+    /// "" if the struct is not generic.
+    fn generics_suffix(&self, separator: &str) -> String {
+        match &self.instantiation {
+            Some(instantiation) => instantiation
+                .args
+                .iter()
+                .map(|arg| format!("{}{}", separator, arg))
+                .collect(),
+            None => "".to_string(),
+        }
+    }
+
     pub(crate) fn ffi_name_string(&self) -> String {
         let name = self.swift_name_string();
 
-        format!("{}${}", SWIFT_BRIDGE_PREFIX, name)
+        format!(
+            "{}${}{}",
+            SWIFT_BRIDGE_PREFIX,
+            name,
+            self.generics_suffix("$")
+        )
     }
 
     pub(crate) fn ffi_name_tokens(&self) -> TokenStream {
         let name = Ident::new(
-            &format!("{}{}", SWIFT_BRIDGE_PREFIX, &self.name),
+            &format!(
+                "{}{}{}",
+                SWIFT_BRIDGE_PREFIX,
+                &self.name,
+                self.generics_suffix("_")
+            ),
             self.name.span(),
         );
 
@@ -287,7 +497,12 @@ impl SharedStruct {
     /// __swift_bridge__Option_SomeStruct
     pub fn ffi_option_name_tokens(&self) -> TokenStream {
         let name = Ident::new(
-            &format!("{}Option_{}", SWIFT_BRIDGE_PREFIX, self.name),
+            &format!(
+                "{}Option_{}{}",
+                SWIFT_BRIDGE_PREFIX,
+                self.name,
+                self.generics_suffix("_")
+            ),
             self.name.span(),
         );
         quote! { #name }
@@ -296,7 +511,12 @@ impl SharedStruct {
     /// __swift_bridge__$Option$SomeStruct
     pub fn ffi_option_name_string(&self) -> String {
         let name = self.swift_name_string();
-        format!("{}$Option${}", SWIFT_BRIDGE_PREFIX, name,)
+        format!(
+            "{}$Option${}{}",
+            SWIFT_BRIDGE_PREFIX,
+            name,
+            self.generics_suffix("$")
+        )
     }
 
     /// Some if the struct has a single variant.
@@ -370,8 +590,8 @@ impl SharedStruct {
         swift_bridge_path: &Path,
         span: Span,
     ) -> TokenStream {
-        let struct_name = &self.name;
-        let struct_ffi_name = format_ident!("{}{}", SWIFT_BRIDGE_PREFIX, struct_name);
+        let struct_name = self.rust_type_tokens();
+        let struct_ffi_name = self.ffi_name_tokens();
 
         let converted_fields: Vec<TokenStream> = self
             .fields
@@ -420,6 +640,117 @@ impl SharedStruct {
         }
     }
 
+    /// Generate `From` impls between this struct and the type that it mirrors, converting each
+    /// field with `Into`.
+    ///
+    /// impl From<SomeStruct> for crate::models::SomeStruct {
+    ///     fn from(val: SomeStruct) -> Self {
+    ///         Self { field: val.field.into() }
+    ///     }
+    /// }
+    pub(crate) fn generate_mirror_conversions(&self) -> Option<TokenStream> {
+        let mirror = self.mirror.as_ref()?;
+        let struct_name = self.rust_type_tokens();
+
+        let converted_fields: Vec<TokenStream> = self
+            .fields
+            .normalized_fields()
+            .iter()
+            .map(|norm_field| {
+                let maybe_name_and_colon = norm_field.maybe_name_and_colon();
+                let access_field = norm_field.append_field_accessor(&quote! {val});
+
+                quote! {
+                    #maybe_name_and_colon #access_field.into()
+                }
+            })
+            .collect();
+        let converted_fields = self.wrap_fields(&converted_fields);
+
+        let val = if self.fields.is_empty() {
+            quote! { _val }
+        } else {
+            quote! { val }
+        };
+
+        Some(quote! {
+            impl From<#struct_name> for #mirror {
+                fn from(#val: #struct_name) -> Self {
+                    Self #converted_fields
+                }
+            }
+
+            impl From<#mirror> for #struct_name {
+                fn from(#val: #mirror) -> Self {
+                    Self #converted_fields
+                }
+            }
+        })
+    }
+
+    /// Generate methods for encoding a `little_endian` struct's fields, in order and without
+    /// padding, as little-endian bytes.
+    ///
+    /// impl SomeStruct {
+    ///     pub const LE_BYTES_LEN: usize = 4;
+    ///     pub fn to_le_bytes(&self) -> [u8; 4] { ... }
+    ///     pub fn from_le_bytes(bytes: [u8; 4]) -> Self { ... }
+    /// }
+    pub(crate) fn generate_little_endian_conversions(&self) -> Option<TokenStream> {
+        if !self.little_endian {
+            return None;
+        }
+        let struct_name = self.rust_type_tokens();
+
+        let mut len = 0;
+        let mut encoded_fields: Vec<TokenStream> = vec![];
+        let mut decoded_fields: Vec<TokenStream> = vec![];
+        for norm_field in self.fields.normalized_fields() {
+            let start = Literal::usize_unsuffixed(len);
+            len += little_endian_field_len(&norm_field.ty).unwrap();
+            let end = Literal::usize_unsuffixed(len);
+
+            let maybe_name_and_colon = norm_field.maybe_name_and_colon();
+            let access_field = norm_field.append_field_accessor(&quote! {self});
+            let ty = &norm_field.ty;
+
+            encoded_fields.push(quote! {
+                bytes[#start..#end].copy_from_slice(&#access_field.to_le_bytes());
+            });
+            decoded_fields.push(quote! {
+                #maybe_name_and_colon #ty::from_le_bytes(bytes[#start..#end].try_into().unwrap())
+            });
+        }
+        let len = Literal::usize_unsuffixed(len);
+        let decoded_fields = self.wrap_fields(&decoded_fields);
+
+        let bytes = if self.fields.is_empty() {
+            quote! { _bytes }
+        } else {
+            quote! { bytes }
+        };
+
+        Some(quote! {
+            impl #struct_name {
+                /// The number of bytes in this struct's little-endian encoding.
+                pub const LE_BYTES_LEN: usize = #len;
+
+                /// Encode this struct's fields, in order and without padding, as little-endian
+                /// bytes.
+                pub fn to_le_bytes(&self) -> [u8; #len] {
+                    let mut bytes = [0; #len];
+                    #(#encoded_fields)*
+                    bytes
+                }
+
+                /// Decode a struct that was encoded using `to_le_bytes`.
+                pub fn from_le_bytes(#bytes: [u8; #len]) -> Self {
+                    Self #decoded_fields
+                }
+            }
+        })
+    }
+
     pub(crate) fn convert_swift_to_ffi_repr(
         &self,
         expression: &str,
@@ -526,13 +857,13 @@ impl SharedStruct {
     pub fn type_name_with_swift_bridge_prefix(&self, swift_bridge_path: &Path) -> TokenStream {
         let ty_name = &self.name;
 
-        let prefixed_ty_name = Ident::new(
-            &format!("{}{}", SWIFT_BRIDGE_PREFIX, ty_name),
-            ty_name.span(),
-        );
+        let prefixed_ty_name = self.ffi_name_tokens();
 
         let prefixed_ty_name = if self.already_declared {
             quote! { <super:: #ty_name as #swift_bridge_path::SharedStruct>::FfiRepr }
+        } else if self.is_class() {
+            let struct_type = self.rust_type_tokens();
+            quote! { *mut #struct_type }
         } else {
             quote! { #prefixed_ty_name }
         };
@@ -545,6 +876,11 @@ impl SharedStruct {
         value: &TokenStream,
         span: Span,
     ) -> TokenStream {
+        if self.is_class() {
+            return quote_spanned! {span=>
+                unsafe { *Box::from_raw(#value) }
+            };
+        }
         quote_spanned! {span=>
             #value.into_rust_repr()
         }
@@ -554,6 +890,11 @@ impl SharedStruct {
         if let Some(_only) = self.only_encoding() {
             return quote! { {#expression;} };
         }
+        if self.is_class() {
+            return quote! {
+                Box::into_raw(Box::new(#expression))
+            };
+        }
         quote! {
             #expression.into_ffi_repr()
         }
@@ -563,14 +904,47 @@ impl SharedStruct {
         if let Some(only) = self.only_encoding() {
             return format!("{{ let _ = {}; return {} }}()", expression, only.swift);
         }
+        if self.is_class() {
+            return format!("{}(ptr: {})", self.swift_name_string(), expression);
+        }
         format!("{}.intoSwiftRepr()", expression)
     }
     pub fn convert_swift_expression_to_ffi_type(&self, expression: &str) -> String {
         if let Some(_only) = self.only_encoding() {
             return format!("{{ let _ = {}; }}()", expression);
         }
+        if self.is_class() {
+            return format!(
+                "{{ let val = {}; val.isOwned = false; return val.ptr }}()",
+                expression
+            );
+        }
         format!("{}.intoFfiRepr()", expression)
     }
+
+    /// Whether the struct has `swift_repr = "class"`, in which case it is passed over FFI as a
+    /// pointer to a boxed Rust value instead of by value.
+    pub(crate) fn is_class(&self) -> bool {
+        self.swift_repr == StructSwiftRepr::Class
+    }
+
+    /// Whether we generate the functions that let Swift use a `Vec` of this struct as a
+    /// `RustVec`.
+    ///
+    /// Reading an element from Swift copies it out of the `Vec`, so the struct must derive `Clone`.
+    pub(crate) fn supports_vec(&self) -> bool {
+        self.derives.clone && !self.is_class() && self.instantiation.is_none()
+    }
+
+    /// Whether a `serdeJsonRoundTrip` function gets generated, which tests that the struct's
+    /// `Codable` and serde JSON formats agree.
+    pub(crate) fn has_serde_json_round_trip(&self) -> bool {
+        self.serde_json_round_trip
+            && self.derives.codable
+            && self.derives.serialize
+            && self.derives.deserialize
+            && self.instantiation.is_none()
+    }
 }
 
 impl PartialEq for SharedStruct {
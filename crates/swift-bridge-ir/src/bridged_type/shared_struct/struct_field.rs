@@ -2,7 +2,7 @@ use std::fmt::{Debug, Formatter};
 
 use proc_macro2::{Ident, TokenStream};
 use quote::{quote, ToTokens};
-use syn::{Fields, Type};
+use syn::{Expr, ExprLit, ExprUnary, Fields, Lit, LitStr, Type, UnOp};
 
 pub(crate) use self::normalized_field::*;
 
@@ -25,6 +25,15 @@ impl StructFields {
         }
     }
 
+    /// The types of the fields, in order.
+    pub fn types(&self) -> Vec<&Type> {
+        match self {
+            StructFields::Named(named) => named.iter().map(|n| &n.ty).collect(),
+            StructFields::Unnamed(unnamed) => unnamed.iter().map(|u| &u.ty).collect(),
+            StructFields::Unit => Vec::new(),
+        }
+    }
+
     pub fn normalized_fields(&self) -> Vec<NormalizedStructField> {
         match self {
             StructFields::Named(named) => named
@@ -32,6 +41,7 @@ impl StructFields {
                 .map(|n| NormalizedStructField {
                     accessor: NormalizedStructFieldAccessor::Named(n.name.clone()),
                     ty: n.ty.clone(),
+                    swift_name: n.swift_name.as_ref().map(|name| name.value()),
                 })
                 .collect(),
             StructFields::Unnamed(unnamed) => unnamed
@@ -39,6 +49,7 @@ impl StructFields {
                 .map(|u| NormalizedStructField {
                     accessor: NormalizedStructFieldAccessor::Unnamed(u.idx),
                     ty: u.ty.clone(),
+                    swift_name: None,
                 })
                 .collect(),
             StructFields::Unit => Vec::new(),
@@ -82,6 +93,34 @@ impl StructFields {
         }
     }
 
+    /// Replace the type of each of the fields.
+    pub fn map_types(&self, map: impl Fn(&Type) -> Type) -> Self {
+        match self {
+            StructFields::Named(named) => StructFields::Named(
+                named
+                    .iter()
+                    .map(|field| NamedStructField {
+                        name: field.name.clone(),
+                        ty: map(&field.ty),
+                        swift_name: field.swift_name.clone(),
+                        default: field.default.clone(),
+                    })
+                    .collect(),
+            ),
+            StructFields::Unnamed(unnamed) => StructFields::Unnamed(
+                unnamed
+                    .iter()
+                    .map(|field| UnnamedStructField {
+                        ty: map(&field.ty),
+                        idx: field.idx,
+                        default: field.default.clone(),
+                    })
+                    .collect(),
+            ),
+            StructFields::Unit => StructFields::Unit,
+        }
+    }
+
     pub fn from_syn_fields(fields: Fields) -> Self {
         match fields {
             Fields::Named(f) => {
@@ -90,6 +129,8 @@ impl StructFields {
                     let field = NamedStructField {
                         name: field.ident.clone().unwrap(),
                         ty: field.ty.clone(),
+                        swift_name: None,
+                        default: None,
                     };
                     fields.push(field);
                 }
@@ -102,6 +143,7 @@ impl StructFields {
                     let field = UnnamedStructField {
                         ty: field.ty.clone(),
                         idx,
+                        default: None,
                     };
                     fields.push(field);
                 }
@@ -117,17 +159,53 @@ impl StructFields {
 pub(crate) struct NamedStructField {
     pub name: Ident,
     pub ty: Type,
+    /// `#[swift_bridge(swift_name = "someField")]`
+    /// The name of the field in Swift and in the C header.
+    pub swift_name: Option<LitStr>,
+    /// `#[swift_bridge(default = 0)]`
+    pub default: Option<Expr>,
 }
 
 #[derive(Clone)]
 pub(crate) struct UnnamedStructField {
     pub ty: Type,
     pub idx: usize,
+    /// `#[swift_bridge(default = 0)]`
+    pub default: Option<Expr>,
 }
 
 pub(crate) trait StructField {
     fn field_type(&self) -> &Type;
     fn swift_name_string(&self) -> String;
+    /// The field's default value in the Swift initializer, such as `0` or `true`.
+    fn swift_default_value(&self) -> Option<String>;
+}
+
+/// Convert a field's default value into Swift, or return `None` if it is not an integer, float
+/// or boolean literal.
+///
+/// `0` -> `0`, `-1.5` -> `-1.5`, `true` -> `true`
+pub(crate) fn swift_default_value(default: &Expr) -> Option<String> {
+    match default {
+        Expr::Lit(expr_lit) => match &expr_lit.lit {
+            Lit::Int(int) => Some(int.base10_digits().to_string()),
+            Lit::Float(float) => Some(float.base10_digits().to_string()),
+            Lit::Bool(bool) => Some(bool.value.to_string()),
+            _ => None,
+        },
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => match expr.as_ref() {
+            Expr::Lit(ExprLit {
+                lit: Lit::Int(_) | Lit::Float(_),
+                ..
+            }) => swift_default_value(expr).map(|value| format!("-{}", value)),
+            _ => None,
+        },
+        _ => None,
+    }
 }
 
 impl StructField for NamedStructField {
@@ -136,7 +214,14 @@ impl StructField for NamedStructField {
     }
 
     fn swift_name_string(&self) -> String {
-        self.name.to_string()
+        match &self.swift_name {
+            Some(swift_name) => swift_name.value(),
+            None => self.name.to_string(),
+        }
+    }
+
+    fn swift_default_value(&self) -> Option<String> {
+        self.default.as_ref().and_then(swift_default_value)
     }
 }
 
@@ -148,12 +233,18 @@ impl StructField for UnnamedStructField {
     fn swift_name_string(&self) -> String {
         format!("_{}", self.idx)
     }
+
+    fn swift_default_value(&self) -> Option<String> {
+        self.default.as_ref().and_then(swift_default_value)
+    }
 }
 
 impl PartialEq for NamedStructField {
     fn eq(&self, other: &Self) -> bool {
         self.name.to_string() == other.name.to_string()
             && self.ty.to_token_stream().to_string() == other.ty.to_token_stream().to_string()
+            && self.swift_name.as_ref().map(|name| name.value())
+                == other.swift_name.as_ref().map(|name| name.value())
     }
 }
 
@@ -162,6 +253,10 @@ impl Debug for NamedStructField {
         f.debug_struct("NamedStructField")
             .field("name", &self.name.to_string())
             .field("ty", &self.ty.to_token_stream())
+            .field(
+                "swift_name",
+                &self.swift_name.as_ref().map(|name| name.value()),
+            )
             .finish()
     }
 }
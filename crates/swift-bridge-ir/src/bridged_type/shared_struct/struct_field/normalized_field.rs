@@ -6,6 +6,8 @@ use syn::Type;
 pub(crate) struct NormalizedStructField {
     pub accessor: NormalizedStructFieldAccessor,
     pub ty: Type,
+    /// The field's `#[swift_bridge(swift_name = "...")]`, if it has one.
+    pub swift_name: Option<String>,
 }
 
 pub(crate) enum NormalizedStructFieldAccessor {
@@ -86,7 +88,12 @@ impl NormalizedStructField {
         }
     }
 
+    /// The name of the field in Swift and in the C header.
     pub fn ffi_field_name(&self) -> String {
+        if let Some(swift_name) = &self.swift_name {
+            return swift_name.clone();
+        }
+
         match &self.accessor {
             NormalizedStructFieldAccessor::Named(name) => name.to_string(),
             NormalizedStructFieldAccessor::Unnamed(idx) => {
@@ -1,9 +1,18 @@
 use crate::bridge_module_attributes::CfgAttr;
+use crate::bridged_type::bridgeable_arc::BuiltInArc;
+use crate::bridged_type::bridgeable_measurement::BuiltInMeasurement;
+use crate::parse::{SharedTypeDeclaration, TypeDeclaration};
 use crate::SwiftBridgeModule;
+use quote::ToTokens;
+use std::collections::HashSet;
+use syn::{FnArg, ReturnType, Type};
 
 mod generate_c_header;
 mod generate_rust_tokens;
 mod generate_swift;
+mod hash_symbols;
+
+pub use self::hash_symbols::HashedSymbol;
 
 #[cfg(test)]
 mod codegen_tests;
@@ -14,6 +23,12 @@ pub struct SwiftCodeAndCHeader {
     pub swift: String,
     /// The generated C header.
     pub c_header: String,
+    /// The link names that were replaced with hashes, if the module uses
+    /// `#[swift_bridge(hash_symbols)]`.
+    pub hashed_symbols: Vec<HashedSymbol>,
+    /// The Rust names of the types that the module declares, not counting the types that are
+    /// `already_declared` in another module.
+    pub declared_types: Vec<String>,
 }
 
 /// Configuration for how we will generate our Swift code.
@@ -36,10 +51,45 @@ impl CodegenConfig {
 impl SwiftBridgeModule {
     /// Generate the corresponding Swift code and C header for a bridge module.
     pub fn generate_swift_code_and_c_header(&self, config: CodegenConfig) -> SwiftCodeAndCHeader {
+        let mut hashed_symbols = vec![];
+        let swift = self.generate_swift_with_hashed_symbols(&config, &mut hashed_symbols);
+        let c_header = self.generate_c_header_with_hashed_symbols(&config, &mut hashed_symbols);
+
         SwiftCodeAndCHeader {
-            swift: self.generate_swift(&config),
-            c_header: self.generate_c_header(&config),
+            swift,
+            c_header,
+            hashed_symbols,
+            declared_types: self.declared_type_names(),
+        }
+    }
+
+    /// The names of the types that this module declares, in the order that they were declared.
+    ///
+    /// Each instantiation of a generic struct is its own declaration, so the struct's name is
+    /// only listed once.
+    fn declared_type_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = vec![];
+
+        for ty in self.types.types() {
+            let (name, already_declared) = match ty {
+                TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct)) => (
+                    shared_struct.name.to_string(),
+                    shared_struct.already_declared,
+                ),
+                TypeDeclaration::Shared(SharedTypeDeclaration::Enum(shared_enum)) => {
+                    (shared_enum.name.to_string(), shared_enum.already_declared)
+                }
+                TypeDeclaration::Opaque(opaque) => {
+                    (opaque.ty.to_string(), opaque.attributes.already_declared)
+                }
+            };
+
+            if !already_declared && !names.contains(&name) {
+                names.push(name);
+            }
         }
+
+        names
     }
 
     /// Whether or not the module's conditional compilation flags willl lead it to being included
@@ -58,4 +108,90 @@ impl SwiftBridgeModule {
 
         true
     }
+
+    /// The names of the opaque Rust types that this module's functions pass across the bridge
+    /// inside of an `Arc<T>`.
+    fn opaque_rust_types_in_arc(&self) -> HashSet<String> {
+        let mut names = HashSet::new();
+
+        for func in &self.functions {
+            for arg in func.func.sig.inputs.iter() {
+                if let FnArg::Typed(pat_ty) = arg {
+                    BuiltInArc::collect_pointee_names(&pat_ty.ty, &self.types, &mut names);
+                }
+            }
+            if let ReturnType::Type(_, ty) = &func.func.sig.output {
+                BuiltInArc::collect_pointee_names(ty, &self.types, &mut names);
+            }
+        }
+
+        names
+    }
+
+    /// Whether or not this module's functions or shared structs use a type that Swift sees as a
+    /// Foundation type, such as a `Duration` (`TimeInterval`), a `SystemTime` (`Date`), a `Uuid`
+    /// (`UUID`) or a `Length` (`Measurement<UnitLength>`).
+    ///
+    /// Compressed `Vec<u8>`s are returned to Swift as a Foundation `Data`, and a struct's
+    /// `serdeJsonRoundTrip` uses Foundation's `JSONEncoder` and `JSONDecoder`. An opaque type
+    /// with the `Error` attribute conforms to Foundation's `LocalizedError`.
+    fn uses_foundation_types(&self) -> bool {
+        self.functions.iter().any(|func| func.compress.is_some())
+            || self.types.types().iter().any(|ty| match ty {
+                TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct)) => {
+                    shared_struct.has_serde_json_round_trip()
+                }
+                TypeDeclaration::Opaque(opaque) => {
+                    opaque.host_lang.is_rust() && opaque.attributes.error
+                }
+                _ => false,
+            })
+            || self.uses_type_named(|name| {
+                matches!(
+                    name,
+                    "Duration" | "SystemTime" | "DateTime" | "OffsetDateTime" | "Uuid"
+                ) || BuiltInMeasurement::type_names().contains(&name)
+            })
+    }
+
+    /// Whether or not this module's functions or shared structs use a `PixelBuffer`, which Swift
+    /// sees as a Core Video `CVPixelBuffer`.
+    fn uses_core_video_types(&self) -> bool {
+        self.uses_type_named(|name| name == "PixelBuffer")
+    }
+
+    /// Whether or not one of the words in a function signature or shared struct field's type
+    /// matches, i.e. "Option", "Vec" and "Duration" for an `Option<Vec<Duration>>`.
+    fn uses_type_named(&self, is_match: impl Fn(&str) -> bool) -> bool {
+        let uses_type = |ty: &Type| {
+            ty.to_token_stream()
+                .to_string()
+                .split(|c: char| !c.is_alphanumeric() && c != '_')
+                .any(&is_match)
+        };
+
+        for func in &self.functions {
+            for arg in func.func.sig.inputs.iter() {
+                if let FnArg::Typed(pat_ty) = arg {
+                    if uses_type(&pat_ty.ty) {
+                        return true;
+                    }
+                }
+            }
+            if let ReturnType::Type(_, ty) = &func.func.sig.output {
+                if uses_type(ty) {
+                    return true;
+                }
+            }
+        }
+
+        self.types.types().into_iter().any(|ty| match ty {
+            TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct)) => shared_struct
+                .fields
+                .normalized_fields()
+                .iter()
+                .any(|field| uses_type(&field.ty)),
+            _ => false,
+        })
+    }
 }
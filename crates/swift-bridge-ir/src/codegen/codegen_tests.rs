@@ -28,30 +28,66 @@ use crate::test_utils::{
 };
 
 mod already_declared_attribute_codegen_tests;
+mod arc_codegen_tests;
 mod argument_label_codegen_tests;
+mod array_codegen_tests;
 mod async_function_codegen_tests;
+mod boxed_fn_codegen_tests;
 mod boxed_fnonce_codegen_tests;
+mod btree_map_codegen_tests;
 mod built_in_tuple_codegen_tests;
+mod byte_stream_codegen_tests;
 mod c_header_declaration_order_codegen_tests;
+mod char_codegen_tests;
+mod compression_codegen_tests;
 mod conditional_compilation_codegen_tests;
+mod constant_codegen_tests;
+mod cow_str_codegen_tests;
+mod cursor_codegen_tests;
+mod date_time_codegen_tests;
 mod derive_attribute_codegen_tests;
 mod derive_struct_attribute_codegen_tests;
+mod duration_codegen_tests;
 mod extern_rust_function_opaque_rust_type_argument_codegen_tests;
 mod extern_rust_function_opaque_rust_type_return_codegen_tests;
 mod extern_rust_method_swift_class_placement_codegen_tests;
 mod extern_swift_function_opaque_swift_type_return_codegen_tests;
+mod failable_initializer_codegen_tests;
 mod function_attribute_codegen_tests;
 mod generic_opaque_rust_type_codegen_tests;
+mod hash_set_codegen_tests;
+mod hash_symbols_codegen_tests;
+mod image_buffer_codegen_tests;
+mod measurement_codegen_tests;
+mod module_table_codegen_tests;
+mod mut_slice_codegen_tests;
+mod net_addr_codegen_tests;
 mod opaque_rust_type_codegen_tests;
+mod opaque_rust_type_slice_codegen_tests;
 mod opaque_swift_type_codegen_tests;
 mod option_codegen_tests;
+mod out_argument_codegen_tests;
+mod ownership_doc_comment_codegen_tests;
+mod path_codegen_tests;
+mod pixel_buffer_codegen_tests;
+mod pointer_codegen_tests;
+mod range_codegen_tests;
+mod reflection_codegen_tests;
 mod result_codegen_tests;
 mod return_into_attribute_codegen_tests;
+mod scoped_codegen_tests;
+mod sendable_codegen_tests;
+mod serde_json_codegen_tests;
 mod single_representation_type_elision_codegen_tests;
 mod string_codegen_tests;
+mod swift_attr_codegen_tests;
+mod system_time_codegen_tests;
+mod tensor_codegen_tests;
 mod transparent_enum_codegen_tests;
 mod transparent_struct_codegen_tests;
+mod uuid_codegen_tests;
 mod vec_codegen_tests;
+mod watch_codegen_tests;
 
 struct CodegenTest {
     bridge_module: BridgeModule,
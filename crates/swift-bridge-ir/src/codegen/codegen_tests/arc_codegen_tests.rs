@@ -0,0 +1,201 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we can take and return an Arc of an opaque Rust type from a Rust function.
+mod extern_rust_fn_arc_of_opaque_rust_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn some_function (arg: Arc<SomeType>) -> Arc<SomeType>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$some_function"]
+                pub extern "C" fn __swift_bridge__some_function(
+                    arg: *const super::SomeType
+                ) -> *const super::SomeType {
+                    std::sync::Arc::into_raw(super::some_function(unsafe { std::sync::Arc::from_raw(arg) }))
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$_arc_free"]
+                pub extern "C" fn __swift_bridge__SomeType__arc_free (this: *const super::SomeType) {
+                    unsafe { std::sync::Arc::decrement_strong_count(this) }
+                }
+
+                #[export_name = "__swift_bridge__$SomeType$_arc_clone"]
+                pub extern "C" fn __swift_bridge__SomeType__arc_clone (this: *const super::SomeType) -> *const super::SomeType {
+                    unsafe { std::sync::Arc::increment_strong_count(this) };
+                    this
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func some_function(_ arg: SomeTypeArc) -> SomeTypeArc {
+    SomeTypeArc(ptr: __swift_bridge__$some_function(__swift_bridge__$SomeType$_arc_clone(arg.ptr)))
+}
+"#,
+            r#"
+public class SomeTypeArc: SomeTypeRef {
+    public override init(ptr: UnsafeMutableRawPointer) {
+        super.init(ptr: ptr)
+    }
+
+    deinit {
+        __swift_bridge__$SomeType$_arc_free(ptr)
+    }
+
+    public func clone() -> SomeTypeArc {
+        SomeTypeArc(ptr: __swift_bridge__$SomeType$_arc_clone(ptr))
+    }
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+void __swift_bridge__$SomeType$_arc_free(void* self);
+void* __swift_bridge__$SomeType$_arc_clone(void* self);
+"#,
+            r#"
+void* __swift_bridge__$some_function(void* arg);
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_arc_of_opaque_rust_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we can return an `Option<Arc<T>>` from a Rust function.
+mod extern_rust_fn_return_option_arc {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn some_function () -> Option<Arc<SomeType>>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$some_function"]
+                pub extern "C" fn __swift_bridge__some_function() -> *const super::SomeType {
+                    if let Some(val) = super::some_function() {
+                        std::sync::Arc::into_raw(val)
+                    } else {
+                        std::ptr::null()
+                    }
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$_arc_free"]
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func some_function() -> Optional<SomeTypeArc> {
+    { let val = __swift_bridge__$some_function(); if val != nil { return SomeTypeArc(ptr: val!) } else { return nil } }()
+}
+"#,
+            r#"
+public class SomeTypeArc: SomeTypeRef {
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$some_function(void);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_return_option_arc() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we do not generate `Arc` support for an opaque Rust type that is never used in an
+/// `Arc`.
+mod opaque_rust_type_without_arc {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::DoesNotContain(quote! {
+            _arc_free
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::DoesNotContainAfterTrim("SomeTypeArc")
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::DoesNotContainAfterTrim("_arc_free")
+    }
+
+    #[test]
+    fn opaque_rust_type_without_arc() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -0,0 +1,189 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we can take and return a fixed-size array from a Rust function.
+mod extern_rust_fn_array_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    fn some_function (arg: [u8; 4]) -> [f32; 2];
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::FfiArray<u8, 4usize>
+            ) -> swift_bridge::FfiArray<f32, 2usize> {
+                swift_bridge::FfiArray(super::some_function(arg.0))
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+func some_function(_ arg: (UInt8, UInt8, UInt8, UInt8)) -> (Float, Float) {
+    __swift_bridge__$some_function(__swift_bridge__$array$U8$4(_0: arg))._0
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+#include <stdint.h>
+struct __swift_bridge__$array$F32$2 __swift_bridge__$some_function(struct __swift_bridge__$array$U8$4 arg);
+typedef struct __swift_bridge__$array$F32$2 { float _0[2]; } __swift_bridge__$array$F32$2;
+typedef struct __swift_bridge__$array$U8$4 { uint8_t _0[4]; } __swift_bridge__$array$U8$4;
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_array_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we can pass a fixed-size array to and from a Swift function.
+mod extern_swift_fn_array_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Swift" {
+                    fn some_function (arg: [i64; 2]) -> [bool; 3];
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(arg: [i64; 2usize]) -> [bool; 3usize] {
+                    unsafe { __swift_bridge__some_function(swift_bridge::FfiArray(arg)) }.0
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(
+                    arg: swift_bridge::FfiArray<i64, 2usize>
+                ) -> swift_bridge::FfiArray<bool, 3usize>;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: __swift_bridge__$array$I64$2) -> __swift_bridge__$array$Bool$3 {
+    __swift_bridge__$array$Bool$3(_0: some_function(arg: arg._0))
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            "typedef struct __swift_bridge__$array$I64$2 { int64_t _0[2]; } __swift_bridge__$array$I64$2;",
+            "typedef struct __swift_bridge__$array$Bool$3 { bool _0[3]; } __swift_bridge__$array$Bool$3;",
+        ])
+    }
+
+    #[test]
+    fn extern_swift_fn_array_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a shared struct can have a fixed-size array field, and that the array's C struct
+/// gets declared before the shared struct that uses it.
+mod shared_struct_with_array_field {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    id: [u8; 16],
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub struct __swift_bridge__SomeStruct {
+                    id: swift_bridge::FfiArray<u8, 16usize>
+                }
+            },
+            quote! {
+                __swift_bridge__SomeStruct { id: swift_bridge::FfiArray(val.id) }
+            },
+            quote! {
+                SomeStruct { id: val.id.0 }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public struct SomeStruct {
+    public var id: (UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8, UInt8)
+"#,
+            r#"
+__swift_bridge__$SomeStruct(id: __swift_bridge__$array$U8$16(_0: val.id))
+"#,
+            r#"
+SomeStruct(id: val.id._0)
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef struct __swift_bridge__$array$U8$16 { uint8_t _0[16]; } __swift_bridge__$array$U8$16;
+typedef struct __swift_bridge__$SomeStruct { struct __swift_bridge__$array$U8$16 id; } __swift_bridge__$SomeStruct;
+"#,
+        )
+    }
+
+    #[test]
+    fn shared_struct_with_array_field() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -1049,3 +1049,153 @@ void __swift_bridge__$some_function(void* callback_wrapper, void __swift_bridge_
         .test();
     }
 }
+
+/// Verify that we generate an async Swift initializer for an extern "Rust" async init function.
+mod extern_rust_async_initializer {
+    use super::*;
+
+    fn bridge_module() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(init)]
+                    async fn new(value: u8) -> SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub extern "C" fn __swift_bridge__SomeType_new(
+                callback_wrapper: *mut std::ffi::c_void,
+                callback: extern "C" fn(*mut std::ffi::c_void, *mut super::SomeType) -> (),
+                value: u8
+            ) {
+                let callback_wrapper = swift_bridge::async_support::SwiftCallbackWrapper(callback_wrapper);
+                let fut = super::SomeType::new(value);
+                let task = async move {
+                    let val = Box::into_raw(Box::new({
+                        let val: super::SomeType = fut.await;
+                        val
+                    })) as *mut super::SomeType;
+
+                    let callback_wrapper = callback_wrapper;
+                    let callback_wrapper = callback_wrapper.0;
+
+                    (callback)(callback_wrapper, val)
+                };
+                swift_bridge::async_support::ASYNC_RUNTIME.spawn_task(Box::pin(task))
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeType {
+    public convenience init(_ value: UInt8) async {
+        func onComplete(cbWrapperPtr: UnsafeMutableRawPointer?, rustFnRetVal: UnsafeMutableRawPointer?) {
+            let wrapper = Unmanaged<CbWrapper$SomeType$new>.fromOpaque(cbWrapperPtr!).takeRetainedValue()
+            wrapper.cb(.success(SomeType(ptr: rustFnRetVal!)))
+        }
+
+        let val: SomeType = await withCheckedContinuation({ (continuation: CheckedContinuation<SomeType, Never>) in
+            let callback = { rustFnRetVal in
+                continuation.resume(with: rustFnRetVal)
+            }
+
+            let wrapper = CbWrapper$SomeType$new(cb: callback)
+            let wrapperPtr = Unmanaged.passRetained(wrapper).toOpaque()
+
+            __swift_bridge__$SomeType$new(wrapperPtr, onComplete, value)
+        })
+        val.isOwned = false
+        self.init(ptr: val.ptr)
+    }
+    class CbWrapper$SomeType$new {
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$SomeType$new(void* callback_wrapper, void __swift_bridge__$SomeType$new$async(void* callback_wrapper, void* ret), uint8_t value);
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_async_initializer() {
+        CodegenTest {
+            bridge_module: bridge_module().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that an extern "Rust" async init function that returns a `Result` becomes an
+/// `async throws` Swift initializer.
+mod extern_rust_async_throwing_initializer {
+    use super::*;
+
+    fn bridge_module() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(init)]
+                    async fn new() -> Result<SomeType, String>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            let fut = super::SomeType::new();
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+    public convenience init() async throws {
+"#,
+            r#"
+        let val: SomeType = try await withCheckedThrowingContinuation({ (continuation: CheckedContinuation<SomeType, Error>) in
+"#,
+            r#"
+            __swift_bridge__$SomeType$new(wrapperPtr, onComplete)
+        })
+        val.isOwned = false
+        self.init(ptr: val.ptr)
+    }
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn extern_rust_async_throwing_initializer() {
+        CodegenTest {
+            bridge_module: bridge_module().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -0,0 +1,275 @@
+//! Tests for passing a Swift closure to Rust as a `Box<dyn Fn(A, B) -> C>` or a
+//! `Box<dyn FnOnce(A, B) -> C>`.
+//!
+//! Swift retains the closure and passes Rust an opaque pointer to it. Rust calls the closure
+//! through a Swift `@_cdecl` function and releases it through another one when the
+//! `Box<dyn Fn>` or `Box<dyn FnOnce>` gets dropped.
+
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we can pass a `Box<dyn Fn(A) -> B>` from Swift to Rust.
+mod extern_rust_fn_boxed_fn_arg {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(callback: Box<dyn Fn(u32) -> String>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$some_function"]
+                pub extern "C" fn __swift_bridge__some_function(callback: *mut std::ffi::c_void) {
+                    super::some_function({
+                        let callback = unsafe {
+                            swift_bridge::boxed_fn_support::SwiftCallback::new(callback, free_some_function_param0)
+                        };
+                        Box::new(move |arg0: u32| {
+                            let ret = unsafe { some_function_param0(callback.as_ptr(), arg0) };
+                            unsafe { Box::from_raw(ret).0 }
+                        })
+                    })
+                }
+            },
+            quote! {
+                extern "C" {
+                    #[link_name = "__swift_bridge__$some_function$param0"]
+                    fn some_function_param0(
+                        callback: *mut std::ffi::c_void,
+                        arg0: u32
+                    ) -> *mut swift_bridge::string::RustString;
+                    #[link_name = "__swift_bridge__$some_function$_free$param0"]
+                    fn free_some_function_param0(callback: *mut std::ffi::c_void);
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func some_function(_ callback: @escaping (UInt32) -> RustString) {
+    __swift_bridge__$some_function(Unmanaged.passRetained(__private__SwiftCallback(callback)).toOpaque())
+}
+"#,
+            r#"
+@_cdecl("__swift_bridge__$some_function$param0")
+func __swift_bridge__some_function_param0 (_ callback: UnsafeMutableRawPointer, _ arg0: UInt32) -> UnsafeMutableRawPointer {
+    { let rustString = Unmanaged<__private__SwiftCallback<(UInt32) -> RustString>>.fromOpaque(callback).takeUnretainedValue().closure(arg0).intoRustString(); rustString.isOwned = false; return rustString.ptr }()
+}
+@_cdecl("__swift_bridge__$some_function$_free$param0")
+func __swift_bridge__some_function__free_param0 (_ callback: UnsafeMutableRawPointer) {
+    Unmanaged<__private__SwiftCallback<(UInt32) -> RustString>>.fromOpaque(callback).release()
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            "void __swift_bridge__$some_function(void* callback);",
+            r#"
+void* __swift_bridge__$some_function$param0(void* some_function_callback, uint32_t arg0);
+void __swift_bridge__$some_function$_free$param0(void* some_function_callback);
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_boxed_fn_arg() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we can pass a `Box<dyn Fn()>` that has no arguments and no return value from Swift
+/// to a Rust method.
+mod extern_rust_method_boxed_fn_no_args_no_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+                    fn some_method(&self, callback: Box<dyn Fn()>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$some_method"]
+                pub extern "C" fn __swift_bridge__SomeType_some_method(
+                    this: *mut super::SomeType,
+                    callback: *mut std::ffi::c_void
+                ) {
+                    (unsafe { &*this }).some_method({
+                        let callback = unsafe {
+                            swift_bridge::boxed_fn_support::SwiftCallback::new(callback, free_SomeType_some_method_param1)
+                        };
+                        Box::new(move || {
+                            let ret = unsafe { SomeType_some_method_param1(callback.as_ptr()) };
+                            ret
+                        })
+                    })
+                }
+            },
+            quote! {
+                extern "C" {
+                    #[link_name = "__swift_bridge__$SomeType$some_method$param1"]
+                    fn SomeType_some_method_param1(callback: *mut std::ffi::c_void);
+                    #[link_name = "__swift_bridge__$SomeType$some_method$_free$param1"]
+                    fn free_SomeType_some_method_param1(callback: *mut std::ffi::c_void);
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+    public func some_method(_ callback: @escaping () -> ()) {
+        __swift_bridge__$SomeType$some_method(ptr, Unmanaged.passRetained(__private__SwiftCallback(callback)).toOpaque())
+    }
+"#,
+            r#"
+@_cdecl("__swift_bridge__$SomeType$some_method$param1")
+func __swift_bridge__SomeType_some_method_param1 (_ callback: UnsafeMutableRawPointer) {
+    Unmanaged<__private__SwiftCallback<() -> ()>>.fromOpaque(callback).takeUnretainedValue().closure()
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$SomeType$some_method$param1(void* some_method_callback);
+void __swift_bridge__$SomeType$some_method$_free$param1(void* some_method_callback);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_method_boxed_fn_no_args_no_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we can pass a `Box<dyn FnOnce(Result<A, B>)>` completion callback from Swift to a
+/// Rust method.
+mod extern_rust_method_boxed_fnonce_result_arg {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+                    fn fetch(&self, done: Box<dyn FnOnce(Result<String, String>)>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$SomeType$fetch"]
+                pub extern "C" fn __swift_bridge__SomeType_fetch(
+                    this: *mut super::SomeType,
+                    done: *mut std::ffi::c_void
+                ) {
+                    (unsafe { &*this }).fetch({
+                        let callback = unsafe {
+                            swift_bridge::boxed_fn_support::SwiftCallback::new(done, free_SomeType_fetch_param1)
+                        };
+                        Box::new(move |arg0: Result<String, String>| {
+                            let ret = unsafe { SomeType_fetch_param1(
+                                callback.as_ptr(),
+                                match arg0 {
+                                    Ok(ok) => {
+                                        swift_bridge::result::ResultPtrAndPtr {
+                                            is_ok: true,
+                                            ok_or_err: swift_bridge::string::RustString(ok).box_into_raw() as *mut std::ffi::c_void
+                                        }
+                                    }
+                                    Err(err) => {
+                                        swift_bridge::result::ResultPtrAndPtr {
+                                            is_ok: false,
+                                            ok_or_err: swift_bridge::string::RustString(err).box_into_raw() as *mut std::ffi::c_void
+                                        }
+                                    }
+                                }
+                            ) };
+                            ret
+                        })
+                    })
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+    public func fetch(_ done: @escaping (RustResult<RustString, RustString>) -> ()) {
+        __swift_bridge__$SomeType$fetch(ptr, Unmanaged.passRetained(__private__SwiftCallback(done)).toOpaque())
+    }
+"#,
+            r#"
+@_cdecl("__swift_bridge__$SomeType$fetch$param1")
+func __swift_bridge__SomeType_fetch_param1 (_ callback: UnsafeMutableRawPointer, _ arg0: __private__ResultPtrAndPtr) {
+    Unmanaged<__private__SwiftCallback<(RustResult<RustString, RustString>) -> ()>>.fromOpaque(callback).takeUnretainedValue().closure({ () -> RustResult<RustString, RustString> in let val = arg0; if val.is_ok { return RustResult.Ok(RustString(ptr: val.ok_or_err!)) } else { return RustResult.Err(RustString(ptr: val.ok_or_err!)) } }())
+}
+@_cdecl("__swift_bridge__$SomeType$fetch$_free$param1")
+func __swift_bridge__SomeType_fetch__free_param1 (_ callback: UnsafeMutableRawPointer) {
+    Unmanaged<__private__SwiftCallback<(RustResult<RustString, RustString>) -> ()>>.fromOpaque(callback).release()
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            "void __swift_bridge__$SomeType$fetch(void* self, void* done);",
+            r#"
+void __swift_bridge__$SomeType$fetch$param1(void* fetch_done, struct __private__ResultPtrAndPtr arg0);
+void __swift_bridge__$SomeType$fetch$_free$param1(void* fetch_done);
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_method_boxed_fnonce_result_arg() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -0,0 +1,128 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we can take and return a BTreeMap of primitives from a Rust function.
+mod extern_rust_fn_btree_map_of_primitives {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    fn some_function (arg: BTreeMap<u8, f64>) -> BTreeMap<u8, f64>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::btree_map::FfiBTreeMap
+            ) -> swift_bridge::btree_map::FfiBTreeMap {
+                swift_bridge::btree_map::FfiBTreeMap::from_entries(
+                    super::some_function(
+                        unsafe { swift_bridge::btree_map::FfiBTreeMap::into_entries::<u8, f64>(arg) }
+                            .collect::<std::collections::BTreeMap<_, _>>()
+                    )
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+func some_function(_ arg: RustBTreeMap<UInt8, Double>) -> RustBTreeMap<UInt8, Double> {
+    RustBTreeMap(ffi: __swift_bridge__$some_function(arg.intoFfiRepr()))
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+struct __private__FfiBTreeMap __swift_bridge__$some_function(struct __private__FfiBTreeMap arg);
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_btree_map_of_primitives() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that String keys are passed over FFI as RustStrings.
+mod extern_rust_fn_btree_map_with_string_keys {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    fn some_function (arg: BTreeMap<String, u32>) -> BTreeMap<String, u32>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::btree_map::FfiBTreeMap
+            ) -> swift_bridge::btree_map::FfiBTreeMap {
+                swift_bridge::btree_map::FfiBTreeMap::from_entries(
+                    super::some_function(
+                        unsafe {
+                            swift_bridge::btree_map::FfiBTreeMap::into_entries::<swift_bridge::string::RustString, u32>(arg)
+                        }
+                            .map(|(key, value)| (key.0, value))
+                            .collect::<std::collections::BTreeMap<_, _>>()
+                    )
+                        .into_iter()
+                        .map(|(key, value)| (swift_bridge::string::RustString(key), value))
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+func some_function(_ arg: RustBTreeMap<RustString, UInt32>) -> RustBTreeMap<RustString, UInt32> {
+    RustBTreeMap(ffi: __swift_bridge__$some_function(arg.intoFfiRepr()))
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+struct __private__FfiBTreeMap __swift_bridge__$some_function(struct __private__FfiBTreeMap arg);
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_btree_map_with_string_keys() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
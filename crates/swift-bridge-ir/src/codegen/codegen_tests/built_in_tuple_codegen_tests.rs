@@ -489,3 +489,73 @@ func __swift_bridge__some_function (_ arg: __swift_bridge__$tuple$SomeStructSome
         .test();
     }
 }
+
+/// Verify that an opaque Rust type's method can return a tuple, and that the tuple's FFI struct is
+/// only declared once when several functions return the same tuple type.
+mod extern_rust_method_returns_tuple {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+                    fn min_max(&self) -> (i32, i32);
+                    fn first_last(&self) -> (i32, i32);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub extern "C" fn __swift_bridge__SomeType_min_max (this: *mut super::SomeType) -> __swift_bridge__tuple_I32I32 {
+                    { let val = (unsafe { &*this }).min_max(); __swift_bridge__tuple_I32I32(val.0, val.1) }
+                }
+            },
+            quote! {
+                #[repr(C)]
+                #[doc(hidden)]
+                pub struct __swift_bridge__tuple_I32I32(i32, i32);
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+    public func min_max() -> (Int32, Int32) {
+        { let val = __swift_bridge__$SomeType$min_max(ptr); return (val._0, val._1); }()
+    }
+"#,
+            r#"
+    public func first_last() -> (Int32, Int32) {
+        { let val = __swift_bridge__$SomeType$first_last(ptr); return (val._0, val._1); }()
+    }
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __swift_bridge__$tuple$I32I32 __swift_bridge__$SomeType$min_max(void* self);
+struct __swift_bridge__$tuple$I32I32 __swift_bridge__$SomeType$first_last(void* self);
+typedef struct __swift_bridge__$tuple$I32I32 { int32_t _0; int32_t _1; } __swift_bridge__$tuple$I32I32;
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_method_returns_tuple() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -0,0 +1,242 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we can take and return a `char` from a Rust function.
+mod extern_rust_fn_char_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    fn some_function (arg: char) -> char;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(arg: u32) -> u32 {
+                super::some_function(char::from_u32(arg).unwrap()) as u32
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+func some_function(_ arg: Unicode.Scalar) -> Unicode.Scalar {
+    Unicode.Scalar(__swift_bridge__$some_function(arg.value))!
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+#include <stdint.h>
+uint32_t __swift_bridge__$some_function(uint32_t arg);
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_char_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we can pass a `char` to and from a Swift function.
+mod extern_swift_fn_char_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Swift" {
+                    fn some_function (arg: char) -> char;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(arg: char) -> char {
+                    char::from_u32(unsafe { __swift_bridge__some_function(arg as u32) }).unwrap()
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(arg: u32) -> u32;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: UInt32) -> UInt32 {
+    some_function(arg: Unicode.Scalar(arg)!).value
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_char_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we can take and return an `Option<char>` from a Rust function.
+mod extern_rust_fn_option_char_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    fn some_function (arg: Option<char>) -> Option<char>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::option::OptionU32
+            ) -> swift_bridge::option::OptionU32 {
+                if let Some(val) = super::some_function(
+                    {
+                        let val = arg;
+                        if val.is_some {
+                            Some(char::from_u32(val.val).unwrap())
+                        } else {
+                            None
+                        }
+                    }
+                ) {
+                    swift_bridge::option::OptionU32 { val: val as u32, is_some: true }
+                } else {
+                    swift_bridge::option::OptionU32 { val: 123, is_some: false }
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+func some_function(_ arg: Optional<Unicode.Scalar>) -> Optional<Unicode.Scalar> {
+    { let val = __swift_bridge__$some_function({ if let val = arg { return __private__OptionU32(val: val.value, is_some: true) } else { return __private__OptionU32(val: 123, is_some: false) } }()); if val.is_some { return Unicode.Scalar(val.val)! } else { return nil } }()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __private__OptionU32 __swift_bridge__$some_function(struct __private__OptionU32 arg);
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_option_char_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a shared struct can have a `char` field.
+mod shared_struct_with_char_field {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    letter: char,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub struct __swift_bridge__SomeStruct {
+                    letter: u32
+                }
+            },
+            quote! {
+                __swift_bridge__SomeStruct { letter: val.letter as u32 }
+            },
+            quote! {
+                SomeStruct { letter: char::from_u32(val.letter).unwrap() }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public struct SomeStruct {
+    public var letter: Unicode.Scalar
+"#,
+            r#"
+__swift_bridge__$SomeStruct(letter: val.letter.value)
+"#,
+            r#"
+SomeStruct(letter: Unicode.Scalar(val.letter)!)
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef struct __swift_bridge__$SomeStruct { uint32_t letter; } __swift_bridge__$SomeStruct;
+"#,
+        )
+    }
+
+    #[test]
+    fn shared_struct_with_char_field() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -0,0 +1,118 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that a Rust function that returns a `Vec<u8>` can compress it before returning it to
+/// Swift.
+mod extern_rust_fn_compress_vec_u8 {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(compress = "lz4")]
+                    fn some_function (arg: u32) -> Vec<u8>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(arg: u32) -> *mut Vec<u8> {
+                swift_bridge::compression_support::lz4_compress(
+                    AsRef::<[u8]>::as_ref(&super::some_function(arg))
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: UInt32) -> Data {
+    __swift_bridge__decompressLz4(RustVec<UInt8>(ptr: __swift_bridge__$some_function(arg)))
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+#include <stdint.h>
+void* __swift_bridge__$some_function(uint32_t arg);
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_compress_vec_u8() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a Rust function that returns a `String` can compress it before returning it to
+/// Swift.
+mod extern_rust_fn_compress_string {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(compress = "lz4")]
+                    fn some_function () -> String;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function() -> *mut Vec<u8> {
+                swift_bridge::compression_support::lz4_compress(
+                    AsRef::<[u8]>::as_ref(&super::some_function())
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function() -> String {
+    String(decoding: __swift_bridge__decompressLz4(RustVec<UInt8>(ptr: __swift_bridge__$some_function())), as: UTF8.self)
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+void* __swift_bridge__$some_function(void);
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_compress_string() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -0,0 +1,81 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that constants are defined in Rust, Swift and the C header.
+mod constants {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                /// The size of the read buffer.
+                const BUFFER_SIZE: usize = 4096;
+                const OFFSET: i32 = -1;
+                const SCALE: f32 = 2.;
+                const ENABLED: bool = true;
+                const PROTOCOL_NAME: &'static str = "my-protocol \"v1\"";
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[doc = r" The size of the read buffer."]
+                pub const BUFFER_SIZE: usize = 4096;
+            },
+            quote! {
+                pub const OFFSET: i32 = -1;
+            },
+            quote! {
+                pub const SCALE: f32 = 2.;
+            },
+            quote! {
+                pub const ENABLED: bool = true;
+            },
+            quote! {
+                pub const PROTOCOL_NAME: &'static str = "my-protocol \"v1\"";
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public let BUFFER_SIZE: UInt = 4096
+public let OFFSET: Int32 = -1
+public let SCALE: Float = 2.0
+public let ENABLED: Bool = true
+public let PROTOCOL_NAME: String = "my-protocol \"v1\""
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+#include <stdint.h>
+#include <stdbool.h>
+"#,
+            r#"
+static const uintptr_t __swift_bridge__$BUFFER_SIZE = 4096ull;
+static const int32_t __swift_bridge__$OFFSET = -1;
+static const float __swift_bridge__$SCALE = 2.f;
+static const bool __swift_bridge__$ENABLED = true;
+static const char* const __swift_bridge__$PROTOCOL_NAME = "my-protocol \"v1\"";
+"#,
+        ])
+    }
+
+    #[test]
+    fn constants() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
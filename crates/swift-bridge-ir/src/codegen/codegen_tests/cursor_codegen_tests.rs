@@ -0,0 +1,89 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we can return a cursor of shared structs from a Rust function, and that we
+/// generate the functions that Swift uses to read the cursor's batches of rows.
+mod extern_rust_fn_return_cursor {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                #[swift_bridge(swift_repr = "struct")]
+                struct Song {
+                    id: i64
+                }
+
+                extern "Rust" {
+                    fn query(sql: &str) -> Cursor<Song>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$query"]
+                pub extern "C" fn __swift_bridge__query(
+                    sql: swift_bridge::string::RustStr
+                ) -> *mut swift_bridge::cursor::Cursor<Song> {
+                    Box::into_raw(Box::new(super::query(sql.to_str())))
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$Song$_cursor_next_batch"]
+                pub extern "C" fn __swift_bridge__Song__cursor_next_batch(
+                    this: *mut swift_bridge::cursor::Cursor<Song>
+                ) -> swift_bridge::cursor::FfiCursorBatch {
+                    let rows: Vec<_> = unsafe { &mut *this }
+                        .next_batch()
+                        .into_iter()
+                        .map(|row| row.into_ffi_repr())
+                        .collect();
+                    swift_bridge::cursor::FfiCursorBatch::new(rows)
+                }
+
+                #[export_name = "__swift_bridge__$Song$_cursor_free"]
+                pub extern "C" fn __swift_bridge__Song__cursor_free(
+                    this: *mut swift_bridge::cursor::Cursor<Song>
+                ) {
+                    drop(unsafe { Box::from_raw(this) });
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func query<GenericToRustStr: ToRustStr>(_ sql: GenericToRustStr) -> RustCursor<Song> {
+    return sql.toRustStr({ sqlAsRustStr in
+        RustCursor<Song>(ptr: __swift_bridge__$query(sqlAsRustStr), nextBatch: { RustCursor<Song>.readBatch(__swift_bridge__$Song$_cursor_next_batch($0), { (row: __swift_bridge__$Song) in row.intoSwiftRepr() }) }, free: { __swift_bridge__$Song$_cursor_free($0) })
+    })
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __private__CursorBatch __swift_bridge__$Song$_cursor_next_batch(void* self);
+void __swift_bridge__$Song$_cursor_free(void* self);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_return_cursor() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
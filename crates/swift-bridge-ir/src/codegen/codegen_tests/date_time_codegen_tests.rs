@@ -0,0 +1,134 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we can take and return a chrono `DateTime<Utc>` from a Rust function.
+mod extern_rust_fn_chrono_date_time_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    fn some_function (arg: DateTime<Utc>) -> chrono::DateTime<chrono::Utc>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::system_time::FfiSystemTime
+            ) -> swift_bridge::system_time::FfiSystemTime {
+                swift_bridge::system_time::FfiSystemTime::from(
+                    super::some_function(
+                        <swift_bridge::chrono::DateTime<swift_bridge::chrono::Utc> >::from(arg)
+                    )
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+import Foundation
+"#,
+            r#"
+func some_function(_ arg: Date) -> Date {
+    __swift_bridge__$some_function(__private__SystemTime(arg)).toDate()
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+struct __private__SystemTime __swift_bridge__$some_function(struct __private__SystemTime arg);
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_chrono_date_time_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we can pass an `Option<OffsetDateTime>` to and from a Swift function.
+mod extern_swift_fn_option_offset_date_time_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Swift" {
+                    fn some_function (arg: Option<OffsetDateTime>) -> time::OffsetDateTime;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(
+                    arg: Option<swift_bridge::time::OffsetDateTime>
+                ) -> swift_bridge::time::OffsetDateTime {
+                    <swift_bridge::time::OffsetDateTime>::from(unsafe {
+                        __swift_bridge__some_function(
+                            if let Some(val) = arg {
+                                swift_bridge::system_time::OptionSystemTime {
+                                    val: swift_bridge::system_time::FfiSystemTime::from(val),
+                                    is_some: true
+                                }
+                            } else {
+                                swift_bridge::system_time::OptionSystemTime {
+                                    val: swift_bridge::system_time::FfiSystemTime { secs: 0, nanos: 0 },
+                                    is_some: false
+                                }
+                            }
+                        )
+                    })
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(
+                    arg: swift_bridge::system_time::OptionSystemTime
+                ) -> swift_bridge::system_time::FfiSystemTime;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: __private__OptionSystemTime) -> __private__SystemTime {
+    __private__SystemTime(some_function(arg: arg.intoSwiftRepr()))
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_option_offset_date_time_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
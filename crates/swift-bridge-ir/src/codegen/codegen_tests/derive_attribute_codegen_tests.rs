@@ -66,3 +66,50 @@ void* __swift_bridge__$SomeEnum$Debug(__swift_bridge__$SomeEnum this);
         .test();
     }
 }
+
+/// Verify that we derive Clone, PartialEq and Debug on an enum that has variants that carry data,
+/// without exposing the Debug impl to Swift.
+mod derive_data_carrying_enum {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[derive(Clone, PartialEq, Debug)]
+                enum Shape {
+                    Circle { radius: f32 },
+                    Rect { w: f32, h: f32 },
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsManyAndDoesNotContainMany {
+            contains: vec![quote! {
+                #[derive(Clone, PartialEq, ::std::fmt::Debug)]
+                pub enum Shape {
+                    Circle { radius: f32 },
+                    Rect { w: f32, h: f32 }
+                }
+            }],
+            does_not_contain: vec![quote! {
+                __swift_bridge__Shape_Debug
+            }],
+        }
+    }
+
+    #[test]
+    fn derive_data_carrying_enum() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: ExpectedSwiftCode::DoesNotContainAfterTrim(
+                "CustomDebugStringConvertible",
+            ),
+            expected_c_header: ExpectedCHeader::DoesNotContainAfterTrim("$Debug"),
+        }
+        .test();
+    }
+}
@@ -93,3 +93,376 @@ mod derive_clone_struct {
         .test();
     }
 }
+
+/// Verify that `#[swift_bridge(derive(Equatable))]` derives `PartialEq` in Rust and conforms to
+/// `Equatable` in Swift.
+mod derive_equatable_struct {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", derive(Equatable))]
+                struct SomeStruct {
+                    field: u8,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![quote! {
+            #[derive(PartialEq)]
+            pub struct SomeStruct {
+                pub field: u8
+            }
+        }])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeStruct: Equatable {}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn generates_struct() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that `#[swift_bridge(derive(Hashable))]` derives `PartialEq`, `Eq` and `Hash` in Rust
+/// and conforms to `Hashable` in Swift.
+mod derive_hashable_struct {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", derive(Equatable, Hashable))]
+                #[derive(Clone)]
+                struct SomeStruct {
+                    field: u8,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![quote! {
+            #[derive(Clone, PartialEq, Eq, Hash)]
+            pub struct SomeStruct {
+                pub field: u8
+            }
+        }])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeStruct: Hashable {}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn generates_struct() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a generic struct only conforms to `Hashable` when its generics do.
+mod derive_hashable_generic_struct {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", instantiate(u32, u64), derive(Hashable))]
+                struct Pair<T> {
+                    first: T,
+                    second: T,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::SkipTest
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension Pair: Hashable where T: Hashable {}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn generates_conditional_conformance() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that `#[swift_bridge(derive(Codable))]` conforms to `Codable` in Swift, using the Rust
+/// field names as the coding keys.
+mod derive_codable_struct {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", derive(Codable))]
+                struct SomeStruct {
+                    field: u8,
+                    #[swift_bridge(swift_name = "isVisible")]
+                    is_visible: bool,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![quote! {
+            pub struct SomeStruct {
+                pub field: u8,
+                pub is_visible: bool
+            }
+        }])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeStruct: Codable {
+    enum CodingKeys: String, CodingKey {
+        case field
+        case isVisible = "is_visible"
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn generates_struct() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a tuple struct can conform to `Codable`, and that a generic struct only conforms
+/// when its generics do.
+mod derive_codable_tuple_and_generic_struct {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", derive(Codable))]
+                struct SomeTupleStruct(u8);
+
+                #[swift_bridge(swift_repr = "struct", instantiate(u32), derive(Equatable, Codable))]
+                struct Wrapper<T> {
+                    value: T,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::SkipTest
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+extension SomeTupleStruct: Codable {}
+"#,
+            r#"
+extension Wrapper: Equatable where T: Equatable {}
+extension Wrapper: Codable where T: Codable {
+    enum CodingKeys: String, CodingKey {
+        case value
+    }
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn generates_conformances() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a `Codable` struct that derives serde's `Serialize` and `Deserialize` and uses the
+/// `serde_json_round_trip` attribute gets a `serdeJsonRoundTrip` function that encodes with Swift,
+/// then decodes and encodes with serde.
+mod derive_codable_and_serde_struct {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[derive(serde::Serialize, serde::Deserialize)]
+                #[swift_bridge(swift_repr = "struct", derive(Codable), serde_json_round_trip)]
+                struct SomeStruct {
+                    field: u8,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[derive(serde::Serialize, serde::Deserialize)]
+                pub struct SomeStruct {
+                    pub field: u8
+                }
+            },
+            quote! {
+                #[doc(hidden)]
+                #[export_name = "__swift_bridge__$SomeStruct$_serde_json_round_trip"]
+                pub extern "C" fn __swift_bridge__SomeStruct__serde_json_round_trip(
+                    json: *mut swift_bridge::string::RustString
+                ) -> swift_bridge::result::ResultPtrAndPtr {
+                    swift_bridge::__serde_json_round_trip!(SomeStruct, json)
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+import Foundation
+"#,
+            r#"
+extension SomeStruct {
+    public static func serdeJsonRoundTrip(_ value: SomeStruct) throws -> SomeStruct {
+        let json = String(data: try JSONEncoder().encode(value), encoding: .utf8)!
+        let result = __swift_bridge__$SomeStruct$_serde_json_round_trip({ let rustString = json.intoRustString(); rustString.isOwned = false; return rustString.ptr }())
+        let output = RustString(ptr: result.ok_or_err!).toString()
+        guard result.is_ok else {
+            throw DecodingError.dataCorrupted(DecodingError.Context(codingPath: [], debugDescription: output))
+        }
+        return try JSONDecoder().decode(SomeStruct.self, from: output.data(using: .utf8)!)
+    }
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __private__ResultPtrAndPtr __swift_bridge__$SomeStruct$_serde_json_round_trip(void* json);
+"#,
+        )
+    }
+
+    #[test]
+    fn generates_serde_json_round_trip() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a `Codable` struct that derives serde's `Serialize` and `Deserialize` does not get
+/// a `serdeJsonRoundTrip` function unless it uses the `serde_json_round_trip` attribute.
+mod derive_codable_and_serde_struct_without_round_trip {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[derive(serde::Serialize, serde::Deserialize)]
+                #[swift_bridge(swift_repr = "struct", derive(Codable))]
+                struct SomeStruct {
+                    field: u8,
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn does_not_generate_serde_json_round_trip() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::DoesNotContain(quote! {
+                #[export_name = "__swift_bridge__$SomeStruct$_serde_json_round_trip"]
+            }),
+            expected_swift_code: ExpectedSwiftCode::DoesNotContainAfterTrim("serdeJsonRoundTrip"),
+            expected_c_header: ExpectedCHeader::DoesNotContainAfterTrim(
+                "__swift_bridge__$SomeStruct$_serde_json_round_trip",
+            ),
+        }
+        .test();
+    }
+}
@@ -0,0 +1,189 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we can take and return a `Duration` from a Rust function.
+mod extern_rust_fn_duration_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    fn some_function (arg: Duration) -> std::time::Duration;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::duration::FfiDuration
+            ) -> swift_bridge::duration::FfiDuration {
+                swift_bridge::duration::FfiDuration::from(
+                    super::some_function(std::time::Duration::from(arg))
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+func some_function(_ arg: TimeInterval) -> TimeInterval {
+    __swift_bridge__$some_function(__private__Duration(arg)).toTimeInterval()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+struct __private__Duration __swift_bridge__$some_function(struct __private__Duration arg);
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_duration_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we can pass a `Duration` to and from a Swift function.
+mod extern_swift_fn_duration_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Swift" {
+                    fn some_function (arg: Duration) -> Duration;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(arg: std::time::Duration) -> std::time::Duration {
+                    std::time::Duration::from(unsafe {
+                        __swift_bridge__some_function(swift_bridge::duration::FfiDuration::from(arg))
+                    })
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(
+                    arg: swift_bridge::duration::FfiDuration
+                ) -> swift_bridge::duration::FfiDuration;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: __private__Duration) -> __private__Duration {
+    __private__Duration(some_function(arg: arg.toTimeInterval()))
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_duration_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we can take and return an `Option<Duration>` from a Rust function.
+mod extern_rust_fn_option_duration_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    fn some_function (arg: Option<Duration>) -> Option<Duration>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::duration::OptionDuration
+            ) -> swift_bridge::duration::OptionDuration {
+                if let Some(val) = super::some_function(
+                    {
+                        let val = arg;
+                        if val.is_some {
+                            Some(std::time::Duration::from(val.val))
+                        } else {
+                            None
+                        }
+                    }
+                ) {
+                    swift_bridge::duration::OptionDuration {
+                        val: swift_bridge::duration::FfiDuration::from(val),
+                        is_some: true
+                    }
+                } else {
+                    swift_bridge::duration::OptionDuration {
+                        val: swift_bridge::duration::FfiDuration { secs: 0, nanos: 0 },
+                        is_some: false
+                    }
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+func some_function(_ arg: Optional<TimeInterval>) -> Optional<TimeInterval> {
+    __swift_bridge__$some_function(__private__OptionDuration(arg)).intoSwiftRepr()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __private__OptionDuration __swift_bridge__$some_function(struct __private__OptionDuration arg);
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_option_duration_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -229,3 +229,62 @@ struct __swift_bridge__$SomeType __swift_bridge__$some_function(void);
         .test();
     }
 }
+
+/// Verify that a method that returns a reference to an opaque Rust type that borrows from `self`
+/// keeps `self` alive for as long as the returned reference is alive.
+mod test_extern_rust_method_ref_opaque_rust_type_borrowed_from_self {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type Camera;
+                    type Scene;
+
+                    #[swift_bridge(get(&camera))]
+                    fn camera(self: &Scene) -> &Camera;
+                    #[swift_bridge(get(&mut camera))]
+                    fn camera_mut(self: &mut Scene) -> &mut Camera;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$Scene$camera"]
+            pub extern "C" fn __swift_bridge__Scene_camera(
+                this: *mut super::Scene
+            ) -> *const super::Camera {
+                &(unsafe { &*this }).camera as *const super::Camera
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+    public func camera() -> CameraRef {
+        { let val = CameraRef(ptr: __swift_bridge__$Scene$camera(ptr)); val.__swift_bridge__parent = self; return val }()
+    }
+"#,
+            r#"
+    public func camera_mut() -> CameraRefMut {
+        { let val = CameraRefMut(ptr: __swift_bridge__$Scene$camera_mut(ptr)); val.__swift_bridge__parent = self; return val }()
+    }
+"#,
+        ])
+    }
+
+    #[test]
+    fn test_extern_rust_method_ref_opaque_rust_type_borrowed_from_self() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
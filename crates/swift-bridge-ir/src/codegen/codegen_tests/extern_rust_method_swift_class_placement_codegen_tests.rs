@@ -50,10 +50,12 @@ public class SomeType: SomeTypeRefMut {
     }
 }
 extension SomeType {
+    /// Consumes `self`. Ownership moves to Rust, so this instance must not be used after this call.
     public func a() {
         __swift_bridge__$SomeType$a({isOwned = false; return ptr;}())
     }
 
+    /// Consumes `self`. Ownership moves to Rust, so this instance must not be used after this call.
     public func b() {
         __swift_bridge__$SomeType$b({isOwned = false; return ptr;}())
     }
@@ -74,6 +76,7 @@ extension SomeTypeRefMut {
 }
 public class SomeTypeRef {
     var ptr: UnsafeMutableRawPointer
+    var __swift_bridge__parent: AnyObject?
 
     public init(ptr: UnsafeMutableRawPointer) {
         self.ptr = ptr
@@ -0,0 +1,188 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that an initializer that returns a `Result` becomes a throwing Swift initializer.
+mod throwing_initializer {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(init)]
+                    fn new(value: u8) -> Result<SomeType, String>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeType$new"]
+            pub extern "C" fn __swift_bridge__SomeType_new(
+                value: u8
+            ) -> swift_bridge::result::ResultPtrAndPtr {
+                match super::SomeType::new(value) {
+                    Ok(ok) => {
+                        swift_bridge::result::ResultPtrAndPtr {
+                            is_ok: true,
+                            ok_or_err: Box::into_raw(Box::new({
+                                let val: super::SomeType = ok;
+                                val
+                            })) as *mut super::SomeType as *mut std::ffi::c_void
+                        }
+                    }
+                    Err(err) => {
+                        swift_bridge::result::ResultPtrAndPtr {
+                            is_ok: false,
+                            ok_or_err: swift_bridge::string::RustString(err).box_into_raw() as *mut std::ffi::c_void
+                        }
+                    }
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeType {
+    public convenience init(_ value: UInt8) throws {
+        let val: SomeType = try { let val = __swift_bridge__$SomeType$new(value); if val.is_ok { return SomeType(ptr: val.ok_or_err!) } else { throw RustString(ptr: val.ok_or_err!) } }()
+        val.isOwned = false
+        self.init(ptr: val.ptr)
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            "struct __private__ResultPtrAndPtr __swift_bridge__$SomeType$new(uint8_t value);",
+        )
+    }
+
+    #[test]
+    fn throwing_initializer() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that an initializer that returns an `Option` becomes a failable Swift initializer.
+mod optional_initializer {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(init)]
+                    fn new(value: u8) -> Option<SomeType>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeType$new"]
+            pub extern "C" fn __swift_bridge__SomeType_new(value: u8) -> *mut super::SomeType {
+                if let Some(val) = super::SomeType::new(value) {
+                    Box::into_raw(Box::new(val))
+                } else {
+                    std::ptr::null_mut()
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeType {
+    public convenience init?(_ value: UInt8) {
+        guard let val: SomeType = { let val = __swift_bridge__$SomeType$new(value); if val != nil { return SomeType(ptr: val!) } else { return nil } }() else { return nil }
+        val.isOwned = false
+        self.init(ptr: val.ptr)
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim("void* __swift_bridge__$SomeType$new(uint8_t value);")
+    }
+
+    #[test]
+    fn optional_initializer() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that an initializer of a `#[swift_bridge(Copy(...))]` type can return an `Option`.
+mod optional_copy_type_initializer {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Copy(4))]
+                    type SomeType;
+
+                    #[swift_bridge(init)]
+                    fn new_if_even(value: u8) -> Option<SomeType>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::SkipTest
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+    public init?(_ value: UInt8) {
+        guard let val: SomeType = { let val = __swift_bridge__$SomeType$new_if_even(value); if val.is_some { return SomeType(bytes: val.val) } else { return nil } }() else { return nil }
+        self = val
+    }
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn optional_copy_type_initializer() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -221,6 +221,7 @@ mod protocol_identifiable {
             r#"
 public class SomeTypeRef {
     var ptr: UnsafeMutableRawPointer
+    var __swift_bridge__parent: AnyObject?
 
     public init(ptr: UnsafeMutableRawPointer) {
         self.ptr = ptr
@@ -234,6 +235,7 @@ extension SomeTypeRef: Identifiable {
             r#"
 public class AnotherTypeRef {
     var ptr: UnsafeMutableRawPointer
+    var __swift_bridge__parent: AnyObject?
 
     public init(ptr: UnsafeMutableRawPointer) {
         self.ptr = ptr
@@ -259,6 +261,159 @@ extension AnotherTypeRef: Identifiable {}"#,
     }
 }
 
+/// Verify that `#[swift_bridge(Identifiable = "...")]` on a type uses the named method as the
+/// Identifiable protocol's `id`.
+mod type_attribute_identifiable {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Identifiable = "user_id")]
+                    type User;
+                    #[swift_bridge(Identifiable = "id")]
+                    type Post;
+
+                    #[swift_bridge(swift_name = "userId")]
+                    fn user_id(self: &User) -> u64;
+                    fn name(self: &User) -> String;
+
+                    fn id(self: &Post) -> u32;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::SkipTest
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+extension UserRef: Identifiable {
+    public var id: UInt64 {
+        return self.userId()
+    }
+}"#,
+            r#"
+extension PostRef: Identifiable {}"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn type_attribute_identifiable() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate a `Store` class for a type that has `Store(state)` and
+/// `Store(dispatch)` methods.
+mod store {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct CounterState {
+                    count: u32,
+                }
+
+                enum CounterAction {
+                    Increment,
+                }
+
+                extern "Rust" {
+                    type Counter;
+                    type NotAStore;
+
+                    #[swift_bridge(Store(state))]
+                    fn state(self: &Counter) -> CounterState;
+
+                    #[swift_bridge(Store(dispatch), swift_name = "send")]
+                    fn dispatch(
+                        self: &mut Counter,
+                        #[swift_bridge(label = "action")] action: CounterAction,
+                    );
+
+                    #[swift_bridge(Store(state))]
+                    fn snapshot(self: &NotAStore) -> CounterState;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::SkipTest
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+import Combine
+public class CounterStore: ObservableObject {
+    public let inner: Counter
+    @Published public private(set) var state: CounterState
+
+    public init(_ inner: Counter) {
+        self.inner = inner
+        self.state = inner.state()
+    }
+
+    public func dispatch(_ action: CounterAction) {
+        inner.send(action: action)
+        self.state = inner.state()
+    }
+
+    public func refresh() {
+        self.state = inner.state()
+    }
+}"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn store() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+
+    /// Verify that we only generate a `Store` class when both methods are present.
+    #[test]
+    fn no_store_without_dispatch_method() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: ExpectedSwiftCode::DoesNotContainAfterTrim("NotAStoreStore"),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
 /// Verify that we can use the get attribute
 mod get {
     use super::*;
@@ -49,6 +49,7 @@ public class SomeTypeRefMut<A>: SomeTypeRef<A> {
 }
 public class SomeTypeRef<A> {
     var ptr: UnsafeMutableRawPointer
+    var __swift_bridge__parent: AnyObject?
 
     public init(ptr: UnsafeMutableRawPointer) {
         self.ptr = ptr
@@ -513,3 +514,260 @@ mod generic_opaque_rust_type_inner_opaque_ty {
         .test();
     }
 }
+
+/// Verify that a concrete generic opaque Rust type without a `declare_generic` declaration gets
+/// its own Swift class for each instantiation.
+mod generic_opaque_rust_type_own_swift_class {
+    use super::*;
+
+    fn bridge_module() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type MyCache<u32>;
+                }
+                extern "Rust" {
+                    type MyCache<String>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$MyCache$u32$_free"]
+                pub extern "C" fn __swift_bridge__MyCache_u32__free (
+                    this: *mut super::MyCache<u32>
+                ) {
+                    let this = unsafe { Box::from_raw(this) };
+                    drop(this);
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$MyCache$String$_free"]
+                pub extern "C" fn __swift_bridge__MyCache_String__free (
+                    this: *mut super::MyCache<String>
+                ) {
+                    let this = unsafe { Box::from_raw(this) };
+                    drop(this);
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public class MyCacheU32: MyCacheU32RefMut {
+    var isOwned: Bool = true
+
+    public override init(ptr: UnsafeMutableRawPointer) {
+        super.init(ptr: ptr)
+    }
+
+    deinit {
+        if isOwned {
+            __swift_bridge__$MyCache$u32$_free(ptr)
+        }
+    }
+}
+public class MyCacheU32RefMut: MyCacheU32Ref {
+"#,
+            r#"
+public class MyCacheString: MyCacheStringRefMut {
+    var isOwned: Bool = true
+
+    public override init(ptr: UnsafeMutableRawPointer) {
+        super.init(ptr: ptr)
+    }
+
+    deinit {
+        if isOwned {
+            __swift_bridge__$MyCache$String$_free(ptr)
+        }
+    }
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            "void __swift_bridge__$MyCache$u32$_free(void* self);",
+            "void __swift_bridge__$MyCache$String$_free(void* self);",
+        ])
+    }
+
+    #[test]
+    fn generic_opaque_rust_type_own_swift_class() {
+        CodegenTest {
+            bridge_module: bridge_module().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that the methods of a generic opaque Rust type that has its own Swift class are
+/// exposed using symbols that are distinct for each instantiation.
+mod generic_opaque_rust_type_methods {
+    use super::*;
+
+    fn bridge_module() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type MyCache<u32>;
+
+                    #[swift_bridge(init)]
+                    fn new() -> MyCache<u32>;
+                    fn insert(&mut self, key: u32);
+                    fn len(self: &MyCache<u32>) -> usize;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$MyCache$u32$new"]
+                pub extern "C" fn __swift_bridge__MyCache_u32_new() -> *mut super::MyCache<u32> {
+                    Box::into_raw(Box::new({
+                        let val: super::MyCache<u32> = super::MyCache::<u32>::new();
+                        val
+                    })) as *mut super::MyCache<u32>
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$MyCache$u32$insert"]
+                pub extern "C" fn __swift_bridge__MyCache_u32_insert(
+                    this: *mut super::MyCache<u32>,
+                    key: u32
+                ) {
+                    (unsafe { &mut *this }).insert(key)
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$MyCache$u32$len"]
+                pub extern "C" fn __swift_bridge__MyCache_u32_len(
+                    this: *mut super::MyCache<u32>
+                ) -> usize {
+                    (unsafe { &*this }).len()
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+extension MyCacheU32 {
+    public convenience init() {
+        self.init(ptr: __swift_bridge__$MyCache$u32$new())
+    }
+}
+"#,
+            r#"
+extension MyCacheU32RefMut {
+    public func insert(_ key: UInt32) {
+        __swift_bridge__$MyCache$u32$insert(ptr, key)
+    }
+}
+"#,
+            r#"
+extension MyCacheU32Ref {
+    public func len() -> UInt {
+        __swift_bridge__$MyCache$u32$len(ptr)
+    }
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            "void* __swift_bridge__$MyCache$u32$new(void);",
+            "void __swift_bridge__$MyCache$u32$insert(void* self, uint32_t key);",
+            "uintptr_t __swift_bridge__$MyCache$u32$len(void* self);",
+        ])
+    }
+
+    #[test]
+    fn generic_opaque_rust_type_methods() {
+        CodegenTest {
+            bridge_module: bridge_module().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that functions use the Swift class of a generic opaque Rust type that has its own Swift
+/// class.
+mod generic_opaque_rust_type_own_swift_class_args_and_returns {
+    use super::*;
+
+    fn bridge_module() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type MyCache<u32>;
+
+                    fn merge(cache: MyCache<u32>, other: &MyCache<u32>) -> Option<MyCache<u32>>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$merge"]
+            pub extern "C" fn __swift_bridge__merge(
+                cache: *mut super::MyCache<u32>,
+                other: *const super::MyCache<u32>
+            ) -> *mut super::MyCache<u32> {
+                if let Some(val) = super::merge(unsafe { *Box::from_raw(cache) }, unsafe { &*other }) {
+                    Box::into_raw(Box::new(val))
+                } else {
+                    std::ptr::null_mut()
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func merge(_ cache: MyCacheU32, _ other: MyCacheU32Ref) -> Optional<MyCacheU32> {
+    { let val = __swift_bridge__$merge({cache.isOwned = false; return cache.ptr;}(), other.ptr); if val != nil { return MyCacheU32(ptr: val!) } else { return nil } }()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            "void* __swift_bridge__$merge(void* cache, void* other);",
+        )
+    }
+
+    #[test]
+    fn generic_opaque_rust_type_own_swift_class_args_and_returns() {
+        CodegenTest {
+            bridge_module: bridge_module().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
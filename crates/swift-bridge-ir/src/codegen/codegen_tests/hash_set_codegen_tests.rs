@@ -0,0 +1,239 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we can take and return a HashSet of primitives from a Rust function.
+mod extern_rust_fn_hash_set_of_primitive {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    fn some_function (arg: HashSet<u64>) -> HashSet<u64>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: *mut std::collections::HashSet<u64>
+            ) -> *mut std::collections::HashSet<u64> {
+                Box::into_raw(Box::new(super::some_function(unsafe { * Box::from_raw(arg) })))
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+func some_function(_ arg: RustHashSet<UInt64>) -> RustHashSet<UInt64> {
+    RustHashSet(ptr: __swift_bridge__$some_function({ let val = arg; val.isOwned = false; return val.ptr }()))
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+void* __swift_bridge__$some_function(void* arg);
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_hash_set_of_primitive() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a HashSet<String> is exposed to Swift as a RustHashSet<RustString>.
+mod extern_rust_fn_hash_set_of_string {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    fn some_function (arg: HashSet<String>) -> HashSet<String>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: *mut std::collections::HashSet<String>
+            ) -> *mut std::collections::HashSet<String> {
+                Box::into_raw(Box::new(super::some_function(unsafe { * Box::from_raw(arg) })))
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+func some_function(_ arg: RustHashSet<RustString>) -> RustHashSet<RustString> {
+    RustHashSet(ptr: __swift_bridge__$some_function({ let val = arg; val.isOwned = false; return val.ptr }()))
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+void* __swift_bridge__$some_function(void* arg);
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_hash_set_of_string() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we can take and return an Option<HashSet<T>> from a Rust function.
+mod extern_rust_fn_option_hash_set {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    fn some_function (arg: Option<HashSet<u64>>) -> Option<HashSet<u64>>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: *mut std::collections::HashSet<u64>
+            ) -> *mut std::collections::HashSet<u64> {
+                if let Some(val) = super::some_function({
+                    let val = arg;
+                    if val.is_null() {
+                        None
+                    } else {
+                        Some(unsafe { * Box::from_raw(val) })
+                    }
+                }) {
+                    Box::into_raw(Box::new(val))
+                } else {
+                    std::ptr::null_mut()
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: Optional<RustHashSet<UInt64>>) -> Optional<RustHashSet<UInt64>> {
+    { let val = __swift_bridge__$some_function({ if let val = arg { val.isOwned = false; return val.ptr } else { return nil } }()); if val != nil { return RustHashSet(ptr: val!) } else { return nil } }()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+void* __swift_bridge__$some_function(void* arg);
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_option_hash_set() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we can take and return an Option<HashSet<T>> from a Swift function.
+mod extern_swift_fn_option_hash_set {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Swift" {
+                    fn some_function (arg: Option<HashSet<u64>>) -> Option<HashSet<u64>>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub fn some_function(
+                arg: Option<std::collections::HashSet<u64> >
+            ) -> Option<std::collections::HashSet<u64> > {
+                {
+                    let val = unsafe {
+                        __swift_bridge__some_function(if let Some(val) = arg {
+                            Box::into_raw(Box::new(val))
+                        } else {
+                            std::ptr::null_mut()
+                        })
+                    };
+                    if val.is_null() {
+                        None
+                    } else {
+                        Some(unsafe { * Box::from_raw(val) })
+                    }
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: UnsafeMutableRawPointer?) -> UnsafeMutableRawPointer? {
+    { if let val = some_function(arg: { let val = arg; if val != nil { return RustHashSet(ptr: val!) } else { return nil } }()) { val.isOwned = false; return val.ptr } else { return nil } }()
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_option_hash_set() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
@@ -0,0 +1,75 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that a module with the `hash_symbols` attribute uses the same hashed link names in its
+/// Rust, Swift and C code.
+mod hash_symbols_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge(hash_symbols)]
+            mod foo {
+                extern "Rust" {
+                    fn some_function (arg: u8) -> u8;
+                }
+
+                extern "Swift" {
+                    fn swift_function ();
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "sb_850784fe481877e5"]
+                pub extern "C" fn __swift_bridge__some_function(arg: u8) -> u8 {
+                    super::some_function(arg)
+                }
+            },
+            quote! {
+                #[link_name = "sb_bd927bee813f8048"]
+                fn __swift_bridge__swift_function();
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public func some_function(_ arg: UInt8) -> UInt8 {
+    __swift_bridge__$some_function(arg)
+}
+"#,
+            r#"
+@_cdecl("sb_bd927bee813f8048")
+func __swift_bridge__swift_function () {
+    swift_function()
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+#include <stdint.h>
+uint8_t __swift_bridge__$some_function(uint8_t arg) __SWIFT_BRIDGE_SYMBOL("sb_850784fe481877e5");
+    "#,
+        )
+    }
+
+    #[test]
+    fn hash_symbols_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -0,0 +1,194 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we can take and return a measurement from a Rust function.
+mod extern_rust_fn_measurement_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    fn some_function (arg: Length) -> swift_bridge::measurement::Temperature;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::measurement::FfiMeasurement
+            ) -> swift_bridge::measurement::FfiMeasurement {
+                swift_bridge::measurement::FfiMeasurement::from(
+                    super::some_function(swift_bridge::measurement::Length::from(arg))
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            "import Foundation",
+            r#"
+func some_function(_ arg: Measurement<UnitLength>) -> Measurement<UnitTemperature> {
+    __swift_bridge__$some_function(__private__Measurement(arg, __private__lengthUnits)).intoMeasurement(__private__temperatureUnits)
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+struct __private__Measurement __swift_bridge__$some_function(struct __private__Measurement arg);
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_measurement_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we can pass a measurement to and from a Swift function.
+mod extern_swift_fn_measurement_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Swift" {
+                    fn some_function (arg: Mass) -> Energy;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(
+                    arg: swift_bridge::measurement::Mass
+                ) -> swift_bridge::measurement::Energy {
+                    swift_bridge::measurement::Energy::from(unsafe {
+                        __swift_bridge__some_function(
+                            swift_bridge::measurement::FfiMeasurement::from(arg)
+                        )
+                    })
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(
+                    arg: swift_bridge::measurement::FfiMeasurement
+                ) -> swift_bridge::measurement::FfiMeasurement;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: __private__Measurement) -> __private__Measurement {
+    __private__Measurement(some_function(arg: arg.intoMeasurement(__private__massUnits)), __private__energyUnits)
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_measurement_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we can take and return an optional measurement from a Rust function.
+mod extern_rust_fn_option_measurement_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    fn some_function (arg: Option<Speed>) -> Option<Speed>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::measurement::OptionMeasurement
+            ) -> swift_bridge::measurement::OptionMeasurement {
+                if let Some(val) = super::some_function(
+                    {
+                        let val = arg;
+                        if val.is_some {
+                            Some(swift_bridge::measurement::Speed::from(val.val))
+                        } else {
+                            None
+                        }
+                    }
+                ) {
+                    swift_bridge::measurement::OptionMeasurement {
+                        val: swift_bridge::measurement::FfiMeasurement::from(val),
+                        is_some: true
+                    }
+                } else {
+                    swift_bridge::measurement::OptionMeasurement {
+                        val: swift_bridge::measurement::FfiMeasurement { value: 0., unit: 0 },
+                        is_some: false
+                    }
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+func some_function(_ arg: Optional<Measurement<UnitSpeed>>) -> Optional<Measurement<UnitSpeed>> {
+    __swift_bridge__$some_function(__private__OptionMeasurement(arg, __private__speedUnits)).intoSwiftRepr(__private__speedUnits)
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+struct __private__OptionMeasurement __swift_bridge__$some_function(struct __private__OptionMeasurement arg);
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_option_measurement_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -0,0 +1,113 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we export a table of the module's functions when the module has a
+/// `#[swift_bridge(module_table = "...")]` attribute.
+mod module_table {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge(module_table = "my_plugin")]
+            mod foo {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn some_function(arg: u8) -> u8;
+                    fn some_method(&self);
+                }
+
+                extern "Swift" {
+                    fn swift_function();
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$my_plugin$module_table"]
+            pub extern "C" fn __swift_bridge__my_plugin_module_table() -> swift_bridge::module_table::ModuleTable {
+                static ENTRIES: [swift_bridge::module_table::ModuleTableEntry; 3usize] = [
+                    swift_bridge::module_table::ModuleTableEntry {
+                        name: "__swift_bridge__$some_function\0".as_ptr() as *const std::os::raw::c_char,
+                        symbol: __swift_bridge__some_function as *const std::ffi::c_void
+                    },
+                    swift_bridge::module_table::ModuleTableEntry {
+                        name: "__swift_bridge__$SomeType$some_method\0".as_ptr() as *const std::os::raw::c_char,
+                        symbol: __swift_bridge__SomeType_some_method as *const std::ffi::c_void
+                    },
+                    swift_bridge::module_table::ModuleTableEntry {
+                        name: "__swift_bridge__$SomeType$_free\0".as_ptr() as *const std::os::raw::c_char,
+                        symbol: __swift_bridge__SomeType__free as *const std::ffi::c_void
+                    }
+                ];
+
+                swift_bridge::module_table::ModuleTable {
+                    entries: ENTRIES.as_ptr(),
+                    len: 3usize
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::SkipTest
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __private__ModuleTable __swift_bridge__$my_plugin$module_table(void);
+"#,
+        )
+    }
+
+    #[test]
+    fn module_table() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we do not export a module table by default.
+mod no_module_table {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    fn some_function();
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::DoesNotContain(quote! {
+            module_table
+        })
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::DoesNotContainAfterTrim("module_table")
+    }
+
+    #[test]
+    fn no_module_table() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: ExpectedSwiftCode::SkipTest,
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -0,0 +1,103 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that an extern "Rust" function can take a `&mut [T]` that Swift passes as an
+/// `UnsafeMutableBufferPointer<T>`.
+mod extern_rust_fn_mut_slice_arg {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    fn fill(buffer: &mut [u8]);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$fill"]
+            pub extern "C" fn __swift_bridge__fill(buffer: swift_bridge::FfiSlice<u8>) {
+                super::fill(buffer.as_mut_slice())
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func fill(_ buffer: UnsafeMutableBufferPointer<UInt8>) {
+    __swift_bridge__$fill(buffer.toFfiSlice())
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void __swift_bridge__$fill(struct __private__FfiSlice buffer);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_mut_slice_arg() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that an extern "Swift" function can take a `&mut [T]`, which Swift sees as an
+/// `UnsafeMutableBufferPointer<T>`.
+mod extern_swift_fn_mut_slice_arg {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Swift" {
+                    fn fill(buffer: &mut [f32]);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub fn fill(buffer: &mut [f32]) {
+                unsafe { __swift_bridge__fill(swift_bridge::FfiSlice::from_mut_slice(buffer)) }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$fill")
+func __swift_bridge__fill (_ buffer: __private__FfiSlice) {
+    fill(buffer: buffer.toUnsafeMutableBufferPointer(Float.self))
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_mut_slice_arg() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
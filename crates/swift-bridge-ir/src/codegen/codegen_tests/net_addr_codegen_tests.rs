@@ -0,0 +1,187 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we can take and return an `IpAddr` from a Rust function.
+mod extern_rust_fn_ip_addr_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    fn some_function (arg: IpAddr) -> std::net::IpAddr;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::net::FfiIpAddr
+            ) -> swift_bridge::net::FfiIpAddr {
+                swift_bridge::net::FfiIpAddr::from(
+                    super::some_function(std::net::IpAddr::from(arg))
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+func some_function(_ arg: RustIpAddr) -> RustIpAddr {
+    __swift_bridge__$some_function(__private__IpAddr(arg)).intoSwiftRepr()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+struct __private__IpAddr __swift_bridge__$some_function(struct __private__IpAddr arg);
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_ip_addr_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we can pass an `Option<SocketAddr>` to a Swift function.
+mod extern_swift_fn_option_socket_addr_arg {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Swift" {
+                    fn some_function (arg: Option<SocketAddr>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub fn some_function(arg: Option<std::net::SocketAddr>) {
+                unsafe {
+                    __swift_bridge__some_function(
+                        if let Some(val) = arg {
+                            swift_bridge::net::OptionSocketAddr {
+                                val: swift_bridge::net::FfiSocketAddr::from(val),
+                                is_some: true
+                            }
+                        } else {
+                            swift_bridge::net::OptionSocketAddr {
+                                val: swift_bridge::net::FfiSocketAddr {
+                                    ip: swift_bridge::net::FfiIpAddr { octets: [0; 16], is_v6: false },
+                                    port: 0,
+                                    flowinfo: 0,
+                                    scope_id: 0
+                                },
+                                is_some: false
+                            }
+                        }
+                    )
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: __private__OptionSocketAddr) {
+    some_function(arg: arg.intoSwiftRepr())
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_option_socket_addr_arg() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that a module annotated with `net_addrs_as_strings` passes its `IpAddr`s and
+/// `SocketAddr`s to and from Swift as strings.
+mod net_addrs_as_strings {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge(net_addrs_as_strings)]
+            mod foo {
+                extern "Rust" {
+                    fn some_function (arg: SocketAddr) -> IpAddr;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: *mut swift_bridge::string::RustString
+            ) -> *mut swift_bridge::string::RustString {
+                swift_bridge::string::RustString(
+                    super::some_function(
+                        <std::net::SocketAddr as std::str::FromStr>::from_str(
+                            &unsafe { Box::from_raw(arg).0 }
+                        ).expect("Swift passes a valid SocketAddr")
+                    ).to_string()
+                ).box_into_raw()
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+func some_function<GenericIntoRustString: IntoRustString>(_ arg: GenericIntoRustString) -> String {
+    RustString(ptr: __swift_bridge__$some_function({ let rustString = arg.intoRustString(); rustString.isOwned = false; return rustString.ptr }())).toString()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+void* __swift_bridge__$some_function(void* arg);
+    "#,
+        )
+    }
+
+    #[test]
+    fn net_addrs_as_strings() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
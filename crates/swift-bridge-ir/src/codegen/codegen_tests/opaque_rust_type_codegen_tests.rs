@@ -53,6 +53,7 @@ public class SomeTypeRefMut: SomeTypeRef {
 }
 public class SomeTypeRef {
     var ptr: UnsafeMutableRawPointer
+    var __swift_bridge__parent: AnyObject?
 
     public init(ptr: UnsafeMutableRawPointer) {
         self.ptr = ptr
@@ -82,6 +83,7 @@ void __swift_bridge__$SomeType$_free(void* self);
 }
 
 /// Test code generation for an extern "Rust" type that implements Hashable.
+/// Swift's `Hashable` inherits from `Equatable`, so we also generate the `Equatable` conformance.
 mod extern_rust_hashable_type {
     use super::*;
 
@@ -97,22 +99,33 @@ mod extern_rust_hashable_type {
     }
 
     fn expected_rust_tokens() -> ExpectedRustTokens {
-        ExpectedRustTokens::Contains(quote! {
-        #[export_name = "__swift_bridge__$HashableType$_hash"]
-        pub extern "C" fn __swift_bridge__HashableType__hash (
-            this: *const super::HashableType,
-        ) -> u64 {
-            use std::hash::{Hash, Hasher};
-            use std::collections::hash_map::DefaultHasher;
-            let mut s = DefaultHasher::new();
-            (unsafe {&*this}).hash(&mut s);
-            s.finish()
-        }
-        })
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$HashableType$_hash"]
+                pub extern "C" fn __swift_bridge__HashableType__hash (
+                    this: *const super::HashableType,
+                ) -> u64 {
+                    use std::hash::{Hash, Hasher};
+                    use std::collections::hash_map::DefaultHasher;
+                    let mut s = DefaultHasher::new();
+                    (unsafe {&*this}).hash(&mut s);
+                    s.finish()
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$HashableType$_partial_eq"]
+                pub extern "C" fn __swift_bridge__HashableType__partial_eq (
+                    lhs: *const super::HashableType,
+                    rhs: *const super::HashableType
+                ) -> bool {
+                    unsafe { &*lhs == &*rhs }
+                }
+            },
+        ])
     }
 
     fn expected_swift_code() -> ExpectedSwiftCode {
-        ExpectedSwiftCode::ContainsAfterTrim(
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
             r#"
 extension HashableTypeRef: Hashable{
     public func hash(into hasher: inout Hasher){
@@ -120,15 +133,23 @@ extension HashableTypeRef: Hashable{
     }
 }
 "#,
-        )
+            r#"
+extension HashableTypeRef: Equatable {
+    public static func == (lhs: HashableTypeRef, rhs: HashableTypeRef) -> Bool {
+        __swift_bridge__$HashableType$_partial_eq(rhs.ptr, lhs.ptr)
+    }
+}
+"#,
+        ])
     }
 
     fn expected_c_header() -> ExpectedCHeader {
         ExpectedCHeader::ContainsManyAfterTrim(vec![
             r#"
 uint64_t __swift_bridge__$HashableType$_hash(void* self);
-    "#,
+"#,
             r#"
+bool __swift_bridge__$HashableType$_partial_eq(void* lhs, void* rhs);
 "#,
         ])
     }
@@ -208,6 +229,511 @@ bool __swift_bridge__$EquatableType$_partial_eq(void* lhs, void* rhs);
     }
 }
 
+/// Test code generation for an extern "Rust" type that implements Comparable.
+/// Swift's `Comparable` inherits from `Equatable`, so we also generate the `Equatable` conformance.
+mod extern_rust_comparable_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Comparable)]
+                    type ComparableType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$ComparableType$_less_than"]
+                pub extern "C" fn __swift_bridge__ComparableType__less_than (
+                    lhs: *const super::ComparableType,
+                    rhs: *const super::ComparableType
+                ) -> bool {
+                    unsafe { &*lhs < &*rhs }
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$ComparableType$_partial_eq"]
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+extension ComparableTypeRef: Comparable {
+    public static func < (lhs: ComparableTypeRef, rhs: ComparableTypeRef) -> Bool {
+        __swift_bridge__$ComparableType$_less_than(lhs.ptr, rhs.ptr)
+    }
+}
+"#,
+            r#"
+extension ComparableTypeRef: Equatable {
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+bool __swift_bridge__$ComparableType$_partial_eq(void* lhs, void* rhs);
+bool __swift_bridge__$ComparableType$_less_than(void* lhs, void* rhs);
+"#,
+            r#"
+#include <stdbool.h>
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_comparable_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for an extern "Rust" type that Swift can duplicate using its `Clone`
+/// implementation.
+mod extern_rust_clone_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Clone)]
+                    type CloneType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$CloneType$_clone"]
+            pub extern "C" fn __swift_bridge__CloneType__clone (
+                this: *const super::CloneType,
+            ) -> *mut super::CloneType {
+                Box::into_raw(Box::new((unsafe { &*this }).clone()))
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension CloneTypeRef {
+    public func copy() -> CloneType {
+        CloneType(ptr: __swift_bridge__$CloneType$_clone(self.ptr))
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$CloneType$_clone(void* self);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_clone_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that the copy of an identity mapped type goes through the identity map.
+mod extern_rust_clone_identity_map_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Clone, identity_map)]
+                    type CloneType;
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension CloneTypeRef {
+    public func copy() -> CloneType {
+        CloneType.__swift_bridge__fromIdentityMap(ptr: __swift_bridge__$CloneType$_clone(self.ptr))
+    }
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_clone_identity_map_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Test code generation for an extern "Rust" type whose `Display` implementation is used as its
+/// Swift `description`.
+mod extern_rust_custom_string_convertible_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(CustomStringConvertible)]
+                    type DisplayType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$DisplayType$_description"]
+            pub extern "C" fn __swift_bridge__DisplayType__description (
+                this: *const super::DisplayType,
+            ) -> *mut swift_bridge::string::RustString {
+                swift_bridge::string::RustString(
+                    (unsafe { &*this }).to_string()
+                ).box_into_raw()
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension DisplayTypeRef: CustomStringConvertible {
+    public var description: String {
+        RustString(ptr: __swift_bridge__$DisplayType$_description(self.ptr)).toString()
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$DisplayType$_description(void* self);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_custom_string_convertible_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for an extern "Rust" type whose `Debug` implementation is used as its
+/// Swift `debugDescription`.
+mod extern_rust_custom_debug_string_convertible_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(CustomDebugStringConvertible)]
+                    type DebugType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$DebugType$_debug_description"]
+            pub extern "C" fn __swift_bridge__DebugType__debug_description (
+                this: *const super::DebugType,
+            ) -> *mut swift_bridge::string::RustString {
+                swift_bridge::string::RustString(
+                    format!("{:?}", unsafe { &*this })
+                ).box_into_raw()
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension DebugTypeRef: CustomDebugStringConvertible {
+    public var debugDescription: String {
+        RustString(ptr: __swift_bridge__$DebugType$_debug_description(self.ptr)).toString()
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$DebugType$_debug_description(void* self);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_custom_debug_string_convertible_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for an extern "Rust" type that Swift can throw, whose `Display`
+/// implementation is used as its Swift `localizedDescription`.
+mod extern_rust_error_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Error)]
+                    type ParseError;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$ParseError$_error_description"]
+            pub extern "C" fn __swift_bridge__ParseError__error_description (
+                this: *const super::ParseError,
+            ) -> *mut swift_bridge::string::RustString {
+                swift_bridge::string::RustString(
+                    (unsafe { &*this }).to_string()
+                ).box_into_raw()
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+import Foundation
+"#,
+            r#"
+extension ParseError: LocalizedError {
+    public var errorDescription: String? {
+        RustString(ptr: __swift_bridge__$ParseError$_error_description(self.ptr)).toString()
+    }
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$ParseError$_error_description(void* self);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_error_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for an extern "Rust" type that uses an identity map, so that Swift reuses
+/// the same class instance for the same Rust object.
+mod extern_rust_identity_map_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(identity_map)]
+                    type SomeType;
+
+                    #[swift_bridge(init)]
+                    fn new() -> SomeType;
+                    fn some_function() -> SomeType;
+                    fn some_ref_function() -> &'static SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeType$_object_identity"]
+            pub extern "C" fn __swift_bridge__SomeType__object_identity (
+                this: *const super::SomeType,
+            ) -> usize {
+                <super::SomeType as swift_bridge::identity::ObjectIdentity>::object_identity(unsafe { &*this })
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+    deinit {
+        if isOwned {
+            SomeType.__swift_bridge__identityMap.removeReleased(__swift_bridge__$SomeType$_object_identity(ptr))
+            __swift_bridge__$SomeType$_free(ptr)
+        }
+    }
+}
+extension SomeType {
+    static let __swift_bridge__identityMap = __private__IdentityMap<SomeType>()
+
+    static func __swift_bridge__fromIdentityMap(ptr: UnsafeMutableRawPointer) -> SomeType {
+        let (wrapper, isNew) = __swift_bridge__identityMap.wrapper(
+            for: __swift_bridge__$SomeType$_object_identity(ptr),
+            existingIsValid: { $0.isOwned },
+            makeWrapper: { SomeType(ptr: ptr) }
+        )
+        if !isNew {
+            __swift_bridge__$SomeType$_free(ptr)
+        }
+        return wrapper
+    }
+}
+"#,
+            r#"
+    public convenience init() {
+        self.init(ptr: __swift_bridge__$SomeType$new())
+        SomeType.__swift_bridge__identityMap.insert(__swift_bridge__$SomeType$_object_identity(self.ptr), self)
+    }
+"#,
+            r#"
+public func some_function() -> SomeType {
+    SomeType.__swift_bridge__fromIdentityMap(ptr: __swift_bridge__$some_function())
+}
+"#,
+            r#"
+public func some_ref_function() -> SomeTypeRef {
+    SomeTypeRef(ptr: __swift_bridge__$some_ref_function())
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+uintptr_t __swift_bridge__$SomeType$_object_identity(void* self);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_identity_map_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for an extern "Rust" type that must always be dropped on a specific
+/// dispatch queue.
+mod extern_rust_drop_queue_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(drop_queue = "DispatchQueue.main")]
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+import Dispatch
+public class SomeType: SomeTypeRefMut {
+    var isOwned: Bool = true
+
+    public override init(ptr: UnsafeMutableRawPointer) {
+        super.init(ptr: ptr)
+    }
+
+    deinit {
+        if isOwned {
+            let ptr = self.ptr
+            DispatchQueue.main.async {
+                __swift_bridge__$SomeType$_free(ptr)
+            }
+        }
+    }
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_drop_queue_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
 /// Test code generation for an extern "Rust" type that implements Copy.
 mod extern_rust_copy_type {
     use super::*;
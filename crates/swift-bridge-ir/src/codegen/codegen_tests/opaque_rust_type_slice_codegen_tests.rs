@@ -0,0 +1,114 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we can return a slice of opaque Rust types, which Swift sees as an array of
+/// non-owning references to the elements.
+mod extern_rust_fn_return_opaque_rust_type_slice {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    type SomeType;
+                    fn some_function() -> &'static [SomeType];
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function() -> swift_bridge::FfiSlice<*const std::ffi::c_void> {
+                swift_bridge::FfiSlice::from_opaque_slice(super::some_function())
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function() -> [SomeTypeRef] {
+    let slice = __swift_bridge__$some_function(); defer { __swift_bridge__$pointer_slice$free(slice) }; return slice.toUnsafeBufferPointer(UnsafeMutableRawPointer.self).map { SomeTypeRef(ptr: $0) };
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __private__FfiSlice __swift_bridge__$some_function(void);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_return_opaque_rust_type_slice() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we can pass a slice of opaque Rust types to Rust using the buffer of a `RustVec`.
+mod extern_rust_fn_opaque_rust_type_slice_arg {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    type SomeType;
+                    fn some_function(arg: &[SomeType]) -> usize;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::FfiSlice<*const std::ffi::c_void>
+            ) -> usize {
+                super::some_function(arg.as_opaque_slice::<super::SomeType>())
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: RustVec<SomeType>) -> UInt {
+    __swift_bridge__$some_function({ let vec = arg; return __private__FfiSlice(start: UnsafeMutableRawPointer(mutating: vec.as_ptr()), len: UInt(vec.len())) }())
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+uintptr_t __swift_bridge__$some_function(struct __private__FfiSlice arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_opaque_rust_type_slice_arg() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -162,10 +162,13 @@ mod extern_rust_fn_option_vector_primitive {
                 arg: *mut Vec<i32>
             ) -> *mut Vec<u32> {
                 if let Some(value) = super::some_function(
-                    if arg.is_null() {
-                        None
-                    } else {
-                        Some( unsafe { * Box::from_raw(arg) } )
+                    {
+                        let val = arg;
+                        if val.is_null() {
+                            None
+                        } else {
+                            Some( unsafe { * Box::from_raw(val) } )
+                        }
                     }
                 ) {
                     Box::into_raw(Box::new(value))
@@ -204,6 +207,131 @@ void* __swift_bridge__$some_function(void* arg);
     }
 }
 
+/// Test code generation for Swift function that accepts and returns an Option<Vec<T>> where T is
+/// a primitive.
+mod extern_swift_fn_option_vector_primitive {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function (arg: Option<Vec<u8>>) -> Option<Vec<u8>>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(arg: Option<Vec<u8>>) -> Option<Vec<u8>> {
+                    {
+                        let val = unsafe {
+                            __swift_bridge__some_function(
+                                if let Some(value) = arg {
+                                    Box::into_raw(Box::new(value))
+                                } else {
+                                    std::ptr::null_mut()
+                                }
+                            )
+                        };
+                        if val.is_null() {
+                            None
+                        } else {
+                            Some(unsafe { *Box::from_raw(val) })
+                        }
+                    }
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(arg: *mut Vec<u8>) -> *mut Vec<u8>;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: UnsafeMutableRawPointer?) -> UnsafeMutableRawPointer? {
+    { if let val = some_function(arg: { let val = arg; if val != nil { return RustVec(ptr: val!) } else { return nil } }()) { val.isOwned = false; return val.ptr } else { return nil } }()
+}
+"#,
+        )
+    }
+
+    const EXPECTED_C_HEADER: ExpectedCHeader = ExpectedCHeader::ExactAfterTrim(r#""#);
+
+    #[test]
+    fn extern_swift_fn_option_vector_primitive() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: EXPECTED_C_HEADER,
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that accepts and returns an Option<&[T]> where T is a
+/// primitive.
+/// `None` and an empty slice are passed differently, since an empty Swift buffer can have a `nil`
+/// start.
+mod extern_rust_fn_option_ref_slice_primitive {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function (arg: Option<&[u8]>) -> Option<&[u16]>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::OptionFfiSlice<u8>
+            ) -> swift_bridge::OptionFfiSlice<u16> {
+                swift_bridge::OptionFfiSlice::from_slice(super::some_function(arg.as_slice()))
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: Optional<UnsafeBufferPointer<UInt8>>) -> Optional<UnsafeBufferPointer<UInt16>> {
+    { let val = __swift_bridge__$some_function({ if let val = arg { return __private__OptionFfiSlice(val: val.toFfiSlice(), is_some: true) } else { return __private__OptionFfiSlice(val: __private__FfiSlice(start: nil, len: 0), is_some: false) } }()); if val.is_some { return val.val.toUnsafeBufferPointer(UInt16.self) } else { return nil } }()
+}
+"#,
+        )
+    }
+
+    const EXPECTED_C_HEADER: ExpectedCHeader = ExpectedCHeader::ContainsAfterTrim(
+        r#"
+struct __private__OptionFfiSlice __swift_bridge__$some_function(struct __private__OptionFfiSlice arg);
+    "#,
+    );
+
+    #[test]
+    fn extern_rust_fn_option_ref_slice_primitive() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: EXPECTED_C_HEADER,
+        }
+        .test();
+    }
+}
+
 /// Test code generation for Rust function that accepts and returns Option<String>.
 mod extern_rust_fn_option_string {
     use super::*;
@@ -1025,3 +1153,100 @@ typedef struct __swift_bridge__$SomeStruct { struct __private__OptionU8 field; }
         .test();
     }
 }
+
+/// Verify that a shared struct can have `Option<String>` and `Option<SharedStruct>` fields.
+mod shared_struct_with_option_string_and_struct_fields {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                struct Inner {
+                    field: u8
+                }
+
+                struct SomeStruct {
+                    name: Option<String>,
+                    inner: Option<Inner>
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub struct __swift_bridge__SomeStruct {
+                    name: *mut swift_bridge::string::RustString,
+                    inner: __swift_bridge__Option_Inner
+                }
+            },
+            quote! {
+                __swift_bridge__SomeStruct {
+                    name: if let Some(val) = val.name {
+                        swift_bridge::string::RustString(val).box_into_raw()
+                    } else {
+                        std::ptr::null::<swift_bridge::string::RustString>() as *mut swift_bridge::string::RustString
+                    },
+                    inner: __swift_bridge__Option_Inner::from_rust_repr(val.inner)
+                }
+            },
+            quote! {
+                SomeStruct {
+                    name: if val.name.is_null() {
+                        None
+                    } else {
+                        Some(unsafe { Box::from_raw(val.name).0 })
+                    },
+                    inner: val.inner.into_rust_repr()
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public struct SomeStruct {
+    public var name: Optional<RustString>
+    public var inner: Optional<Inner>
+
+    public init(name: Optional<RustString>,inner: Optional<Inner>) {
+        self.name = name
+        self.inner = inner
+    }
+
+    @inline(__always)
+    func intoFfiRepr() -> __swift_bridge__$SomeStruct {
+        { let val = self; return __swift_bridge__$SomeStruct(name: { if let rustString = optionalStringIntoRustString(val.name) { rustString.isOwned = false; return rustString.ptr } else { return nil } }(), inner: __swift_bridge__$Option$Inner.fromSwiftRepr(val.inner)); }()
+    }
+}
+extension __swift_bridge__$SomeStruct {
+    @inline(__always)
+    func intoSwiftRepr() -> SomeStruct {
+        { let val = self; return SomeStruct(name: { let val = val.name; if val != nil { return RustString(ptr: val!) } else { return nil } }(), inner: val.inner.intoSwiftRepr()); }()
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef struct __swift_bridge__$SomeStruct { void* name; struct __swift_bridge__$Option$Inner inner; } __swift_bridge__$SomeStruct;
+    "#,
+        )
+    }
+
+    #[test]
+    fn shared_struct_with_option_string_and_struct_fields() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
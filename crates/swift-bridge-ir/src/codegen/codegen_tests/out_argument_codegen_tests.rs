@@ -0,0 +1,117 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that a Rust function's `#[swift_bridge(out)]` arguments are `inout` parameters in Swift.
+mod extern_rust_fn_out_arguments {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    fn min_max(
+                        values: &[u32],
+                        #[swift_bridge(out)] min: &mut u32,
+                        #[swift_bridge(out)] max: &mut u32,
+                    ) -> bool;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$min_max"]
+            pub extern "C" fn __swift_bridge__min_max(
+                values: swift_bridge::FfiSlice<u32>,
+                min: *mut u32,
+                max: *mut u32
+            ) -> bool {
+                super::min_max(values.as_slice(), unsafe { &mut *min }, unsafe { &mut *max })
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func min_max(_ values: UnsafeBufferPointer<UInt32>, _ min: inout UInt32, _ max: inout UInt32) -> Bool {
+    __swift_bridge__$min_max(values.toFfiSlice(), &min, &max)
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+#include <stdint.h>
+#include <stdbool.h>
+bool __swift_bridge__$min_max(struct __private__FfiSlice values, uint32_t* min, uint32_t* max);
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_out_arguments() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a Swift function's `#[swift_bridge(out)]` arguments are `inout` parameters in Swift.
+mod extern_swift_fn_out_arguments {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Swift" {
+                    fn read_position(#[swift_bridge(out)] x: &mut f32, #[swift_bridge(out)] y: &mut f32);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn read_position(x: &mut f32, y: &mut f32) {
+                    unsafe { __swift_bridge__read_position(x as *mut f32, y as *mut f32) }
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$read_position"]
+                fn __swift_bridge__read_position(x: *mut f32, y: *mut f32);
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$read_position")
+func __swift_bridge__read_position (_ x: UnsafeMutablePointer<Float>, _ y: UnsafeMutablePointer<Float>) {
+    read_position(x: &x.pointee, y: &y.pointee)
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_out_arguments() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
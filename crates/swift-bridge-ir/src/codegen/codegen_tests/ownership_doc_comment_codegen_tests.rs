@@ -0,0 +1,79 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we document which arguments an extern "Rust" function consumes or borrows, and
+/// which returned references must not be retained.
+mod extern_rust_fn_ownership_doc_comments {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn consume(self);
+                    fn name(&self) -> &str;
+                    fn child(&self) -> &SomeType;
+
+                    fn merge(a: SomeType, b: &SomeType, c: &mut SomeType);
+                    fn version() -> &'static str;
+                    fn no_opaque_types(arg: u8) -> u8;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::SkipTest
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+extension SomeType {
+    /// Consumes `self`. Ownership moves to Rust, so this instance must not be used after this call.
+    public func consume() {
+"#,
+            r#"
+extension SomeTypeRef {
+    /// - Returns: A reference into Rust memory that is only valid while the value that it borrows from is alive. It must not be retained.
+    public func name() -> RustStr {
+        __swift_bridge__$SomeType$name(ptr)
+    }
+
+    public func child() -> SomeTypeRef {
+"#,
+            r#"
+/// - Parameter a: Consumed. Ownership moves to Rust, so `a` must not be used after this call.
+/// - Parameter b: Borrowed for the duration of the call.
+/// - Parameter c: Mutably borrowed for the duration of the call.
+public func merge(_ a: SomeType, _ b: SomeTypeRef, _ c: SomeTypeRefMut) {
+"#,
+            r#"
+}
+public func version() -> RustStr {
+"#,
+            r#"
+}
+public func no_opaque_types(_ arg: UInt8) -> UInt8 {
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn extern_rust_fn_ownership_doc_comments() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -0,0 +1,117 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we can take and return a pixel buffer from a Rust function, and that the generated
+/// Swift imports Core Video.
+mod extern_rust_fn_pixel_buffer_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    fn some_function (arg: PixelBuffer) -> swift_bridge::pixel_buffer::PixelBuffer;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: *mut std::ffi::c_void
+            ) -> *mut std::ffi::c_void {
+                super::some_function(unsafe {
+                    swift_bridge::pixel_buffer::PixelBuffer::from_retained_ptr(arg)
+                }).into_retained_ptr()
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            "import CoreVideo",
+            r#"
+func some_function(_ arg: CVPixelBuffer) -> CVPixelBuffer {
+    Unmanaged<CVPixelBuffer>.fromOpaque(__swift_bridge__$some_function(Unmanaged.passRetained(arg).toOpaque())).takeRetainedValue()
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+void* __swift_bridge__$some_function(void* arg);
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_pixel_buffer_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we can pass an optional pixel buffer to a Swift function.
+mod extern_swift_fn_option_pixel_buffer_arg {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Swift" {
+                    fn some_function (arg: Option<PixelBuffer>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub fn some_function(arg: Option<swift_bridge::pixel_buffer::PixelBuffer>) {
+                unsafe {
+                    __swift_bridge__some_function(if let Some(val) = arg {
+                        val.into_retained_ptr()
+                    } else {
+                        std::ptr::null_mut()
+                    })
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: UnsafeMutableRawPointer?) {
+    some_function(arg: { let val = arg; if val != nil { return Unmanaged<CVPixelBuffer>.fromOpaque(val!).takeRetainedValue() } else { return nil } }())
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim("")
+    }
+
+    #[test]
+    fn extern_swift_fn_option_pixel_buffer_arg() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
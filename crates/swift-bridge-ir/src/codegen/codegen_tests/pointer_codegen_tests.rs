@@ -0,0 +1,198 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we can take and return an optional void pointer from a Rust function, where `None`
+/// is passed as a null pointer.
+mod extern_rust_fn_option_void_pointer {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function (arg: Option<*const c_void>) -> Option<*mut c_void>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: *const super::c_void
+            ) -> *mut super::c_void {
+                if let Some(val) = super::some_function({
+                    let val = arg;
+                    if val.is_null() { None } else { Some(val) }
+                }) {
+                    val
+                } else {
+                    std::ptr::null_mut()
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: Optional<UnsafeRawPointer>) -> Optional<UnsafeMutableRawPointer> {
+    __swift_bridge__$some_function(UnsafeMutableRawPointer(mutating: arg))
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+void* __swift_bridge__$some_function(void* arg);
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_option_void_pointer() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we can take and return an optional pointer to a primitive from a Swift function.
+mod extern_swift_fn_option_primitive_pointer {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Swift" {
+                    fn some_function (arg: Option<*const u8>) -> Option<*mut u8>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub fn some_function(arg: Option<*const u8>) -> Option<*mut u8> {
+                {
+                    let val = unsafe {
+                        __swift_bridge__some_function(if let Some(val) = arg {
+                            val
+                        } else {
+                            std::ptr::null()
+                        })
+                    };
+                    if val.is_null() { None } else { Some(val) }
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: UnsafePointer<UInt8>?) -> UnsafeMutablePointer<UInt8>? {
+    some_function(arg: arg)
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim("")
+    }
+
+    #[test]
+    fn extern_swift_fn_option_primitive_pointer() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a shared struct can hold pointers, and that a void pointer's pointee is looked up
+/// outside of the bridge module.
+mod shared_struct_pointer_fields {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    texture: *mut c_void,
+                    device: *const c_void,
+                    fallback: Option<*mut c_void>,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub struct SomeStruct {
+                    pub texture: *mut super::c_void,
+                    pub device: *const super::c_void,
+                    pub fallback: Option<*mut super::c_void>
+                }
+            },
+            quote! {
+                pub struct __swift_bridge__SomeStruct {
+                    texture: *mut super::c_void,
+                    device: *const super::c_void,
+                    fallback: *mut super::c_void
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public struct SomeStruct {
+    public var texture: UnsafeMutableRawPointer
+    public var device: UnsafeRawPointer
+    public var fallback: Optional<UnsafeMutableRawPointer>
+"#,
+            r#"
+        { let val = self; return __swift_bridge__$SomeStruct(texture: val.texture, device: UnsafeMutableRawPointer(mutating: val.device), fallback: val.fallback); }()
+"#,
+            r#"
+        { let val = self; return SomeStruct(texture: val.texture, device: UnsafeRawPointer(val.device!), fallback: val.fallback); }()
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef struct __swift_bridge__$SomeStruct { void* texture; void* device; void* fallback; } __swift_bridge__$SomeStruct;
+"#,
+        )
+    }
+
+    #[test]
+    fn shared_struct_pointer_fields() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -0,0 +1,161 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we can take a `Range<T>` and return a `RangeInclusive<T>` from a Rust function.
+mod extern_rust_fn_range_arg_and_range_inclusive_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    fn some_function (arg: Range<u32>) -> std::ops::RangeInclusive<i64>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::range::FfiRange<u32>
+            ) -> swift_bridge::range::FfiRange<i64> {
+                swift_bridge::range::FfiRange::from(
+                    super::some_function(<std::ops::Range<u32>>::from(arg))
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+func some_function(_ arg: Range<UInt32>) -> ClosedRange<Int64> {
+    __swift_bridge__$some_function(__private__RangeU32(arg)).intoClosedRange()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+struct __private__RangeI64 __swift_bridge__$some_function(struct __private__RangeU32 arg);
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_range_arg_and_range_inclusive_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we can take a `RangeInclusive<T>` and return a `Range<T>` from a Swift function.
+mod extern_swift_fn_range_inclusive_arg_and_range_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Swift" {
+                    fn some_function (arg: RangeInclusive<usize>) -> Range<i8>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub fn some_function(arg: std::ops::RangeInclusive<usize>) -> std::ops::Range<i8> {
+                <std::ops::Range<i8>>::from(unsafe {
+                    __swift_bridge__some_function(swift_bridge::range::FfiRange::from(arg))
+                })
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: __private__RangeUsize) -> __private__RangeI8 {
+    __private__RangeI8(some_function(arg: arg.intoClosedRange()))
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_range_inclusive_arg_and_range_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that a shared struct can have a `Range<T>` field.
+mod shared_struct_range_field {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                #[swift_bridge(swift_repr = "struct")]
+                struct Page {
+                    rows: Range<u64>,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[repr(C)]
+            #[doc(hidden)]
+            pub struct __swift_bridge__Page {
+                rows: swift_bridge::range::FfiRange<u64>
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public struct Page {
+    public var rows: Range<UInt64>
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef struct __swift_bridge__$Page { struct __private__RangeU64 rows; } __swift_bridge__$Page;
+    "#,
+        )
+    }
+
+    #[test]
+    fn shared_struct_range_field() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -0,0 +1,182 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we generate a registry of the module's types and functions when the module has a
+/// `#[swift_bridge(reflection = "...")]` attribute.
+mod reflection {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge(reflection = "my_module")]
+            mod foo {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn some_function();
+                    fn some_method(&self);
+                }
+
+                extern "Swift" {
+                    fn swift_function();
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                static __SWIFT_BRIDGE_REFLECTED_TYPES: [swift_bridge::reflection::ReflectedType; 1usize] = [
+                    swift_bridge::reflection::ReflectedType {
+                        name: "SomeType\0".as_ptr() as *const std::os::raw::c_char,
+                        id: 1572101627239260409u64,
+                        kind: swift_bridge::reflection::ReflectedTypeKind::OpaqueRust
+                    }
+                ];
+            },
+            quote! {
+                static __SWIFT_BRIDGE_REFLECTED_FUNCTIONS: [swift_bridge::reflection::ReflectedFunction; 3usize] = [
+                    swift_bridge::reflection::ReflectedFunction {
+                        name: "some_function\0".as_ptr() as *const std::os::raw::c_char,
+                        type_name: std::ptr::null(),
+                        link_name: "__swift_bridge__$some_function\0".as_ptr() as *const std::os::raw::c_char,
+                        is_rust: true,
+                        calls: std::sync::atomic::AtomicU64::new(0)
+                    },
+                    swift_bridge::reflection::ReflectedFunction {
+                        name: "some_method\0".as_ptr() as *const std::os::raw::c_char,
+                        type_name: "SomeType\0".as_ptr() as *const std::os::raw::c_char,
+                        link_name: "__swift_bridge__$SomeType$some_method\0".as_ptr() as *const std::os::raw::c_char,
+                        is_rust: true,
+                        calls: std::sync::atomic::AtomicU64::new(0)
+                    },
+                    swift_bridge::reflection::ReflectedFunction {
+                        name: "swift_function\0".as_ptr() as *const std::os::raw::c_char,
+                        type_name: std::ptr::null(),
+                        link_name: "__swift_bridge__$swift_function\0".as_ptr() as *const std::os::raw::c_char,
+                        is_rust: false,
+                        calls: std::sync::atomic::AtomicU64::new(0)
+                    }
+                ];
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$my_module$reflection"]
+                pub extern "C" fn __swift_bridge__my_module_reflection() -> &'static swift_bridge::reflection::ModuleReflection {
+                    &REFLECTION
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension RustReflection {
+    public static let my_module = RustReflection(__swift_bridge__$my_module$reflection())
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+const struct __private__ModuleReflection* __swift_bridge__$my_module$reflection(void);
+"#,
+        )
+    }
+
+    #[test]
+    fn reflection() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we count the calls that are made to functions in both directions.
+mod reflection_call_counts {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge(reflection = "my_module")]
+            mod foo {
+                extern "Rust" {
+                    fn some_function();
+                }
+
+                extern "Swift" {
+                    fn swift_function();
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$some_function"]
+                pub extern "C" fn __swift_bridge__some_function() {
+                    __SWIFT_BRIDGE_REFLECTED_FUNCTIONS[0usize].record_call();
+                    super::some_function()
+                }
+            },
+            quote! {
+                pub fn swift_function() {
+                    __SWIFT_BRIDGE_REFLECTED_FUNCTIONS[1usize].record_call();
+                    unsafe { __swift_bridge__swift_function() }
+                }
+            },
+        ])
+    }
+
+    #[test]
+    fn reflection_call_counts() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: ExpectedSwiftCode::SkipTest,
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we do not generate a registry by default.
+mod no_reflection {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    fn some_function();
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::DoesNotContain(quote! {
+            record_call
+        })
+    }
+
+    #[test]
+    fn no_reflection() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: ExpectedSwiftCode::DoesNotContainAfterTrim("RustReflection"),
+            expected_c_header: ExpectedCHeader::DoesNotContainAfterTrim("reflection"),
+        }
+        .test();
+    }
+}
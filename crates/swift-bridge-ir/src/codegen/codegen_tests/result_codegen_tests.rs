@@ -687,3 +687,132 @@ typedef struct __swift_bridge__$ResultTupleI32U32AndSomeErrEnum{__swift_bridge__
         .test();
     }
 }
+
+/// Test code generation for Rust function that accepts a Result<T, E> where T is a shared struct
+/// and E is a transparent enum type.
+mod extern_rust_fn_arg_result_shared_struct_and_transparent_enum_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                struct SomeStruct {
+                    field: u8,
+                }
+                enum SomeErrEnum {
+                    Variant1,
+                    Variant2(i32),
+                }
+                extern "Rust" {
+                    fn some_function(arg: Result<SomeStruct, SomeErrEnum>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[repr(C)]
+                pub enum ResultSomeStructAndSomeErrEnum{
+                    Ok(__swift_bridge__SomeStruct),
+                    Err(__swift_bridge__SomeErrEnum),
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$some_function"]
+                pub extern "C" fn __swift_bridge__some_function(arg: ResultSomeStructAndSomeErrEnum) {
+                    super::some_function(match arg {
+                        ResultSomeStructAndSomeErrEnum::Ok(ok) => std::result::Result::Ok(ok.into_rust_repr()),
+                        ResultSomeStructAndSomeErrEnum::Err(err) => std::result::Result::Err(err.into_rust_repr()),
+                    })
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: RustResult<SomeStruct, SomeErrEnum>) {
+    __swift_bridge__$some_function({ switch arg { case .Ok(let ok): return __swift_bridge__$ResultSomeStructAndSomeErrEnum(tag: __swift_bridge__$ResultSomeStructAndSomeErrEnum$ResultOk, payload: __swift_bridge__$ResultSomeStructAndSomeErrEnum$Fields(ok: ok.intoFfiRepr())) case .Err(let err): return __swift_bridge__$ResultSomeStructAndSomeErrEnum(tag: __swift_bridge__$ResultSomeStructAndSomeErrEnum$ResultErr, payload: __swift_bridge__$ResultSomeStructAndSomeErrEnum$Fields(err: err.intoFfiRepr())) } }())
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+typedef enum __swift_bridge__$ResultSomeStructAndSomeErrEnum$Tag {__swift_bridge__$ResultSomeStructAndSomeErrEnum$ResultOk, __swift_bridge__$ResultSomeStructAndSomeErrEnum$ResultErr} __swift_bridge__$ResultSomeStructAndSomeErrEnum$Tag;
+union __swift_bridge__$ResultSomeStructAndSomeErrEnum$Fields {struct __swift_bridge__$SomeStruct ok; struct __swift_bridge__$SomeErrEnum err;};
+typedef struct __swift_bridge__$ResultSomeStructAndSomeErrEnum{__swift_bridge__$ResultSomeStructAndSomeErrEnum$Tag tag; union __swift_bridge__$ResultSomeStructAndSomeErrEnum$Fields payload;} __swift_bridge__$ResultSomeStructAndSomeErrEnum;
+"#,
+            r#"void __swift_bridge__$some_function(struct __swift_bridge__$ResultSomeStructAndSomeErrEnum arg);"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_arg_result_shared_struct_and_transparent_enum_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for Rust function that accepts a Result<T, E> where T is () and
+/// E is a transparent enum type.
+mod extern_rust_fn_arg_result_unit_type_and_transparent_enum_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                enum SomeErrEnum {
+                    Variant1,
+                    Variant2(i32),
+                }
+                extern "Rust" {
+                    fn some_function(arg: Result<(), SomeErrEnum>);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(arg: ResultVoidAndSomeErrEnum) {
+                super::some_function(match arg {
+                    ResultVoidAndSomeErrEnum::Ok => std::result::Result::Ok(()),
+                    ResultVoidAndSomeErrEnum::Err(err) => std::result::Result::Err(err.into_rust_repr()),
+                })
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func some_function(_ arg: RustResult<(), SomeErrEnum>) {
+    __swift_bridge__$some_function({ switch arg { case .Ok: return __swift_bridge__$ResultVoidAndSomeErrEnum(tag: __swift_bridge__$ResultVoidAndSomeErrEnum$ResultOk, payload: __swift_bridge__$ResultVoidAndSomeErrEnum$Fields()) case .Err(let err): return __swift_bridge__$ResultVoidAndSomeErrEnum(tag: __swift_bridge__$ResultVoidAndSomeErrEnum$ResultErr, payload: __swift_bridge__$ResultVoidAndSomeErrEnum$Fields(err: err.intoFfiRepr())) } }())
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_arg_result_unit_type_and_transparent_enum_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
@@ -0,0 +1,147 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that a method that returns a guard gets a Swift closure API that drops the guard once
+/// the closure returns.
+mod extern_rust_method_scoped_guard {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type Database;
+                    type TransactionGuard;
+
+                    #[swift_bridge(scoped = "withTransaction")]
+                    fn begin(self: &mut Database) -> TransactionGuard;
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension DatabaseRefMut {
+    public func begin() -> TransactionGuard {
+        TransactionGuard(ptr: __swift_bridge__$Database$begin(ptr))
+    }
+    public func withTransaction<R>(_ body: (TransactionGuardRefMut) throws -> R) rethrows -> R {
+        let scope = begin()
+        return try withExtendedLifetime(scope) {
+            try body(TransactionGuardRefMut(ptr: scope.ptr))
+        }
+    }
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_method_scoped_guard() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that a freestanding function that returns a guard gets a freestanding Swift closure
+/// API, which calls the function by its Swift name.
+mod extern_rust_freestanding_fn_scoped_guard {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type LockGuard;
+
+                    #[swift_bridge(scoped = "withLock", swift_name = "lock")]
+                    fn acquire_lock() -> LockGuard;
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func lock() -> LockGuard {
+    LockGuard(ptr: __swift_bridge__$acquire_lock())
+}
+public func withLock<R>(_ body: (LockGuardRefMut) throws -> R) rethrows -> R {
+    let scope = lock()
+    return try withExtendedLifetime(scope) {
+        try body(LockGuardRefMut(ptr: scope.ptr))
+    }
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_freestanding_fn_scoped_guard() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that a method that returns a reference gets a Swift closure API that passes the
+/// borrowed value to the closure.
+mod extern_rust_method_scoped_borrow {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type Document;
+                    type Metadata;
+
+                    #[swift_bridge(scoped = "withMetadata")]
+                    fn metadata(self: &Document) -> &Metadata;
+
+                    #[swift_bridge(scoped = "withMetadataMut")]
+                    fn metadata_mut(self: &mut Document) -> &mut Metadata;
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+    public func withMetadataMut<R>(_ body: (MetadataRefMut) throws -> R) rethrows -> R {
+        try body(metadata_mut())
+    }
+"#,
+            r#"
+    public func withMetadata<R>(_ body: (MetadataRef) throws -> R) rethrows -> R {
+        try body(metadata())
+    }
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_method_scoped_borrow() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
@@ -0,0 +1,171 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that a `Sendable` opaque Rust type asserts that it is `Send + Sync` and conforms to
+/// `Sendable` in Swift.
+mod sendable_opaque_rust_type {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(Sendable)]
+                    type SomeType;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            const _: () = {
+                fn _assert_send_sync() {
+                    swift_bridge::sendable_support::assert_send_sync::<super::SomeType>();
+                }
+            };
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeTypeRef: @unchecked Sendable {}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn sendable_opaque_rust_type() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a `Sendable` shared struct asserts that it is `Send + Sync` and conforms to
+/// `Sendable` in Swift.
+mod sendable_shared_struct {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", Sendable)]
+                struct SomeStruct {
+                    field: String,
+                }
+
+                #[swift_bridge(swift_repr = "class", Sendable)]
+                struct SomeClassStruct {
+                    field: u8,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                const _: () = {
+                    fn _assert_send_sync() {
+                        swift_bridge::sendable_support::assert_send_sync::<SomeStruct>();
+                    }
+                };
+            },
+            quote! {
+                const _: () = {
+                    fn _assert_send_sync() {
+                        swift_bridge::sendable_support::assert_send_sync::<SomeClassStruct>();
+                    }
+                };
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+extension SomeStruct: @unchecked Sendable {}
+"#,
+            r#"
+extension SomeClassStructRef: @unchecked Sendable {}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn sendable_shared_struct() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a generic shared struct is only `Sendable` when its generics are.
+mod sendable_generic_shared_struct {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", instantiate(u32, u64), Sendable)]
+                struct Pair<T> {
+                    first: T,
+                    second: T,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                swift_bridge::sendable_support::assert_send_sync::<Pair<u32> >();
+            },
+            quote! {
+                swift_bridge::sendable_support::assert_send_sync::<Pair<u64> >();
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension Pair: @unchecked Sendable where T: Sendable {}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn sendable_generic_shared_struct() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -0,0 +1,120 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we can take and return a `serde_json::Value` from a Rust function.
+mod extern_rust_fn_serde_json_value_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    fn some_function (arg: serde_json::Value) -> swift_bridge::serde_json::Value;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: *mut swift_bridge::string::RustString
+            ) -> *mut swift_bridge::string::RustString {
+                swift_bridge::serde_json::value_into_ffi(super::some_function(unsafe {
+                    swift_bridge::serde_json::value_from_ffi(arg)
+                }))
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+func some_function(_ arg: Any) -> Any {
+    __private__jsonFromRustString(__swift_bridge__$some_function(__private__jsonIntoRustString(arg)))
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+void* __swift_bridge__$some_function(void* arg);
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_serde_json_value_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we can pass an optional `serde_json::Value` to and from a Swift function.
+mod extern_swift_fn_option_serde_json_value_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Swift" {
+                    fn some_function (arg: Option<serde_json::Value>) -> Option<serde_json::Value>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            pub fn some_function(
+                arg: Option<swift_bridge::serde_json::Value>
+            ) -> Option<swift_bridge::serde_json::Value> {
+                {
+                    let val = unsafe {
+                        __swift_bridge__some_function(if let Some(val) = arg {
+                            swift_bridge::serde_json::value_into_ffi(val)
+                        } else {
+                            std::ptr::null_mut()
+                        })
+                    };
+                    if val.is_null() {
+                        None
+                    } else {
+                        Some(unsafe { swift_bridge::serde_json::value_from_ffi(val) })
+                    }
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: UnsafeMutableRawPointer?) -> UnsafeMutableRawPointer? {
+    { if let val = some_function(arg: { let val = arg; if val != nil { return __private__jsonFromRustString(val!) } else { return nil } }()) { return __private__jsonIntoRustString(val) } else { return nil } }()
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_option_serde_json_value_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
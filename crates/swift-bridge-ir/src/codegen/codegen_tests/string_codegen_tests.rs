@@ -114,6 +114,95 @@ void __swift_bridge__$some_function(struct RustStr arg);
     }
 }
 
+/// Verify that a module's `utf8` attribute controls how the `&str`s that Rust receives from Swift
+/// are converted.
+mod utf8_attribute {
+    use super::*;
+
+    fn bridge_module_tokens(utf8: &str) -> TokenStream {
+        quote! {
+            #[swift_bridge(utf8 = #utf8)]
+            mod foo {
+                extern "Rust" {
+                    fn some_function (arg: &str, maybe: Option<&str>);
+                }
+            }
+        }
+    }
+
+    /// Verify that `&str`s are validated when the module does not use the `utf8` attribute.
+    #[test]
+    fn utf8_validate_by_default() {
+        CodegenTest {
+            bridge_module: quote! {
+                mod foo {
+                    extern "Rust" {
+                        fn some_function (arg: &str, maybe: Option<&str>);
+                    }
+                }
+            }
+            .into(),
+            expected_rust_tokens: ExpectedRustTokens::Contains(quote! {
+                super::some_function(
+                    arg.to_str(),
+                    if maybe.start.is_null() { None } else { Some(maybe.to_str()) }
+                )
+            }),
+            expected_swift_code: ExpectedSwiftCode::SkipTest,
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+
+    #[test]
+    fn utf8_validate() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens("validate").into(),
+            expected_rust_tokens: ExpectedRustTokens::Contains(quote! {
+                super::some_function(
+                    arg.to_str(),
+                    if maybe.start.is_null() { None } else { Some(maybe.to_str()) }
+                )
+            }),
+            expected_swift_code: ExpectedSwiftCode::SkipTest,
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+
+    #[test]
+    fn utf8_lossy() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens("lossy").into(),
+            expected_rust_tokens: ExpectedRustTokens::Contains(quote! {
+                super::some_function(
+                    &*arg.to_cow_lossy(),
+                    maybe.to_optional_cow_lossy().as_deref()
+                )
+            }),
+            expected_swift_code: ExpectedSwiftCode::SkipTest,
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+
+    #[test]
+    fn utf8_unchecked() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens("unchecked").into(),
+            expected_rust_tokens: ExpectedRustTokens::Contains(quote! {
+                super::some_function(
+                    unsafe { arg.to_str_unchecked() },
+                    if maybe.start.is_null() { None } else { Some(unsafe { maybe.to_str_unchecked() }) }
+                )
+            }),
+            expected_swift_code: ExpectedSwiftCode::SkipTest,
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
 /// Test code generation for Rust function that returns an owned String argument.
 mod extern_rust_fn_returns_string {
     use super::*;
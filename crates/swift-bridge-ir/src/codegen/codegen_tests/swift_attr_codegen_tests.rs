@@ -0,0 +1,147 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that `#[swift_bridge(swift_attr = "...")]` attributes are added in front of the
+/// generated Swift functions and the classes of an opaque Rust type.
+mod swift_attr_on_functions_and_opaque_types {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(swift_attr = "@_spi(Internal)")]
+                    type SomeType;
+
+                    #[swift_bridge(swift_attr = "@_spi(Internal)")]
+                    fn value(&self) -> u8;
+                }
+
+                extern "Rust" {
+                    #[swift_bridge(swift_attr = "@_spi(Internal)", swift_attr = "@discardableResult")]
+                    fn some_function() -> u8;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::SkipTest
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+@_spi(Internal)
+@discardableResult
+public func some_function() -> UInt8 {
+    __swift_bridge__$some_function()
+}
+"#,
+            r#"
+@_spi(Internal)
+public class SomeType: SomeTypeRefMut {
+"#,
+            r#"
+@_spi(Internal)
+public class SomeTypeRefMut: SomeTypeRef {
+"#,
+            r#"
+@_spi(Internal)
+public class SomeTypeRef {
+"#,
+            r#"
+extension SomeTypeRef {
+    @_spi(Internal)
+    public func value() -> UInt8 {
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn swift_attr_on_functions_and_opaque_types() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that `#[swift_bridge(swift_attr = "...")]` attributes are added in front of the
+/// generated Swift declarations of shared structs and enums.
+mod swift_attr_on_shared_types {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", swift_attr = "@_spi(Internal)")]
+                struct SomeStruct {
+                    field: u8,
+                }
+
+                #[swift_bridge(swift_repr = "class", swift_attr = "@_spi(Internal)")]
+                struct SomeClassStruct {
+                    field: u8,
+                }
+
+                #[swift_bridge(swift_attr = "@_spi(Internal)")]
+                enum SomeEnum {
+                    Variant,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::SkipTest
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+@_spi(Internal)
+public struct SomeStruct {
+"#,
+            r#"
+@_spi(Internal)
+public class SomeClassStruct: SomeClassStructRefMut {
+"#,
+            r#"
+@_spi(Internal)
+public class SomeClassStructRefMut: SomeClassStructRef {
+"#,
+            r#"
+@_spi(Internal)
+public class SomeClassStructRef {
+"#,
+            r#"
+@_spi(Internal)
+public enum SomeEnum {
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn swift_attr_on_shared_types() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
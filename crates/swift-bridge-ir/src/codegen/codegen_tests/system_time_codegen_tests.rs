@@ -0,0 +1,223 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we can take and return a `SystemTime` from a Rust function.
+mod extern_rust_fn_system_time_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    fn some_function (arg: SystemTime) -> std::time::SystemTime;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::system_time::FfiSystemTime
+            ) -> swift_bridge::system_time::FfiSystemTime {
+                swift_bridge::system_time::FfiSystemTime::from(
+                    super::some_function(std::time::SystemTime::from(arg))
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+import Foundation
+"#,
+            r#"
+func some_function(_ arg: Date) -> Date {
+    __swift_bridge__$some_function(__private__SystemTime(arg)).toDate()
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+struct __private__SystemTime __swift_bridge__$some_function(struct __private__SystemTime arg);
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_system_time_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we can pass a `SystemTime` to and from a Swift function.
+mod extern_swift_fn_system_time_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Swift" {
+                    fn some_function (arg: SystemTime) -> SystemTime;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(arg: std::time::SystemTime) -> std::time::SystemTime {
+                    std::time::SystemTime::from(unsafe {
+                        __swift_bridge__some_function(swift_bridge::system_time::FfiSystemTime::from(arg))
+                    })
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(
+                    arg: swift_bridge::system_time::FfiSystemTime
+                ) -> swift_bridge::system_time::FfiSystemTime;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: __private__SystemTime) -> __private__SystemTime {
+    __private__SystemTime(some_function(arg: arg.toDate()))
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_system_time_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we can take and return an `Option<SystemTime>` from a Rust function.
+mod extern_rust_fn_option_system_time_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    fn some_function (arg: Option<SystemTime>) -> Option<SystemTime>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::system_time::OptionSystemTime
+            ) -> swift_bridge::system_time::OptionSystemTime {
+                if let Some(val) = super::some_function(
+                    {
+                        let val = arg;
+                        if val.is_some {
+                            Some(std::time::SystemTime::from(val.val))
+                        } else {
+                            None
+                        }
+                    }
+                ) {
+                    swift_bridge::system_time::OptionSystemTime {
+                        val: swift_bridge::system_time::FfiSystemTime::from(val),
+                        is_some: true
+                    }
+                } else {
+                    swift_bridge::system_time::OptionSystemTime {
+                        val: swift_bridge::system_time::FfiSystemTime { secs: 0, nanos: 0 },
+                        is_some: false
+                    }
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+func some_function(_ arg: Optional<Date>) -> Optional<Date> {
+    __swift_bridge__$some_function(__private__OptionSystemTime(arg)).intoSwiftRepr()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __private__OptionSystemTime __swift_bridge__$some_function(struct __private__OptionSystemTime arg);
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_option_system_time_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we only import Foundation into the generated Swift when the module uses a type
+/// that Swift sees as a Foundation type.
+mod module_without_foundation_types_does_not_import_foundation {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    fn some_function (arg: u64) -> u64;
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::DoesNotContainAfterTrim("import Foundation")
+    }
+
+    #[test]
+    fn module_without_foundation_types_does_not_import_foundation() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
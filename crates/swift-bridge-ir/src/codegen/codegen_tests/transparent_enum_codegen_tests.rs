@@ -87,7 +87,7 @@ extension __swift_bridge__$SomeEnum {
             case __swift_bridge__$SomeEnum$Variant2:
                 return SomeEnum.Variant2
             default:
-                fatalError("Unreachable")
+                __swift_bridge__invalidEnumDiscriminant("SomeEnum", Int64(self.tag.rawValue))
         }
     }
 }
@@ -118,6 +118,191 @@ typedef struct __swift_bridge__$Option$SomeEnum { bool is_some; __swift_bridge__
     }
 }
 
+/// Verify that we keep the explicit discriminants of an enum where none of the variants contain
+/// any data.
+mod generates_enum_with_explicit_discriminants {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                enum Direction {
+                    North = 1,
+                    South,
+                    Below = -1,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[derive(Copy, Clone)]
+                pub enum Direction {
+                    North = 1,
+                    South,
+                    Below = -1
+                }
+            },
+            quote! {
+                #[repr(C)]
+                #[doc(hidden)]
+                pub enum __swift_bridge__Direction {
+                    North = 1,
+                    South,
+                    Below = -1
+                }
+            },
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef enum __swift_bridge__$DirectionTag { __swift_bridge__$Direction$North = 1, __swift_bridge__$Direction$South, __swift_bridge__$Direction$Below = -1, } __swift_bridge__$DirectionTag;
+"#,
+        )
+    }
+
+    #[test]
+    fn generates_enum_with_explicit_discriminants() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: ExpectedSwiftCode::ContainsAfterTrim(
+                r#"
+public enum Direction {
+    case North
+    case South
+    case Below
+}
+"#,
+            ),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that the `#[swift_bridge(try_from_discriminant)]` attribute generates a `TryFrom<u32>`
+/// implementation that checks a discriminant against each of the enum's variants.
+mod enum_try_from_discriminant_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(try_from_discriminant)]
+                enum Direction {
+                    North = 1,
+                    South,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            impl std::convert::TryFrom<u32> for Direction {
+                type Error = swift_bridge::bridge_error::UnknownVariant;
+
+                fn try_from(discriminant: u32) -> std::result::Result<Self, Self::Error> {
+                    if discriminant == Direction::North as u32 {
+                        return std::result::Result::Ok(Direction::North);
+                    }
+                    if discriminant == Direction::South as u32 {
+                        return std::result::Result::Ok(Direction::South);
+                    }
+                    std::result::Result::Err(swift_bridge::bridge_error::UnknownVariant {
+                        enum_name: "Direction",
+                        discriminant,
+                    })
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn enum_try_from_discriminant_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: ExpectedSwiftCode::SkipTest,
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that a `#[swift_bridge(non_exhaustive)]` enum gets an `unknown` case in Swift that it
+/// falls back to when it receives a discriminant that it does not know about.
+mod generates_non_exhaustive_enum {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(non_exhaustive)]
+                enum SomeEnum {
+                    Variant1,
+                    Variant2,
+                }
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public enum SomeEnum {
+    case Variant1
+    case Variant2
+    case unknown(Int64)
+}
+extension SomeEnum {
+    func intoFfiRepr() -> __swift_bridge__$SomeEnum {
+        switch self {
+            case SomeEnum.Variant1:
+                return __swift_bridge__$SomeEnum(tag: __swift_bridge__$SomeEnum$Variant1)
+            case SomeEnum.Variant2:
+                return __swift_bridge__$SomeEnum(tag: __swift_bridge__$SomeEnum$Variant2)
+            case SomeEnum.unknown(let discriminant):
+                __swift_bridge__invalidEnumDiscriminant("SomeEnum", discriminant)
+        }
+    }
+}
+extension __swift_bridge__$SomeEnum {
+    func intoSwiftRepr() -> SomeEnum {
+        switch self.tag {
+            case __swift_bridge__$SomeEnum$Variant1:
+                return SomeEnum.Variant1
+            case __swift_bridge__$SomeEnum$Variant2:
+                return SomeEnum.Variant2
+            default:
+                return SomeEnum.unknown(Int64(self.tag.rawValue))
+        }
+    }
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn generates_non_exhaustive_enum() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::SkipTest,
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
 /// Verify that we generate the correct code for a function that has an enum as an argument and
 /// returns an enum.
 mod using_enum_in_extern_rust_fn {
@@ -430,7 +615,7 @@ extension __swift_bridge__$SomeEnum {
             case __swift_bridge__$SomeEnum$Variant2:
                 return SomeEnum.Variant2
             default:
-                fatalError("Unreachable")
+                __swift_bridge__invalidEnumDiscriminant("SomeEnum", Int64(self.tag.rawValue))
         }
     }
 }
@@ -548,7 +733,7 @@ extension __swift_bridge__$SomeEnum {
             case __swift_bridge__$SomeEnum$B:
                 return SomeEnum.B(RustString(ptr: self.payload.B._0))
             default:
-                fatalError("Unreachable")
+                __swift_bridge__invalidEnumDiscriminant("SomeEnum", Int64(self.tag.rawValue))
         }
     }
 }
@@ -682,7 +867,7 @@ extension __swift_bridge__$SomeEnum {
             case __swift_bridge__$SomeEnum$B:
                 return SomeEnum.B(description: RustString(ptr: self.payload.B.description))
             default:
-                fatalError("Unreachable")
+                __swift_bridge__invalidEnumDiscriminant("SomeEnum", Int64(self.tag.rawValue))
         }
     }
 }
@@ -812,7 +997,7 @@ extension __swift_bridge__$SomeEnum {
             case __swift_bridge__$SomeEnum$Named:
                 return SomeEnum.Named(data: SomeType(ptr: self.payload.Named.data))
             default:
-                fatalError("Unreachable")
+                __swift_bridge__invalidEnumDiscriminant("SomeEnum", Int64(self.tag.rawValue))
         }
     }
 }
@@ -847,3 +1032,72 @@ typedef struct __swift_bridge__$Option$SomeEnum { bool is_some; __swift_bridge__
         .test();
     }
 }
+
+/// Verify that we generate a table of variant names that Swift can use to check that Rust's and
+/// Swift's discriminants match when using the `#[swift_bridge(discriminant_check)]` attribute.
+mod enum_discriminant_check_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(discriminant_check)]
+                enum SomeEnum {
+                    Variant1,
+                    Variant2(u8),
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$SomeEnum$_variant_name"]
+            pub extern "C" fn __swift_bridge__SomeEnum__variant_name(discriminant: u32) -> swift_bridge::string::RustStr {
+                const VARIANT_NAMES: [&str; 2usize] = ["Variant1", "Variant2"];
+                swift_bridge::string::RustStr::from_str(
+                    VARIANT_NAMES.get(discriminant as usize).copied().unwrap_or("")
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeEnum {
+    /// Crash if the Rust library that this code is linked against has a different variant at any of this enum's discriminants.
+    public static func assertDiscriminantsMatchRust() {
+        __swift_bridge__debug_check {
+            let discriminants: [(String, UInt32)] = [("Variant1", UInt32(__swift_bridge__$SomeEnum$Variant1.rawValue)), ("Variant2", UInt32(__swift_bridge__$SomeEnum$Variant2.rawValue))]
+            for (name, discriminant) in discriminants {
+                let rustName = __swift_bridge__$SomeEnum$_variant_name(discriminant).toString()
+                precondition(rustName == name, "SomeEnum.\(name) has discriminant \(discriminant) in Swift, but discriminant \(discriminant) is \"\(rustName)\" in Rust")
+            }
+        }
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct RustStr __swift_bridge__$SomeEnum$_variant_name(uint32_t discriminant);
+"#,
+        )
+    }
+
+    #[test]
+    fn enum_discriminant_check_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -582,6 +582,138 @@ mod shared_struct_swift_name_attribute {
     }
 }
 
+/// Verify that a field's `swift_name` is used in Swift and in the C header, while Rust keeps the
+/// field's original name.
+/// Related: crates/swift-integration-tests/src/struct_attributes/swift_name.rs
+mod shared_struct_field_swift_name_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    #[swift_bridge(swift_name = "userId")]
+                    user_id: u32,
+                    age: u8,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub struct SomeStruct {
+                    pub user_id: u32,
+                    pub age: u8
+                }
+            },
+            quote! {
+                pub struct __swift_bridge__SomeStruct {
+                    user_id: u32,
+                    age: u8
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public struct SomeStruct {
+    public var userId: UInt32
+    public var age: UInt8
+
+    public init(userId: UInt32,age: UInt8) {
+        self.userId = userId
+        self.age = age
+    }
+
+    @inline(__always)
+    func intoFfiRepr() -> __swift_bridge__$SomeStruct {
+        { let val = self; return __swift_bridge__$SomeStruct(userId: val.userId, age: val.age); }()
+    }
+}
+extension __swift_bridge__$SomeStruct {
+    @inline(__always)
+    func intoSwiftRepr() -> SomeStruct {
+        { let val = self; return SomeStruct(userId: val.userId, age: val.age); }()
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef struct __swift_bridge__$SomeStruct { uint32_t userId; uint8_t age; } __swift_bridge__$SomeStruct;
+"#,
+        )
+    }
+
+    #[test]
+    fn shared_struct_field_swift_name_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that field defaults are used as default parameter values in the Swift initializer.
+mod shared_struct_field_defaults {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct Config {
+                    #[swift_bridge(default = 8080)]
+                    port: u16,
+                    #[swift_bridge(default = -0.5)]
+                    gain: f32,
+                    #[swift_bridge(default = false)]
+                    verbose: bool,
+                    name: u8,
+                }
+
+                #[swift_bridge(swift_repr = "struct")]
+                struct Unnamed(#[swift_bridge(default = 1)] u8);
+            }
+        }
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+    public init(port: UInt16 = 8080,gain: Float = -0.5,verbose: Bool = false,name: UInt8) {
+"#,
+            r#"
+    public init(_0: UInt8 = 1) {
+"#,
+        ])
+    }
+
+    #[test]
+    fn shared_struct_field_defaults() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::DoesNotContain(quote! { default }),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
 /// Verify that we can use `Option<Struct>` as Rust function arg and return type.
 mod extern_rust_option_struct {
     use super::*;
@@ -702,3 +834,934 @@ struct __swift_bridge__$Option$SomeStruct __swift_bridge__$some_function(struct
         .test();
     }
 }
+
+/// Verify that we generate methods for computing and applying the changed fields of a
+/// `diffable` struct.
+mod diffable_struct {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", diffable)]
+                struct SomeStruct {
+                    count: u32,
+                    is_enabled: bool,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            impl SomeStruct {
+                /// Get a bit mask of the fields that differ from the `previous` value.
+                /// Bit `n` is set if the `n`th field changed.
+                ///
+                /// Swift can pass this mask to `apply(_:changedFields:)` to only update the
+                /// fields that changed.
+                pub fn changed_fields(&self, previous: &SomeStruct) -> u64 {
+                    let mut changed_fields = 0;
+                    if self.count != previous.count {
+                        changed_fields |= 1 << 0usize;
+                    }
+                    if self.is_enabled != previous.is_enabled {
+                        changed_fields |= 1 << 1usize;
+                    }
+                    changed_fields
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeStruct {
+    public struct ChangedFields: OptionSet {
+        public let rawValue: UInt64
+
+        public init(rawValue: UInt64) {
+            self.rawValue = rawValue
+        }
+
+        public static let count = ChangedFields(rawValue: 1 << 0)
+        public static let is_enabled = ChangedFields(rawValue: 1 << 1)
+    }
+
+    public mutating func apply(_ update: SomeStruct, changedFields: ChangedFields) {
+        if changedFields.contains(.count) {
+            self.count = update.count
+        }
+        if changedFields.contains(.is_enabled) {
+            self.is_enabled = update.is_enabled
+        }
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::SkipTest
+    }
+
+    #[test]
+    fn diffable_struct() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we do not generate changed fields methods for structs that are not `diffable`.
+mod non_diffable_struct {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    count: u32,
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn non_diffable_struct() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: ExpectedRustTokens::DoesNotContain(quote! { changed_fields }),
+            expected_swift_code: ExpectedSwiftCode::DoesNotContainAfterTrim("ChangedFields"),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate methods for encoding a `little_endian` struct as little-endian bytes.
+mod little_endian_struct {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", little_endian)]
+                struct SomeStruct {
+                    kind: u8,
+                    len: u32,
+                    scale: f64,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            impl SomeStruct {
+                /// The number of bytes in this struct's little-endian encoding.
+                pub const LE_BYTES_LEN: usize = 13;
+
+                /// Encode this struct's fields, in order and without padding, as little-endian
+                /// bytes.
+                pub fn to_le_bytes(&self) -> [u8; 13] {
+                    let mut bytes = [0; 13];
+                    bytes[0..1].copy_from_slice(&self.kind.to_le_bytes());
+                    bytes[1..5].copy_from_slice(&self.len.to_le_bytes());
+                    bytes[5..13].copy_from_slice(&self.scale.to_le_bytes());
+                    bytes
+                }
+
+                /// Decode a struct that was encoded using `to_le_bytes`.
+                pub fn from_le_bytes(bytes: [u8; 13]) -> Self {
+                    Self {
+                        kind: u8::from_le_bytes(bytes[0..1].try_into().unwrap()),
+                        len: u32::from_le_bytes(bytes[1..5].try_into().unwrap()),
+                        scale: f64::from_le_bytes(bytes[5..13].try_into().unwrap())
+                    }
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+extension SomeStruct {
+    /// The number of bytes in this struct's little-endian encoding.
+    public static var littleEndianByteCount: Int { 13 }
+
+    /// Encode this struct's fields, in order and without padding, as little-endian bytes.
+    public func toLittleEndianBytes() -> [UInt8] {
+        var bytes: [UInt8] = []
+        bytes.reserveCapacity(13)
+        withUnsafeBytes(of: self.kind.littleEndian) { bytes.append(contentsOf: $0) }
+        withUnsafeBytes(of: self.len.littleEndian) { bytes.append(contentsOf: $0) }
+        withUnsafeBytes(of: self.scale.bitPattern.littleEndian) { bytes.append(contentsOf: $0) }
+        return bytes
+    }
+
+    /// Decode a struct that was encoded using `toLittleEndianBytes()`, or return nil if the number of bytes is wrong.
+    public init?(littleEndianBytes bytes: [UInt8]) {
+        guard bytes.count == 13 else {
+            return nil
+        }
+        self = bytes.withUnsafeBytes { buffer in
+            SomeStruct(kind: UInt8(littleEndian: buffer.loadUnaligned(fromByteOffset: 0, as: UInt8.self)), len: UInt32(littleEndian: buffer.loadUnaligned(fromByteOffset: 1, as: UInt32.self)), scale: Double(bitPattern: UInt64(littleEndian: buffer.loadUnaligned(fromByteOffset: 5, as: UInt64.self))))
+        }
+    }
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn little_endian_struct() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that a shared struct can have a `&'static str` field, which Swift sees as a `RustStr`.
+mod shared_struct_with_static_str_field {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    name: &'static str
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub struct __swift_bridge__SomeStruct {
+                    name: swift_bridge::string::RustStr
+                }
+            },
+            quote! {
+                __swift_bridge__SomeStruct {
+                    name: swift_bridge::string::RustStr::from_str(val.name)
+                }
+            },
+            quote! {
+                SomeStruct { name: val.name.to_str() }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public struct SomeStruct {
+    public var name: RustStr
+"#,
+            r#"
+__swift_bridge__$SomeStruct(name: val.name)
+"#,
+            r#"
+SomeStruct(name: val.name)
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef struct __swift_bridge__$SomeStruct { struct RustStr name; } __swift_bridge__$SomeStruct;
+"#,
+        )
+    }
+
+    #[test]
+    fn shared_struct_with_static_str_field() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a shared struct can have a `String` field, whose ownership is transferred along
+/// with the struct.
+mod shared_struct_with_string_field {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeStruct {
+                    name: String
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub struct __swift_bridge__SomeStruct {
+                    name: *mut swift_bridge::string::RustString
+                }
+            },
+            quote! {
+                __swift_bridge__SomeStruct {
+                    name: swift_bridge::string::RustString(val.name).box_into_raw()
+                }
+            },
+            quote! {
+                SomeStruct { name: unsafe { Box::from_raw(val.name).0 } }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public struct SomeStruct {
+    public var name: RustString
+"#,
+            r#"
+__swift_bridge__$SomeStruct(name: { let rustString = val.name.intoRustString(); rustString.isOwned = false; return rustString.ptr }())
+"#,
+            r#"
+SomeStruct(name: RustString(ptr: val.name))
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef struct __swift_bridge__$SomeStruct { void* name; } __swift_bridge__$SomeStruct;
+"#,
+        )
+    }
+
+    #[test]
+    fn shared_struct_with_string_field() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate `From` impls in both directions between a shared struct and the type
+/// that it mirrors.
+mod shared_struct_mirror_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", mirror = "crate::models::User")]
+                struct User {
+                    name: String,
+                    age: u8,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            impl From<User> for crate::models::User {
+                fn from(val: User) -> Self {
+                    Self {
+                        name: val.name.into(),
+                        age: val.age.into()
+                    }
+                }
+            }
+
+            impl From<crate::models::User> for User {
+                fn from(val: crate::models::User) -> Self {
+                    Self {
+                        name: val.name.into(),
+                        age: val.age.into()
+                    }
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn shared_struct_mirror_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: ExpectedSwiftCode::DoesNotContainAfterTrim("models"),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we generate `From` impls for a tuple struct that mirrors another type.
+mod shared_tuple_struct_mirror_attribute {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", mirror = "super::Meters")]
+                struct Meters(f64);
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            impl From<Meters> for super::Meters {
+                fn from(val: Meters) -> Self {
+                    Self(val.0.into())
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn shared_tuple_struct_mirror_attribute() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: ExpectedSwiftCode::SkipTest,
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that a shared struct's `#[repr(C, align(N))]` is used for its Rust and FFI
+/// representations, and that the C struct is given the same alignment.
+mod shared_struct_repr_align {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                #[repr(C, align(16))]
+                struct SomeStruct {
+                    a: f32,
+                    b: f32,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[repr(C, align(16))]
+                pub struct SomeStruct {
+                    pub a: f32,
+                    pub b: f32
+                }
+            },
+            quote! {
+                #[repr(C, align(16))]
+                #[doc(hidden)]
+                pub struct __swift_bridge__SomeStruct {
+                    a: f32,
+                    b: f32
+                }
+            },
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef struct __swift_bridge__$SomeStruct { _Alignas(16) float a; float b; } __swift_bridge__$SomeStruct;
+typedef struct __swift_bridge__$Option$SomeStruct { bool is_some; __swift_bridge__$SomeStruct val; } __swift_bridge__$Option$SomeStruct;
+"#,
+        )
+    }
+
+    #[test]
+    fn shared_struct_repr_align() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: ExpectedSwiftCode::SkipTest,
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a `#[swift_bridge(packed)]` struct has packed Rust and FFI representations, and
+/// that only the C struct, and not its `Option` wrapper, is packed.
+mod shared_struct_packed {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", packed)]
+                struct SomeStruct {
+                    tag: u8,
+                    value: u32,
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[repr(C, packed)]
+                pub struct SomeStruct {
+                    pub tag: u8,
+                    pub value: u32
+                }
+            },
+            quote! {
+                #[repr(C, packed)]
+                #[doc(hidden)]
+                pub struct __swift_bridge__SomeStruct {
+                    tag: u8,
+                    value: u32
+                }
+            },
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+#pragma pack(push, 1)
+typedef struct __swift_bridge__$SomeStruct { uint8_t tag; uint32_t value; } __swift_bridge__$SomeStruct;
+#pragma pack(pop)
+typedef struct __swift_bridge__$Option$SomeStruct { bool is_some; __swift_bridge__$SomeStruct val; } __swift_bridge__$Option$SomeStruct;
+"#,
+        )
+    }
+
+    #[test]
+    fn shared_struct_packed() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: ExpectedSwiftCode::SkipTest,
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a generic struct is declared once, and that each of its instantiations gets its own
+/// FFI representation and conversions.
+mod generic_shared_struct_instantiations {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", instantiate(u32, f64))]
+                struct Pair<T> {
+                    first: T,
+                    second: T,
+                }
+
+                extern "Rust" {
+                    fn swap_u32(pair: Pair<u32>) -> Pair<u32>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub struct Pair<T> {
+                    pub first: T,
+                    pub second: T
+                }
+            },
+            quote! {
+                #[repr(C)]
+                #[doc(hidden)]
+                pub struct __swift_bridge__Pair_u32 {
+                    first: u32,
+                    second: u32
+                }
+            },
+            quote! {
+                #[repr(C)]
+                #[doc(hidden)]
+                pub struct __swift_bridge__Pair_f64 {
+                    first: f64,
+                    second: f64
+                }
+            },
+            quote! {
+                impl swift_bridge::SharedStruct for Pair<f64> {
+                    type FfiRepr = __swift_bridge__Pair_f64;
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$swap_u32"]
+                pub extern "C" fn __swift_bridge__swap_u32(
+                    pair: __swift_bridge__Pair_u32
+                ) -> __swift_bridge__Pair_u32 {
+                    super::swap_u32(pair.into_rust_repr()).into_ffi_repr()
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+public struct Pair<T> {
+    public var first: T
+    public var second: T
+
+    public init(first: T,second: T) {
+        self.first = first
+        self.second = second
+    }
+}
+extension Pair where T == UInt32 {
+    @inline(__always)
+    func intoFfiRepr() -> __swift_bridge__$Pair$u32 {
+"#,
+            r#"
+extension Pair where T == Double {
+    @inline(__always)
+    func intoFfiRepr() -> __swift_bridge__$Pair$f64 {
+"#,
+            r#"
+extension __swift_bridge__$Pair$f64 {
+    @inline(__always)
+    func intoSwiftRepr() -> Pair<Double> {
+"#,
+            r#"
+public func swap_u32(_ pair: Pair<UInt32>) -> Pair<UInt32> {
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+typedef struct __swift_bridge__$Pair$u32 { uint32_t first; uint32_t second; } __swift_bridge__$Pair$u32;
+typedef struct __swift_bridge__$Option$Pair$u32 { bool is_some; __swift_bridge__$Pair$u32 val; } __swift_bridge__$Option$Pair$u32;
+"#,
+            r#"
+typedef struct __swift_bridge__$Pair$f64 { double first; double second; } __swift_bridge__$Pair$f64;
+"#,
+            r#"
+struct __swift_bridge__$Pair$u32 __swift_bridge__$swap_u32(struct __swift_bridge__$Pair$u32 pair);
+"#,
+        ])
+    }
+
+    #[test]
+    fn generic_shared_struct_instantiations() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a `swift_repr = "class"` struct is passed over FFI as a pointer to a boxed Rust
+/// value, and that Swift reads and writes its fields through getters and setters.
+mod shared_struct_swift_repr_class {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "class")]
+                struct Config {
+                    count: u32,
+                    name: String,
+                }
+
+                extern "Rust" {
+                    fn make_config() -> Config;
+                    fn use_config(config: Config);
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                impl swift_bridge::SharedStruct for Config {
+                    type FfiRepr = *mut Config;
+                }
+
+                #[export_name = "__swift_bridge__$Config$new"]
+                pub extern "C" fn __swift_bridge__Config_new(
+                    count: u32,
+                    name: *mut swift_bridge::string::RustString
+                ) -> *mut Config {
+                    Box::into_raw(Box::new(Config {
+                        count: count,
+                        name: unsafe { Box::from_raw(name).0 }
+                    }))
+                }
+
+                #[export_name = "__swift_bridge__$Config$_free"]
+                pub extern "C" fn __swift_bridge__Config__free(this: *mut Config) {
+                    let _ = unsafe { Box::from_raw(this) };
+                }
+
+                #[export_name = "__swift_bridge__$Config$get$count"]
+                pub extern "C" fn __swift_bridge__Config_get_count(this: *const Config) -> u32 {
+                    let this = unsafe { &*this };
+                    this.count.clone()
+                }
+
+                #[export_name = "__swift_bridge__$Config$set$count"]
+                pub extern "C" fn __swift_bridge__Config_set_count(this: *mut Config, value: u32) {
+                    let this = unsafe { &mut *this };
+                    this.count = value;
+                }
+            },
+            quote! {
+                pub extern "C" fn __swift_bridge__make_config() -> *mut Config {
+                    Box::into_raw(Box::new(super::make_config()))
+                }
+            },
+            quote! {
+                pub extern "C" fn __swift_bridge__use_config(config: *mut Config) {
+                    super::use_config(unsafe { *Box::from_raw(config) })
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func make_config() -> Config {
+    Config(ptr: __swift_bridge__$make_config())
+}
+public func use_config(_ config: Config) {
+    __swift_bridge__$use_config({ let val = config; val.isOwned = false; return val.ptr }())
+}
+public class Config: ConfigRefMut {
+    var isOwned: Bool = true
+
+    public init(count: UInt32,name: RustString) {
+        super.init(ptr: __swift_bridge__$Config$new(count, { let rustString = name.intoRustString(); rustString.isOwned = false; return rustString.ptr }()))
+    }
+
+    public override init(ptr: UnsafeMutableRawPointer) {
+        super.init(ptr: ptr)
+    }
+
+    deinit {
+        if isOwned {
+            __swift_bridge__$Config$_free(ptr)
+        }
+    }
+}
+public class ConfigRefMut: ConfigRef {
+    public override init(ptr: UnsafeMutableRawPointer) {
+        super.init(ptr: ptr)
+    }
+
+    public override var count: UInt32 {
+        get {
+            return __swift_bridge__$Config$get$count(ptr)
+        }
+        set {
+            __swift_bridge__$Config$set$count(ptr, newValue)
+        }
+    }
+
+    public override var name: RustString {
+        get {
+            return RustString(ptr: __swift_bridge__$Config$get$name(ptr))
+        }
+        set {
+            __swift_bridge__$Config$set$name(ptr, { let rustString = newValue.intoRustString(); rustString.isOwned = false; return rustString.ptr }())
+        }
+    }
+}
+public class ConfigRef {
+    var ptr: UnsafeMutableRawPointer
+
+    public init(ptr: UnsafeMutableRawPointer) {
+        self.ptr = ptr
+    }
+
+    public var count: UInt32 {
+        return __swift_bridge__$Config$get$count(ptr)
+    }
+
+    public var name: RustString {
+        return RustString(ptr: __swift_bridge__$Config$get$name(ptr))
+    }
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$Config$new(uint32_t count, void* name);
+void __swift_bridge__$Config$_free(void* self);
+uint32_t __swift_bridge__$Config$get$count(void* self);
+void __swift_bridge__$Config$set$count(void* self, uint32_t value);
+void* __swift_bridge__$Config$get$name(void* self);
+void __swift_bridge__$Config$set$name(void* self, void* value);
+void* __swift_bridge__$make_config(void);
+void __swift_bridge__$use_config(void* config);
+"#,
+        )
+    }
+
+    #[test]
+    fn shared_struct_swift_repr_class() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a shared struct can contain other shared structs, and that the C header declares
+/// the contained structs first even if they are declared later in the bridge module.
+/// Related: crates/swift-integration-tests/src/shared_types/shared_struct.rs
+mod nested_shared_structs {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct Line {
+                    start: Point,
+                    end: Point,
+                }
+
+                #[swift_bridge(swift_repr = "struct")]
+                struct Point {
+                    x: f32,
+                    y: f32,
+                }
+
+                extern "Rust" {
+                    fn make_line() -> Line;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub struct __swift_bridge__Line {
+                    start: __swift_bridge__Point,
+                    end: __swift_bridge__Point
+                }
+            },
+            quote! {
+                pub fn into_ffi_repr(self) -> __swift_bridge__Line {
+                    {
+                        let val = self;
+                        __swift_bridge__Line {
+                            start: val.start.into_ffi_repr(),
+                            end: val.end.into_ffi_repr()
+                        }
+                    }
+                }
+            },
+            quote! {
+                pub fn into_rust_repr(self) -> Line {
+                    {
+                        let val = self;
+                        Line {
+                            start: val.start.into_rust_repr(),
+                            end: val.end.into_rust_repr()
+                        }
+                    }
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+    func intoFfiRepr() -> __swift_bridge__$Line {
+        { let val = self; return __swift_bridge__$Line(start: val.start.intoFfiRepr(), end: val.end.intoFfiRepr()); }()
+    }
+"#,
+            r#"
+    func intoSwiftRepr() -> Line {
+        { let val = self; return Line(start: val.start.intoSwiftRepr(), end: val.end.intoSwiftRepr()); }()
+    }
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+#include <stdbool.h>
+typedef struct __swift_bridge__$Point { float x; float y; } __swift_bridge__$Point;
+typedef struct __swift_bridge__$Option$Point { bool is_some; __swift_bridge__$Point val; } __swift_bridge__$Option$Point;
+typedef struct __swift_bridge__$Line { struct __swift_bridge__$Point start; struct __swift_bridge__$Point end; } __swift_bridge__$Line;
+typedef struct __swift_bridge__$Option$Line { bool is_some; __swift_bridge__$Line val; } __swift_bridge__$Option$Line;
+struct __swift_bridge__$Line __swift_bridge__$make_line(void);
+"#,
+        )
+    }
+
+    #[test]
+    fn nested_shared_structs() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
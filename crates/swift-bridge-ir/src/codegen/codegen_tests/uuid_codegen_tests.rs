@@ -0,0 +1,251 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we can take and return a `Uuid` from a Rust function.
+mod extern_rust_fn_uuid_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    fn some_function (arg: Uuid) -> swift_bridge::uuid::Uuid;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::uuid::FfiUuid
+            ) -> swift_bridge::uuid::FfiUuid {
+                swift_bridge::uuid::FfiUuid::from(
+                    super::some_function(swift_bridge::uuid::Uuid::from(arg))
+                )
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+import Foundation
+"#,
+            r#"
+func some_function(_ arg: UUID) -> UUID {
+    __swift_bridge__$some_function(__private__Uuid(arg)).toUUID()
+}
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ExactAfterTrim(
+            r#"
+struct __private__Uuid __swift_bridge__$some_function(struct __private__Uuid arg);
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_uuid_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that we can pass a `Uuid` to and from a Swift function.
+mod extern_swift_fn_uuid_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Swift" {
+                    fn some_function (arg: Uuid) -> Uuid;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                pub fn some_function(arg: swift_bridge::uuid::Uuid) -> swift_bridge::uuid::Uuid {
+                    swift_bridge::uuid::Uuid::from(unsafe {
+                        __swift_bridge__some_function(swift_bridge::uuid::FfiUuid::from(arg))
+                    })
+                }
+            },
+            quote! {
+                #[link_name = "__swift_bridge__$some_function"]
+                fn __swift_bridge__some_function(
+                    arg: swift_bridge::uuid::FfiUuid
+                ) -> swift_bridge::uuid::FfiUuid;
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function (_ arg: __private__Uuid) -> __private__Uuid {
+    __private__Uuid(some_function(arg: arg.toUUID()))
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_swift_fn_uuid_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: ExpectedCHeader::SkipTest,
+        }
+        .test();
+    }
+}
+
+/// Verify that we can take and return an `Option<Uuid>` from a Rust function.
+mod extern_rust_fn_option_uuid_arg_and_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                extern "Rust" {
+                    fn some_function (arg: Option<Uuid>) -> Option<Uuid>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: swift_bridge::uuid::OptionUuid
+            ) -> swift_bridge::uuid::OptionUuid {
+                if let Some(val) = super::some_function(
+                    {
+                        let val = arg;
+                        if val.is_some {
+                            Some(swift_bridge::uuid::Uuid::from(val.val))
+                        } else {
+                            None
+                        }
+                    }
+                ) {
+                    swift_bridge::uuid::OptionUuid {
+                        val: swift_bridge::uuid::FfiUuid::from(val),
+                        is_some: true
+                    }
+                } else {
+                    swift_bridge::uuid::OptionUuid {
+                        val: swift_bridge::uuid::FfiUuid { bytes: [0; 16] },
+                        is_some: false
+                    }
+                }
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+func some_function(_ arg: Optional<UUID>) -> Optional<UUID> {
+    __swift_bridge__$some_function(__private__OptionUuid(arg)).intoSwiftRepr()
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+struct __private__OptionUuid __swift_bridge__$some_function(struct __private__OptionUuid arg);
+    "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_option_uuid_arg_and_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Verify that a declared `type Uuid;`, such as one that wraps `uuid::Uuid`, is bridged as an
+/// opaque type instead of as the built-in `Uuid`.
+mod extern_rust_fn_declared_uuid_return {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    type Uuid;
+
+                    fn some_function() -> Uuid;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function () -> *mut super::Uuid {
+                Box::into_raw(Box::new({
+                    let val: super::Uuid = super::some_function();
+                    val
+                })) as *mut super::Uuid
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+func some_function() -> Uuid {
+    Uuid(ptr: __swift_bridge__$some_function())
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$some_function(void);
+            "#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_declared_uuid_return() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -430,6 +430,176 @@ void* __swift_bridge__$Vec_SomeEnum$as_ptr(void* vec_ptr);
     }
 }
 
+/// Verify that we emit Rust, Swift and C header code that allows a transparent struct that derives
+/// `Clone` to be used within a Vec<T>, such as in a field of another struct.
+mod transparent_struct_vec_support {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                #[derive(Clone)]
+                struct Point {
+                    x: f32,
+                    y: f32
+                }
+
+                #[swift_bridge(swift_repr = "struct")]
+                struct Polygon {
+                    points: Vec<Point>,
+                    ids: Vec<u32>
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                const _: () = {
+                    #[doc(hidden)]
+                    #[export_name = "__swift_bridge__$Vec_Point$new"]
+                    pub extern "C" fn _new() -> *mut Vec<Point> {
+                        Box::into_raw(Box::new(Vec::new()))
+                    }
+
+                    #[doc(hidden)]
+                    #[export_name = "__swift_bridge__$Vec_Point$drop"]
+                    pub extern "C" fn _drop(vec: *mut Vec<Point>) {
+                        let vec = unsafe { Box::from_raw(vec) };
+                        drop(vec)
+                    }
+
+                    #[doc(hidden)]
+                    #[export_name = "__swift_bridge__$Vec_Point$len"]
+                    pub extern "C" fn _len(vec: *const Vec<Point>) -> usize {
+                        unsafe { &*vec }.len()
+                    }
+
+                    #[doc(hidden)]
+                    #[export_name = "__swift_bridge__$Vec_Point$get"]
+                    pub extern "C" fn _get(vec: *const Vec<Point>, index: usize) -> __swift_bridge__Option_Point {
+                        let vec = unsafe { &*vec };
+                        let val = vec.get(index).cloned();
+                        __swift_bridge__Option_Point::from_rust_repr(val)
+                    }
+
+                    #[doc(hidden)]
+                    #[export_name = "__swift_bridge__$Vec_Point$get_mut"]
+                    pub extern "C" fn _get_mut(vec: *mut Vec<Point>, index: usize) -> __swift_bridge__Option_Point {
+                        let vec = unsafe { &mut *vec };
+                        let val = vec.get_mut(index).map(|v| v.clone());
+                        __swift_bridge__Option_Point::from_rust_repr(val)
+                    }
+
+                    #[doc(hidden)]
+                    #[export_name = "__swift_bridge__$Vec_Point$push"]
+                    pub extern "C" fn _push(vec: *mut Vec<Point>, val: __swift_bridge__Point) {
+                        unsafe { &mut *vec }.push(val.into_rust_repr())
+                    }
+
+                    #[doc(hidden)]
+                    #[export_name = "__swift_bridge__$Vec_Point$pop"]
+                    pub extern "C" fn _pop(vec: *mut Vec<Point>) -> __swift_bridge__Option_Point {
+                        let vec = unsafe { &mut *vec };
+                        let val = vec.pop();
+                        __swift_bridge__Option_Point::from_rust_repr(val)
+                    }
+
+                    #[doc(hidden)]
+                    #[export_name = "__swift_bridge__$Vec_Point$as_ptr"]
+                    pub extern "C" fn _as_ptr(vec: *const Vec<Point>) -> *const Point {
+                        unsafe { & *vec }.as_ptr()
+                    }
+                };
+            },
+            quote! {
+                pub struct __swift_bridge__Polygon {
+                    points: *mut Vec<Point>,
+                    ids: *mut Vec<u32>
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsManyAfterTrim(vec![
+            r#"
+extension Point: Vectorizable {
+    public static func vecOfSelfNew() -> UnsafeMutableRawPointer {
+        __swift_bridge__$Vec_Point$new()
+    }
+
+    public static func vecOfSelfFree(vecPtr: UnsafeMutableRawPointer) {
+        __swift_bridge__$Vec_Point$drop(vecPtr)
+    }
+
+    public static func vecOfSelfPush(vecPtr: UnsafeMutableRawPointer, value: Self) {
+        __swift_bridge__$Vec_Point$push(vecPtr, value.intoFfiRepr())
+    }
+
+    public static func vecOfSelfPop(vecPtr: UnsafeMutableRawPointer) -> Optional<Self> {
+        let maybeStruct = __swift_bridge__$Vec_Point$pop(vecPtr)
+        return maybeStruct.intoSwiftRepr()
+    }
+
+    public static func vecOfSelfGet(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<Self> {
+        let maybeStruct = __swift_bridge__$Vec_Point$get(vecPtr, index)
+        return maybeStruct.intoSwiftRepr()
+    }
+
+    public static func vecOfSelfGetMut(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<Self> {
+        let maybeStruct = __swift_bridge__$Vec_Point$get_mut(vecPtr, index)
+        return maybeStruct.intoSwiftRepr()
+    }
+
+    public static func vecOfSelfAsPtr(vecPtr: UnsafeMutableRawPointer) -> UnsafePointer<Self> {
+        UnsafePointer<Self>(OpaquePointer(__swift_bridge__$Vec_Point$as_ptr(vecPtr)))
+    }
+
+    public static func vecOfSelfLen(vecPtr: UnsafeMutableRawPointer) -> UInt {
+        __swift_bridge__$Vec_Point$len(vecPtr)
+    }
+}
+"#,
+            r#"
+public struct Polygon {
+    public var points: RustVec<Point>
+    public var ids: RustVec<UInt32>
+"#,
+        ])
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+typedef struct __swift_bridge__$Option$Point { bool is_some; __swift_bridge__$Point val; } __swift_bridge__$Option$Point;
+void* __swift_bridge__$Vec_Point$new(void);
+void __swift_bridge__$Vec_Point$drop(void* vec_ptr);
+void __swift_bridge__$Vec_Point$push(void* vec_ptr, __swift_bridge__$Point item);
+__swift_bridge__$Option$Point __swift_bridge__$Vec_Point$pop(void* vec_ptr);
+__swift_bridge__$Option$Point __swift_bridge__$Vec_Point$get(void* vec_ptr, uintptr_t index);
+__swift_bridge__$Option$Point __swift_bridge__$Vec_Point$get_mut(void* vec_ptr, uintptr_t index);
+uintptr_t __swift_bridge__$Vec_Point$len(void* vec_ptr);
+void* __swift_bridge__$Vec_Point$as_ptr(void* vec_ptr);
+typedef struct __swift_bridge__$Polygon { void* points; void* ids; } __swift_bridge__$Polygon;
+"#,
+        )
+    }
+
+    #[test]
+    fn transparent_struct_vec_support() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
 /// Test code generation for Rust function that returns a Vec<T> where T is a transparent enum.
 mod extern_rust_fn_return_vec_of_transparent_enum {
     use super::*;
@@ -657,3 +827,114 @@ func __swift_bridge__some_function (_ arg: UnsafeMutableRawPointer) {
         .test();
     }
 }
+
+/// Test code generation for a Rust function that takes and returns a Vec<Vec<T>> where T is a
+/// primitive Rust type.
+mod extern_rust_fn_vec_of_vec_of_primitive {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: Vec<Vec<u8>>) -> Vec<Vec<u8>>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: *mut Vec<Vec<u8>>
+            ) -> *mut Vec<Vec<u8>> {
+                Box::into_raw(Box::new(super::some_function(unsafe { *Box::from_raw(arg) })))
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+func some_function(_ arg: RustVec<RustVec<UInt8>>) -> RustVec<RustVec<UInt8>> {
+    RustVec(ptr: __swift_bridge__$some_function({ let val = arg; val.isOwned = false; return val.ptr }()))
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$some_function(void* arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_vec_of_vec_of_primitive() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
+
+/// Test code generation for a Rust function that takes and returns a Vec<Option<String>>.
+mod extern_rust_fn_vec_of_option_string {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod ffi {
+                extern "Rust" {
+                    fn some_function(arg: Vec<Option<String>>) -> Vec<Option<String>>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::Contains(quote! {
+            #[export_name = "__swift_bridge__$some_function"]
+            pub extern "C" fn __swift_bridge__some_function(
+                arg: *mut Vec<Option<String>>
+            ) -> *mut Vec<Option<String>> {
+                Box::into_raw(Box::new(super::some_function(unsafe { *Box::from_raw(arg) })))
+            }
+        })
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+func some_function(_ arg: RustVec<Optional<RustString>>) -> RustVec<Optional<RustString>> {
+    RustVec(ptr: __swift_bridge__$some_function({ let val = arg; val.isOwned = false; return val.ptr }()))
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsAfterTrim(
+            r#"
+void* __swift_bridge__$some_function(void* arg);
+"#,
+        )
+    }
+
+    #[test]
+    fn extern_rust_fn_vec_of_option_string() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
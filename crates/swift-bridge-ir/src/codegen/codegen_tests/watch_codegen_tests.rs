@@ -0,0 +1,84 @@
+use super::{CodegenTest, ExpectedCHeader, ExpectedRustTokens, ExpectedSwiftCode};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Verify that we can return a watch of shared structs from a Rust function, and that we
+/// generate the functions that Swift uses to read the watch's events.
+mod extern_rust_fn_return_watch {
+    use super::*;
+
+    fn bridge_module_tokens() -> TokenStream {
+        quote! {
+            mod foo {
+                #[swift_bridge(swift_repr = "struct")]
+                struct FileChanged {
+                    size: u64
+                }
+
+                extern "Rust" {
+                    fn watch_files() -> Watch<FileChanged>;
+                }
+            }
+        }
+    }
+
+    fn expected_rust_tokens() -> ExpectedRustTokens {
+        ExpectedRustTokens::ContainsMany(vec![
+            quote! {
+                #[export_name = "__swift_bridge__$watch_files"]
+                pub extern "C" fn __swift_bridge__watch_files(
+                ) -> *mut swift_bridge::watch::Watch<FileChanged> {
+                    Box::into_raw(Box::new(super::watch_files()))
+                }
+            },
+            quote! {
+                #[export_name = "__swift_bridge__$FileChanged$_watch_next"]
+                pub extern "C" fn __swift_bridge__FileChanged__watch_next(
+                    this: *mut swift_bridge::watch::Watch<FileChanged>
+                ) -> __swift_bridge__Option_FileChanged {
+                    __swift_bridge__Option_FileChanged::from_rust_repr(unsafe { &mut *this }.next_event())
+                }
+
+                #[export_name = "__swift_bridge__$FileChanged$_watch_free"]
+                pub extern "C" fn __swift_bridge__FileChanged__watch_free(
+                    this: *mut swift_bridge::watch::Watch<FileChanged>
+                ) {
+                    drop(unsafe { Box::from_raw(this) });
+                }
+            },
+        ])
+    }
+
+    fn expected_swift_code() -> ExpectedSwiftCode {
+        ExpectedSwiftCode::ContainsAfterTrim(
+            r#"
+public func watch_files() -> RustWatch<FileChanged> {
+    RustWatch<FileChanged>(ptr: __swift_bridge__$watch_files(), next: { __swift_bridge__$FileChanged$_watch_next($0).intoSwiftRepr() }, free: { __swift_bridge__$FileChanged$_watch_free($0) })
+}
+"#,
+        )
+    }
+
+    fn expected_c_header() -> ExpectedCHeader {
+        ExpectedCHeader::ContainsManyAfterTrim(vec![
+            r#"
+void* __swift_bridge__$watch_files(void);
+"#,
+            r#"
+struct __swift_bridge__$Option$FileChanged __swift_bridge__$FileChanged$_watch_next(void* self);
+void __swift_bridge__$FileChanged$_watch_free(void* self);
+"#,
+        ])
+    }
+
+    #[test]
+    fn extern_rust_fn_return_watch() {
+        CodegenTest {
+            bridge_module: bridge_module_tokens().into(),
+            expected_rust_tokens: expected_rust_tokens(),
+            expected_swift_code: expected_swift_code(),
+            expected_c_header: expected_c_header(),
+        }
+        .test();
+    }
+}
@@ -1,13 +1,18 @@
 //! Tests can be found in src/codegen/codegen_tests.rs and its submodules.
 
+use crate::bridged_type::bridgeable_arc::generate_arc_c_support;
 use crate::bridged_type::shared_struct::StructField;
-use crate::bridged_type::{BridgeableType, BridgedType, CFfiStruct, StdLibType, StructFields};
+use crate::bridged_type::{
+    BridgeableType, BridgedType, CFfiStruct, SharedStruct, StdLibType, StructFields,
+};
+use crate::codegen::hash_symbols::{hash_c_header_link_names, HashedSymbol};
 use crate::codegen::CodegenConfig;
 use crate::parse::{SharedTypeDeclaration, TypeDeclaration, TypeDeclarations};
 use crate::parsed_extern_fn::ParsedExternFn;
-use crate::{SwiftBridgeModule, SWIFT_BRIDGE_PREFIX};
+use crate::SwiftBridgeModule;
+use quote::ToTokens;
 use std::collections::{BTreeSet, HashSet};
-use syn::{FnArg, ReturnType};
+use syn::{FnArg, ReturnType, Type};
 
 const NOTICE: &'static str = "// File automatically generated by swift-bridge.";
 
@@ -30,16 +35,36 @@ struct CFfiStructDeclarationBookkeeping {
 
 impl SwiftBridgeModule {
     /// Generate the contents of a C header file based on the contents of this module.
+    #[cfg(test)]
     pub(crate) fn generate_c_header(&self, config: &CodegenConfig) -> String {
+        self.generate_c_header_with_hashed_symbols(config, &mut vec![])
+    }
+
+    /// Generate the contents of a C header file, pushing any link names that are replaced because
+    /// of `#[swift_bridge(hash_symbols)]` to `hashed_symbols`.
+    pub(crate) fn generate_c_header_with_hashed_symbols(
+        &self,
+        config: &CodegenConfig,
+        hashed_symbols: &mut Vec<HashedSymbol>,
+    ) -> String {
         format!(
             r#"{notice}
 {header}"#,
             notice = NOTICE,
-            header = self.generate_c_header_inner(config)
+            header = self.generate_c_header_inner_with_hashed_symbols(config, hashed_symbols)
         )
     }
 
+    #[cfg(test)]
     pub(crate) fn generate_c_header_inner(&self, config: &CodegenConfig) -> String {
+        self.generate_c_header_inner_with_hashed_symbols(config, &mut vec![])
+    }
+
+    fn generate_c_header_inner_with_hashed_symbols(
+        &self,
+        config: &CodegenConfig,
+        hashed_symbols: &mut Vec<HashedSymbol>,
+    ) -> String {
         let mut header = "".to_string();
 
         if !self.module_will_be_compiled(config) {
@@ -53,7 +78,16 @@ impl SwiftBridgeModule {
             slice_types: HashSet::new(),
         };
 
-        for ty in self.types.types() {
+        // Types such as `[T; N]` that are used in shared struct fields need to be declared before
+        // the structs that contain them.
+        let mut shared_struct_field_c_ffi_struct_bookkeeping = CFfiStructDeclarationBookkeeping {
+            encountered_custom_type_declarations: HashSet::new(),
+            custom_type_declarations: Vec::new(),
+        };
+
+        let opaque_rust_types_in_arc = self.opaque_rust_types_in_arc();
+
+        for ty in types_in_c_declaration_order(&self.types) {
             match ty {
                 TypeDeclaration::Shared(ty) => match ty {
                     SharedTypeDeclaration::Struct(ty_struct) => {
@@ -61,7 +95,14 @@ impl SwiftBridgeModule {
                             continue;
                         }
 
-                        let name = ty_struct.swift_name_string();
+                        if ty_struct.is_class() {
+                            header += &self.generate_shared_struct_class_c_functions(
+                                ty_struct,
+                                &mut bookkeeping,
+                            );
+                            continue;
+                        }
+
                         let ffi_name = ty_struct.ffi_name_string();
                         let option_ffi_name = ty_struct.ffi_option_name_string();
 
@@ -89,6 +130,15 @@ impl SwiftBridgeModule {
                                             }
                                         }
 
+                                        if let Some(declaration) =
+                                            ty.generate_custom_c_ffi_types(&self.types)
+                                        {
+                                            push_custom_type_declarations(
+                                                &declaration,
+                                                &mut shared_struct_field_c_ffi_struct_bookkeeping,
+                                            );
+                                        }
+
                                         let name = field.swift_name_string();
 
                                         fields.push(format!("{} {}", ty.to_c(&self.types), name));
@@ -104,6 +154,15 @@ impl SwiftBridgeModule {
                                             }
                                         }
 
+                                        if let Some(declaration) =
+                                            ty.generate_custom_c_ffi_types(&self.types)
+                                        {
+                                            push_custom_type_declarations(
+                                                &declaration,
+                                                &mut shared_struct_field_c_ffi_struct_bookkeeping,
+                                            );
+                                        }
+
                                         let name = format!("_{}", idx);
 
                                         fields.push(format!("{} {}", ty.to_c(&self.types), name));
@@ -117,6 +176,8 @@ impl SwiftBridgeModule {
                             }
                         }
 
+                        ty_struct.layout.align_c_fields(&mut fields);
+
                         let maybe_fields = if fields.len() > 0 {
                             let mut maybe_fields = " ".to_string();
 
@@ -128,14 +189,30 @@ impl SwiftBridgeModule {
                             "".to_string()
                         };
 
+                        let struct_decl = ty_struct.layout.wrap_c_declaration(format!(
+                            r#"typedef struct {ffi_name} {{{maybe_fields}}} {ffi_name};"#,
+                            ffi_name = ffi_name,
+                            maybe_fields = maybe_fields
+                        ));
+                        let mut maybe_functions = if ty_struct.supports_vec() {
+                            bookkeeping.includes.insert("stdint.h");
+                            vec_transparent_struct_c_support(&ty_struct.swift_name_string())
+                        } else {
+                            "".to_string()
+                        };
+                        if ty_struct.has_serde_json_round_trip() {
+                            maybe_functions += &format!(
+                                "\nstruct __private__ResultPtrAndPtr __swift_bridge__${}$_serde_json_round_trip(void* json);",
+                                ty_struct.swift_name_string()
+                            );
+                        }
+
                         let ty_decl = format!(
-                            r#"typedef struct {prefix}${name} {{{maybe_fields}}} {prefix}${name};
-typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi_name};"#,
-                            prefix = SWIFT_BRIDGE_PREFIX,
+                            r#"{struct_decl}
+typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi_name};{maybe_functions}"#,
+                            struct_decl = struct_decl,
                             ffi_name = ffi_name,
                             option_ffi_name = option_ffi_name,
-                            name = name,
-                            maybe_fields = maybe_fields
                         );
 
                         header += &ty_decl;
@@ -160,15 +237,27 @@ typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi
                         let mut variants = "".to_string();
 
                         for variant in ty_enum.variants.iter() {
-                            let variant = format!("{}${}, ", ffi_name, variant.name);
+                            let variant = match variant.discriminant {
+                                Some(discriminant) => {
+                                    format!("{}${} = {}, ", ffi_name, variant.name, discriminant)
+                                }
+                                None => format!("{}${}, ", ffi_name, variant.name),
+                            };
                             variants += &variant;
                         }
 
-                        let derive_debug_impl = if ty_enum.derive.debug {
+                        let derive_debug_impl = if ty_enum.derive.debug && all_variants_empty {
                             format!("void* {ffi_name}$Debug({ffi_name} this);")
                         } else {
                             "".to_string()
                         };
+                        let discriminant_check = if ty_enum.discriminant_check {
+                            format!(
+                                "struct RustStr {ffi_name}$_variant_name(uint32_t discriminant);"
+                            )
+                        } else {
+                            "".to_string()
+                        };
 
                         let maybe_vec_support = if ty_enum.has_one_or_more_variants_with_data() {
                             "".to_string()
@@ -181,7 +270,7 @@ typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi
                                 r#"typedef enum {ffi_tag_name} {{ {variants}}} {ffi_tag_name};
 typedef struct {ffi_name} {{ {ffi_tag_name} tag; }} {ffi_name};
 typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi_name};
-{derive_debug_impl}{maybe_vec_support}"#,
+{derive_debug_impl}{discriminant_check}{maybe_vec_support}"#,
                                 ffi_name = ffi_name,
                                 ffi_tag_name = ffi_tag_name,
                                 option_ffi_name = option_ffi_name,
@@ -243,7 +332,7 @@ typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi
 typedef enum {ffi_tag_name} {{ {variants}}} {ffi_tag_name};
 typedef struct {ffi_name} {{ {ffi_tag_name} tag; union {ffi_union_name} payload;}} {ffi_name};
 typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi_name};
-{derive_debug_impl}{maybe_vec_support}"#,
+{derive_debug_impl}{discriminant_check}{maybe_vec_support}"#,
                                 union_fields = ffi_union_field_names,
                                 variant_fields = variant_fields,
                                 ffi_name = ffi_name,
@@ -274,7 +363,9 @@ typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi
                         let ty_name = ty.ty_name_ident();
                         let hash_ty =
                             format!("uint64_t __swift_bridge__${}$_hash(void* self);", ty_name);
+                        bookkeeping.includes.insert("stdint.h");
                         header += &hash_ty;
+                        header += "\n";
                     }
                     if ty.attributes.equatable {
                         let ty_name = ty.ty_name_ident();
@@ -287,6 +378,60 @@ typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi
                         header += &equal_ty;
                         header += "\n";
                     }
+                    if ty.attributes.comparable {
+                        let ty_name = ty.ty_name_ident();
+                        let less_than_ty = format!(
+                            "bool __swift_bridge__${}$_less_than(void* lhs, void* rhs);",
+                            ty_name
+                        );
+                        bookkeeping.includes.insert("stdbool.h");
+                        header += &less_than_ty;
+                        header += "\n";
+                    }
+                    if ty.attributes.clone {
+                        let ty_name = ty.ty_name_ident();
+                        let clone_fn =
+                            format!("void* __swift_bridge__${}$_clone(void* self);", ty_name);
+                        header += &clone_fn;
+                        header += "\n";
+                    }
+                    if ty.attributes.custom_string_convertible {
+                        let ty_name = ty.ty_name_ident();
+                        let description_fn = format!(
+                            "void* __swift_bridge__${}$_description(void* self);",
+                            ty_name
+                        );
+                        header += &description_fn;
+                        header += "\n";
+                    }
+                    if ty.attributes.custom_debug_string_convertible {
+                        let ty_name = ty.ty_name_ident();
+                        let debug_description_fn = format!(
+                            "void* __swift_bridge__${}$_debug_description(void* self);",
+                            ty_name
+                        );
+                        header += &debug_description_fn;
+                        header += "\n";
+                    }
+                    if ty.attributes.error {
+                        let ty_name = ty.ty_name_ident();
+                        let error_description_fn = format!(
+                            "void* __swift_bridge__${}$_error_description(void* self);",
+                            ty_name
+                        );
+                        header += &error_description_fn;
+                        header += "\n";
+                    }
+                    if ty.attributes.identity_map {
+                        let ty_name = ty.ty_name_ident();
+                        let identity_fn = format!(
+                            "uintptr_t __swift_bridge__${}$_object_identity(void* self);",
+                            ty_name
+                        );
+                        bookkeeping.includes.insert("stdint.h");
+                        header += &identity_fn;
+                        header += "\n";
+                    }
                     let ty_name = ty.to_string();
 
                     if let Some(copy) = ty.attributes.copy {
@@ -335,12 +480,19 @@ typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi
 
                         header += &vec_functions;
                         header += "\n";
+
+                        if opaque_rust_types_in_arc.contains(&ty_name) {
+                            header += &generate_arc_c_support(&ty_name);
+                            header += "\n";
+                        }
                     }
                 }
             }
         }
         let mut c_ffi_struct_bookkeeping = CFfiStructDeclarationBookkeeping {
-            encountered_custom_type_declarations: HashSet::new(),
+            encountered_custom_type_declarations: shared_struct_field_c_ffi_struct_bookkeeping
+                .encountered_custom_type_declarations
+                .clone(),
             custom_type_declarations: Vec::new(),
         };
 
@@ -360,6 +512,32 @@ typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi
             }
 
             header += &declare_func(&func, &mut bookkeeping, &self.types);
+
+            for (idx, boxed_fn) in func.args_filtered_to_boxed_fns(&self.types) {
+                header += &func.boxed_fn_to_c_header_fns(idx, &boxed_fn, &self.types);
+                header += "\n";
+            }
+        }
+
+        for constant in &self.constants {
+            if let Some(include) = constant.c_includes() {
+                bookkeeping.includes.insert(include);
+            }
+            header += &constant.to_c_header();
+        }
+
+        if let Some(table_name) = &self.module_table {
+            header += &format!(
+                "struct __private__ModuleTable __swift_bridge__${}$module_table(void);\n",
+                table_name.value()
+            );
+        }
+
+        if let Some(reflection_name) = &self.reflection {
+            header += &format!(
+                "const struct __private__ModuleReflection* __swift_bridge__${}$reflection(void);\n",
+                reflection_name.value()
+            );
         }
 
         for slice_ty in bookkeeping.slice_types.iter() {
@@ -371,6 +549,14 @@ typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi
             )
         }
 
+        for custom_type_declaration in shared_struct_field_c_ffi_struct_bookkeeping
+            .custom_type_declarations
+            .iter()
+            .rev()
+        {
+            header = format!("{custom_type_declaration}\n{header}");
+        }
+
         let mut includes = bookkeeping.includes.iter().collect::<Vec<_>>();
         includes.sort();
         for include in includes {
@@ -384,8 +570,96 @@ typedef struct {option_ffi_name} {{ bool is_some; {ffi_name} val; }} {option_ffi
             header += &custom_type_declaration;
             header += "\n";
         }
+
+        if self.hash_symbols {
+            header = hash_c_header_link_names(&header, hashed_symbols);
+        }
+
         header
     }
+
+    /// Declare the functions that Swift uses to create, free, read and write the fields of a
+    /// `swift_repr = "class"` struct.
+    fn generate_shared_struct_class_c_functions(
+        &self,
+        ty_struct: &SharedStruct,
+        bookkeeping: &mut Bookkeeping,
+    ) -> String {
+        let prefix = format!("__swift_bridge__${}", ty_struct.swift_name_string());
+
+        let mut params = vec![];
+        let mut accessors = "".to_string();
+        for field in ty_struct.fields.normalized_fields() {
+            let ty = BridgedType::new_with_type(&field.ty, &self.types).unwrap();
+            if let Some(includes) = ty.to_c_include(&self.types) {
+                for include in includes {
+                    bookkeeping.includes.insert(include);
+                }
+            }
+
+            let c_ty = ty.to_c(&self.types);
+            let field_name = field.ffi_field_name();
+
+            params.push(format!("{} {}", c_ty, field_name));
+            accessors += &format!(
+                "{c_ty} {prefix}$get${field_name}(void* self);\nvoid {prefix}$set${field_name}(void* self, {c_ty} value);\n"
+            );
+        }
+
+        format!(
+            "void* {prefix}$new({params});\nvoid {prefix}$_free(void* self);\n{accessors}",
+            params = params.join(", ")
+        )
+    }
+}
+
+/// The module's types, with each shared struct or enum placed after the shared types that its
+/// fields contain, since C requires a struct to be declared before it can be used as a field.
+fn types_in_c_declaration_order(types: &TypeDeclarations) -> Vec<&TypeDeclaration> {
+    let mut ordered = vec![];
+    for ty in types.types() {
+        push_after_field_types(ty, types, &mut ordered);
+    }
+    ordered
+}
+
+fn push_after_field_types<'a>(
+    ty: &'a TypeDeclaration,
+    types: &'a TypeDeclarations,
+    ordered: &mut Vec<&'a TypeDeclaration>,
+) {
+    if ordered.iter().any(|other| std::ptr::eq(*other, ty)) {
+        return;
+    }
+
+    let field_types: Vec<&Type> = match ty {
+        TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct)) => {
+            shared_struct.fields.types()
+        }
+        TypeDeclaration::Shared(SharedTypeDeclaration::Enum(shared_enum)) => shared_enum
+            .variants
+            .iter()
+            .flat_map(|variant| variant.fields.types())
+            .collect(),
+        TypeDeclaration::Opaque(_) => vec![],
+    };
+
+    // Push ourselves first so that a type that (incorrectly) contains itself can't recurse forever.
+    let idx = ordered.len();
+    ordered.push(ty);
+
+    for field_ty in field_types {
+        // Finds the shared types inside of fields such as `Point`, `Option<Point>` or `[Point; 2]`.
+        let field_ty = field_ty.to_token_stream().to_string();
+        for word in field_ty.split(|c: char| !(c.is_alphanumeric() || c == '_')) {
+            if let Some(dependency @ TypeDeclaration::Shared(_)) = types.get(word) {
+                push_after_field_types(dependency, types, ordered);
+            }
+        }
+    }
+
+    let ty = ordered.remove(idx);
+    ordered.push(ty);
 }
 
 fn vec_opaque_rust_type_c_support(ty_name: &str) -> String {
@@ -420,6 +694,21 @@ void* __swift_bridge__$Vec_{enum_name}$as_ptr(void* vec_ptr);
     )
 }
 
+fn vec_transparent_struct_c_support(struct_name: &str) -> String {
+    format!(
+        r#"
+void* __swift_bridge__$Vec_{struct_name}$new(void);
+void __swift_bridge__$Vec_{struct_name}$drop(void* vec_ptr);
+void __swift_bridge__$Vec_{struct_name}$push(void* vec_ptr, __swift_bridge__${struct_name} item);
+__swift_bridge__$Option${struct_name} __swift_bridge__$Vec_{struct_name}$pop(void* vec_ptr);
+__swift_bridge__$Option${struct_name} __swift_bridge__$Vec_{struct_name}$get(void* vec_ptr, uintptr_t index);
+__swift_bridge__$Option${struct_name} __swift_bridge__$Vec_{struct_name}$get_mut(void* vec_ptr, uintptr_t index);
+uintptr_t __swift_bridge__$Vec_{struct_name}$len(void* vec_ptr);
+void* __swift_bridge__$Vec_{struct_name}$as_ptr(void* vec_ptr);"#,
+        struct_name = struct_name
+    )
+}
+
 fn push_custom_type_declarations(
     custom_type_declaration: &CFfiStruct,
     c_ffi_struct_bookkeeping: &mut CFfiStructDeclarationBookkeeping,
@@ -478,7 +767,9 @@ fn declare_func(
     if let ReturnType::Type(_, ty) = &func.func.sig.output {
         if let Some(ty) = BridgedType::new_with_type(&ty, types) {
             if let BridgedType::StdLib(StdLibType::RefSlice(ref_slice)) = ty {
-                bookkeeping.slice_types.insert(ref_slice.ty.to_c(types));
+                if ref_slice.opaque_rust_element.is_none() {
+                    bookkeeping.slice_types.insert(ref_slice.ty.to_c(types));
+                }
             }
         }
     }
@@ -6,11 +6,17 @@ use proc_macro2::TokenStream;
 use quote::ToTokens;
 use quote::{quote, quote_spanned};
 
+use self::module_table::generate_module_table;
+use self::reflection::{generate_reflection, record_reflected_call, ReflectedFunctionEntry};
 use self::vec::vec_of_opaque_rust_type::generate_vec_of_opaque_rust_type_functions;
 use crate::bridge_module_attributes::CfgAttr;
+use crate::bridged_type::bridgeable_arc::generate_arc_rust_support;
+use crate::codegen::hash_symbols::hash_rust_link_names;
 use crate::parse::{HostLang, SharedTypeDeclaration, TypeDeclaration};
 use crate::SwiftBridgeModule;
 
+mod module_table;
+mod reflection;
 mod shared_enum;
 mod shared_struct;
 mod vec;
@@ -31,19 +37,55 @@ impl ToTokens for SwiftBridgeModule {
         let mut callbacks_support = vec![];
         let mut freestanding_rust_call_swift_fn_tokens = vec![];
         let mut extern_swift_fn_tokens = vec![];
+        let mut module_table_entries = vec![];
+        let mut reflected_functions = vec![];
+
+        for (idx, func) in self.functions.iter().enumerate() {
+            if self.reflection.is_some() {
+                reflected_functions.push(ReflectedFunctionEntry {
+                    name: func.sig.ident.to_string(),
+                    type_name: func.associated_type.as_ref().map(|ty| match ty {
+                        TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct)) => {
+                            shared_struct
+                                .rust_type_tokens()
+                                .to_string()
+                                .replace(" ", "")
+                        }
+                        TypeDeclaration::Shared(SharedTypeDeclaration::Enum(shared_enum)) => {
+                            shared_enum.name.to_string()
+                        }
+                        TypeDeclaration::Opaque(opaque) => opaque.ty.to_string(),
+                    }),
+                    link_name: func.link_name(),
+                    is_rust: func.host_lang.is_rust(),
+                });
+            }
 
-        for func in &self.functions {
             match func.host_lang {
                 HostLang::Rust => {
-                    extern_rust_fn_tokens.push(func.to_extern_c_function_tokens(
+                    module_table_entries.push((func.link_name(), func.prefixed_fn_name()));
+                    let tokens = func.to_extern_c_function_tokens(
                         &self.swift_bridge_path,
                         &self.types,
                         &mut custom_type_definitions,
-                    ));
+                    );
+                    let tokens = if self.reflection.is_some() {
+                        record_reflected_call(tokens, idx)
+                    } else {
+                        tokens
+                    };
+                    extern_rust_fn_tokens.push(tokens);
+                    callbacks_support
+                        .push(func.swift_closures_support(&self.swift_bridge_path, &self.types));
                 }
                 HostLang::Swift => {
                     let tokens = func
                         .to_rust_fn_that_calls_a_swift_extern(&self.swift_bridge_path, &self.types);
+                    let tokens = if self.reflection.is_some() {
+                        record_reflected_call(tokens, idx)
+                    } else {
+                        tokens
+                    };
                     callbacks_support
                         .push(func.callbacks_support(&self.swift_bridge_path, &self.types));
 
@@ -74,6 +116,8 @@ impl ToTokens for SwiftBridgeModule {
             };
         }
 
+        let opaque_rust_types_in_arc = self.opaque_rust_types_in_arc();
+
         for ty in &self.types.types() {
             match ty {
                 TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct)) => {
@@ -138,6 +182,126 @@ impl ToTokens for SwiftBridgeModule {
                                 };
                                 extern_rust_fn_tokens.push(tokens);
                             }
+                            if ty.attributes.comparable {
+                                let export_name =
+                                    format!("__swift_bridge__${}$_less_than", ty_name);
+                                let function_name = syn::Ident::new(
+                                    &format!("__swift_bridge__{}__less_than", ty_name),
+                                    ty.ty.span(),
+                                );
+                                let tokens = quote! {
+                                    #[export_name = #export_name]
+                                    pub extern "C" fn #function_name (
+                                        lhs: *const super::#ty_name,
+                                        rhs: *const super::#ty_name
+                                    ) -> bool {
+                                        unsafe { &*lhs < &*rhs }
+                                    }
+                                };
+                                extern_rust_fn_tokens.push(tokens);
+                            }
+                            if ty.attributes.clone {
+                                let export_name = format!("__swift_bridge__${}$_clone", ty_name);
+                                let function_name = syn::Ident::new(
+                                    &format!("__swift_bridge__{}__clone", ty_name),
+                                    ty.ty.span(),
+                                );
+                                let tokens = quote! {
+                                    #[export_name = #export_name]
+                                    pub extern "C" fn #function_name (
+                                        this: *const super::#ty_name,
+                                    ) -> *mut super::#ty_name {
+                                        Box::into_raw(Box::new((unsafe { &*this }).clone()))
+                                    }
+                                };
+                                extern_rust_fn_tokens.push(tokens);
+                            }
+                            if ty.attributes.custom_string_convertible {
+                                let export_name =
+                                    format!("__swift_bridge__${}$_description", ty_name);
+                                let function_name = syn::Ident::new(
+                                    &format!("__swift_bridge__{}__description", ty_name),
+                                    ty.ty.span(),
+                                );
+                                let tokens = quote! {
+                                    #[export_name = #export_name]
+                                    pub extern "C" fn #function_name (
+                                        this: *const super::#ty_name,
+                                    ) -> *mut #swift_bridge_path::string::RustString {
+                                        #swift_bridge_path::string::RustString(
+                                            (unsafe { &*this }).to_string()
+                                        ).box_into_raw()
+                                    }
+                                };
+                                extern_rust_fn_tokens.push(tokens);
+                            }
+                            if ty.attributes.custom_debug_string_convertible {
+                                let export_name =
+                                    format!("__swift_bridge__${}$_debug_description", ty_name);
+                                let function_name = syn::Ident::new(
+                                    &format!("__swift_bridge__{}__debug_description", ty_name),
+                                    ty.ty.span(),
+                                );
+                                let tokens = quote! {
+                                    #[export_name = #export_name]
+                                    pub extern "C" fn #function_name (
+                                        this: *const super::#ty_name,
+                                    ) -> *mut #swift_bridge_path::string::RustString {
+                                        #swift_bridge_path::string::RustString(
+                                            format!("{:?}", unsafe { &*this })
+                                        ).box_into_raw()
+                                    }
+                                };
+                                extern_rust_fn_tokens.push(tokens);
+                            }
+                            if ty.attributes.error {
+                                let export_name =
+                                    format!("__swift_bridge__${}$_error_description", ty_name);
+                                let function_name = syn::Ident::new(
+                                    &format!("__swift_bridge__{}__error_description", ty_name),
+                                    ty.ty.span(),
+                                );
+                                let tokens = quote! {
+                                    #[export_name = #export_name]
+                                    pub extern "C" fn #function_name (
+                                        this: *const super::#ty_name,
+                                    ) -> *mut #swift_bridge_path::string::RustString {
+                                        #swift_bridge_path::string::RustString(
+                                            (unsafe { &*this }).to_string()
+                                        ).box_into_raw()
+                                    }
+                                };
+                                extern_rust_fn_tokens.push(tokens);
+                            }
+                            if ty.attributes.identity_map {
+                                let export_name =
+                                    format!("__swift_bridge__${}$_object_identity", ty_name);
+                                let function_name = syn::Ident::new(
+                                    &format!("__swift_bridge__{}__object_identity", ty_name),
+                                    ty.ty.span(),
+                                );
+                                let tokens = quote! {
+                                    #[export_name = #export_name]
+                                    pub extern "C" fn #function_name (
+                                        this: *const super::#ty_name,
+                                    ) -> usize {
+                                        <super::#ty_name as #swift_bridge_path::identity::ObjectIdentity>::object_identity(unsafe { &*this })
+                                    }
+                                };
+                                extern_rust_fn_tokens.push(tokens);
+                            }
+                            // Generic types don't support `Sendable`, since each of their
+                            // instantiations shares the same Swift classes.
+                            if ty.attributes.sendable && ty.generics.is_empty() {
+                                let assert_send_sync = quote_spanned! {ty.ty.span()=>
+                                    const _: () = {
+                                        fn _assert_send_sync() {
+                                            #swift_bridge_path::sendable_support::assert_send_sync::<super::#ty_name>();
+                                        }
+                                    };
+                                };
+                                extern_rust_fn_tokens.push(assert_send_sync);
+                            }
                             if let Some(copy) = ty.attributes.copy {
                                 let size = copy.size_bytes;
 
@@ -205,6 +369,8 @@ impl ToTokens for SwiftBridgeModule {
                                     };
 
                                     extern_rust_fn_tokens.push(free);
+                                    module_table_entries
+                                        .push((link_name.clone(), free_mem_func_name.clone()));
 
                                     // TODO: Support Vec<OpaqueCopyType>. Add codegen tests and then
                                     //  make them pass.
@@ -214,6 +380,11 @@ impl ToTokens for SwiftBridgeModule {
                                             generate_vec_of_opaque_rust_type_functions(ty_name);
                                         extern_rust_fn_tokens.push(vec_functions);
                                     }
+
+                                    if opaque_rust_types_in_arc.contains(&ty_name.to_string()) {
+                                        extern_rust_fn_tokens
+                                            .push(generate_arc_rust_support(ty_name));
+                                    }
                                 }
                             }
                         }
@@ -258,6 +429,51 @@ impl ToTokens for SwiftBridgeModule {
             }
         }
 
+        if let Some(table_name) = &self.module_table {
+            extern_rust_fn_tokens.push(generate_module_table(
+                table_name,
+                &module_table_entries,
+                swift_bridge_path,
+            ));
+        }
+
+        if let Some(reflection_name) = &self.reflection {
+            let reflected_types: Vec<(String, TokenStream)> = self
+                .types
+                .types()
+                .into_iter()
+                .filter_map(|ty| match ty {
+                    TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct)) => {
+                        Some((
+                            shared_struct
+                                .rust_type_tokens()
+                                .to_string()
+                                .replace(" ", ""),
+                            quote! { SharedStruct },
+                        ))
+                    }
+                    TypeDeclaration::Shared(SharedTypeDeclaration::Enum(shared_enum)) => {
+                        Some((shared_enum.name.to_string(), quote! { SharedEnum }))
+                    }
+                    TypeDeclaration::Opaque(opaque) if opaque.attributes.declare_generic => None,
+                    TypeDeclaration::Opaque(opaque) => {
+                        let kind = match opaque.host_lang {
+                            HostLang::Rust => quote! { OpaqueRust },
+                            HostLang::Swift => quote! { OpaqueSwift },
+                        };
+                        Some((opaque.ty.to_string(), kind))
+                    }
+                })
+                .collect();
+
+            extern_rust_fn_tokens.push(generate_reflection(
+                reflection_name,
+                &reflected_types,
+                &reflected_functions,
+                swift_bridge_path,
+            ));
+        }
+
         let extern_swift_fn_tokens = if extern_swift_fn_tokens.len() > 0 {
             quote! {
                 extern "C" {
@@ -281,7 +497,23 @@ impl ToTokens for SwiftBridgeModule {
             };
         }
         let custom_type_definitions = custom_type_definitions.into_values();
+
+        // Tells Cargo to expand the module again when one of its included files changes.
+        let included_files = self.included_files.iter().map(|path| {
+            let path = path.to_string_lossy().to_string();
+            quote! {
+                const _: &[u8] = include_bytes!(#path);
+            }
+        });
+
+        let constants = self
+            .constants
+            .iter()
+            .map(|constant| constant.to_rust_tokens());
+
         let module_inner = quote! {
+            #(#constants)*
+
             #(#shared_struct_definitions)*
 
             #(#shared_enum_definitions)*
@@ -297,6 +529,14 @@ impl ToTokens for SwiftBridgeModule {
             #extern_swift_fn_tokens
 
             #(#callbacks_support)*
+
+            #(#included_files)*
+        };
+
+        let module_inner = if self.hash_symbols {
+            hash_rust_link_names(module_inner)
+        } else {
+            module_inner
         };
 
         let t = quote! {
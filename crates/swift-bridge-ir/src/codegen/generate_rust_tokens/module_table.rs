@@ -0,0 +1,55 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn::{LitStr, Path};
+
+/// Generate an exported function that returns a table of the module's exported functions.
+///
+/// ```no_run,ignore
+/// #[export_name = "__swift_bridge__$my_plugin$module_table"]
+/// pub extern "C" fn __swift_bridge__my_plugin_module_table() -> swift_bridge::module_table::ModuleTable {
+///     static ENTRIES: [swift_bridge::module_table::ModuleTableEntry; 1] = [
+///         swift_bridge::module_table::ModuleTableEntry {
+///             name: "__swift_bridge__$some_function\0".as_ptr() as *const std::os::raw::c_char,
+///             symbol: __swift_bridge__some_function as *const std::ffi::c_void,
+///         },
+///     ];
+///     // ...
+/// }
+/// ```
+pub(super) fn generate_module_table(
+    table_name: &LitStr,
+    entries: &[(String, Ident)],
+    swift_bridge_path: &Path,
+) -> TokenStream {
+    let export_name = format!("__swift_bridge__${}$module_table", table_name.value());
+    let fn_name = format_ident!(
+        "__swift_bridge__{}_module_table",
+        table_name.value(),
+        span = table_name.span()
+    );
+
+    let len = entries.len();
+    let entries = entries.iter().map(|(link_name, fn_ident)| {
+        let name = format!("{}\0", link_name);
+        quote! {
+            #swift_bridge_path::module_table::ModuleTableEntry {
+                name: #name.as_ptr() as *const std::os::raw::c_char,
+                symbol: #fn_ident as *const std::ffi::c_void
+            }
+        }
+    });
+
+    quote! {
+        #[export_name = #export_name]
+        pub extern "C" fn #fn_name() -> #swift_bridge_path::module_table::ModuleTable {
+            static ENTRIES: [#swift_bridge_path::module_table::ModuleTableEntry; #len] = [
+                #(#entries),*
+            ];
+
+            #swift_bridge_path::module_table::ModuleTable {
+                entries: ENTRIES.as_ptr(),
+                len: #len
+            }
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use crate::codegen::hash_symbols::fnv1a;
+use proc_macro2::{Group, TokenStream, TokenTree};
+use quote::{format_ident, quote};
+use syn::{LitStr, Path};
+
+/// A function in a `#[swift_bridge(reflection = "...")]` module's registry.
+pub(super) struct ReflectedFunctionEntry {
+    pub name: String,
+    pub type_name: Option<String>,
+    pub link_name: String,
+    pub is_rust: bool,
+}
+
+/// Generate the statics that hold a module's registry of types and functions, along with an
+/// exported function that Swift uses to look it up.
+///
+/// ```no_run,ignore
+/// static __SWIFT_BRIDGE_REFLECTED_TYPES: [swift_bridge::reflection::ReflectedType; 1] = [
+///     swift_bridge::reflection::ReflectedType {
+///         name: "SomeType\0".as_ptr() as *const std::os::raw::c_char,
+///         id: 1234567890,
+///         kind: swift_bridge::reflection::ReflectedTypeKind::OpaqueRust,
+///     },
+/// ];
+/// static __SWIFT_BRIDGE_REFLECTED_FUNCTIONS: [swift_bridge::reflection::ReflectedFunction; 1] = [
+///     // ...
+/// ];
+/// pub static REFLECTION: swift_bridge::reflection::ModuleReflection = // ...
+///
+/// #[export_name = "__swift_bridge__$my_module$reflection"]
+/// pub extern "C" fn __swift_bridge__my_module_reflection() -> &'static swift_bridge::reflection::ModuleReflection {
+///     &REFLECTION
+/// }
+/// ```
+pub(super) fn generate_reflection(
+    reflection_name: &LitStr,
+    types: &[(String, TokenStream)],
+    functions: &[ReflectedFunctionEntry],
+    swift_bridge_path: &Path,
+) -> TokenStream {
+    let export_name = format!("__swift_bridge__${}$reflection", reflection_name.value());
+    let fn_name = format_ident!(
+        "__swift_bridge__{}_reflection",
+        reflection_name.value(),
+        span = reflection_name.span()
+    );
+    let module_name = c_str(&reflection_name.value());
+
+    let types_len = types.len();
+    let types = types.iter().map(|(name, kind)| {
+        let id = fnv1a(name);
+        let name = c_str(name);
+        quote! {
+            #swift_bridge_path::reflection::ReflectedType {
+                name: #name,
+                id: #id,
+                kind: #swift_bridge_path::reflection::ReflectedTypeKind::#kind
+            }
+        }
+    });
+
+    let functions_len = functions.len();
+    let functions = functions.iter().map(|function| {
+        let name = c_str(&function.name);
+        let type_name = match &function.type_name {
+            Some(type_name) => c_str(type_name),
+            None => quote! { std::ptr::null() },
+        };
+        let link_name = c_str(&function.link_name);
+        let is_rust = function.is_rust;
+        quote! {
+            #swift_bridge_path::reflection::ReflectedFunction {
+                name: #name,
+                type_name: #type_name,
+                link_name: #link_name,
+                is_rust: #is_rust,
+                calls: std::sync::atomic::AtomicU64::new(0)
+            }
+        }
+    });
+
+    quote! {
+        static __SWIFT_BRIDGE_REFLECTED_TYPES: [#swift_bridge_path::reflection::ReflectedType; #types_len] = [
+            #(#types),*
+        ];
+        static __SWIFT_BRIDGE_REFLECTED_FUNCTIONS: [#swift_bridge_path::reflection::ReflectedFunction; #functions_len] = [
+            #(#functions),*
+        ];
+
+        /// The types and functions that this module declares.
+        pub static REFLECTION: #swift_bridge_path::reflection::ModuleReflection = #swift_bridge_path::reflection::ModuleReflection {
+            name: #module_name,
+            types: __SWIFT_BRIDGE_REFLECTED_TYPES.as_ptr(),
+            types_len: #types_len,
+            functions: __SWIFT_BRIDGE_REFLECTED_FUNCTIONS.as_ptr(),
+            functions_len: #functions_len
+        };
+
+        #[export_name = #export_name]
+        pub extern "C" fn #fn_name() -> &'static #swift_bridge_path::reflection::ModuleReflection {
+            &REFLECTION
+        }
+    }
+}
+
+/// Count a call to the function at the given index of the module's registry, by adding a
+/// statement to the start of the body of the function in the given tokens.
+///
+/// The body is the last `{ ... }` group in the tokens.
+pub(super) fn record_reflected_call(function_tokens: TokenStream, idx: usize) -> TokenStream {
+    let mut tokens: Vec<TokenTree> = function_tokens.into_iter().collect();
+
+    let body_idx = tokens.iter().rposition(|token| {
+        matches!(token, TokenTree::Group(group) if group.delimiter() == proc_macro2::Delimiter::Brace)
+    });
+
+    if let Some(body_idx) = body_idx {
+        if let TokenTree::Group(body) = &tokens[body_idx] {
+            let stmts = body.stream();
+            let mut new_body = Group::new(
+                body.delimiter(),
+                quote! {
+                    __SWIFT_BRIDGE_REFLECTED_FUNCTIONS[#idx].record_call();
+                    #stmts
+                },
+            );
+            new_body.set_span(body.span());
+            tokens[body_idx] = TokenTree::Group(new_body);
+        }
+    }
+
+    tokens.into_iter().collect()
+}
+
+/// `"SomeType"` -> `"SomeType\0".as_ptr() as *const std::os::raw::c_char`
+fn c_str(string: &str) -> TokenStream {
+    let string = format!("{}\0", string);
+    quote! {
+        #string.as_ptr() as *const std::os::raw::c_char
+    }
+}
@@ -5,7 +5,7 @@ use crate::bridged_type::{BridgedType, SharedEnum, StructFields};
 use crate::codegen::generate_rust_tokens::vec::vec_of_transparent_enum::generate_vec_of_transparent_enum_functions;
 use crate::parse::TypeDeclarations;
 use crate::{SwiftBridgeModule, SWIFT_BRIDGE_PREFIX};
-use proc_macro2::TokenStream;
+use proc_macro2::{Literal, TokenStream};
 use quote::{format_ident, quote};
 use syn::Ident;
 
@@ -59,11 +59,19 @@ impl SwiftBridgeModule {
                         #variant_name (#(#names),*)
                     }
                 }
-                StructFields::Unit => {
-                    quote! {
-                        #variant_name
+                StructFields::Unit => match variant.discriminant {
+                    Some(discriminant) => {
+                        let discriminant = Literal::i64_unsuffixed(discriminant);
+                        quote! {
+                            #variant_name = #discriminant
+                        }
                     }
-                }
+                    None => {
+                        quote! {
+                            #variant_name
+                        }
+                    }
+                },
             };
             enum_variants.push(enum_variant);
         }
@@ -98,11 +106,19 @@ impl SwiftBridgeModule {
                         #variant_name (#(#names),*)
                     }
                 }
-                StructFields::Unit => {
-                    quote! {
-                        #variant_name
+                StructFields::Unit => match variant.discriminant {
+                    Some(discriminant) => {
+                        let discriminant = Literal::i64_unsuffixed(discriminant);
+                        quote! {
+                            #variant_name = #discriminant
+                        }
                     }
-                }
+                    None => {
+                        quote! {
+                            #variant_name
+                        }
+                    }
+                },
             };
             enum_ffi_variants.push(enum_ffi_variant);
         }
@@ -140,12 +156,22 @@ impl SwiftBridgeModule {
         // User derives
         let mut derive_impl_ffi_bridges = vec![];
 
-        // We currently only allow derive(Debug) on non data carrying enums in order
+        // Enums without data are always Clone.
+        if shared_enum.derive.clone && shared_enum.has_one_or_more_variants_with_data() {
+            derives.push(quote! {Clone});
+        }
+        if shared_enum.derive.partial_eq {
+            derives.push(quote! {PartialEq});
+        }
+
+        if shared_enum.derive.debug {
+            derives.push(quote! {::std::fmt::Debug});
+        }
+
+        // We currently only expose derive(Debug) to Swift for non data carrying enums in order
         // to prevent a potential memory safety issue.
         // https://github.com/chinedufn/swift-bridge/pull/194#discussion_r1134386788
         if shared_enum.derive.debug && !shared_enum.has_one_or_more_variants_with_data() {
-            derives.push(quote! {::std::fmt::Debug});
-
             // __swift_bridge__$SomeEnum$Debug
             let export_name = format!("{}$Debug", shared_enum.ffi_name_string());
             // __swift_bridge__SomeEnum_Debug
@@ -158,6 +184,57 @@ impl SwiftBridgeModule {
             });
         }
 
+        // Lets Swift check that Rust has the same variant at each discriminant, which catches
+        // variants being reordered between the builds of a prebuilt Rust library and the Swift
+        // code that uses it.
+        let discriminant_check = if shared_enum.discriminant_check {
+            // __swift_bridge__$SomeEnum$_variant_name
+            let export_name = format!("{}$_variant_name", shared_enum.ffi_name_string());
+            // __swift_bridge__SomeEnum__variant_name
+            let fn_name = format_ident!("{}__variant_name", enum_ffi_name);
+            let variant_names = shared_enum.variants.iter().map(|v| v.name.to_string());
+            let variant_count = shared_enum.variants.len();
+
+            quote! {
+                #[export_name = #export_name]
+                pub extern "C" fn #fn_name(discriminant: u32) -> #swift_bridge_path::string::RustStr {
+                    const VARIANT_NAMES: [&str; #variant_count] = [#(#variant_names),*];
+                    #swift_bridge_path::string::RustStr::from_str(
+                        VARIANT_NAMES.get(discriminant as usize).copied().unwrap_or("")
+                    )
+                }
+            }
+        } else {
+            quote! {}
+        };
+
+        // Lets Rust code that receives a raw discriminant, such as one that was stored or sent
+        // over the network, check it against the variants instead of transmuting it.
+        let try_from_discriminant = if shared_enum.try_from_discriminant {
+            let enum_name_string = enum_name.to_string();
+            let variant_names = shared_enum.variants.iter().map(|v| &v.name);
+
+            quote! {
+                impl std::convert::TryFrom<u32> for #enum_name {
+                    type Error = #swift_bridge_path::bridge_error::UnknownVariant;
+
+                    fn try_from(discriminant: u32) -> std::result::Result<Self, Self::Error> {
+                        #(
+                            if discriminant == #enum_name::#variant_names as u32 {
+                                return std::result::Result::Ok(#enum_name::#variant_names);
+                            }
+                        )*
+                        std::result::Result::Err(#swift_bridge_path::bridge_error::UnknownVariant {
+                            enum_name: #enum_name_string,
+                            discriminant,
+                        })
+                    }
+                }
+            }
+        } else {
+            quote! {}
+        };
+
         let vec_support = if shared_enum.has_one_or_more_variants_with_data() {
             // Enums with variants that contain data are not yet supported.
             quote! {}
@@ -236,9 +313,13 @@ impl SwiftBridgeModule {
                 }
             }
 
+            #try_from_discriminant
+
             #vec_support
 
             #(#derive_impl_ffi_bridges),*
+
+            #discriminant_check
         };
 
         Some(definition)
@@ -1,11 +1,14 @@
 //! More tests can be found in
 //! crates/swift-bridge-ir/src/codegen/codegen_tests/shared_struct_codegen_tests.rs
 
-use crate::bridged_type::{BridgedType, SharedStruct};
+use crate::bridged_type::shared_struct::StructLayout;
+use crate::bridged_type::{BridgedType, SharedStruct, StdLibType};
+use crate::codegen::generate_rust_tokens::vec::vec_of_transparent_struct::generate_vec_of_transparent_struct_functions;
 use crate::{SwiftBridgeModule, SWIFT_BRIDGE_PREFIX};
-use proc_macro2::{Span, TokenStream};
-use quote::quote;
-use syn::Ident;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{quote, ToTokens};
+use std::ops::Deref;
+use syn::spanned::Spanned;
 
 impl SwiftBridgeModule {
     /// Generate the tokens for a shared struct.
@@ -18,27 +21,51 @@ impl SwiftBridgeModule {
         }
 
         let struct_name = &shared_struct.name;
+        let struct_type = shared_struct.rust_type_tokens();
         let swift_bridge_path = &self.swift_bridge_path;
 
-        let struct_ffi_name = format!("{}{}", SWIFT_BRIDGE_PREFIX, struct_name);
-        let struct_ffi_name = Ident::new(&struct_ffi_name, struct_name.span());
+        let struct_ffi_name = shared_struct.ffi_name_tokens();
 
         let option_struct = shared_struct.ffi_option_name_tokens();
 
-        let struct_fields: Vec<TokenStream> = shared_struct
-            .fields
+        // A generic struct is declared using its generics, such as `first: T`.
+        let (declaration_generics, declaration_fields) = match &shared_struct.instantiation {
+            Some(instantiation) => {
+                let generics = &instantiation.generics;
+                (quote! { <#(#generics),*> }, &instantiation.generic_fields)
+            }
+            None => (quote! {}, &shared_struct.fields),
+        };
+
+        let struct_fields: Vec<TokenStream> = declaration_fields
             .normalized_fields()
             .iter()
             .map(|norm_field| {
                 let maybe_name_and_colon = norm_field.maybe_name_and_colon();
-                let ty = &norm_field.ty;
+
+                // A pointer's pointee, such as `c_void`, is declared outside of the bridge module.
+                let ty = match BridgedType::new_with_type(&norm_field.ty, &self.types) {
+                    Some(ty @ BridgedType::StdLib(StdLibType::Pointer(_))) => {
+                        ty.to_rust_type_path(&self.types)
+                    }
+                    Some(BridgedType::StdLib(StdLibType::Option(opt)))
+                        if matches!(
+                            opt.ty.deref(),
+                            BridgedType::StdLib(StdLibType::Pointer(_))
+                        ) =>
+                    {
+                        let ty = opt.ty.to_rust_type_path(&self.types);
+                        quote! { Option<#ty> }
+                    }
+                    _ => norm_field.ty.to_token_stream(),
+                };
 
                 quote! {
                     pub #maybe_name_and_colon #ty
                 }
             })
             .collect();
-        let struct_fields = shared_struct.fields.wrap_declaration_fields(&struct_fields);
+        let struct_fields = declaration_fields.wrap_declaration_fields(&struct_fields);
 
         let repr_c_struct_fields: Vec<TokenStream> = shared_struct
             .fields
@@ -74,10 +101,11 @@ impl SwiftBridgeModule {
             &self.types,
         );
 
+        let repr = shared_struct.layout.to_rust_repr();
         let struct_ffi_repr = if shared_struct.fields.is_empty() {
             // Using a u8 is arbitrary... We just need a field since empty structs aren't FFI safe.
             quote! {
-                #[repr(C)]
+                #repr
                 #[doc(hidden)]
                 pub struct #struct_ffi_name {
                     _private: u8
@@ -85,7 +113,7 @@ impl SwiftBridgeModule {
             }
         } else {
             quote! {
-                #[repr(C)]
+                #repr
                 #[doc(hidden)]
                 pub struct #struct_ffi_name #repr_c_struct_fields
             }
@@ -98,14 +126,155 @@ impl SwiftBridgeModule {
         if shared_struct.derives.clone {
             derives.push(quote! {Clone});
         }
+        if shared_struct.derives.equatable || shared_struct.derives.hashable {
+            derives.push(quote! {PartialEq});
+        }
+        if shared_struct.derives.hashable {
+            derives.push(quote! {Eq});
+            derives.push(quote! {Hash});
+        }
+        derives.extend(shared_struct.derives.serde_paths.iter().cloned());
+
+        let changed_fields_impl = if shared_struct.diffable {
+            let field_checks: Vec<TokenStream> = shared_struct
+                .fields
+                .normalized_fields()
+                .iter()
+                .enumerate()
+                .map(|(idx, norm_field)| {
+                    let current = norm_field.append_field_accessor(&quote! {self});
+                    let previous = norm_field.append_field_accessor(&quote! {previous});
+
+                    quote! {
+                        if #current != #previous {
+                            changed_fields |= 1 << #idx;
+                        }
+                    }
+                })
+                .collect();
+
+            quote! {
+                impl #struct_type {
+                    /// Get a bit mask of the fields that differ from the `previous` value.
+                    /// Bit `n` is set if the `n`th field changed.
+                    ///
+                    /// Swift can pass this mask to `apply(_:changedFields:)` to only update the
+                    /// fields that changed.
+                    pub fn changed_fields(&self, previous: &#struct_type) -> u64 {
+                        let mut changed_fields = 0;
+                        #(#field_checks)*
+                        changed_fields
+                    }
+                }
+            }
+        } else {
+            quote! {}
+        };
+
+        let mirror_conversions = shared_struct.generate_mirror_conversions();
+
+        let little_endian_conversions = shared_struct.generate_little_endian_conversions();
+
+        // The struct keeps Rust's layout unless a specific layout was requested.
+        let struct_repr = if shared_struct.layout == StructLayout::default() {
+            quote! {}
+        } else {
+            repr
+        };
+
+        let assert_send_sync = if shared_struct.sendable {
+            quote! {
+                const _: () = {
+                    fn _assert_send_sync() {
+                        #swift_bridge_path::sendable_support::assert_send_sync::<#struct_type>();
+                    }
+                };
+            }
+        } else {
+            quote! {}
+        };
+
+        // The generic struct is only declared once, alongside its first instantiation.
+        let declaration = match &shared_struct.instantiation {
+            Some(instantiation) if !instantiation.is_first => quote! {},
+            _ => quote! {
+                #[derive(#(#derives),*)]
+                #struct_repr
+                pub struct #struct_name #declaration_generics #struct_fields
+            },
+        };
+
+        // A class is passed over FFI as a pointer to a boxed Rust value, so it doesn't need an FFI
+        // representation.
+        if shared_struct.is_class() {
+            let class_functions = self.generate_shared_struct_class_functions(shared_struct);
+
+            return Some(quote! {
+                #declaration
+
+                #assert_send_sync
+
+                #changed_fields_impl
+
+                #mirror_conversions
+
+                #little_endian_conversions
+
+                impl #swift_bridge_path::SharedStruct for #struct_type {
+                    type FfiRepr = *mut #struct_type;
+                }
+
+                #class_functions
+            });
+        }
+
+        let vec_support = if shared_struct.supports_vec() {
+            generate_vec_of_transparent_struct_functions(shared_struct)
+        } else {
+            quote! {}
+        };
+
+        let serde_json_round_trip = if shared_struct.has_serde_json_round_trip() {
+            let export_name = format!(
+                "{}${}$_serde_json_round_trip",
+                SWIFT_BRIDGE_PREFIX,
+                shared_struct.swift_name_string()
+            );
+            let fn_name = Ident::new(
+                &format!(
+                    "{}{}__serde_json_round_trip",
+                    SWIFT_BRIDGE_PREFIX, struct_name
+                ),
+                struct_name.span(),
+            );
+
+            quote! {
+                #[doc(hidden)]
+                #[export_name = #export_name]
+                pub extern "C" fn #fn_name(
+                    json: *mut #swift_bridge_path::string::RustString
+                ) -> #swift_bridge_path::result::ResultPtrAndPtr {
+                    #swift_bridge_path::__serde_json_round_trip!(#struct_type, json)
+                }
+            }
+        } else {
+            quote! {}
+        };
 
         let definition = quote! {
-            #[derive(#(#derives),*)]
-            pub struct #struct_name #struct_fields
+            #declaration
+
+            #assert_send_sync
+
+            #changed_fields_impl
+
+            #mirror_conversions
+
+            #little_endian_conversions
 
             #struct_ffi_repr
 
-            impl #swift_bridge_path::SharedStruct for #struct_name {
+            impl #swift_bridge_path::SharedStruct for #struct_type {
                 type FfiRepr = #struct_ffi_name;
             }
 
@@ -114,7 +283,7 @@ impl SwiftBridgeModule {
             impl #struct_ffi_name {
                 #[doc(hidden)]
                 #[inline(always)]
-                pub fn into_rust_repr(self) -> #struct_name {
+                pub fn into_rust_repr(self) -> #struct_type {
                     #convert_ffi_to_rust
                 }
             }
@@ -129,7 +298,7 @@ impl SwiftBridgeModule {
             impl #option_struct {
                 #[doc(hidden)]
                 #[inline(always)]
-                pub fn into_rust_repr(self) -> Option<#struct_name> {
+                pub fn into_rust_repr(self) -> Option<#struct_type> {
                     if self.is_some {
                         Some(unsafe { self.val.assume_init().into_rust_repr() })
                     } else {
@@ -139,7 +308,7 @@ impl SwiftBridgeModule {
 
                 #[doc(hidden)]
                 #[inline(always)]
-                pub fn from_rust_repr(val: Option<#struct_name>) -> #option_struct {
+                pub fn from_rust_repr(val: Option<#struct_type>) -> #option_struct {
                     if let Some(val) = val {
                         #option_struct {
                             is_some: true,
@@ -153,8 +322,110 @@ impl SwiftBridgeModule {
                     }
                 }
             }
+
+            #vec_support
+
+            #serde_json_round_trip
         };
 
         Some(definition)
     }
+
+    /// Generate the functions that Swift uses to create, free, read and write the fields of a
+    /// `swift_repr = "class"` struct.
+    fn generate_shared_struct_class_functions(&self, shared_struct: &SharedStruct) -> TokenStream {
+        let struct_type = shared_struct.rust_type_tokens();
+        let swift_bridge_path = &self.swift_bridge_path;
+        let types = &self.types;
+
+        let export_name = |suffix: &str| {
+            format!(
+                "{}${}${}",
+                SWIFT_BRIDGE_PREFIX,
+                shared_struct.swift_name_string(),
+                suffix
+            )
+        };
+        let fn_name = |suffix: &str| {
+            Ident::new(
+                &format!("{}{}_{}", SWIFT_BRIDGE_PREFIX, shared_struct.name, suffix),
+                shared_struct.name.span(),
+            )
+        };
+
+        let mut new_params = vec![];
+        let mut new_fields = vec![];
+        let mut accessors = vec![];
+
+        for norm_field in shared_struct.fields.normalized_fields() {
+            let ty = BridgedType::new_with_type(&norm_field.ty, types).unwrap();
+            let ffi_ty = ty.to_ffi_compatible_rust_type(swift_bridge_path, types);
+            let field_name = norm_field.ffi_field_name();
+            let param = Ident::new(&field_name, Span::call_site());
+            let span = norm_field.ty.span();
+
+            let maybe_name_and_colon = norm_field.maybe_name_and_colon();
+            let converted = ty.convert_ffi_expression_to_rust_type(
+                &quote! {#param},
+                span,
+                swift_bridge_path,
+                types,
+            );
+            new_params.push(quote! { #param: #ffi_ty });
+            new_fields.push(quote! { #maybe_name_and_colon #converted });
+
+            let field = norm_field.append_field_accessor(&quote! { this });
+            let get_export_name = export_name(&format!("get${}", field_name));
+            let get_fn_name = fn_name(&format!("get_{}", field_name));
+            let get_val = ty.convert_rust_expression_to_ffi_type(
+                &quote! { #field.clone() },
+                swift_bridge_path,
+                types,
+                span,
+            );
+            let set_export_name = export_name(&format!("set${}", field_name));
+            let set_fn_name = fn_name(&format!("set_{}", field_name));
+            let set_val = ty.convert_ffi_expression_to_rust_type(
+                &quote! { value },
+                span,
+                swift_bridge_path,
+                types,
+            );
+
+            accessors.push(quote! {
+                #[export_name = #get_export_name]
+                pub extern "C" fn #get_fn_name(this: *const #struct_type) -> #ffi_ty {
+                    let this = unsafe { &*this };
+                    #get_val
+                }
+
+                #[export_name = #set_export_name]
+                pub extern "C" fn #set_fn_name(this: *mut #struct_type, value: #ffi_ty) {
+                    let this = unsafe { &mut *this };
+                    #field = #set_val;
+                }
+            });
+        }
+
+        let new_fields = shared_struct.fields.wrap_declaration_fields(&new_fields);
+        let new_export_name = export_name("new");
+        let new_fn_name = fn_name("new");
+        let free_export_name = export_name("_free");
+        let free_fn_name = fn_name("_free");
+        let struct_name = &shared_struct.name;
+
+        quote! {
+            #[export_name = #new_export_name]
+            pub extern "C" fn #new_fn_name(#(#new_params),*) -> *mut #struct_type {
+                Box::into_raw(Box::new(#struct_name #new_fields))
+            }
+
+            #[export_name = #free_export_name]
+            pub extern "C" fn #free_fn_name(this: *mut #struct_type) {
+                let _ = unsafe { Box::from_raw(this) };
+            }
+
+            #(#accessors)*
+        }
+    }
 }
@@ -169,6 +169,10 @@ mod tests {
             already_declared: false,
             swift_name: None,
             derive: DeriveAttrs::default(),
+            discriminant_check: false,
+            non_exhaustive: false,
+            try_from_discriminant: false,
+            swift_attrs: vec![],
         };
         assert_tokens_eq(
             &generate_vec_of_transparent_enum_functions(&shared_enum),
@@ -1,18 +1,21 @@
 use std::collections::HashMap;
+use std::ops::Deref;
 
-use syn::Path;
+use syn::{FnArg, Pat, Path};
 
+use crate::bridged_type::bridgeable_arc::generate_arc_swift_class;
 use crate::bridged_type::{BridgeableType, BridgedType, TypePosition};
 use crate::codegen::generate_swift::generate_function_swift_calls_rust::gen_func_swift_calls_rust;
 use crate::codegen::generate_swift::opaque_copy_type::generate_opaque_copy_struct;
 use crate::codegen::generate_swift::swift_class::generate_swift_class;
 use crate::codegen::generate_swift::vec::generate_vectorizable_extension;
+use crate::codegen::hash_symbols::{hash_swift_cdecl_names, HashedSymbol};
 use crate::codegen::CodegenConfig;
 use crate::parse::{
     HostLang, OpaqueForeignTypeDeclaration, SharedTypeDeclaration, TypeDeclaration,
     TypeDeclarations,
 };
-use crate::parsed_extern_fn::ParsedExternFn;
+use crate::parsed_extern_fn::{ParsedExternFn, StoreRole};
 use crate::SwiftBridgeModule;
 
 mod vec;
@@ -25,7 +28,18 @@ mod swift_class;
 
 impl SwiftBridgeModule {
     /// Generate the corresponding Swift code for the bridging module.
+    #[cfg(test)]
     pub(crate) fn generate_swift(&self, config: &CodegenConfig) -> String {
+        self.generate_swift_with_hashed_symbols(config, &mut vec![])
+    }
+
+    /// Generate the corresponding Swift code for the bridging module, pushing any link names
+    /// that are replaced because of `#[swift_bridge(hash_symbols)]` to `hashed_symbols`.
+    pub(crate) fn generate_swift_with_hashed_symbols(
+        &self,
+        config: &CodegenConfig,
+        hashed_symbols: &mut Vec<HashedSymbol>,
+    ) -> String {
         let mut swift = "".to_string();
 
         if !self.module_will_be_compiled(config) {
@@ -38,6 +52,8 @@ impl SwiftBridgeModule {
 
         for function in &self.functions {
             if function.host_lang.is_rust() {
+                swift += &gen_swift_closures_support(function, &self.types);
+
                 if let Some(ty) = function.associated_type.as_ref() {
                     match ty {
                         TypeDeclaration::Shared(_) => {
@@ -46,13 +62,25 @@ impl SwiftBridgeModule {
                         }
                         TypeDeclaration::Opaque(opaque_ty) => {
                             associated_funcs_and_methods
-                                .entry(opaque_ty.to_string())
+                                .entry(opaque_ty.swift_class_name())
                                 .or_default()
                                 .push(function);
 
-                            if function.is_swift_identifiable {
+                            // `#[swift_bridge(Identifiable = "...")]` on the type names the method.
+                            let type_names_method = match &opaque_ty.attributes.identifiable {
+                                Some(method) => {
+                                    function.is_method()
+                                        && function.func.sig.ident == method.value()
+                                }
+                                None => false,
+                            };
+                            if function.is_swift_identifiable || type_names_method {
                                 let identifiable_protocol = IdentifiableProtocol {
-                                    func_name: function.func.sig.ident.to_string(),
+                                    func_name: function
+                                        .swift_name_override
+                                        .as_ref()
+                                        .map(|name| name.value())
+                                        .unwrap_or(function.func.sig.ident.to_string()),
                                     return_ty: BridgedType::new_with_return_type(
                                         &function.func.sig.output,
                                         &self.types,
@@ -64,10 +92,66 @@ impl SwiftBridgeModule {
                                     ),
                                 };
                                 class_protocols
-                                    .entry(opaque_ty.to_string())
+                                    .entry(opaque_ty.swift_class_name())
                                     .or_default()
                                     .identifiable = Some(identifiable_protocol);
                             }
+
+                            if let Some(store_role) = function.store_role {
+                                let swift_name = function
+                                    .swift_name_override
+                                    .as_ref()
+                                    .map(|name| name.value())
+                                    .unwrap_or(function.sig.ident.to_string());
+                                let type_pos = TypePosition::FnReturn(opaque_ty.host_lang);
+
+                                let store = &mut class_protocols
+                                    .entry(opaque_ty.swift_class_name())
+                                    .or_default()
+                                    .store;
+                                match store_role {
+                                    StoreRole::State => {
+                                        store.state = Some(StoreStateMethod {
+                                            func_name: swift_name,
+                                            state_ty: BridgedType::new_with_return_type(
+                                                &function.func.sig.output,
+                                                &self.types,
+                                            )
+                                            .unwrap()
+                                            .to_swift_type(type_pos, &self.types),
+                                        });
+                                    }
+                                    StoreRole::Dispatch => {
+                                        // The parser has verified that the action is the only
+                                        // non-self argument.
+                                        let action = match function.sig.inputs.iter().last() {
+                                            Some(FnArg::Typed(action)) => action,
+                                            _ => unreachable!(),
+                                        };
+                                        let action_label = match action.pat.deref() {
+                                            Pat::Ident(ident) => function
+                                                .argument_labels
+                                                .get(&ident.ident)
+                                                .map(|label| label.value()),
+                                            _ => None,
+                                        };
+
+                                        store.dispatch = Some(StoreDispatchMethod {
+                                            func_name: swift_name,
+                                            action_label,
+                                            action_ty: BridgedType::new_with_type(
+                                                &action.ty,
+                                                &self.types,
+                                            )
+                                            .unwrap()
+                                            .to_swift_type(
+                                                TypePosition::FnArg(opaque_ty.host_lang, 1),
+                                                &self.types,
+                                            ),
+                                        });
+                                    }
+                                }
+                            }
                         }
                     };
                     continue;
@@ -87,6 +171,8 @@ impl SwiftBridgeModule {
             swift += "\n";
         }
 
+        let opaque_rust_types_in_arc = self.opaque_rust_types_in_arc();
+
         for ty in self.types.types() {
             match ty {
                 TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct)) => {
@@ -111,7 +197,7 @@ impl SwiftBridgeModule {
                                 &self.swift_bridge_path,
                             );
                         } else {
-                            let class_protocols = class_protocols.get(&ty.ty.to_string());
+                            let class_protocols = class_protocols.get(&ty.swift_class_name());
                             let default_cp = ClassProtocols::default();
                             let class_protocols = class_protocols.unwrap_or(&default_cp);
 
@@ -133,6 +219,12 @@ impl SwiftBridgeModule {
                             if ty.attributes.copy.is_none() && ty.generics.len() == 0 {
                                 swift += &generate_vectorizable_extension(&ty);
                                 swift += "\n";
+
+                                let ty_name = ty.ty.to_string();
+                                if opaque_rust_types_in_arc.contains(&ty_name) {
+                                    swift += &generate_arc_swift_class(&ty_name);
+                                    swift += "\n";
+                                }
                             }
                         }
                     }
@@ -144,6 +236,36 @@ impl SwiftBridgeModule {
             };
         }
 
+        for constant in &self.constants {
+            swift += &constant.to_swift();
+        }
+
+        if let Some(reflection_name) = &self.reflection {
+            swift += &format!(
+                r#"extension RustReflection {{
+    public static let {name} = RustReflection(__swift_bridge__${name}$reflection())
+}}
+"#,
+                name = reflection_name.value()
+            );
+        }
+
+        // `CVPixelBuffer` is declared in Core Video, which the generated Swift files don't
+        // otherwise import.
+        if self.uses_core_video_types() {
+            swift = format!("import CoreVideo\n{}", swift);
+        }
+
+        // `TimeInterval` and `Date` are declared in Foundation, which the generated Swift files
+        // don't otherwise import.
+        if self.uses_foundation_types() {
+            swift = format!("import Foundation\n{}", swift);
+        }
+
+        if self.hash_symbols {
+            swift = hash_swift_cdecl_names(&swift, hashed_symbols);
+        }
+
         swift
     }
 }
@@ -152,12 +274,31 @@ impl SwiftBridgeModule {
 struct ClassProtocols {
     // The name of the function to use for the Identifiable protocol implementation.
     identifiable: Option<IdentifiableProtocol>,
+    // The methods to use for the generated `Store` class.
+    store: StoreMethods,
 }
 struct IdentifiableProtocol {
     func_name: String,
     return_ty: String,
 }
 
+/// A `{TypeName}Store` class is generated when an opaque Rust type has both a `Store(state)` and
+/// a `Store(dispatch)` method.
+#[derive(Default)]
+struct StoreMethods {
+    state: Option<StoreStateMethod>,
+    dispatch: Option<StoreDispatchMethod>,
+}
+struct StoreStateMethod {
+    func_name: String,
+    state_ty: String,
+}
+struct StoreDispatchMethod {
+    func_name: String,
+    action_label: Option<String>,
+    action_ty: String,
+}
+
 // Generate functions to drop the reference count on a Swift class instance.
 //
 // # Example
@@ -185,6 +326,79 @@ func {fn_name} (ptr: UnsafeMutableRawPointer) {{
     )
 }
 
+/// Generates the functions that Rust uses to call and release the Swift closures that are passed
+/// to an extern "Rust" function as a `Box<dyn Fn(A, B) -> C>` or a `Box<dyn FnOnce(A, B) -> C>`.
+///
+/// @_cdecl("__swift_bridge__$some_function$param0")
+/// func __swift_bridge__some_function_param0 (_ callback: UnsafeMutableRawPointer, _ arg0: UInt8) -> UInt16 {
+///     Unmanaged<__private__SwiftCallback<(UInt8) -> UInt16>>.fromOpaque(callback).takeUnretainedValue().closure(arg0)
+/// }
+/// @_cdecl("__swift_bridge__$some_function$_free$param0")
+/// func __swift_bridge__some_function__free_param0 (_ callback: UnsafeMutableRawPointer) {
+///     Unmanaged<__private__SwiftCallback<(UInt8) -> UInt16>>.fromOpaque(callback).release()
+/// }
+fn gen_swift_closures_support(func: &ParsedExternFn, types: &TypeDeclarations) -> String {
+    let mut support = "".to_string();
+
+    let prefixed_fn_name = func.prefixed_fn_name();
+
+    for (idx, boxed_fn) in func.args_filtered_to_boxed_fns(types) {
+        let call_link_name = func.call_boxed_fn_link_name(idx);
+        let free_link_name = func.free_boxed_fn_link_name(idx);
+
+        let callback_class = format!(
+            "__private__SwiftCallback<{}>",
+            boxed_fn.to_swift_closure_type(types)
+        );
+
+        let mut params = "_ callback: UnsafeMutableRawPointer".to_string();
+        let mut args = vec![];
+        for (param_idx, param) in boxed_fn.params.iter().enumerate() {
+            let ty = param.to_swift_type(TypePosition::FnArg(HostLang::Swift, param_idx), types);
+            params += &format!(", _ arg{param_idx}: {ty}");
+
+            args.push(param.convert_ffi_value_to_swift_value(
+                &format!("arg{param_idx}"),
+                TypePosition::FnArg(HostLang::Swift, param_idx),
+                types,
+            ));
+        }
+
+        let call_closure = format!(
+            "Unmanaged<{callback_class}>.fromOpaque(callback).takeUnretainedValue().closure({})",
+            args.join(", ")
+        );
+
+        let (maybe_ret, call_closure) = if boxed_fn.ret.is_null() {
+            ("".to_string(), call_closure)
+        } else {
+            let ret = boxed_fn
+                .ret
+                .to_swift_type(TypePosition::FnReturn(HostLang::Swift), types);
+            let call_closure = boxed_fn.ret.convert_swift_expression_to_ffi_type(
+                &call_closure,
+                types,
+                TypePosition::FnReturn(HostLang::Swift),
+            );
+            (format!(" -> {ret}"), call_closure)
+        };
+
+        support += &format!(
+            r#"@_cdecl("{call_link_name}")
+func {prefixed_fn_name}_param{idx} ({params}){maybe_ret} {{
+    {call_closure}
+}}
+@_cdecl("{free_link_name}")
+func {prefixed_fn_name}__free_param{idx} (_ callback: UnsafeMutableRawPointer) {{
+    Unmanaged<{callback_class}>.fromOpaque(callback).release()
+}}
+"#
+        );
+    }
+
+    support
+}
+
 fn gen_function_exposes_swift_to_rust(
     func: &ParsedExternFn,
     types: &TypeDeclarations,
@@ -381,6 +595,15 @@ fn generate_swift_class_methods(
     }
 }
 
+/// The attributes from `#[swift_bridge(swift_attr = "...")]`, one per line, to put in front of a
+/// generated Swift declaration.
+fn swift_attributes(swift_attrs: &[String], indentation: &str) -> String {
+    swift_attrs
+        .iter()
+        .map(|attr| format!("{}{}\n", indentation, attr))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     //! More tests can be found in src/codegen/codegen_tests.rs and its submodules.
@@ -544,7 +767,7 @@ public func foo() -> UInt32 {
 
         let expected = r#"
 func foo() -> UnsafeBufferPointer<UInt8> {
-    let slice = __swift_bridge__$foo(); return UnsafeBufferPointer(start: slice.start.assumingMemoryBound(to: UInt8.self), count: Int(slice.len));
+    __swift_bridge__$foo().toUnsafeBufferPointer(UInt8.self)
 } 
 "#;
 
@@ -732,6 +955,7 @@ func __swift_bridge__Foo_pop (_ this: UnsafeMutableRawPointer) {
         let expected = r#"
 public class FooRef {
     var ptr: UnsafeMutableRawPointer
+    var __swift_bridge__parent: AnyObject?
 
     public init(ptr: UnsafeMutableRawPointer) {
         self.ptr = ptr
@@ -765,12 +989,14 @@ extension FooRef {
         let expected = r#"
 public class FooRef {
     var ptr: UnsafeMutableRawPointer
+    var __swift_bridge__parent: AnyObject?
 
     public init(ptr: UnsafeMutableRawPointer) {
         self.ptr = ptr
     }
 }
 extension FooRef {
+    /// - Parameter other: Borrowed for the duration of the call.
     public func bar(_ other: FooRef) {
         __swift_bridge__$Foo$bar(ptr, other.ptr)
     }
@@ -799,6 +1025,7 @@ extension FooRef {
         let expected = r#"
 public class FooRef {
     var ptr: UnsafeMutableRawPointer
+    var __swift_bridge__parent: AnyObject?
 
     public init(ptr: UnsafeMutableRawPointer) {
         self.ptr = ptr
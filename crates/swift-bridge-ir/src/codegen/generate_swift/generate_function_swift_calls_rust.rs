@@ -1,5 +1,7 @@
 use crate::bridged_type::{fn_arg_name, BridgeableType, BridgedType, StdLibType, TypePosition};
+use crate::codegen::generate_swift::swift_attributes;
 use crate::parse::{HostLang, TypeDeclaration};
+use crate::parsed_extern_fn::{Compression, FailableInitializer, ScopedValue};
 use crate::{ParsedExternFn, TypeDeclarations, SWIFT_BRIDGE_PREFIX};
 use quote::ToTokens;
 use std::ops::Deref;
@@ -32,7 +34,7 @@ pub(super) fn gen_func_swift_calls_rust(
                 todo!()
             }
             TypeDeclaration::Opaque(ty) => {
-                format!("${}", ty.to_string())
+                format!("${}", ty.dollar_separated_name())
             }
         }
     } else {
@@ -51,11 +53,27 @@ pub(super) fn gen_func_swift_calls_rust(
         ""
     };
 
+    let failable_initializer = function.failable_initializer();
+    // The Swift type that an initializer initializes.
+    let initialized_ty = function
+        .associated_type
+        .as_ref()
+        .filter(|_| function.is_swift_initializer)
+        .and_then(|ty| ty.as_opaque())
+        .map(|ty| ty.swift_class_name())
+        .unwrap_or_default();
+
     let public_func_fn_name = if function.is_swift_initializer {
+        let maybe_optional = if failable_initializer == Some(FailableInitializer::Optional) {
+            "?"
+        } else {
+            ""
+        };
+
         if function.is_copy_method_on_opaque_type() {
-            "public init".to_string()
+            format!("public init{}", maybe_optional)
         } else {
-            "public convenience init".to_string()
+            format!("public convenience init{}", maybe_optional)
         }
     } else {
         if let Some(swift_name) = &function.swift_name_override {
@@ -79,8 +97,20 @@ pub(super) fn gen_func_swift_calls_rust(
     );
     let mut call_rust = if function.sig.asyncness.is_some() {
         call_rust
-    } else if function.is_swift_initializer {
+    } else if function.is_swift_initializer && failable_initializer.is_none() {
         call_rust
+    } else if let Some(compression) = function.compress {
+        let decompressed = match compression {
+            Compression::Lz4 => format!(
+                "__swift_bridge__decompressLz4(RustVec<UInt8>(ptr: {}))",
+                call_rust
+            ),
+        };
+        if function.compressed_return_is_string() {
+            format!("String(decoding: {}, as: UTF8.self)", decompressed)
+        } else {
+            decompressed
+        }
     } else if let Some(built_in) = function.return_ty_built_in(types) {
         built_in.convert_ffi_value_to_swift_value(
             &call_rust,
@@ -127,6 +157,13 @@ pub(super) fn gen_func_swift_calls_rust(
             }
         }
     };
+    if function.sig.asyncness.is_none() && function.returns_reference_borrowed_from_self(types) {
+        call_rust = format!(
+            "{{ let val = {call_rust}; val.__swift_bridge__parent = self; return val }}()",
+            call_rust = call_rust
+        );
+    }
+
     let returns_null = BridgedType::new_with_return_type(&function.func.sig.output, types)
         .map(|b| b.is_null())
         .unwrap_or(false);
@@ -174,22 +211,64 @@ pub(super) fn gen_func_swift_calls_rust(
         }
     }
 
-    if function.is_swift_initializer {
+    // Async initializers initialize `self` after awaiting the value.
+    if function.is_swift_initializer && function.sig.asyncness.is_none() {
+        // A failable initializer's value has already been converted to the Swift type, so we
+        // take over its pointer or bytes.
+        let ty = &initialized_ty;
+
         if function.is_copy_method_on_opaque_type() {
-            call_rust = format!("self.bytes = {}", call_rust)
+            call_rust = match failable_initializer {
+                None => format!("self.bytes = {}", call_rust),
+                Some(FailableInitializer::Throws) => format!("self = {}", call_rust),
+                Some(FailableInitializer::Optional) => format!(
+                    "guard let val: {ty} = {call_rust} else {{ return nil }}\n{indentation}    self = val"
+                ),
+            };
         } else {
-            call_rust = format!("self.init(ptr: {})", call_rust)
+            call_rust = match failable_initializer {
+                None => format!("self.init(ptr: {})", call_rust),
+                Some(FailableInitializer::Throws) => format!(
+                    "let val: {ty} = {call_rust}\n{indentation}    val.isOwned = false\n{indentation}    self.init(ptr: val.ptr)"
+                ),
+                Some(FailableInitializer::Optional) => format!(
+                    "guard let val: {ty} = {call_rust} else {{ return nil }}\n{indentation}    val.isOwned = false\n{indentation}    self.init(ptr: val.ptr)"
+                ),
+            };
+
+            if let Some(TypeDeclaration::Opaque(ty)) = function.associated_type.as_ref() {
+                if ty.attributes.identity_map {
+                    call_rust = format!(
+                        "{call_rust}\n{indentation}    {ty}.__swift_bridge__identityMap.insert({prefix}${ty}$_object_identity(self.ptr), self)",
+                        call_rust = call_rust,
+                        indentation = indentation,
+                        ty = ty.to_string(),
+                        prefix = SWIFT_BRIDGE_PREFIX
+                    );
+                }
+            }
         }
     }
 
     let maybe_return = if function.is_swift_initializer {
-        "".to_string()
+        if failable_initializer == Some(FailableInitializer::Throws) {
+            " throws".to_string()
+        } else {
+            "".to_string()
+        }
     } else {
         function.to_swift_return_type(types)
     };
 
     let maybe_generics = function.maybe_swift_generics(types);
 
+    let ownership_doc_comment: String = function
+        .swift_ownership_doc_lines(types)
+        .into_iter()
+        .map(|line| format!("{}/// {}\n", indentation, line))
+        .collect();
+    let swift_attrs = swift_attributes(&function.swift_attrs, indentation);
+
     let func_definition = if function.sig.asyncness.is_some() {
         let func_ret_ty = function.return_ty_built_in(types).unwrap();
         let rust_fn_ret_ty =
@@ -246,13 +325,39 @@ pub(super) fn gen_func_swift_calls_rust(
             cb_wrapper_ty = callback_wrapper_ty
         );
 
+        // An async initializer awaits the value before taking over its pointer or bytes.
+        let (await_value, maybe_else_return_nil, initialize_self) = if function.is_swift_initializer
+        {
+            let initialize_self = if function.is_copy_method_on_opaque_type() {
+                "\nself = val"
+            } else {
+                "\nval.isOwned = false\nself.init(ptr: val.ptr)"
+            };
+
+            if failable_initializer == Some(FailableInitializer::Optional) {
+                (
+                    format!("guard let val: {} =", initialized_ty),
+                    " else { return nil }",
+                    initialize_self,
+                )
+            } else {
+                (
+                    format!("let val: {} =", initialized_ty),
+                    "",
+                    initialize_self,
+                )
+            }
+        } else {
+            ("return".to_string(), "", "")
+        };
+
         let fn_body = format!(
             r#"func onComplete(cbWrapperPtr: UnsafeMutableRawPointer?{maybe_on_complete_sig_ret_val}) {{
     let wrapper = Unmanaged<{cb_wrapper_ty}>.fromOpaque(cbWrapperPtr!).takeRetainedValue()
     {run_wrapper_cb}
 }}
 
-return{maybe_try}await {with_checked_continuation_function_name}({{ (continuation: CheckedContinuation<{rust_fn_ret_ty}, {error}>) in
+{await_value}{maybe_try}await {with_checked_continuation_function_name}({{ (continuation: CheckedContinuation<{rust_fn_ret_ty}, {error}>) in
     let callback = {{ rustFnRetVal in
         continuation.resume(with: rustFnRetVal)
     }}
@@ -261,7 +366,7 @@ return{maybe_try}await {with_checked_continuation_function_name}({{ (continuatio
     let wrapperPtr = Unmanaged.passRetained(wrapper).toOpaque()
 
     {call_rust}
-}})"#,
+}}){maybe_else_return_nil}{initialize_self}"#,
             rust_fn_ret_ty = rust_fn_ret_ty,
             error = error,
             maybe_on_complete_sig_ret_val = maybe_on_complete_sig_ret_val,
@@ -307,5 +412,57 @@ return{maybe_try}await {with_checked_continuation_function_name}({{ (continuatio
             call_rust = call_rust,
         )
     };
-    func_definition
+    let scoped_func_definition = match (&function.scoped, function.scoped_value(types)) {
+        (Some(scoped), Some(scoped_value)) => {
+            let swift_fn_name = function
+                .swift_name_override
+                .as_ref()
+                .map(|name| name.value())
+                .unwrap_or_else(|| fn_name.clone());
+
+            let (closure_arg_ty, body) = match scoped_value {
+                // The closure only receives a `RefMut`, so the guard's only strong reference is
+                // the one that `withExtendedLifetime` keeps alive until the closure returns or
+                // throws.
+                ScopedValue::Guard(guard) => {
+                    let guard_ty = guard.swift_class_name();
+                    (
+                        format!("{}RefMut", guard_ty),
+                        format!(
+                            r#"let scope = {swift_fn_name}()
+{indentation}    return try withExtendedLifetime(scope) {{
+{indentation}        try body({guard_ty}RefMut(ptr: scope.ptr))
+{indentation}    }}"#
+                        ),
+                    )
+                }
+                ScopedValue::Borrow { ty, mutable } => {
+                    let maybe_mut = if mutable { "Mut" } else { "" };
+                    (
+                        format!("{}Ref{}", ty.swift_class_name(), maybe_mut),
+                        format!("try body({swift_fn_name}())"),
+                    )
+                }
+            };
+
+            format!(
+                r#"
+{swift_attrs}{indentation}{maybe_static_class_func}public func {scoped_name}<R>(_ body: ({closure_arg_ty}) throws -> R) rethrows -> R {{
+{indentation}    {body}
+{indentation}}}"#,
+                indentation = indentation,
+                swift_attrs = swift_attrs,
+                maybe_static_class_func = maybe_static_class_func,
+                scoped_name = scoped.value(),
+                closure_arg_ty = closure_arg_ty,
+                body = body,
+            )
+        }
+        _ => "".to_string(),
+    };
+
+    format!(
+        "{}{}{}{}",
+        ownership_doc_comment, swift_attrs, func_definition, scoped_func_definition
+    )
 }
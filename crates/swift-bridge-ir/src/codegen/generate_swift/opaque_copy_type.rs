@@ -1,4 +1,4 @@
-use crate::codegen::generate_swift::generate_swift_class_methods;
+use crate::codegen::generate_swift::{generate_swift_class_methods, swift_attributes};
 use crate::parse::OpaqueForeignTypeDeclaration;
 use crate::{ParsedExternFn, TypeDeclarations, SWIFT_BRIDGE_PREFIX};
 use std::collections::HashMap;
@@ -50,10 +50,11 @@ fn generate_struct_definition(
 ) -> String {
     let type_name = ty.ty.to_string();
     let generics = ty.generics.angle_bracketed_generic_placeholders_string();
+    let swift_attrs = swift_attributes(&ty.attributes.swift_attrs, "");
 
     let declare_struct = if ty.generics.is_empty() {
         format!(
-            r#"public struct {type_name} {{
+            r#"{swift_attrs}public struct {type_name} {{
     fileprivate var bytes: {prefix}${type_name}
 
     func intoFfiRepr() -> {prefix}${type_name} {{
@@ -65,7 +66,7 @@ fn generate_struct_definition(
         )
     } else {
         format!(
-            r#"public struct {type_name}{generics} {{
+            r#"{swift_attrs}public struct {type_name}{generics} {{
     fileprivate var bytes: SwiftBridgeGenericCopyTypeFfiRepr
 }}"#,
             type_name = type_name,
@@ -109,9 +110,15 @@ extension {ffi_repr_name}: SwiftBridgeGenericCopyTypeFfiRepr {{}}"#,
         )
     };
 
+    let sendable_conformance = if ty.attributes.sendable && ty.generics.is_empty() {
+        format!("\nextension {type_name}: @unchecked Sendable {{}}")
+    } else {
+        "".to_string()
+    };
+
     format!(
         r#"{declare_struct}
-{ffi_repr_conversion}"#,
+{ffi_repr_conversion}{sendable_conformance}"#,
         declare_struct = declare_struct,
         ffi_repr_conversion = ffi_repr_conversion
     )
@@ -1,4 +1,5 @@
 use crate::bridged_type::{BridgedType, SharedEnum, StructFields, TypePosition};
+use crate::codegen::generate_swift::swift_attributes;
 use crate::SwiftBridgeModule;
 
 impl SwiftBridgeModule {
@@ -60,6 +61,10 @@ impl SwiftBridgeModule {
             };
             variants += &v;
         }
+        if shared_enum.non_exhaustive {
+            variants += r#"
+    case unknown(Int64)"#;
+        }
         if variants.len() > 0 {
             variants += "\n";
         }
@@ -73,6 +78,13 @@ impl SwiftBridgeModule {
             );
             convert_swift_to_ffi_repr += &convert_swift_variant_to_ffi_repr;
         }
+        if shared_enum.non_exhaustive {
+            convert_swift_to_ffi_repr += &format!(
+                r#"            case {enum_name}.unknown(let discriminant):
+                __swift_bridge__invalidEnumDiscriminant("{enum_name}", discriminant)
+"#
+            );
+        }
         if convert_swift_to_ffi_repr.len() > 0 {
             convert_swift_to_ffi_repr += "        ";
         }
@@ -82,10 +94,16 @@ impl SwiftBridgeModule {
                 variant.convert_ffi_expression_to_swift(&self.types, format!("{}", enum_name));
             convert_ffi_repr_to_swift += &convert_ffi_variant_to_swift;
         }
-        if convert_ffi_repr_to_swift.len() > 0 {
+        if shared_enum.non_exhaustive {
+            convert_ffi_repr_to_swift += &format!(
+                r#"            default:
+                return {enum_name}.unknown(Int64(self.tag.rawValue))
+        "#
+            );
+        } else if convert_ffi_repr_to_swift.len() > 0 {
             convert_ffi_repr_to_swift += &format!(
                 r#"            default:
-                fatalError("Unreachable")
+                __swift_bridge__invalidEnumDiscriminant("{enum_name}", Int64(self.tag.rawValue))
         "#
             );
         }
@@ -134,11 +152,19 @@ extension {enum_name}: Vectorizable {{
             )
         };
 
-        let derive_debug_impl = if shared_enum.derive.debug {
+        let derive_debug_impl = if shared_enum.derive.debug && all_variants_empty {
+            let debug_unknown = if shared_enum.non_exhaustive {
+                r#"
+        if case .unknown(let discriminant) = self {
+            return "unknown(\(discriminant))"
+        }"#
+            } else {
+                ""
+            };
             format!(
                 r#"
 extension {enum_name}: CustomDebugStringConvertible {{
-    public var debugDescription: String {{
+    public var debugDescription: String {{{debug_unknown}
         RustString(ptr: __swift_bridge__${enum_name}$Debug(self.intoFfiRepr())).toString()
     }}
 }}"#
@@ -147,8 +173,42 @@ extension {enum_name}: CustomDebugStringConvertible {{
             "".to_string()
         };
 
+        let discriminant_check_impl = if shared_enum.discriminant_check {
+            let discriminants: Vec<String> = shared_enum
+                .variants
+                .iter()
+                .map(|variant| {
+                    format!(
+                        r#"("{name}", UInt32({enum_ffi_name}${name}.rawValue))"#,
+                        name = variant.name
+                    )
+                })
+                .collect();
+            let discriminants = discriminants.join(", ");
+
+            format!(
+                r#"
+extension {enum_name} {{
+    /// Crash if the Rust library that this code is linked against has a different variant at any of this enum's discriminants.
+    public static func assertDiscriminantsMatchRust() {{
+        __swift_bridge__debug_check {{
+            let discriminants: [(String, UInt32)] = [{discriminants}]
+            for (name, discriminant) in discriminants {{
+                let rustName = {enum_ffi_name}$_variant_name(discriminant).toString()
+                precondition(rustName == name, "{enum_name}.\(name) has discriminant \(discriminant) in Swift, but discriminant \(discriminant) is \"\(rustName)\" in Rust")
+            }}
+        }}
+    }}
+}}"#
+            )
+        } else {
+            "".to_string()
+        };
+
+        let swift_attrs = swift_attributes(&shared_enum.swift_attrs, "");
+
         let swift_enum = format!(
-            r#"public enum {enum_name} {{{variants}}}
+            r#"{swift_attrs}public enum {enum_name} {{{variants}}}
 extension {enum_name} {{
     func intoFfiRepr() -> {ffi_repr_name} {{
         switch self {{{convert_swift_to_ffi_repr}}}
@@ -176,7 +236,7 @@ extension {option_ffi_name} {{
             return {option_ffi_name}(is_some: false, val: {ffi_repr_name}())
         }}
     }}
-}}{vectorizable_impl}{derive_debug_impl}"#,
+}}{vectorizable_impl}{derive_debug_impl}{discriminant_check_impl}"#,
             enum_name = enum_name,
             enum_ffi_name = enum_ffi_name,
             option_ffi_name = option_ffi_name,
@@ -1,6 +1,9 @@
-use crate::bridged_type::shared_struct::StructField;
+use crate::bridged_type::shared_struct::{little_endian_field_len, StructField};
 use crate::bridged_type::{BridgedType, SharedStruct, StructFields, StructSwiftRepr, TypePosition};
-use crate::SwiftBridgeModule;
+use crate::codegen::generate_swift::swift_attributes;
+use crate::{SwiftBridgeModule, SWIFT_BRIDGE_PREFIX};
+use proc_macro2::Ident;
+use syn::Type;
 
 impl SwiftBridgeModule {
     /// Generate the tokens for a shared struct.
@@ -13,22 +16,32 @@ impl SwiftBridgeModule {
         }
 
         let struct_name = &shared_struct.swift_name_string();
+        let struct_type = &shared_struct.swift_type_string(&self.types);
         let option_ffi_name = shared_struct.ffi_option_name_string();
+        let generics: &[Ident] = match &shared_struct.instantiation {
+            Some(instantiation) => &instantiation.generics,
+            None => &[],
+        };
+        // A generic struct is declared using its generics, such as `first: T`.
+        let declaration_fields = match &shared_struct.instantiation {
+            Some(instantiation) => &instantiation.generic_fields,
+            None => &shared_struct.fields,
+        };
 
         match shared_struct.swift_repr {
-            StructSwiftRepr::Class => {
-                todo!()
-            }
+            StructSwiftRepr::Class => Some(self.generate_shared_struct_class_string(shared_struct)),
             StructSwiftRepr::Structure => {
-                let initializer_params = match &shared_struct.fields {
-                    StructFields::Named(named) => self.convert_fields_to_initializer_params(named),
+                let initializer_params = match declaration_fields {
+                    StructFields::Named(named) => {
+                        self.convert_fields_to_initializer_params(named, generics)
+                    }
                     StructFields::Unnamed(unnamed) => {
-                        self.convert_fields_to_initializer_params(unnamed)
+                        self.convert_fields_to_initializer_params(unnamed, generics)
                     }
                     StructFields::Unit => "".to_string(),
                 };
 
-                let initializer_body = match &shared_struct.fields {
+                let initializer_body = match declaration_fields {
                     StructFields::Named(named) => self.convert_fields_to_initializer_body(named),
                     StructFields::Unnamed(unnamed) => {
                         self.convert_fields_to_initializer_body(unnamed)
@@ -36,12 +49,55 @@ impl SwiftBridgeModule {
                     StructFields::Unit => "".to_string(),
                 };
 
-                let fields = match &shared_struct.fields {
-                    StructFields::Named(named) => self.declare_fields(named),
-                    StructFields::Unnamed(unnamed) => self.declare_fields(unnamed),
+                let fields = match declaration_fields {
+                    StructFields::Named(named) => self.declare_fields(named, generics),
+                    StructFields::Unnamed(unnamed) => self.declare_fields(unnamed, generics),
                     StructFields::Unit => "".to_string(),
                 };
 
+                let swift_attrs = swift_attributes(&shared_struct.swift_attrs, "");
+                let declaration = format!(
+                    r#"{swift_attrs}public struct {struct_name}{generic_params} {{{fields}
+    public init({initializer_params}) {{{initializer_body}}}
+"#,
+                    generic_params = if generics.is_empty() {
+                        "".to_string()
+                    } else {
+                        let generics: Vec<String> =
+                            generics.iter().map(|g| g.to_string()).collect();
+                        format!("<{}>", generics.join(", "))
+                    }
+                );
+
+                // The generic struct is only declared once, and each of its instantiations
+                // converts to and from its own FFI representation in a constrained extension.
+                let declaration = match &shared_struct.instantiation {
+                    Some(instantiation) => {
+                        let bounds: Vec<String> = instantiation
+                            .generics
+                            .iter()
+                            .zip(instantiation.args.iter())
+                            .map(|(generic, arg)| {
+                                let arg = BridgedType::new_with_str(&arg.to_string(), &self.types)
+                                    .unwrap()
+                                    .to_swift_type(TypePosition::SharedStructField, &self.types);
+                                format!("{} == {}", generic, arg)
+                            })
+                            .collect();
+                        let extension = format!(
+                            "extension {struct_name} where {bounds} {{",
+                            bounds = bounds.join(", ")
+                        );
+
+                        if instantiation.is_first {
+                            format!("{declaration}}}\n{extension}")
+                        } else {
+                            extension
+                        }
+                    }
+                    None => declaration,
+                };
+
                 let convert_swift_to_ffi_repr =
                     shared_struct.convert_swift_to_ffi_repr("self", &self.types);
                 let convert_ffi_repr_to_swift =
@@ -50,9 +106,7 @@ impl SwiftBridgeModule {
                 // No need to generate any code. Swift will automatically generate a
                 //  struct from our C header typedef that we generate for this struct.
                 let swift_struct = format!(
-                    r#"public struct {struct_name} {{{fields}
-    public init({initializer_params}) {{{initializer_body}}}
-
+                    r#"{declaration}
     @inline(__always)
     func intoFfiRepr() -> {ffi_repr_name} {{
         {convert_swift_to_ffi_repr}
@@ -60,13 +114,13 @@ impl SwiftBridgeModule {
 }}
 extension {ffi_repr_name} {{
     @inline(__always)
-    func intoSwiftRepr() -> {struct_name} {{
+    func intoSwiftRepr() -> {struct_type} {{
         {convert_ffi_repr_to_swift}
     }}
 }}
 extension {option_ffi_name} {{
     @inline(__always)
-    func intoSwiftRepr() -> Optional<{struct_name}> {{
+    func intoSwiftRepr() -> Optional<{struct_type}> {{
         if self.is_some {{
             return self.val.intoSwiftRepr()
         }} else {{
@@ -75,7 +129,7 @@ extension {option_ffi_name} {{
     }}
 
     @inline(__always)
-    static func fromSwiftRepr(_ val: Optional<{struct_name}>) -> {option_ffi_name} {{
+    static func fromSwiftRepr(_ val: Optional<{struct_type}>) -> {option_ffi_name} {{
         if let v = val {{
             return {option_ffi_name}(is_some: true, val: v.intoFfiRepr())
         }} else {{
@@ -83,24 +137,254 @@ extension {option_ffi_name} {{
         }}
     }}
 }}"#,
-                    struct_name = struct_name,
-                    initializer_params = initializer_params,
-                    initializer_body = initializer_body,
-                    fields = fields,
+                    declaration = declaration,
+                    struct_type = struct_type,
                     ffi_repr_name = shared_struct.ffi_name_string(),
                     option_ffi_name = option_ffi_name,
                     convert_swift_to_ffi_repr = convert_swift_to_ffi_repr,
                     convert_ffi_repr_to_swift = convert_ffi_repr_to_swift
                 );
 
+                let mut swift_struct = swift_struct;
+                let is_declared = match &shared_struct.instantiation {
+                    Some(instantiation) => instantiation.is_first,
+                    None => true,
+                };
+                if shared_struct.diffable && is_declared {
+                    swift_struct += &self.generate_changed_fields_extension(shared_struct);
+                }
+                if shared_struct.little_endian && is_declared {
+                    swift_struct += &self.generate_little_endian_extension(shared_struct);
+                }
+                if is_declared {
+                    swift_struct += &self.generate_derived_conformances(shared_struct, generics);
+                }
+                if shared_struct.sendable && is_declared {
+                    swift_struct += &format!(
+                        "\nextension {struct_name}: @unchecked Sendable{} {{}}",
+                        where_generics_conform(generics, "Sendable")
+                    );
+                }
+                if shared_struct.supports_vec() {
+                    swift_struct += &self.generate_vectorizable_extension(shared_struct);
+                }
+                if shared_struct.has_serde_json_round_trip() {
+                    swift_struct += &self.generate_serde_json_round_trip(shared_struct);
+                }
+
                 Some(swift_struct)
             }
         }
     }
 
+    /// Generate the classes for a `swift_repr = "class"` struct, whose fields are read and written
+    /// through a pointer to a boxed Rust value.
+    fn generate_shared_struct_class_string(&self, shared_struct: &SharedStruct) -> String {
+        let struct_name = shared_struct.swift_name_string();
+        let prefix = format!("{}${}", SWIFT_BRIDGE_PREFIX, struct_name);
+
+        let initializer_params = match &shared_struct.fields {
+            StructFields::Named(named) => self.convert_fields_to_initializer_params(named, &[]),
+            StructFields::Unnamed(unnamed) => {
+                self.convert_fields_to_initializer_params(unnamed, &[])
+            }
+            StructFields::Unit => "".to_string(),
+        };
+
+        let mut new_args = vec![];
+        let mut getters = "".to_string();
+        let mut setters = "".to_string();
+        for field in shared_struct.fields.normalized_fields() {
+            let field_name = field.ffi_field_name();
+            let ty = BridgedType::new_with_type(&field.ty, &self.types).unwrap();
+            let swift_ty = ty.to_swift_type(TypePosition::SharedStructField, &self.types);
+
+            new_args.push(ty.convert_swift_expression_to_ffi_type(
+                &field_name,
+                &self.types,
+                TypePosition::SharedStructField,
+            ));
+
+            let get = ty.convert_ffi_value_to_swift_value(
+                &format!("{prefix}$get${field_name}(ptr)"),
+                TypePosition::SharedStructField,
+                &self.types,
+            );
+            let new_value = ty.convert_swift_expression_to_ffi_type(
+                "newValue",
+                &self.types,
+                TypePosition::SharedStructField,
+            );
+
+            getters += &format!(
+                r#"
+
+    public var {field_name}: {swift_ty} {{
+        return {get}
+    }}"#
+            );
+            setters += &format!(
+                r#"
+
+    public override var {field_name}: {swift_ty} {{
+        get {{
+            return {get}
+        }}
+        set {{
+            {prefix}$set${field_name}(ptr, {new_value})
+        }}
+    }}"#
+            );
+        }
+        let new_args = new_args.join(", ");
+        let swift_attrs = swift_attributes(&shared_struct.swift_attrs, "");
+        let sendable_conformance = if shared_struct.sendable {
+            format!("\nextension {struct_name}Ref: @unchecked Sendable {{}}")
+        } else {
+            "".to_string()
+        };
+
+        format!(
+            r#"{swift_attrs}public class {struct_name}: {struct_name}RefMut {{
+    var isOwned: Bool = true
+
+    public init({initializer_params}) {{
+        super.init(ptr: {prefix}$new({new_args}))
+    }}
+
+    public override init(ptr: UnsafeMutableRawPointer) {{
+        super.init(ptr: ptr)
+    }}
+
+    deinit {{
+        if isOwned {{
+            {prefix}$_free(ptr)
+        }}
+    }}
+}}
+{swift_attrs}public class {struct_name}RefMut: {struct_name}Ref {{
+    public override init(ptr: UnsafeMutableRawPointer) {{
+        super.init(ptr: ptr)
+    }}{setters}
+}}
+{swift_attrs}public class {struct_name}Ref {{
+    var ptr: UnsafeMutableRawPointer
+
+    public init(ptr: UnsafeMutableRawPointer) {{
+        self.ptr = ptr
+    }}{getters}
+}}{sendable_conformance}"#
+        )
+    }
+
+    /// Generate a `ChangedFields` option set and an `apply` method for a `diffable` struct.
+    fn generate_changed_fields_extension(&self, shared_struct: &SharedStruct) -> String {
+        let struct_name = shared_struct.swift_name_string();
+
+        let mut options = "".to_string();
+        let mut apply_body = "".to_string();
+        for (idx, field) in shared_struct.fields.normalized_fields().iter().enumerate() {
+            let field_name = field.ffi_field_name();
+
+            options += &format!(
+                "\n        public static let {field_name} = ChangedFields(rawValue: 1 << {idx})"
+            );
+            apply_body += &format!(
+                r#"
+        if changedFields.contains(.{field_name}) {{
+            self.{field_name} = update.{field_name}
+        }}"#
+            );
+        }
+
+        format!(
+            r#"
+extension {struct_name} {{
+    public struct ChangedFields: OptionSet {{
+        public let rawValue: UInt64
+
+        public init(rawValue: UInt64) {{
+            self.rawValue = rawValue
+        }}
+{options}
+    }}
+
+    public mutating func apply(_ update: {struct_name}, changedFields: ChangedFields) {{{apply_body}
+    }}
+}}"#
+        )
+    }
+
+    /// Generate methods for encoding a `little_endian` struct as little-endian bytes.
+    fn generate_little_endian_extension(&self, shared_struct: &SharedStruct) -> String {
+        let struct_name = shared_struct.swift_name_string();
+
+        let mut len = 0;
+        let mut encode_fields = "".to_string();
+        let mut decoded_fields = vec![];
+        for field in shared_struct.fields.normalized_fields() {
+            let offset = len;
+            len += little_endian_field_len(&field.ty).unwrap();
+
+            let field_name = field.ffi_field_name();
+            let swift_ty = BridgedType::new_with_type(&field.ty, &self.types)
+                .unwrap()
+                .to_swift_type(TypePosition::SharedStructField, &self.types);
+            // Floats are encoded using their bit pattern.
+            let bit_pattern_ty = match swift_ty.as_str() {
+                "Float" => Some("UInt32"),
+                "Double" => Some("UInt64"),
+                _ => None,
+            };
+
+            let (encoded, decoded) = match bit_pattern_ty {
+                Some(bits) => (
+                    format!("self.{field_name}.bitPattern.littleEndian"),
+                    format!("{swift_ty}(bitPattern: {bits}(littleEndian: buffer.loadUnaligned(fromByteOffset: {offset}, as: {bits}.self)))"),
+                ),
+                None => (
+                    format!("self.{field_name}.littleEndian"),
+                    format!("{swift_ty}(littleEndian: buffer.loadUnaligned(fromByteOffset: {offset}, as: {swift_ty}.self))"),
+                ),
+            };
+
+            encode_fields += &format!(
+                "\n        withUnsafeBytes(of: {encoded}) {{ bytes.append(contentsOf: $0) }}"
+            );
+            decoded_fields.push(format!("{field_name}: {decoded}"));
+        }
+        let decoded_fields = decoded_fields.join(", ");
+
+        format!(
+            r#"
+extension {struct_name} {{
+    /// The number of bytes in this struct's little-endian encoding.
+    public static var littleEndianByteCount: Int {{ {len} }}
+
+    /// Encode this struct's fields, in order and without padding, as little-endian bytes.
+    public func toLittleEndianBytes() -> [UInt8] {{
+        var bytes: [UInt8] = []
+        bytes.reserveCapacity({len}){encode_fields}
+        return bytes
+    }}
+
+    /// Decode a struct that was encoded using `toLittleEndianBytes()`, or return nil if the number of bytes is wrong.
+    public init?(littleEndianBytes bytes: [UInt8]) {{
+        guard bytes.count == {len} else {{
+            return nil
+        }}
+        self = bytes.withUnsafeBytes {{ buffer in
+            {struct_name}({decoded_fields})
+        }}
+    }}
+}}"#
+        )
+    }
+
     fn convert_fields_to_initializer_params<'a, T>(
         &self,
         struct_fields: impl IntoIterator<Item = &'a T>,
+        generics: &[Ident],
     ) -> String
     where
         T: StructField + 'a,
@@ -108,12 +392,16 @@ extension {option_ffi_name} {{
         let mut params = "".to_string();
 
         for field in struct_fields.into_iter() {
-            let bridged_ty = BridgedType::new_with_type(field.field_type(), &self.types).unwrap();
+            let default = match field.swift_default_value() {
+                Some(default) => format!(" = {}", default),
+                None => "".to_string(),
+            };
 
             params += &format!(
-                "{}: {},",
+                "{}: {}{},",
                 field.swift_name_string(),
-                bridged_ty.to_swift_type(TypePosition::SharedStructField, &self.types)
+                self.field_swift_type(field.field_type(), generics),
+                default
             );
         }
 
@@ -148,19 +436,21 @@ extension {option_ffi_name} {{
         body
     }
 
-    fn declare_fields<'a, T>(&self, struct_fields: impl IntoIterator<Item = &'a T>) -> String
+    fn declare_fields<'a, T>(
+        &self,
+        struct_fields: impl IntoIterator<Item = &'a T>,
+        generics: &[Ident],
+    ) -> String
     where
         T: StructField + 'a,
     {
         let mut fields = "".to_string();
 
         for field in struct_fields.into_iter() {
-            let bridged_ty = BridgedType::new_with_type(field.field_type(), &self.types).unwrap();
-
             fields += &format!(
                 "    public var {}: {}\n",
                 field.swift_name_string(),
-                bridged_ty.to_swift_type(TypePosition::SharedStructField, &self.types)
+                self.field_swift_type(field.field_type(), generics)
             );
         }
 
@@ -170,4 +460,161 @@ extension {option_ffi_name} {{
 
         fields
     }
+
+    /// The `Equatable`, `Hashable` and `Codable` conformances from `#[swift_bridge(derive(...))]`,
+    /// which Swift synthesizes from the struct's fields.
+    fn generate_derived_conformances(
+        &self,
+        shared_struct: &SharedStruct,
+        generics: &[Ident],
+    ) -> String {
+        let struct_name = shared_struct.swift_name_string();
+        let mut conformances = "".to_string();
+
+        let equatable_protocol = if shared_struct.derives.hashable {
+            Some("Hashable")
+        } else if shared_struct.derives.equatable {
+            Some("Equatable")
+        } else {
+            None
+        };
+        if let Some(protocol) = equatable_protocol {
+            conformances += &format!(
+                "\nextension {struct_name}: {protocol}{} {{}}",
+                where_generics_conform(generics, protocol)
+            );
+        }
+
+        if shared_struct.derives.codable {
+            conformances += &format!(
+                "\nextension {struct_name}: Codable{} {{{}}}",
+                where_generics_conform(generics, "Codable"),
+                self.generate_coding_keys(shared_struct)
+            );
+        }
+
+        conformances
+    }
+
+    /// Encode a `Codable` struct's fields using their Rust names, so that a field that was
+    /// renamed with `swift_name` has the same key as when Rust serializes it.
+    fn generate_coding_keys(&self, shared_struct: &SharedStruct) -> String {
+        let named = match &shared_struct.fields {
+            StructFields::Named(named) => named,
+            _ => return "".to_string(),
+        };
+
+        let mut cases = "".to_string();
+        for field in named {
+            let swift_name = field.swift_name_string();
+            let rust_name = field.name.to_string();
+
+            if swift_name == rust_name {
+                cases += &format!("        case {}\n", swift_name);
+            } else {
+                cases += &format!("        case {} = \"{}\"\n", swift_name, rust_name);
+            }
+        }
+
+        format!(
+            r#"
+    enum CodingKeys: String, CodingKey {{
+{cases}    }}
+"#
+        )
+    }
+
+    /// Lets tests check that a struct's `Codable` conformance and its serde implementation produce
+    /// the same JSON, by encoding it in Swift, decoding and encoding it in Rust, then decoding it
+    /// in Swift.
+    fn generate_serde_json_round_trip(&self, shared_struct: &SharedStruct) -> String {
+        let struct_name = shared_struct.swift_name_string();
+
+        format!(
+            r#"
+extension {struct_name} {{
+    public static func serdeJsonRoundTrip(_ value: {struct_name}) throws -> {struct_name} {{
+        let json = String(data: try JSONEncoder().encode(value), encoding: .utf8)!
+        let result = __swift_bridge__${struct_name}$_serde_json_round_trip({{ let rustString = json.intoRustString(); rustString.isOwned = false; return rustString.ptr }}())
+        let output = RustString(ptr: result.ok_or_err!).toString()
+        guard result.is_ok else {{
+            throw DecodingError.dataCorrupted(DecodingError.Context(codingPath: [], debugDescription: output))
+        }}
+        return try JSONDecoder().decode({struct_name}.self, from: output.data(using: .utf8)!)
+    }}
+}}"#
+        )
+    }
+
+    /// Lets a `RustVec` hold this struct, such as for a `Vec<SomeStruct>` field in another struct.
+    fn generate_vectorizable_extension(&self, shared_struct: &SharedStruct) -> String {
+        let struct_name = shared_struct.swift_name_string();
+
+        format!(
+            r#"
+extension {struct_name}: Vectorizable {{
+    public static func vecOfSelfNew() -> UnsafeMutableRawPointer {{
+        __swift_bridge__$Vec_{struct_name}$new()
+    }}
+
+    public static func vecOfSelfFree(vecPtr: UnsafeMutableRawPointer) {{
+        __swift_bridge__$Vec_{struct_name}$drop(vecPtr)
+    }}
+
+    public static func vecOfSelfPush(vecPtr: UnsafeMutableRawPointer, value: Self) {{
+        __swift_bridge__$Vec_{struct_name}$push(vecPtr, value.intoFfiRepr())
+    }}
+
+    public static func vecOfSelfPop(vecPtr: UnsafeMutableRawPointer) -> Optional<Self> {{
+        let maybeStruct = __swift_bridge__$Vec_{struct_name}$pop(vecPtr)
+        return maybeStruct.intoSwiftRepr()
+    }}
+
+    public static func vecOfSelfGet(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<Self> {{
+        let maybeStruct = __swift_bridge__$Vec_{struct_name}$get(vecPtr, index)
+        return maybeStruct.intoSwiftRepr()
+    }}
+
+    public static func vecOfSelfGetMut(vecPtr: UnsafeMutableRawPointer, index: UInt) -> Optional<Self> {{
+        let maybeStruct = __swift_bridge__$Vec_{struct_name}$get_mut(vecPtr, index)
+        return maybeStruct.intoSwiftRepr()
+    }}
+
+    public static func vecOfSelfAsPtr(vecPtr: UnsafeMutableRawPointer) -> UnsafePointer<Self> {{
+        UnsafePointer<Self>(OpaquePointer(__swift_bridge__$Vec_{struct_name}$as_ptr(vecPtr)))
+    }}
+
+    public static func vecOfSelfLen(vecPtr: UnsafeMutableRawPointer) -> UInt {{
+        __swift_bridge__$Vec_{struct_name}$len(vecPtr)
+    }}
+}}"#
+        )
+    }
+
+    /// The Swift type of a field, which is either a type or one of the struct's generics.
+    fn field_swift_type(&self, ty: &Type, generics: &[Ident]) -> String {
+        if let Type::Path(path) = ty {
+            if let Some(generic) = generics.iter().find(|g| path.path.is_ident(*g)) {
+                return generic.to_string();
+            }
+        }
+
+        BridgedType::new_with_type(ty, &self.types)
+            .unwrap()
+            .to_swift_type(TypePosition::SharedStructField, &self.types)
+    }
+}
+
+/// ` where T: Hashable` for a generic struct `Pair<T>`, so that it only conforms to a protocol when
+/// its generics do.
+fn where_generics_conform(generics: &[Ident], protocol: &str) -> String {
+    if generics.is_empty() {
+        return "".to_string();
+    }
+
+    let bounds: Vec<String> = generics
+        .iter()
+        .map(|generic| format!("{}: {}", generic, protocol))
+        .collect();
+    format!(" where {}", bounds.join(", "))
 }
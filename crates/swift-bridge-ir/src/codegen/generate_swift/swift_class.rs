@@ -1,4 +1,6 @@
-use crate::codegen::generate_swift::{generate_swift_class_methods, ClassProtocols};
+use crate::codegen::generate_swift::{
+    generate_swift_class_methods, swift_attributes, ClassProtocols,
+};
 use crate::parse::OpaqueForeignTypeDeclaration;
 use crate::{ParsedExternFn, TypeDeclarations, SWIFT_BRIDGE_PREFIX};
 use std::collections::HashMap;
@@ -11,7 +13,7 @@ pub(super) fn generate_swift_class(
     types: &TypeDeclarations,
     swift_bridge_path: &Path,
 ) -> String {
-    let type_name = ty.to_string();
+    let type_name = ty.swift_class_name();
 
     let class_methods = generate_swift_class_methods(
         &type_name,
@@ -40,18 +42,52 @@ fn create_class_declaration(
     ref_mut_self_methods: &[String],
     types: &TypeDeclarations,
 ) -> String {
-    let type_name = &ty.ty_name_ident().to_string();
-    let generics = ty.generics.angle_bracketed_generic_placeholders_string();
+    let type_name = &ty.swift_class_name();
+    let generics = if ty.has_own_swift_class {
+        "".to_string()
+    } else {
+        ty.generics.angle_bracketed_generic_placeholders_string()
+    };
+    let swift_attrs = swift_attributes(&ty.attributes.swift_attrs, "");
 
     let mut class_decl = {
-        let free_func_call = if ty.generics.len() == 0 {
-            format!("{}${}$_free(ptr)", SWIFT_BRIDGE_PREFIX, type_name)
+        let mut free_func_call = if ty.generics.len() == 0 || ty.has_own_swift_class {
+            format!("{}(ptr)", ty.free_rust_opaque_type_ffi_name())
         } else {
             "(self as! SwiftBridgeGenericFreer).rust_free()".to_string()
         };
+        if ty.attributes.identity_map {
+            free_func_call = format!(
+                "{type_name}.__swift_bridge__identityMap.removeReleased({prefix}${type_name}$_object_identity(ptr))
+            {free_func_call}",
+                type_name = type_name,
+                prefix = SWIFT_BRIDGE_PREFIX,
+                free_func_call = free_func_call
+            );
+        }
+
+        let mut maybe_import = "";
+        // Generic Swift classes are freed using `self`, which cannot be captured by a closure
+        // that outlives `deinit`, so `drop_queue` is rejected for them while parsing.
+        if let Some(queue) = ty
+            .attributes
+            .drop_queue
+            .as_ref()
+            .filter(|_| ty.generics.is_empty() || ty.has_own_swift_class)
+        {
+            free_func_call = format!(
+                r#"let ptr = self.ptr
+            {queue}.async {{
+                {free_func_call}
+            }}"#,
+                queue = queue,
+                free_func_call = free_func_call.replace("\n", "\n    ")
+            );
+            maybe_import = "import Dispatch\n";
+        }
 
         format!(
-            r#"public class {type_name}{generics}: {type_name}RefMut{generics} {{
+            r#"{maybe_import}{swift_attrs}public class {type_name}{generics}: {type_name}RefMut{generics} {{
     var isOwned: Bool = true
 
     public override init(ptr: UnsafeMutableRawPointer) {{
@@ -64,6 +100,8 @@ fn create_class_declaration(
         }}
     }}
 }}"#,
+            maybe_import = maybe_import,
+            swift_attrs = swift_attrs,
             type_name = type_name,
             generics = generics,
             free_func_call = free_func_call
@@ -73,11 +111,12 @@ fn create_class_declaration(
     let mut class_ref_mut_decl = {
         format!(
             r#"
-public class {type_name}RefMut{generics}: {type_name}Ref{generics} {{
+{swift_attrs}public class {type_name}RefMut{generics}: {type_name}Ref{generics} {{
     public override init(ptr: UnsafeMutableRawPointer) {{
         super.init(ptr: ptr)
     }}
 }}"#,
+            swift_attrs = swift_attrs,
             type_name = type_name,
             generics = generics
         )
@@ -85,13 +124,15 @@ public class {type_name}RefMut{generics}: {type_name}Ref{generics} {{
     let mut class_ref_decl = {
         format!(
             r#"
-public class {type_name}Ref{generics} {{
+{swift_attrs}public class {type_name}Ref{generics} {{
     var ptr: UnsafeMutableRawPointer
+    var __swift_bridge__parent: AnyObject?
 
     public init(ptr: UnsafeMutableRawPointer) {{
         self.ptr = ptr
     }}
 }}"#,
+            swift_attrs = swift_attrs,
             type_name = type_name,
             generics = generics
         )
@@ -119,6 +160,29 @@ extension {type_name}Ref: Identifiable {{{identifiable_var}}}"#,
         );
     }
 
+    if ty.attributes.identity_map {
+        class_decl += &format!(
+            r#"
+extension {type_name} {{
+    static let __swift_bridge__identityMap = __private__IdentityMap<{type_name}>()
+
+    static func __swift_bridge__fromIdentityMap(ptr: UnsafeMutableRawPointer) -> {type_name} {{
+        let (wrapper, isNew) = __swift_bridge__identityMap.wrapper(
+            for: {prefix}${type_name}$_object_identity(ptr),
+            existingIsValid: {{ $0.isOwned }},
+            makeWrapper: {{ {type_name}(ptr: ptr) }}
+        )
+        if !isNew {{
+            {prefix}${type_name}$_free(ptr)
+        }}
+        return wrapper
+    }}
+}}"#,
+            type_name = type_name,
+            prefix = SWIFT_BRIDGE_PREFIX,
+        );
+    }
+
     let initializers = if initializers.len() == 0 {
         "".to_string()
     } else {
@@ -175,7 +239,8 @@ extension {type_name}RefMut {{
         )
     };
 
-    let is_concrete_generic = ty.generics.len() > 0 && !ty.attributes.declare_generic;
+    let is_concrete_generic =
+        ty.generics.len() > 0 && !ty.attributes.declare_generic && !ty.has_own_swift_class;
 
     if ty.attributes.already_declared || is_concrete_generic {
         class_decl = "".to_string();
@@ -207,6 +272,91 @@ extension {ty_name}Ref: Equatable {{
     public static func == (lhs: {ty_name}Ref, rhs: {ty_name}Ref) -> Bool {{
         __swift_bridge__${ty_name}$_partial_eq(rhs.ptr, lhs.ptr)
     }}
+}}"#,
+            )
+        } else {
+            "".to_string()
+        }
+    };
+    let comparable_method: String = {
+        if ty.attributes.comparable {
+            let ty_name = ty.ty_name_ident();
+            format!(
+                r#"
+extension {ty_name}Ref: Comparable {{
+    public static func < (lhs: {ty_name}Ref, rhs: {ty_name}Ref) -> Bool {{
+        __swift_bridge__${ty_name}$_less_than(lhs.ptr, rhs.ptr)
+    }}
+}}"#,
+            )
+        } else {
+            "".to_string()
+        }
+    };
+    // An identity mapped clone goes through the identity map, in case the clone has the same
+    // `ObjectIdentity` as an object that Swift already holds.
+    let copy_method: String = {
+        if ty.attributes.clone {
+            let ty_name = ty.ty_name_ident();
+            let clone_ptr = format!("__swift_bridge__${ty_name}$_clone(self.ptr)");
+            let clone = if ty.attributes.identity_map {
+                format!("{ty_name}.__swift_bridge__fromIdentityMap(ptr: {clone_ptr})")
+            } else {
+                format!("{ty_name}(ptr: {clone_ptr})")
+            };
+            format!(
+                r#"
+extension {ty_name}Ref {{
+    public func copy() -> {ty_name} {{
+        {clone}
+    }}
+}}"#,
+            )
+        } else {
+            "".to_string()
+        }
+    };
+    let description_method: String = {
+        if ty.attributes.custom_string_convertible {
+            let ty_name = ty.ty_name_ident();
+            format!(
+                r#"
+extension {ty_name}Ref: CustomStringConvertible {{
+    public var description: String {{
+        RustString(ptr: __swift_bridge__${ty_name}$_description(self.ptr)).toString()
+    }}
+}}"#,
+            )
+        } else {
+            "".to_string()
+        }
+    };
+    let debug_description_method: String = {
+        if ty.attributes.custom_debug_string_convertible {
+            let ty_name = ty.ty_name_ident();
+            format!(
+                r#"
+extension {ty_name}Ref: CustomDebugStringConvertible {{
+    public var debugDescription: String {{
+        RustString(ptr: __swift_bridge__${ty_name}$_debug_description(self.ptr)).toString()
+    }}
+}}"#,
+            )
+        } else {
+            "".to_string()
+        }
+    };
+    // Conforming to `LocalizedError` instead of only `Error` lets `localizedDescription` use the
+    // Rust type's `Display` implementation.
+    let error_conformance: String = {
+        if ty.attributes.error {
+            let ty_name = ty.ty_name_ident();
+            format!(
+                r#"
+extension {ty_name}: LocalizedError {{
+    public var errorDescription: String? {{
+        RustString(ptr: __swift_bridge__${ty_name}$_error_description(self.ptr)).toString()
+    }}
 }}"#,
             )
         } else {
@@ -229,9 +379,18 @@ extension {ty_name}Ref: Hashable{{
             "".to_string()
         }
     };
+    // A class's `Sendable` conformance has to be declared in the same file as the class, and the
+    // owned and `RefMut` classes inherit it from the `Ref` class.
+    let sendable_conformance =
+        if ty.attributes.sendable && ty.generics.is_empty() && !ty.attributes.already_declared {
+            format!("\nextension {type_name}Ref: @unchecked Sendable {{}}")
+        } else {
+            "".to_string()
+        };
+    let store_class = generate_store_class(ty, class_protocols);
     let class = format!(
         r#"
-{class_decl}{initializers}{owned_instance_methods}{class_ref_decl}{ref_mut_instance_methods}{class_ref_mut_decl}{ref_instance_methods}{generic_freer}{equatable_method}{hashable_method}"#,
+{class_decl}{initializers}{owned_instance_methods}{class_ref_decl}{ref_mut_instance_methods}{class_ref_mut_decl}{ref_instance_methods}{generic_freer}{copy_method}{equatable_method}{comparable_method}{description_method}{debug_description_method}{error_conformance}{hashable_method}{sendable_conformance}{store_class}"#,
         class_decl = class_decl,
         class_ref_decl = class_ref_mut_decl,
         class_ref_mut_decl = class_ref_decl,
@@ -239,9 +398,86 @@ extension {ty_name}Ref: Hashable{{
         owned_instance_methods = owned_instance_methods,
         ref_mut_instance_methods = ref_mut_instance_methods,
         ref_instance_methods = ref_instance_methods,
+        copy_method = copy_method,
         equatable_method = equatable_method,
+        comparable_method = comparable_method,
+        description_method = description_method,
+        debug_description_method = debug_description_method,
+        error_conformance = error_conformance,
         hashable_method = hashable_method,
+        store_class = store_class,
     );
 
     return class;
 }
+
+// Generate an `ObservableObject` that publishes the type's state after every dispatched action.
+//
+// # Example
+//
+// ```
+// public class CounterStore: ObservableObject {
+//     public let inner: Counter
+//     @Published public private(set) var state: CounterState
+//
+//     public init(_ inner: Counter) {
+//         self.inner = inner
+//         self.state = inner.state()
+//     }
+//
+//     public func dispatch(_ action: CounterAction) {
+//         inner.dispatch(action)
+//         self.state = inner.state()
+//     }
+//
+//     public func refresh() {
+//         self.state = inner.state()
+//     }
+// }
+// ```
+fn generate_store_class(
+    ty: &OpaqueForeignTypeDeclaration,
+    class_protocols: &ClassProtocols,
+) -> String {
+    let (state, dispatch) = match (
+        &class_protocols.store.state,
+        &class_protocols.store.dispatch,
+    ) {
+        (Some(state), Some(dispatch)) if ty.generics.is_empty() => (state, dispatch),
+        _ => return "".to_string(),
+    };
+
+    let type_name = ty.ty_name_ident();
+    let swift_attrs = swift_attributes(&ty.attributes.swift_attrs, "");
+    let state_fn = &state.func_name;
+    let state_ty = &state.state_ty;
+    let dispatch_fn = &dispatch.func_name;
+    let action_ty = &dispatch.action_ty;
+    let action_arg = match &dispatch.action_label {
+        Some(label) => format!("{label}: action"),
+        None => "action".to_string(),
+    };
+
+    format!(
+        r#"
+import Combine
+{swift_attrs}public class {type_name}Store: ObservableObject {{
+    public let inner: {type_name}
+    @Published public private(set) var state: {state_ty}
+
+    public init(_ inner: {type_name}) {{
+        self.inner = inner
+        self.state = inner.{state_fn}()
+    }}
+
+    public func dispatch(_ action: {action_ty}) {{
+        inner.{dispatch_fn}({action_arg})
+        self.state = inner.{state_fn}()
+    }}
+
+    public func refresh() {{
+        self.state = inner.{state_fn}()
+    }}
+}}"#
+    )
+}
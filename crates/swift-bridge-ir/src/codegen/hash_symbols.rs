@@ -0,0 +1,287 @@
+//! Replace the link names of a `#[swift_bridge(hash_symbols)]` module's functions with short
+//! hashes.
+//!
+//! The generated Rust, C and Swift code is produced as usual and then rewritten, so that the rest
+//! of codegen never needs to know whether or not a module's symbols are hashed.
+//!
+//! - Rust: `#[export_name = "..."]` and `#[link_name = "..."]` values are hashed.
+//! - C: each function declaration keeps its readable name, so that the generated Swift can keep
+//!   calling it, but is given an asm label with the hashed symbol.
+//! - Swift: `@_cdecl("...")` values are hashed.
+
+use proc_macro2::{Group, Literal, TokenStream, TokenTree};
+use std::fmt::Write;
+
+/// A link name that was replaced with a hash.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HashedSymbol {
+    /// The symbol that ends up in the binary, i.e. `sb_8f3c2a5e1b7d9046`.
+    /// On Apple platforms the linker prefixes it with an underscore.
+    pub hashed: String,
+    /// The link name that would have been used, i.e. `__swift_bridge__$SomeType$some_method`.
+    pub original: String,
+}
+
+/// The hashed symbol for a link name.
+pub(crate) fn hashed_link_name(link_name: &str) -> String {
+    format!("sb_{:016x}", fnv1a(link_name))
+}
+
+/// 64 bit FNV-1a, which is used since, unlike `DefaultHasher`, its output is guaranteed to be the
+/// same for the proc macro and the build script across Rust versions.
+pub(crate) fn fnv1a(string: &str) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in string.bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+/// Module tables are looked up by name at runtime using `dlsym`, so their symbols are never
+/// hashed.
+fn should_hash(link_name: &str) -> bool {
+    link_name.starts_with("__swift_bridge__") && !link_name.ends_with("$module_table")
+}
+
+/// Hash the values of all `export_name` and `link_name` attributes.
+pub(crate) fn hash_rust_link_names(tokens: TokenStream) -> TokenStream {
+    let mut hashed = vec![];
+
+    for token in tokens {
+        let token = match token {
+            TokenTree::Group(group) => {
+                let mut new_group =
+                    Group::new(group.delimiter(), hash_rust_link_names(group.stream()));
+                new_group.set_span(group.span());
+                TokenTree::Group(new_group)
+            }
+            TokenTree::Literal(literal) if follows_link_name_attribute(&hashed) => {
+                match syn::parse_str::<syn::LitStr>(&literal.to_string()) {
+                    Ok(link_name) if should_hash(&link_name.value()) => {
+                        let mut new_literal =
+                            Literal::string(&hashed_link_name(&link_name.value()));
+                        new_literal.set_span(literal.span());
+                        TokenTree::Literal(new_literal)
+                    }
+                    _ => TokenTree::Literal(literal),
+                }
+            }
+            other => other,
+        };
+        hashed.push(token);
+    }
+
+    hashed.into_iter().collect()
+}
+
+/// Whether the previous tokens are `export_name =` or `link_name =`.
+fn follows_link_name_attribute(previous: &[TokenTree]) -> bool {
+    match previous {
+        [.., TokenTree::Ident(ident), TokenTree::Punct(punct)] => {
+            punct.as_char() == '=' && (ident == "export_name" || ident == "link_name")
+        }
+        _ => false,
+    }
+}
+
+/// Give every function declaration in the header an asm label with its hashed symbol.
+///
+/// `void __swift_bridge__$some_function(void);` becomes
+/// `void __swift_bridge__$some_function(void) __SWIFT_BRIDGE_SYMBOL("sb_...");`
+pub(crate) fn hash_c_header_link_names(
+    c_header: &str,
+    hashed_symbols: &mut Vec<HashedSymbol>,
+) -> String {
+    let mut hashed_header = String::with_capacity(c_header.len());
+
+    for line in c_header.split_inclusive('\n') {
+        let (declaration, newline) = match line.strip_suffix('\n') {
+            Some(declaration) => (declaration, "\n"),
+            None => (line, ""),
+        };
+
+        let fn_name = if declaration.starts_with("typedef") || !declaration.ends_with(");") {
+            None
+        } else {
+            declaration
+                .split('(')
+                .next()
+                .and_then(|before_args| before_args.split_whitespace().last())
+                .map(|fn_name| fn_name.trim_start_matches('*'))
+                .filter(|fn_name| should_hash(fn_name))
+        };
+
+        match fn_name {
+            Some(fn_name) => {
+                let hashed = record_hashed_symbol(fn_name, hashed_symbols);
+                write!(
+                    hashed_header,
+                    r#"{} __SWIFT_BRIDGE_SYMBOL("{}");{}"#,
+                    declaration.trim_end_matches(';'),
+                    hashed,
+                    newline
+                )
+                .unwrap();
+            }
+            None => hashed_header += line,
+        }
+    }
+
+    hashed_header
+}
+
+/// Hash the symbols of the Swift functions that are exposed to Rust using `@_cdecl`.
+pub(crate) fn hash_swift_cdecl_names(
+    swift: &str,
+    hashed_symbols: &mut Vec<HashedSymbol>,
+) -> String {
+    const CDECL: &str = r#"@_cdecl(""#;
+
+    let mut hashed_swift = String::with_capacity(swift.len());
+    let mut remaining = swift;
+
+    while let Some(start) = remaining.find(CDECL) {
+        let name_start = start + CDECL.len();
+        hashed_swift += &remaining[..name_start];
+        remaining = &remaining[name_start..];
+
+        let name_end = match remaining.find('"') {
+            Some(name_end) => name_end,
+            None => break,
+        };
+        let link_name = &remaining[..name_end];
+        if should_hash(link_name) {
+            hashed_swift += &record_hashed_symbol(link_name, hashed_symbols);
+        } else {
+            hashed_swift += link_name;
+        }
+        remaining = &remaining[name_end..];
+    }
+    hashed_swift += remaining;
+
+    hashed_swift
+}
+
+fn record_hashed_symbol(link_name: &str, hashed_symbols: &mut Vec<HashedSymbol>) -> String {
+    let hashed = hashed_link_name(link_name);
+    hashed_symbols.push(HashedSymbol {
+        hashed: hashed.clone(),
+        original: link_name.to_string(),
+    });
+    hashed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::assert_tokens_eq;
+    use quote::quote;
+
+    /// Verify that the hash of a link name never changes, since the proc macro and the build
+    /// script need to agree on it.
+    #[test]
+    fn hash_is_stable() {
+        assert_eq!(hashed_link_name(""), "sb_cbf29ce484222325");
+        assert_eq!(
+            hashed_link_name("__swift_bridge__$some_function"),
+            hashed_link_name("__swift_bridge__$some_function")
+        );
+        assert_ne!(
+            hashed_link_name("__swift_bridge__$some_function"),
+            hashed_link_name("__swift_bridge__$other_function")
+        );
+    }
+
+    /// Verify that we only hash the values of `export_name` and `link_name` attributes.
+    #[test]
+    fn hashes_rust_link_name_attributes() {
+        let tokens = quote! {
+            #[export_name = "__swift_bridge__$a"]
+            pub extern "C" fn __swift_bridge__a() {
+                let name = "__swift_bridge__$a";
+            }
+            extern "C" {
+                #[link_name = "__swift_bridge__$b"]
+                fn __swift_bridge__b();
+            }
+            #[export_name = "__swift_bridge__$my_plugin$module_table"]
+            pub extern "C" fn table() {}
+        };
+
+        let a = hashed_link_name("__swift_bridge__$a");
+        let b = hashed_link_name("__swift_bridge__$b");
+        let expected = quote! {
+            #[export_name = #a]
+            pub extern "C" fn __swift_bridge__a() {
+                let name = "__swift_bridge__$a";
+            }
+            extern "C" {
+                #[link_name = #b]
+                fn __swift_bridge__b();
+            }
+            #[export_name = "__swift_bridge__$my_plugin$module_table"]
+            pub extern "C" fn table() {}
+        };
+
+        assert_tokens_eq(&hash_rust_link_names(tokens), &expected);
+    }
+
+    /// Verify that we add an asm label to function declarations and leave everything else as is.
+    #[test]
+    fn hashes_c_header_function_declarations() {
+        let header = r#"typedef struct __swift_bridge__$SomeStruct { uint8_t field; } __swift_bridge__$SomeStruct;
+void* __swift_bridge__$SomeType$new(void);
+struct __swift_bridge__$SomeStruct __swift_bridge__$some_function(struct __swift_bridge__$SomeStruct arg);
+"#;
+
+        let mut hashed_symbols = vec![];
+        let hashed = hash_c_header_link_names(header, &mut hashed_symbols);
+
+        assert_eq!(
+            hashed,
+            format!(
+                r#"typedef struct __swift_bridge__$SomeStruct {{ uint8_t field; }} __swift_bridge__$SomeStruct;
+void* __swift_bridge__$SomeType$new(void) __SWIFT_BRIDGE_SYMBOL("{}");
+struct __swift_bridge__$SomeStruct __swift_bridge__$some_function(struct __swift_bridge__$SomeStruct arg) __SWIFT_BRIDGE_SYMBOL("{}");
+"#,
+                hashed_link_name("__swift_bridge__$SomeType$new"),
+                hashed_link_name("__swift_bridge__$some_function"),
+            )
+        );
+        assert_eq!(hashed_symbols.len(), 2);
+        assert_eq!(hashed_symbols[1].original, "__swift_bridge__$some_function");
+    }
+
+    /// Verify that we hash the names in `@_cdecl` attributes.
+    #[test]
+    fn hashes_swift_cdecl_names() {
+        let swift = r#"@_cdecl("__swift_bridge__$some_function")
+func __swift_bridge__some_function () {
+    some_function()
+}
+"#;
+
+        let mut hashed_symbols = vec![];
+        let hashed = hash_swift_cdecl_names(swift, &mut hashed_symbols);
+
+        assert_eq!(
+            hashed,
+            format!(
+                r#"@_cdecl("{}")
+func __swift_bridge__some_function () {{
+    some_function()
+}}
+"#,
+                hashed_link_name("__swift_bridge__$some_function")
+            )
+        );
+        assert_eq!(
+            hashed_symbols,
+            vec![HashedSymbol {
+                hashed: hashed_link_name("__swift_bridge__$some_function"),
+                original: "__swift_bridge__$some_function".to_string(),
+            }]
+        );
+    }
+}
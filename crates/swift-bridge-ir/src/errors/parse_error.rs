@@ -1,6 +1,6 @@
-use proc_macro2::Ident;
+use proc_macro2::{Ident, TokenStream};
 use quote::ToTokens;
-use syn::{Error, FnArg, Item, Receiver};
+use syn::{Error, Expr, FnArg, Item, Receiver};
 use syn::{ForeignItemFn, ForeignItemType, LitStr};
 use syn::{Token, Type};
 
@@ -32,6 +32,32 @@ pub(crate) enum ParseError {
     /// Declared a type that we already support.
     /// Example: `type u32`
     DeclaredBuiltInType { ty: ForeignItemType },
+    /// `std::time::Instant` is opaque and only meaningful within the current process, so there is
+    /// no Swift type that it could be converted into.
+    /// Example: `fn foo (bar: Instant)`
+    UnsupportedInstant { ty: Type },
+    /// `Cow<str>` can only be returned from an `extern "Rust"` function.
+    /// Example: `fn foo (bar: Cow<str>)`
+    CowStrNotRustReturnType { ty: Type },
+    /// `Box<dyn Fn>` can only be the type of an `extern "Rust"` function's argument.
+    /// Example: `fn foo () -> Box<dyn Fn()>`
+    BoxedDynFnNotRustArgType { ty: Type },
+    /// A slice of opaque Rust types was used in an `extern "Swift"` function or inside of an
+    /// `Option`.
+    /// Example: `fn foo (arg: Option<&[SomeRustType]>)`
+    UnsupportedOpaqueRustSlice { ty: Type },
+    /// A struct with a `&'static str` field can only be passed from Rust to Swift.
+    /// Example: `fn foo (arg: StructWithStaticStrField)` in an `extern "Rust"` block.
+    StaticStrStructPassedToRust { ty: Type },
+    /// `[T; N]` can't yet be held by an `Option` or a `Result`.
+    /// Example: `fn foo () -> Option<[u8; 4]>`
+    ArrayInOptionOrResult { ty: Type },
+    /// `BTreeMap<K, V>` can't yet be held by an `Option` or a `Result`.
+    /// Example: `fn foo () -> Option<BTreeMap<String, u32>>`
+    BTreeMapInOptionOrResult { ty: Type },
+    /// `Range<T>` and `RangeInclusive<T>` can't yet be held by an `Option` or a `Result`.
+    /// Example: `fn foo () -> Option<Range<u32>>`
+    RangeInOptionOrResult { ty: Type },
     /// A bridge module struct with one or more fields must have a
     /// `#\[swift_bridge(swift_repr ="...")\[\]` attribute so that we know whether to create a
     /// `struct` or `class` on the Swift side.
@@ -42,18 +68,75 @@ pub(crate) enum ParseError {
     StructUnrecognizedAttribute { attribute: Ident },
     /// An enum was declared with an unrecognized attribute.
     EnumUnrecognizedAttribute { attribute: Ident },
+    /// An enum variant's discriminant was not an integer literal, such as `North = 1` or
+    /// `Below = -1`.
+    EnumInvalidDiscriminant { discriminant: Expr },
+    /// Explicit discriminants can only be used on enums where none of the variants hold data.
+    EnumDiscriminantWithData { enum_ident: Ident },
+    /// `#[swift_bridge(non_exhaustive)]` can only be used on enums where none of the variants
+    /// hold data.
+    NonExhaustiveEnumWithData { enum_ident: Ident },
+    /// `#[swift_bridge(try_from_discriminant)]` can only be used on enums where none of the
+    /// variants hold data.
+    TryFromDiscriminantEnumWithData { enum_ident: Ident },
     /// There is no reason to use `swift_repr = "class"` on an empty struct.
     /// It's extra overhead with no advantages.
     EmptyStructHasSwiftReprClass {
         struct_ident: Ident,
         swift_repr_attr_value: LitStr,
     },
+    /// The changed fields of a `diffable` struct are tracked using a `u64` bit mask, so it can
+    /// have at most 64 fields.
+    DiffableStructHasTooManyFields { struct_ident: Ident },
+    /// Only `Equatable`, `Hashable` and `Codable` can be used in a struct's
+    /// `#[swift_bridge(derive(...))]`.
+    StructUnrecognizedDerive { derive: Ident },
+    /// The Swift conformances from `#[swift_bridge(derive(...))]` are synthesized from the
+    /// struct's fields, which a `swift_repr = "class"` struct does not store.
+    StructDeriveOnSwiftReprClass { struct_ident: Ident },
+    /// `#[swift_bridge(serde_json_round_trip)]` was used on a struct that is generic or that does
+    /// not derive `Codable` as well as serde's `Serialize` and `Deserialize`.
+    SerdeJsonRoundTripStructMissingDerives { struct_ident: Ident },
+    /// Unnamed fields are always named `_0`, `_1`, ... in Swift, so they cannot be renamed.
+    UnnamedStructFieldHasSwiftName { swift_name: LitStr },
+    /// A field's default value must be an integer, float or boolean literal.
+    StructFieldInvalidDefault { default: Expr },
+    /// The fields of a `little_endian` struct must be fixed-size integers or floats.
+    LittleEndianStructUnsupportedField { ty: Type },
+    /// A shared struct's `#[repr(...)]` contained something other than `C`, `packed` or
+    /// `align(N)`, where `N` is a power of two.
+    StructInvalidRepr { repr: TokenStream },
+    /// A shared struct cannot be both packed and aligned.
+    StructPackedAndAligned { struct_ident: Ident },
+    /// A generic shared struct needs `#[swift_bridge(instantiate(...))]`.
+    StructGenericsWithoutInstantiate { struct_ident: Ident },
+    /// One of the types in `#[swift_bridge(instantiate(...))]` does not have one type name for
+    /// each of the struct's generics.
+    StructInvalidInstantiation { instantiation: Type },
+    /// The field of a generic shared struct can either be one of the generics, such as `T`, or a
+    /// type that does not use the generics.
+    StructUnsupportedGenericField { ty: Type },
     /// See [`FunctionAttributeParseError`]
     FunctionAttribute(FunctionAttributeParseError),
     /// The function argument is a mutable reference to a Copy opaque type.
     /// We do not currently support passing mutable references to Copy opaque types across FFI.
     // Would need to Box the copy type and pass a pointer between languages.
     ArgCopyAndRefMut { arg: FnArg },
+    /// The `#[swift_bridge(out)]` attribute was used on an argument that is not a mutable
+    /// reference to a primitive, such as `&mut u32`.
+    ArgOutNotMutRef { arg: FnArg },
+    /// A mutable reference to a primitive, such as `&mut u32`, needs the
+    /// `#[swift_bridge(out)]` attribute.
+    ArgMutRefWithoutOut { arg: FnArg },
+    /// A constant's type must be an integer, float, `bool` or `&'static str`.
+    ConstUnsupportedType { ty: Type },
+    /// A constant's value must be a literal, such as `4096` or `"v1"`.
+    ConstInvalidValue { value: Expr },
+    /// `#[swift_bridge(Identifiable = "...")]` named a method that the type does not have.
+    IdentifiableMethodNotFound { method: LitStr },
+    /// `#[swift_bridge(drop_queue = "...")]` was used on a generic type that doesn't get its own
+    /// Swift class, such as a `declare_generic` type.
+    DropQueueOnGenericType { ty: Ident },
     /// There was an unsupported item in the module, such as a `use` statement.
     InvalidModuleItem { item: Item },
     /// The associated_to attribute is used for only an associated method.
@@ -63,6 +146,9 @@ pub(crate) enum ParseError {
 /// An error while parsing a function attribute.
 pub(crate) enum FunctionAttributeParseError {
     Identifiable(IdentifiableParseError),
+    Store(StoreParseError),
+    Compress(CompressParseError),
+    Scoped(ScopedParseError),
 }
 
 /// An error while parsing a function's `Identifiable` attribute.
@@ -73,6 +159,29 @@ pub(crate) enum IdentifiableParseError {
     MissingReturnType { fn_ident: Ident },
 }
 
+/// An error while parsing a function's `Store` attribute.
+pub(crate) enum StoreParseError {
+    /// A `Store(state)` function must take a single `(&self)` argument and return a value.
+    InvalidStateSignature { fn_ident: Ident },
+    /// A `Store(dispatch)` function must take `&mut self` and a single action argument.
+    InvalidDispatchSignature { fn_ident: Ident },
+}
+
+/// An error while parsing a function's `compress` attribute.
+pub(crate) enum CompressParseError {
+    /// Only synchronous extern "Rust" functions that return a `Vec<u8>` or a `String` can be
+    /// compressed.
+    UnsupportedFunction { fn_ident: Ident },
+}
+
+/// An error while parsing a function's `scoped` attribute.
+pub(crate) enum ScopedParseError {
+    /// Only synchronous extern "Rust" functions that take no arguments other than `self` and
+    /// return an owned opaque Rust type, or a method that returns a reference to one, can be
+    /// scoped.
+    UnsupportedFunction { fn_ident: Ident },
+}
+
 // <!-- ANCHOR: mdbook-parse-error-message -->
 impl Into<syn::Error> for ParseError {
     fn into(self) -> Error {
@@ -131,6 +240,45 @@ self: &mut SomeType
                 );
                 Error::new_spanned(ty, message)
             }
+            ParseError::UnsupportedInstant { ty } => {
+                let message = r#"`Instant` cannot be bridged since it has no fixed epoch.
+Use a `SystemTime` (a Swift `Date`) for a point in time, or a `Duration` (a Swift `TimeInterval`) for
+the time between two `Instant`s.
+"#;
+                Error::new_spanned(ty, message)
+            }
+            ParseError::CowStrNotRustReturnType { ty } => Error::new_spanned(
+                ty,
+                r#"`Cow<str>` can only be used as the return type of an extern "Rust" function.
+Use a `String` or `&str` instead.
+"#,
+            ),
+            ParseError::BoxedDynFnNotRustArgType { ty } => Error::new_spanned(
+                ty,
+                r#"`Box<dyn Fn>` can only be used as the type of an extern "Rust" function's argument.
+Use a `Box<dyn FnOnce>` instead.
+"#,
+            ),
+            ParseError::UnsupportedOpaqueRustSlice { ty } => Error::new_spanned(
+                ty,
+                r#"Slices of opaque Rust types can only be used in extern "Rust" functions, and cannot be wrapped in an `Option`."#,
+            ),
+            ParseError::StaticStrStructPassedToRust { ty } => Error::new_spanned(
+                ty,
+                r#"A struct with a `&'static str` field can only be passed from Rust to Swift, since Swift cannot create a `&'static str`."#,
+            ),
+            ParseError::ArrayInOptionOrResult { ty } => Error::new_spanned(
+                ty,
+                r#"An `Option` or `Result` that holds a `[T; N]` is not yet supported."#,
+            ),
+            ParseError::BTreeMapInOptionOrResult { ty } => Error::new_spanned(
+                ty,
+                r#"An `Option` or `Result` that holds a `BTreeMap<K, V>` is not yet supported."#,
+            ),
+            ParseError::RangeInOptionOrResult { ty } => Error::new_spanned(
+                ty,
+                r#"An `Option` or `Result` that holds a `Range<T>` or `RangeInclusive<T>` is not yet supported."#,
+            ),
             ParseError::StructMissingSwiftRepr { struct_ident } => {
                 let message = format!(
                     r#"Shared structs with one or more fields must specify their swift
@@ -156,6 +304,87 @@ TODO: Link to documntation on how to decide on the swift representation.
                 let message = r#"Invalid value. Must be either "class" or "struct"#;
                 Error::new_spanned(swift_repr_attr_value, message)
             }
+            ParseError::DiffableStructHasTooManyFields { struct_ident } => {
+                let message = format!(
+                    r#"Diffable struct {} must have 64 fields or fewer."#,
+                    struct_ident
+                );
+                Error::new_spanned(struct_ident, message)
+            }
+            ParseError::StructUnrecognizedDerive { derive } => {
+                let message = format!(
+                    r#"Did not recognize derive "{}". Expected "Equatable", "Hashable" or "Codable"."#,
+                    derive
+                );
+                Error::new_spanned(derive, message)
+            }
+            ParseError::StructDeriveOnSwiftReprClass { struct_ident } => {
+                let message = format!(
+                    r#"Struct {} must have `swift_repr = "struct"` to use `#[swift_bridge(derive(...))]`."#,
+                    struct_ident
+                );
+                Error::new_spanned(struct_ident, message)
+            }
+            ParseError::SerdeJsonRoundTripStructMissingDerives { struct_ident } => {
+                let message = format!(
+                    r#"Struct {} must derive serde's `Serialize` and `Deserialize` and use `#[swift_bridge(derive(Codable))]` to use `serde_json_round_trip`. Generic structs are not supported."#,
+                    struct_ident
+                );
+                Error::new_spanned(struct_ident, message)
+            }
+            ParseError::UnnamedStructFieldHasSwiftName { swift_name } => {
+                let message = r#"Only named fields can have a swift_name. Unnamed fields are named _0, _1, ... in Swift."#;
+                Error::new_spanned(swift_name, message)
+            }
+            ParseError::StructFieldInvalidDefault { default } => {
+                let message = format!(
+                    r#"Unsupported default value "{}". Field defaults must be integer, float or boolean literals."#,
+                    default.to_token_stream()
+                );
+                Error::new_spanned(default, message)
+            }
+            ParseError::LittleEndianStructUnsupportedField { ty } => {
+                let message = format!(
+                    r#"Field type "{}" cannot be encoded as little-endian bytes. Fields of little_endian structs must be fixed-size integers or floats."#,
+                    ty.to_token_stream()
+                );
+                Error::new_spanned(ty, message)
+            }
+            ParseError::StructInvalidRepr { repr } => {
+                let message = format!(
+                    r#"Unsupported repr "{}". Shared structs support `C`, `packed` and `align(N)`, where N is a power of two."#,
+                    repr
+                );
+                Error::new_spanned(repr, message)
+            }
+            ParseError::StructPackedAndAligned { struct_ident } => {
+                let message = format!(
+                    r#"Struct {} cannot be both packed and aligned."#,
+                    struct_ident
+                );
+                Error::new_spanned(struct_ident, message)
+            }
+            ParseError::StructGenericsWithoutInstantiate { struct_ident } => {
+                let message = format!(
+                    r#"Generic struct {} must list the types that it is used with, such as #[swift_bridge(instantiate(u32, f64))]."#,
+                    struct_ident
+                );
+                Error::new_spanned(struct_ident, message)
+            }
+            ParseError::StructInvalidInstantiation { instantiation } => {
+                let message = format!(
+                    r#"Cannot instantiate struct with "{}". Use one type name for each generic, such as `u32` or `(u32, f64)`."#,
+                    instantiation.to_token_stream()
+                );
+                Error::new_spanned(instantiation, message)
+            }
+            ParseError::StructUnsupportedGenericField { ty } => {
+                let message = format!(
+                    r#"Field type "{}" is not supported. Fields of generic structs must either be one of the generics, or not use them."#,
+                    ty.to_token_stream()
+                );
+                Error::new_spanned(ty, message)
+            }
             ParseError::EmptyStructHasSwiftReprClass {
                 struct_ident,
                 swift_repr_attr_value,
@@ -181,6 +410,31 @@ struct {struct_name};
                 let message = format!(r#"Did not recognize enum attribute "{}"."#, attribute);
                 Error::new_spanned(attribute, message)
             }
+            ParseError::EnumInvalidDiscriminant { discriminant } => {
+                let message = "Enum discriminants must be integer literals.";
+                Error::new_spanned(discriminant, message)
+            }
+            ParseError::EnumDiscriminantWithData { enum_ident } => {
+                let message = format!(
+                    r#"Enum {} cannot have explicit discriminants since one or more of its variants hold data."#,
+                    enum_ident
+                );
+                Error::new_spanned(enum_ident, message)
+            }
+            ParseError::NonExhaustiveEnumWithData { enum_ident } => {
+                let message = format!(
+                    r#"Enum {} cannot be non_exhaustive since one or more of its variants hold data."#,
+                    enum_ident
+                );
+                Error::new_spanned(enum_ident, message)
+            }
+            ParseError::TryFromDiscriminantEnumWithData { enum_ident } => {
+                let message = format!(
+                    r#"Enum {} cannot use try_from_discriminant since one or more of its variants hold data."#,
+                    enum_ident
+                );
+                Error::new_spanned(enum_ident, message)
+            }
             ParseError::FunctionAttribute(fn_attrib) => match fn_attrib {
                 FunctionAttributeParseError::Identifiable(identifiable) => match identifiable {
                     IdentifiableParseError::MustBeRefSelf { fn_ident } => {
@@ -198,16 +452,86 @@ struct {struct_name};
                         Error::new_spanned(fn_ident, message)
                     }
                 },
+                FunctionAttributeParseError::Store(store) => match store {
+                    StoreParseError::InvalidStateSignature { fn_ident } => {
+                        let message = format!(
+                            r#"Store(state) function {} must take `&self` as its only argument and return the state."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                    StoreParseError::InvalidDispatchSignature { fn_ident } => {
+                        let message = format!(
+                            r#"Store(dispatch) function {} must take `&mut self` and an action as its only arguments."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                },
+                FunctionAttributeParseError::Compress(compress) => match compress {
+                    CompressParseError::UnsupportedFunction { fn_ident } => {
+                        let message = format!(
+                            r#"compress function {} must be a non-async extern "Rust" function that returns a `Vec<u8>` or a `String`."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                },
+                FunctionAttributeParseError::Scoped(scoped) => match scoped {
+                    ScopedParseError::UnsupportedFunction { fn_ident } => {
+                        let message = format!(
+                            r#"scoped function {} must be a non-async extern "Rust" function that takes no arguments other than `self` and returns an owned opaque Rust type or a method that returns a reference to one."#,
+                            fn_ident
+                        );
+                        Error::new_spanned(fn_ident, message)
+                    }
+                },
             },
             ParseError::ArgCopyAndRefMut { arg } => {
                 let message =
                     format!(r#"Mutable references to opaque Copy types are not yet supported."#);
                 Error::new_spanned(arg, message)
             }
+            ParseError::ArgOutNotMutRef { arg } => {
+                let message = "Only mutable references to primitives, such as `&mut u32`, can be out arguments.";
+                Error::new_spanned(arg, message)
+            }
+            ParseError::ArgMutRefWithoutOut { arg } => {
+                let message =
+                    "Mutable references to primitives must be annotated with `#[swift_bridge(out)]`.";
+                Error::new_spanned(arg, message)
+            }
+            ParseError::ConstUnsupportedType { ty } => {
+                let message = format!(
+                    r#"Unsupported constant type "{}". Constants must be integers, floats, `bool` or `&'static str`."#,
+                    ty.to_token_stream()
+                );
+                Error::new_spanned(ty, message)
+            }
+            ParseError::ConstInvalidValue { value } => {
+                let message = format!(
+                    r#"Unsupported constant value "{}". Constants must be literals of the constant's type."#,
+                    value.to_token_stream()
+                );
+                Error::new_spanned(value, message)
+            }
+            ParseError::IdentifiableMethodNotFound { method } => {
+                let message = format!(
+                    r#"The Identifiable attribute names a method "{}", but the type has no method with that name."#,
+                    method.value()
+                );
+                Error::new_spanned(method, message)
+            }
             ParseError::InvalidModuleItem { item } => {
-                let message = format!(r#"Only `extern` blocks, structs and enums are supported."#);
+                let message =
+                    r#"Only `extern` blocks, structs, enums and constants are supported."#
+                        .to_string();
                 Error::new_spanned(item, message)
             }
+            ParseError::DropQueueOnGenericType { ty } => Error::new_spanned(
+                ty,
+                r#"`drop_queue` cannot be used on a generic type that shares a generic Swift class, such as a `declare_generic` type or one of its concrete types."#,
+            ),
             ParseError::InvalidAssociatedTo { self_ } => {
                 let message =
                     format!(r#"The associated_to attribute can only be used on static methods."#);
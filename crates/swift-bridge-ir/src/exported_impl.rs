@@ -0,0 +1,346 @@
+use crate::bridged_type::{pat_type_pat_is_self, BridgedType};
+use crate::codegen::{CodegenConfig, SwiftCodeAndCHeader};
+use crate::parse::TypeDeclarations;
+use crate::SwiftBridgeModule;
+use proc_macro2::{Group, Ident, TokenStream, TokenTree};
+use quote::{format_ident, quote, ToTokens};
+use syn::parse::{Parse, ParseStream};
+use syn::{Attribute, FnArg, ImplItem, ItemImpl, Signature, Type, Visibility};
+
+/// An `impl` block that is annotated with `#[swift_bridge::export]`.
+///
+/// The type gets declared as an opaque Rust type and every public method with a bridgeable
+/// signature gets exposed to Swift, without needing to be re-declared inside of a bridge module.
+/// Bridge modules that use the type declare it with `#[swift_bridge(already_declared)]`.
+///
+/// ```no_run,ignore
+/// #[swift_bridge::export]
+/// impl Counter {
+///     pub fn new() -> Self {
+///         Counter { count: 0 }
+///     }
+///
+///     pub fn increment(&mut self) {
+///         self.count += 1;
+///     }
+///
+///     // Not exposed to Swift since the method is not public.
+///     fn reset(&mut self) {
+///         self.count = 0;
+///     }
+/// }
+/// ```
+pub struct ExportedImpl {
+    /// The impl block as it was written, minus any `#[swift_bridge(...)]` attributes.
+    item_impl: ItemImpl,
+    /// A bridge module that declares the type and its exported methods.
+    module: SwiftBridgeModule,
+}
+
+impl ExportedImpl {
+    /// Generate the corresponding Swift code and C header for the exported methods.
+    pub fn generate_swift_code_and_c_header(&self, config: CodegenConfig) -> SwiftCodeAndCHeader {
+        self.module.generate_swift_code_and_c_header(config)
+    }
+}
+
+impl Parse for ExportedImpl {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut item_impl: ItemImpl = input.parse()?;
+
+        if let Some((_, trait_path, _)) = &item_impl.trait_ {
+            return Err(syn::Error::new_spanned(
+                trait_path,
+                "#[swift_bridge::export] cannot be used on a trait implementation.",
+            ));
+        }
+        if !item_impl.generics.params.is_empty() {
+            return Err(syn::Error::new_spanned(
+                &item_impl.generics,
+                "#[swift_bridge::export] cannot be used on a generic impl block.",
+            ));
+        }
+        let ident = match item_impl.self_ty.as_ref() {
+            Type::Path(path) if path.qself.is_none() && path.path.get_ident().is_some() => {
+                path.path.get_ident().unwrap().clone()
+            }
+            other => {
+                return Err(syn::Error::new_spanned(
+                    other,
+                    "#[swift_bridge::export] can only be used on an impl block for a named type.",
+                ));
+            }
+        };
+
+        let type_attrs: Vec<Attribute> = item_impl
+            .attrs
+            .iter()
+            .filter(|attr| is_swift_bridge_attribute(attr))
+            .cloned()
+            .collect();
+        item_impl
+            .attrs
+            .retain(|attr| !is_swift_bridge_attribute(attr));
+
+        // The types that the exported methods can use.
+        let types = syn::parse2::<SwiftBridgeModule>(quote! {
+            mod __swift_bridge__types {
+                extern "Rust" {
+                    type #ident;
+                }
+            }
+        })?
+        .types;
+
+        let mut declarations = vec![];
+        for impl_item in item_impl.items.iter_mut() {
+            let method = match impl_item {
+                ImplItem::Method(method) => method,
+                _ => continue,
+            };
+
+            let method_attrs: Vec<Attribute> = method
+                .attrs
+                .iter()
+                .filter(|attr| is_swift_bridge_attribute(attr))
+                .cloned()
+                .collect();
+            method.attrs.retain(|attr| !is_swift_bridge_attribute(attr));
+
+            if !matches!(method.vis, Visibility::Public(_)) {
+                continue;
+            }
+
+            let sig = &method.sig;
+            if !sig.generics.params.is_empty()
+                || sig.unsafety.is_some()
+                || sig.abi.is_some()
+                || sig.variadic.is_some()
+            {
+                continue;
+            }
+
+            let mut sig: Signature = syn::parse2(replace_self_type(sig.to_token_stream(), &ident))?;
+            sig.constness = None;
+            if !has_bridgeable_signature(&sig, &types) {
+                continue;
+            }
+
+            // The bridge module parser only reads one `#[swift_bridge(...)]` attribute per
+            // function, so we combine them.
+            let mut fn_args: Vec<TokenStream> = method_attrs
+                .iter()
+                .map(|attr| match attr.tokens.clone().into_iter().next() {
+                    Some(TokenTree::Group(group)) => group.stream(),
+                    _ => TokenStream::new(),
+                })
+                .filter(|args| !args.is_empty())
+                .collect();
+            if sig.receiver().is_none() {
+                fn_args.insert(0, quote! { associated_to = #ident });
+            }
+
+            let maybe_attr = if fn_args.is_empty() {
+                quote! {}
+            } else {
+                quote! { #[swift_bridge(#(#fn_args),*)] }
+            };
+            declarations.push(quote! {
+                #maybe_attr
+                #sig;
+            });
+        }
+
+        let mod_name = format_ident!("__swift_bridge__export_{}", ident);
+        let module: SwiftBridgeModule = syn::parse2(quote! {
+            mod #mod_name {
+                extern "Rust" {
+                    #(#type_attrs)*
+                    type #ident;
+
+                    #(#declarations)*
+                }
+            }
+        })?;
+
+        Ok(ExportedImpl { item_impl, module })
+    }
+}
+
+impl ToTokens for ExportedImpl {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let item_impl = &self.item_impl;
+        let module = &self.module;
+
+        let t = quote! {
+            #item_impl
+
+            #module
+        };
+        t.to_tokens(tokens);
+    }
+}
+
+fn is_swift_bridge_attribute(attr: &Attribute) -> bool {
+    attr.path.is_ident("swift_bridge")
+}
+
+/// Whether or not every argument and the return type can cross the bridge.
+fn has_bridgeable_signature(sig: &Signature, types: &TypeDeclarations) -> bool {
+    for arg in sig.inputs.iter() {
+        if let FnArg::Typed(pat_ty) = arg {
+            if pat_type_pat_is_self(pat_ty) {
+                continue;
+            }
+            if BridgedType::new_with_type(&pat_ty.ty, types).is_none() {
+                return false;
+            }
+        }
+    }
+
+    BridgedType::new_with_return_type(&sig.output, types).is_some()
+}
+
+/// `fn new() -> Self` -> `fn new() -> SomeType`
+pub(crate) fn replace_self_type(tokens: TokenStream, ident: &Ident) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(|token| match token {
+            TokenTree::Ident(self_ty) if self_ty == "Self" => TokenTree::Ident(ident.clone()),
+            TokenTree::Group(group) => {
+                let mut replaced =
+                    Group::new(group.delimiter(), replace_self_type(group.stream(), ident));
+                replaced.set_span(group.span());
+                TokenTree::Group(replaced)
+            }
+            other => other,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::assert_tokens_contain;
+
+    fn parse_exported_impl(tokens: TokenStream) -> ExportedImpl {
+        syn::parse2(tokens).unwrap()
+    }
+
+    /// Verify that we keep the user's impl block and expose its public methods.
+    #[test]
+    fn exports_public_methods() {
+        let exported = parse_exported_impl(quote! {
+            impl Counter {
+                pub fn new() -> Self {
+                    Counter { count: 0 }
+                }
+
+                pub fn count(&self) -> u32 {
+                    self.count
+                }
+
+                fn reset(&mut self) {
+                    self.count = 0;
+                }
+            }
+        });
+        let tokens = exported.to_token_stream();
+
+        assert_tokens_contain(
+            &tokens,
+            &quote! {
+                fn reset(&mut self) {
+                    self.count = 0;
+                }
+            },
+        );
+        assert_tokens_contain(
+            &tokens,
+            &quote! {
+                #[export_name = "__swift_bridge__$Counter$new"]
+                pub extern "C" fn __swift_bridge__Counter_new() -> *mut super::Counter {
+                    Box::into_raw(Box::new({
+                        let val: super::Counter = super::Counter::new();
+                        val
+                    })) as *mut super::Counter
+                }
+            },
+        );
+        assert_tokens_contain(
+            &tokens,
+            &quote! {
+                #[export_name = "__swift_bridge__$Counter$count"]
+            },
+        );
+        assert!(!tokens.to_string().contains("Counter$reset"));
+    }
+
+    /// Verify that we skip public methods whose signatures cannot cross the bridge.
+    #[test]
+    fn skips_methods_that_are_not_bridgeable() {
+        let exported = parse_exported_impl(quote! {
+            impl Counter {
+                pub fn count(&self) -> u32 {
+                    self.count
+                }
+
+                pub fn as_any(&self) -> &dyn std::any::Any {
+                    self
+                }
+
+                pub fn generic<T>(&self, _val: T) {}
+
+                pub unsafe fn raw(&self) -> u32 {
+                    self.count
+                }
+            }
+        });
+        let generated =
+            exported.generate_swift_code_and_c_header(CodegenConfig::no_features_enabled());
+
+        assert!(generated.swift.contains("public func count() -> UInt32"));
+        assert!(!generated.swift.contains("as_any"));
+        assert!(!generated.swift.contains("generic"));
+        assert!(!generated.swift.contains("raw"));
+    }
+
+    /// Verify that we forward `#[swift_bridge(...)]` attributes to the generated declarations.
+    #[test]
+    fn forwards_swift_bridge_attributes() {
+        let exported = parse_exported_impl(quote! {
+            #[swift_bridge(Equatable)]
+            impl Counter {
+                #[swift_bridge(swift_name = "makeCounter")]
+                pub fn new() -> Self {
+                    Counter { count: 0 }
+                }
+            }
+        });
+        let generated =
+            exported.generate_swift_code_and_c_header(CodegenConfig::no_features_enabled());
+
+        assert!(generated
+            .swift
+            .contains("class public func makeCounter() -> Counter"));
+        assert!(generated.swift.contains("extension CounterRef: Equatable"));
+        assert!(!exported
+            .to_token_stream()
+            .to_string()
+            .contains("swift_bridge (swift_name"));
+    }
+
+    /// Verify that we return an error if the attribute is used on a trait implementation.
+    #[test]
+    fn error_if_trait_impl() {
+        let result: syn::Result<ExportedImpl> = syn::parse2(quote! {
+            impl Default for Counter {
+                fn default() -> Self {
+                    Counter { count: 0 }
+                }
+            }
+        });
+
+        assert!(result.is_err());
+    }
+}
@@ -8,21 +8,28 @@
 #![deny(missing_docs)]
 
 use proc_macro2::Ident;
-use syn::Path;
+use std::path::PathBuf;
+use syn::{LitStr, Path};
 
 use crate::bridge_module_attributes::CfgAttr;
-use crate::parse::TypeDeclarations;
+use crate::parse::{BridgeConstant, TypeDeclarations};
 use crate::parsed_extern_fn::ParsedExternFn;
 
+pub use self::api_report::{PublicApiReport, UnbridgeableItem, UnbridgeableReason};
 pub use self::bridge_macro_attributes::{SwiftBridgeModuleAttr, SwiftBridgeModuleAttrs};
-pub use self::codegen::CodegenConfig;
+pub use self::bridged_item::BridgedItem;
+pub use self::codegen::{CodegenConfig, HashedSymbol, SwiftCodeAndCHeader};
+pub use self::exported_impl::ExportedImpl;
 
 mod errors;
 mod parse;
 
+mod api_report;
 mod bridge_macro_attributes;
 mod bridge_module_attributes;
+mod bridged_item;
 mod bridged_type;
+mod exported_impl;
 mod parsed_extern_fn;
 
 mod codegen;
@@ -60,8 +67,18 @@ pub struct SwiftBridgeModule {
     name: Ident,
     types: TypeDeclarations,
     functions: Vec<ParsedExternFn>,
+    /// `const NAME: TYPE = VALUE;` items.
+    constants: Vec<BridgeConstant>,
     swift_bridge_path: Path,
     cfg_attrs: Vec<CfgAttr>,
+    /// `#[swift_bridge(module_table = "...")]`
+    module_table: Option<LitStr>,
+    /// `#[swift_bridge(hash_symbols)]`
+    hash_symbols: bool,
+    /// `#[swift_bridge(reflection = "...")]`
+    reflection: Option<LitStr>,
+    /// The files that `#[swift_bridge(include = "...")]` read the module's items from.
+    included_files: Vec<PathBuf>,
 }
 
 impl SwiftBridgeModule {
@@ -1,15 +1,23 @@
-use crate::bridge_module_attributes::CfgAttr;
+use crate::bridge_module_attributes::{CfgAttr, ModuleAttr, ModuleAttrs};
+use crate::bridged_type::shared_struct::holds_struct_with_static_str_field;
 use crate::bridged_type::BridgedType;
-use crate::errors::{ParseError, ParseErrors};
+use crate::errors::{
+    FunctionAttributeParseError, IdentifiableParseError, ParseError, ParseErrors, ScopedParseError,
+};
+use crate::parse::included_files::read_included_files;
+use crate::parse::parse_const::ConstantParser;
 use crate::parse::parse_enum::SharedEnumDeclarationParser;
 use crate::parse::parse_extern_mod::ForeignModParser;
 use crate::parse::parse_struct::SharedStructDeclarationParser;
 use crate::SwiftBridgeModule;
 use proc_macro2::TokenTree;
 use quote::{quote, ToTokens};
+use std::ops::Deref;
 use syn::parse::{Parse, ParseStream};
-use syn::{Item, ItemMod, Token};
+use syn::{FnArg, Item, ItemMod, ReturnType, Token, Type};
 
+mod included_files;
+mod parse_const;
 mod parse_enum;
 mod parse_extern_mod;
 mod parse_struct;
@@ -17,6 +25,8 @@ mod parse_struct;
 mod type_declarations;
 pub(crate) use self::type_declarations::*;
 
+pub(crate) use self::parse_const::BridgeConstant;
+
 impl Parse for SwiftBridgeModule {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let module_and_errors: SwiftBridgeModuleAndErrors = input.parse()?;
@@ -62,6 +72,10 @@ impl Parse for SwiftBridgeModuleAndErrors {
             let mut type_declarations = TypeDeclarations::default();
             let mut unresolved_types = vec![];
             let mut cfg_attrs = vec![];
+            let mut module_table = None;
+            let mut hash_symbols = false;
+            let mut reflection = None;
+            let mut includes = vec![];
 
             for attr in item_mod.attrs {
                 match attr.path.to_token_stream().to_string().as_str() {
@@ -69,11 +83,38 @@ impl Parse for SwiftBridgeModuleAndErrors {
                         let cfg: CfgAttr = syn::parse2(attr.tokens)?;
                         cfg_attrs.push(cfg);
                     }
+                    "swift_bridge" if is_parenthesized(&attr.tokens) => {
+                        let module_attrs: ModuleAttrs = syn::parse2(attr.tokens)?;
+                        for module_attr in module_attrs.attrs {
+                            match module_attr {
+                                ModuleAttr::ModuleTable(name) => module_table = Some(name),
+                                ModuleAttr::HashSymbols => hash_symbols = true,
+                                ModuleAttr::NetAddrsAsStrings => {
+                                    type_declarations.set_net_addrs_as_strings(true)
+                                }
+                                ModuleAttr::Reflection(name) => reflection = Some(name),
+                                ModuleAttr::Include(include) => includes.push(include),
+                                ModuleAttr::Utf8(validation) => {
+                                    type_declarations.set_utf8_validation(validation)
+                                }
+                            }
+                        }
+                    }
                     _ => {}
                 };
             }
 
-            for outer_mod_item in item_mod.content.unwrap().1 {
+            let mut items = item_mod.content.unwrap().1;
+            let mut constants = vec![];
+            let mut included_files = vec![];
+            for include in includes {
+                for included in read_included_files(&include)? {
+                    items.extend(included.file.items);
+                    included_files.push(included.path);
+                }
+            }
+
+            for outer_mod_item in items {
                 match outer_mod_item {
                     Item::ForeignMod(foreign_mod) => {
                         ForeignModParser {
@@ -85,15 +126,22 @@ impl Parse for SwiftBridgeModuleAndErrors {
                         .parse(foreign_mod)?;
                     }
                     Item::Struct(item_struct) => {
-                        let shared_struct = SharedStructDeclarationParser {
+                        let shared_structs = SharedStructDeclarationParser {
                             item_struct,
                             errors: &mut errors,
                         }
                         .parse()?;
-                        type_declarations.insert(
-                            shared_struct.name.to_string(),
-                            TypeDeclaration::Shared(SharedTypeDeclaration::Struct(shared_struct)),
-                        );
+                        for shared_struct in shared_structs {
+                            // Generic structs are looked up by their concrete type, such as
+                            // "Pair<u32>".
+                            let type_name = shared_struct.rust_type_tokens().to_string();
+                            type_declarations.insert(
+                                type_name.replace(" ", ""),
+                                TypeDeclaration::Shared(SharedTypeDeclaration::Struct(
+                                    shared_struct,
+                                )),
+                            );
+                        }
                     }
                     Item::Enum(item_enum) => {
                         let shared_enum = SharedEnumDeclarationParser {
@@ -106,6 +154,14 @@ impl Parse for SwiftBridgeModuleAndErrors {
                             TypeDeclaration::Shared(SharedTypeDeclaration::Enum(shared_enum)),
                         );
                     }
+                    Item::Const(item_const) => {
+                        let constant = ConstantParser {
+                            item_const,
+                            errors: &mut errors,
+                        }
+                        .parse();
+                        constants.extend(constant);
+                    }
                     invalid_item => {
                         let error = ParseError::InvalidModuleItem { item: invalid_item };
                         errors.push(error);
@@ -118,17 +174,134 @@ impl Parse for SwiftBridgeModuleAndErrors {
                     continue;
                 }
 
+                let mentions_instant = unresolved_type
+                    .to_token_stream()
+                    .to_string()
+                    .split(|c: char| !c.is_alphanumeric() && c != '_')
+                    .any(|word| word == "Instant");
+                if mentions_instant {
+                    errors.push(ParseError::UnsupportedInstant {
+                        ty: unresolved_type.clone(),
+                    });
+                    continue;
+                }
+
                 errors.push(ParseError::UndeclaredType {
                     ty: unresolved_type.clone(),
                 });
             }
 
+            for ty in type_declarations.types() {
+                let opaque = match ty {
+                    TypeDeclaration::Opaque(opaque) => opaque,
+                    _ => continue,
+                };
+                let method = match &opaque.attributes.identifiable {
+                    Some(method) => method,
+                    None => continue,
+                };
+
+                let func = functions.iter().find(|func| {
+                    let is_associated = matches!(
+                        &func.associated_type,
+                        Some(TypeDeclaration::Opaque(associated)) if associated.ty == opaque.ty
+                    );
+                    is_associated && func.is_method() && func.func.sig.ident == method.value()
+                });
+                let func = match func {
+                    Some(func) => func,
+                    None => {
+                        errors.push(ParseError::IdentifiableMethodNotFound {
+                            method: method.clone(),
+                        });
+                        continue;
+                    }
+                };
+
+                let is_ref_self_no_args = func.func.sig.inputs.len() == 1
+                    && func.self_reference().is_some()
+                    && func.self_mutability().is_none();
+                if !is_ref_self_no_args {
+                    errors.push(ParseError::FunctionAttribute(
+                        FunctionAttributeParseError::Identifiable(
+                            IdentifiableParseError::MustBeRefSelf {
+                                fn_ident: func.func.sig.ident.clone(),
+                            },
+                        ),
+                    ));
+                }
+                if matches!(&func.func.sig.output, ReturnType::Default) {
+                    errors.push(ParseError::FunctionAttribute(
+                        FunctionAttributeParseError::Identifiable(
+                            IdentifiableParseError::MissingReturnType {
+                                fn_ident: func.func.sig.ident.clone(),
+                            },
+                        ),
+                    ));
+                }
+            }
+
+            for func in functions.iter() {
+                let arg_types = func.func.sig.inputs.iter().filter_map(|arg| match arg {
+                    FnArg::Typed(pat_ty) => Some(pat_ty.ty.deref()),
+                    FnArg::Receiver(_) => None,
+                });
+                let return_type = match &func.func.sig.output {
+                    ReturnType::Type(_, ty) => Some(ty.deref()),
+                    ReturnType::Default => None,
+                };
+                for ty in arg_types.chain(return_type) {
+                    let is_supported = match BridgedType::new_with_type(ty, &type_declarations) {
+                        Some(ty) => ty.is_supported_opaque_rust_slice(func.host_lang),
+                        None => true,
+                    };
+                    if !is_supported {
+                        errors.push(ParseError::UnsupportedOpaqueRustSlice { ty: ty.clone() });
+                    }
+                }
+
+                // Values that Swift passes to Rust.
+                let swift_to_rust_types: Vec<&Type> = if func.host_lang.is_rust() {
+                    func.func
+                        .sig
+                        .inputs
+                        .iter()
+                        .filter_map(|arg| match arg {
+                            FnArg::Typed(pat_ty) => Some(pat_ty.ty.deref()),
+                            FnArg::Receiver(_) => None,
+                        })
+                        .collect()
+                } else {
+                    return_type.into_iter().collect()
+                };
+                for ty in swift_to_rust_types {
+                    if holds_struct_with_static_str_field(ty, &type_declarations) {
+                        errors.push(ParseError::StaticStrStructPassedToRust { ty: ty.clone() });
+                    }
+                }
+
+                if func.scoped.is_some() && func.scoped_value(&type_declarations).is_none() {
+                    errors.push(ParseError::FunctionAttribute(
+                        FunctionAttributeParseError::Scoped(
+                            ScopedParseError::UnsupportedFunction {
+                                fn_ident: func.func.sig.ident.clone(),
+                            },
+                        ),
+                    ));
+                }
+            }
+
             let module = SwiftBridgeModule {
                 name: module_name,
                 types: type_declarations,
                 functions,
+                constants,
                 swift_bridge_path: syn::parse2(quote! { swift_bridge }).unwrap(),
                 cfg_attrs,
+                module_table,
+                hash_symbols,
+                reflection,
+                included_files,
             };
             Ok(SwiftBridgeModuleAndErrors { module, errors })
         } else {
@@ -140,6 +313,14 @@ impl Parse for SwiftBridgeModuleAndErrors {
     }
 }
 
+/// Whether the tokens are a `(...)` group, such as in `#[swift_bridge(...)]`.
+fn is_parenthesized(tokens: &proc_macro2::TokenStream) -> bool {
+    matches!(
+        tokens.clone().into_iter().next(),
+        Some(TokenTree::Group(group)) if group.delimiter() == proc_macro2::Delimiter::Parenthesis
+    )
+}
+
 // Used to fast-forward our attribute parsing to the next attribute when we've run into an
 // issue parsing the current attribute.
 fn move_input_cursor_to_next_comma(input: ParseStream) {
@@ -164,6 +345,8 @@ fn move_input_cursor_to_next_comma(input: ParseStream) {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::bridge_module_attributes::Utf8Validation;
+    use crate::parse::parse_const::ConstantValue;
     use crate::test_utils::{parse_errors, parse_ok};
 
     /// Verify that we can parse a cfg feature from a module.
@@ -186,6 +369,137 @@ mod tests {
         };
     }
 
+    /// Verify that we can parse a module table attribute from a module.
+    #[test]
+    fn parse_module_table_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            #[swift_bridge(module_table = "my_plugin")]
+            mod foo {}
+        };
+
+        let module = parse_ok(tokens);
+
+        assert_eq!(module.module_table.unwrap().value(), "my_plugin");
+    }
+
+    /// Verify that we can parse a hash symbols attribute alongside other module attributes.
+    #[test]
+    fn parse_hash_symbols_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            #[swift_bridge(module_table = "my_plugin", hash_symbols)]
+            mod foo {}
+        };
+
+        let module = parse_ok(tokens);
+
+        assert!(module.hash_symbols);
+        assert!(module.module_table.is_some());
+    }
+
+    /// Verify that we can parse a reflection attribute from a module.
+    #[test]
+    fn parse_reflection_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            #[swift_bridge(reflection = "my_module")]
+            mod foo {}
+        };
+
+        let module = parse_ok(tokens);
+
+        assert_eq!(module.reflection.unwrap().value(), "my_module");
+    }
+
+    /// Verify that the items in included files are parsed as part of the module, so that they can
+    /// use the module's types and the module can use theirs.
+    #[test]
+    fn parse_include_attribute() {
+        let dir = std::env::temp_dir().join("swift-bridge-ir-parse-include");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("users.rs"),
+            r#"
+extern "Rust" {
+    type User;
+    fn current_user() -> User;
+}
+"#,
+        )
+        .unwrap();
+        std::fs::write(
+            dir.join("settings.rs"),
+            r#"
+struct Settings {
+    owner_id: u32,
+}
+"#,
+        )
+        .unwrap();
+
+        let include = format!("{}/*.rs", dir.display());
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            #[swift_bridge(include = #include)]
+            mod foo {
+                extern "Rust" {
+                    fn settings_owner(settings: Settings) -> User;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert_eq!(module.functions.len(), 2);
+        assert!(module.types.get("User").is_some());
+        assert!(module.types.get("Settings").is_some());
+        assert_eq!(
+            module.included_files,
+            vec![dir.join("settings.rs"), dir.join("users.rs")]
+        );
+    }
+
+    /// Verify that we can parse a net addrs as strings attribute.
+    #[test]
+    fn parse_net_addrs_as_strings_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            #[swift_bridge(net_addrs_as_strings)]
+            mod foo {}
+        };
+
+        let module = parse_ok(tokens);
+
+        assert!(module.types.net_addrs_as_strings());
+    }
+
+    /// Verify that we can parse a utf8 attribute, and that we validate strings by default.
+    #[test]
+    fn parse_utf8_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod foo {}
+        };
+        let module = parse_ok(tokens);
+        assert_eq!(module.types.utf8_validation(), Utf8Validation::Validate);
+
+        for (value, expected) in [
+            ("validate", Utf8Validation::Validate),
+            ("lossy", Utf8Validation::Lossy),
+            ("unchecked", Utf8Validation::Unchecked),
+        ] {
+            let tokens = quote! {
+                #[swift_bridge::bridge]
+                #[swift_bridge(utf8 = #value)]
+                mod foo {}
+            };
+            let module = parse_ok(tokens);
+            assert_eq!(module.types.utf8_validation(), expected);
+        }
+    }
+
     /// Verify that we get an error when parsing an unsupported module item, such as a
     /// `use` statement.
     #[test]
@@ -207,4 +521,96 @@ mod tests {
             _ => panic!(),
         }
     }
+
+    /// Verify that we parse constants.
+    #[test]
+    fn parse_constants() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod foo {
+                const BUFFER_SIZE: usize = 4096;
+                const OFFSET: i32 = -1;
+                const RATIO: f64 = 0.5;
+                const ENABLED: bool = true;
+                const NAME: &'static str = "v1";
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let constants: Vec<(String, &ConstantValue)> = module
+            .constants
+            .iter()
+            .map(|constant| (constant.name.to_string(), &constant.value))
+            .collect();
+        assert_eq!(
+            constants,
+            vec![
+                (
+                    "BUFFER_SIZE".to_string(),
+                    &ConstantValue::Number("4096".to_string())
+                ),
+                (
+                    "OFFSET".to_string(),
+                    &ConstantValue::Number("-1".to_string())
+                ),
+                (
+                    "RATIO".to_string(),
+                    &ConstantValue::Number("0.5".to_string())
+                ),
+                ("ENABLED".to_string(), &ConstantValue::Bool(true)),
+                ("NAME".to_string(), &ConstantValue::Str("v1".to_string())),
+            ]
+        );
+    }
+
+    /// Verify that we get an error if a constant has an unsupported type or a value that is not
+    /// a literal.
+    #[test]
+    fn invalid_constants() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod foo {
+                const A: String = "a";
+                const B: u32 = 1 + 2;
+                const C: u32 = true;
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 3);
+        assert!(matches!(
+            &errors[0],
+            ParseError::ConstUnsupportedType { .. }
+        ));
+        assert!(matches!(&errors[1], ParseError::ConstInvalidValue { .. }));
+        assert!(matches!(&errors[2], ParseError::ConstInvalidValue { .. }));
+    }
+
+    /// Verify that we get a dedicated error when bridging an `Instant`, since it cannot be
+    /// converted into a Swift type.
+    #[test]
+    fn instant_is_unsupported() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod foo {
+                extern "Rust" {
+                    fn a () -> Instant;
+                    fn b (arg: std::time::Instant);
+                    fn c () -> Option<Instant>;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 3);
+        for error in errors.iter() {
+            match error {
+                ParseError::UnsupportedInstant { .. } => {}
+                _ => panic!(),
+            }
+        }
+    }
 }
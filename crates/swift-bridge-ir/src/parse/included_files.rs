@@ -0,0 +1,125 @@
+//! Reads the files that a `#[swift_bridge(include = "...")]` module attribute points to.
+
+use std::path::{Path, PathBuf};
+use syn::{File, LitStr};
+
+/// A file whose items get added to a bridge module.
+pub(crate) struct IncludedFile {
+    /// The absolute path of the file.
+    pub path: PathBuf,
+    pub file: File,
+}
+
+/// Read and parse the files that an `include = "..."` pattern points to, in the order of their
+/// file names.
+///
+/// Relative paths are relative to the crate's `Cargo.toml`, since that's where Cargo runs both
+/// proc macros and build scripts from. The file name can contain a `*`, such as in
+/// `"src/ffi/*.rs"`.
+pub(crate) fn read_included_files(include: &LitStr) -> syn::Result<Vec<IncludedFile>> {
+    let pattern = include.value();
+    let manifest_dir = std::env::var_os("CARGO_MANIFEST_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_default();
+
+    let paths = match_include_pattern(&manifest_dir.join(&pattern))
+        .map_err(|err| syn::Error::new(include.span(), err))?;
+    if paths.is_empty() {
+        return Err(syn::Error::new(
+            include.span(),
+            format!(r#"No files match "{}"."#, pattern),
+        ));
+    }
+
+    let mut included = vec![];
+    for path in paths {
+        let contents = std::fs::read_to_string(&path).map_err(|err| {
+            syn::Error::new(
+                include.span(),
+                format!("Could not read {}: {}", path.display(), err),
+            )
+        })?;
+        let file: File = syn::parse_str(&contents).map_err(|err| {
+            syn::Error::new(
+                include.span(),
+                format!("Error while parsing {}: {}", path.display(), err),
+            )
+        })?;
+
+        included.push(IncludedFile { path, file });
+    }
+
+    Ok(included)
+}
+
+/// The files that match a path whose file name can contain one `*`.
+fn match_include_pattern(pattern: &Path) -> Result<Vec<PathBuf>, String> {
+    let file_name = pattern
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    let (prefix, suffix) = match file_name.split_once('*') {
+        Some(prefix_and_suffix) => prefix_and_suffix,
+        None => return Ok(vec![pattern.to_path_buf()]),
+    };
+    if suffix.contains('*') {
+        return Err("Only one `*` is supported in an included file name.".to_string());
+    }
+
+    let dir = pattern.parent().unwrap_or(Path::new(""));
+    let entries = std::fs::read_dir(dir)
+        .map_err(|err| format!("Could not read directory {}: {}", dir.display(), err))?;
+
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| {
+            let name = match path.file_name() {
+                Some(name) => name.to_string_lossy(),
+                None => return false,
+            };
+            path.is_file()
+                && name.len() >= prefix.len() + suffix.len()
+                && name.starts_with(prefix)
+                && name.ends_with(suffix)
+        })
+        .collect();
+    paths.sort();
+
+    Ok(paths)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proc_macro2::Span;
+
+    /// Verify that a `*` matches every file with the same prefix and suffix, sorted by name.
+    #[test]
+    fn matches_wildcard_file_names() {
+        let dir = std::env::temp_dir().join("swift-bridge-ir-included-files");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        for name in ["b.rs", "a.rs", "notes.txt"] {
+            std::fs::write(dir.join(name), "").unwrap();
+        }
+
+        let paths = match_include_pattern(&dir.join("*.rs")).unwrap();
+
+        assert_eq!(paths, vec![dir.join("a.rs"), dir.join("b.rs")]);
+    }
+
+    /// Verify that we return an error if no files match the pattern.
+    #[test]
+    fn error_if_no_files_match() {
+        let dir = std::env::temp_dir().join("swift-bridge-ir-no-included-files");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let include = LitStr::new(&format!("{}/*.rs", dir.display()), Span::call_site());
+        let err = read_included_files(&include).err().unwrap();
+
+        assert!(err.to_string().starts_with("No files match"));
+    }
+}
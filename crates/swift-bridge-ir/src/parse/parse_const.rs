@@ -0,0 +1,303 @@
+//! Parses `const NAME: TYPE = VALUE;` items in a bridge module.
+
+use crate::errors::{ParseError, ParseErrors};
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{Attribute, Expr, ExprLit, ExprUnary, ItemConst, Lit, Type, UnOp};
+
+/// A constant that gets defined in Rust, Swift and the C header.
+///
+/// ```no_run,ignore
+/// #[swift_bridge::bridge]
+/// mod ffi {
+///     const BUFFER_SIZE: usize = 4096;
+///     const PROTOCOL_NAME: &'static str = "my-protocol";
+/// }
+/// ```
+pub(crate) struct BridgeConstant {
+    pub attrs: Vec<Attribute>,
+    pub name: Ident,
+    pub ty: ConstantType,
+    /// The type as it was written in the bridge module.
+    pub rust_ty: Type,
+    pub value: ConstantValue,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) enum ConstantType {
+    U8,
+    I8,
+    U16,
+    I16,
+    U32,
+    I32,
+    U64,
+    I64,
+    Usize,
+    Isize,
+    F32,
+    F64,
+    Bool,
+    Str,
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum ConstantValue {
+    /// The base 10 digits of an integer or float literal, such as `-1` or `2.5`.
+    Number(String),
+    Bool(bool),
+    Str(String),
+}
+
+pub(crate) struct ConstantParser<'a> {
+    pub item_const: ItemConst,
+    pub errors: &'a mut ParseErrors,
+}
+
+impl<'a> ConstantParser<'a> {
+    pub fn parse(self) -> Option<BridgeConstant> {
+        let item_const = self.item_const;
+
+        let ty = match ConstantType::new(&item_const.ty) {
+            Some(ty) => ty,
+            None => {
+                self.errors
+                    .push(ParseError::ConstUnsupportedType { ty: *item_const.ty });
+                return None;
+            }
+        };
+
+        let value = match ConstantValue::new(&item_const.expr, ty) {
+            Some(value) => value,
+            None => {
+                self.errors.push(ParseError::ConstInvalidValue {
+                    value: *item_const.expr,
+                });
+                return None;
+            }
+        };
+
+        Some(BridgeConstant {
+            attrs: item_const.attrs,
+            name: item_const.ident,
+            ty,
+            rust_ty: *item_const.ty,
+            value,
+        })
+    }
+}
+
+impl ConstantType {
+    fn new(ty: &Type) -> Option<Self> {
+        let ty = match ty.to_token_stream().to_string().as_str() {
+            "u8" => ConstantType::U8,
+            "i8" => ConstantType::I8,
+            "u16" => ConstantType::U16,
+            "i16" => ConstantType::I16,
+            "u32" => ConstantType::U32,
+            "i32" => ConstantType::I32,
+            "u64" => ConstantType::U64,
+            "i64" => ConstantType::I64,
+            "usize" => ConstantType::Usize,
+            "isize" => ConstantType::Isize,
+            "f32" => ConstantType::F32,
+            "f64" => ConstantType::F64,
+            "bool" => ConstantType::Bool,
+            "& str" | "& 'static str" => ConstantType::Str,
+            _ => return None,
+        };
+        Some(ty)
+    }
+
+    fn is_float(&self) -> bool {
+        matches!(self, ConstantType::F32 | ConstantType::F64)
+    }
+
+    fn is_integer(&self) -> bool {
+        !self.is_float() && !matches!(self, ConstantType::Bool | ConstantType::Str)
+    }
+
+    fn to_swift_type(self) -> &'static str {
+        match self {
+            ConstantType::U8 => "UInt8",
+            ConstantType::I8 => "Int8",
+            ConstantType::U16 => "UInt16",
+            ConstantType::I16 => "Int16",
+            ConstantType::U32 => "UInt32",
+            ConstantType::I32 => "Int32",
+            ConstantType::U64 => "UInt64",
+            ConstantType::I64 => "Int64",
+            ConstantType::Usize => "UInt",
+            ConstantType::Isize => "Int",
+            ConstantType::F32 => "Float",
+            ConstantType::F64 => "Double",
+            ConstantType::Bool => "Bool",
+            ConstantType::Str => "String",
+        }
+    }
+
+    fn to_c_type(self) -> &'static str {
+        match self {
+            ConstantType::U8 => "uint8_t",
+            ConstantType::I8 => "int8_t",
+            ConstantType::U16 => "uint16_t",
+            ConstantType::I16 => "int16_t",
+            ConstantType::U32 => "uint32_t",
+            ConstantType::I32 => "int32_t",
+            ConstantType::U64 => "uint64_t",
+            ConstantType::I64 => "int64_t",
+            ConstantType::Usize => "uintptr_t",
+            ConstantType::Isize => "intptr_t",
+            ConstantType::F32 => "float",
+            ConstantType::F64 => "double",
+            ConstantType::Bool => "bool",
+            ConstantType::Str => "char* const",
+        }
+    }
+
+    /// The suffix that keeps C from truncating large integer literals.
+    fn c_literal_suffix(&self) -> &'static str {
+        match self {
+            ConstantType::U8 | ConstantType::U16 | ConstantType::U32 => "u",
+            ConstantType::U64 | ConstantType::Usize => "ull",
+            ConstantType::I64 | ConstantType::Isize => "ll",
+            ConstantType::F32 => "f",
+            _ => "",
+        }
+    }
+}
+
+impl ConstantValue {
+    fn new(expr: &Expr, ty: ConstantType) -> Option<Self> {
+        match expr {
+            Expr::Lit(ExprLit { lit, .. }) => match lit {
+                Lit::Int(int) if ty.is_integer() => {
+                    Some(ConstantValue::Number(int.base10_digits().to_string()))
+                }
+                Lit::Float(float) if ty.is_float() => {
+                    Some(ConstantValue::Number(float.base10_digits().to_string()))
+                }
+                Lit::Bool(bool) if ty == ConstantType::Bool => {
+                    Some(ConstantValue::Bool(bool.value))
+                }
+                Lit::Str(str) if ty == ConstantType::Str => Some(ConstantValue::Str(str.value())),
+                _ => None,
+            },
+            Expr::Unary(ExprUnary {
+                op: UnOp::Neg(_),
+                expr,
+                ..
+            }) => match ConstantValue::new(expr, ty)? {
+                ConstantValue::Number(digits) => {
+                    Some(ConstantValue::Number(format!("-{}", digits)))
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+impl BridgeConstant {
+    pub fn to_rust_tokens(&self) -> TokenStream {
+        let attrs = &self.attrs;
+        let name = &self.name;
+        let ty = &self.rust_ty;
+        let value = match &self.value {
+            ConstantValue::Number(digits) => {
+                let value: TokenStream = digits.parse().unwrap();
+                value
+            }
+            ConstantValue::Bool(bool) => quote! { #bool },
+            ConstantValue::Str(str) => quote! { #str },
+        };
+
+        quote! {
+            #(#attrs)*
+            pub const #name: #ty = #value;
+        }
+    }
+
+    pub fn to_swift(&self) -> String {
+        let value = match &self.value {
+            ConstantValue::Number(digits) => {
+                // Swift floats need at least one digit after the decimal point.
+                if digits.ends_with('.') {
+                    format!("{}0", digits)
+                } else {
+                    digits.clone()
+                }
+            }
+            ConstantValue::Bool(bool) => bool.to_string(),
+            // Rust's escapes, such as `\n` and `\u{1F600}`, are also valid in Swift.
+            ConstantValue::Str(str) => format!("{:?}", str),
+        };
+
+        format!(
+            "public let {name}: {ty} = {value}\n",
+            name = self.name,
+            ty = self.ty.to_swift_type(),
+            value = value
+        )
+    }
+
+    /// The constant is prefixed in the C header so that it does not clash with the Swift
+    /// constant of the same name.
+    pub fn to_c_header(&self) -> String {
+        let value = match &self.value {
+            ConstantValue::Number(digits) => {
+                let suffix = self.ty.c_literal_suffix();
+                if self.ty == ConstantType::F32 && !digits.contains(['.', 'e', 'E']) {
+                    format!("{}.{}", digits, suffix)
+                } else {
+                    format!("{}{}", digits, suffix)
+                }
+            }
+            ConstantValue::Bool(bool) => bool.to_string(),
+            ConstantValue::Str(str) => c_string_literal(str),
+        };
+
+        format!(
+            "static const {ty} __swift_bridge__${name} = {value};\n",
+            ty = self.ty.to_c_type(),
+            name = self.name,
+            value = value
+        )
+    }
+
+    /// The headers that the constant's C type needs.
+    pub fn c_includes(&self) -> Option<&'static str> {
+        match self.ty {
+            ConstantType::Bool => Some("stdbool.h"),
+            ConstantType::F32 | ConstantType::F64 | ConstantType::Str => None,
+            _ => Some("stdint.h"),
+        }
+    }
+}
+
+/// A C string literal that escapes everything other than printable ASCII as octal.
+fn c_string_literal(str: &str) -> String {
+    let mut literal = "\"".to_string();
+    for byte in str.bytes() {
+        match byte {
+            b'"' => literal += "\\\"",
+            b'\\' => literal += "\\\\",
+            b' '..=b'~' => literal.push(byte as char),
+            _ => literal += &format!("\\{:03o}", byte),
+        }
+    }
+    literal += "\"";
+    literal
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Verify that non-ASCII characters are escaped in C string literals.
+    #[test]
+    fn escapes_c_string_literals() {
+        assert_eq!(c_string_literal("a\"b\\c"), r#""a\"b\\c""#);
+        assert_eq!(c_string_literal("é\n"), r#""\303\251\012""#);
+    }
+}
@@ -1,6 +1,6 @@
 use crate::bridged_type::{EnumVariant, SharedEnum, StructFields};
-use crate::errors::ParseErrors;
-use syn::ItemEnum;
+use crate::errors::{ParseError, ParseErrors};
+use syn::{Expr, ExprLit, ExprUnary, ItemEnum, Lit, UnOp};
 
 use self::enum_attributes::SharedEnumAllAttributes;
 
@@ -21,25 +21,78 @@ impl<'a> SharedEnumDeclarationParser<'a> {
         let mut variants = vec![];
 
         for v in item_enum.variants {
+            let discriminant = match v.discriminant {
+                Some((_eq, expr)) => match parse_discriminant(&expr) {
+                    Some(discriminant) => Some(discriminant),
+                    None => {
+                        self.errors
+                            .push(ParseError::EnumInvalidDiscriminant { discriminant: expr });
+                        None
+                    }
+                },
+                None => None,
+            };
+
             let variant = EnumVariant {
                 name: v.ident,
                 fields: StructFields::from_syn_fields(v.fields),
+                discriminant,
             };
             variants.push(variant);
         }
 
+        let has_discriminant = variants.iter().any(|v| v.discriminant.is_some());
+        let has_data = variants
+            .iter()
+            .any(|v| !matches!(v.fields, StructFields::Unit));
+        if has_discriminant && has_data {
+            self.errors.push(ParseError::EnumDiscriminantWithData {
+                enum_ident: item_enum.ident.clone(),
+            });
+        }
+        if attribs.swift_bridge.non_exhaustive && has_data {
+            self.errors.push(ParseError::NonExhaustiveEnumWithData {
+                enum_ident: item_enum.ident.clone(),
+            });
+        }
+        if attribs.swift_bridge.try_from_discriminant && has_data {
+            self.errors
+                .push(ParseError::TryFromDiscriminantEnumWithData {
+                    enum_ident: item_enum.ident.clone(),
+                });
+        }
+
         let shared_enum = SharedEnum {
             name: item_enum.ident,
             variants,
             already_declared: attribs.swift_bridge.already_declared,
             swift_name: attribs.swift_bridge.swift_name,
             derive: attribs.derive,
+            discriminant_check: attribs.swift_bridge.discriminant_check,
+            non_exhaustive: attribs.swift_bridge.non_exhaustive,
+            try_from_discriminant: attribs.swift_bridge.try_from_discriminant,
+            swift_attrs: attribs.swift_bridge.swift_attrs,
         };
 
         Ok(shared_enum)
     }
 }
 
+/// `1` -> `Some(1)`, `-1` -> `Some(-1)`, `1 + 1` -> `None`
+fn parse_discriminant(expr: &Expr) -> Option<i64> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(int), ..
+        }) => int.base10_parse().ok(),
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => parse_discriminant(expr).map(|discriminant| -discriminant),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::bridged_type::StructFields;
@@ -153,6 +206,25 @@ mod tests {
         assert!(ty.already_declared);
     }
 
+    /// Verify that we can parse the `#[swift_bridge(discriminant_check)`] attribute.
+    #[test]
+    fn discriminant_check_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(discriminant_check)]
+                enum SomeEnum {
+                    Variant
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = &module.types.types()[0].unwrap_shared_enum();
+        assert!(ty.discriminant_check);
+    }
+
     /// Verify that we return an error if an attribute isn't recognized.
     #[test]
     fn error_if_attribute_unrecognized() {
@@ -184,6 +256,158 @@ mod tests {
         };
     }
 
+    /// Verify that we can parse explicit discriminants on an enum whose variants do not hold data.
+    #[test]
+    fn parse_explicit_discriminants() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                enum Direction {
+                    North = 1,
+                    South,
+                    Below = -1,
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_enum();
+        assert_eq!(ty.variants[0].discriminant, Some(1));
+        assert_eq!(ty.variants[1].discriminant, None);
+        assert_eq!(ty.variants[2].discriminant, Some(-1));
+    }
+
+    /// Verify that we return an error if a discriminant is not an integer literal, or if an enum
+    /// that holds data has a discriminant.
+    #[test]
+    fn error_if_invalid_discriminant() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                enum Direction {
+                    North = 1 + 1,
+                }
+
+                enum WithData {
+                    Variant(u8),
+                    Other = 2,
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 2);
+
+        match &errors[0] {
+            ParseError::EnumInvalidDiscriminant { discriminant } => {
+                assert_eq!(discriminant.to_token_stream().to_string(), "1 + 1");
+            }
+            _ => panic!(),
+        };
+        match &errors[1] {
+            ParseError::EnumDiscriminantWithData { enum_ident } => {
+                assert_eq!(enum_ident.to_string(), "WithData");
+            }
+            _ => panic!(),
+        };
+    }
+
+    /// Verify that we can parse the `#[swift_bridge(non_exhaustive)]` attribute, and that we
+    /// return an error if it is used on an enum that holds data.
+    #[test]
+    fn non_exhaustive_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(non_exhaustive)]
+                enum Direction {
+                    North,
+                }
+            }
+        };
+        let module = parse_ok(tokens);
+        assert!(module.types.types()[0].unwrap_shared_enum().non_exhaustive);
+
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(non_exhaustive)]
+                enum WithData {
+                    Variant(u8),
+                }
+            }
+        };
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+        match &errors[0] {
+            ParseError::NonExhaustiveEnumWithData { enum_ident } => {
+                assert_eq!(enum_ident.to_string(), "WithData");
+            }
+            _ => panic!(),
+        };
+    }
+
+    /// Verify that we can parse the `#[swift_bridge(try_from_discriminant)]` attribute, and that
+    /// we return an error if it is used on an enum that holds data.
+    #[test]
+    fn try_from_discriminant_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(try_from_discriminant)]
+                enum Direction {
+                    North,
+                }
+            }
+        };
+        let module = parse_ok(tokens);
+        assert!(
+            module.types.types()[0]
+                .unwrap_shared_enum()
+                .try_from_discriminant
+        );
+
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(try_from_discriminant)]
+                enum WithData {
+                    Variant(u8),
+                }
+            }
+        };
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+        match &errors[0] {
+            ParseError::TryFromDiscriminantEnumWithData { enum_ident } => {
+                assert_eq!(enum_ident.to_string(), "WithData");
+            }
+            _ => panic!(),
+        };
+    }
+
+    /// Verify that we can parse the `#[swift_bridge(swift_attr = "...")]` attribute.
+    #[test]
+    fn swift_attr_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_attr = "@_spi(Internal)")]
+                enum Direction {
+                    North,
+                }
+            }
+        };
+        let module = parse_ok(tokens);
+
+        assert_eq!(
+            module.types.types()[0].unwrap_shared_enum().swift_attrs,
+            vec!["@_spi(Internal)"]
+        );
+    }
+
     /// Verify that we can parse #[derive(Debug)] on enums
     #[test]
     fn derive_debug() {
@@ -202,4 +426,25 @@ mod tests {
         let ty = module.types.types()[0].unwrap_shared_enum();
         assert!(ty.derive.debug);
     }
+
+    /// Verify that we can parse #[derive(Clone, PartialEq)] on enums
+    #[test]
+    fn derive_clone_and_partial_eq() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[derive(Clone, PartialEq)]
+                enum Shape {
+                    Circle { radius: f32 }
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_enum();
+        assert!(ty.derive.clone);
+        assert!(ty.derive.partial_eq);
+        assert!(!ty.derive.debug);
+    }
 }
@@ -28,6 +28,8 @@ impl SharedEnumAllAttributes {
                     for arg in args.into_iter() {
                         match arg.get_ident().unwrap().to_string().as_str() {
                             "Debug" => attributes.derive.debug = true,
+                            "Clone" => attributes.derive.clone = true,
+                            "PartialEq" => attributes.derive.partial_eq = true,
                             _ => todo!("Unsupported derive macro; please see https://github.com/chinedufn/swift-bridge/issues/190#issuecomment-1463234027"),
                         }
                     }
@@ -48,8 +50,12 @@ impl SharedEnumAllAttributes {
 
 pub(super) enum EnumAttr {
     AlreadyDeclared,
+    DiscriminantCheck,
     Error(ParseError),
+    NonExhaustive,
+    SwiftAttr(LitStr),
     SwiftName(LitStr),
+    TryFromDiscriminant,
 }
 
 #[derive(Default)]
@@ -57,14 +63,22 @@ pub(super) struct SharedEnumSwiftBridgeAttributes {
     pub errors: Vec<ParseError>,
     pub already_declared: bool,
     pub swift_name: Option<LitStr>,
+    pub discriminant_check: bool,
+    pub non_exhaustive: bool,
+    pub try_from_discriminant: bool,
+    pub swift_attrs: Vec<String>,
 }
 
 impl SharedEnumSwiftBridgeAttributes {
     pub(super) fn store_attrib(&mut self, attrib: EnumAttr) -> syn::Result<()> {
         match attrib {
             EnumAttr::AlreadyDeclared => self.already_declared = true,
+            EnumAttr::DiscriminantCheck => self.discriminant_check = true,
             EnumAttr::Error(error) => self.errors.push(error),
+            EnumAttr::NonExhaustive => self.non_exhaustive = true,
+            EnumAttr::SwiftAttr(attr) => self.swift_attrs.push(attr.value()),
             EnumAttr::SwiftName(name) => self.swift_name = Some(name),
+            EnumAttr::TryFromDiscriminant => self.try_from_discriminant = true,
         };
         Ok(())
     }
@@ -91,12 +105,20 @@ impl Parse for EnumAttr {
 
         let attr = match key.to_string().as_str() {
             "already_declared" => EnumAttr::AlreadyDeclared,
+            "discriminant_check" => EnumAttr::DiscriminantCheck,
+            "non_exhaustive" => EnumAttr::NonExhaustive,
+            "swift_attr" => {
+                input.parse::<Token![=]>()?;
+
+                EnumAttr::SwiftAttr(input.parse()?)
+            }
             "swift_name" => {
                 input.parse::<Token![=]>()?;
 
                 let name = input.parse()?;
                 EnumAttr::SwiftName(name)
             }
+            "try_from_discriminant" => EnumAttr::TryFromDiscriminant,
             _ => {
                 move_input_cursor_to_next_comma(input);
                 EnumAttr::Error(ParseError::EnumUnrecognizedAttribute { attribute: key })
@@ -1,16 +1,25 @@
 use self::argument_attributes::ArgumentAttributes;
 pub(crate) use self::opaque_type_attributes::OpaqueTypeAllAttributes;
+use crate::bridged_type::boxed_fn::BridgeableBoxedFn;
+use crate::bridged_type::bridgeable_array::BuiltInArray;
+use crate::bridged_type::bridgeable_btree_map::BuiltInBTreeMap;
+use crate::bridged_type::bridgeable_cow_str::BuiltInCowStr;
+use crate::bridged_type::bridgeable_mut_ref::BuiltInMutRef;
+use crate::bridged_type::bridgeable_range::BuiltInRange;
 use crate::bridged_type::{
     bridgeable_type_from_fn_arg, pat_type_pat_is_self, BridgeableType, BridgedType,
 };
-use crate::errors::{FunctionAttributeParseError, IdentifiableParseError, ParseError, ParseErrors};
+use crate::errors::{
+    CompressParseError, FunctionAttributeParseError, IdentifiableParseError, ParseError,
+    ParseErrors, StoreParseError,
+};
 use crate::parse::parse_extern_mod::function_attributes::FunctionAttributes;
 use crate::parse::parse_extern_mod::generics::GenericOpaqueType;
 use crate::parse::type_declarations::{
     OpaqueForeignTypeDeclaration, TypeDeclaration, TypeDeclarations,
 };
 use crate::parse::{HostLang, OpaqueRustTypeGenerics};
-use crate::parsed_extern_fn::fn_arg_is_mutable_reference;
+use crate::parsed_extern_fn::{fn_arg_is_mutable_reference, FailableInitializer, StoreRole};
 use crate::ParsedExternFn;
 use proc_macro2::Ident;
 use quote::{format_ident, ToTokens};
@@ -71,27 +80,28 @@ impl<'a> ForeignModParser<'a> {
 
                     let ty_name = foreign_ty.ident.to_string();
 
-                    if let Some(ty) = BridgedType::new_with_str(
-                        &foreign_ty.ident.to_string(),
-                        &self.type_declarations,
-                    ) {
-                        if ty.is_built_in_type() {
-                            self.errors.push(ParseError::DeclaredBuiltInType {
-                                ty: foreign_ty.clone(),
-                            });
-                        }
-                    }
-
                     let foreign_type = OpaqueForeignTypeDeclaration {
                         ty: foreign_ty.ident.clone(),
                         host_lang,
                         attributes: OpaqueTypeAllAttributes::from_attributes(&foreign_ty.attrs)?,
                         generics: OpaqueRustTypeGenerics::new(),
+                        has_own_swift_class: false,
                     };
                     self.type_declarations.insert(
                         ty_name.clone(),
                         TypeDeclaration::Opaque(foreign_type.clone()),
                     );
+
+                    // Checked after inserting the declaration, since a declared type such as a
+                    // `type Uuid;` takes precedence over a built-in type with the same name.
+                    if let Some(ty) = BridgedType::new_with_str(&ty_name, &self.type_declarations) {
+                        if ty.is_built_in_type() {
+                            self.errors.push(ParseError::DeclaredBuiltInType {
+                                ty: foreign_ty.clone(),
+                            });
+                        }
+                    }
+
                     local_type_declarations.insert(ty_name, foreign_type);
                 }
                 ForeignItem::Fn(func) => {
@@ -104,18 +114,60 @@ impl<'a> ForeignModParser<'a> {
                     for arg in func.sig.inputs.iter() {
                         if let FnArg::Typed(pat_ty) = arg {
                             let ty = &pat_ty.ty;
-                            if BridgedType::new_with_type(&ty, &self.type_declarations).is_none() {
+                            if BridgeableBoxedFn::type_contains_boxed_dyn_fn(ty)
+                                && !(host_lang.is_rust()
+                                    && BridgeableBoxedFn::type_is_boxed_dyn_fn(ty))
+                            {
+                                self.errors.push(ParseError::BoxedDynFnNotRustArgType {
+                                    ty: ty.deref().clone(),
+                                });
+                            } else if let Some(ty) = BuiltInArray::find_in_option_or_result(ty) {
+                                self.errors.push(ParseError::ArrayInOptionOrResult { ty });
+                            } else if let Some(ty) = BuiltInBTreeMap::find_in_option_or_result(ty) {
+                                self.errors
+                                    .push(ParseError::BTreeMapInOptionOrResult { ty });
+                            } else if let Some(ty) = BuiltInRange::find_in_option_or_result(ty) {
+                                self.errors.push(ParseError::RangeInOptionOrResult { ty });
+                            } else if BridgedType::new_with_type(&ty, &self.type_declarations)
+                                .is_none()
+                            {
                                 self.unresolved_types.push(ty.deref().clone());
+                            } else if BuiltInCowStr::type_contains_cow_str(ty) {
+                                self.errors.push(ParseError::CowStrNotRustReturnType {
+                                    ty: ty.deref().clone(),
+                                });
                             }
                         }
                     }
 
                     let return_type = &func.sig.output;
                     if let ReturnType::Type(_, return_ty) = return_type {
-                        if BridgedType::new_with_type(return_ty.deref(), &self.type_declarations)
-                            .is_none()
+                        if BridgeableBoxedFn::type_contains_boxed_dyn_fn(return_ty) {
+                            self.errors.push(ParseError::BoxedDynFnNotRustArgType {
+                                ty: return_ty.deref().clone(),
+                            });
+                        } else if let Some(ty) = BuiltInArray::find_in_option_or_result(return_ty) {
+                            self.errors.push(ParseError::ArrayInOptionOrResult { ty });
+                        } else if let Some(ty) =
+                            BuiltInBTreeMap::find_in_option_or_result(return_ty)
+                        {
+                            self.errors
+                                .push(ParseError::BTreeMapInOptionOrResult { ty });
+                        } else if let Some(ty) = BuiltInRange::find_in_option_or_result(return_ty) {
+                            self.errors.push(ParseError::RangeInOptionOrResult { ty });
+                        } else if BridgedType::new_with_type(
+                            return_ty.deref(),
+                            &self.type_declarations,
+                        )
+                        .is_none()
                         {
                             self.unresolved_types.push(return_ty.deref().clone());
+                        } else if host_lang.is_swift()
+                            && BuiltInCowStr::type_contains_cow_str(return_ty)
+                        {
+                            self.errors.push(ParseError::CowStrNotRustReturnType {
+                                ty: return_ty.deref().clone(),
+                            });
                         }
                     }
 
@@ -165,6 +217,73 @@ impl<'a> ForeignModParser<'a> {
                             ));
                         }
                     }
+                    if let Some(store_role) = attributes.store_role {
+                        let mut args = func.sig.inputs.iter();
+                        let receiver_is_ref_self = |arg: Option<&FnArg>, mutable: bool| match arg {
+                            Some(FnArg::Receiver(receiver)) => {
+                                receiver.reference.is_some()
+                                    && receiver.mutability.is_some() == mutable
+                            }
+                            Some(FnArg::Typed(pat_ty)) => {
+                                let ty = pat_ty.ty.to_token_stream().to_string();
+                                let ref_prefix = if mutable { "& mut" } else { "&" };
+                                pat_type_pat_is_self(pat_ty)
+                                    && ty.starts_with(ref_prefix)
+                                    && (mutable || !ty.starts_with("& mut"))
+                            }
+                            None => false,
+                        };
+
+                        let fn_ident = func.sig.ident.clone();
+                        match store_role {
+                            StoreRole::State => {
+                                let is_valid = receiver_is_ref_self(args.next(), false)
+                                    && args.next().is_none()
+                                    && matches!(&func.sig.output, ReturnType::Type(_, _));
+                                if !is_valid {
+                                    self.errors.push(ParseError::FunctionAttribute(
+                                        FunctionAttributeParseError::Store(
+                                            StoreParseError::InvalidStateSignature { fn_ident },
+                                        ),
+                                    ));
+                                }
+                            }
+                            StoreRole::Dispatch => {
+                                let is_valid = receiver_is_ref_self(args.next(), true)
+                                    && matches!(args.next(), Some(FnArg::Typed(_)))
+                                    && args.next().is_none();
+                                if !is_valid {
+                                    self.errors.push(ParseError::FunctionAttribute(
+                                        FunctionAttributeParseError::Store(
+                                            StoreParseError::InvalidDispatchSignature { fn_ident },
+                                        ),
+                                    ));
+                                }
+                            }
+                        }
+                    }
+                    if attributes.compress.is_some() {
+                        let returns_bytes_or_string = match &func.sig.output {
+                            ReturnType::Type(_, ty) => {
+                                let ty = ty.to_token_stream().to_string();
+                                ty == "Vec < u8 >" || ty == "String"
+                            }
+                            ReturnType::Default => false,
+                        };
+
+                        if !host_lang.is_rust()
+                            || func.sig.asyncness.is_some()
+                            || !returns_bytes_or_string
+                        {
+                            self.errors.push(ParseError::FunctionAttribute(
+                                FunctionAttributeParseError::Compress(
+                                    CompressParseError::UnsupportedFunction {
+                                        fn_ident: func.sig.ident.clone(),
+                                    },
+                                ),
+                            ));
+                        }
+                    }
                     let mut argument_labels: HashMap<Ident, LitStr> = HashMap::new();
                     for arg in func.sig.inputs.iter() {
                         let is_mutable_ref = fn_arg_is_mutable_reference(arg);
@@ -186,6 +305,7 @@ impl<'a> ForeignModParser<'a> {
                         }
                         match arg {
                             syn::FnArg::Typed(ty) => {
+                                let mut is_out = false;
                                 for attr in ty.attrs.iter() {
                                     let attribute: ArgumentAttributes = attr.parse_args()?;
                                     if let Some(label) = attribute.label {
@@ -197,6 +317,18 @@ impl<'a> ForeignModParser<'a> {
                                             label,
                                         );
                                     }
+                                    is_out |= attribute.out;
+                                }
+
+                                let is_mut_ref_primitive =
+                                    BuiltInMutRef::from_type(&ty.ty, self.type_declarations)
+                                        .is_some();
+                                if is_out && !is_mut_ref_primitive {
+                                    self.errors
+                                        .push(ParseError::ArgOutNotMutRef { arg: arg.clone() });
+                                } else if !is_out && is_mut_ref_primitive {
+                                    self.errors
+                                        .push(ParseError::ArgMutRefWithoutOut { arg: arg.clone() });
                                 }
                             }
                             _ => {}
@@ -238,6 +370,10 @@ impl<'a> ForeignModParser<'a> {
                         args_into: attributes.args_into,
                         get_field: attributes.get_field,
                         argument_labels: argument_labels,
+                        store_role: attributes.store_role,
+                        compress: attributes.compress,
+                        scoped: attributes.scoped,
+                        swift_attrs: attributes.swift_attrs,
                     };
                     self.functions.push(func);
                 }
@@ -246,13 +382,34 @@ impl<'a> ForeignModParser<'a> {
                         syn::parse2::<GenericOpaqueType>(foreign_item_verbatim)
                     {
                         let ty_name = generic_foreign_type.ident.to_string();
+                        let attributes = OpaqueTypeAllAttributes::from_attributes(
+                            &generic_foreign_type.attributes,
+                        )?;
+
+                        // A concrete generic type gets its own Swift class unless it is an
+                        // instantiation of a previously declared generic Swift class or struct.
+                        let has_generic_declaration =
+                            self.type_declarations.types().iter().any(|ty| {
+                                ty.as_opaque().map_or(false, |ty| {
+                                    ty.ty == generic_foreign_type.ident
+                                        && ty.attributes.declare_generic
+                                })
+                            });
+                        let has_own_swift_class = host_lang.is_rust()
+                            && !attributes.declare_generic
+                            && attributes.copy.is_none()
+                            && !has_generic_declaration;
+                        if !has_own_swift_class {
+                            attributes.check_shared_generic_class(
+                                &generic_foreign_type.ident,
+                                self.errors,
+                            );
+                        }
 
                         let foreign_ty = OpaqueForeignTypeDeclaration {
                             ty: generic_foreign_type.ident,
                             host_lang,
-                            attributes: OpaqueTypeAllAttributes::from_attributes(
-                                &generic_foreign_type.attributes,
-                            )?,
+                            attributes,
                             generics: OpaqueRustTypeGenerics {
                                 generics: generic_foreign_type
                                     .generics
@@ -267,6 +424,7 @@ impl<'a> ForeignModParser<'a> {
                                     })
                                     .collect(),
                             },
+                            has_own_swift_class,
                         };
                         let generics: Vec<String> = foreign_ty
                             .generics
@@ -369,8 +527,15 @@ Otherwise we use a more general error that says that your argument is invalid.
                             todo!("Push error if initializer does not return a type")
                         }
                         ReturnType::Type(_, ty) => {
-                            let ty_string = ty.deref().to_token_stream().to_string();
-                            ty_string
+                            // `Result<SomeType, E>` and `Option<SomeType>` initialize a
+                            // `SomeType`.
+                            let ty = FailableInitializer::from_return_type(ty)
+                                .map(|(_, initialized)| initialized)
+                                .unwrap_or(ty);
+
+                            let ty_string = ty.to_token_stream().to_string();
+                            // Handles generics. i.e. "SomeType< u32 >" -> "SomeType<u32>";
+                            ty_string.replace(" ", "")
                         }
                     };
 
@@ -391,10 +556,11 @@ Otherwise we use a more general error that says that your argument is invalid.
 
 #[cfg(test)]
 mod tests {
-    use crate::errors::ParseError;
+    use crate::bridged_type::{BridgeableType, BridgedType};
+    use crate::errors::{FunctionAttributeParseError, IdentifiableParseError, ParseError};
     use crate::test_utils::{parse_errors, parse_ok};
     use crate::SwiftBridgeModule;
-    use quote::{quote, ToTokens};
+    use quote::{format_ident, quote, ToTokens};
     use syn::parse_quote;
 
     /// Verify that we can parse a SwiftBridgeModule from an empty module.
@@ -475,6 +641,50 @@ mod tests {
         assert_eq!(errors.len(), 1);
     }
 
+    /// Verify that an opaque type can have the same name as a built-in type that is only
+    /// recognized by name, such as `swift_bridge::measurement::Length` or `uuid::Uuid`.
+    #[test]
+    fn declared_type_takes_precedence_over_built_in_type_name() {
+        let names = [
+            "Length",
+            "Mass",
+            "Temperature",
+            "Energy",
+            "Speed",
+            "Uuid",
+            "Tensor",
+            "ImageBuffer",
+            "PixelBuffer",
+            "ByteStream",
+            "Duration",
+            "SystemTime",
+            "OffsetDateTime",
+            "PathBuf",
+            "OsString",
+            "IpAddr",
+            "SocketAddr",
+        ];
+
+        for name in names {
+            let ty = format_ident!("{}", name);
+            let tokens = quote! {
+                mod foo {
+                    extern "Rust" {
+                        type #ty;
+                        fn some_function(arg: #ty) -> #ty;
+                    }
+                }
+            };
+            assert_eq!(parse_errors(tokens.clone()).len(), 0, "{}", name);
+
+            let module = parse_ok(tokens);
+            assert!(module.types.get(name).unwrap().as_opaque().is_some());
+
+            let ty = BridgedType::new_with_str(name, &module.types).unwrap();
+            assert!(!ty.is_built_in_type(), "{}", name);
+        }
+    }
+
     /// Verify that we can parse a Rust type's methods.
     /// We test all of the possible ways we can specify self.
     #[test]
@@ -647,6 +857,233 @@ mod tests {
         }
     }
 
+    /// Verify that we can return a `Cow<str>` from a Rust function, but that we push an error if
+    /// it is used anywhere else.
+    #[test]
+    fn cow_str_only_supported_as_rust_return_type() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    fn a () -> Cow<'static, str>;
+                    fn b () -> Option<Cow<str>>;
+                    fn c (arg: Cow<str>);
+                }
+
+                extern "Swift" {
+                    fn d () -> Cow<'static, str>;
+                }
+            }
+        };
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 2);
+
+        for error in errors.iter() {
+            match error {
+                ParseError::CowStrNotRustReturnType { .. } => {}
+                _ => panic!(),
+            }
+        }
+    }
+
+    /// Verify that we can pass a `Box<dyn Fn>` to a Rust function, but that we push an error if
+    /// it is used anywhere else.
+    #[test]
+    fn boxed_dyn_fn_only_supported_as_rust_arg_type() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    fn a (arg: Box<dyn Fn(u8) -> u8>);
+                    fn b (arg: Box<dyn FnOnce(u8) -> u8>) -> Box<dyn FnOnce()>;
+                    fn c () -> Box<dyn Fn()>;
+                    fn d (arg: Option<Box<dyn Fn()>>);
+                }
+
+                extern "Swift" {
+                    fn f (arg: Box<dyn Fn()>);
+                    fn g () -> Box<dyn Fn()>;
+                }
+            }
+        };
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 4);
+
+        for error in errors.iter() {
+            match error {
+                ParseError::BoxedDynFnNotRustArgType { .. } => {}
+                _ => panic!(),
+            }
+        }
+    }
+
+    /// Verify that we push an error if an array is held by an `Option` or a `Result`.
+    #[test]
+    fn error_if_array_in_option_or_result() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    fn a (arg: [u8; 4]) -> [u8; 4];
+                    fn b (arg: Option<[u8; 4]>);
+                    fn c () -> Result<[u8; 4], String>;
+                    fn d () -> Result<String, [u8; 4]>;
+                    fn e (arg: Vec<Option<[u8; 4]>>);
+                }
+            }
+        };
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 4);
+
+        for error in errors.iter() {
+            match error {
+                ParseError::ArrayInOptionOrResult { ty } => {
+                    let ty = ty.to_token_stream().to_string();
+                    assert!(ty.starts_with("Option") || ty.starts_with("Result"));
+                }
+                _ => panic!(),
+            }
+        }
+    }
+
+    /// Verify that we push an error if a `BTreeMap` is held by an `Option` or a `Result`.
+    #[test]
+    fn error_if_btree_map_in_option_or_result() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    fn a (arg: BTreeMap<String, u32>) -> BTreeMap<String, u32>;
+                    fn b (arg: Option<BTreeMap<String, u32>>);
+                    fn c () -> Result<BTreeMap<String, u32>, String>;
+                    fn d () -> Result<String, std::collections::BTreeMap<String, u32>>;
+                }
+
+                extern "Swift" {
+                    fn e () -> Option<BTreeMap<u8, u8>>;
+                }
+            }
+        };
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 4);
+
+        for error in errors.iter() {
+            match error {
+                ParseError::BTreeMapInOptionOrResult { ty } => {
+                    let ty = ty.to_token_stream().to_string();
+                    assert!(ty.starts_with("Option") || ty.starts_with("Result"));
+                }
+                _ => panic!(),
+            }
+        }
+    }
+
+    /// Verify that we push an error if a `Range` or `RangeInclusive` is held by an `Option` or a
+    /// `Result`.
+    #[test]
+    fn error_if_range_in_option_or_result() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    fn a (arg: Range<u32>) -> RangeInclusive<u32>;
+                    fn b () -> Option<Range<u32>>;
+                    fn c (arg: Option<std::ops::RangeInclusive<i64>>);
+                    fn d () -> Result<Range<u8>, String>;
+                }
+
+                extern "Swift" {
+                    fn e () -> Result<String, RangeInclusive<u8>>;
+                }
+            }
+        };
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 4);
+
+        for error in errors.iter() {
+            match error {
+                ParseError::RangeInOptionOrResult { ty } => {
+                    let ty = ty.to_token_stream().to_string();
+                    assert!(ty.starts_with("Option") || ty.starts_with("Result"));
+                }
+                _ => panic!(),
+            }
+        }
+    }
+
+    /// Verify that we push an error if a struct with a `&'static str` field could be passed from
+    /// Swift to Rust.
+    #[test]
+    fn error_if_static_str_struct_passed_to_rust() {
+        let tokens = quote! {
+            mod foo {
+                #[swift_bridge(swift_repr = "struct")]
+                struct ErrorInfo {
+                    code: u32,
+                    message: &'static str,
+                }
+
+                #[swift_bridge(swift_repr = "struct")]
+                struct ErrorResponse {
+                    error: ErrorInfo,
+                }
+
+                extern "Rust" {
+                    fn a (code: u32) -> ErrorInfo;
+                    fn b (arg: ErrorInfo);
+                    fn c (arg: Option<ErrorResponse>);
+                }
+
+                extern "Swift" {
+                    fn d (arg: ErrorInfo) -> u32;
+                    fn e () -> Vec<ErrorInfo>;
+                }
+            }
+        };
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 3);
+
+        let mut tys: Vec<String> = errors
+            .iter()
+            .map(|error| match error {
+                ParseError::StaticStrStructPassedToRust { ty } => ty.to_token_stream().to_string(),
+                _ => panic!(),
+            })
+            .collect();
+        tys.sort();
+        assert_eq!(
+            tys,
+            vec!["ErrorInfo", "Option < ErrorResponse >", "Vec < ErrorInfo >"]
+        );
+    }
+
+    /// Verify that we push an error if a slice of opaque Rust types is used in an extern "Swift"
+    /// function or inside of an `Option`.
+    #[test]
+    fn error_if_unsupported_opaque_rust_slice() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn a (arg: &[SomeType]) -> &[SomeType];
+                    fn b (arg: Option<&[SomeType]>);
+                    fn c () -> Option<&'static [SomeType]>;
+                }
+
+                extern "Swift" {
+                    fn d (arg: &[SomeType]);
+                    fn e () -> &'static [SomeType];
+                    fn f (arg: &[u8]);
+                }
+            }
+        };
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 4);
+
+        for error in errors.iter() {
+            match error {
+                ParseError::UnsupportedOpaqueRustSlice { .. } => {}
+                _ => panic!(),
+            }
+        }
+    }
+
     /// Verify that if an extern Rust block has more than one type, we push errors for any methods
     /// that have an ambiguous self.
     #[test]
@@ -789,7 +1226,7 @@ mod tests {
         );
     }
 
-    //Verify that we can parse the `hashable` attribute.
+    //Verify that we can parse the `hashable` attribute, and that it implies `equatable`.
     #[test]
     fn parse_hashable_attribute() {
         let tokens = quote! {
@@ -803,15 +1240,204 @@ mod tests {
 
         let module = parse_ok(tokens);
 
-        assert_eq!(
+        let attributes = &module
+            .types
+            .get("SomeType")
+            .unwrap()
+            .unwrap_opaque()
+            .attributes;
+        assert!(attributes.hashable);
+        assert!(attributes.equatable);
+    }
+
+    /// Verify that we can parse the `Comparable` attribute, and that it implies `equatable`.
+    #[test]
+    fn parse_comparable_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(Comparable)]
+                    type SomeType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let attributes = &module
+            .types
+            .get("SomeType")
+            .unwrap()
+            .unwrap_opaque()
+            .attributes;
+        assert!(attributes.comparable);
+        assert!(attributes.equatable);
+    }
+
+    /// Verify that we can parse the `Identifiable = "..."` attribute.
+    #[test]
+    fn parse_identifiable_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(Identifiable = "user_id")]
+                    type SomeType;
+
+                    fn user_id(&self) -> u64;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let attributes = &module
+            .types
+            .get("SomeType")
+            .unwrap()
+            .unwrap_opaque()
+            .attributes;
+        assert_eq!(attributes.identifiable.as_ref().unwrap().value(), "user_id");
+    }
+
+    /// Verify that we push an error if the `Identifiable = "..."` attribute names a method that the
+    /// type does not have.
+    #[test]
+    fn error_if_identifiable_method_not_found() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(Identifiable = "missing")]
+                    type SomeType;
+
+                    #[swift_bridge(Identifiable = "new")]
+                    type AnotherType;
+
+                    fn user_id(self: &SomeType) -> u64;
+                    fn new() -> AnotherType;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 2);
+        for error in errors.iter() {
+            assert!(matches!(
+                error,
+                ParseError::IdentifiableMethodNotFound { .. }
+            ));
+        }
+    }
+
+    /// Verify that we push an error if the method that the `Identifiable = "..."` attribute names
+    /// does not take `&self` or does not return a value.
+    #[test]
+    fn error_if_identifiable_method_has_invalid_signature() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(Identifiable = "owned_id")]
+                    type OwnedSelf;
+                    #[swift_bridge(Identifiable = "mut_id")]
+                    type MutSelf;
+                    #[swift_bridge(Identifiable = "no_return")]
+                    type NoReturn;
+
+                    fn owned_id(self: OwnedSelf) -> u64;
+                    fn mut_id(self: &mut MutSelf) -> u64;
+                    fn no_return(self: &NoReturn);
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 3);
+        for error in errors.iter() {
+            match error {
+                ParseError::FunctionAttribute(FunctionAttributeParseError::Identifiable(
+                    IdentifiableParseError::MustBeRefSelf { fn_ident },
+                )) => assert!(fn_ident == "owned_id" || fn_ident == "mut_id"),
+                ParseError::FunctionAttribute(FunctionAttributeParseError::Identifiable(
+                    IdentifiableParseError::MissingReturnType { fn_ident },
+                )) => assert_eq!(fn_ident, "no_return"),
+                _ => panic!(),
+            }
+        }
+    }
+
+    /// Verify that we can parse the `Clone` attribute.
+    #[test]
+    fn parse_clone_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(Clone)]
+                    type SomeType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert!(
             module
                 .types
                 .get("SomeType")
                 .unwrap()
                 .unwrap_opaque()
                 .attributes
-                .hashable,
-            true
+                .clone
+        );
+    }
+
+    /// Verify that we can parse the `CustomStringConvertible` attribute.
+    #[test]
+    fn parse_custom_string_convertible_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(CustomStringConvertible)]
+                    type SomeType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert!(
+            module
+                .types
+                .get("SomeType")
+                .unwrap()
+                .unwrap_opaque()
+                .attributes
+                .custom_string_convertible
+        );
+    }
+
+    /// Verify that we can parse the `CustomDebugStringConvertible` attribute.
+    #[test]
+    fn parse_custom_debug_string_convertible_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(CustomDebugStringConvertible)]
+                    type SomeType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert!(
+            module
+                .types
+                .get("SomeType")
+                .unwrap()
+                .unwrap_opaque()
+                .attributes
+                .custom_debug_string_convertible
         );
     }
 
@@ -841,6 +1467,163 @@ mod tests {
         );
     }
 
+    /// Verify that we can parse the `Error` attribute.
+    #[test]
+    fn parse_error_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(Error)]
+                    type SomeType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert!(
+            module
+                .types
+                .get("SomeType")
+                .unwrap()
+                .unwrap_opaque()
+                .attributes
+                .error
+        );
+    }
+
+    /// Verify that we can parse the `drop_queue` attribute.
+    #[test]
+    fn parse_drop_queue_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(drop_queue = "DispatchQueue.main")]
+                    type SomeType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert_eq!(
+            module
+                .types
+                .get("SomeType")
+                .unwrap()
+                .unwrap_opaque()
+                .attributes
+                .drop_queue
+                .as_deref(),
+            Some("DispatchQueue.main")
+        );
+    }
+
+    /// Verify that we push an error if `drop_queue` is used on a generic type that shares a
+    /// generic Swift class, since the attribute would be ignored.
+    #[test]
+    fn error_if_drop_queue_on_shared_generic_type() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(declare_generic, drop_queue = "DispatchQueue.main")]
+                    type SomeType<A>;
+                    #[swift_bridge(drop_queue = "DispatchQueue.main")]
+                    type SomeType<u32>;
+
+                    #[swift_bridge(drop_queue = "DispatchQueue.main")]
+                    type AnotherType<u32>;
+                }
+            }
+        };
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 2);
+
+        for error in errors.iter() {
+            match error {
+                ParseError::DropQueueOnGenericType { ty } => assert_eq!(ty, "SomeType"),
+                _ => panic!(),
+            }
+        }
+    }
+
+    /// Verify that we can parse the `identity_map` attribute.
+    #[test]
+    fn parse_identity_map_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(identity_map)]
+                    type SomeType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert_eq!(
+            module
+                .types
+                .get("SomeType")
+                .unwrap()
+                .unwrap_opaque()
+                .attributes
+                .identity_map,
+            true
+        );
+    }
+
+    /// Verify that we can parse the `Sendable` attribute.
+    #[test]
+    fn parse_sendable_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(Sendable)]
+                    type SomeType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert!(
+            module
+                .types
+                .get("SomeType")
+                .unwrap()
+                .unwrap_opaque()
+                .attributes
+                .sendable
+        );
+    }
+
+    /// Verify that we can parse the `swift_attr` attribute.
+    #[test]
+    fn parse_swift_attr_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(swift_attr = "@_spi(Internal)")]
+                    type SomeType;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert_eq!(
+            module
+                .types
+                .get("SomeType")
+                .unwrap()
+                .unwrap_opaque()
+                .attributes
+                .swift_attrs,
+            vec!["@_spi(Internal)"]
+        );
+    }
+
     /// Verify that we can parse the `copy` attribute.
     #[test]
     fn parse_copy_attribute() {
@@ -6,11 +6,15 @@ use syn::{LitStr, Token};
 pub(super) struct ArgumentAttributes {
     /// LitStr: argument_name
     pub label: Option<LitStr>,
+    /// `#[swift_bridge(out)]`
+    pub out: bool,
 }
 
 enum ArgumentAttr {
     /// LitStr: argument_name
     ArgumentLabel(LitStr),
+    /// A `&mut T` argument that Swift sees as an `inout T` parameter.
+    Out,
 }
 
 impl Parse for ArgumentAttributes {
@@ -23,6 +27,9 @@ impl Parse for ArgumentAttributes {
                 ArgumentAttr::ArgumentLabel(label) => {
                     attributes.label = Some(label);
                 }
+                ArgumentAttr::Out => {
+                    attributes.out = true;
+                }
             }
         }
         Ok(attributes)
@@ -38,6 +45,7 @@ impl Parse for ArgumentAttr {
                 let value: LitStr = input.parse()?;
                 ArgumentAttr::ArgumentLabel(value)
             }
+            "out" => ArgumentAttr::Out,
             _ => {
                 let attrib = key.to_string();
                 Err(syn::Error::new_spanned(
@@ -52,7 +60,8 @@ impl Parse for ArgumentAttr {
 
 #[cfg(test)]
 mod tests {
-    use crate::test_utils::parse_ok;
+    use crate::errors::ParseError;
+    use crate::test_utils::{parse_errors, parse_ok};
     use quote::{format_ident, quote};
 
     /// Verify that we can parse a function that has a argument label.
@@ -78,4 +87,49 @@ mod tests {
             .unwrap();
         assert_eq!(argument_label.value().to_string(), "argumentLabel1");
     }
+
+    /// Verify that we can parse a function that has out arguments.
+    #[test]
+    fn parse_out_argument_attribute() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    fn some_function(
+                        #[swift_bridge(out)] min: &mut u32,
+                        #[swift_bridge(label = "maximum", out)] max: &mut u32,
+                    );
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+        assert_eq!(module.functions.len(), 1);
+        assert_eq!(module.functions[0].argument_labels.len(), 1);
+    }
+
+    /// Verify that we push an error if an out argument is not a mutable reference to a primitive,
+    /// or if a mutable reference to a primitive is not an out argument.
+    #[test]
+    fn error_if_invalid_out_argument() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    type SomeType;
+
+                    fn some_function(
+                        #[swift_bridge(out)] a: u32,
+                        #[swift_bridge(out)] b: &mut SomeType,
+                        c: &mut u32,
+                    );
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 3);
+
+        assert!(matches!(errors[0], ParseError::ArgOutNotMutRef { .. }));
+        assert!(matches!(errors[1], ParseError::ArgOutNotMutRef { .. }));
+        assert!(matches!(errors[2], ParseError::ArgMutRefWithoutOut { .. }));
+    }
 }
@@ -1,4 +1,4 @@
-use crate::parsed_extern_fn::{GetField, GetFieldDirect, GetFieldWith};
+use crate::parsed_extern_fn::{Compression, GetField, GetFieldDirect, GetFieldWith, StoreRole};
 use proc_macro2::Ident;
 use syn::parse::{Parse, ParseStream};
 use syn::{LitStr, Path, Token};
@@ -14,6 +14,10 @@ pub(super) struct FunctionAttributes {
     pub return_with: Option<Path>,
     pub args_into: Option<Vec<Ident>>,
     pub get_field: Option<GetField>,
+    pub store_role: Option<StoreRole>,
+    pub compress: Option<Compression>,
+    pub scoped: Option<LitStr>,
+    pub swift_attrs: Vec<String>,
 }
 
 impl FunctionAttributes {
@@ -43,6 +47,10 @@ impl FunctionAttributes {
             FunctionAttr::GetFieldWith(get_field) => {
                 self.get_field = Some(GetField::With(get_field))
             }
+            FunctionAttr::Store(role) => self.store_role = Some(role),
+            FunctionAttr::Compress(compression) => self.compress = Some(compression),
+            FunctionAttr::Scoped(name) => self.scoped = Some(name),
+            FunctionAttr::SwiftAttr(attr) => self.swift_attrs.push(attr.value()),
         }
     }
 }
@@ -58,6 +66,10 @@ pub(super) enum FunctionAttr {
     ArgsInto(Vec<Ident>),
     GetField(GetFieldDirect),
     GetFieldWith(GetFieldWith),
+    Store(StoreRole),
+    Compress(Compression),
+    Scoped(LitStr),
+    SwiftAttr(LitStr),
 }
 
 impl Parse for FunctionAttributes {
@@ -149,6 +161,46 @@ impl Parse for FunctionAttr {
                     path,
                 })
             }
+            "Store" => {
+                let content;
+                syn::parenthesized!(content in input);
+
+                let role = content.parse::<Ident>()?;
+                match role.to_string().as_str() {
+                    "state" => FunctionAttr::Store(StoreRole::State),
+                    "dispatch" => FunctionAttr::Store(StoreRole::Dispatch),
+                    _ => Err(syn::Error::new_spanned(
+                        &role,
+                        format!(
+                            r#"Unrecognized Store role "{}". Expected "state" or "dispatch"."#,
+                            role
+                        ),
+                    ))?,
+                }
+            }
+            "compress" => {
+                input.parse::<Token![=]>()?;
+                let value: LitStr = input.parse()?;
+
+                match value.value().as_str() {
+                    "lz4" => FunctionAttr::Compress(Compression::Lz4),
+                    _ => Err(syn::Error::new_spanned(
+                        &value,
+                        format!(
+                            r#"Unsupported compression algorithm "{}". Expected "lz4"."#,
+                            value.value()
+                        ),
+                    ))?,
+                }
+            }
+            "scoped" => {
+                input.parse::<Token![=]>()?;
+                FunctionAttr::Scoped(input.parse()?)
+            }
+            "swift_attr" => {
+                input.parse::<Token![=]>()?;
+                FunctionAttr::SwiftAttr(input.parse()?)
+            }
             _ => {
                 let attrib = key.to_string();
                 Err(syn::Error::new_spanned(
@@ -164,7 +216,11 @@ impl Parse for FunctionAttr {
 
 #[cfg(test)]
 mod tests {
-    use crate::errors::{FunctionAttributeParseError, IdentifiableParseError, ParseError};
+    use crate::errors::{
+        CompressParseError, FunctionAttributeParseError, IdentifiableParseError, ParseError,
+        ScopedParseError, StoreParseError,
+    };
+    use crate::parsed_extern_fn::{Compression, FailableInitializer, StoreRole};
     use crate::test_utils::{parse_errors, parse_ok};
     use quote::{quote, ToTokens};
 
@@ -297,6 +353,39 @@ mod tests {
         assert!(func.is_swift_initializer);
     }
 
+    /// Verify that an init function that returns a `Result` or an `Option` is associated with the
+    /// type that it initializes.
+    #[test]
+    fn failable_initializer() {
+        let tokens = quote! {
+            mod foo {
+                extern "Rust" {
+                    type Foo;
+
+                    #[swift_bridge(init)]
+                    fn bar () -> Result<Foo, String>;
+
+                    #[swift_bridge(init)]
+                    fn bazz () -> Option<Foo>;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        for (func, failable) in module
+            .functions
+            .iter()
+            .zip([FailableInitializer::Throws, FailableInitializer::Optional])
+        {
+            assert_eq!(
+                func.associated_type.as_ref().unwrap().unwrap_opaque().ty,
+                "Foo"
+            );
+            assert_eq!(func.failable_initializer(), Some(failable));
+        }
+    }
+
     /// Verify that we push an error if the initialize type is not defined.
     #[test]
     fn error_if_initialized_type_not_defined() {
@@ -448,6 +537,228 @@ mod tests {
         assert!(func.is_swift_identifiable);
     }
 
+    /// Verify that we can parse the `Store` attribute.
+    #[test]
+    fn parses_store_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(Store(state))]
+                    fn state(&self) -> u16;
+
+                    #[swift_bridge(Store(dispatch))]
+                    fn dispatch(&mut self, action: u8);
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert_eq!(module.functions[0].store_role, Some(StoreRole::State));
+        assert_eq!(module.functions[1].store_role, Some(StoreRole::Dispatch));
+    }
+
+    /// Verify that we push a parse error if a `Store` method has the wrong signature.
+    #[test]
+    fn error_if_store_attribute_on_invalid_signature() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type SomeType;
+
+                    #[swift_bridge(Store(state))]
+                    fn a(&mut self) -> u16;
+
+                    #[swift_bridge(Store(state))]
+                    fn b(&self);
+
+                    #[swift_bridge(Store(dispatch))]
+                    fn c(&self, action: u8);
+
+                    #[swift_bridge(Store(dispatch))]
+                    fn d(&mut self);
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 4);
+
+        for (idx, expected) in vec!["a", "b"].into_iter().enumerate() {
+            match &errors[idx] {
+                ParseError::FunctionAttribute(FunctionAttributeParseError::Store(
+                    StoreParseError::InvalidStateSignature { fn_ident },
+                )) => {
+                    assert_eq!(fn_ident, expected);
+                }
+                _ => panic!(),
+            };
+        }
+        for (idx, expected) in vec!["c", "d"].into_iter().enumerate() {
+            match &errors[idx + 2] {
+                ParseError::FunctionAttribute(FunctionAttributeParseError::Store(
+                    StoreParseError::InvalidDispatchSignature { fn_ident },
+                )) => {
+                    assert_eq!(fn_ident, expected);
+                }
+                _ => panic!(),
+            };
+        }
+    }
+
+    /// Verify that we can parse the `compress` attribute.
+    #[test]
+    fn parses_compress_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(compress = "lz4")]
+                    fn some_function() -> Vec<u8>;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert_eq!(module.functions[0].compress, Some(Compression::Lz4));
+    }
+
+    /// Verify that we can parse the `swift_attr` attribute, which can be used more than once.
+    #[test]
+    fn parses_swift_attr_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(swift_attr = "@_spi(Internal)", swift_attr = "@discardableResult")]
+                    fn some_function() -> u8;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert_eq!(
+            module.functions[0].swift_attrs,
+            vec!["@_spi(Internal)", "@discardableResult"]
+        );
+    }
+
+    /// Verify that we push a parse error if a function that can't be compressed has the
+    /// `compress` attribute.
+    #[test]
+    fn error_if_compress_attribute_on_unsupported_function() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    #[swift_bridge(compress = "lz4")]
+                    fn a() -> Vec<u16>;
+
+                    #[swift_bridge(compress = "lz4")]
+                    async fn b() -> String;
+                }
+
+                extern "Swift" {
+                    #[swift_bridge(compress = "lz4")]
+                    fn c() -> Vec<u8>;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 3);
+
+        for (idx, expected) in vec!["a", "b", "c"].into_iter().enumerate() {
+            match &errors[idx] {
+                ParseError::FunctionAttribute(FunctionAttributeParseError::Compress(
+                    CompressParseError::UnsupportedFunction { fn_ident },
+                )) => {
+                    assert_eq!(fn_ident, expected);
+                }
+                _ => panic!(),
+            };
+        }
+    }
+
+    /// Verify that we can parse the `scoped` attribute.
+    #[test]
+    fn parses_scoped_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type TransactionGuard;
+
+                    #[swift_bridge(scoped = "withTransaction")]
+                    fn begin() -> TransactionGuard;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        assert_eq!(
+            module.functions[0].scoped.as_ref().unwrap().value(),
+            "withTransaction"
+        );
+    }
+
+    /// Verify that we push a parse error if a function that doesn't return an owned opaque Rust
+    /// guard, or that takes arguments, has the `scoped` attribute.
+    #[test]
+    fn error_if_scoped_attribute_on_unsupported_function() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                extern "Rust" {
+                    type TransactionGuard;
+
+                    #[swift_bridge(scoped = "withA")]
+                    fn a() -> u8;
+
+                    #[swift_bridge(scoped = "withB")]
+                    fn b() -> &'static TransactionGuard;
+
+                    #[swift_bridge(scoped = "withC")]
+                    fn c(name: &str) -> TransactionGuard;
+
+                    #[swift_bridge(scoped = "withD")]
+                    async fn d() -> TransactionGuard;
+                }
+
+                extern "Swift" {
+                    type SwiftGuard;
+
+                    #[swift_bridge(scoped = "withE")]
+                    fn e() -> SwiftGuard;
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+
+        assert_eq!(errors.len(), 5);
+
+        for (idx, expected) in vec!["a", "b", "c", "d", "e"].into_iter().enumerate() {
+            match &errors[idx] {
+                ParseError::FunctionAttribute(FunctionAttributeParseError::Scoped(
+                    ScopedParseError::UnsupportedFunction { fn_ident },
+                )) => {
+                    assert_eq!(fn_ident, expected);
+                }
+                _ => panic!(),
+            };
+        }
+    }
+
     /// Verify that we can parse the `get` attribute.
     #[test]
     fn parses_get_attribute() {
@@ -51,6 +51,42 @@ mod tests {
         assert_eq!(get_generics(&module, "SomeType<u64>").len(), 1);
     }
 
+    /// Verify that a concrete generic type only gets its own Swift class if there is no
+    /// `declare_generic` declaration of the generic type.
+    #[test]
+    fn has_own_swift_class() {
+        let tokens = quote! {
+            #[swift_bridge:bridge]
+            mod foo {
+                extern "Rust" {
+                    #[swift_bridge(declare_generic)]
+                    type SomeType<A>;
+                    type SomeType<u32>;
+
+                    type AnotherType<u32>;
+
+                    #[swift_bridge(Copy(4))]
+                    type CopyType<u32>;
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let has_own_swift_class = |type_name: &str| {
+            module
+                .types
+                .get(type_name)
+                .unwrap()
+                .unwrap_opaque()
+                .has_own_swift_class
+        };
+        assert!(!has_own_swift_class("SomeType<A>"));
+        assert!(!has_own_swift_class("SomeType<u32>"));
+        assert!(has_own_swift_class("AnotherType<u32>"));
+        assert!(!has_own_swift_class("CopyType<u32>"));
+    }
+
     fn get_generics<'a>(module: &'a SwiftBridgeModule, type_name: &str) -> &'a Vec<TypeParam> {
         &module
             .types
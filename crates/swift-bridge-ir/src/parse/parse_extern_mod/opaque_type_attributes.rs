@@ -1,9 +1,10 @@
+use crate::errors::{ParseError, ParseErrors};
 use crate::parse::OpaqueCopy;
 use proc_macro2::Ident;
 use quote::ToTokens;
 use std::ops::Deref;
 use syn::parse::{Parse, ParseStream};
-use syn::{Attribute, LitInt, Meta};
+use syn::{Attribute, LitInt, LitStr, Meta, Token};
 
 #[derive(Default, Clone)]
 pub(crate) struct OpaqueTypeAllAttributes {
@@ -20,18 +21,54 @@ pub(crate) struct OpaqueTypeSwiftBridgeAttributes {
     /// If it was, we won't generate Swift and C type declarations for this type, since we
     /// will elsewhere.
     pub already_declared: bool,
+    /// `#[swift_bridge(Clone)]`
+    /// Used to generate a Swift `copy()` method that uses the type's `Clone` implementation.
+    pub clone: bool,
     /// `#[swift_bridge(Copy(...)]`
     /// Describes the type's Copy semantics.
     pub copy: Option<OpaqueCopy>,
+    /// `#[swift_bridge(Comparable)]`
+    /// Used to determine if Comparable need to be implemented.
+    /// Implies `Equatable`, since Swift's `Comparable` inherits from `Equatable`.
+    pub comparable: bool,
+    /// `#[swift_bridge(CustomStringConvertible)]`
+    /// Used to implement Swift's `description` using the type's `Display` implementation.
+    pub custom_string_convertible: bool,
+    /// `#[swift_bridge(CustomDebugStringConvertible)]`
+    /// Used to implement Swift's `debugDescription` using the type's `Debug` implementation.
+    pub custom_debug_string_convertible: bool,
     /// `#[swift_bridge(declare_generic)]`
     /// Used to declare a generic type.
     pub declare_generic: bool,
     /// `#[swift_bridge(Equatable)]`
     /// Used to determine if Equatable need to be implemented.
     pub equatable: bool,
+    /// `#[swift_bridge(Error)]`
+    /// Used to make the Swift class throwable, with a `localizedDescription` that uses the
+    /// type's `Display` implementation.
+    pub error: bool,
     /// `#[swift_bridge(Hashable)]`
     /// Used to determine if Hashable need to be implemented.
+    /// Implies `Equatable`, since Swift's `Hashable` inherits from `Equatable`.
     pub hashable: bool,
+    /// `#[swift_bridge(Identifiable = "id")]`
+    /// The `&self` method whose return value is the `id` of the type's `Identifiable`
+    /// conformance.
+    pub identifiable: Option<LitStr>,
+    /// `#[swift_bridge(drop_queue = "DispatchQueue.main")]`
+    /// A Swift expression for the `DispatchQueue` that the Rust type is always dropped on.
+    pub drop_queue: Option<String>,
+    /// `#[swift_bridge(identity_map)]`
+    /// Used to reuse the same Swift class instance for values with the same
+    /// `swift_bridge::identity::ObjectIdentity`.
+    pub identity_map: bool,
+    /// `#[swift_bridge(Sendable)]`
+    /// Used to mark the Swift classes as `Sendable` and assert that the Rust type is
+    /// `Send + Sync`.
+    pub sendable: bool,
+    /// `#[swift_bridge(swift_attr = "@_spi(Internal)")]`
+    /// Swift attributes that are added to the generated Swift classes.
+    pub swift_attrs: Vec<String>,
 }
 
 impl OpaqueTypeAllAttributes {
@@ -67,26 +104,65 @@ impl OpaqueTypeAllAttributes {
 
         Ok(attributes)
     }
+
+    /// Push errors for attributes that can't be used on a generic type that doesn't get its own
+    /// Swift class.
+    ///
+    /// A generic Swift class is freed using `self`, which cannot be captured by a closure that
+    /// outlives `deinit`, so it can't be dropped on a `drop_queue`.
+    pub(super) fn check_shared_generic_class(&self, ty: &Ident, errors: &mut ParseErrors) {
+        if self.drop_queue.is_some() {
+            errors.push(ParseError::DropQueueOnGenericType { ty: ty.clone() });
+        }
+    }
 }
 
 impl OpaqueTypeSwiftBridgeAttributes {
     pub(super) fn store_attrib(&mut self, attrib: OpaqueTypeAttr) {
         match attrib {
             OpaqueTypeAttr::AlreadyDeclared => self.already_declared = true,
+            OpaqueTypeAttr::Clone => self.clone = true,
+            OpaqueTypeAttr::Comparable => {
+                self.comparable = true;
+                self.equatable = true;
+            }
             OpaqueTypeAttr::Copy { size } => self.copy = Some(OpaqueCopy { size_bytes: size }),
+            OpaqueTypeAttr::CustomDebugStringConvertible => {
+                self.custom_debug_string_convertible = true
+            }
+            OpaqueTypeAttr::CustomStringConvertible => self.custom_string_convertible = true,
             OpaqueTypeAttr::DeclareGeneric => self.declare_generic = true,
+            OpaqueTypeAttr::DropQueue(queue) => self.drop_queue = Some(queue.value()),
             OpaqueTypeAttr::Equatable => self.equatable = true,
-            OpaqueTypeAttr::Hashable => self.hashable = true,
+            OpaqueTypeAttr::Error => self.error = true,
+            OpaqueTypeAttr::Hashable => {
+                self.hashable = true;
+                self.equatable = true;
+            }
+            OpaqueTypeAttr::Identifiable(method) => self.identifiable = Some(method),
+            OpaqueTypeAttr::IdentityMap => self.identity_map = true,
+            OpaqueTypeAttr::Sendable => self.sendable = true,
+            OpaqueTypeAttr::SwiftAttr(attr) => self.swift_attrs.push(attr.value()),
         }
     }
 }
 
 pub(crate) enum OpaqueTypeAttr {
     AlreadyDeclared,
+    Clone,
+    Comparable,
     Copy { size: usize },
+    CustomDebugStringConvertible,
+    CustomStringConvertible,
     DeclareGeneric,
+    DropQueue(LitStr),
     Equatable,
+    Error,
     Hashable,
+    Identifiable(LitStr),
+    IdentityMap,
+    Sendable,
+    SwiftAttr(LitStr),
 }
 
 impl Parse for OpaqueTypeSwiftBridgeAttributes {
@@ -110,6 +186,8 @@ impl Parse for OpaqueTypeAttr {
 
         let attrib = match key.to_string().as_str() {
             "already_declared" => OpaqueTypeAttr::AlreadyDeclared,
+            "Clone" => OpaqueTypeAttr::Clone,
+            "Comparable" => OpaqueTypeAttr::Comparable,
             // Copy(10)
             "Copy" => {
                 let content;
@@ -121,9 +199,26 @@ impl Parse for OpaqueTypeAttr {
                     size: size.to_string().parse().unwrap(),
                 }
             }
+            "CustomDebugStringConvertible" => OpaqueTypeAttr::CustomDebugStringConvertible,
+            "CustomStringConvertible" => OpaqueTypeAttr::CustomStringConvertible,
             "declare_generic" => OpaqueTypeAttr::DeclareGeneric,
+            "drop_queue" => {
+                input.parse::<Token![=]>()?;
+                OpaqueTypeAttr::DropQueue(input.parse()?)
+            }
             "Equatable" => OpaqueTypeAttr::Equatable,
+            "Error" => OpaqueTypeAttr::Error,
             "Hashable" => OpaqueTypeAttr::Hashable,
+            "Identifiable" => {
+                input.parse::<Token![=]>()?;
+                OpaqueTypeAttr::Identifiable(input.parse()?)
+            }
+            "identity_map" => OpaqueTypeAttr::IdentityMap,
+            "Sendable" => OpaqueTypeAttr::Sendable,
+            "swift_attr" => {
+                input.parse::<Token![=]>()?;
+                OpaqueTypeAttr::SwiftAttr(input.parse()?)
+            }
             _ => {
                 let attrib = key.to_string();
                 Err(syn::Error::new_spanned(
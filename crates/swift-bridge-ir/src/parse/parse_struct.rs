@@ -1,12 +1,20 @@
 use crate::bridged_type::{
-    shared_struct::StructDerives, SharedStruct, StructFields, StructSwiftRepr,
+    bridgeable_array::BuiltInArray,
+    bridgeable_btree_map::BuiltInBTreeMap,
+    bridgeable_range::BuiltInRange,
+    shared_struct::{
+        little_endian_field_len, swift_default_value, StructDerives, StructInstantiation,
+        StructLayout,
+    },
+    SharedStruct, StructFields, StructSwiftRepr,
 };
 use crate::errors::{ParseError, ParseErrors};
 use crate::parse::move_input_cursor_to_next_comma;
-use proc_macro2::Ident;
+use proc_macro2::{Ident, TokenStream, TokenTree};
 use quote::ToTokens;
 use syn::parse::{Parse, ParseStream};
-use syn::{ItemStruct, LitStr, Meta, Token};
+use syn::punctuated::Punctuated;
+use syn::{Expr, ItemStruct, LitStr, Meta, NestedMeta, Path, Token, Type};
 
 pub(crate) struct SharedStructDeclarationParser<'a> {
     pub item_struct: ItemStruct,
@@ -18,6 +26,27 @@ enum StructAttr {
     SwiftName(LitStr),
     Error(StructAttrParseError),
     AlreadyDeclared,
+    Diffable,
+    LittleEndian,
+    Mirror(Path),
+    Packed,
+    Instantiate(Vec<Type>),
+    Derive(Vec<Ident>),
+    SwiftAttr(LitStr),
+    Sendable,
+    SerdeJsonRoundTrip,
+}
+
+enum StructFieldAttr {
+    SwiftName(LitStr),
+    Default(Box<Expr>),
+    Unrecognized(Ident),
+}
+
+#[derive(Default)]
+struct StructFieldAttribs {
+    swift_name: Option<LitStr>,
+    default: Option<Expr>,
 }
 
 enum StructAttrParseError {
@@ -30,7 +59,15 @@ struct StructAttribs {
     swift_repr: Option<(StructSwiftRepr, LitStr)>,
     swift_name: Option<LitStr>,
     already_declared: bool,
+    diffable: bool,
+    little_endian: bool,
+    mirror: Option<Path>,
     derives: StructDerives,
+    layout: StructLayout,
+    instantiate: Option<Vec<Type>>,
+    swift_attrs: Vec<String>,
+    sendable: bool,
+    serde_json_round_trip: bool,
 }
 
 impl Default for StructDerives {
@@ -38,6 +75,12 @@ impl Default for StructDerives {
         StructDerives {
             copy: false,
             clone: false,
+            equatable: false,
+            hashable: false,
+            codable: false,
+            serialize: false,
+            deserialize: false,
+            serde_paths: vec![],
         }
     }
 }
@@ -77,6 +120,36 @@ impl Parse for StructAttr {
                 StructAttr::SwiftName(name)
             }
             "already_declared" => StructAttr::AlreadyDeclared,
+            "diffable" => StructAttr::Diffable,
+            "little_endian" => StructAttr::LittleEndian,
+            "packed" => StructAttr::Packed,
+            "instantiate" => {
+                let content;
+                syn::parenthesized!(content in input);
+
+                let instantiations = Punctuated::<Type, Token![,]>::parse_terminated(&content)?;
+                StructAttr::Instantiate(instantiations.into_iter().collect())
+            }
+            "derive" => {
+                let content;
+                syn::parenthesized!(content in input);
+
+                let derives = Punctuated::<Ident, Token![,]>::parse_terminated(&content)?;
+                StructAttr::Derive(derives.into_iter().collect())
+            }
+            "mirror" => {
+                input.parse::<Token![=]>()?;
+
+                let path: LitStr = input.parse()?;
+                StructAttr::Mirror(path.parse()?)
+            }
+            "swift_attr" => {
+                input.parse::<Token![=]>()?;
+
+                StructAttr::SwiftAttr(input.parse()?)
+            }
+            "Sendable" => StructAttr::Sendable,
+            "serde_json_round_trip" => StructAttr::SerdeJsonRoundTrip,
             _ => {
                 move_input_cursor_to_next_comma(input);
                 StructAttr::Error(StructAttrParseError::UnrecognizedAttribute(key))
@@ -87,8 +160,34 @@ impl Parse for StructAttr {
     }
 }
 
+impl Parse for StructFieldAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let key: Ident = input.parse()?;
+
+        let attr = match key.to_string().as_str() {
+            "swift_name" => {
+                input.parse::<Token![=]>()?;
+
+                StructFieldAttr::SwiftName(input.parse()?)
+            }
+            "default" => {
+                input.parse::<Token![=]>()?;
+
+                StructFieldAttr::Default(input.parse()?)
+            }
+            _ => {
+                move_input_cursor_to_next_comma(input);
+                StructFieldAttr::Unrecognized(key)
+            }
+        };
+
+        Ok(attr)
+    }
+}
+
 impl<'a> SharedStructDeclarationParser<'a> {
-    pub fn parse(self) -> Result<SharedStruct, syn::Error> {
+    /// Parse a shared struct, or each of the concrete types of a generic shared struct.
+    pub fn parse(self) -> Result<Vec<SharedStruct>, syn::Error> {
         let item_struct = self.item_struct;
 
         let mut attribs = StructAttribs::default();
@@ -124,21 +223,83 @@ impl<'a> SharedStructDeclarationParser<'a> {
                             StructAttr::AlreadyDeclared => {
                                 attribs.already_declared = true;
                             }
+                            StructAttr::Diffable => {
+                                attribs.diffable = true;
+                            }
+                            StructAttr::LittleEndian => {
+                                attribs.little_endian = true;
+                            }
+                            StructAttr::Mirror(path) => {
+                                attribs.mirror = Some(path);
+                            }
+                            StructAttr::Packed => {
+                                attribs.layout.packed = true;
+                            }
+                            StructAttr::Instantiate(instantiations) => {
+                                attribs.instantiate = Some(instantiations);
+                            }
+                            StructAttr::SwiftAttr(swift_attr) => {
+                                attribs.swift_attrs.push(swift_attr.value());
+                            }
+                            StructAttr::Sendable => {
+                                attribs.sendable = true;
+                            }
+                            StructAttr::SerdeJsonRoundTrip => {
+                                attribs.serde_json_round_trip = true;
+                            }
+                            StructAttr::Derive(derives) => {
+                                for derive in derives {
+                                    match derive.to_string().as_str() {
+                                        "Equatable" => attribs.derives.equatable = true,
+                                        "Hashable" => attribs.derives.hashable = true,
+                                        "Codable" => attribs.derives.codable = true,
+                                        _ => self
+                                            .errors
+                                            .push(ParseError::StructUnrecognizedDerive { derive }),
+                                    }
+                                }
+                            }
                         };
                     }
                 }
                 "derive" => match attr.parse_meta()? {
                     Meta::List(meta_list) => {
                         for derive in meta_list.nested {
-                            match derive.to_token_stream().to_string().as_str() {
+                            let path = match derive {
+                                NestedMeta::Meta(Meta::Path(path)) => path,
+                                _ => continue,
+                            };
+                            let name = match path.segments.last() {
+                                Some(segment) => segment.ident.to_string(),
+                                None => continue,
+                            };
+                            match name.as_str() {
                                 "Copy" => attribs.derives.copy = true,
                                 "Clone" => attribs.derives.clone = true,
+                                "Serialize" => {
+                                    attribs.derives.serialize = true;
+                                    attribs.derives.serde_paths.push(path.to_token_stream());
+                                }
+                                "Deserialize" => {
+                                    attribs.derives.deserialize = true;
+                                    attribs.derives.serde_paths.push(path.to_token_stream());
+                                }
                                 _ => {}
                             }
                         }
                     }
                     _ => todo!("Push parse error that derive attribute is in incorrect format"),
                 },
+                "repr" => match attr.parse_meta()? {
+                    Meta::List(meta_list) => {
+                        for repr in meta_list.nested {
+                            parse_repr(repr, &mut attribs.layout, self.errors);
+                        }
+                    }
+                    meta => self.errors.push(ParseError::StructInvalidRepr {
+                        repr: meta.to_token_stream(),
+                    }),
+                },
                 _ => todo!("Push unsupported attribute error."),
             }
         }
@@ -164,22 +325,280 @@ impl<'a> SharedStructDeclarationParser<'a> {
             StructSwiftRepr::Structure
         };
 
+        if swift_repr == StructSwiftRepr::Class
+            && (attribs.derives.equatable || attribs.derives.hashable || attribs.derives.codable)
+        {
+            self.errors.push(ParseError::StructDeriveOnSwiftReprClass {
+                struct_ident: item_struct.ident.clone(),
+            });
+        }
+
+        if attribs.diffable && item_struct.fields.len() > 64 {
+            self.errors
+                .push(ParseError::DiffableStructHasTooManyFields {
+                    struct_ident: item_struct.ident.clone(),
+                });
+        }
+
+        if attribs.serde_json_round_trip
+            && !(attribs.derives.codable
+                && attribs.derives.serialize
+                && attribs.derives.deserialize
+                && item_struct.generics.params.is_empty())
+        {
+            self.errors
+                .push(ParseError::SerdeJsonRoundTripStructMissingDerives {
+                    struct_ident: item_struct.ident.clone(),
+                });
+        }
+
+        if attribs.layout.packed && attribs.layout.align.is_some() {
+            self.errors.push(ParseError::StructPackedAndAligned {
+                struct_ident: item_struct.ident.clone(),
+            });
+        }
+
+        let generics: Vec<Ident> = item_struct
+            .generics
+            .type_params()
+            .map(|param| param.ident.clone())
+            .collect();
+
+        let mut field_attribs = vec![];
+        for field in item_struct.fields.iter() {
+            field_attribs.push(parse_field_attributes(field, self.errors)?);
+        }
+
+        let mut fields = StructFields::from_syn_fields(item_struct.fields);
+        match &mut fields {
+            StructFields::Named(named) => {
+                for (field, attribs) in named.iter_mut().zip(field_attribs) {
+                    field.swift_name = attribs.swift_name;
+                    field.default = attribs.default;
+                }
+            }
+            StructFields::Unnamed(unnamed) => {
+                for (field, attribs) in unnamed.iter_mut().zip(field_attribs) {
+                    if let Some(swift_name) = attribs.swift_name {
+                        self.errors
+                            .push(ParseError::UnnamedStructFieldHasSwiftName { swift_name });
+                    }
+                    field.default = attribs.default;
+                }
+            }
+            StructFields::Unit => {}
+        }
+
         let shared_struct = SharedStruct {
             name: item_struct.ident,
             swift_repr,
-            fields: StructFields::from_syn_fields(item_struct.fields),
+            fields,
             swift_name: attribs.swift_name,
             already_declared: attribs.already_declared,
+            diffable: attribs.diffable,
+            little_endian: attribs.little_endian,
+            mirror: attribs.mirror,
             derives: attribs.derives,
+            layout: attribs.layout,
+            instantiation: None,
+            swift_attrs: attribs.swift_attrs,
+            sendable: attribs.sendable,
+            serde_json_round_trip: attribs.serde_json_round_trip,
+        };
+
+        if shared_struct.little_endian {
+            for field in shared_struct.fields.normalized_fields() {
+                if little_endian_field_len(&field.ty).is_none() {
+                    self.errors
+                        .push(ParseError::LittleEndianStructUnsupportedField { ty: field.ty });
+                }
+            }
+        }
+
+        for field in shared_struct.fields.normalized_fields() {
+            if let Some(ty) = BuiltInArray::find_in_option_or_result(&field.ty) {
+                self.errors.push(ParseError::ArrayInOptionOrResult { ty });
+            } else if let Some(ty) = BuiltInBTreeMap::find_in_option_or_result(&field.ty) {
+                self.errors
+                    .push(ParseError::BTreeMapInOptionOrResult { ty });
+            } else if let Some(ty) = BuiltInRange::find_in_option_or_result(&field.ty) {
+                self.errors.push(ParseError::RangeInOptionOrResult { ty });
+            }
+        }
+
+        match attribs.instantiate {
+            None if generics.is_empty() => Ok(vec![shared_struct]),
+            None => {
+                self.errors
+                    .push(ParseError::StructGenericsWithoutInstantiate {
+                        struct_ident: shared_struct.name,
+                    });
+                Ok(vec![])
+            }
+            Some(instantiations) => Ok(instantiate(
+                shared_struct,
+                &generics,
+                instantiations,
+                self.errors,
+            )),
+        }
+    }
+}
+
+/// Create a concrete struct for each of the types in `#[swift_bridge(instantiate(...))]`.
+///
+/// A struct with a single generic is instantiated with types, such as `instantiate(u32, f64)`,
+/// and a struct with more than one generic is instantiated with tuples, such as
+/// `instantiate((u32, f64), (u8, u8))`.
+fn instantiate(
+    shared_struct: SharedStruct,
+    generics: &[Ident],
+    instantiations: Vec<Type>,
+    errors: &mut ParseErrors,
+) -> Vec<SharedStruct> {
+    for field in shared_struct.fields.normalized_fields() {
+        let is_generic = generics
+            .iter()
+            .any(|generic| type_is_ident(&field.ty, generic));
+        if !is_generic && tokens_contain_any(field.ty.to_token_stream(), generics) {
+            errors.push(ParseError::StructUnsupportedGenericField { ty: field.ty });
+        }
+    }
+
+    let mut instantiated = vec![];
+    for instantiation in instantiations {
+        let args: Option<Vec<Ident>> = match &instantiation {
+            Type::Tuple(tuple) if generics.len() > 1 => {
+                tuple.elems.iter().map(type_ident).collect()
+            }
+            ty if generics.len() == 1 => type_ident(ty).map(|ident| vec![ident]),
+            _ => None,
+        };
+        let args = match args {
+            Some(args) if args.len() == generics.len() => args,
+            _ => {
+                errors.push(ParseError::StructInvalidInstantiation { instantiation });
+                continue;
+            }
         };
 
-        Ok(shared_struct)
+        let fields = shared_struct.fields.map_types(|ty| {
+            match generics
+                .iter()
+                .position(|generic| type_is_ident(ty, generic))
+            {
+                Some(idx) => {
+                    let arg = &args[idx];
+                    syn::parse_quote! { #arg }
+                }
+                None => ty.clone(),
+            }
+        });
+
+        instantiated.push(SharedStruct {
+            fields,
+            instantiation: Some(StructInstantiation {
+                generics: generics.to_vec(),
+                args,
+                generic_fields: shared_struct.fields.clone(),
+                is_first: instantiated.is_empty(),
+            }),
+            ..shared_struct.clone()
+        });
+    }
+
+    instantiated
+}
+
+/// Parse the `#[swift_bridge(...)]` attributes of one of a struct's fields.
+fn parse_field_attributes(
+    field: &syn::Field,
+    errors: &mut ParseErrors,
+) -> Result<StructFieldAttribs, syn::Error> {
+    let mut field_attribs = StructFieldAttribs::default();
+
+    for attr in field.attrs.iter() {
+        if attr.path.to_token_stream().to_string() != "swift_bridge" {
+            continue;
+        }
+
+        let attrs =
+            attr.parse_args_with(Punctuated::<StructFieldAttr, Token![,]>::parse_terminated)?;
+        for attr in attrs {
+            match attr {
+                StructFieldAttr::SwiftName(name) => {
+                    field_attribs.swift_name = Some(name);
+                }
+                StructFieldAttr::Default(default) => {
+                    if swift_default_value(&default).is_none() {
+                        errors.push(ParseError::StructFieldInvalidDefault { default: *default });
+                    } else {
+                        field_attribs.default = Some(*default);
+                    }
+                }
+                StructFieldAttr::Unrecognized(attribute) => {
+                    errors.push(ParseError::StructUnrecognizedAttribute { attribute });
+                }
+            }
+        }
+    }
+
+    Ok(field_attribs)
+}
+
+/// `u32` -> `Some(u32)`, `Vec<u32>` -> `None`
+fn type_ident(ty: &Type) -> Option<Ident> {
+    match ty {
+        Type::Path(path) if path.qself.is_none() => path.path.get_ident().cloned(),
+        _ => None,
+    }
+}
+
+fn type_is_ident(ty: &Type, ident: &Ident) -> bool {
+    type_ident(ty).as_ref() == Some(ident)
+}
+
+fn tokens_contain_any(tokens: TokenStream, idents: &[Ident]) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => idents.contains(&ident),
+        TokenTree::Group(group) => tokens_contain_any(group.stream(), idents),
+        _ => false,
+    })
+}
+
+/// Parse one of the items in a `#[repr(...)]` attribute.
+///
+/// `C` is always used, so it is allowed but ignored.
+fn parse_repr(repr: NestedMeta, layout: &mut StructLayout, errors: &mut ParseErrors) {
+    match &repr {
+        NestedMeta::Meta(Meta::Path(path)) if path.is_ident("C") => {}
+        NestedMeta::Meta(Meta::Path(path)) if path.is_ident("packed") => {
+            layout.packed = true;
+        }
+        NestedMeta::Meta(Meta::List(list)) if list.path.is_ident("align") => {
+            let align = match list.nested.first() {
+                Some(NestedMeta::Lit(syn::Lit::Int(align))) if list.nested.len() == 1 => {
+                    align.base10_parse::<u32>().ok()
+                }
+                _ => None,
+            };
+            match align {
+                Some(align) if align.is_power_of_two() => layout.align = Some(align),
+                _ => errors.push(ParseError::StructInvalidRepr {
+                    repr: repr.to_token_stream(),
+                }),
+            }
+        }
+        _ => errors.push(ParseError::StructInvalidRepr {
+            repr: repr.to_token_stream(),
+        }),
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::bridged_type::shared_struct::StructField;
     use crate::test_utils::{parse_errors, parse_ok};
     use quote::{quote, ToTokens};
 
@@ -363,6 +782,39 @@ mod tests {
         assert_eq!(ty2.derives.clone, true);
     }
 
+    /// Verify that we parse serde's `Serialize` and `Deserialize` derives, with or without a
+    /// `serde::` prefix.
+    #[test]
+    fn parse_serde_derives() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[derive(Serialize, serde::Deserialize)]
+                struct Foo;
+
+                #[derive(Clone)]
+                struct Bar;
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_struct();
+        assert!(ty.derives.serialize);
+        assert!(ty.derives.deserialize);
+        let serde_paths: Vec<String> = ty
+            .derives
+            .serde_paths
+            .iter()
+            .map(|path| path.to_string())
+            .collect();
+        assert_eq!(serde_paths, vec!["Serialize", "serde :: Deserialize"]);
+
+        let ty2 = module.types.types()[1].unwrap_shared_struct();
+        assert!(!ty2.derives.serialize);
+        assert!(!ty2.derives.deserialize);
+    }
+
     /// Verify that we properly parse multiple comma separated struct attributes.
     #[test]
     fn parses_multiple_struct_attributes() {
@@ -406,6 +858,120 @@ mod tests {
         assert_eq!(ty.derives.clone, true);
     }
 
+    /// Verify that we parse the Swift conformances in a `#[swift_bridge(derive(...))]` attribute.
+    #[test]
+    fn parses_swift_derive_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", derive(Equatable))]
+                struct Foo {
+                    field: u8
+                }
+
+                #[swift_bridge(swift_repr = "struct", derive(Equatable, Hashable))]
+                struct Bar {
+                    field: u8
+                }
+
+                #[swift_bridge(swift_repr = "struct", derive(Codable))]
+                struct Baz {
+                    field: u8
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.get("Foo").unwrap().unwrap_shared_struct();
+        assert!(ty.derives.equatable);
+        assert!(!ty.derives.hashable);
+
+        let ty2 = module.types.get("Bar").unwrap().unwrap_shared_struct();
+        assert!(ty2.derives.equatable);
+        assert!(ty2.derives.hashable);
+        assert!(!ty2.derives.codable);
+
+        let ty3 = module.types.get("Baz").unwrap().unwrap_shared_struct();
+        assert!(ty3.derives.codable);
+        assert!(!ty3.derives.equatable);
+    }
+
+    /// Verify that we can parse the `Sendable` attribute.
+    #[test]
+    fn parses_sendable_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", Sendable)]
+                struct Foo {
+                    field: u8
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.get("Foo").unwrap().unwrap_shared_struct();
+        assert!(ty.sendable);
+    }
+
+    /// Verify that we can parse the `swift_attr` attribute.
+    #[test]
+    fn parses_swift_attr_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", swift_attr = "@_spi(Internal)")]
+                struct Foo {
+                    field: u8
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.get("Foo").unwrap().unwrap_shared_struct();
+        assert_eq!(ty.swift_attrs, vec!["@_spi(Internal)"]);
+    }
+
+    /// Verify that we return an error if a `#[swift_bridge(derive(...))]` attribute contains
+    /// something other than `Equatable`, `Hashable` or `Codable`, or is used on a
+    /// `swift_repr = "class"` struct.
+    #[test]
+    fn error_if_invalid_swift_derive_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", derive(Comparable))]
+                struct Foo {
+                    field: u8
+                }
+
+                #[swift_bridge(swift_repr = "class", derive(Hashable))]
+                struct Bar {
+                    field: u8
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 2);
+
+        match &errors[0] {
+            ParseError::StructUnrecognizedDerive { derive } => {
+                assert_eq!(derive, "Comparable");
+            }
+            _ => panic!(),
+        };
+        match &errors[1] {
+            ParseError::StructDeriveOnSwiftReprClass { struct_ident } => {
+                assert_eq!(struct_ident, "Bar");
+            }
+            _ => panic!(),
+        };
+    }
+
     /// Verify that we can parse an `already_defined = "struct"` attribute.
     #[test]
     fn parses_struct_already_declared_attribute() {
@@ -423,6 +989,589 @@ mod tests {
         assert!(ty.already_declared);
     }
 
+    /// Verify that we can parse a `diffable` attribute.
+    #[test]
+    fn parses_struct_diffable_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", diffable)]
+                struct SomeType {
+                    field: u8
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_struct();
+        assert!(ty.diffable);
+    }
+
+    /// Verify that we can parse the `swift_name` attribute of a struct's fields.
+    #[test]
+    fn parses_struct_field_swift_name() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeType {
+                    #[swift_bridge(swift_name = "userId")]
+                    user_id: u32,
+                    age: u8,
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_struct();
+        let names: Vec<String> = ty
+            .fields
+            .normalized_fields()
+            .iter()
+            .map(|field| field.ffi_field_name())
+            .collect();
+        assert_eq!(names, vec!["userId", "age"]);
+    }
+
+    /// Verify that we return an error if an unnamed field has a `swift_name`, or if a field has an
+    /// unrecognized attribute.
+    #[test]
+    fn error_if_invalid_struct_field_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct Unnamed(#[swift_bridge(swift_name = "first")] u8);
+
+                #[swift_bridge(swift_repr = "struct")]
+                struct Named {
+                    #[swift_bridge(unrecognized)]
+                    field: u8,
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 2);
+
+        match &errors[0] {
+            ParseError::UnnamedStructFieldHasSwiftName { swift_name } => {
+                assert_eq!(swift_name.value(), "first");
+            }
+            _ => panic!(),
+        };
+        match &errors[1] {
+            ParseError::StructUnrecognizedAttribute { attribute } => {
+                assert_eq!(attribute, "unrecognized");
+            }
+            _ => panic!(),
+        };
+    }
+
+    /// Verify that we can parse the `default` attribute of a struct's fields.
+    #[test]
+    fn parses_struct_field_default() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeType {
+                    #[swift_bridge(default = 8080)]
+                    port: u16,
+                    #[swift_bridge(default = -1.5)]
+                    offset: f32,
+                    #[swift_bridge(default = true)]
+                    enabled: bool,
+                    name: String,
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_struct();
+        let defaults: Vec<Option<String>> = match &ty.fields {
+            StructFields::Named(named) => named
+                .iter()
+                .map(|field| field.swift_default_value())
+                .collect(),
+            _ => panic!(),
+        };
+        assert_eq!(
+            defaults,
+            vec![
+                Some("8080".to_string()),
+                Some("-1.5".to_string()),
+                Some("true".to_string()),
+                None
+            ]
+        );
+    }
+
+    /// Verify that we return an error if a field's default value is not an integer, float or
+    /// boolean literal.
+    #[test]
+    fn error_if_invalid_struct_field_default() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeType {
+                    #[swift_bridge(default = "hello")]
+                    name: String,
+                    #[swift_bridge(default = some_fn())]
+                    count: u8,
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 2);
+
+        match &errors[0] {
+            ParseError::StructFieldInvalidDefault { default } => {
+                assert_eq!(default.to_token_stream().to_string(), r#""hello""#);
+            }
+            _ => panic!(),
+        };
+        match &errors[1] {
+            ParseError::StructFieldInvalidDefault { default } => {
+                assert_eq!(default.to_token_stream().to_string(), "some_fn ()");
+            }
+            _ => panic!(),
+        };
+    }
+
+    /// Verify that we can parse a `little_endian` attribute.
+    #[test]
+    fn parses_struct_little_endian_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", little_endian)]
+                struct SomeType {
+                    field: u32
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_struct();
+        assert!(ty.little_endian);
+    }
+
+    /// Verify that we return an error if a `little_endian` struct has a field that does not have
+    /// a fixed size.
+    #[test]
+    fn error_if_little_endian_struct_has_unsupported_field() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", little_endian)]
+                struct SomeType {
+                    len: u32,
+                    name: String,
+                    ptr: usize,
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 2);
+
+        match &errors[0] {
+            ParseError::LittleEndianStructUnsupportedField { ty } => {
+                assert_eq!(ty.to_token_stream().to_string(), "String");
+            }
+            _ => panic!(),
+        };
+        match &errors[1] {
+            ParseError::LittleEndianStructUnsupportedField { ty } => {
+                assert_eq!(ty.to_token_stream().to_string(), "usize");
+            }
+            _ => panic!(),
+        };
+    }
+
+    /// Verify that we return an error if a struct field's array is held by an `Option`.
+    #[test]
+    fn error_if_struct_field_has_array_in_option() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeType {
+                    bytes: [u8; 4],
+                    maybe_bytes: Option<[u8; 4]>,
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+
+        match &errors[0] {
+            ParseError::ArrayInOptionOrResult { ty } => {
+                assert_eq!(ty.to_token_stream().to_string(), "Option < [u8 ; 4] >");
+            }
+            _ => panic!(),
+        };
+    }
+
+    /// Verify that we push an error if a struct field holds a `BTreeMap` in an `Option`.
+    #[test]
+    fn error_if_struct_field_has_btree_map_in_option() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeType {
+                    maybe_map: Option<BTreeMap<u8, u8>>,
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+
+        match &errors[0] {
+            ParseError::BTreeMapInOptionOrResult { ty } => {
+                assert_eq!(
+                    ty.to_token_stream().to_string(),
+                    "Option < BTreeMap < u8 , u8 > >"
+                );
+            }
+            _ => panic!(),
+        };
+    }
+
+    /// Verify that we push an error if a struct field holds a `Range` in an `Option`.
+    #[test]
+    fn error_if_struct_field_has_range_in_option() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct SomeType {
+                    maybe_range: Option<Range<u32>>,
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+
+        match &errors[0] {
+            ParseError::RangeInOptionOrResult { ty } => {
+                assert_eq!(ty.to_token_stream().to_string(), "Option < Range < u32 > >");
+            }
+            _ => panic!(),
+        };
+    }
+
+    /// Verify that we can parse a `mirror` attribute.
+    #[test]
+    fn parses_struct_mirror_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", mirror = "crate::models::Foo")]
+                struct Foo {
+                    bar: u8
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_struct();
+        assert_eq!(
+            ty.mirror.to_token_stream().to_string(),
+            "crate :: models :: Foo"
+        );
+    }
+
+    /// Verify that we can parse a `serde_json_round_trip` attribute.
+    #[test]
+    fn parses_struct_serde_json_round_trip_attribute() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[derive(serde::Serialize, serde::Deserialize)]
+                #[swift_bridge(swift_repr = "struct", derive(Codable), serde_json_round_trip)]
+                struct Foo {
+                    bar: u8
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let ty = module.types.types()[0].unwrap_shared_struct();
+        assert!(ty.serde_json_round_trip);
+        assert!(ty.has_serde_json_round_trip());
+    }
+
+    /// Verify that we return an error if a `serde_json_round_trip` struct does not derive
+    /// `Codable`, `Serialize` and `Deserialize`.
+    #[test]
+    fn error_if_serde_json_round_trip_struct_missing_derives() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[derive(serde::Serialize)]
+                #[swift_bridge(swift_repr = "struct", derive(Codable), serde_json_round_trip)]
+                struct Foo {
+                    bar: u8
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+
+        match &errors[0] {
+            ParseError::SerdeJsonRoundTripStructMissingDerives { struct_ident } => {
+                assert_eq!(struct_ident, "Foo");
+            }
+            _ => panic!(),
+        }
+    }
+
+    /// Verify that we return an error if a `diffable` struct has more fields than fit in the
+    /// changed fields bit mask.
+    #[test]
+    fn error_if_diffable_struct_has_too_many_fields() {
+        let fields = (0..65usize).map(|idx| quote::format_ident!("field_{}", idx));
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", diffable)]
+                struct SomeType {
+                    #(#fields: u8),*
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 1);
+
+        match &errors[0] {
+            ParseError::DiffableStructHasTooManyFields { struct_ident } => {
+                assert_eq!(struct_ident, "SomeType");
+            }
+            _ => panic!(),
+        };
+    }
+
+    /// Verify that we parse a struct's alignment and packing from its `#[repr(...)]` and from the
+    /// `packed` attribute.
+    #[test]
+    fn parse_struct_layout() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                #[repr(C, align(8))]
+                struct Aligned {
+                    field: u8
+                }
+
+                #[swift_bridge(swift_repr = "struct")]
+                #[repr(C, packed)]
+                struct ReprPacked {
+                    field: u8
+                }
+
+                #[swift_bridge(swift_repr = "struct", packed)]
+                struct Packed {
+                    field: u8
+                }
+
+                #[swift_bridge(swift_repr = "struct")]
+                #[repr(C)]
+                struct ReprC {
+                    field: u8
+                }
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let layouts: Vec<StructLayout> = module
+            .types
+            .types()
+            .iter()
+            .map(|ty| ty.unwrap_shared_struct().layout.clone())
+            .collect();
+        assert_eq!(
+            layouts,
+            vec![
+                StructLayout {
+                    align: Some(8),
+                    packed: false
+                },
+                StructLayout {
+                    align: None,
+                    packed: true
+                },
+                StructLayout {
+                    align: None,
+                    packed: true
+                },
+                StructLayout::default(),
+            ]
+        );
+    }
+
+    /// Verify that we return an error for an unsupported repr, an alignment that is not a power
+    /// of two, and a struct that is both packed and aligned.
+    #[test]
+    fn error_if_invalid_struct_layout() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                #[repr(transparent)]
+                struct Transparent {
+                    field: u8
+                }
+
+                #[swift_bridge(swift_repr = "struct")]
+                #[repr(C, align(3))]
+                struct BadAlign {
+                    field: u8
+                }
+
+                #[swift_bridge(swift_repr = "struct", packed)]
+                #[repr(C, align(4))]
+                struct PackedAndAligned {
+                    field: u8
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 3);
+
+        match &errors[0] {
+            ParseError::StructInvalidRepr { repr } => {
+                assert_eq!(repr.to_string(), "transparent");
+            }
+            _ => panic!(),
+        };
+        match &errors[1] {
+            ParseError::StructInvalidRepr { repr } => {
+                assert_eq!(repr.to_string(), "align (3)");
+            }
+            _ => panic!(),
+        };
+        match &errors[2] {
+            ParseError::StructPackedAndAligned { struct_ident } => {
+                assert_eq!(struct_ident, "PackedAndAligned");
+            }
+            _ => panic!(),
+        };
+    }
+
+    /// Verify that we create one shared struct for each instantiation of a generic struct.
+    #[test]
+    fn parse_struct_instantiations() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct", instantiate(u32, f64))]
+                struct Pair<T> {
+                    first: T,
+                    second: T,
+                    count: u8,
+                }
+
+                #[swift_bridge(swift_repr = "struct", instantiate((u8, String)))]
+                struct Entry<K, V>(K, V);
+            }
+        };
+
+        let module = parse_ok(tokens);
+
+        let pair_u32 = module
+            .types
+            .get("Pair<u32>")
+            .unwrap()
+            .unwrap_shared_struct();
+        let pair_f64 = module
+            .types
+            .get("Pair<f64>")
+            .unwrap()
+            .unwrap_shared_struct();
+        assert!(pair_u32.instantiation.as_ref().unwrap().is_first);
+        assert!(!pair_f64.instantiation.as_ref().unwrap().is_first);
+
+        let fields = pair_f64.fields.normalized_fields();
+        assert_eq!(fields[0].ty.to_token_stream().to_string(), "f64");
+        assert_eq!(fields[1].ty.to_token_stream().to_string(), "f64");
+        assert_eq!(fields[2].ty.to_token_stream().to_string(), "u8");
+
+        let entry = module
+            .types
+            .get("Entry<u8,String>")
+            .unwrap()
+            .unwrap_shared_struct();
+        let fields = entry.fields.normalized_fields();
+        assert_eq!(fields[0].ty.to_token_stream().to_string(), "u8");
+        assert_eq!(fields[1].ty.to_token_stream().to_string(), "String");
+    }
+
+    /// Verify that we return an error if a generic struct has no instantiations, if an
+    /// instantiation does not match the generics, or if a field only partially uses a generic.
+    #[test]
+    fn error_if_invalid_struct_instantiation() {
+        let tokens = quote! {
+            #[swift_bridge::bridge]
+            mod ffi {
+                #[swift_bridge(swift_repr = "struct")]
+                struct NoInstantiations<T> {
+                    field: T
+                }
+
+                #[swift_bridge(swift_repr = "struct", instantiate((u8, u16)))]
+                struct WrongArity<T> {
+                    field: T
+                }
+
+                #[swift_bridge(swift_repr = "struct", instantiate(u8))]
+                struct NestedGeneric<T> {
+                    field: Option<T>
+                }
+            }
+        };
+
+        let errors = parse_errors(tokens);
+        assert_eq!(errors.len(), 3);
+
+        match &errors[0] {
+            ParseError::StructGenericsWithoutInstantiate { struct_ident } => {
+                assert_eq!(struct_ident, "NoInstantiations");
+            }
+            _ => panic!(),
+        };
+        match &errors[1] {
+            ParseError::StructInvalidInstantiation { instantiation } => {
+                assert_eq!(instantiation.to_token_stream().to_string(), "(u8 , u16)");
+            }
+            _ => panic!(),
+        };
+        match &errors[2] {
+            ParseError::StructUnsupportedGenericField { ty } => {
+                assert_eq!(ty.to_token_stream().to_string(), "Option < T >");
+            }
+            _ => panic!(),
+        };
+    }
+
     /// Verify that we return an error if an attribute isn't recognized.
     #[test]
     fn error_if_attribute_unrecognized() {
@@ -1,3 +1,4 @@
+use crate::bridge_module_attributes::Utf8Validation;
 use crate::bridged_type::{
     BridgedType, CustomBridgedType, OpaqueForeignType, SharedEnum, SharedStruct, SharedType,
 };
@@ -17,6 +18,10 @@ pub(crate) use self::generics::*;
 pub(crate) struct TypeDeclarations {
     decls: HashMap<String, TypeDeclaration>,
     order: Vec<String>,
+    /// `#[swift_bridge(net_addrs_as_strings)]`
+    net_addrs_as_strings: bool,
+    /// `#[swift_bridge(utf8 = "...")]`
+    utf8_validation: Utf8Validation,
 }
 
 #[derive(Clone)]
@@ -58,7 +63,9 @@ impl TypeDeclaration {
                 reference,
                 mutable,
                 has_swift_bridge_copy_annotation: opaque.attributes.copy.is_some(),
+                has_swift_bridge_identity_map_annotation: opaque.attributes.identity_map,
                 generics: opaque.generics.clone(),
+                has_own_swift_class: opaque.has_own_swift_class,
             }),
             _ => None,
         }
@@ -71,20 +78,61 @@ pub(crate) struct OpaqueForeignTypeDeclaration {
     pub host_lang: HostLang,
     pub attributes: OpaqueTypeAllAttributes,
     pub generics: OpaqueRustTypeGenerics,
+    /// Whether this concrete generic type gets its own Swift class, such as `MyCacheU32` for
+    /// `type MyCache<u32>`, since there is no `#[swift_bridge(declare_generic)]` declaration of
+    /// the generic type.
+    pub has_own_swift_class: bool,
 }
 
 impl OpaqueForeignTypeDeclaration {
-    pub(crate) fn ffi_repr_type_tokens(&self) -> TokenStream {
+    pub(crate) fn ffi_repr_type_tokens(&self, types: &TypeDeclarations) -> TokenStream {
         if self.attributes.copy.is_some() {
             self.ffi_copy_repr_ident().to_token_stream()
         } else {
             let ty_name = &self.ty;
+            let generics = self
+                .generics
+                .angle_bracketed_concrete_generics_tokens(types);
             quote::quote! {
-                *mut super::#ty_name
+                *mut super::#ty_name #generics
             }
         }
     }
 
+    /// The name of the Swift class for this opaque Rust type.
+    ///
+    /// For `type SomeType<u32>` this would be "SomeTypeU32" if the type has its own Swift class,
+    /// or "SomeType" if it is an instantiation of a `declare_generic` Swift class.
+    pub(crate) fn swift_class_name(&self) -> String {
+        if self.has_own_swift_class {
+            format!("{}{}", self.ty, self.generics.capitalized_generics_string())
+        } else {
+            self.ty.to_string()
+        }
+    }
+
+    /// The type's segment of the C FFI link names of its methods.
+    ///
+    /// For `type SomeType<u32>` this would be "SomeType$u32"
+    pub(crate) fn dollar_separated_name(&self) -> String {
+        format!(
+            "{}{}",
+            self.ty,
+            self.generics.dollar_prefixed_generics_string()
+        )
+    }
+
+    /// The type's segment of the names of the Rust functions that expose its methods.
+    ///
+    /// For `type SomeType<u32>` this would be "SomeType_u32"
+    pub(crate) fn underscore_separated_name(&self) -> String {
+        format!(
+            "{}{}",
+            self.ty,
+            self.generics.underscore_prefixed_generics_string()
+        )
+    }
+
     /// The name of the FFI representation for an opaque Rust type.
     /// __swift_bridge__$SomeType
     pub(crate) fn ffi_repr_name_string(&self) -> String {
@@ -208,6 +256,25 @@ impl OpaqueForeignTypeDeclaration {
 }
 
 impl TypeDeclarations {
+    /// Whether the module's `IpAddr`s and `SocketAddr`s are seen by Swift as a `String` instead
+    /// of a `RustIpAddr` or `RustSocketAddr`.
+    pub(crate) fn net_addrs_as_strings(&self) -> bool {
+        self.net_addrs_as_strings
+    }
+
+    pub(crate) fn set_net_addrs_as_strings(&mut self, net_addrs_as_strings: bool) {
+        self.net_addrs_as_strings = net_addrs_as_strings;
+    }
+
+    /// How the module's `&str`s that come from Swift are checked for valid UTF-8.
+    pub(crate) fn utf8_validation(&self) -> Utf8Validation {
+        self.utf8_validation
+    }
+
+    pub(crate) fn set_utf8_validation(&mut self, utf8_validation: Utf8Validation) {
+        self.utf8_validation = utf8_validation;
+    }
+
     pub(crate) fn get<Q: ?Sized>(&self, type_name: &Q) -> Option<&TypeDeclaration>
     where
         Q: std::hash::Hash + Eq,
@@ -235,6 +302,10 @@ impl TypeDeclarations {
             Type::Path(path) => path.to_token_stream().to_string(),
             _ => todo!("Handle other cases"),
         };
+
+        // Handles generics. i.e. "SomeType < u32, u64 >" -> SomeType<u32,u64>
+        let ty = ty.replace(" ", "");
+
         self.get(&ty)
     }
 
@@ -127,6 +127,23 @@ impl OpaqueRustTypeGenerics {
         generics
     }
 
+    /// "U32String" for generics `<u32, String>`.
+    /// "" if there are no generics.
+    pub(crate) fn capitalized_generics_string(&self) -> String {
+        let mut generics = String::with_capacity(self.generics.len() * 4);
+
+        for generic in self.generics.iter() {
+            let generic = generic.ident.to_string();
+            let mut chars = generic.chars();
+            if let Some(first) = chars.next() {
+                generics.extend(first.to_uppercase());
+                generics += chars.as_str();
+            }
+        }
+
+        generics
+    }
+
     /// "_A_B_C" if there are generics.
     /// "" if there are no generics.
     pub(crate) fn underscore_prefixed_generics_string(&self) -> String {
@@ -1,13 +1,19 @@
-use crate::bridged_type::boxed_fn::BridgeableBoxedFnOnce;
+use crate::bridged_type::boxed_fn::BridgeableBoxedFn;
 use crate::bridged_type::{pat_type_pat_is_self, BridgeableType, BridgedType, StdLibType};
-use crate::parse::{HostLang, SharedTypeDeclaration, TypeDeclaration, TypeDeclarations};
+use crate::parse::{
+    HostLang, OpaqueForeignTypeDeclaration, SharedTypeDeclaration, TypeDeclaration,
+    TypeDeclarations,
+};
 use crate::SWIFT_BRIDGE_PREFIX;
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{quote, quote_spanned, ToTokens};
 use std::collections::{HashMap, HashSet};
 use std::ops::Deref;
 use syn::spanned::Spanned;
-use syn::{FnArg, ForeignItemFn, Lifetime, LitStr, Path, ReturnType, Token, Type};
+use syn::{
+    FnArg, ForeignItemFn, GenericArgument, Lifetime, LitStr, Path, PathArguments, ReturnType,
+    Token, Type,
+};
 
 mod to_extern_c_fn;
 mod to_extern_c_param_names_and_types;
@@ -93,6 +99,79 @@ pub(crate) struct ParsedExternFn {
     /// Get one of the associated type's fields
     pub get_field: Option<GetField>,
     pub argument_labels: HashMap<Ident, LitStr>,
+    /// The role that this method plays in the associated type's generated Swift `Store` class.
+    pub store_role: Option<StoreRole>,
+    /// Compress the `Vec<u8>` or `String` that this function returns before passing it to Swift.
+    pub compress: Option<Compression>,
+    /// `#[swift_bridge(scoped = "withTransaction")]`
+    /// The name of a Swift function that calls this function and passes the returned guard or
+    /// borrow to a closure, which it must not outlive.
+    pub scoped: Option<LitStr>,
+    /// `#[swift_bridge(swift_attr = "@_spi(Internal)")]`
+    /// Swift attributes that are added to the generated Swift function.
+    pub swift_attrs: Vec<String>,
+}
+
+/// `#[swift_bridge(Store(state))]` or `#[swift_bridge(Store(dispatch))]`
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum StoreRole {
+    /// Returns a snapshot of the store's state.
+    State,
+    /// Sends an action to the store.
+    Dispatch,
+}
+
+/// What a `#[swift_bridge(scoped = "...")]` function passes to its Swift closure.
+pub(crate) enum ScopedValue<'a> {
+    /// `fn begin(&self) -> TransactionGuard` passes a `TransactionGuardRefMut` and drops the guard
+    /// once the closure returns.
+    Guard(&'a OpaqueForeignTypeDeclaration),
+    /// `fn inner(&self) -> &Inner` passes an `InnerRef` that is borrowed from `self`.
+    Borrow {
+        ty: &'a OpaqueForeignTypeDeclaration,
+        mutable: bool,
+    },
+}
+
+/// `#[swift_bridge(compress = "...")]`
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum Compression {
+    /// The LZ4 block format, which Swift decodes with the Compression framework's
+    /// `COMPRESSION_LZ4_RAW`.
+    Lz4,
+}
+
+/// How a `#[swift_bridge(init)]` function that can fail reports the failure to Swift.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum FailableInitializer {
+    /// `fn new() -> Result<SomeType, E>` becomes `init() throws`
+    Throws,
+    /// `fn new() -> Option<SomeType>` becomes `init?()`
+    Optional,
+}
+
+impl FailableInitializer {
+    /// For an initializer's `Result<SomeType, E>` or `Option<SomeType>` return type, how the
+    /// initializer fails and the type that it initializes.
+    pub(crate) fn from_return_type(ty: &Type) -> Option<(FailableInitializer, &Type)> {
+        let segment = match ty {
+            Type::Path(path) => path.path.segments.last()?,
+            _ => return None,
+        };
+        let failable = match segment.ident.to_string().as_str() {
+            "Result" => FailableInitializer::Throws,
+            "Option" => FailableInitializer::Optional,
+            _ => return None,
+        };
+
+        match &segment.arguments {
+            PathArguments::AngleBracketed(args) => match args.args.first()? {
+                GenericArgument::Type(initialized) => Some((failable, initialized)),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
 }
 
 pub(crate) enum GetField {
@@ -135,6 +214,21 @@ impl ParsedExternFn {
         self.func.sig.receiver().is_some()
     }
 
+    /// How this initializer fails, if it returns a `Result<SomeType, E>` or an
+    /// `Option<SomeType>`.
+    pub(crate) fn failable_initializer(&self) -> Option<FailableInitializer> {
+        if !self.is_swift_initializer {
+            return None;
+        }
+
+        match &self.func.sig.output {
+            ReturnType::Type(_, ty) => {
+                FailableInitializer::from_return_type(ty).map(|(failable, _)| failable)
+            }
+            ReturnType::Default => None,
+        }
+    }
+
     pub fn self_reference(&self) -> Option<(Token![&], Option<Lifetime>)> {
         match self.func.sig.receiver()? {
             FnArg::Receiver(receiver) => receiver.reference.clone(),
@@ -155,6 +249,38 @@ impl ParsedExternFn {
         }
     }
 
+    /// Whether or not this is a method that returns a reference to an opaque Rust type that
+    /// borrows from `self`, such as `fn camera(&self) -> &Camera`.
+    ///
+    /// The returned reference points into `self`, so Swift keeps `self` alive for as long as the
+    /// reference is alive.
+    pub(crate) fn returns_reference_borrowed_from_self(&self, types: &TypeDeclarations) -> bool {
+        if !self.host_lang.is_rust()
+            || self.self_reference().is_none()
+            || self.is_copy_method_on_opaque_type()
+        {
+            return false;
+        }
+
+        let ret = match &self.func.sig.output {
+            ReturnType::Type(_, ret) => ret,
+            ReturnType::Default => return false,
+        };
+        let elem = match ret.deref() {
+            Type::Reference(ty_ref) => match ty_ref.elem.deref() {
+                Type::Path(elem) => elem,
+                _ => return false,
+            },
+            _ => return false,
+        };
+        match types.get_with_type_path(elem) {
+            Some(TypeDeclaration::Opaque(opaque)) => {
+                opaque.host_lang.is_rust() && opaque.attributes.copy.is_none()
+            }
+            _ => false,
+        }
+    }
+
     pub(crate) fn rust_fn_sig_return_tokens(
         &self,
         swift_bridge_path: &Path,
@@ -163,6 +289,10 @@ impl ParsedExternFn {
     ) -> TokenStream {
         let sig = &self.func.sig;
 
+        if self.compress.is_some() {
+            return quote! { -> *mut Vec<u8> };
+        }
+
         if let Some(ret) = BridgedType::new_with_return_type(&sig.output, types) {
             if ret.can_be_encoded_with_zero_bytes() {
                 return quote! {};
@@ -198,6 +328,61 @@ impl ParsedExternFn {
         }
     }
 
+    /// Whether this function returns a `String`, as opposed to a `Vec<u8>`, that gets compressed
+    /// before it is passed to Swift.
+    pub(crate) fn compressed_return_is_string(&self) -> bool {
+        match &self.func.sig.output {
+            ReturnType::Type(_, ty) => ty.to_token_stream().to_string() == "String",
+            ReturnType::Default => false,
+        }
+    }
+
+    /// What a `#[swift_bridge(scoped = "...")]` function passes to its Swift closure.
+    ///
+    /// Only non-async extern "Rust" functions that take no arguments other than `self` can be
+    /// scoped. They must return an owned, non-generic, non-Copy opaque Rust type, or a method
+    /// must return a reference to one.
+    pub(crate) fn scoped_value<'a>(&self, types: &'a TypeDeclarations) -> Option<ScopedValue<'a>> {
+        let max_inputs = if self.is_method() { 1 } else { 0 };
+        if !self.host_lang.is_rust()
+            || self.sig.asyncness.is_some()
+            || self.sig.inputs.len() > max_inputs
+        {
+            return None;
+        }
+
+        let ty = match &self.sig.output {
+            ReturnType::Type(_, ty) => ty,
+            ReturnType::Default => return None,
+        };
+        let (ty, reference) = match ty.deref() {
+            Type::Path(_) => (ty.deref(), None),
+            Type::Reference(reference) if self.is_method() => {
+                (reference.elem.deref(), Some(reference.mutability.is_some()))
+            }
+            _ => return None,
+        };
+        if !matches!(ty, Type::Path(_)) {
+            return None;
+        }
+
+        let opaque = types.get(&ty.to_token_stream().to_string())?.as_opaque()?;
+        if !opaque.host_lang.is_rust()
+            || !opaque.generics.is_empty()
+            || opaque.attributes.copy.is_some()
+        {
+            return None;
+        }
+
+        Some(match reference {
+            None => ScopedValue::Guard(opaque),
+            Some(mutable) => ScopedValue::Borrow {
+                ty: opaque,
+                mutable,
+            },
+        })
+    }
+
     pub fn extern_swift_linked_fn_new(&self) -> Ident {
         let sig = &self.func.sig;
 
@@ -256,7 +441,7 @@ impl ParsedExternFn {
     ) -> TokenStream {
         let mut args = vec![];
         let inputs = &self.func.sig.inputs;
-        for fn_arg in inputs.into_iter() {
+        for (idx, fn_arg) in inputs.into_iter().enumerate() {
             match fn_arg {
                 FnArg::Receiver(_receiver) => {
                     if self.host_lang.is_swift() {
@@ -280,6 +465,16 @@ impl ParsedExternFn {
                         if self.host_lang.is_rust() {
                             arg = if let Some(repr) = built_in.only_encoding() {
                                 repr.rust
+                            } else if let BridgedType::StdLib(StdLibType::BoxedFn(boxed_fn)) =
+                                &built_in
+                            {
+                                self.swift_closure_to_boxed_fn(
+                                    idx,
+                                    &arg,
+                                    boxed_fn,
+                                    swift_bridge_path,
+                                    types,
+                                )
                             } else {
                                 built_in.convert_ffi_expression_to_rust_type(
                                     &arg,
@@ -459,7 +654,7 @@ impl ParsedExternFn {
                         todo!()
                     }
                     TypeDeclaration::Opaque(h) => {
-                        format!("${}", h.to_string())
+                        format!("${}", h.dollar_separated_name())
                     }
                 }
             })
@@ -480,13 +675,39 @@ impl ParsedExternFn {
         format!("{}$_free$param{}", self.link_name(), boxed_fn_idx)
     }
 
+    /// The Rust names of the functions that call and free the boxed function at the given
+    /// argument index.
+    ///
+    /// (some_function_param0, free_some_function_param0)
+    pub fn boxed_fn_support_fn_idents(&self, boxed_fn_idx: usize) -> (Ident, Ident) {
+        let fn_name = &self.func.sig.ident;
+
+        let maybe_associated_ty = self
+            .associated_type
+            .as_ref()
+            .and_then(|t| t.as_opaque())
+            .map(|o| format!("{}_", o.underscore_separated_name()))
+            .unwrap_or("".to_string());
+
+        let boxed_fn_name = format!("{}{}_param{boxed_fn_idx}", maybe_associated_ty, fn_name);
+        let free_boxed_fn_name = format!(
+            "free_{}{}_param{boxed_fn_idx}",
+            maybe_associated_ty, fn_name
+        );
+
+        (
+            Ident::new(&boxed_fn_name, fn_name.span()),
+            Ident::new(&free_boxed_fn_name, fn_name.span()),
+        )
+    }
+
     /// Generates something like:
     /// void __swift_bridge__$some_function$param0(void* boxed_fn, uint8_t arg);
     /// void __swift_bridge__$some_function$_free$param0(void* boxed_fn);
     pub fn boxed_fn_to_c_header_fns(
         &self,
         idx: usize,
-        boxed_fn: &BridgeableBoxedFnOnce,
+        boxed_fn: &BridgeableBoxedFn,
         types: &TypeDeclarations,
     ) -> String {
         let call_boxed_fn_link_name = self.call_boxed_fn_link_name(idx);
@@ -522,7 +743,7 @@ void {free_boxed_fn_link_name}(void* {boxed_fn_arg_name});"#
                         todo!()
                     }
                     TypeDeclaration::Opaque(h) => {
-                        format!("{}_", h.to_token_stream().to_string())
+                        format!("{}_", h.underscore_separated_name())
                     }
                 }
             })
@@ -546,7 +767,7 @@ void {free_boxed_fn_link_name}(void* {boxed_fn_arg_name});"#
     pub fn args_filtered_to_boxed_fns(
         &self,
         type_decls: &TypeDeclarations,
-    ) -> Vec<(usize, BridgeableBoxedFnOnce)> {
+    ) -> Vec<(usize, BridgeableBoxedFn)> {
         self.func
             .sig
             .inputs
@@ -556,7 +777,7 @@ void {free_boxed_fn_link_name}(void* {boxed_fn_arg_name});"#
                 let ty = BridgedType::new_with_fn_arg(arg, type_decls)?;
 
                 match ty {
-                    BridgedType::StdLib(StdLibType::BoxedFnOnce(boxed_fn)) => Some((idx, boxed_fn)),
+                    BridgedType::StdLib(StdLibType::BoxedFn(boxed_fn)) => Some((idx, boxed_fn)),
                     _ => None,
                 }
             })
@@ -1,8 +1,11 @@
+use crate::bridged_type::boxed_fn::BridgeableBoxedFn;
 use crate::bridged_type::BridgedType;
 use crate::parse::{HostLang, OpaqueCopy, TypeDeclaration, TypeDeclarations};
-use crate::parsed_extern_fn::{GetField, GetFieldDirect, GetFieldWith, ParsedExternFn};
+use crate::parsed_extern_fn::{
+    Compression, GetField, GetFieldDirect, GetFieldWith, ParsedExternFn,
+};
 use proc_macro2::{Ident, Span, TokenStream};
-use quote::quote;
+use quote::{format_ident, quote};
 use std::collections::HashMap;
 use syn::spanned::Spanned;
 use syn::Path;
@@ -118,6 +121,129 @@ impl ParsedExternFn {
         }
     }
 
+    /// Declares the Swift functions that call and release the Swift closures that are passed to
+    /// this function.
+    ///
+    /// extern "C" {
+    ///     #\[link_name = "__swift_bridge__$some_function$param0"]
+    ///     fn some_function_param0(callback: *mut std::ffi::c_void, arg0: u8) -> u16;
+    ///     #\[link_name = "__swift_bridge__$some_function$_free$param0"]
+    ///     fn free_some_function_param0(callback: *mut std::ffi::c_void);
+    /// }
+    pub fn swift_closures_support(
+        &self,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let mut linked_fns = vec![];
+
+        for (idx, boxed_fn) in self.args_filtered_to_boxed_fns(types) {
+            let (call_fn_name, free_fn_name) = self.boxed_fn_support_fn_idents(idx);
+            let call_link_name = self.call_boxed_fn_link_name(idx);
+            let free_link_name = self.free_boxed_fn_link_name(idx);
+
+            let params = boxed_fn.params_to_ffi_compatible_rust_types(swift_bridge_path, types);
+            let maybe_ret = if boxed_fn.ret.is_null() {
+                quote! {}
+            } else {
+                let ret = boxed_fn
+                    .ret
+                    .to_ffi_compatible_rust_type(swift_bridge_path, types);
+                quote! { -> #ret }
+            };
+
+            linked_fns.push(quote! {
+                #[link_name = #call_link_name]
+                fn #call_fn_name(callback: *mut std::ffi::c_void #(, #params)*) #maybe_ret;
+                #[link_name = #free_link_name]
+                fn #free_fn_name(callback: *mut std::ffi::c_void);
+            });
+        }
+
+        if linked_fns.is_empty() {
+            return quote! {};
+        }
+
+        quote! {
+            extern "C" {
+                #(#linked_fns)*
+            }
+        }
+    }
+
+    /// Wraps a Swift closure in a `Box<dyn Fn>` or a `Box<dyn FnOnce>`.
+    ///
+    /// The Swift closure is released when the box is dropped. A `Box<dyn FnOnce>` is dropped right
+    /// after it is called, so a Swift closure that is passed as a `Box<dyn FnOnce>` is always
+    /// released exactly once, whether or not Rust ever calls it.
+    ///
+    /// {
+    ///     let callback = unsafe {
+    ///         swift_bridge::boxed_fn_support::SwiftCallback::new(callback, free_some_function_param0)
+    ///     };
+    ///     Box::new(move |arg0: u8| {
+    ///         let ret = unsafe { some_function_param0(callback.as_ptr(), arg0) };
+    ///         ret
+    ///     })
+    /// }
+    pub(super) fn swift_closure_to_boxed_fn(
+        &self,
+        idx: usize,
+        callback: &TokenStream,
+        boxed_fn: &BridgeableBoxedFn,
+        swift_bridge_path: &Path,
+        types: &TypeDeclarations,
+    ) -> TokenStream {
+        let (call_fn_name, free_fn_name) = self.boxed_fn_support_fn_idents(idx);
+
+        let params: Vec<TokenStream> = boxed_fn
+            .params
+            .iter()
+            .enumerate()
+            .map(|(idx, ty)| {
+                let param_name = format_ident!("arg{}", idx);
+                let param_ty = ty.to_rust_type_path(types);
+                quote! { #param_name: #param_ty }
+            })
+            .collect();
+        let call_args: Vec<TokenStream> = boxed_fn
+            .params
+            .iter()
+            .enumerate()
+            .map(|(idx, ty)| {
+                let arg_name = format_ident!("arg{}", idx);
+                ty.convert_rust_expression_to_ffi_type(
+                    &quote! { #arg_name },
+                    swift_bridge_path,
+                    types,
+                    Span::call_site(),
+                )
+            })
+            .collect();
+
+        let ret = boxed_fn.ret.convert_ffi_expression_to_rust_type(
+            &quote! { ret },
+            Span::call_site(),
+            swift_bridge_path,
+            types,
+        );
+        let call_closure = quote! {
+            {
+                let ret = unsafe { #call_fn_name(callback.as_ptr() #(, #call_args)*) };
+                #ret
+            }
+        };
+
+        quote! {
+            {
+                let callback = unsafe {
+                    #swift_bridge_path::boxed_fn_support::SwiftCallback::new(#callback, #free_fn_name)
+                };
+                Box::new(move |#(#params),*| #call_closure)
+            }
+        }
+    }
+
     fn call_fn_tokens(&self, swift_bridge_path: &Path, types: &TypeDeclarations) -> TokenStream {
         let sig = &self.func.sig;
         let fn_name = if let Some(fn_name) = self.rust_name_override.as_ref() {
@@ -141,7 +267,7 @@ impl ParsedExternFn {
         let mut call_fn = if self.is_method() {
             self.call_method_tokens(&call_fn)
         } else {
-            self.call_function_tokens(&call_fn)
+            self.call_function_tokens(&call_fn, types)
         };
 
         let return_ty = self.return_ty_built_in(types).unwrap();
@@ -156,8 +282,16 @@ impl ParsedExternFn {
             }
         }
 
-        // Async functions get this conversion done after awaiting the returned future.
-        if self.sig.asyncness.is_none() {
+        if let Some(compression) = self.compress {
+            call_fn = match compression {
+                Compression::Lz4 => quote! {
+                    #swift_bridge_path::compression_support::lz4_compress(
+                        AsRef::<[u8]>::as_ref(&#call_fn)
+                    )
+                },
+            };
+        } else if self.sig.asyncness.is_none() {
+            // Async functions get this conversion done after awaiting the returned future.
             let fn_span = self.func.span();
             call_fn = return_ty.convert_rust_expression_to_ffi_type(
                 &call_fn,
@@ -222,7 +356,7 @@ impl ParsedExternFn {
     }
 
     /// Generate tokens for calling a freestanding or an associated function.
-    fn call_function_tokens(&self, call_fn: &TokenStream) -> TokenStream {
+    fn call_function_tokens(&self, call_fn: &TokenStream, types: &TypeDeclarations) -> TokenStream {
         let maybe_associated_type = self.associated_type.as_ref().map(|ty| {
             match ty {
                 TypeDeclaration::Shared(_) => {
@@ -230,8 +364,14 @@ impl ParsedExternFn {
                     todo!()
                 }
                 TypeDeclaration::Opaque(ty) => {
+                    let generics = ty.generics.angle_bracketed_concrete_generics_tokens(types);
                     let ty = &ty.ty;
-                    quote! {#ty::}
+                    if generics.is_empty() {
+                        quote! {#ty::}
+                    } else {
+                        // `super::SomeType::<u32>::some_function()`
+                        quote! {#ty:: #generics ::}
+                    }
                 }
             }
         });
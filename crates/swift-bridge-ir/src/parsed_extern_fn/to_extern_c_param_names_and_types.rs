@@ -1,8 +1,8 @@
-use crate::bridged_type::{pat_type_pat_is_self, BridgeableType, BridgedType};
+use crate::bridged_type::{pat_type_pat_is_self, BridgeableType, BridgedType, StdLibType};
 use crate::parse::{HostLang, TypeDeclaration, TypeDeclarations};
 use crate::parsed_extern_fn::ParsedExternFn;
 use proc_macro2::{Ident, TokenStream};
-use quote::{quote, ToTokens};
+use quote::quote;
 use std::collections::HashMap;
 use std::ops::Deref;
 use syn::spanned::Spanned;
@@ -23,7 +23,7 @@ impl ParsedExternFn {
                     HostLang::Rust => {
                         let this = match self.associated_type.as_ref().unwrap() {
                             TypeDeclaration::Opaque(opaque) => {
-                                let opaque_ty_ffi_repr = opaque.ffi_repr_type_tokens();
+                                let opaque_ty_ffi_repr = opaque.ffi_repr_type_tokens(types);
                                 quote! { this: #opaque_ty_ffi_repr }
                             }
                             TypeDeclaration::Shared(_) => {
@@ -55,7 +55,16 @@ impl ParsedExternFn {
                             }
 
                             let pat = &pat_ty.pat;
-                            let ty = built_in.to_ffi_compatible_rust_type(swift_bridge_path, types);
+                            let ty = match &built_in {
+                                // Swift closures are passed to Rust as a pointer to a retained
+                                // Swift object.
+                                BridgedType::StdLib(StdLibType::BoxedFn(_))
+                                    if self.host_lang.is_rust() =>
+                                {
+                                    quote! { *mut std::ffi::c_void }
+                                }
+                                _ => built_in.to_ffi_compatible_rust_type(swift_bridge_path, types),
+                            };
 
                             params.push(quote! { #pat: #ty});
 
@@ -85,14 +94,11 @@ impl ParsedExternFn {
                         bridged_type = &ty_ref.elem;
                     };
 
-                    let arg_ty = match types
-                        .get(&bridged_type.to_token_stream().to_string())
-                        .unwrap()
-                    {
+                    let arg_ty = match types.get_with_type(bridged_type).unwrap() {
                         TypeDeclaration::Shared(_) => {
                             todo!("Support methods on shared types.")
                         }
-                        TypeDeclaration::Opaque(opaque) => opaque.ffi_repr_type_tokens(),
+                        TypeDeclaration::Opaque(opaque) => opaque.ffi_repr_type_tokens(types),
                     };
 
                     params.push(quote! {
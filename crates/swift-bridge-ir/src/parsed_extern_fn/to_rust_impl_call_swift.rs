@@ -103,21 +103,10 @@ impl ParsedExternFn {
                 continue;
             }
 
-            let maybe_associated_ty = self
-                .associated_type
-                .as_ref()
-                .and_then(|t| t.as_opaque())
-                .map(|o| format!("{}_", o.ty.to_string()))
-                .unwrap_or("".to_string());
-
-            let boxed_fn_name = format!("{}{}_param{idx}", maybe_associated_ty, fn_name);
-            let boxed_fn_name = Ident::new(&boxed_fn_name, fn_name.span());
+            let (boxed_fn_name, free_boxed_fn_name) = self.boxed_fn_support_fn_idents(idx);
 
             let boxed_fn_ffi_repr = boxed_fn.to_ffi_compatible_rust_type(types);
 
-            let free_boxed_fn_name = format!("free_{}{}_param{idx}", maybe_associated_ty, fn_name);
-            let free_boxed_fn_name = Ident::new(&free_boxed_fn_name, fn_name.span());
-
             let params = boxed_fn.params_to_ffi_compatible_rust_types(swift_bridge_path, types);
             let call_args = boxed_fn.to_rust_call_args(swift_bridge_path, types);
 
@@ -1,5 +1,5 @@
 use crate::bridged_type::{pat_type_pat_is_self, BridgeableType, BridgedType, TypePosition};
-use crate::parse::TypeDeclarations;
+use crate::parse::{TypeDeclaration, TypeDeclarations};
 use crate::parsed_extern_fn::ParsedExternFn;
 use quote::{format_ident, ToTokens};
 use std::ops::Deref;
@@ -140,6 +140,14 @@ impl ParsedExternFn {
     }
 
     pub fn to_swift_return_type(&self, types: &TypeDeclarations) -> String {
+        if self.compress.is_some() {
+            return if self.compressed_return_is_string() {
+                " -> String".to_string()
+            } else {
+                " -> Data".to_string()
+            };
+        }
+
         match &self.func.sig.output {
             ReturnType::Default => "".to_string(),
             ReturnType::Type(_, ty) => {
@@ -164,6 +172,87 @@ impl ParsedExternFn {
         }
     }
 
+    /// The lines of the Swift doc comment that describes who owns the values that this function
+    /// takes and returns, such as an opaque Rust type that Swift gives up ownership of when it
+    /// passes it to Rust.
+    ///
+    /// Empty if none of the function's arguments or return value need explaining.
+    pub(crate) fn swift_ownership_doc_lines(&self, types: &TypeDeclarations) -> Vec<String> {
+        if !self.host_lang.is_rust() {
+            return vec![];
+        }
+
+        let is_opaque_rust_type = |ty: &Type| match ty {
+            Type::Path(ty_path) => match types.get_with_type_path(ty_path) {
+                Some(TypeDeclaration::Opaque(opaque)) => {
+                    opaque.host_lang.is_rust() && opaque.attributes.copy.is_none()
+                }
+                _ => false,
+            },
+            _ => false,
+        };
+
+        let mut lines = vec![];
+
+        if self.is_method()
+            && self.self_reference().is_none()
+            && !self.is_copy_method_on_opaque_type()
+        {
+            lines.push(
+                "Consumes `self`. Ownership moves to Rust, so this instance must not be used after this call."
+                    .to_string(),
+            );
+        }
+
+        for arg in self.func.sig.inputs.iter() {
+            let pat_ty = match arg {
+                FnArg::Typed(pat_ty) if !pat_type_pat_is_self(pat_ty) => pat_ty,
+                _ => continue,
+            };
+            let arg_name = pat_ty.pat.to_token_stream().to_string();
+
+            match pat_ty.ty.deref() {
+                Type::Reference(ty_ref) if is_opaque_rust_type(&ty_ref.elem) => {
+                    let maybe_mutably = if ty_ref.mutability.is_some() {
+                        "Mutably borrowed"
+                    } else {
+                        "Borrowed"
+                    };
+                    lines.push(format!(
+                        "- Parameter {}: {} for the duration of the call.",
+                        arg_name, maybe_mutably
+                    ));
+                }
+                ty if is_opaque_rust_type(ty) => {
+                    lines.push(format!(
+                        "- Parameter {arg}: Consumed. Ownership moves to Rust, so `{arg}` must not be used after this call.",
+                        arg = arg_name
+                    ));
+                }
+                _ => {}
+            }
+        }
+
+        if let ReturnType::Type(_, ret) = &self.func.sig.output {
+            if let Type::Reference(ty_ref) = ret.deref() {
+                let is_static = ty_ref
+                    .lifetime
+                    .as_ref()
+                    .map(|lifetime| lifetime.ident == "static")
+                    .unwrap_or(false);
+
+                if !is_static && !self.returns_reference_borrowed_from_self(types) {
+                    lines.push(
+                        "- Returns: A reference into Rust memory that is only valid while the value that it borrows from is alive. It must not be retained."
+                            .to_string(),
+                    );
+                }
+            }
+        }
+
+        lines
+    }
+
     fn push_receiver_as_arg(&self, args: &mut Vec<String>, is_reference: bool) {
         let arg = if self.is_copy_method_on_opaque_type() {
             "self.bytes"
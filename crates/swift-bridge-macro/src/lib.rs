@@ -1,5 +1,7 @@
 use quote::quote;
-use swift_bridge_ir::{SwiftBridgeModule, SwiftBridgeModuleAttr, SwiftBridgeModuleAttrs};
+use swift_bridge_ir::{
+    BridgedItem, ExportedImpl, SwiftBridgeModule, SwiftBridgeModuleAttr, SwiftBridgeModuleAttrs,
+};
 use syn::parse_macro_input;
 
 #[proc_macro_attribute]
@@ -25,6 +27,36 @@ pub fn bridge(
     tokens.into()
 }
 
+/// Register a struct or enum that is defined outside of a bridge module as a shared type.
+#[proc_macro_attribute]
+pub fn bridged(
+    _args: proc_macro::TokenStream,
+    input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let item = parse_macro_input!(input as BridgedItem);
+
+    let tokens = quote! {
+        #item
+    };
+
+    tokens.into()
+}
+
+/// Expose the public methods of an impl block to Swift without declaring them in a bridge module.
+#[proc_macro_attribute]
+pub fn export(
+    _args: proc_macro::TokenStream,
+    input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let exported = parse_macro_input!(input as ExportedImpl);
+
+    let tokens = quote! {
+        #exported
+    };
+
+    tokens.into()
+}
+
 #[cfg(test)]
 mod ui_tests {
     #[test]
@@ -12,6 +12,16 @@ fn main() {
     }
 
     swift_bridge_build::parse_bridges(bridges)
+        .with_byte_stream_support()
+        .with_compression_support()
+        .with_cursor_support()
+        .with_image_buffer_support()
+        .with_measurement_support()
+        .with_pixel_buffer_support()
+        .with_serde_json_support()
+        .with_tensor_support()
+        .with_uuid_support()
+        .with_watch_support()
         .write_all_concatenated(out_dir, env!("CARGO_PKG_NAME"));
 }
 
@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type ArcTestCounter;
+
+        fn rust_make_arc_test_counter(value: u32) -> Arc<ArcTestCounter>;
+        fn rust_arc_test_counter_strong_count(counter: Arc<ArcTestCounter>) -> usize;
+        fn rust_maybe_arc_test_counter(some: bool) -> Option<Arc<ArcTestCounter>>;
+
+        fn value(&self) -> u32;
+    }
+}
+
+pub struct ArcTestCounter {
+    value: u32,
+}
+
+impl ArcTestCounter {
+    fn value(&self) -> u32 {
+        self.value
+    }
+}
+
+fn rust_make_arc_test_counter(value: u32) -> Arc<ArcTestCounter> {
+    Arc::new(ArcTestCounter { value })
+}
+
+/// The count includes the reference that was passed in.
+fn rust_arc_test_counter_strong_count(counter: Arc<ArcTestCounter>) -> usize {
+    Arc::strong_count(&counter)
+}
+
+fn rust_maybe_arc_test_counter(some: bool) -> Option<Arc<ArcTestCounter>> {
+    if some {
+        Some(rust_make_arc_test_counter(1))
+    } else {
+        None
+    }
+}
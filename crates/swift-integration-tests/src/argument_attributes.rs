@@ -1 +1,2 @@
 mod argument_label;
+mod out;
@@ -0,0 +1,40 @@
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn test_out_arguments(
+            values: &[i32],
+            #[swift_bridge(out)] min: &mut i32,
+            #[swift_bridge(out)] max: &mut i32,
+        ) -> bool;
+
+        fn test_rust_calls_swift_out_arguments();
+    }
+
+    extern "Swift" {
+        fn swift_split_position(
+            position: u64,
+            #[swift_bridge(out)] x: &mut u32,
+            #[swift_bridge(out)] y: &mut u32,
+        );
+    }
+}
+
+fn test_out_arguments(values: &[i32], min: &mut i32, max: &mut i32) -> bool {
+    match (values.iter().min(), values.iter().max()) {
+        (Some(smallest), Some(largest)) => {
+            *min = *smallest;
+            *max = *largest;
+            true
+        }
+        _ => false,
+    }
+}
+
+fn test_rust_calls_swift_out_arguments() {
+    let mut x = 0;
+    let mut y = 0;
+    ffi::swift_split_position(5 << 32 | 7, &mut x, &mut y);
+
+    assert_eq!(x, 5);
+    assert_eq!(y, 7);
+}
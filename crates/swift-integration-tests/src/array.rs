@@ -0,0 +1,34 @@
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct")]
+    struct ArrayTestColor {
+        rgba: [f32; 4],
+    }
+
+    extern "Rust" {
+        fn rust_reflect_array_u8(arg: [u8; 16]) -> [u8; 16];
+        fn rust_reverse_array_i32(arg: [i32; 3]) -> [i32; 3];
+
+        fn rust_make_array_test_color(r: f32, g: f32, b: f32, a: f32) -> ArrayTestColor;
+        fn rust_reflect_array_test_color(arg: ArrayTestColor) -> ArrayTestColor;
+    }
+}
+
+use ffi::ArrayTestColor;
+
+fn rust_reflect_array_u8(arg: [u8; 16]) -> [u8; 16] {
+    arg
+}
+
+fn rust_reverse_array_i32(mut arg: [i32; 3]) -> [i32; 3] {
+    arg.reverse();
+    arg
+}
+
+fn rust_make_array_test_color(r: f32, g: f32, b: f32, a: f32) -> ArrayTestColor {
+    ArrayTestColor { rgba: [r, g, b, a] }
+}
+
+fn rust_reflect_array_test_color(arg: ArrayTestColor) -> ArrayTestColor {
+    arg
+}
@@ -26,6 +26,14 @@ mod ffi {
         async fn reflect_u16(&self, arg: u16) -> u16;
     }
 
+    extern "Rust" {
+        type AsyncInitTestType;
+
+        #[swift_bridge(init)]
+        async fn new(value: u8) -> AsyncInitTestType;
+        fn value(&self) -> u8;
+    }
+
     extern "Rust" {
         type AsyncResultOpaqueRustType1;
 
@@ -95,6 +103,18 @@ impl TestRustAsyncSelf {
     }
 }
 
+pub struct AsyncInitTestType(u8);
+
+impl AsyncInitTestType {
+    async fn new(value: u8) -> Self {
+        AsyncInitTestType(value)
+    }
+
+    fn value(&self) -> u8 {
+        self.0
+    }
+}
+
 pub struct AsyncResultOpaqueRustType1(u32);
 
 impl AsyncResultOpaqueRustType1 {
@@ -64,6 +64,26 @@ mod ffi {
     extern "Rust" {
         fn test_callbacks_rust_calls_swift();
     }
+
+    extern "Rust" {
+        type CallbackTestStoredFn;
+
+        #[swift_bridge(init)]
+        fn new(formatter: Box<dyn Fn(u32) -> String>) -> CallbackTestStoredFn;
+        fn format(&self, val: u32) -> String;
+        fn set_formatter(&mut self, formatter: Box<dyn Fn(u32) -> String>);
+    }
+
+    extern "Rust" {
+        type CallbackTestFetcher;
+
+        #[swift_bridge(init)]
+        fn new() -> CallbackTestFetcher;
+        fn fetch(&self, key: String, done: Box<dyn FnOnce(Result<String, String>)>);
+        fn fetch_and_drop_callback(&self, done: Box<dyn FnOnce(Result<String, String>)>);
+        fn fetch_later(&mut self, done: Box<dyn FnOnce(Result<String, String>)>);
+        fn complete_pending_fetch(&mut self, value: String);
+    }
 }
 
 // TODO
@@ -137,3 +157,53 @@ fn test_callbacks_rust_calls_swift() {
         assert_eq!(result.unwrap().val(), 555)
     }));
 }
+
+/// Holds on to a Swift closure and calls it whenever `format` is called.
+pub struct CallbackTestStoredFn {
+    formatter: Box<dyn Fn(u32) -> String>,
+}
+impl CallbackTestStoredFn {
+    fn new(formatter: Box<dyn Fn(u32) -> String>) -> Self {
+        Self { formatter }
+    }
+
+    fn format(&self, val: u32) -> String {
+        (self.formatter)(val)
+    }
+
+    fn set_formatter(&mut self, formatter: Box<dyn Fn(u32) -> String>) {
+        self.formatter = formatter;
+    }
+}
+
+/// Completes fetches with either a value or an error.
+pub struct CallbackTestFetcher {
+    pending: Option<Box<dyn FnOnce(Result<String, String>)>>,
+}
+impl CallbackTestFetcher {
+    fn new() -> Self {
+        Self { pending: None }
+    }
+
+    fn fetch(&self, key: String, done: Box<dyn FnOnce(Result<String, String>)>) {
+        if key.is_empty() {
+            done(Err("empty key".to_string()))
+        } else {
+            done(Ok(format!("value for {}", key)))
+        }
+    }
+
+    fn fetch_and_drop_callback(&self, done: Box<dyn FnOnce(Result<String, String>)>) {
+        drop(done)
+    }
+
+    fn fetch_later(&mut self, done: Box<dyn FnOnce(Result<String, String>)>) {
+        self.pending = Some(done);
+    }
+
+    fn complete_pending_fetch(&mut self, value: String) {
+        if let Some(done) = self.pending.take() {
+            done(Ok(value))
+        }
+    }
+}
@@ -0,0 +1,48 @@
+//! Verify that types that are defined outside of a bridge module can be registered as shared
+//! types using `#[swift_bridge::bridged]`.
+
+/// A domain type that is used throughout the crate and is also exposed to Swift.
+#[swift_bridge::bridged]
+#[swift_bridge(swift_repr = "struct")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct BridgedTypeTestUser {
+    pub name: String,
+    pub age: u8,
+}
+
+#[swift_bridge::bridged]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum BridgedTypeTestRole {
+    Admin,
+    Member,
+}
+
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(already_declared, swift_repr = "struct")]
+    struct BridgedTypeTestUser;
+
+    #[swift_bridge(already_declared)]
+    enum BridgedTypeTestRole {}
+
+    extern "Rust" {
+        fn rust_reflect_bridged_struct(arg: BridgedTypeTestUser) -> BridgedTypeTestUser;
+        fn rust_reflect_bridged_enum(arg: BridgedTypeTestRole) -> BridgedTypeTestRole;
+        fn rust_make_bridged_struct() -> BridgedTypeTestUser;
+    }
+}
+
+fn rust_reflect_bridged_struct(arg: BridgedTypeTestUser) -> BridgedTypeTestUser {
+    arg
+}
+
+fn rust_reflect_bridged_enum(arg: BridgedTypeTestRole) -> BridgedTypeTestRole {
+    arg
+}
+
+fn rust_make_bridged_struct() -> BridgedTypeTestUser {
+    BridgedTypeTestUser {
+        name: "Rust".to_string(),
+        age: 8,
+    }
+}
@@ -0,0 +1,29 @@
+use std::collections::BTreeMap;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_make_btree_map_string_u32() -> BTreeMap<String, u32>;
+        fn rust_make_btree_map_u8_string() -> BTreeMap<u8, String>;
+
+        fn rust_reflect_btree_map_string_u32(arg: BTreeMap<String, u32>) -> BTreeMap<String, u32>;
+    }
+}
+
+fn rust_make_btree_map_string_u32() -> BTreeMap<String, u32> {
+    [("volume", 7), ("brightness", 3), ("contrast", 5)]
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), value))
+        .collect()
+}
+
+fn rust_make_btree_map_u8_string() -> BTreeMap<u8, String> {
+    [(30, "thirty"), (10, "ten"), (20, "twenty")]
+        .into_iter()
+        .map(|(key, value)| (key, value.to_string()))
+        .collect()
+}
+
+fn rust_reflect_btree_map_string_u32(arg: BTreeMap<String, u32>) -> BTreeMap<String, u32> {
+    arg
+}
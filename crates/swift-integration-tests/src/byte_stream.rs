@@ -0,0 +1,35 @@
+use swift_bridge::byte_stream::ByteStream;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_make_byte_stream(chunk_count: u8, chunk_len: usize) -> ByteStream;
+        fn rust_make_byte_stream_from_writer(chunk_count: u8) -> ByteStream;
+        fn rust_byte_stream_len(arg: ByteStream) -> usize;
+    }
+}
+
+/// A stream of `chunk_count` chunks, where every byte of a chunk is set to the chunk's index.
+fn rust_make_byte_stream(chunk_count: u8, chunk_len: usize) -> ByteStream {
+    ByteStream::new((0..chunk_count).map(move |idx| vec![idx; chunk_len]))
+}
+
+/// A stream whose chunks `[0]`, `[1]`, ... are written from a background thread.
+fn rust_make_byte_stream_from_writer(chunk_count: u8) -> ByteStream {
+    let (writer, stream) = ByteStream::channel(1);
+
+    std::thread::spawn(move || {
+        for idx in 0..chunk_count {
+            if writer.write(vec![idx]).is_err() {
+                return;
+            }
+        }
+    });
+
+    stream
+}
+
+/// The total number of bytes in the stream's remaining chunks.
+fn rust_byte_stream_len(arg: ByteStream) -> usize {
+    arg.map(|chunk| chunk.len()).sum()
+}
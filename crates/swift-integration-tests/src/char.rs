@@ -0,0 +1,41 @@
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct")]
+    struct CharTestStruct {
+        letter: char,
+    }
+
+    extern "Rust" {
+        fn rust_reflect_char(arg: char) -> char;
+        fn rust_next_char(arg: char) -> Option<char>;
+        fn rust_reflect_char_test_struct(arg: CharTestStruct) -> CharTestStruct;
+    }
+
+    extern "Swift" {
+        fn swift_reflect_char(arg: char) -> char;
+    }
+
+    extern "Rust" {
+        fn test_rust_calls_swift_reflect_char();
+    }
+}
+
+use ffi::CharTestStruct;
+
+fn rust_reflect_char(arg: char) -> char {
+    arg
+}
+
+fn rust_next_char(arg: char) -> Option<char> {
+    char::from_u32(arg as u32 + 1)
+}
+
+fn rust_reflect_char_test_struct(arg: CharTestStruct) -> CharTestStruct {
+    arg
+}
+
+fn test_rust_calls_swift_reflect_char() {
+    for c in ['a', 'é', '🦀'] {
+        assert_eq!(ffi::swift_reflect_char(c), c);
+    }
+}
@@ -0,0 +1,21 @@
+#[swift_bridge::bridge]
+mod ffi {
+    const CONSTANT_TEST_BUFFER_SIZE: usize = 4096;
+    const CONSTANT_TEST_OFFSET: i64 = -10;
+    const CONSTANT_TEST_RATIO: f64 = 0.25;
+    const CONSTANT_TEST_ENABLED: bool = true;
+    const CONSTANT_TEST_PROTOCOL: &'static str = "swift-bridge/1";
+
+    extern "Rust" {
+        fn rust_constant_test_buffer_size() -> usize;
+        fn rust_constant_test_protocol() -> &'static str;
+    }
+}
+
+fn rust_constant_test_buffer_size() -> usize {
+    ffi::CONSTANT_TEST_BUFFER_SIZE
+}
+
+fn rust_constant_test_protocol() -> &'static str {
+    ffi::CONSTANT_TEST_PROTOCOL
+}
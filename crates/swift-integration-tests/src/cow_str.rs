@@ -0,0 +1,49 @@
+use std::borrow::Cow;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type CowStrGreeter;
+
+        #[swift_bridge(init)]
+        fn new(name: String) -> CowStrGreeter;
+        fn name(&self) -> Cow<str>;
+
+        fn rust_cow_str_greeting(formal: bool, name: &str) -> Cow<'static, str>;
+        fn rust_option_cow_str(borrowed: Option<bool>) -> Option<Cow<'static, str>>;
+    }
+}
+
+pub struct CowStrGreeter {
+    name: String,
+}
+
+impl CowStrGreeter {
+    fn new(name: String) -> Self {
+        CowStrGreeter { name }
+    }
+
+    fn name(&self) -> Cow<str> {
+        Cow::Borrowed(&self.name)
+    }
+}
+
+/// Returns a borrowed static string for an informal greeting and an owned string for a formal
+/// greeting.
+fn rust_cow_str_greeting(formal: bool, name: &str) -> Cow<'static, str> {
+    if formal {
+        Cow::Owned(format!("Good day, {}", name))
+    } else {
+        Cow::Borrowed("hi")
+    }
+}
+
+fn rust_option_cow_str(borrowed: Option<bool>) -> Option<Cow<'static, str>> {
+    borrowed.map(|borrowed| {
+        if borrowed {
+            Cow::Borrowed("borrowed")
+        } else {
+            Cow::Owned("owned".to_string())
+        }
+    })
+}
@@ -0,0 +1,41 @@
+use swift_bridge::cursor::Cursor;
+
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct")]
+    struct CursorTestRow {
+        id: u32,
+        name: String,
+    }
+
+    extern "Rust" {
+        fn rust_make_cursor(row_count: u32, batch_size: usize) -> Cursor<CursorTestRow>;
+        fn rust_make_cursor_from_pages(page_count: u32) -> Cursor<CursorTestRow>;
+    }
+}
+
+/// A cursor of `row_count` rows whose `id` is their index and whose `name` is "row {id}".
+fn rust_make_cursor(row_count: u32, batch_size: usize) -> Cursor<ffi::CursorTestRow> {
+    Cursor::with_batch_size((0..row_count).map(row), batch_size)
+}
+
+/// A cursor that fetches pages of 3 rows, like a paginated database query would.
+fn rust_make_cursor_from_pages(page_count: u32) -> Cursor<ffi::CursorTestRow> {
+    let mut page = 0;
+
+    Cursor::from_batches(std::iter::from_fn(move || {
+        if page == page_count {
+            return None;
+        }
+        let rows = (page * 3..page * 3 + 3).map(row).collect();
+        page += 1;
+        Some(rows)
+    }))
+}
+
+fn row(id: u32) -> ffi::CursorTestRow {
+    ffi::CursorTestRow {
+        id,
+        name: format!("row {}", id),
+    }
+}
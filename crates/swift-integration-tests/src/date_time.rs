@@ -0,0 +1,76 @@
+use swift_bridge::chrono::{DateTime, Utc};
+use swift_bridge::time::OffsetDateTime;
+
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct")]
+    struct DateTimeTestStruct {
+        chrono: swift_bridge::chrono::DateTime<swift_bridge::chrono::Utc>,
+        time: swift_bridge::time::OffsetDateTime,
+    }
+
+    extern "Rust" {
+        fn rust_reflect_chrono_date_time(arg: DateTime<Utc>) -> DateTime<Utc>;
+        fn rust_reflect_option_chrono_date_time(
+            arg: Option<DateTime<Utc>>,
+        ) -> Option<DateTime<Utc>>;
+        fn rust_chrono_date_time_rfc3339(arg: DateTime<Utc>) -> String;
+        fn rust_reflect_offset_date_time(arg: OffsetDateTime) -> OffsetDateTime;
+        fn rust_reflect_option_offset_date_time(
+            arg: Option<OffsetDateTime>,
+        ) -> Option<OffsetDateTime>;
+        fn rust_offset_date_time_unix_timestamp(arg: OffsetDateTime) -> i64;
+        fn rust_reflect_date_time_test_struct(arg: DateTimeTestStruct) -> DateTimeTestStruct;
+    }
+
+    extern "Swift" {
+        fn swift_reflect_chrono_date_time(arg: DateTime<Utc>) -> DateTime<Utc>;
+        fn swift_reflect_offset_date_time(arg: OffsetDateTime) -> OffsetDateTime;
+    }
+
+    extern "Rust" {
+        fn test_rust_calls_swift_reflect_date_times();
+    }
+}
+
+use ffi::DateTimeTestStruct;
+
+fn rust_reflect_chrono_date_time(arg: DateTime<Utc>) -> DateTime<Utc> {
+    arg
+}
+
+fn rust_reflect_option_chrono_date_time(arg: Option<DateTime<Utc>>) -> Option<DateTime<Utc>> {
+    arg
+}
+
+fn rust_chrono_date_time_rfc3339(arg: DateTime<Utc>) -> String {
+    arg.to_rfc3339()
+}
+
+fn rust_reflect_offset_date_time(arg: OffsetDateTime) -> OffsetDateTime {
+    arg
+}
+
+fn rust_reflect_option_offset_date_time(arg: Option<OffsetDateTime>) -> Option<OffsetDateTime> {
+    arg
+}
+
+fn rust_offset_date_time_unix_timestamp(arg: OffsetDateTime) -> i64 {
+    arg.unix_timestamp()
+}
+
+fn rust_reflect_date_time_test_struct(arg: DateTimeTestStruct) -> DateTimeTestStruct {
+    arg
+}
+
+fn test_rust_calls_swift_reflect_date_times() {
+    for (secs, nanos) in [(0, 0), (1_650_000_000, 500_000_000), (-2, 500_000_000)] {
+        let chrono = DateTime::from_timestamp(secs, nanos).unwrap();
+        assert_eq!(ffi::swift_reflect_chrono_date_time(chrono), chrono);
+
+        let time =
+            OffsetDateTime::from_unix_timestamp_nanos(secs as i128 * 1_000_000_000 + nanos as i128)
+                .unwrap();
+        assert_eq!(ffi::swift_reflect_offset_date_time(time), time);
+    }
+}
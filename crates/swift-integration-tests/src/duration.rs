@@ -0,0 +1,52 @@
+use std::time::Duration;
+
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct")]
+    struct DurationTestStruct {
+        timeout: std::time::Duration,
+    }
+
+    extern "Rust" {
+        fn rust_reflect_duration(arg: Duration) -> Duration;
+        fn rust_double_duration(arg: Duration) -> Duration;
+        fn rust_reflect_option_duration(arg: Option<Duration>) -> Option<Duration>;
+        fn rust_reflect_duration_test_struct(arg: DurationTestStruct) -> DurationTestStruct;
+    }
+
+    extern "Swift" {
+        fn swift_reflect_duration(arg: Duration) -> Duration;
+    }
+
+    extern "Rust" {
+        fn test_rust_calls_swift_reflect_duration();
+    }
+}
+
+use ffi::DurationTestStruct;
+
+fn rust_reflect_duration(arg: Duration) -> Duration {
+    arg
+}
+
+fn rust_double_duration(arg: Duration) -> Duration {
+    arg * 2
+}
+
+fn rust_reflect_option_duration(arg: Option<Duration>) -> Option<Duration> {
+    arg
+}
+
+fn rust_reflect_duration_test_struct(arg: DurationTestStruct) -> DurationTestStruct {
+    arg
+}
+
+fn test_rust_calls_swift_reflect_duration() {
+    for duration in [
+        Duration::ZERO,
+        Duration::from_millis(1500),
+        Duration::from_secs(60 * 60),
+    ] {
+        assert_eq!(ffi::swift_reflect_duration(duration), duration);
+    }
+}
@@ -1,3 +1,4 @@
 mod already_declared;
 mod derive;
+mod discriminant_check;
 mod swift_name;
@@ -0,0 +1,14 @@
+/// We declare an enum that uses the `discriminant_check` attribute so that Swift can verify that
+/// Rust has the same variant at each discriminant.
+///
+/// Related: crates/swift-bridge-ir/src/codegen/codegen_tests/transparent_enum_codegen_tests.rs
+///   - enum_discriminant_check_attribute
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(discriminant_check)]
+    enum DiscriminantCheckEnum {
+        Variant1,
+        Variant2(u8),
+        Variant3 { field: bool },
+    }
+}
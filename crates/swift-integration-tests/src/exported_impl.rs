@@ -0,0 +1,52 @@
+//! Verify that the public methods of an impl block can be exposed to Swift using
+//! `#[swift_bridge::export]`.
+
+pub struct ExportedImplTestCounter {
+    count: u32,
+}
+
+#[swift_bridge::export]
+impl ExportedImplTestCounter {
+    pub fn new(start: u32) -> Self {
+        ExportedImplTestCounter { count: start }
+    }
+
+    pub fn increment(&mut self) {
+        self.count += 1;
+    }
+
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    #[swift_bridge(swift_name = "describe")]
+    pub fn description(&self) -> String {
+        format!("count: {}", self.count)
+    }
+
+    /// Not exported since it is not public.
+    #[allow(unused)]
+    fn reset(&mut self) {
+        self.count = 0;
+    }
+
+    /// Not exported since `Range<u32>` cannot cross the bridge.
+    #[allow(unused)]
+    pub fn range(&self) -> std::ops::Range<u32> {
+        0..self.count
+    }
+}
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(already_declared)]
+        type ExportedImplTestCounter;
+
+        fn rust_double_exported_impl_counter(counter: &ExportedImplTestCounter) -> u32;
+    }
+}
+
+fn rust_double_exported_impl_counter(counter: &ExportedImplTestCounter) -> u32 {
+    counter.count() * 2
+}
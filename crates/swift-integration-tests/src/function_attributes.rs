@@ -1,8 +1,12 @@
 mod args_into;
+mod compress;
 mod get;
 mod get_with;
 mod identifiable;
+mod init;
 mod return_into;
 mod return_with;
 mod rust_name;
+mod scoped;
+mod store;
 mod swift_name;
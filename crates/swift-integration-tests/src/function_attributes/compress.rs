@@ -0,0 +1,20 @@
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(compress = "lz4")]
+        fn rust_compressed_bytes(len: usize) -> Vec<u8>;
+
+        #[swift_bridge(compress = "lz4")]
+        fn rust_compressed_string(repeat: usize) -> String;
+    }
+}
+
+/// `len` bytes that count up from 0 and wrap around at 256.
+fn rust_compressed_bytes(len: usize) -> Vec<u8> {
+    (0..len).map(|idx| idx as u8).collect()
+}
+
+/// "hello, world! " repeated `repeat` times.
+fn rust_compressed_string(repeat: usize) -> String {
+    "hello, world! ".repeat(repeat)
+}
@@ -14,6 +14,16 @@ mod ffi {
 
         #[swift_bridge(get(my_opt_static_str))]
         fn my_opt_static_str(&self) -> Option<&'static str>;
+
+        #[swift_bridge(get(&child))]
+        fn child(&self) -> &SomeTypeGetChild;
+    }
+
+    extern "Rust" {
+        type SomeTypeGetChild;
+
+        #[swift_bridge(get(value))]
+        fn value(&self) -> u32;
     }
 }
 
@@ -21,6 +31,11 @@ pub struct SomeTypeGet {
     my_u8: u8,
     my_string: String,
     my_opt_static_str: Option<&'static str>,
+    child: SomeTypeGetChild,
+}
+
+pub struct SomeTypeGetChild {
+    value: u32,
 }
 
 impl SomeTypeGet {
@@ -29,6 +44,7 @@ impl SomeTypeGet {
             my_u8: 123,
             my_string: "Hello".to_string(),
             my_opt_static_str: Some("world"),
+            child: SomeTypeGetChild { value: 456 },
         }
     }
 }
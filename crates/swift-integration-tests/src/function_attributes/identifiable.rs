@@ -61,6 +61,17 @@ mod ffi {
         fn id(&self) -> &'static str;
     }
 
+    extern "Rust" {
+        // Here we make sure that the `Identifiable = "..."` attribute works on a type.
+        #[swift_bridge(Identifiable = "user_id")]
+        type IdentifiableTypeAttribute;
+
+        #[swift_bridge(init)]
+        fn new() -> IdentifiableTypeAttribute;
+        #[swift_bridge(swift_name = "userId")]
+        fn user_id(&self) -> u64;
+    }
+
     // TODO: Add more Identifiable test types..
 }
 
@@ -76,6 +87,18 @@ impl IdentifiableFnNotNamedId {
     }
 }
 
+pub struct IdentifiableTypeAttribute;
+
+impl IdentifiableTypeAttribute {
+    fn new() -> Self {
+        Self
+    }
+
+    fn user_id(&self) -> u64 {
+        456
+    }
+}
+
 #[derive(Copy, Clone)]
 pub struct OpaqueCopyTypeIdentifiable(u8);
 
@@ -0,0 +1,42 @@
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type FailableInitTestType;
+
+        #[swift_bridge(init)]
+        fn new(value: u8) -> Result<FailableInitTestType, String>;
+
+        #[swift_bridge(init)]
+        fn new_if_even(
+            #[swift_bridge(label = "evenValue")] value: u8,
+        ) -> Option<FailableInitTestType>;
+
+        fn value(&self) -> u8;
+    }
+}
+
+pub struct FailableInitTestType {
+    value: u8,
+}
+
+impl FailableInitTestType {
+    fn new(value: u8) -> Result<Self, String> {
+        if value == 0 {
+            return Err("value must not be zero".to_string());
+        }
+
+        Ok(FailableInitTestType { value })
+    }
+
+    fn new_if_even(value: u8) -> Option<Self> {
+        if value % 2 == 0 {
+            Some(FailableInitTestType { value })
+        } else {
+            None
+        }
+    }
+
+    fn value(&self) -> u8 {
+        self.value
+    }
+}
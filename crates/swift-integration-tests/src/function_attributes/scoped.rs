@@ -0,0 +1,112 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        type ScopedTestDatabase;
+        type ScopedTestTransaction;
+        type ScopedTestSchema;
+
+        #[swift_bridge(init)]
+        fn new() -> ScopedTestDatabase;
+
+        #[swift_bridge(scoped = "withTransaction")]
+        fn begin(self: &ScopedTestDatabase) -> ScopedTestTransaction;
+
+        fn open_transactions(self: &ScopedTestDatabase) -> u32;
+        fn committed_rows(self: &ScopedTestDatabase) -> u32;
+
+        #[swift_bridge(scoped = "withSchema")]
+        fn schema(self: &ScopedTestDatabase) -> &ScopedTestSchema;
+        #[swift_bridge(scoped = "withSchemaMut")]
+        fn schema_mut(self: &mut ScopedTestDatabase) -> &mut ScopedTestSchema;
+
+        fn insert(self: &mut ScopedTestTransaction, value: u32);
+        fn pending_rows(self: &ScopedTestTransaction) -> u32;
+
+        fn version(self: &ScopedTestSchema) -> u32;
+        fn migrate(self: &mut ScopedTestSchema);
+    }
+}
+
+pub struct ScopedTestDatabase {
+    open_transactions: Arc<AtomicU32>,
+    committed_rows: Arc<AtomicU32>,
+    schema: ScopedTestSchema,
+}
+
+pub struct ScopedTestSchema {
+    version: u32,
+}
+
+/// Commits its rows to the database when it is dropped.
+pub struct ScopedTestTransaction {
+    open_transactions: Arc<AtomicU32>,
+    committed_rows: Arc<AtomicU32>,
+    pending: Vec<u32>,
+}
+
+impl ScopedTestDatabase {
+    fn new() -> Self {
+        ScopedTestDatabase {
+            open_transactions: Arc::new(AtomicU32::new(0)),
+            committed_rows: Arc::new(AtomicU32::new(0)),
+            schema: ScopedTestSchema { version: 1 },
+        }
+    }
+
+    fn begin(&self) -> ScopedTestTransaction {
+        self.open_transactions.fetch_add(1, Ordering::SeqCst);
+
+        ScopedTestTransaction {
+            open_transactions: self.open_transactions.clone(),
+            committed_rows: self.committed_rows.clone(),
+            pending: vec![],
+        }
+    }
+
+    fn open_transactions(&self) -> u32 {
+        self.open_transactions.load(Ordering::SeqCst)
+    }
+
+    fn committed_rows(&self) -> u32 {
+        self.committed_rows.load(Ordering::SeqCst)
+    }
+
+    fn schema(&self) -> &ScopedTestSchema {
+        &self.schema
+    }
+
+    fn schema_mut(&mut self) -> &mut ScopedTestSchema {
+        &mut self.schema
+    }
+}
+
+impl ScopedTestTransaction {
+    fn insert(&mut self, value: u32) {
+        self.pending.push(value);
+    }
+
+    fn pending_rows(&self) -> u32 {
+        self.pending.len() as u32
+    }
+}
+
+impl ScopedTestSchema {
+    fn version(&self) -> u32 {
+        self.version
+    }
+
+    fn migrate(&mut self) {
+        self.version += 1;
+    }
+}
+
+impl Drop for ScopedTestTransaction {
+    fn drop(&mut self) {
+        self.committed_rows
+            .fetch_add(self.pending.len() as u32, Ordering::SeqCst);
+        self.open_transactions.fetch_sub(1, Ordering::SeqCst);
+    }
+}
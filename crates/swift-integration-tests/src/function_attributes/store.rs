@@ -0,0 +1,66 @@
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct")]
+    struct StoreTestCounterState {
+        count: u32,
+        history_len: u32,
+    }
+
+    enum StoreTestCounterAction {
+        Increment,
+        Add(u32),
+        Reset,
+    }
+
+    extern "Rust" {
+        type StoreTestCounter;
+
+        #[swift_bridge(init)]
+        fn new() -> StoreTestCounter;
+
+        #[swift_bridge(Store(state))]
+        fn state(self: &StoreTestCounter) -> StoreTestCounterState;
+
+        #[swift_bridge(Store(dispatch))]
+        fn dispatch(self: &mut StoreTestCounter, action: StoreTestCounterAction);
+
+        // Changes the state without going through `dispatch`, so that we can test
+        // `StoreTestCounterStore.refresh()`.
+        fn reset_from_rust(self: &mut StoreTestCounter);
+    }
+}
+
+use ffi::{StoreTestCounterAction, StoreTestCounterState};
+
+#[derive(Default)]
+pub struct StoreTestCounter {
+    count: u32,
+    history: Vec<StoreTestCounterAction>,
+}
+
+impl StoreTestCounter {
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn state(&self) -> StoreTestCounterState {
+        StoreTestCounterState {
+            count: self.count,
+            history_len: self.history.len() as u32,
+        }
+    }
+
+    fn dispatch(&mut self, action: StoreTestCounterAction) {
+        match &action {
+            StoreTestCounterAction::Increment => self.count += 1,
+            StoreTestCounterAction::Add(amount) => self.count += amount,
+            StoreTestCounterAction::Reset => self.count = 0,
+        };
+        self.history.push(action);
+    }
+
+    fn reset_from_rust(&mut self) {
+        self.count = 0;
+        self.history.clear();
+    }
+}
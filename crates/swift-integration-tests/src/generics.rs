@@ -29,6 +29,24 @@ mod ffi {
             arg: GenericWithOpaqueRustInnerTy<InnerTy>,
         ) -> GenericWithOpaqueRustInnerTy<InnerTy>;
     }
+
+    extern "Rust" {
+        type GenericCache<u32>;
+
+        #[swift_bridge(init)]
+        fn new() -> GenericCache<u32>;
+        fn insert(&mut self, item: u32);
+        fn len(&self) -> usize;
+    }
+
+    extern "Rust" {
+        type GenericCache<String>;
+
+        #[swift_bridge(init)]
+        fn new() -> GenericCache<String>;
+        fn insert(&mut self, item: String);
+        fn len(&self) -> usize;
+    }
 }
 
 pub struct SomeGenericType<T> {
@@ -48,6 +66,24 @@ pub struct GenericWithOpaqueRustInnerTy<T> {
 }
 pub struct InnerTy;
 
+pub struct GenericCache<T> {
+    items: Vec<T>,
+}
+
+impl<T> GenericCache<T> {
+    fn new() -> Self {
+        GenericCache { items: vec![] }
+    }
+
+    fn insert(&mut self, item: T) {
+        self.items.push(item);
+    }
+
+    fn len(&self) -> usize {
+        self.items.len()
+    }
+}
+
 fn new_some_generic_type_u32() -> SomeGenericType<u32> {
     SomeGenericType { field: 123 }
 }
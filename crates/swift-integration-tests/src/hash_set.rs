@@ -0,0 +1,32 @@
+use std::collections::HashSet;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_reflect_hash_set_u64(arg: HashSet<u64>) -> HashSet<u64>;
+        fn rust_reflect_hash_set_string(arg: HashSet<String>) -> HashSet<String>;
+
+        fn rust_make_hash_set_string() -> HashSet<String>;
+
+        fn rust_reflect_option_hash_set_u64(arg: Option<HashSet<u64>>) -> Option<HashSet<u64>>;
+    }
+}
+
+fn rust_reflect_hash_set_u64(arg: HashSet<u64>) -> HashSet<u64> {
+    arg
+}
+
+fn rust_reflect_hash_set_string(arg: HashSet<String>) -> HashSet<String> {
+    arg
+}
+
+fn rust_reflect_option_hash_set_u64(arg: Option<HashSet<u64>>) -> Option<HashSet<u64>> {
+    arg
+}
+
+fn rust_make_hash_set_string() -> HashSet<String> {
+    ["hello", "world", "hello"]
+        .into_iter()
+        .map(|s| s.to_string())
+        .collect()
+}
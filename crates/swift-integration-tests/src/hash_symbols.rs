@@ -0,0 +1,43 @@
+#[swift_bridge::bridge]
+#[swift_bridge(hash_symbols)]
+mod ffi {
+    extern "Rust" {
+        type HashSymbolsTestGreeter;
+
+        #[swift_bridge(init)]
+        fn new(name: String) -> HashSymbolsTestGreeter;
+        fn greet(&self) -> String;
+
+        fn rust_hash_symbols_add(lhs: u32, rhs: u32) -> u32;
+    }
+
+    extern "Swift" {
+        fn swift_hash_symbols_multiply(lhs: u32, rhs: u32) -> u32;
+    }
+
+    extern "Rust" {
+        fn test_rust_calls_swift_with_hashed_symbols();
+    }
+}
+
+pub struct HashSymbolsTestGreeter {
+    name: String,
+}
+
+impl HashSymbolsTestGreeter {
+    fn new(name: String) -> Self {
+        HashSymbolsTestGreeter { name }
+    }
+
+    fn greet(&self) -> String {
+        format!("Hello, {}", self.name)
+    }
+}
+
+fn rust_hash_symbols_add(lhs: u32, rhs: u32) -> u32 {
+    lhs + rhs
+}
+
+fn test_rust_calls_swift_with_hashed_symbols() {
+    assert_eq!(ffi::swift_hash_symbols_multiply(3, 4), 12);
+}
@@ -0,0 +1,24 @@
+use swift_bridge::image_buffer::{ImageBuffer, PixelFormat};
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_make_image_buffer(width: u32, height: u32) -> ImageBuffer;
+        fn rust_image_buffer_byte_sum(arg: ImageBuffer) -> u64;
+        fn rust_reflect_option_image_buffer(arg: Option<ImageBuffer>) -> Option<ImageBuffer>;
+    }
+}
+
+/// An opaque red image.
+fn rust_make_image_buffer(width: u32, height: u32) -> ImageBuffer {
+    let pixels = [255, 0, 0, 255].repeat(width as usize * height as usize);
+    ImageBuffer::new(width, height, PixelFormat::Rgba8, pixels).unwrap()
+}
+
+fn rust_image_buffer_byte_sum(arg: ImageBuffer) -> u64 {
+    arg.bytes().iter().map(|byte| *byte as u64).sum()
+}
+
+fn rust_reflect_option_image_buffer(arg: Option<ImageBuffer>) -> Option<ImageBuffer> {
+    arg
+}
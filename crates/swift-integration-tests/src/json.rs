@@ -0,0 +1,44 @@
+use swift_bridge::serde_json::Value;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_reflect_json(arg: serde_json::Value) -> serde_json::Value;
+        fn rust_json_get(arg: serde_json::Value, key: &str) -> Option<serde_json::Value>;
+        fn rust_json_to_string(arg: serde_json::Value) -> String;
+    }
+
+    extern "Swift" {
+        fn swift_reflect_json(arg: serde_json::Value) -> serde_json::Value;
+    }
+
+    extern "Rust" {
+        fn test_rust_calls_swift_reflect_json();
+    }
+}
+
+fn rust_reflect_json(arg: Value) -> Value {
+    arg
+}
+
+fn rust_json_get(arg: Value, key: &str) -> Option<Value> {
+    arg.get(key).cloned()
+}
+
+fn rust_json_to_string(arg: Value) -> String {
+    arg.to_string()
+}
+
+fn test_rust_calls_swift_reflect_json() {
+    let values = [
+        Value::Null,
+        Value::from(true),
+        Value::from(-1.5),
+        Value::from("a string"),
+        Value::from(vec![Value::from(1), Value::from("two")]),
+    ];
+
+    for value in values {
+        assert_eq!(ffi::swift_reflect_json(value.clone()), value);
+    }
+}
@@ -1,13 +1,37 @@
 mod expose_opaque_rust_type;
 mod import_opaque_swift_class;
 
+mod arc;
+mod array;
 mod async_function;
 mod boxed_functions;
+mod bridged_types;
+mod btree_map;
+mod byte_stream;
+mod char;
 mod conditional_compilation;
+mod constant;
+mod cow_str;
+mod cursor;
+mod date_time;
+mod duration;
+mod exported_impl;
 mod generics;
+mod hash_set;
+mod hash_symbols;
+mod image_buffer;
+mod json;
+mod measurement;
+mod module_include;
+mod module_table;
+mod net;
 mod option;
+mod path;
+mod pixel_buffer;
 mod pointer;
 mod primitive;
+mod range;
+mod reflection;
 mod result;
 mod rust_function_uses_opaque_swift_type;
 mod shared_types;
@@ -16,8 +40,13 @@ mod slice;
 mod string;
 mod swift_function_uses_opaque_rust_type;
 mod swift_function_uses_opaque_swift_type;
+mod system_time;
+mod tensor;
 mod tuple;
+mod utf8;
+mod uuid;
 mod vec;
+mod watch;
 
 mod enum_attributes;
 mod function_attributes;
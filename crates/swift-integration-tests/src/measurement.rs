@@ -0,0 +1,54 @@
+use swift_bridge::measurement::{
+    Length, LengthUnit, Mass, Speed, SpeedUnit, Temperature, TemperatureUnit,
+};
+
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct")]
+    struct MeasurementTestStruct {
+        body_temperature: swift_bridge::measurement::Temperature,
+    }
+
+    extern "Rust" {
+        fn rust_reflect_length(arg: Length) -> Length;
+        fn rust_temperature_in_celsius(arg: Temperature) -> Temperature;
+        fn rust_reflect_option_mass(arg: Option<Mass>) -> Option<Mass>;
+        fn rust_reflect_measurement_test_struct(
+            arg: MeasurementTestStruct,
+        ) -> MeasurementTestStruct;
+    }
+
+    extern "Swift" {
+        fn swift_reflect_speed(arg: Speed) -> Speed;
+    }
+
+    extern "Rust" {
+        fn test_rust_calls_swift_reflect_speed();
+    }
+}
+
+use ffi::MeasurementTestStruct;
+
+fn rust_reflect_length(arg: Length) -> Length {
+    arg
+}
+
+fn rust_temperature_in_celsius(arg: Temperature) -> Temperature {
+    arg.converted_to(TemperatureUnit::Celsius)
+}
+
+fn rust_reflect_option_mass(arg: Option<Mass>) -> Option<Mass> {
+    arg
+}
+
+fn rust_reflect_measurement_test_struct(arg: MeasurementTestStruct) -> MeasurementTestStruct {
+    arg
+}
+
+fn test_rust_calls_swift_reflect_speed() {
+    let speed = Speed::new(12., SpeedUnit::Knots);
+    assert_eq!(ffi::swift_reflect_speed(speed), speed);
+
+    let distance = Length::new(1., LengthUnit::Miles);
+    assert_eq!(distance.value_in(LengthUnit::Meters), 1609.344);
+}
@@ -0,0 +1,29 @@
+//! Verify that a bridge module's items can be split across files using
+//! `#[swift_bridge(include = "...")]`.
+
+#[swift_bridge::bridge]
+#[swift_bridge(include = "src/module_include/*.rs")]
+mod ffi {
+    extern "Rust" {
+        fn rust_included_counter_total(counter: &IncludedCounter, extra: IncludedTally) -> u32;
+    }
+}
+
+pub struct IncludedCounter {
+    count: u32,
+}
+
+impl IncludedCounter {
+    fn new() -> Self {
+        IncludedCounter { count: 0 }
+    }
+
+    fn increment(&mut self) -> u32 {
+        self.count += 1;
+        self.count
+    }
+}
+
+fn rust_included_counter_total(counter: &IncludedCounter, extra: ffi::IncludedTally) -> u32 {
+    counter.count + extra.amount
+}
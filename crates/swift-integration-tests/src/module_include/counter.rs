@@ -0,0 +1,7 @@
+extern "Rust" {
+    type IncludedCounter;
+
+    #[swift_bridge(init)]
+    fn new() -> IncludedCounter;
+    fn increment(&mut self) -> u32;
+}
@@ -0,0 +1,4 @@
+#[swift_bridge(swift_repr = "struct")]
+struct IncludedTally {
+    amount: u32,
+}
@@ -0,0 +1,32 @@
+#[swift_bridge::bridge]
+#[swift_bridge(module_table = "integration_tests")]
+mod ffi {
+    extern "Rust" {
+        type ModuleTableTestCounter;
+
+        #[swift_bridge(init)]
+        fn new() -> ModuleTableTestCounter;
+        fn increment(&mut self) -> u32;
+
+        fn rust_module_table_add(lhs: u32, rhs: u32) -> u32;
+    }
+}
+
+pub struct ModuleTableTestCounter {
+    count: u32,
+}
+
+impl ModuleTableTestCounter {
+    fn new() -> Self {
+        ModuleTableTestCounter { count: 0 }
+    }
+
+    fn increment(&mut self) -> u32 {
+        self.count += 1;
+        self.count
+    }
+}
+
+fn rust_module_table_add(lhs: u32, rhs: u32) -> u32 {
+    lhs + rhs
+}
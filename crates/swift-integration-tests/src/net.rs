@@ -0,0 +1,81 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct")]
+    struct NetAddrTestStruct {
+        ip: std::net::IpAddr,
+        socket: std::net::SocketAddr,
+    }
+
+    extern "Rust" {
+        fn rust_reflect_ip_addr(arg: IpAddr) -> IpAddr;
+        fn rust_reflect_socket_addr(arg: SocketAddr) -> SocketAddr;
+        fn rust_reflect_option_socket_addr(arg: Option<SocketAddr>) -> Option<SocketAddr>;
+        fn rust_socket_addr_string(arg: SocketAddr) -> String;
+        fn rust_reflect_net_addr_test_struct(arg: NetAddrTestStruct) -> NetAddrTestStruct;
+    }
+
+    extern "Swift" {
+        fn swift_reflect_socket_addr(arg: SocketAddr) -> SocketAddr;
+    }
+
+    extern "Rust" {
+        fn test_rust_calls_swift_reflect_socket_addr();
+    }
+}
+
+#[swift_bridge::bridge]
+#[swift_bridge(net_addrs_as_strings)]
+mod ffi_net_addrs_as_strings {
+    extern "Rust" {
+        fn rust_ip_addr_string_is_loopback(arg: IpAddr) -> bool;
+        fn rust_socket_addr_string_with_port(arg: SocketAddr, port: u16) -> SocketAddr;
+        fn rust_reflect_option_ip_addr_string(arg: Option<IpAddr>) -> Option<IpAddr>;
+    }
+}
+
+use ffi::NetAddrTestStruct;
+
+fn rust_reflect_ip_addr(arg: IpAddr) -> IpAddr {
+    arg
+}
+
+fn rust_reflect_socket_addr(arg: SocketAddr) -> SocketAddr {
+    arg
+}
+
+fn rust_reflect_option_socket_addr(arg: Option<SocketAddr>) -> Option<SocketAddr> {
+    arg
+}
+
+fn rust_socket_addr_string(arg: SocketAddr) -> String {
+    arg.to_string()
+}
+
+fn rust_reflect_net_addr_test_struct(arg: NetAddrTestStruct) -> NetAddrTestStruct {
+    arg
+}
+
+fn test_rust_calls_swift_reflect_socket_addr() {
+    for addr in [
+        SocketAddr::new(IpAddr::V4(Ipv4Addr::new(192, 168, 1, 20)), 8080),
+        SocketAddr::new(IpAddr::V6(Ipv6Addr::LOCALHOST), 443),
+        "[fe80::1%3]:22".parse().unwrap(),
+    ] {
+        assert_eq!(ffi::swift_reflect_socket_addr(addr), addr);
+    }
+}
+
+fn rust_ip_addr_string_is_loopback(arg: IpAddr) -> bool {
+    arg.is_loopback()
+}
+
+fn rust_socket_addr_string_with_port(mut arg: SocketAddr, port: u16) -> SocketAddr {
+    arg.set_port(port);
+    arg
+}
+
+fn rust_reflect_option_ip_addr_string(arg: Option<IpAddr>) -> Option<IpAddr> {
+    arg
+}
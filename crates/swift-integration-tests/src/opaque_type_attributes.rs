@@ -1,4 +1,12 @@
 mod already_declared;
+mod clone;
+mod comparable;
 mod copy;
+mod custom_debug_string_convertible;
+mod custom_string_convertible;
+mod drop_queue;
 mod equatable;
+mod error;
 mod hashable;
+mod identity_map;
+mod sendable;
@@ -0,0 +1,25 @@
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(Comparable)]
+        type RustComparableType;
+
+        #[swift_bridge(init)]
+        fn new(value: i32) -> RustComparableType;
+
+        fn value(&self) -> i32;
+    }
+}
+
+#[derive(PartialEq, PartialOrd)]
+pub struct RustComparableType(i32);
+
+impl RustComparableType {
+    fn new(value: i32) -> Self {
+        RustComparableType(value)
+    }
+
+    fn value(&self) -> i32 {
+        self.0
+    }
+}
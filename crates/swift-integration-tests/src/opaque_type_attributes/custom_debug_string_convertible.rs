@@ -0,0 +1,22 @@
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(CustomDebugStringConvertible)]
+        type RustDebugType;
+
+        #[swift_bridge(init)]
+        fn new(name: String, retries: u8) -> RustDebugType;
+    }
+}
+
+#[derive(Debug)]
+pub struct RustDebugType {
+    name: String,
+    retries: u8,
+}
+
+impl RustDebugType {
+    fn new(name: String, retries: u8) -> Self {
+        RustDebugType { name, retries }
+    }
+}
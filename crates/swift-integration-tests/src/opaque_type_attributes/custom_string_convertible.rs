@@ -0,0 +1,27 @@
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(CustomStringConvertible)]
+        type RustDisplayType;
+
+        #[swift_bridge(init)]
+        fn new(major: u32, minor: u32) -> RustDisplayType;
+    }
+}
+
+pub struct RustDisplayType {
+    major: u32,
+    minor: u32,
+}
+
+impl RustDisplayType {
+    fn new(major: u32, minor: u32) -> Self {
+        RustDisplayType { major, minor }
+    }
+}
+
+impl std::fmt::Display for RustDisplayType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "v{}.{}", self.major, self.minor)
+    }
+}
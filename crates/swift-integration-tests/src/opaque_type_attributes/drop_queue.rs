@@ -0,0 +1,51 @@
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::thread::ThreadId;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(drop_queue = "DispatchQueue.main")]
+        type DropQueueTestType;
+
+        #[swift_bridge(init)]
+        fn new() -> DropQueueTestType;
+    }
+
+    extern "Rust" {
+        fn drop_queue_test_type_drop_count() -> u32;
+        fn drop_queue_test_type_dropped_on_other_thread() -> bool;
+    }
+}
+
+static DROP_COUNT: AtomicU32 = AtomicU32::new(0);
+static DROPPED_ON_OTHER_THREAD: AtomicBool = AtomicBool::new(false);
+
+/// Records whether it was dropped on the thread that created it.
+pub struct DropQueueTestType {
+    created_on: ThreadId,
+}
+
+impl DropQueueTestType {
+    fn new() -> Self {
+        DropQueueTestType {
+            created_on: std::thread::current().id(),
+        }
+    }
+}
+
+impl Drop for DropQueueTestType {
+    fn drop(&mut self) {
+        if std::thread::current().id() != self.created_on {
+            DROPPED_ON_OTHER_THREAD.store(true, Ordering::SeqCst);
+        }
+        DROP_COUNT.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+fn drop_queue_test_type_drop_count() -> u32 {
+    DROP_COUNT.load(Ordering::SeqCst)
+}
+
+fn drop_queue_test_type_dropped_on_other_thread() -> bool {
+    DROPPED_ON_OTHER_THREAD.load(Ordering::SeqCst)
+}
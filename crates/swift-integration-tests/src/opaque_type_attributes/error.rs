@@ -0,0 +1,25 @@
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(Error)]
+        type RustErrorType;
+
+        fn rust_func_parse_port(port: &str) -> Result<u16, RustErrorType>;
+    }
+}
+
+pub struct RustErrorType {
+    port: String,
+}
+
+impl std::fmt::Display for RustErrorType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid port: {}", self.port)
+    }
+}
+
+fn rust_func_parse_port(port: &str) -> Result<u16, RustErrorType> {
+    port.parse().map_err(|_| RustErrorType {
+        port: port.to_string(),
+    })
+}
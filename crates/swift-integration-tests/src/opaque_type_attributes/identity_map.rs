@@ -0,0 +1,51 @@
+use std::sync::Arc;
+use swift_bridge::identity::ObjectIdentity;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(identity_map)]
+        type IdentityMapTestType;
+
+        #[swift_bridge(init)]
+        fn new(num: u32) -> IdentityMapTestType;
+
+        fn num(&self) -> u32;
+        fn share(&self) -> IdentityMapTestType;
+    }
+
+    extern "Rust" {
+        fn rust_identity_map_test_type_shared() -> IdentityMapTestType;
+    }
+}
+
+/// Multiple `IdentityMapTestType` values can share the same underlying Rust object.
+pub struct IdentityMapTestType(Arc<u32>);
+
+impl IdentityMapTestType {
+    fn new(num: u32) -> Self {
+        IdentityMapTestType(Arc::new(num))
+    }
+
+    fn num(&self) -> u32 {
+        *self.0
+    }
+
+    fn share(&self) -> Self {
+        IdentityMapTestType(self.0.clone())
+    }
+}
+
+impl ObjectIdentity for IdentityMapTestType {
+    fn object_identity(&self) -> usize {
+        Arc::as_ptr(&self.0) as usize
+    }
+}
+
+thread_local! {
+    static SHARED: IdentityMapTestType = IdentityMapTestType::new(5);
+}
+
+fn rust_identity_map_test_type_shared() -> IdentityMapTestType {
+    SHARED.with(|shared| shared.share())
+}
@@ -0,0 +1,32 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        #[swift_bridge(Sendable)]
+        type SendableTestType;
+
+        #[swift_bridge(init)]
+        fn new() -> SendableTestType;
+
+        fn increment(&self);
+        fn count(&self) -> u32;
+    }
+}
+
+/// Can be incremented from more than one Swift task at a time.
+pub struct SendableTestType(AtomicU32);
+
+impl SendableTestType {
+    fn new() -> Self {
+        SendableTestType(AtomicU32::new(0))
+    }
+
+    fn increment(&self) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+
+    fn count(&self) -> u32 {
+        self.0.load(Ordering::SeqCst)
+    }
+}
@@ -17,8 +17,10 @@ mod ffi {
         f32: Option<f32>,
         f64: Option<f64>,
         boolean: Option<bool>,
+        string: Option<String>,
+        option_struct: Option<OptionStruct>,
+        option_enum: Option<OptionEnumWithNoData>,
         // TODO: Support test more types:
-        // string: Option<String>,
         // str: Option<&'static str>,
     }
 
@@ -80,6 +82,7 @@ mod ffi {
         fn rust_reflect_option_str(arg: Option<&str>) -> Option<&str>;
 
         fn rust_reflect_option_vector_rust_type(arg: Option<Vec<u16>>) -> Option<Vec<u16>>;
+        fn rust_reflect_option_slice_u8(arg: Option<&[u8]>) -> Option<&[u8]>;
 
         fn rust_reflect_option_opaque_rust_type(
             arg: Option<OptTestOpaqueRustType>,
@@ -237,6 +240,10 @@ fn rust_reflect_option_vector_rust_type(arg: Option<Vec<u16>>) -> Option<Vec<u16
     arg
 }
 
+fn rust_reflect_option_slice_u8(arg: Option<&[u8]>) -> Option<&[u8]> {
+    arg
+}
+
 fn rust_reflect_option_opaque_rust_type(
     arg: Option<OptTestOpaqueRustType>,
 ) -> Option<OptTestOpaqueRustType> {
@@ -0,0 +1,53 @@
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct")]
+    struct PathTestStruct {
+        path: std::path::PathBuf,
+    }
+
+    extern "Rust" {
+        fn rust_reflect_path_buf(arg: PathBuf) -> PathBuf;
+        fn rust_path_file_name(arg: &Path) -> Option<OsString>;
+        fn rust_join_path(base: PathBuf, child: &Path) -> PathBuf;
+        fn rust_reflect_os_string(arg: OsString) -> OsString;
+        fn rust_reflect_path_test_struct(arg: PathTestStruct) -> PathTestStruct;
+    }
+
+    extern "Swift" {
+        fn swift_path_is_absolute(arg: PathBuf) -> bool;
+    }
+
+    extern "Rust" {
+        fn test_rust_calls_swift_path_is_absolute();
+    }
+}
+
+use ffi::PathTestStruct;
+
+fn rust_reflect_path_buf(arg: PathBuf) -> PathBuf {
+    arg
+}
+
+fn rust_path_file_name(arg: &Path) -> Option<OsString> {
+    arg.file_name().map(|name| name.to_os_string())
+}
+
+fn rust_join_path(base: PathBuf, child: &Path) -> PathBuf {
+    base.join(child)
+}
+
+fn rust_reflect_os_string(arg: OsString) -> OsString {
+    arg
+}
+
+fn rust_reflect_path_test_struct(arg: PathTestStruct) -> PathTestStruct {
+    arg
+}
+
+fn test_rust_calls_swift_path_is_absolute() {
+    assert!(ffi::swift_path_is_absolute(PathBuf::from("/tmp/file.txt")));
+    assert!(!ffi::swift_path_is_absolute(PathBuf::from("file.txt")));
+}
@@ -0,0 +1,38 @@
+use swift_bridge::pixel_buffer::PixelBuffer;
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_pixel_buffer_byte_sum(arg: PixelBuffer) -> u64;
+        fn rust_fill_pixel_buffer(arg: PixelBuffer, value: u8) -> PixelBuffer;
+        fn rust_reflect_option_pixel_buffer(arg: Option<PixelBuffer>) -> Option<PixelBuffer>;
+    }
+}
+
+/// Sum every byte of every plane, including the padding at the end of each row.
+fn rust_pixel_buffer_byte_sum(arg: PixelBuffer) -> u64 {
+    let lock = arg.lock().unwrap();
+
+    (0..lock.plane_count())
+        .filter_map(|index| lock.plane(index))
+        .flat_map(|plane| plane.bytes().iter())
+        .map(|byte| *byte as u64)
+        .sum()
+}
+
+fn rust_fill_pixel_buffer(mut arg: PixelBuffer, value: u8) -> PixelBuffer {
+    {
+        let mut lock = arg.lock_mut().unwrap();
+        for index in 0..lock.plane_count() {
+            if let Some(mut plane) = lock.plane_mut(index) {
+                plane.bytes_mut().fill(value);
+            }
+        }
+    }
+
+    arg
+}
+
+fn rust_reflect_option_pixel_buffer(arg: Option<PixelBuffer>) -> Option<PixelBuffer> {
+    arg
+}
@@ -10,8 +10,22 @@ mod ffi {
         fn rust_echo_const_u8(ptr: *const u8) -> *const u8;
         fn rust_echo_mut_u8(ptr: *mut u8) -> *mut u8;
 
+        fn rust_echo_option_const_c_void(ptr: Option<*const c_void>) -> Option<*const c_void>;
+        fn rust_echo_option_mut_u8(ptr: Option<*mut u8>) -> Option<*mut u8>;
+
         fn rust_run_opaque_pointer_tests();
         fn rust_run_u8_pointer_tests();
+        fn rust_run_option_pointer_tests();
+    }
+
+    #[swift_bridge(swift_repr = "struct")]
+    struct TextureHandles {
+        texture: *mut c_void,
+        fallback: Option<*const c_void>,
+    }
+
+    extern "Rust" {
+        fn rust_reflect_texture_handles(handles: TextureHandles) -> TextureHandles;
     }
 
     // Opaque pointers.
@@ -21,6 +35,8 @@ mod ffi {
 
         fn swift_echo_const_u8(ptr: *const u8) -> *const u8;
         fn swift_echo_mut_u8(ptr: *mut u8) -> *mut u8;
+
+        fn swift_echo_option_mut_c_void(ptr: Option<*mut c_void>) -> Option<*mut c_void>;
     }
 }
 
@@ -56,6 +72,17 @@ fn rust_run_u8_pointer_tests() {
     assert_eq!(unsafe { *ptr_mut_copy }, 10);
 }
 
+/// Verify that optional pointers cross the boundary as null when they are `None`.
+fn rust_run_option_pointer_tests() {
+    let num_mut = &mut 555;
+    let ptr_mut = num_mut as *mut i32 as *mut c_void;
+
+    let ptr_copy = ffi::swift_echo_option_mut_c_void(Some(ptr_mut)).unwrap();
+    assert_eq!(unsafe { *(ptr_copy as *mut i32) }, 555);
+
+    assert!(ffi::swift_echo_option_mut_c_void(None).is_none());
+}
+
 fn rust_echo_const_c_void(ptr: *const c_void) -> *const c_void {
     ptr
 }
@@ -71,3 +98,15 @@ fn rust_echo_const_u8(ptr: *const u8) -> *const u8 {
 fn rust_echo_mut_u8(ptr: *mut u8) -> *mut u8 {
     ptr
 }
+
+fn rust_echo_option_const_c_void(ptr: Option<*const c_void>) -> Option<*const c_void> {
+    ptr
+}
+
+fn rust_echo_option_mut_u8(ptr: Option<*mut u8>) -> Option<*mut u8> {
+    ptr
+}
+
+fn rust_reflect_texture_handles(handles: ffi::TextureHandles) -> ffi::TextureHandles {
+    handles
+}
@@ -0,0 +1,67 @@
+use std::ops::{Range, RangeInclusive};
+
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct")]
+    struct RangeTestStruct {
+        rows: std::ops::Range<u64>,
+        pages: std::ops::RangeInclusive<i32>,
+    }
+
+    extern "Rust" {
+        fn rust_reflect_range_u32(arg: Range<u32>) -> Range<u32>;
+        fn rust_reflect_range_inclusive_i64(arg: RangeInclusive<i64>) -> RangeInclusive<i64>;
+        fn rust_page_range(page: usize, page_size: usize) -> Range<usize>;
+        fn rust_reversed_range_u32() -> Range<u32>;
+        fn rust_empty_range_inclusive_i64() -> RangeInclusive<i64>;
+        fn rust_reflect_range_test_struct(arg: RangeTestStruct) -> RangeTestStruct;
+    }
+
+    extern "Swift" {
+        fn swift_reflect_range_u32(arg: Range<u32>) -> Range<u32>;
+        fn swift_reflect_range_inclusive_i64(arg: RangeInclusive<i64>) -> RangeInclusive<i64>;
+    }
+
+    extern "Rust" {
+        fn test_rust_calls_swift_reflect_ranges();
+    }
+}
+
+use ffi::RangeTestStruct;
+
+fn rust_reflect_range_u32(arg: Range<u32>) -> Range<u32> {
+    arg
+}
+
+fn rust_reflect_range_inclusive_i64(arg: RangeInclusive<i64>) -> RangeInclusive<i64> {
+    arg
+}
+
+fn rust_page_range(page: usize, page_size: usize) -> Range<usize> {
+    page * page_size..(page + 1) * page_size
+}
+
+#[allow(clippy::reversed_empty_ranges)]
+fn rust_reversed_range_u32() -> Range<u32> {
+    5..3
+}
+
+#[allow(clippy::reversed_empty_ranges)]
+fn rust_empty_range_inclusive_i64() -> RangeInclusive<i64> {
+    1..=0
+}
+
+fn rust_reflect_range_test_struct(arg: RangeTestStruct) -> RangeTestStruct {
+    arg
+}
+
+#[allow(clippy::reversed_empty_ranges)]
+fn test_rust_calls_swift_reflect_ranges() {
+    for range in [0..0, 5..10, 0..u32::MAX] {
+        assert_eq!(ffi::swift_reflect_range_u32(range.clone()), range);
+    }
+    assert_eq!(ffi::swift_reflect_range_u32(5..3), 5..5);
+    for range in [-3..=3, i64::MIN..=i64::MAX] {
+        assert_eq!(ffi::swift_reflect_range_inclusive_i64(range.clone()), range);
+    }
+}
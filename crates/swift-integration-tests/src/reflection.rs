@@ -0,0 +1,52 @@
+#[swift_bridge::bridge]
+#[swift_bridge(reflection = "reflection_tests")]
+mod ffi {
+    extern "Rust" {
+        type ReflectionTestCounter;
+
+        #[swift_bridge(init)]
+        fn new() -> ReflectionTestCounter;
+        fn increment(&mut self) -> u32;
+
+        fn rust_reflection_add(lhs: u32, rhs: u32) -> u32;
+    }
+
+    extern "Swift" {
+        fn swift_reflection_multiply(lhs: u32, rhs: u32) -> u32;
+    }
+
+    extern "Rust" {
+        fn test_rust_calls_swift_with_reflection();
+    }
+}
+
+pub struct ReflectionTestCounter {
+    count: u32,
+}
+
+impl ReflectionTestCounter {
+    fn new() -> Self {
+        ReflectionTestCounter { count: 0 }
+    }
+
+    fn increment(&mut self) -> u32 {
+        self.count += 1;
+        self.count
+    }
+}
+
+fn rust_reflection_add(lhs: u32, rhs: u32) -> u32 {
+    lhs + rhs
+}
+
+fn test_rust_calls_swift_with_reflection() {
+    assert_eq!(ffi::swift_reflection_multiply(2, 3), 6);
+
+    let multiply = ffi::REFLECTION
+        .functions()
+        .iter()
+        .find(|function| function.name() == "swift_reflection_multiply")
+        .unwrap();
+    assert!(!multiply.is_rust());
+    assert!(multiply.call_count() >= 1);
+}
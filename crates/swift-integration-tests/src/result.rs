@@ -78,6 +78,17 @@ mod ffi {
             succeed: bool,
         ) -> Result<(i32, ResultTestOpaqueRustType, String), ResultTransparentEnum>;
     }
+
+    #[swift_bridge(swift_repr = "struct")]
+    struct ResultTestSharedStruct {
+        data: u16,
+    }
+
+    extern "Rust" {
+        fn rust_func_reflect_result_shared_struct_transparent_enum(
+            arg: Result<ResultTestSharedStruct, ResultTransparentEnum>,
+        ) -> Result<ResultTestSharedStruct, ResultTransparentEnum>;
+    }
 }
 
 fn rust_func_takes_result_string(arg: Result<String, String>) {
@@ -210,3 +221,9 @@ fn rust_func_return_result_tuple_transparent_enum(
         Err(ffi::ResultTransparentEnum::NamedField { data: -123 })
     }
 }
+
+fn rust_func_reflect_result_shared_struct_transparent_enum(
+    arg: Result<ffi::ResultTestSharedStruct, ffi::ResultTransparentEnum>,
+) -> Result<ffi::ResultTestSharedStruct, ffi::ResultTransparentEnum> {
+    arg
+}
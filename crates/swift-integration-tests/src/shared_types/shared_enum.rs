@@ -9,6 +9,27 @@ mod ffi {
         fn reflect_enum_with_no_data(arg: EnumWithNoData) -> EnumWithNoData;
     }
 
+    enum EnumWithDiscriminants {
+        North = 1,
+        South,
+        Below = -1,
+    }
+
+    extern "Rust" {
+        fn reflect_enum_with_discriminants(arg: EnumWithDiscriminants) -> EnumWithDiscriminants;
+        fn enum_with_discriminants_as_i32(arg: EnumWithDiscriminants) -> i32;
+    }
+
+    #[derive(Clone, PartialEq, Debug)]
+    enum EnumWithFloatData {
+        Circle { radius: f32 },
+        Rect { w: f32, h: f32 },
+    }
+
+    extern "Rust" {
+        fn scale_enum_with_float_data(arg: EnumWithFloatData, factor: f32) -> EnumWithFloatData;
+    }
+
     extern "Rust" {
         #[swift_bridge(Equatable)]
         type OpaqueRustForEnumTest;
@@ -72,6 +93,28 @@ fn reflect_enum_with_no_data(arg: ffi::EnumWithNoData) -> ffi::EnumWithNoData {
     arg
 }
 
+fn reflect_enum_with_discriminants(arg: ffi::EnumWithDiscriminants) -> ffi::EnumWithDiscriminants {
+    arg
+}
+
+fn enum_with_discriminants_as_i32(arg: ffi::EnumWithDiscriminants) -> i32 {
+    arg as i32
+}
+
+fn scale_enum_with_float_data(arg: ffi::EnumWithFloatData, factor: f32) -> ffi::EnumWithFloatData {
+    let scaled = match arg.clone() {
+        ffi::EnumWithFloatData::Circle { radius } => ffi::EnumWithFloatData::Circle {
+            radius: radius * factor,
+        },
+        ffi::EnumWithFloatData::Rect { w, h } => ffi::EnumWithFloatData::Rect {
+            w: w * factor,
+            h: h * factor,
+        },
+    };
+    assert!(factor == 1. || scaled != arg, "{:?}", scaled);
+    scaled
+}
+
 fn reflect_enum_with_unnamed_data(arg: ffi::EnumWithUnnamedData) -> ffi::EnumWithUnnamedData {
     arg
 }
@@ -17,6 +17,51 @@ mod ffi {
     #[swift_bridge(swift_repr = "struct")]
     struct StructReprStructTupleStruct(u8, u32);
 
+    #[swift_bridge(swift_repr = "struct")]
+    struct StructReprStructWithStaticStrField {
+        code: u32,
+        message: &'static str,
+    }
+
+    #[swift_bridge(swift_repr = "struct")]
+    struct StructReprStructWithStringField {
+        id: u32,
+        name: String,
+    }
+
+    #[swift_bridge(swift_repr = "struct")]
+    #[derive(Clone)]
+    struct StructReprStructVertex {
+        x: i32,
+        y: i32,
+    }
+
+    #[swift_bridge(swift_repr = "struct")]
+    struct StructReprStructWithVecFields {
+        vertices: Vec<StructReprStructVertex>,
+        ids: Vec<u32>,
+    }
+
+    // Declared before `StructReprStructPoint` to verify that the C header declares the
+    //  contained struct first.
+    #[swift_bridge(swift_repr = "struct")]
+    struct StructReprStructLine {
+        start: StructReprStructPoint,
+        end: StructReprStructPoint,
+    }
+
+    #[swift_bridge(swift_repr = "struct")]
+    struct StructReprStructPoint {
+        x: i32,
+        y: i32,
+    }
+
+    #[swift_bridge(swift_repr = "class")]
+    struct StructReprClass {
+        count: u32,
+        name: String,
+    }
+
     extern "Rust" {
         fn test_rust_calls_swift();
 
@@ -29,6 +74,20 @@ mod ffi {
         fn swift_calls_rust_tuple_struct(
             arg: StructReprStructTupleStruct,
         ) -> StructReprStructTupleStruct;
+
+        fn rust_error_catalog_entry(code: u32) -> StructReprStructWithStaticStrField;
+
+        fn swift_calls_rust_struct_with_string_field(
+            arg: StructReprStructWithStringField,
+        ) -> StructReprStructWithStringField;
+
+        fn swift_calls_rust_struct_with_vec_fields(
+            arg: StructReprStructWithVecFields,
+        ) -> StructReprStructWithVecFields;
+
+        fn swift_calls_rust_struct_repr_class(arg: StructReprClass) -> StructReprClass;
+
+        fn swift_calls_rust_reverse_line(line: StructReprStructLine) -> StructReprStructLine;
     }
 
     extern "Swift" {
@@ -37,12 +96,15 @@ mod ffi {
         fn rust_calls_struct_repr_struct_one_primitive_field(
             arg: StructReprStructWithOnePrimitiveField,
         ) -> StructReprStructWithOnePrimitiveField;
+
+        fn rust_calls_struct_repr_class(arg: StructReprClass) -> StructReprClass;
     }
 }
 
 fn test_rust_calls_swift() {
     self::tests::test_rust_calls_swift_struct_with_no_fields();
     self::tests::test_rust_calls_struct_repr_struct_one_primitive_field();
+    self::tests::test_rust_calls_struct_repr_class();
 }
 
 fn swift_calls_rust_struct_with_no_fields(arg: ffi::StructWithNoFields) -> ffi::StructWithNoFields {
@@ -61,6 +123,47 @@ fn swift_calls_rust_tuple_struct(
     arg
 }
 
+static ERROR_CATALOG: [(u32, &str); 2] = [(404, "Not Found"), (500, "Internal Server Error")];
+
+fn rust_error_catalog_entry(code: u32) -> ffi::StructReprStructWithStaticStrField {
+    let message = ERROR_CATALOG
+        .iter()
+        .find(|(entry_code, _)| *entry_code == code)
+        .map(|(_, message)| *message)
+        .unwrap_or("Unknown");
+
+    ffi::StructReprStructWithStaticStrField { code, message }
+}
+
+fn swift_calls_rust_struct_with_string_field(
+    mut arg: ffi::StructReprStructWithStringField,
+) -> ffi::StructReprStructWithStringField {
+    arg.id += 1;
+    arg.name = arg.name.to_uppercase();
+    arg
+}
+
+fn swift_calls_rust_struct_with_vec_fields(
+    mut arg: ffi::StructReprStructWithVecFields,
+) -> ffi::StructReprStructWithVecFields {
+    arg.vertices.reverse();
+    arg.ids.push(arg.vertices.len() as u32);
+    arg
+}
+
+fn swift_calls_rust_struct_repr_class(mut arg: ffi::StructReprClass) -> ffi::StructReprClass {
+    arg.count += 1;
+    arg.name.push_str(" world");
+    arg
+}
+
+fn swift_calls_rust_reverse_line(line: ffi::StructReprStructLine) -> ffi::StructReprStructLine {
+    ffi::StructReprStructLine {
+        start: line.end,
+        end: line.start,
+    }
+}
+
 #[deny(unused)]
 mod tests {
     use super::ffi;
@@ -77,4 +180,16 @@ mod tests {
 
         assert_eq!(val.named_field, 10);
     }
+
+    pub(super) fn test_rust_calls_struct_repr_class() {
+        let arg = ffi::StructReprClass {
+            count: 1,
+            name: "hello".to_string(),
+        };
+
+        let val = ffi::rust_calls_struct_repr_class(arg);
+
+        assert_eq!(val.count, 2);
+        assert_eq!(val.name, "hello");
+    }
 }
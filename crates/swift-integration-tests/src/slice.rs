@@ -1,10 +1,75 @@
-// TODO:
-// - Define struct SliceTestOpaqueRustType
-// - Add a method to create Vec<SliceTestOpaqueRustType>
-// - Add method to reflect &[OpaqueRustType]
-//   - Add test to verify that we can iterate over the slice
-//   - Add test to verify that that we can index into the slice
-//   - Add test to verify that we can get the length of the slice
-// - Create SliceTests.swift
-//   - Add Swift test verifying that we can use an Array<SliceTestOpaqueRustType> as a slice
-//   - Add Swift test verifying that we can use a RustVec<SliceTestOpaqueRustType> as a slice
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_double_values_in_place(values: &mut [u8]);
+        fn rust_slice_len(values: &[u8]) -> usize;
+        fn rust_make_empty_slice() -> &'static [u8];
+    }
+
+    extern "Rust" {
+        type SliceTestOpaqueRustType;
+
+        #[swift_bridge(init)]
+        fn new(val: u32) -> SliceTestOpaqueRustType;
+        fn val(&self) -> u32;
+
+        fn rust_make_slice_of_opaque_rust_types() -> &'static [SliceTestOpaqueRustType];
+        fn rust_sum_slice_of_opaque_rust_types(values: &[SliceTestOpaqueRustType]) -> u32;
+    }
+
+    extern "Swift" {
+        fn swift_fill_slice(buffer: &mut [f32], value: f32);
+    }
+
+    extern "Rust" {
+        fn test_rust_calls_swift_fill_slice();
+    }
+}
+
+fn rust_double_values_in_place(values: &mut [u8]) {
+    for val in values.iter_mut() {
+        *val *= 2;
+    }
+}
+
+fn rust_slice_len(values: &[u8]) -> usize {
+    values.len()
+}
+
+fn rust_make_empty_slice() -> &'static [u8] {
+    &[]
+}
+
+pub struct SliceTestOpaqueRustType(u32);
+
+impl SliceTestOpaqueRustType {
+    fn new(val: u32) -> Self {
+        SliceTestOpaqueRustType(val)
+    }
+
+    fn val(&self) -> u32 {
+        self.0
+    }
+}
+
+static OPAQUE_RUST_TYPES: [SliceTestOpaqueRustType; 3] = [
+    SliceTestOpaqueRustType(1),
+    SliceTestOpaqueRustType(2),
+    SliceTestOpaqueRustType(3),
+];
+
+fn rust_make_slice_of_opaque_rust_types() -> &'static [SliceTestOpaqueRustType] {
+    &OPAQUE_RUST_TYPES
+}
+
+fn rust_sum_slice_of_opaque_rust_types(values: &[SliceTestOpaqueRustType]) -> u32 {
+    values.iter().map(|val| val.0).sum()
+}
+
+fn test_rust_calls_swift_fill_slice() {
+    let mut buffer = [0.0f32; 4];
+
+    ffi::swift_fill_slice(&mut buffer, 1.5);
+
+    assert_eq!(buffer, [1.5; 4]);
+}
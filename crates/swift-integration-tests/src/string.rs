@@ -4,6 +4,8 @@ mod ffi {
         fn run_string_tests();
 
         fn create_string(str: &str) -> String;
+        fn rust_str_byte_len(str: &str) -> usize;
+        fn rust_make_string() -> String;
     }
 
     extern "Swift" {
@@ -26,3 +28,11 @@ fn run_string_tests() {
 fn create_string(str: &str) -> String {
     str.to_string()
 }
+
+fn rust_str_byte_len(str: &str) -> usize {
+    str.len()
+}
+
+fn rust_make_string() -> String {
+    format!("hello {}", '🦀')
+}
@@ -1,3 +1,10 @@
 mod already_declared;
 mod derive;
+mod diffable;
+mod field_default;
+mod instantiate;
+mod layout;
+mod little_endian;
+mod mirror;
+mod sendable;
 mod swift_name;
@@ -25,4 +25,74 @@ mod ffi {
     struct StructDeriveClone3 {
         field: String,
     }
+
+    #[swift_bridge(swift_repr = "struct", derive(Equatable))]
+    struct StructDeriveEquatable {
+        field: u8,
+    }
+
+    #[swift_bridge(swift_repr = "struct", derive(Hashable))]
+    #[derive(Clone)]
+    struct StructDeriveHashable {
+        id: u32,
+        nested: StructDeriveEquatableHashable,
+    }
+
+    #[swift_bridge(swift_repr = "struct", derive(Equatable, Hashable))]
+    #[derive(Clone)]
+    struct StructDeriveEquatableHashable {
+        field: u8,
+    }
+
+    #[swift_bridge(swift_repr = "struct", derive(Equatable, Codable))]
+    struct StructDeriveCodable {
+        id: u32,
+        #[swift_bridge(swift_name = "isVisible")]
+        is_visible: bool,
+    }
+
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[swift_bridge(
+        swift_repr = "struct",
+        derive(Equatable, Codable),
+        serde_json_round_trip
+    )]
+    struct StructDeriveCodableSerde {
+        id: u32,
+        offset: i64,
+        score: f64,
+        #[swift_bridge(swift_name = "isVisible")]
+        is_visible: bool,
+        parent_id: Option<u32>,
+    }
+
+    extern "Rust" {
+        fn rust_count_unique_hashable_structs(values: Vec<StructDeriveHashable>) -> usize;
+        fn rust_equatable_structs_are_equal(
+            lhs: StructDeriveEquatable,
+            rhs: StructDeriveEquatable,
+        ) -> bool;
+        fn rust_make_codable_struct() -> StructDeriveCodable;
+    }
+}
+
+fn rust_count_unique_hashable_structs(values: Vec<ffi::StructDeriveHashable>) -> usize {
+    values
+        .into_iter()
+        .collect::<std::collections::HashSet<_>>()
+        .len()
+}
+
+fn rust_equatable_structs_are_equal(
+    lhs: ffi::StructDeriveEquatable,
+    rhs: ffi::StructDeriveEquatable,
+) -> bool {
+    lhs == rhs
+}
+
+fn rust_make_codable_struct() -> ffi::StructDeriveCodable {
+    ffi::StructDeriveCodable {
+        id: 7,
+        is_visible: true,
+    }
 }
@@ -0,0 +1,25 @@
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct", diffable)]
+    struct DiffableStructTest {
+        count: u32,
+        is_enabled: bool,
+        ratio: f32,
+    }
+
+    extern "Rust" {
+        fn rust_diffable_struct_changed_fields(
+            current: DiffableStructTest,
+            previous: DiffableStructTest,
+        ) -> u64;
+    }
+}
+
+use ffi::DiffableStructTest;
+
+fn rust_diffable_struct_changed_fields(
+    current: DiffableStructTest,
+    previous: DiffableStructTest,
+) -> u64 {
+    current.changed_fields(&previous)
+}
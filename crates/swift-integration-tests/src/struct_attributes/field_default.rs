@@ -0,0 +1,26 @@
+//! Verify that Swift can leave out the fields of a shared struct that have default values.
+
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct")]
+    struct FieldDefaultTestConfig {
+        #[swift_bridge(default = 8080)]
+        port: u16,
+        #[swift_bridge(default = -0.5)]
+        gain: f32,
+        #[swift_bridge(default = true)]
+        verbose: bool,
+        retries: u8,
+    }
+
+    extern "Rust" {
+        fn rust_reflect_field_default_config(arg: FieldDefaultTestConfig)
+            -> FieldDefaultTestConfig;
+    }
+}
+
+fn rust_reflect_field_default_config(
+    arg: ffi::FieldDefaultTestConfig,
+) -> ffi::FieldDefaultTestConfig {
+    arg
+}
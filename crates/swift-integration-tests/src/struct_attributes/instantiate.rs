@@ -0,0 +1,33 @@
+//! Verify that each instantiation of a generic shared struct can be passed between Rust and
+//! Swift.
+
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct", instantiate(u32, f64))]
+    struct GenericPairTest<T> {
+        first: T,
+        second: T,
+        tag: u8,
+    }
+
+    extern "Rust" {
+        fn rust_swap_u32_pair(arg: GenericPairTest<u32>) -> GenericPairTest<u32>;
+        fn rust_swap_f64_pair(arg: GenericPairTest<f64>) -> GenericPairTest<f64>;
+    }
+}
+
+fn rust_swap_u32_pair(arg: ffi::GenericPairTest<u32>) -> ffi::GenericPairTest<u32> {
+    swap(arg)
+}
+
+fn rust_swap_f64_pair(arg: ffi::GenericPairTest<f64>) -> ffi::GenericPairTest<f64> {
+    swap(arg)
+}
+
+fn swap<T>(arg: ffi::GenericPairTest<T>) -> ffi::GenericPairTest<T> {
+    ffi::GenericPairTest {
+        first: arg.second,
+        second: arg.first,
+        tag: arg.tag,
+    }
+}
@@ -0,0 +1,41 @@
+//! Verify that shared structs with a `#[repr(C, align(N))]` or a `#[swift_bridge(packed)]` layout
+//! have the same size and field offsets in Rust and Swift.
+
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct")]
+    #[repr(C, align(16))]
+    struct AlignedStructTest {
+        x: f32,
+        y: f32,
+    }
+
+    #[swift_bridge(swift_repr = "struct", packed)]
+    struct PackedStructTest {
+        tag: u8,
+        value: u32,
+    }
+
+    extern "Rust" {
+        fn rust_reflect_aligned_struct(arg: AlignedStructTest) -> AlignedStructTest;
+        fn rust_reflect_packed_struct(arg: PackedStructTest) -> PackedStructTest;
+        fn rust_aligned_struct_ffi_size() -> usize;
+        fn rust_packed_struct_ffi_size() -> usize;
+    }
+}
+
+fn rust_reflect_aligned_struct(arg: ffi::AlignedStructTest) -> ffi::AlignedStructTest {
+    arg
+}
+
+fn rust_reflect_packed_struct(arg: ffi::PackedStructTest) -> ffi::PackedStructTest {
+    arg
+}
+
+fn rust_aligned_struct_ffi_size() -> usize {
+    std::mem::size_of::<ffi::__swift_bridge__AlignedStructTest>()
+}
+
+fn rust_packed_struct_ffi_size() -> usize {
+    std::mem::size_of::<ffi::__swift_bridge__PackedStructTest>()
+}
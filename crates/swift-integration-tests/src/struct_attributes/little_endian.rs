@@ -0,0 +1,25 @@
+//! Verify that Rust and Swift encode `little_endian` structs as the same bytes.
+
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct", little_endian)]
+    struct LittleEndianStructTest {
+        kind: u8,
+        len: u32,
+        offset: i16,
+        scale: f64,
+    }
+
+    extern "Rust" {
+        fn rust_encode_little_endian_struct(arg: LittleEndianStructTest) -> Vec<u8>;
+        fn rust_decode_little_endian_struct(bytes: Vec<u8>) -> LittleEndianStructTest;
+    }
+}
+
+fn rust_encode_little_endian_struct(arg: ffi::LittleEndianStructTest) -> Vec<u8> {
+    arg.to_le_bytes().to_vec()
+}
+
+fn rust_decode_little_endian_struct(bytes: Vec<u8>) -> ffi::LittleEndianStructTest {
+    ffi::LittleEndianStructTest::from_le_bytes(bytes.try_into().unwrap())
+}
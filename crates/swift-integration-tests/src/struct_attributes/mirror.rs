@@ -0,0 +1,29 @@
+//! Verify that the `#[swift_bridge(mirror = "...")]` attribute generates conversions between a
+//! shared struct and the domain type that it mirrors.
+
+mod models {
+    #[derive(Debug, PartialEq)]
+    pub struct MirrorStructTest {
+        pub name: String,
+        pub count: u32,
+    }
+}
+
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct", mirror = "super::models::MirrorStructTest")]
+    struct MirrorStructTest {
+        name: String,
+        count: u32,
+    }
+
+    extern "Rust" {
+        fn rust_increment_mirrored_struct(arg: MirrorStructTest) -> MirrorStructTest;
+    }
+}
+
+fn rust_increment_mirrored_struct(arg: ffi::MirrorStructTest) -> ffi::MirrorStructTest {
+    let mut model: models::MirrorStructTest = arg.into();
+    model.count += 1;
+    model.into()
+}
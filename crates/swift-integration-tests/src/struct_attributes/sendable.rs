@@ -0,0 +1,16 @@
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct", Sendable)]
+    struct SendableTestStruct {
+        name: String,
+        count: u32,
+    }
+
+    extern "Rust" {
+        fn rust_reflect_sendable_struct(arg: SendableTestStruct) -> SendableTestStruct;
+    }
+}
+
+fn rust_reflect_sendable_struct(arg: ffi::SendableTestStruct) -> ffi::SendableTestStruct {
+    arg
+}
@@ -16,10 +16,21 @@ mod ffi {
     #[swift_bridge(swift_name = "StructRename3", swift_repr = "struct")]
     struct StructName3(u8);
 
+    #[swift_bridge(swift_repr = "struct")]
+    struct StructWithRenamedFields {
+        #[swift_bridge(swift_name = "userId")]
+        user_id: u32,
+        #[swift_bridge(swift_name = "isAdmin")]
+        is_admin: bool,
+    }
+
     extern "Rust" {
         fn extern_rust_struct_rename_1(arg: StructName1) -> StructName1;
         fn extern_rust_struct_rename_2(arg: StructName2) -> StructName2;
         fn extern_rust_struct_rename_3(arg: StructName3) -> StructName3;
+        fn rust_reflect_struct_with_renamed_fields(
+            arg: StructWithRenamedFields,
+        ) -> StructWithRenamedFields;
     }
 
     extern "Swift" {
@@ -29,7 +40,7 @@ mod ffi {
     }
 }
 
-use ffi::{StructName1, StructName2, StructName3};
+use ffi::{StructName1, StructName2, StructName3, StructWithRenamedFields};
 
 fn extern_rust_struct_rename_1(arg: StructName1) -> StructName1 {
     arg
@@ -40,3 +51,10 @@ fn extern_rust_struct_rename_2(arg: StructName2) -> StructName2 {
 fn extern_rust_struct_rename_3(arg: StructName3) -> StructName3 {
     arg
 }
+fn rust_reflect_struct_with_renamed_fields(
+    arg: StructWithRenamedFields,
+) -> StructWithRenamedFields {
+    assert_eq!(arg.user_id, 123);
+    assert!(arg.is_admin);
+    arg
+}
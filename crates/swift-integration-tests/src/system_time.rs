@@ -0,0 +1,52 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct")]
+    struct SystemTimeTestStruct {
+        created_at: std::time::SystemTime,
+    }
+
+    extern "Rust" {
+        fn rust_reflect_system_time(arg: SystemTime) -> SystemTime;
+        fn rust_system_time_add_seconds(arg: SystemTime, secs: u64) -> SystemTime;
+        fn rust_reflect_option_system_time(arg: Option<SystemTime>) -> Option<SystemTime>;
+        fn rust_reflect_system_time_test_struct(arg: SystemTimeTestStruct) -> SystemTimeTestStruct;
+    }
+
+    extern "Swift" {
+        fn swift_reflect_system_time(arg: SystemTime) -> SystemTime;
+    }
+
+    extern "Rust" {
+        fn test_rust_calls_swift_reflect_system_time();
+    }
+}
+
+use ffi::SystemTimeTestStruct;
+
+fn rust_reflect_system_time(arg: SystemTime) -> SystemTime {
+    arg
+}
+
+fn rust_system_time_add_seconds(arg: SystemTime, secs: u64) -> SystemTime {
+    arg + Duration::from_secs(secs)
+}
+
+fn rust_reflect_option_system_time(arg: Option<SystemTime>) -> Option<SystemTime> {
+    arg
+}
+
+fn rust_reflect_system_time_test_struct(arg: SystemTimeTestStruct) -> SystemTimeTestStruct {
+    arg
+}
+
+fn test_rust_calls_swift_reflect_system_time() {
+    for time in [
+        UNIX_EPOCH,
+        UNIX_EPOCH + Duration::from_millis(1_650_000_000_500),
+        UNIX_EPOCH - Duration::from_millis(1500),
+    ] {
+        assert_eq!(ffi::swift_reflect_system_time(time), time);
+    }
+}
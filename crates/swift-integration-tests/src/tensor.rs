@@ -0,0 +1,42 @@
+use swift_bridge::tensor::{Tensor, TensorData};
+
+#[swift_bridge::bridge]
+mod ffi {
+    extern "Rust" {
+        fn rust_make_tensor(rows: usize, columns: usize) -> Tensor;
+        fn rust_tensor_sum(arg: Tensor) -> f64;
+        fn rust_reflect_option_tensor(arg: Option<Tensor>) -> Option<Tensor>;
+    }
+}
+
+/// A row-major `f32` matrix whose elements count up from 0.
+fn rust_make_tensor(rows: usize, columns: usize) -> Tensor {
+    let elements = (0..rows * columns)
+        .map(|idx| idx as f32)
+        .collect::<Vec<_>>();
+    Tensor::new(vec![rows, columns], elements).unwrap()
+}
+
+/// The sum of the elements that the shape and strides point to.
+fn rust_tensor_sum(arg: Tensor) -> f64 {
+    let mut indices = vec![0];
+    for (dim, stride) in arg.shape().iter().zip(arg.strides()) {
+        indices = indices
+            .iter()
+            .flat_map(|start| (0..*dim).map(move |idx| start + idx * stride))
+            .collect();
+    }
+
+    indices
+        .into_iter()
+        .map(|idx| match arg.data() {
+            TensorData::F32(data) => data[idx] as f64,
+            TensorData::F64(data) => data[idx],
+            TensorData::I32(data) => data[idx] as f64,
+        })
+        .sum()
+}
+
+fn rust_reflect_option_tensor(arg: Option<Tensor>) -> Option<Tensor> {
+    arg
+}
@@ -14,6 +14,7 @@ mod ffi {
         #[swift_bridge(init)]
         fn new(val: i32) -> TupleTestOpaqueRustType;
         fn val(&self) -> i32;
+        fn min_max(&self, other: i32) -> (i32, i32);
         fn rust_reflect_tuple_primitives(tuple: (i16, u32)) -> (i16, u32);
         fn rust_reflect_tuple_opaque_rust_and_string_and_primitive(
             tuple: (TupleTestOpaqueRustType, String, u8),
@@ -45,6 +46,9 @@ impl TupleTestOpaqueRustType {
     fn val(&self) -> i32 {
         self.0
     }
+    fn min_max(&self, other: i32) -> (i32, i32) {
+        (self.0.min(other), self.0.max(other))
+    }
 }
 
 fn rust_reflect_tuple_primitives(tuple: (i16, u32)) -> (i16, u32) {
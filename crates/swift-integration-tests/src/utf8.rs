@@ -0,0 +1,16 @@
+#[swift_bridge::bridge]
+#[swift_bridge(utf8 = "lossy")]
+mod ffi {
+    extern "Rust" {
+        fn rust_lossy_str_to_string(arg: &str) -> String;
+        fn rust_lossy_optional_str_to_string(arg: Option<&str>) -> Option<String>;
+    }
+}
+
+fn rust_lossy_str_to_string(arg: &str) -> String {
+    arg.to_string()
+}
+
+fn rust_lossy_optional_str_to_string(arg: Option<&str>) -> Option<String> {
+    arg.map(|arg| arg.to_string())
+}
@@ -0,0 +1,52 @@
+use swift_bridge::uuid::Uuid;
+
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct")]
+    struct UuidTestStruct {
+        id: swift_bridge::uuid::Uuid,
+    }
+
+    extern "Rust" {
+        fn rust_reflect_uuid(arg: Uuid) -> Uuid;
+        fn rust_uuid_string(arg: Uuid) -> String;
+        fn rust_reflect_option_uuid(arg: Option<Uuid>) -> Option<Uuid>;
+        fn rust_reflect_uuid_test_struct(arg: UuidTestStruct) -> UuidTestStruct;
+    }
+
+    extern "Swift" {
+        fn swift_reflect_uuid(arg: Uuid) -> Uuid;
+    }
+
+    extern "Rust" {
+        fn test_rust_calls_swift_reflect_uuid();
+    }
+}
+
+use ffi::UuidTestStruct;
+
+fn rust_reflect_uuid(arg: Uuid) -> Uuid {
+    arg
+}
+
+fn rust_uuid_string(arg: Uuid) -> String {
+    arg.hyphenated().to_string()
+}
+
+fn rust_reflect_option_uuid(arg: Option<Uuid>) -> Option<Uuid> {
+    arg
+}
+
+fn rust_reflect_uuid_test_struct(arg: UuidTestStruct) -> UuidTestStruct {
+    arg
+}
+
+fn test_rust_calls_swift_reflect_uuid() {
+    for uuid in [
+        Uuid::nil(),
+        Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8),
+        Uuid::from_bytes([0xff; 16]),
+    ] {
+        assert_eq!(ffi::swift_reflect_uuid(uuid), uuid);
+    }
+}
@@ -26,6 +26,11 @@ mod ffi {
         ) -> Vec<TransparentEnumInsideVecT>;
     }
 
+    extern "Rust" {
+        fn rust_reflect_vec_of_vec_u8(arg: Vec<Vec<u8>>) -> Vec<Vec<u8>>;
+        fn rust_reflect_vec_of_option_string(arg: Vec<Option<String>>) -> Vec<Option<String>>;
+    }
+
     extern "Rust" {
         fn run_vec_tests();
     }
@@ -74,3 +79,11 @@ fn rust_reflect_vec_transparent_enum(
 ) -> Vec<ffi::TransparentEnumInsideVecT> {
     arg
 }
+
+fn rust_reflect_vec_of_vec_u8(arg: Vec<Vec<u8>>) -> Vec<Vec<u8>> {
+    arg
+}
+
+fn rust_reflect_vec_of_option_string(arg: Vec<Option<String>>) -> Vec<Option<String>> {
+    arg
+}
@@ -0,0 +1,43 @@
+use swift_bridge::watch::Watch;
+
+#[swift_bridge::bridge]
+mod ffi {
+    #[swift_bridge(swift_repr = "struct")]
+    struct WatchTestEvent {
+        sequence: u32,
+        removed: bool,
+    }
+
+    extern "Rust" {
+        fn rust_make_watch(event_count: u32) -> Watch<WatchTestEvent>;
+        fn rust_make_watch_from_notifier(event_count: u32) -> Watch<WatchTestEvent>;
+    }
+}
+
+/// A watch of `event_count` events whose `sequence` is their index.
+fn rust_make_watch(event_count: u32) -> Watch<ffi::WatchTestEvent> {
+    Watch::new((0..event_count).map(|sequence| ffi::WatchTestEvent {
+        sequence,
+        removed: sequence % 2 == 1,
+    }))
+}
+
+/// A watch whose events are sent from a background thread, like a file system watcher's
+/// callback would.
+fn rust_make_watch_from_notifier(event_count: u32) -> Watch<ffi::WatchTestEvent> {
+    let (notifier, watch) = Watch::channel();
+
+    std::thread::spawn(move || {
+        for sequence in 0..event_count {
+            let event = ffi::WatchTestEvent {
+                sequence,
+                removed: false,
+            };
+            if notifier.notify(event).is_err() {
+                return;
+            }
+        }
+    });
+
+    watch
+}
@@ -15,3 +15,34 @@ pub extern "C" fn __swift_bridge__free_boxed_fn_once_no_args_no_return(
         let _ = Box::from_raw(boxed_fn);
     }
 }
+
+/// A Swift closure that was passed to Rust.
+///
+/// Swift retains the closure before passing it to Rust. The closure gets released when this
+/// handle is dropped.
+pub struct SwiftCallback {
+    callback: *mut std::ffi::c_void,
+    free: unsafe extern "C" fn(*mut std::ffi::c_void),
+}
+
+impl SwiftCallback {
+    /// # Safety
+    /// `callback` must be a retained Swift closure and `free` must release it.
+    pub unsafe fn new(
+        callback: *mut std::ffi::c_void,
+        free: unsafe extern "C" fn(*mut std::ffi::c_void),
+    ) -> Self {
+        SwiftCallback { callback, free }
+    }
+
+    /// A pointer to the Swift closure, to be passed to the Swift function that calls it.
+    pub fn as_ptr(&self) -> *mut std::ffi::c_void {
+        self.callback
+    }
+}
+
+impl Drop for SwiftCallback {
+    fn drop(&mut self) {
+        unsafe { (self.free)(self.callback) }
+    }
+}
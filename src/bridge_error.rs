@@ -0,0 +1,182 @@
+//! Errors that the generated FFI glue can run into when it receives a value that it cannot
+//! convert.
+//!
+//! These errors mean that Rust and Swift disagree about a value that crossed the bridge, such as
+//! when a Rust library and the Swift code that uses it were generated from different versions of
+//! a bridge module. The generated code cannot continue after one of these errors, but a handler
+//! can be set in order to log or report the error before the process crashes.
+//!
+//! ```
+//! use swift_bridge::bridge_error::{set_handler, BridgeError};
+//!
+//! fn log_bridge_error(error: &BridgeError) {
+//!     eprintln!("swift-bridge error: {}", error);
+//! }
+//!
+//! set_handler(log_bridge_error);
+//! ```
+
+use std::fmt::{Display, Formatter};
+use std::sync::RwLock;
+
+/// A value that was received over FFI and could not be converted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BridgeError {
+    /// A null pointer was received where a value was expected.
+    NullPointer {
+        /// The type that the pointer should have pointed to.
+        type_name: &'static str,
+    },
+    /// A shared enum was received with a discriminant that does not match any of its variants.
+    InvalidEnumDiscriminant {
+        /// The name of the enum.
+        enum_name: String,
+        /// The discriminant that was received.
+        discriminant: i64,
+    },
+    /// A slice was received with a null start and a non-zero length.
+    NullSlice {
+        /// The length of the slice.
+        len: usize,
+    },
+    /// A string was received that is not valid UTF-8.
+    InvalidUtf8 {
+        /// The byte offset of the first invalid byte.
+        valid_up_to: usize,
+    },
+}
+
+impl Display for BridgeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BridgeError::NullPointer { type_name } => {
+                write!(f, "Received a null pointer to a {}", type_name)
+            }
+            BridgeError::InvalidEnumDiscriminant {
+                enum_name,
+                discriminant,
+            } => {
+                write!(
+                    f,
+                    "Received {} with discriminant {}, which is not one of its variants",
+                    enum_name, discriminant
+                )
+            }
+            BridgeError::NullSlice { len } => {
+                write!(
+                    f,
+                    "Received a slice with a null start and a length of {}",
+                    len
+                )
+            }
+            BridgeError::InvalidUtf8 { valid_up_to } => {
+                write!(
+                    f,
+                    "Received a string that is not valid UTF-8 after byte {}",
+                    valid_up_to
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for BridgeError {}
+
+/// Returned by the `TryFrom<u32>` implementation that `#[swift_bridge(try_from_discriminant)]`
+/// generates for a shared enum, when the discriminant does not match any of the enum's variants.
+///
+/// ```
+/// #[swift_bridge::bridge]
+/// mod ffi {
+///     #[swift_bridge(try_from_discriminant)]
+///     enum Direction {
+///         North = 1,
+///         South = 2,
+///     }
+/// }
+///
+/// use std::convert::TryFrom;
+///
+/// assert!(matches!(ffi::Direction::try_from(2), Ok(ffi::Direction::South)));
+/// assert_eq!(ffi::Direction::try_from(3).err().unwrap().discriminant, 3);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownVariant {
+    /// The name of the enum.
+    pub enum_name: &'static str,
+    /// The discriminant that does not match any of the enum's variants.
+    pub discriminant: u32,
+}
+
+impl Display for UnknownVariant {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} does not have a variant with discriminant {}",
+            self.enum_name, self.discriminant
+        )
+    }
+}
+
+impl std::error::Error for UnknownVariant {}
+
+impl From<UnknownVariant> for BridgeError {
+    fn from(unknown: UnknownVariant) -> Self {
+        BridgeError::InvalidEnumDiscriminant {
+            enum_name: unknown.enum_name.to_string(),
+            discriminant: unknown.discriminant as i64,
+        }
+    }
+}
+
+static HANDLER: RwLock<Option<fn(&BridgeError)>> = RwLock::new(None);
+
+/// Set the function that is called whenever the generated code runs into a [`BridgeError`].
+///
+/// The handler is called right before the process panics (in Rust) or crashes (in Swift), so it
+/// should do no more than log or report the error. It replaces any previously set handler.
+pub fn set_handler(handler: fn(&BridgeError)) {
+    *HANDLER.write().unwrap_or_else(|e| e.into_inner()) = Some(handler);
+}
+
+/// Call the handler, if one is set.
+fn handle(error: &BridgeError) {
+    let handler = *HANDLER.read().unwrap_or_else(|e| e.into_inner());
+    if let Some(handler) = handler {
+        handler(error);
+    }
+}
+
+/// Call the handler and then panic.
+///
+/// Used by Rust code that received a value that it cannot convert.
+#[doc(hidden)]
+#[track_caller]
+pub fn report(error: BridgeError) -> ! {
+    handle(&error);
+    panic!("{}", error)
+}
+
+/// Called by Swift code that received a value that it cannot convert. Swift crashes after this
+/// returns.
+#[allow(non_snake_case)]
+#[export_name = "__swift_bridge__$bridge_error$invalid_enum_discriminant"]
+#[doc(hidden)]
+pub extern "C" fn __swift_bridge__bridge_error_invalid_enum_discriminant(
+    enum_name: crate::string::RustStr,
+    discriminant: i64,
+) {
+    handle(&BridgeError::InvalidEnumDiscriminant {
+        enum_name: enum_name.to_string(),
+        discriminant,
+    });
+}
+
+/// Called by Swift code that received a slice with a null start and a non-zero length. Swift
+/// crashes after this returns.
+#[allow(non_snake_case)]
+#[export_name = "__swift_bridge__$bridge_error$null_slice"]
+#[doc(hidden)]
+pub extern "C" fn __swift_bridge__bridge_error_null_slice(len: usize) {
+    handle(&BridgeError::NullSlice { len });
+}
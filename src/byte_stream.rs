@@ -0,0 +1,175 @@
+//! Large byte payloads that Rust hands to Swift one chunk at a time, so that they never need to be
+//! held in a single contiguous buffer.
+//!
+//! Enabled by the `byte-stream` feature. The Swift side is generated when the build script calls
+//! `swift_bridge_build::GeneratedCode::with_byte_stream_support`.
+//!
+//! ```ignore
+//! use swift_bridge::byte_stream::ByteStream;
+//!
+//! #[swift_bridge::bridge]
+//! mod ffi {
+//!     extern "Rust" {
+//!         // Seen by Swift as a `RustByteStream`.
+//!         fn export_archive() -> ByteStream;
+//!     }
+//! }
+//!
+//! fn export_archive() -> ByteStream {
+//!     let (writer, stream) = ByteStream::channel(4);
+//!     std::thread::spawn(move || {
+//!         for entry in archive_entries() {
+//!             if writer.write(entry.compress()).is_err() {
+//!                 // Swift stopped reading.
+//!                 return;
+//!             }
+//!         }
+//!     });
+//!     stream
+//! }
+//! ```
+//!
+//! ```swift
+//! for await chunk in export_archive() {
+//!     fileHandle.write(chunk)
+//! }
+//! ```
+//!
+//! Swift receives every chunk as a `Data` that points at the bytes that Rust allocated, so the
+//! chunks are not copied.
+//!
+//! The corresponding Swift code can be found in
+//! crates/swift-bridge-build/src/generate_core/byte_stream_support.swift
+
+use std::ffi::c_void;
+use std::sync::mpsc::{sync_channel, SyncSender};
+
+/// A sequence of byte chunks that Swift reads one at a time.
+///
+/// The next chunk is only produced when Swift asks for it.
+pub struct ByteStream {
+    chunks: Box<dyn Iterator<Item = Vec<u8>> + Send>,
+}
+
+/// Writes the chunks of a `ByteStream` that was created with `ByteStream::channel`.
+///
+/// The stream ends once the writer is dropped.
+#[derive(Debug, Clone)]
+pub struct ByteStreamWriter {
+    sender: SyncSender<Vec<u8>>,
+}
+
+/// The `ByteStream` that a `ByteStreamWriter` was writing to has been dropped, so nobody will read
+/// the chunk.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ByteStreamClosed(pub Vec<u8>);
+
+impl std::fmt::Display for ByteStreamClosed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "the byte stream was dropped before the chunk could be written")
+    }
+}
+
+impl std::error::Error for ByteStreamClosed {}
+
+impl ByteStream {
+    /// Create a stream that pulls its chunks from an iterator.
+    ///
+    /// The iterator is advanced on whichever thread Swift reads the stream from.
+    pub fn new<I>(chunks: I) -> Self
+    where
+        I: IntoIterator<Item = Vec<u8>>,
+        I::IntoIter: Send + 'static,
+    {
+        ByteStream {
+            chunks: Box::new(chunks.into_iter()),
+        }
+    }
+
+    /// Create a stream whose chunks are written by a `ByteStreamWriter`, such as from a background
+    /// thread.
+    ///
+    /// At most `capacity` chunks are buffered. Once the buffer is full, `ByteStreamWriter::write`
+    /// blocks until Swift reads a chunk, which keeps the memory that the stream uses bounded.
+    pub fn channel(capacity: usize) -> (ByteStreamWriter, ByteStream) {
+        let (sender, receiver) = sync_channel(capacity);
+        let writer = ByteStreamWriter { sender };
+        let stream = ByteStream::new(receiver);
+
+        (writer, stream)
+    }
+
+    /// The next chunk, or `None` if the stream has ended.
+    pub fn next_chunk(&mut self) -> Option<Vec<u8>> {
+        self.chunks.next()
+    }
+}
+
+impl Iterator for ByteStream {
+    type Item = Vec<u8>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_chunk()
+    }
+}
+
+impl std::fmt::Debug for ByteStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ByteStream").finish_non_exhaustive()
+    }
+}
+
+impl ByteStreamWriter {
+    /// Send a chunk to the stream, blocking while the stream's buffer is full.
+    ///
+    /// Returns the chunk if the `ByteStream` has been dropped.
+    pub fn write(&self, chunk: impl Into<Vec<u8>>) -> Result<(), ByteStreamClosed> {
+        self.sender
+            .send(chunk.into())
+            .map_err(|err| ByteStreamClosed(err.0))
+    }
+}
+
+/// A chunk that Swift reads without copying it.
+///
+/// `chunk` is null once the stream has ended.
+#[repr(C)]
+struct FfiByteChunk {
+    chunk: *mut c_void,
+    start: *const u8,
+    len: usize,
+}
+
+#[export_name = "__swift_bridge__$ByteStream$next"]
+#[allow(non_snake_case)]
+extern "C" fn __swift_bridge__byte_stream_next(stream: *mut ByteStream) -> FfiByteChunk {
+    let stream = unsafe { &mut *stream };
+
+    match stream.next_chunk() {
+        Some(chunk) => {
+            let chunk = Box::new(chunk);
+            FfiByteChunk {
+                start: chunk.as_ptr(),
+                len: chunk.len(),
+                chunk: Box::into_raw(chunk) as *mut c_void,
+            }
+        }
+        None => FfiByteChunk {
+            chunk: std::ptr::null_mut(),
+            start: std::ptr::null(),
+            len: 0,
+        },
+    }
+}
+
+#[export_name = "__swift_bridge__$ByteStream$_free"]
+#[allow(non_snake_case)]
+extern "C" fn __swift_bridge__byte_stream_free(stream: *mut ByteStream) {
+    drop(unsafe { Box::from_raw(stream) });
+}
+
+#[export_name = "__swift_bridge__$ByteChunk$_free"]
+#[allow(non_snake_case)]
+extern "C" fn __swift_bridge__byte_chunk_free(chunk: *mut Vec<u8>) {
+    drop(unsafe { Box::from_raw(chunk) });
+}
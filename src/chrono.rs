@@ -0,0 +1,50 @@
+//! `chrono::DateTime<Utc>`s that Swift sees as a Foundation `Date`.
+//!
+//! Enabled by the `chrono` feature.
+//!
+//! ```ignore
+//! use chrono::{DateTime, Utc};
+//!
+//! #[swift_bridge::bridge]
+//! mod ffi {
+//!     extern "Rust" {
+//!         // Seen by Swift as a `Date`.
+//!         fn last_synced_at() -> Option<DateTime<Utc>>;
+//!         fn set_last_synced_at(time: DateTime<Utc>);
+//!     }
+//! }
+//! ```
+//!
+//! A `DateTime<Utc>` is passed over FFI the same way as a `std::time::SystemTime`, so the Swift
+//! side of the conversion is always generated and no build script support is needed.
+//!
+//! Chrono represents a leap second as a nanosecond count of one second or more. `Date` has no
+//! leap seconds, so Swift sees the last nanosecond of the second before it instead.
+
+pub use ::chrono::{DateTime, Utc};
+
+use crate::system_time::FfiSystemTime;
+
+impl From<DateTime<Utc>> for FfiSystemTime {
+    fn from(time: DateTime<Utc>) -> Self {
+        FfiSystemTime {
+            secs: time.timestamp(),
+            nanos: time.timestamp_subsec_nanos().min(999_999_999),
+        }
+    }
+}
+
+impl From<FfiSystemTime> for DateTime<Utc> {
+    /// Swift can round the nanoseconds up to a full second, which is carried over into the
+    /// seconds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the time is outside of the range that a `DateTime` can represent.
+    fn from(time: FfiSystemTime) -> Self {
+        let secs = time.secs + (time.nanos / 1_000_000_000) as i64;
+        let nanos = time.nanos % 1_000_000_000;
+
+        DateTime::from_timestamp(secs, nanos).expect("Date is out of range for a chrono::DateTime")
+    }
+}
@@ -0,0 +1,13 @@
+//! Used by the code that `#[swift_bridge(compress = "...")]` functions generate.
+//!
+//! The corresponding Swift code can be found in
+//! crates/swift-bridge-build/src/generate_core/compression_support.swift
+
+/// Compress bytes into an LZ4 block that is prefixed with the uncompressed length as a
+/// little-endian `u32`.
+///
+/// Swift takes ownership of the returned `Vec<u8>`.
+pub fn lz4_compress(bytes: &[u8]) -> *mut Vec<u8> {
+    let compressed = lz4_flex::block::compress_prepend_size(bytes);
+    Box::into_raw(Box::new(compressed))
+}
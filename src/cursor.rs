@@ -0,0 +1,159 @@
+//! Rows, such as the results of a database query, that Swift reads lazily while Rust fetches them
+//! in batches.
+//!
+//! Enabled by the `cursor` feature. The Swift side is generated when the build script calls
+//! `swift_bridge_build::GeneratedCode::with_cursor_support`.
+//!
+//! ```ignore
+//! use swift_bridge::cursor::Cursor;
+//!
+//! #[swift_bridge::bridge]
+//! mod ffi {
+//!     #[swift_bridge(swift_repr = "struct")]
+//!     struct Song {
+//!         id: i64,
+//!         title: String,
+//!     }
+//!
+//!     extern "Rust" {
+//!         // Seen by Swift as a `RustCursor<Song>`.
+//!         fn query_songs(sql: &str) -> Cursor<Song>;
+//!     }
+//! }
+//!
+//! fn query_songs(sql: &str) -> Cursor<ffi::Song> {
+//!     let db = database();
+//!     let sql = sql.to_string();
+//!     let mut offset = 0;
+//!
+//!     // Every batch is fetched with its own query once Swift has read the previous batch.
+//!     Cursor::from_batches(std::iter::from_fn(move || {
+//!         let songs = db.fetch_page(&sql, offset, 500);
+//!         offset += songs.len();
+//!         (!songs.is_empty()).then(|| songs)
+//!     }))
+//! }
+//! ```
+//!
+//! ```swift
+//! for song in query_songs("SELECT id, title FROM songs") {
+//!     print(song.title.toString())
+//! }
+//! ```
+//!
+//! Every batch crosses the FFI boundary with a single call, instead of one call per row.
+//!
+//! The corresponding Swift code can be found in
+//! crates/swift-bridge-build/src/generate_core/cursor_support.swift
+
+use std::any::Any;
+use std::ffi::c_void;
+
+/// The number of rows in each batch of a cursor that was created with `Cursor::new`.
+pub const DEFAULT_BATCH_SIZE: usize = 100;
+
+/// A sequence of rows that Swift reads one batch at a time.
+///
+/// The next batch is only fetched when Swift has read every row of the previous batch.
+pub struct Cursor<T> {
+    batches: Box<dyn Iterator<Item = Vec<T>> + Send>,
+}
+
+impl<T: 'static> Cursor<T> {
+    /// Create a cursor that pulls its rows from an iterator, `DEFAULT_BATCH_SIZE` rows at a time.
+    ///
+    /// The iterator is advanced on whichever thread Swift reads the cursor from.
+    pub fn new<I>(rows: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: Send + 'static,
+    {
+        Self::with_batch_size(rows, DEFAULT_BATCH_SIZE)
+    }
+
+    /// Create a cursor that pulls its rows from an iterator, `batch_size` rows at a time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `batch_size` is 0.
+    pub fn with_batch_size<I>(rows: I, batch_size: usize) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: Send + 'static,
+    {
+        assert!(batch_size > 0, "a cursor's batch size must be at least 1");
+
+        let mut rows = rows.into_iter();
+        Self::from_batches(std::iter::from_fn(move || {
+            let batch: Vec<T> = rows.by_ref().take(batch_size).collect();
+            Some(batch)
+        }))
+    }
+
+    /// Create a cursor from an iterator of batches, such as one page of query results at a time.
+    ///
+    /// The cursor ends at the first empty batch.
+    pub fn from_batches<I>(batches: I) -> Self
+    where
+        I: IntoIterator<Item = Vec<T>>,
+        I::IntoIter: Send + 'static,
+    {
+        Cursor {
+            batches: Box::new(batches.into_iter().take_while(|batch| !batch.is_empty())),
+        }
+    }
+
+    /// The next batch of rows, or an empty `Vec` if the cursor has ended.
+    pub fn next_batch(&mut self) -> Vec<T> {
+        self.batches.next().unwrap_or_default()
+    }
+}
+
+impl<T> std::fmt::Debug for Cursor<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Cursor").finish_non_exhaustive()
+    }
+}
+
+/// A batch of rows that Swift reads directly from the `Vec` that Rust allocated.
+///
+/// `batch` is null once the cursor has ended.
+#[doc(hidden)]
+#[repr(C)]
+pub struct FfiCursorBatch {
+    batch: *mut c_void,
+    start: *const c_void,
+    len: usize,
+}
+
+impl FfiCursorBatch {
+    /// Hand the FFI representations of a batch's rows to Swift.
+    ///
+    /// Swift takes ownership of every row, so only the `Vec`'s buffer is freed afterwards.
+    #[doc(hidden)]
+    pub fn new<F: 'static>(rows: Vec<F>) -> Self {
+        if rows.is_empty() {
+            return FfiCursorBatch {
+                batch: std::ptr::null_mut(),
+                start: std::ptr::null(),
+                len: 0,
+            };
+        }
+
+        let start = rows.as_ptr() as *const c_void;
+        let len = rows.len();
+        let rows: Box<dyn Any> = Box::new(rows);
+
+        FfiCursorBatch {
+            batch: Box::into_raw(Box::new(rows)) as *mut c_void,
+            start,
+            len,
+        }
+    }
+}
+
+#[export_name = "__swift_bridge__$CursorBatch$_free"]
+#[allow(non_snake_case)]
+extern "C" fn __swift_bridge__cursor_batch_free(batch: *mut c_void) {
+    drop(unsafe { Box::from_raw(batch as *mut Box<dyn Any>) });
+}
@@ -0,0 +1,173 @@
+//! Format numbers and dates for display using Foundation's locale-aware formatters.
+//!
+//! Enabled by the `formatting` feature. The Swift side of these functions is generated when the
+//! build script calls `swift_bridge_build::GeneratedCode::with_formatting_support`.
+//!
+//! ```ignore
+//! use swift_bridge::formatting::{self, DateStyle, Locale, NumberStyle};
+//!
+//! let german = Locale::Identifier("de_DE");
+//!
+//! assert_eq!(formatting::format_number(1234.5, NumberStyle::Decimal, german), "1.234,5");
+//! assert_eq!(formatting::format_currency(3.5, "EUR", german), "3,50 €");
+//!
+//! // i.e. "15.10.26, 14:30"
+//! let now = formatting::format_date(
+//!     std::time::SystemTime::now(),
+//!     DateStyle::Short,
+//!     DateStyle::Short,
+//!     german,
+//! );
+//! ```
+//!
+//! The corresponding Swift code can be found in
+//! crates/swift-bridge-build/src/generate_core/formatting_support.swift
+
+use crate::string::{RustStr, RustString};
+use crate::system_time::FfiSystemTime;
+use std::ffi::c_void;
+use std::time::SystemTime;
+
+/// The locale that a value is formatted for.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Locale<'a> {
+    /// The user's current locale, `Locale.current`.
+    Current,
+    /// A locale identifier such as `"en_US"` or `"de_DE"`.
+    Identifier(&'a str),
+}
+
+/// How `format_number` formats a number. Same as `NumberFormatter.Style`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum NumberStyle {
+    /// i.e. `1,234.5`
+    Decimal,
+    /// i.e. `25%` for `0.25`
+    Percent,
+    /// i.e. `1.2345E3`
+    Scientific,
+    /// i.e. `one thousand two hundred thirty-four point five`
+    SpellOut,
+    /// i.e. `3rd`
+    Ordinal,
+}
+
+/// How `format_date` formats the date or the time of day. Same as `DateFormatter.Style`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DateStyle {
+    /// Leave the date or the time of day out.
+    None,
+    /// i.e. `10/15/26` or `2:30 PM`
+    Short,
+    /// i.e. `Oct 15, 2026` or `2:30:00 PM`
+    Medium,
+    /// i.e. `October 15, 2026` or `2:30:00 PM UTC`
+    Long,
+    /// i.e. `Thursday, October 15, 2026` or `2:30:00 PM Coordinated Universal Time`
+    Full,
+}
+
+/// Format a number in the given style.
+pub fn format_number(value: f64, style: NumberStyle, locale: Locale) -> String {
+    let string = unsafe {
+        __swift_bridge__formatting_format_number(value, style.to_ffi_repr(), locale.to_ffi_repr())
+    };
+    unsafe { rust_string_from_swift(string) }
+}
+
+/// Format an amount of money in the currency with the given ISO 4217 code, such as `"USD"`.
+pub fn format_currency(value: f64, currency_code: &str, locale: Locale) -> String {
+    let string = unsafe {
+        __swift_bridge__formatting_format_currency(
+            value,
+            RustStr::from_str(currency_code),
+            locale.to_ffi_repr(),
+        )
+    };
+    unsafe { rust_string_from_swift(string) }
+}
+
+/// Format a point in time in the given date and time of day styles, in the user's time zone.
+pub fn format_date(
+    time: SystemTime,
+    date_style: DateStyle,
+    time_style: DateStyle,
+    locale: Locale,
+) -> String {
+    let string = unsafe {
+        __swift_bridge__formatting_format_date(
+            FfiSystemTime::from(time),
+            date_style.to_ffi_repr(),
+            time_style.to_ffi_repr(),
+            locale.to_ffi_repr(),
+        )
+    };
+    unsafe { rust_string_from_swift(string) }
+}
+
+impl Locale<'_> {
+    /// An empty identifier means the current locale.
+    fn to_ffi_repr(self) -> RustStr {
+        match self {
+            Locale::Current => RustStr::from_str(""),
+            Locale::Identifier(identifier) => RustStr::from_str(identifier),
+        }
+    }
+}
+
+impl NumberStyle {
+    fn to_ffi_repr(self) -> u8 {
+        match self {
+            NumberStyle::Decimal => 0,
+            NumberStyle::Percent => 1,
+            NumberStyle::Scientific => 2,
+            NumberStyle::SpellOut => 3,
+            NumberStyle::Ordinal => 4,
+        }
+    }
+}
+
+impl DateStyle {
+    fn to_ffi_repr(self) -> u8 {
+        match self {
+            DateStyle::None => 0,
+            DateStyle::Short => 1,
+            DateStyle::Medium => 2,
+            DateStyle::Long => 3,
+            DateStyle::Full => 4,
+        }
+    }
+}
+
+/// # Safety
+/// The pointer must be a `*mut RustString` that Swift gave up ownership of.
+unsafe fn rust_string_from_swift(string: *mut c_void) -> String {
+    Box::from_raw(string as *mut RustString).0
+}
+
+extern "C" {
+    // Returns a `*mut RustString`.
+    #[link_name = "__swift_bridge__$formatting$format_number"]
+    fn __swift_bridge__formatting_format_number(
+        value: f64,
+        style: u8,
+        locale: RustStr,
+    ) -> *mut c_void;
+
+    // Returns a `*mut RustString`.
+    #[link_name = "__swift_bridge__$formatting$format_currency"]
+    fn __swift_bridge__formatting_format_currency(
+        value: f64,
+        currency_code: RustStr,
+        locale: RustStr,
+    ) -> *mut c_void;
+
+    // Returns a `*mut RustString`.
+    #[link_name = "__swift_bridge__$formatting$format_date"]
+    fn __swift_bridge__formatting_format_date(
+        time: FfiSystemTime,
+        date_style: u8,
+        time_style: u8,
+        locale: RustStr,
+    ) -> *mut c_void;
+}
@@ -0,0 +1,30 @@
+//! Support for opaque Rust types that are annotated with `#[swift_bridge(identity_map)]`.
+
+/// Identifies the Rust object that a value refers to.
+///
+/// When an opaque Rust type that uses `#[swift_bridge(identity_map)]` is passed to Swift, Swift
+/// reuses the existing class instance for any value with the same object identity instead of
+/// creating a new one. This means that Swift's `===` holds for values that Rust considers to be
+/// the same object.
+///
+/// ```
+/// use std::sync::Arc;
+/// use swift_bridge::identity::ObjectIdentity;
+///
+/// struct Document(Arc<String>);
+///
+/// impl ObjectIdentity for Document {
+///     fn object_identity(&self) -> usize {
+///         Arc::as_ptr(&self.0) as usize
+///     }
+/// }
+///
+/// let doc = Document(Arc::new("hello".to_string()));
+/// let same_doc = Document(doc.0.clone());
+/// assert_eq!(doc.object_identity(), same_doc.object_identity());
+/// ```
+pub trait ObjectIdentity {
+    /// Two values with the same identity are seen by Swift as the same class instance, as long as
+    /// they are alive at the same time.
+    fn object_identity(&self) -> usize;
+}
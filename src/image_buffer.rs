@@ -0,0 +1,271 @@
+//! Pixels that Rust renders or processes and Swift displays.
+//!
+//! Enabled by the `image-buffer` feature. The Swift side is generated when the build script calls
+//! `swift_bridge_build::GeneratedCode::with_image_buffer_support`.
+//!
+//! ```ignore
+//! use swift_bridge::image_buffer::{ImageBuffer, PixelFormat};
+//!
+//! #[swift_bridge::bridge]
+//! mod ffi {
+//!     extern "Rust" {
+//!         // Seen by Swift as a `RustImageBuffer`.
+//!         fn render_thumbnail(width: u32, height: u32) -> ImageBuffer;
+//!     }
+//! }
+//!
+//! fn render_thumbnail(width: u32, height: u32) -> ImageBuffer {
+//!     let pixels = vec![255; width as usize * height as usize * 4];
+//!     ImageBuffer::new(width, height, PixelFormat::Rgba8, pixels).unwrap()
+//! }
+//! ```
+//!
+//! ```swift
+//! let thumbnail = render_thumbnail(64, 64)
+//! let image = UIImage(cgImage: thumbnail.makeCGImage()!)
+//! ```
+//!
+//! `RustImageBuffer.makeCGImage` and `RustImageBuffer.makeCVPixelBuffer` point at the pixels
+//! that Rust allocated instead of copying them. The pixels are freed once the `RustImageBuffer`
+//! and every image that was made from it have been released.
+//!
+//! The corresponding Swift code can be found in
+//! crates/swift-bridge-build/src/generate_core/image_buffer_support.swift
+
+use crate::FfiSlice;
+use std::ffi::c_void;
+
+/// An image's pixels along with the metadata needed to interpret them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageBuffer {
+    width: u32,
+    height: u32,
+    bytes_per_row: usize,
+    pixel_format: PixelFormat,
+    bytes: Vec<u8>,
+}
+
+/// How a pixel is laid out in memory.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PixelFormat {
+    /// Red, green, blue and alpha bytes, where the colors are not premultiplied by the alpha.
+    Rgba8,
+    /// Red, green, blue and alpha bytes, where the colors are premultiplied by the alpha.
+    Rgba8Premultiplied,
+    /// Blue, green, red and alpha bytes, where the colors are premultiplied by the alpha.
+    /// This is the format that Core Video and Core Animation prefer.
+    Bgra8Premultiplied,
+    /// A single luminance byte.
+    Gray8,
+}
+
+/// The reason that an `ImageBuffer` could not be created.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ImageBufferError {
+    /// A row cannot hold `width` pixels.
+    BytesPerRowTooSmall {
+        /// The bytes per row that were given.
+        bytes_per_row: usize,
+        /// The bytes that `width` pixels take up.
+        min_bytes_per_row: usize,
+    },
+    /// There are fewer bytes than `bytes_per_row * height`.
+    NotEnoughBytes {
+        /// The number of bytes that were given.
+        len: usize,
+        /// `bytes_per_row * height`
+        expected_len: usize,
+    },
+}
+
+impl std::fmt::Display for ImageBufferError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ImageBufferError::BytesPerRowTooSmall {
+                bytes_per_row,
+                min_bytes_per_row,
+            } => write!(
+                f,
+                "{} bytes per row is less than the {} bytes that a row of pixels takes up",
+                bytes_per_row, min_bytes_per_row
+            ),
+            ImageBufferError::NotEnoughBytes { len, expected_len } => write!(
+                f,
+                "the image needs {} bytes but only {} were provided",
+                expected_len, len
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ImageBufferError {}
+
+impl PixelFormat {
+    /// The number of bytes that a single pixel takes up.
+    pub fn bytes_per_pixel(&self) -> usize {
+        match self {
+            PixelFormat::Rgba8
+            | PixelFormat::Rgba8Premultiplied
+            | PixelFormat::Bgra8Premultiplied => 4,
+            PixelFormat::Gray8 => 1,
+        }
+    }
+
+    fn from_ffi_repr(pixel_format: u8) -> Option<Self> {
+        match pixel_format {
+            0 => Some(PixelFormat::Rgba8),
+            1 => Some(PixelFormat::Rgba8Premultiplied),
+            2 => Some(PixelFormat::Bgra8Premultiplied),
+            3 => Some(PixelFormat::Gray8),
+            _ => None,
+        }
+    }
+
+    fn to_ffi_repr(self) -> u8 {
+        match self {
+            PixelFormat::Rgba8 => 0,
+            PixelFormat::Rgba8Premultiplied => 1,
+            PixelFormat::Bgra8Premultiplied => 2,
+            PixelFormat::Gray8 => 3,
+        }
+    }
+}
+
+impl ImageBuffer {
+    /// Create an image whose rows are tightly packed, i.e. each row is
+    /// `width * pixel_format.bytes_per_pixel()` bytes long.
+    pub fn new(
+        width: u32,
+        height: u32,
+        pixel_format: PixelFormat,
+        bytes: Vec<u8>,
+    ) -> Result<Self, ImageBufferError> {
+        let bytes_per_row = width as usize * pixel_format.bytes_per_pixel();
+        Self::with_bytes_per_row(width, height, bytes_per_row, pixel_format, bytes)
+    }
+
+    /// Create an image whose rows are `bytes_per_row` bytes apart, which can be more than the
+    /// pixels in a row take up in order to keep the rows aligned.
+    pub fn with_bytes_per_row(
+        width: u32,
+        height: u32,
+        bytes_per_row: usize,
+        pixel_format: PixelFormat,
+        bytes: Vec<u8>,
+    ) -> Result<Self, ImageBufferError> {
+        let min_bytes_per_row = width as usize * pixel_format.bytes_per_pixel();
+        if bytes_per_row < min_bytes_per_row {
+            return Err(ImageBufferError::BytesPerRowTooSmall {
+                bytes_per_row,
+                min_bytes_per_row,
+            });
+        }
+
+        let expected_len = bytes_per_row * height as usize;
+        if bytes.len() < expected_len {
+            return Err(ImageBufferError::NotEnoughBytes {
+                len: bytes.len(),
+                expected_len,
+            });
+        }
+
+        Ok(ImageBuffer {
+            width,
+            height,
+            bytes_per_row,
+            pixel_format,
+            bytes,
+        })
+    }
+
+    /// The width of the image, in pixels.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// The height of the image, in pixels.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// The number of bytes from the start of one row to the start of the next.
+    pub fn bytes_per_row(&self) -> usize {
+        self.bytes_per_row
+    }
+
+    /// How each pixel is laid out in memory.
+    pub fn pixel_format(&self) -> PixelFormat {
+        self.pixel_format
+    }
+
+    /// The image's pixels, row by row.
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// The image's pixels, row by row.
+    pub fn bytes_mut(&mut self) -> &mut [u8] {
+        &mut self.bytes
+    }
+
+    /// Take the image's pixels.
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+/// What Swift needs to know about an `ImageBuffer` in order to read its pixels.
+#[repr(C)]
+struct FfiImageBufferInfo {
+    bytes: *const u8,
+    len: usize,
+    width: u32,
+    height: u32,
+    bytes_per_row: usize,
+    pixel_format: u8,
+}
+
+#[export_name = "__swift_bridge__$ImageBuffer$info"]
+#[allow(non_snake_case)]
+extern "C" fn __swift_bridge__image_buffer_info(image: *const ImageBuffer) -> FfiImageBufferInfo {
+    let image = unsafe { &*image };
+
+    FfiImageBufferInfo {
+        bytes: image.bytes.as_ptr(),
+        len: image.bytes.len(),
+        width: image.width,
+        height: image.height,
+        bytes_per_row: image.bytes_per_row,
+        pixel_format: image.pixel_format.to_ffi_repr(),
+    }
+}
+
+/// Copy pixels that Swift created into a new `ImageBuffer`.
+///
+/// Returns null if the metadata does not describe the bytes.
+#[export_name = "__swift_bridge__$ImageBuffer$new"]
+#[allow(non_snake_case)]
+extern "C" fn __swift_bridge__image_buffer_new(
+    width: u32,
+    height: u32,
+    bytes_per_row: usize,
+    pixel_format: u8,
+    bytes: FfiSlice<u8>,
+) -> *mut c_void {
+    let pixel_format = match PixelFormat::from_ffi_repr(pixel_format) {
+        Some(pixel_format) => pixel_format,
+        None => return std::ptr::null_mut(),
+    };
+    let bytes = bytes.as_slice().to_vec();
+
+    match ImageBuffer::with_bytes_per_row(width, height, bytes_per_row, pixel_format, bytes) {
+        Ok(image) => Box::into_raw(Box::new(image)) as *mut c_void,
+        Err(_) => std::ptr::null_mut(),
+    }
+}
+
+#[export_name = "__swift_bridge__$ImageBuffer$_free"]
+#[allow(non_snake_case)]
+extern "C" fn __swift_bridge__image_buffer_free(image: *mut ImageBuffer) {
+    drop(unsafe { Box::from_raw(image) });
+}
@@ -0,0 +1,92 @@
+//! Store secrets in the Apple keychain.
+//!
+//! Enabled by the `keychain` feature. The Swift side of these functions is generated when the
+//! build script calls `swift_bridge_build::GeneratedCode::with_keychain_support`.
+//!
+//! ```ignore
+//! swift_bridge::keychain::set("com.example.app", "api-token", b"secret").unwrap();
+//!
+//! let token = swift_bridge::keychain::get("com.example.app", "api-token");
+//! assert_eq!(token.as_deref(), Some(&b"secret"[..]));
+//! ```
+//!
+//! The corresponding Swift code can be found in
+//! crates/swift-bridge-build/src/generate_core/keychain_support.swift
+
+use crate::string::RustStr;
+use crate::FfiSlice;
+
+/// An error returned by the keychain.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct KeychainError {
+    /// The `OSStatus` that was returned by the Security framework.
+    pub status: i32,
+}
+
+impl std::fmt::Display for KeychainError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "keychain operation failed with OSStatus {}", self.status)
+    }
+}
+
+impl std::error::Error for KeychainError {}
+
+/// Get the secret that is stored for the given service and account.
+///
+/// Returns `None` if there is no such secret or it could not be read.
+pub fn get(service: &str, account: &str) -> Option<Vec<u8>> {
+    let vec = unsafe {
+        __swift_bridge__keychain_get(RustStr::from_str(service), RustStr::from_str(account))
+    };
+
+    if vec.is_null() {
+        None
+    } else {
+        Some(unsafe { *Box::from_raw(vec as *mut Vec<u8>) })
+    }
+}
+
+/// Store a secret for the given service and account, replacing any existing secret.
+pub fn set(service: &str, account: &str, value: &[u8]) -> Result<(), KeychainError> {
+    let status = unsafe {
+        __swift_bridge__keychain_set(
+            RustStr::from_str(service),
+            RustStr::from_str(account),
+            FfiSlice::from_slice(value),
+        )
+    };
+    status_to_result(status)
+}
+
+/// Delete the secret that is stored for the given service and account.
+///
+/// Deleting a secret that does not exist is not an error.
+pub fn delete(service: &str, account: &str) -> Result<(), KeychainError> {
+    let status = unsafe {
+        __swift_bridge__keychain_delete(RustStr::from_str(service), RustStr::from_str(account))
+    };
+    status_to_result(status)
+}
+
+fn status_to_result(status: i32) -> Result<(), KeychainError> {
+    // errSecSuccess
+    if status == 0 {
+        Ok(())
+    } else {
+        Err(KeychainError { status })
+    }
+}
+
+extern "C" {
+    #[link_name = "__swift_bridge__$keychain$get"]
+    // Returns a `*mut Vec<u8>` that was created on the Swift side using `RustVec<UInt8>()`, or
+    // null if the secret could not be found.
+    fn __swift_bridge__keychain_get(service: RustStr, account: RustStr) -> *mut std::ffi::c_void;
+
+    #[link_name = "__swift_bridge__$keychain$set"]
+    fn __swift_bridge__keychain_set(service: RustStr, account: RustStr, value: FfiSlice<u8>)
+        -> i32;
+
+    #[link_name = "__swift_bridge__$keychain$delete"]
+    fn __swift_bridge__keychain_delete(service: RustStr, account: RustStr) -> i32;
+}
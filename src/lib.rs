@@ -2,22 +2,116 @@
 
 #![deny(missing_docs)]
 
-pub use swift_bridge_macro::bridge;
+pub use swift_bridge_macro::{bridge, bridged, export};
 
 mod std_bridge;
 
-pub use self::std_bridge::{option, result, string};
+pub use self::std_bridge::{btree_map, duration, net, option, range, result, string, system_time};
 
 #[doc(hidden)]
 #[cfg(feature = "async")]
 pub mod async_support;
 
+pub mod bridge_error;
+
 #[doc(hidden)]
 pub mod boxed_fn_support;
 
+#[doc(hidden)]
+#[cfg(feature = "compression")]
+pub mod compression_support;
+
 #[doc(hidden)]
 pub mod copy_support;
 
+pub mod identity;
+
+#[doc(hidden)]
+pub mod module_table;
+
+pub mod reflection;
+
+#[doc(hidden)]
+pub mod sendable_support;
+
+#[cfg(feature = "byte-stream")]
+pub mod byte_stream;
+
+#[cfg(feature = "chrono")]
+pub mod chrono;
+
+#[cfg(feature = "cursor")]
+pub mod cursor;
+
+#[cfg(feature = "formatting")]
+pub mod formatting;
+
+#[cfg(feature = "image-buffer")]
+pub mod image_buffer;
+
+#[cfg(feature = "keychain")]
+pub mod keychain;
+
+#[cfg(feature = "measurement")]
+pub mod measurement;
+
+#[cfg(feature = "pixel-buffer")]
+pub mod pixel_buffer;
+
+#[cfg(feature = "reachability")]
+pub mod reachability;
+
+#[cfg(feature = "serde-json")]
+pub mod serde_json;
+
+/// Used by the code that `#[swift_bridge(serde_json_round_trip)]` generates.
+#[doc(hidden)]
+#[cfg(feature = "serde-json")]
+#[macro_export]
+macro_rules! __serde_json_round_trip {
+    ($ty:ty, $json:expr) => {
+        unsafe { $crate::serde_json::round_trip::<$ty>($json) }
+    };
+}
+
+/// Used by the code that `#[swift_bridge(serde_json_round_trip)]` generates.
+#[doc(hidden)]
+#[cfg(not(feature = "serde-json"))]
+#[macro_export]
+macro_rules! __serde_json_round_trip {
+    ($ty:ty, $json:expr) => {
+        compile_error!(
+            "`#[swift_bridge(serde_json_round_trip)]` requires the swift-bridge crate's `serde-json` feature."
+        )
+    };
+}
+
+#[cfg(feature = "tensor")]
+pub mod tensor;
+
+#[cfg(feature = "time")]
+pub mod time;
+
+#[cfg(feature = "url-session")]
+pub mod url_session;
+
+#[cfg(feature = "user-defaults")]
+pub mod user_defaults;
+
+#[cfg(feature = "uuid")]
+pub mod uuid;
+
+#[cfg(feature = "watch")]
+pub mod watch;
+
+#[doc(hidden)]
+#[repr(C)]
+pub struct FfiArray<T, const N: usize>(pub [T; N]);
+
+/// A `&[T]` or `&mut [T]`.
+///
+/// When `len` is 0 the slice is empty and `start` can be null or dangling. A null `start` with a
+/// non-zero `len` is never valid, and is reported as a [`bridge_error::BridgeError::NullSlice`].
 #[doc(hidden)]
 #[repr(C)]
 pub struct FfiSlice<T> {
@@ -45,8 +139,152 @@ impl<T> FfiSlice<T> {
 
     /// Get a reference to the slice that this FfiSlice points to.
     pub fn as_slice(&self) -> &'static [T] {
-        unsafe { std::slice::from_raw_parts(self.start, self.len) }
+        if self.len == 0 {
+            return &[];
+        }
+        unsafe { std::slice::from_raw_parts(self.checked_start(), self.len) }
+    }
+
+    /// Create an FfiSlice from a mutable slice.
+    pub fn from_mut_slice(slice: &mut [T]) -> Self {
+        FfiSlice {
+            start: slice.as_mut_ptr(),
+            len: slice.len(),
+        }
     }
+
+    /// Get a mutable reference to the slice that this FfiSlice points to.
+    ///
+    /// The FfiSlice must have been created from a mutable buffer.
+    pub fn as_mut_slice(self) -> &'static mut [T] {
+        if self.len == 0 {
+            return &mut [];
+        }
+        unsafe { std::slice::from_raw_parts_mut(self.checked_start() as *mut T, self.len) }
+    }
+
+    /// The start of a non-empty slice, which must not be null.
+    #[track_caller]
+    fn checked_start(&self) -> *const T {
+        if self.start.is_null() {
+            bridge_error::report(bridge_error::BridgeError::NullSlice { len: self.len });
+        }
+        self.start
+    }
+}
+
+/// An `Option<&[T]>` or `Option<&mut [T]>`.
+///
+/// Swift gives an empty buffer a null start, so `is_some` is what tells `None` apart from an
+/// empty slice.
+#[doc(hidden)]
+#[repr(C)]
+pub struct OptionFfiSlice<T> {
+    pub val: FfiSlice<T>,
+    pub is_some: bool,
+}
+
+impl<T> OptionFfiSlice<T> {
+    /// Create an OptionFfiSlice from an optional slice.
+    pub fn from_slice(slice: Option<&[T]>) -> Self {
+        match slice {
+            Some(slice) => OptionFfiSlice {
+                val: FfiSlice::from_slice(slice),
+                is_some: true,
+            },
+            None => Self::none(),
+        }
+    }
+
+    /// Create an OptionFfiSlice from an optional mutable slice.
+    pub fn from_mut_slice(slice: Option<&mut [T]>) -> Self {
+        match slice {
+            Some(slice) => OptionFfiSlice {
+                val: FfiSlice::from_mut_slice(slice),
+                is_some: true,
+            },
+            None => Self::none(),
+        }
+    }
+
+    /// Get a reference to the slice that this OptionFfiSlice points to, if any.
+    pub fn as_slice(&self) -> Option<&'static [T]> {
+        if self.is_some {
+            Some(self.val.as_slice())
+        } else {
+            None
+        }
+    }
+
+    /// Get a mutable reference to the slice that this OptionFfiSlice points to, if any.
+    ///
+    /// The OptionFfiSlice must have been created from a mutable buffer.
+    pub fn as_mut_slice(self) -> Option<&'static mut [T]> {
+        if self.is_some {
+            Some(self.val.as_mut_slice())
+        } else {
+            None
+        }
+    }
+
+    fn none() -> Self {
+        OptionFfiSlice {
+            val: FfiSlice {
+                start: std::ptr::null(),
+                len: 0,
+            },
+            is_some: false,
+        }
+    }
+}
+
+// `&[OpaqueRustType]` is passed over FFI as an `FfiSlice<*const c_void>`.
+//
+// When Rust returns a slice of opaque types to Swift it allocates an array of pointers to the
+// slice's elements, which Swift wraps in non-owning `SomeTypeRef`s and then frees using
+// `__swift_bridge__$pointer_slice$free`.
+//
+// When Swift passes a slice of opaque types to Rust it passes the buffer of a `RustVec<SomeType>`,
+// so the FfiSlice points directly at the elements.
+impl FfiSlice<*const std::ffi::c_void> {
+    /// Create an array of pointers to each of the slice's elements.
+    pub fn from_opaque_slice<T>(slice: &[T]) -> Self {
+        let pointers: Box<[*const std::ffi::c_void]> = slice
+            .iter()
+            .map(|element| element as *const T as *const std::ffi::c_void)
+            .collect();
+        let len = pointers.len();
+
+        FfiSlice {
+            start: Box::into_raw(pointers) as *const *const std::ffi::c_void,
+            len,
+        }
+    }
+
+    /// Get a reference to the contiguous elements that this FfiSlice points to.
+    pub fn as_opaque_slice<T>(&self) -> &'static [T] {
+        if self.len == 0 {
+            return &[];
+        }
+        unsafe { std::slice::from_raw_parts(self.checked_start() as *const T, self.len) }
+    }
+
+    /// Get a mutable reference to the contiguous elements that this FfiSlice points to.
+    pub fn as_opaque_mut_slice<T>(self) -> &'static mut [T] {
+        if self.len == 0 {
+            return &mut [];
+        }
+        unsafe { std::slice::from_raw_parts_mut(self.checked_start() as *mut T, self.len) }
+    }
+}
+
+#[export_name = "__swift_bridge__$pointer_slice$free"]
+#[allow(non_snake_case)]
+#[doc(hidden)]
+pub extern "C" fn __swift_bridge__pointer_slice_free(slice: FfiSlice<*const std::ffi::c_void>) {
+    let pointers =
+        std::ptr::slice_from_raw_parts_mut(slice.start as *mut *const std::ffi::c_void, slice.len);
+    drop(unsafe { Box::from_raw(pointers) });
 }
 
 // The code generation automatically implements this for all shared structs.
@@ -0,0 +1,242 @@
+//! Physical quantities that Swift sees as a Foundation `Measurement`.
+//!
+//! Enabled by the `measurement` feature. The Swift side of the conversions is generated when the
+//! build script calls `swift_bridge_build::GeneratedCode::with_measurement_support`.
+//!
+//! ```ignore
+//! use swift_bridge::measurement::{Length, LengthUnit};
+//!
+//! #[swift_bridge::bridge]
+//! mod ffi {
+//!     extern "Rust" {
+//!         // Seen by Swift as a `Measurement<UnitLength>`.
+//!         fn distance_walked() -> Length;
+//!     }
+//! }
+//!
+//! fn distance_walked() -> Length {
+//!     Length::new(2.5, LengthUnit::Kilometers)
+//! }
+//! ```
+//!
+//! A measurement keeps the unit that it was created with, so Swift receives
+//! `Measurement(value: 2.5, unit: UnitLength.kilometers)` and can format it as such.
+//!
+//! A Swift `Measurement` whose unit has no corresponding Rust unit, such as `UnitLength.furlongs`,
+//! is converted to the quantity's base unit before it is passed to Rust.
+//!
+//! The corresponding Swift code can be found in
+//! crates/swift-bridge-build/src/generate_core/measurement_support.swift
+
+macro_rules! measurement {
+    (
+        $(#[$quantity_doc:meta])*
+        $quantity:ident,
+        $(#[$unit_doc:meta])*
+        $unit_enum:ident,
+        $foundation_unit:literal,
+        [$(
+            $(#[$variant_doc:meta])*
+            $variant:ident = $discriminant:literal, coefficient: $coefficient:expr, constant: $constant:expr
+        ),+ $(,)?]
+    ) => {
+        $(#[$quantity_doc])*
+        #[derive(Debug, Copy, Clone, PartialEq)]
+        pub struct $quantity {
+            value: f64,
+            unit: $unit_enum,
+        }
+
+        $(#[$unit_doc])*
+        #[doc = concat!("\n\nEach unit corresponds to a `", $foundation_unit, "`.")]
+        #[allow(missing_docs)]
+        #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+        pub enum $unit_enum {
+            $(
+                $(#[$variant_doc])*
+                $variant,
+            )+
+        }
+
+        impl $quantity {
+            /// Create a measurement of `value` in the given unit.
+            pub fn new(value: f64, unit: $unit_enum) -> Self {
+                $quantity { value, unit }
+            }
+
+            /// The measured value, in the measurement's unit.
+            pub fn value(&self) -> f64 {
+                self.value
+            }
+
+            /// The unit that the measurement was created with.
+            pub fn unit(&self) -> $unit_enum {
+                self.unit
+            }
+
+            /// The same measurement expressed in a different unit.
+            pub fn converted_to(&self, unit: $unit_enum) -> Self {
+                let base = self.value * self.unit.coefficient() + self.unit.constant();
+                $quantity {
+                    value: (base - unit.constant()) / unit.coefficient(),
+                    unit,
+                }
+            }
+
+            /// The measured value expressed in the given unit.
+            pub fn value_in(&self, unit: $unit_enum) -> f64 {
+                self.converted_to(unit).value
+            }
+        }
+
+        impl $unit_enum {
+            /// Same as the coefficient of the Foundation unit's `UnitConverterLinear`.
+            fn coefficient(&self) -> f64 {
+                match self {
+                    $($unit_enum::$variant => $coefficient,)+
+                }
+            }
+
+            /// Same as the constant of the Foundation unit's `UnitConverterLinear`.
+            fn constant(&self) -> f64 {
+                match self {
+                    $($unit_enum::$variant => $constant,)+
+                }
+            }
+
+            fn from_ffi_repr(unit: u8) -> Option<Self> {
+                match unit {
+                    $($discriminant => Some($unit_enum::$variant),)+
+                    _ => None,
+                }
+            }
+
+            fn to_ffi_repr(self) -> u8 {
+                match self {
+                    $($unit_enum::$variant => $discriminant,)+
+                }
+            }
+        }
+
+        impl From<$quantity> for FfiMeasurement {
+            fn from(measurement: $quantity) -> Self {
+                FfiMeasurement {
+                    value: measurement.value,
+                    unit: measurement.unit.to_ffi_repr(),
+                }
+            }
+        }
+
+        impl From<FfiMeasurement> for $quantity {
+            /// Swift only sends units that it got from the same list of units, so an unknown unit
+            /// means that the generated Swift code is out of date.
+            fn from(measurement: FfiMeasurement) -> Self {
+                let unit = $unit_enum::from_ffi_repr(measurement.unit).unwrap_or_else(|| {
+                    panic!(
+                        "Unknown {} unit {}. Regenerate the Swift code.",
+                        stringify!($quantity),
+                        measurement.unit
+                    )
+                });
+                $quantity::new(measurement.value, unit)
+            }
+        }
+    };
+}
+
+measurement!(
+    /// A length, seen by Swift as a `Measurement<UnitLength>`.
+    Length,
+    /// A unit of length.
+    LengthUnit,
+    "UnitLength",
+    [
+        /// The base unit.
+        Meters = 0, coefficient: 1.0, constant: 0.0,
+        Kilometers = 1, coefficient: 1000.0, constant: 0.0,
+        Centimeters = 2, coefficient: 0.01, constant: 0.0,
+        Millimeters = 3, coefficient: 0.001, constant: 0.0,
+        Miles = 4, coefficient: 1609.344, constant: 0.0,
+        Yards = 5, coefficient: 0.9144, constant: 0.0,
+        Feet = 6, coefficient: 0.3048, constant: 0.0,
+        Inches = 7, coefficient: 0.0254, constant: 0.0,
+    ]
+);
+
+measurement!(
+    /// A mass, seen by Swift as a `Measurement<UnitMass>`.
+    Mass,
+    /// A unit of mass.
+    MassUnit,
+    "UnitMass",
+    [
+        /// The base unit.
+        Kilograms = 0, coefficient: 1.0, constant: 0.0,
+        Grams = 1, coefficient: 0.001, constant: 0.0,
+        Milligrams = 2, coefficient: 0.000001, constant: 0.0,
+        Pounds = 3, coefficient: 0.45359237, constant: 0.0,
+        Ounces = 4, coefficient: 0.028349523125, constant: 0.0,
+    ]
+);
+
+measurement!(
+    /// A temperature, seen by Swift as a `Measurement<UnitTemperature>`.
+    Temperature,
+    /// A unit of temperature.
+    TemperatureUnit,
+    "UnitTemperature",
+    [
+        /// The base unit.
+        Kelvin = 0, coefficient: 1.0, constant: 0.0,
+        Celsius = 1, coefficient: 1.0, constant: 273.15,
+        Fahrenheit = 2, coefficient: 5.0 / 9.0, constant: 459.67 * 5.0 / 9.0,
+    ]
+);
+
+measurement!(
+    /// An amount of energy, seen by Swift as a `Measurement<UnitEnergy>`.
+    Energy,
+    /// A unit of energy.
+    EnergyUnit,
+    "UnitEnergy",
+    [
+        /// The base unit.
+        Joules = 0, coefficient: 1.0, constant: 0.0,
+        Kilojoules = 1, coefficient: 1000.0, constant: 0.0,
+        /// Food calories.
+        Kilocalories = 2, coefficient: 4184.0, constant: 0.0,
+        KilowattHours = 3, coefficient: 3_600_000.0, constant: 0.0,
+    ]
+);
+
+measurement!(
+    /// A speed, seen by Swift as a `Measurement<UnitSpeed>`.
+    Speed,
+    /// A unit of speed.
+    SpeedUnit,
+    "UnitSpeed",
+    [
+        /// The base unit.
+        MetersPerSecond = 0, coefficient: 1.0, constant: 0.0,
+        KilometersPerHour = 1, coefficient: 1.0 / 3.6, constant: 0.0,
+        MilesPerHour = 2, coefficient: 0.44704, constant: 0.0,
+        Knots = 3, coefficient: 1852.0 / 3600.0, constant: 0.0,
+    ]
+);
+
+/// A measurement that is passed over FFI as its value and the index of its unit in the
+/// quantity's list of units.
+#[doc(hidden)]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct FfiMeasurement {
+    pub value: f64,
+    pub unit: u8,
+}
+
+#[repr(C)]
+#[doc(hidden)]
+pub struct OptionMeasurement {
+    pub val: FfiMeasurement,
+    pub is_some: bool,
+}
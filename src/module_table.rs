@@ -0,0 +1,28 @@
+//! Support for bridge modules annotated with `#[swift_bridge(module_table = "...")]`.
+//!
+//! The corresponding C and Swift code can be found in
+//! crates/swift-bridge-build/src/generate_core/module_table.swift
+
+use std::ffi::c_void;
+use std::os::raw::c_char;
+
+/// The name and address of one of a bridge module's exported functions.
+#[repr(C)]
+pub struct ModuleTableEntry {
+    /// The function's nul terminated symbol name, such as `__swift_bridge__$some_function`.
+    pub name: *const c_char,
+    /// A pointer to the function.
+    pub symbol: *const c_void,
+}
+
+// The entries only point to string literals and functions, which live for the life of the program.
+unsafe impl Sync for ModuleTableEntry {}
+
+/// All of a bridge module's exported functions.
+#[repr(C)]
+pub struct ModuleTable {
+    /// A pointer to the first entry.
+    pub entries: *const ModuleTableEntry,
+    /// The number of entries.
+    pub len: usize,
+}
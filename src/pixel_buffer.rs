@@ -0,0 +1,359 @@
+//! Video frames that Swift captured or decoded and Rust processes.
+//!
+//! Enabled by the `pixel-buffer` feature. The Swift side is generated when the build script calls
+//! `swift_bridge_build::GeneratedCode::with_pixel_buffer_support`.
+//!
+//! ```ignore
+//! use swift_bridge::pixel_buffer::PixelBuffer;
+//!
+//! #[swift_bridge::bridge]
+//! mod ffi {
+//!     extern "Rust" {
+//!         // Seen by Swift as a `CVPixelBuffer`.
+//!         fn average_luma(frame: PixelBuffer) -> f64;
+//!     }
+//! }
+//!
+//! fn average_luma(frame: PixelBuffer) -> f64 {
+//!     let lock = frame.lock().unwrap();
+//!     let luma = lock.plane(0).unwrap();
+//!
+//!     let sum: u64 = luma.bytes().iter().map(|b| *b as u64).sum();
+//!     sum as f64 / luma.bytes().len() as f64
+//! }
+//! ```
+//!
+//! ```swift
+//! func captureOutput(_ output: AVCaptureOutput, didOutput sampleBuffer: CMSampleBuffer, from connection: AVCaptureConnection) {
+//!     let luma = average_luma(CMSampleBufferGetImageBuffer(sampleBuffer)!)
+//! }
+//! ```
+//!
+//! A `PixelBuffer` holds a reference to the `CVPixelBuffer`, so the pixels are never copied.
+//!
+//! The corresponding Swift code can be found in
+//! crates/swift-bridge-build/src/generate_core/pixel_buffer_support.swift
+
+use std::ffi::c_void;
+use std::ptr::NonNull;
+
+/// A reference to a Core Video `CVPixelBuffer`.
+///
+/// Cloning a `PixelBuffer` retains the `CVPixelBuffer` and dropping it releases it.
+#[derive(Debug)]
+pub struct PixelBuffer {
+    ptr: NonNull<c_void>,
+}
+
+// Core Video's reference counting and base address locking are thread safe.
+unsafe impl Send for PixelBuffer {}
+unsafe impl Sync for PixelBuffer {}
+
+/// The base address of a `PixelBuffer` is locked while this is alive, which lets the CPU read the
+/// pixels.
+#[derive(Debug)]
+pub struct PixelBufferLock<'a> {
+    buffer: &'a PixelBuffer,
+    read_only: bool,
+}
+
+/// The base address of a `PixelBuffer` is locked while this is alive, which lets the CPU read and
+/// write the pixels.
+#[derive(Debug)]
+pub struct PixelBufferLockMut<'a> {
+    lock: PixelBufferLock<'a>,
+}
+
+/// One plane of a locked `PixelBuffer`, such as the luma plane of a bi-planar YCbCr frame.
+#[derive(Debug)]
+pub struct Plane<'a> {
+    bytes: &'a [u8],
+    width: usize,
+    height: usize,
+    bytes_per_row: usize,
+}
+
+/// One plane of a `PixelBuffer` that was locked for writing.
+#[derive(Debug)]
+pub struct PlaneMut<'a> {
+    bytes: &'a mut [u8],
+    width: usize,
+    height: usize,
+    bytes_per_row: usize,
+}
+
+/// Core Video could not lock a `PixelBuffer`'s base address.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct LockError {
+    status: i32,
+}
+
+impl PixelBuffer {
+    /// Take ownership of a `CVPixelBufferRef` that has already been retained, such as one that
+    /// was returned by `CVPixelBufferCreate`.
+    ///
+    /// # Safety
+    /// `ptr` must be a non-null `CVPixelBufferRef`, and the caller must own one of its retains.
+    pub unsafe fn from_retained_ptr(ptr: *mut c_void) -> Self {
+        PixelBuffer {
+            ptr: NonNull::new(ptr).expect("null CVPixelBufferRef"),
+        }
+    }
+
+    /// Retain a `CVPixelBufferRef` that is owned by someone else.
+    ///
+    /// # Safety
+    /// `ptr` must be a non-null `CVPixelBufferRef`.
+    pub unsafe fn from_unretained_ptr(ptr: *mut c_void) -> Self {
+        __swift_bridge__pixel_buffer_retain(ptr);
+        Self::from_retained_ptr(ptr)
+    }
+
+    /// Give up ownership of the `CVPixelBufferRef` without releasing it.
+    pub fn into_retained_ptr(self) -> *mut c_void {
+        let ptr = self.ptr.as_ptr();
+        std::mem::forget(self);
+        ptr
+    }
+
+    /// The `CVPixelBufferRef`, which is only guaranteed to be valid for as long as `self` is.
+    pub fn as_ptr(&self) -> *mut c_void {
+        self.ptr.as_ptr()
+    }
+
+    /// The width of the frame, in pixels.
+    pub fn width(&self) -> usize {
+        self.info().width
+    }
+
+    /// The height of the frame, in pixels.
+    pub fn height(&self) -> usize {
+        self.info().height
+    }
+
+    /// The frame's four character pixel format code, such as
+    /// `kCVPixelFormatType_32BGRA` (`u32::from_be_bytes(*b"BGRA")`).
+    pub fn pixel_format(&self) -> u32 {
+        self.info().pixel_format
+    }
+
+    /// The number of planes that the frame's pixels are split into.
+    ///
+    /// A frame that is not planar, such as a BGRA frame, has a single plane.
+    pub fn plane_count(&self) -> usize {
+        self.info().plane_count.max(1)
+    }
+
+    /// Lock the base address so that the pixels can be read.
+    pub fn lock(&self) -> Result<PixelBufferLock<'_>, LockError> {
+        self.lock_base_address(true)
+    }
+
+    /// Lock the base address so that the pixels can be read and written.
+    pub fn lock_mut(&mut self) -> Result<PixelBufferLockMut<'_>, LockError> {
+        let lock = self.lock_base_address(false)?;
+        Ok(PixelBufferLockMut { lock })
+    }
+
+    fn lock_base_address(&self, read_only: bool) -> Result<PixelBufferLock<'_>, LockError> {
+        let status = unsafe { __swift_bridge__pixel_buffer_lock(self.as_ptr(), read_only) };
+        if status != 0 {
+            return Err(LockError { status });
+        }
+
+        Ok(PixelBufferLock {
+            buffer: self,
+            read_only,
+        })
+    }
+
+    fn info(&self) -> FfiPixelBufferInfo {
+        unsafe { __swift_bridge__pixel_buffer_info(self.as_ptr()) }
+    }
+}
+
+impl Clone for PixelBuffer {
+    fn clone(&self) -> Self {
+        unsafe { Self::from_unretained_ptr(self.as_ptr()) }
+    }
+}
+
+impl Drop for PixelBuffer {
+    fn drop(&mut self) {
+        unsafe { __swift_bridge__pixel_buffer_release(self.as_ptr()) }
+    }
+}
+
+impl<'a> PixelBufferLock<'a> {
+    /// The number of planes that the frame's pixels are split into.
+    pub fn plane_count(&self) -> usize {
+        self.buffer.plane_count()
+    }
+
+    /// The plane at the given index, or `None` if the frame has fewer planes.
+    pub fn plane(&self, index: usize) -> Option<Plane<'_>> {
+        let plane = self.ffi_plane(index)?;
+
+        Some(Plane {
+            bytes: unsafe { std::slice::from_raw_parts(plane.base_address, plane.len()) },
+            width: plane.width,
+            height: plane.height,
+            bytes_per_row: plane.bytes_per_row,
+        })
+    }
+
+    fn ffi_plane(&self, index: usize) -> Option<FfiPixelBufferPlane> {
+        if index >= self.plane_count() {
+            return None;
+        }
+
+        let plane = unsafe { __swift_bridge__pixel_buffer_plane(self.buffer.as_ptr(), index) };
+        if plane.base_address.is_null() {
+            return None;
+        }
+
+        Some(plane)
+    }
+}
+
+impl Drop for PixelBufferLock<'_> {
+    fn drop(&mut self) {
+        unsafe { __swift_bridge__pixel_buffer_unlock(self.buffer.as_ptr(), self.read_only) };
+    }
+}
+
+impl<'a> PixelBufferLockMut<'a> {
+    /// The plane at the given index, or `None` if the frame has fewer planes.
+    pub fn plane_mut(&mut self, index: usize) -> Option<PlaneMut<'_>> {
+        let plane = self.lock.ffi_plane(index)?;
+
+        Some(PlaneMut {
+            bytes: unsafe { std::slice::from_raw_parts_mut(plane.base_address, plane.len()) },
+            width: plane.width,
+            height: plane.height,
+            bytes_per_row: plane.bytes_per_row,
+        })
+    }
+}
+
+impl<'a> std::ops::Deref for PixelBufferLockMut<'a> {
+    type Target = PixelBufferLock<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.lock
+    }
+}
+
+impl<'a> Plane<'a> {
+    /// The plane's pixels, row by row. Each row is `bytes_per_row` bytes long.
+    pub fn bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// The width of the plane, in pixels.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The height of the plane, in pixels.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The number of bytes from the start of one row to the start of the next.
+    pub fn bytes_per_row(&self) -> usize {
+        self.bytes_per_row
+    }
+}
+
+impl PlaneMut<'_> {
+    /// The plane's pixels, row by row. Each row is `bytes_per_row` bytes long.
+    pub fn bytes(&self) -> &[u8] {
+        self.bytes
+    }
+
+    /// The plane's pixels, row by row. Each row is `bytes_per_row` bytes long.
+    pub fn bytes_mut(&mut self) -> &mut [u8] {
+        self.bytes
+    }
+
+    /// The width of the plane, in pixels.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The height of the plane, in pixels.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The number of bytes from the start of one row to the start of the next.
+    pub fn bytes_per_row(&self) -> usize {
+        self.bytes_per_row
+    }
+}
+
+impl LockError {
+    /// The `CVReturn` status code.
+    pub fn status(&self) -> i32 {
+        self.status
+    }
+}
+
+impl std::fmt::Display for LockError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "could not lock the pixel buffer's base address (CVReturn {})",
+            self.status
+        )
+    }
+}
+
+impl std::error::Error for LockError {}
+
+#[repr(C)]
+struct FfiPixelBufferInfo {
+    width: usize,
+    height: usize,
+    pixel_format: u32,
+    plane_count: usize,
+}
+
+#[repr(C)]
+struct FfiPixelBufferPlane {
+    base_address: *mut u8,
+    width: usize,
+    height: usize,
+    bytes_per_row: usize,
+}
+
+impl FfiPixelBufferPlane {
+    fn len(&self) -> usize {
+        self.bytes_per_row * self.height
+    }
+}
+
+extern "C" {
+    #[link_name = "__swift_bridge__$PixelBuffer$retain"]
+    fn __swift_bridge__pixel_buffer_retain(buffer: *mut c_void);
+
+    #[link_name = "__swift_bridge__$PixelBuffer$release"]
+    fn __swift_bridge__pixel_buffer_release(buffer: *mut c_void);
+
+    #[link_name = "__swift_bridge__$PixelBuffer$info"]
+    fn __swift_bridge__pixel_buffer_info(buffer: *mut c_void) -> FfiPixelBufferInfo;
+
+    // Returns a `CVReturn`.
+    #[link_name = "__swift_bridge__$PixelBuffer$lock"]
+    fn __swift_bridge__pixel_buffer_lock(buffer: *mut c_void, read_only: bool) -> i32;
+
+    // Returns a `CVReturn`.
+    #[link_name = "__swift_bridge__$PixelBuffer$unlock"]
+    fn __swift_bridge__pixel_buffer_unlock(buffer: *mut c_void, read_only: bool) -> i32;
+
+    // Only valid while the base address is locked.
+    #[link_name = "__swift_bridge__$PixelBuffer$plane"]
+    fn __swift_bridge__pixel_buffer_plane(buffer: *mut c_void, index: usize)
+        -> FfiPixelBufferPlane;
+}
@@ -0,0 +1,151 @@
+//! Observe network connectivity changes using Apple's `NWPathMonitor`.
+//!
+//! Enabled by the `reachability` feature. The Swift side of these functions is generated when the
+//! build script calls `swift_bridge_build::GeneratedCode::with_reachability_support`.
+//!
+//! ```ignore
+//! use swift_bridge::reachability::{PathMonitor, PathStatus};
+//!
+//! let (monitor, updates) = PathMonitor::channel();
+//!
+//! for update in updates {
+//!     if update.status == PathStatus::Satisfied && !update.is_expensive {
+//!         sync_engine.resume();
+//!     }
+//! }
+//!
+//! // Updates stop once the monitor is dropped.
+//! drop(monitor);
+//! ```
+//!
+//! The corresponding Swift code can be found in
+//! crates/swift-bridge-build/src/generate_core/reachability_support.swift
+
+use std::ffi::c_void;
+use std::sync::mpsc::{channel, Receiver};
+
+/// Whether or not the network can currently be used.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PathStatus {
+    /// The network can be used.
+    Satisfied,
+    /// The network can not be used.
+    Unsatisfied,
+    /// The network is not currently available, but establishing a new connection may activate it,
+    /// such as for a VPN on demand.
+    RequiresConnection,
+}
+
+/// The state of the network, sent whenever it changes.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct PathUpdate {
+    /// Whether or not the network can currently be used.
+    pub status: PathStatus,
+    /// Whether the network uses an interface that is considered expensive, such as cellular or a
+    /// personal hotspot.
+    pub is_expensive: bool,
+    /// Whether the network is in Low Data Mode.
+    pub is_constrained: bool,
+    /// Whether the network uses a Wi-Fi interface.
+    pub uses_wifi: bool,
+    /// Whether the network uses a cellular interface.
+    pub uses_cellular: bool,
+    /// Whether the network uses a wired Ethernet interface.
+    pub uses_wired_ethernet: bool,
+}
+
+/// Called with each network change.
+pub type OnPathUpdate = Box<dyn FnMut(PathUpdate) + Send>;
+
+/// Observes the network using an `NWPathMonitor`.
+///
+/// The monitor stops when this is dropped. No updates are delivered after `drop` returns.
+pub struct PathMonitor {
+    monitor: *mut c_void,
+    on_update: *mut OnPathUpdate,
+}
+
+impl PathMonitor {
+    /// Start observing the network.
+    ///
+    /// `on_update` is called with the current state of the network shortly after the monitor
+    /// starts, and then again whenever it changes. It is called on a background thread.
+    pub fn start(on_update: impl FnMut(PathUpdate) + Send + 'static) -> Self {
+        let on_update: OnPathUpdate = Box::new(on_update);
+        let on_update = Box::into_raw(Box::new(on_update));
+
+        let monitor = unsafe { __swift_bridge__reachability_start(on_update as *mut c_void) };
+
+        PathMonitor { monitor, on_update }
+    }
+
+    /// Start observing the network, receiving updates over a channel.
+    ///
+    /// The channel is closed when the returned `PathMonitor` is dropped.
+    pub fn channel() -> (Self, Receiver<PathUpdate>) {
+        let (sender, receiver) = channel();
+        let monitor = PathMonitor::start(move |update| {
+            let _ = sender.send(update);
+        });
+
+        (monitor, receiver)
+    }
+}
+
+// SAFETY: The Swift monitor can be cancelled from any thread and `on_update` is `Send`.
+unsafe impl Send for PathMonitor {}
+
+impl Drop for PathMonitor {
+    fn drop(&mut self) {
+        // Swift waits for any in progress update to finish before returning, so we can then free
+        // the callback.
+        unsafe {
+            __swift_bridge__reachability_cancel(self.monitor);
+            drop(Box::from_raw(self.on_update));
+        }
+    }
+}
+
+#[repr(C)]
+struct FfiPathUpdate {
+    // 0 = satisfied, 1 = unsatisfied, 2 = requires connection
+    status: u8,
+    is_expensive: bool,
+    is_constrained: bool,
+    uses_wifi: bool,
+    uses_cellular: bool,
+    uses_wired_ethernet: bool,
+}
+
+extern "C" {
+    // Returns a retained pointer to the Swift monitor.
+    #[link_name = "__swift_bridge__$reachability$start"]
+    fn __swift_bridge__reachability_start(on_update: *mut c_void) -> *mut c_void;
+
+    #[link_name = "__swift_bridge__$reachability$cancel"]
+    fn __swift_bridge__reachability_cancel(monitor: *mut c_void);
+}
+
+#[export_name = "__swift_bridge__$reachability$on_update"]
+#[allow(non_snake_case)]
+extern "C" fn __swift_bridge__reachability_on_update(
+    on_update: *mut c_void,
+    update: FfiPathUpdate,
+) {
+    let on_update = unsafe { &mut *(on_update as *mut OnPathUpdate) };
+
+    let status = match update.status {
+        0 => PathStatus::Satisfied,
+        2 => PathStatus::RequiresConnection,
+        _ => PathStatus::Unsatisfied,
+    };
+
+    on_update(PathUpdate {
+        status,
+        is_expensive: update.is_expensive,
+        is_constrained: update.is_constrained,
+        uses_wifi: update.uses_wifi,
+        uses_cellular: update.uses_cellular,
+        uses_wired_ethernet: update.uses_wired_ethernet,
+    })
+}
@@ -0,0 +1,183 @@
+//! Support for bridge modules annotated with `#[swift_bridge(reflection = "...")]`.
+//!
+//! The corresponding C and Swift code can be found in
+//! crates/swift-bridge-build/src/generate_core/reflection.swift
+//!
+//! ```no_run,ignore
+//! #[swift_bridge::bridge]
+//! #[swift_bridge(reflection = "my_module")]
+//! mod ffi {
+//!     // ...
+//! }
+//!
+//! for function in ffi::REFLECTION.functions() {
+//!     println!("{} was called {} times", function.name(), function.call_count());
+//! }
+//! ```
+
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// The types and functions of a bridge module.
+#[repr(C)]
+pub struct ModuleReflection {
+    #[doc(hidden)]
+    pub name: *const c_char,
+    #[doc(hidden)]
+    pub types: *const ReflectedType,
+    #[doc(hidden)]
+    pub types_len: usize,
+    #[doc(hidden)]
+    pub functions: *const ReflectedFunction,
+    #[doc(hidden)]
+    pub functions_len: usize,
+}
+
+/// A type that is declared in a bridge module.
+#[repr(C)]
+pub struct ReflectedType {
+    #[doc(hidden)]
+    pub name: *const c_char,
+    #[doc(hidden)]
+    pub id: u64,
+    #[doc(hidden)]
+    pub kind: ReflectedTypeKind,
+}
+
+/// The kind of type that a [`ReflectedType`] is.
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ReflectedTypeKind {
+    /// A type declared in an `extern "Rust"` block.
+    OpaqueRust = 0,
+    /// A type declared in an `extern "Swift"` block.
+    OpaqueSwift = 1,
+    /// A shared struct.
+    SharedStruct = 2,
+    /// A shared enum.
+    SharedEnum = 3,
+}
+
+/// A function or method that is declared in a bridge module.
+#[repr(C)]
+pub struct ReflectedFunction {
+    #[doc(hidden)]
+    pub name: *const c_char,
+    #[doc(hidden)]
+    pub type_name: *const c_char,
+    #[doc(hidden)]
+    pub link_name: *const c_char,
+    #[doc(hidden)]
+    pub is_rust: bool,
+    #[doc(hidden)]
+    pub calls: AtomicU64,
+}
+
+// The pointers only point to string literals and statics, which live for the life of the program.
+unsafe impl Sync for ModuleReflection {}
+unsafe impl Sync for ReflectedType {}
+unsafe impl Sync for ReflectedFunction {}
+
+impl ModuleReflection {
+    /// The name that was given to the `reflection` attribute.
+    pub fn name(&self) -> &'static str {
+        unsafe { static_str(self.name) }
+    }
+
+    /// All of the types that the module declares.
+    pub fn types(&self) -> &'static [ReflectedType] {
+        unsafe { std::slice::from_raw_parts(self.types, self.types_len) }
+    }
+
+    /// All of the functions and methods that the module declares, in the order that they were
+    /// declared.
+    pub fn functions(&self) -> &'static [ReflectedFunction] {
+        unsafe { std::slice::from_raw_parts(self.functions, self.functions_len) }
+    }
+
+    /// The methods of one of the module's types.
+    pub fn methods<'a>(
+        &'a self,
+        type_name: &'a str,
+    ) -> impl Iterator<Item = &'static ReflectedFunction> + 'a {
+        self.functions()
+            .iter()
+            .filter(move |function| function.type_name() == Some(type_name))
+    }
+}
+
+impl ReflectedType {
+    /// The type's name.
+    pub fn name(&self) -> &'static str {
+        unsafe { static_str(self.name) }
+    }
+
+    /// A hash of the type's name that is the same in Rust and Swift, and across builds.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Whether the type is an opaque Rust type, an opaque Swift type, a shared struct or a shared
+    /// enum.
+    pub fn kind(&self) -> ReflectedTypeKind {
+        self.kind
+    }
+}
+
+impl ReflectedFunction {
+    /// The function's name.
+    pub fn name(&self) -> &'static str {
+        unsafe { static_str(self.name) }
+    }
+
+    /// The name of the type that the function is a method of, or `None` for a freestanding
+    /// function.
+    pub fn type_name(&self) -> Option<&'static str> {
+        if self.type_name.is_null() {
+            None
+        } else {
+            Some(unsafe { static_str(self.type_name) })
+        }
+    }
+
+    /// The symbol that the function is exported or imported as, such as
+    /// `__swift_bridge__$SomeType$some_method`.
+    pub fn link_name(&self) -> &'static str {
+        unsafe { static_str(self.link_name) }
+    }
+
+    /// Whether the function is implemented in Rust, or in Swift.
+    pub fn is_rust(&self) -> bool {
+        self.is_rust
+    }
+
+    /// The number of times that the function has been called through the bridge.
+    ///
+    /// Functions that are implemented in Rust count the calls that Swift makes to them, and
+    /// functions that are implemented in Swift count the calls that Rust makes to them.
+    pub fn call_count(&self) -> u64 {
+        self.calls.load(Ordering::Relaxed)
+    }
+
+    #[doc(hidden)]
+    pub fn record_call(&self) {
+        self.calls.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[allow(non_snake_case)]
+#[export_name = "__swift_bridge__$reflected_function$call_count"]
+#[doc(hidden)]
+pub extern "C" fn __swift_bridge__reflected_function_call_count(
+    function: &ReflectedFunction,
+) -> u64 {
+    function.call_count()
+}
+
+/// # Safety
+///
+/// The pointer must point to a nul terminated UTF-8 string that lives for the life of the program.
+unsafe fn static_str(ptr: *const c_char) -> &'static str {
+    CStr::from_ptr(ptr).to_str().unwrap()
+}
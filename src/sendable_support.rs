@@ -0,0 +1 @@
+pub fn assert_send_sync<T: Send + Sync>() {}
@@ -0,0 +1,90 @@
+//! `serde_json::Value`s that Swift sees as the `Any` that `JSONSerialization` produces.
+//!
+//! Enabled by the `serde-json` feature. The Swift side of the conversions is generated when the
+//! build script calls `swift_bridge_build::GeneratedCode::with_serde_json_support`.
+//!
+//! ```ignore
+//! use serde_json::{json, Value};
+//!
+//! #[swift_bridge::bridge]
+//! mod ffi {
+//!     extern "Rust" {
+//!         // Seen by Swift as `Any`.
+//!         fn plugin_config(name: &str) -> Value;
+//!     }
+//! }
+//!
+//! fn plugin_config(name: &str) -> Value {
+//!     json!({ "name": name, "enabled": true, "retries": 3 })
+//! }
+//! ```
+//!
+//! ```swift
+//! let config = plugin_config("sync") as! [String: Any]
+//! let retries = config["retries"] as! Int
+//! ```
+//!
+//! A `Value` is serialized to a JSON string that is passed over FFI and parsed on the other side,
+//! so it is best suited to small, loosely-typed payloads. Data with a fixed layout should use a
+//! shared struct instead.
+//!
+//! JSON objects and arrays are seen by Swift as `[String: Any]` and `[Any]`, and `null` is seen as
+//! `NSNull`. Swift values that `JSONSerialization` cannot serialize, such as a `Date`, cause a
+//! precondition failure when they are passed to Rust.
+//!
+//! A shared struct that derives serde's `Serialize` and `Deserialize` as well as
+//! `#[swift_bridge(derive(Codable))]` can use `#[swift_bridge(serde_json_round_trip)]` to get a
+//! Swift `serdeJsonRoundTrip` function, which encodes a value with `JSONEncoder`, decodes and
+//! re-encodes it with serde, and then decodes it with `JSONDecoder`. Tests can use it to check that
+//! the two formats agree.
+//!
+//! The corresponding Swift code can be found in
+//! crates/swift-bridge-build/src/generate_core/serde_json_support.swift
+
+use crate::result::ResultPtrAndPtr;
+use crate::string::RustString;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+pub use ::serde_json::Value;
+
+/// Serialize a `Value` into a string that Swift parses.
+#[doc(hidden)]
+pub fn value_into_ffi(value: Value) -> *mut RustString {
+    RustString(value.to_string()).box_into_raw()
+}
+
+/// Parse the JSON string that Swift serialized.
+///
+/// # Safety
+/// The pointer must be a `*mut RustString` that Swift gave up ownership of.
+#[doc(hidden)]
+pub unsafe fn value_from_ffi(json: *mut RustString) -> Value {
+    let json = Box::from_raw(json).0;
+    ::serde_json::from_str(&json).expect("Swift passes valid JSON")
+}
+
+/// Deserialize the JSON that Swift's `JSONEncoder` produced and serialize the value again, or
+/// return serde's error message.
+///
+/// # Safety
+/// The pointer must be a `*mut RustString` that Swift gave up ownership of.
+#[doc(hidden)]
+pub unsafe fn round_trip<T: Serialize + DeserializeOwned>(
+    json: *mut RustString,
+) -> ResultPtrAndPtr {
+    let json = Box::from_raw(json).0;
+    let round_tripped =
+        ::serde_json::from_str::<T>(&json).and_then(|value| ::serde_json::to_string(&value));
+
+    match round_tripped {
+        Ok(json) => ResultPtrAndPtr {
+            is_ok: true,
+            ok_or_err: RustString(json).box_into_raw() as *mut std::ffi::c_void,
+        },
+        Err(err) => ResultPtrAndPtr {
+            is_ok: false,
+            ok_or_err: RustString(err.to_string()).box_into_raw() as *mut std::ffi::c_void,
+        },
+    }
+}
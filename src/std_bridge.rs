@@ -2,7 +2,13 @@
 //! crates/swift-bridge-build/src/generate_core/*
 #![allow(missing_docs)]
 
+pub mod btree_map;
+pub mod duration;
+pub mod net;
 pub mod option;
+pub mod range;
 pub mod result;
+mod rust_hash_set;
 mod rust_vec;
 pub mod string;
+pub mod system_time;
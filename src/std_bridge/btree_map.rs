@@ -0,0 +1,42 @@
+//! The corresponding C and Swift code can be found in
+//! crates/swift-bridge-build/src/generate_core/rust_btree_map.swift
+use std::ffi::c_void;
+
+/// A `BTreeMap<K, V>` that is passed over FFI as a `Vec<K>` of its keys and a `Vec<V>` of its
+/// values, both in sorted order.
+///
+/// Swift sees this as a `RustBTreeMap<K, V>`, which can iterate over the entries in order and
+/// binary search the keys without calling back into Rust.
+#[doc(hidden)]
+#[repr(C)]
+pub struct FfiBTreeMap {
+    /// A `*mut Vec<K>`
+    pub keys: *mut c_void,
+    /// A `*mut Vec<V>`
+    pub values: *mut c_void,
+}
+
+impl FfiBTreeMap {
+    /// Create an FfiBTreeMap from the entries of a `BTreeMap<K, V>`, which must already be in
+    /// sorted order.
+    pub fn from_entries<K, V>(entries: impl IntoIterator<Item = (K, V)>) -> Self {
+        let (keys, values): (Vec<K>, Vec<V>) = entries.into_iter().unzip();
+
+        FfiBTreeMap {
+            keys: Box::into_raw(Box::new(keys)) as *mut c_void,
+            values: Box::into_raw(Box::new(values)) as *mut c_void,
+        }
+    }
+
+    /// Take ownership of the keys and values.
+    ///
+    /// # Safety
+    ///
+    /// `K` and `V` must be the same types that this `FfiBTreeMap` was created with.
+    pub unsafe fn into_entries<K, V>(self) -> impl Iterator<Item = (K, V)> {
+        let keys = *Box::from_raw(self.keys as *mut Vec<K>);
+        let values = *Box::from_raw(self.values as *mut Vec<V>);
+
+        keys.into_iter().zip(values)
+    }
+}
@@ -0,0 +1,38 @@
+//! The corresponding C and Swift code can be found in
+//! crates/swift-bridge-build/src/generate_core/duration.swift
+use std::time::Duration;
+
+/// A `Duration` that is passed over FFI as its whole seconds and its fractional nanoseconds.
+///
+/// Swift sees this as a `TimeInterval`.
+#[doc(hidden)]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct FfiDuration {
+    pub secs: u64,
+    pub nanos: u32,
+}
+
+impl From<Duration> for FfiDuration {
+    fn from(duration: Duration) -> Self {
+        FfiDuration {
+            secs: duration.as_secs(),
+            nanos: duration.subsec_nanos(),
+        }
+    }
+}
+
+impl From<FfiDuration> for Duration {
+    /// Swift can round the nanoseconds up to a full second, which `Duration::new` carries over
+    /// into the seconds.
+    fn from(duration: FfiDuration) -> Self {
+        Duration::new(duration.secs, duration.nanos)
+    }
+}
+
+#[repr(C)]
+#[doc(hidden)]
+pub struct OptionDuration {
+    pub val: FfiDuration,
+    pub is_some: bool,
+}
@@ -0,0 +1,105 @@
+//! The corresponding C and Swift code can be found in
+//! crates/swift-bridge-build/src/generate_core/net.swift
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+/// An `IpAddr` that is passed over FFI as its octets.
+///
+/// An IPv4 address uses the first 4 octets and leaves the rest zeroed.
+///
+/// Swift sees this as a `RustIpAddr`.
+#[doc(hidden)]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct FfiIpAddr {
+    pub octets: [u8; 16],
+    pub is_v6: bool,
+}
+
+impl From<IpAddr> for FfiIpAddr {
+    fn from(addr: IpAddr) -> Self {
+        let mut octets = [0; 16];
+
+        match addr {
+            IpAddr::V4(v4) => {
+                octets[..4].copy_from_slice(&v4.octets());
+                FfiIpAddr {
+                    octets,
+                    is_v6: false,
+                }
+            }
+            IpAddr::V6(v6) => FfiIpAddr {
+                octets: v6.octets(),
+                is_v6: true,
+            },
+        }
+    }
+}
+
+impl From<FfiIpAddr> for IpAddr {
+    fn from(addr: FfiIpAddr) -> Self {
+        if addr.is_v6 {
+            IpAddr::V6(Ipv6Addr::from(addr.octets))
+        } else {
+            let [a, b, c, d, ..] = addr.octets;
+            IpAddr::V4(Ipv4Addr::new(a, b, c, d))
+        }
+    }
+}
+
+/// A `SocketAddr` that is passed over FFI as its IP address, port and, for IPv6, its flow info
+/// and scope ID.
+///
+/// Swift sees this as a `RustSocketAddr`.
+#[doc(hidden)]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct FfiSocketAddr {
+    pub ip: FfiIpAddr,
+    pub port: u16,
+    pub flowinfo: u32,
+    pub scope_id: u32,
+}
+
+impl From<SocketAddr> for FfiSocketAddr {
+    fn from(addr: SocketAddr) -> Self {
+        let (flowinfo, scope_id) = match addr {
+            SocketAddr::V4(_) => (0, 0),
+            SocketAddr::V6(v6) => (v6.flowinfo(), v6.scope_id()),
+        };
+
+        FfiSocketAddr {
+            ip: FfiIpAddr::from(addr.ip()),
+            port: addr.port(),
+            flowinfo,
+            scope_id,
+        }
+    }
+}
+
+impl From<FfiSocketAddr> for SocketAddr {
+    fn from(addr: FfiSocketAddr) -> Self {
+        match IpAddr::from(addr.ip) {
+            IpAddr::V4(v4) => SocketAddr::V4(SocketAddrV4::new(v4, addr.port)),
+            IpAddr::V6(v6) => SocketAddr::V6(SocketAddrV6::new(
+                v6,
+                addr.port,
+                addr.flowinfo,
+                addr.scope_id,
+            )),
+        }
+    }
+}
+
+#[repr(C)]
+#[doc(hidden)]
+pub struct OptionIpAddr {
+    pub val: FfiIpAddr,
+    pub is_some: bool,
+}
+
+#[repr(C)]
+#[doc(hidden)]
+pub struct OptionSocketAddr {
+    pub val: FfiSocketAddr,
+    pub is_some: bool,
+}
@@ -0,0 +1,42 @@
+//! The corresponding C and Swift code can be found in
+//! crates/swift-bridge-build/src/generate_core/range_support.rs
+use std::ops::{Range, RangeInclusive};
+
+/// A `Range<T>` or `RangeInclusive<T>` of integers that is passed over FFI as its two bounds.
+///
+/// Swift sees this as a `Range<T>` or a `ClosedRange<T>`.
+#[doc(hidden)]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct FfiRange<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T> From<Range<T>> for FfiRange<T> {
+    fn from(range: Range<T>) -> Self {
+        FfiRange {
+            start: range.start,
+            end: range.end,
+        }
+    }
+}
+
+impl<T> From<FfiRange<T>> for Range<T> {
+    fn from(range: FfiRange<T>) -> Self {
+        range.start..range.end
+    }
+}
+
+impl<T> From<RangeInclusive<T>> for FfiRange<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        let (start, end) = range.into_inner();
+        FfiRange { start, end }
+    }
+}
+
+impl<T> From<FfiRange<T>> for RangeInclusive<T> {
+    fn from(range: FfiRange<T>) -> Self {
+        RangeInclusive::new(range.start, range.end)
+    }
+}
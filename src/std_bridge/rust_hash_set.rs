@@ -0,0 +1,123 @@
+//! The corresponding C and Swift code can be found in
+//! crates/swift-bridge-build/src/generate_core/hash_set_support.rs
+use crate::string::RustString;
+use macro_::hash_set_externs;
+use std::collections::HashSet;
+
+hash_set_externs!(u8);
+hash_set_externs!(u16);
+hash_set_externs!(u32);
+hash_set_externs!(u64);
+hash_set_externs!(usize);
+
+hash_set_externs!(i8);
+hash_set_externs!(i16);
+hash_set_externs!(i32);
+hash_set_externs!(i64);
+hash_set_externs!(isize);
+
+hash_set_externs!(bool);
+
+const _: () = {
+    #[export_name = "__swift_bridge__$HashSet_String$new"]
+    #[doc(hidden)]
+    pub extern "C" fn _new() -> *mut HashSet<String> {
+        Box::into_raw(Box::new(HashSet::new()))
+    }
+
+    #[export_name = "__swift_bridge__$HashSet_String$_free"]
+    #[doc(hidden)]
+    pub extern "C" fn _drop(set: *mut HashSet<String>) {
+        let set = unsafe { Box::from_raw(set) };
+        drop(set)
+    }
+
+    #[export_name = "__swift_bridge__$HashSet_String$len"]
+    #[doc(hidden)]
+    pub extern "C" fn _len(set: *const HashSet<String>) -> usize {
+        unsafe { &*set }.len()
+    }
+
+    #[export_name = "__swift_bridge__$HashSet_String$insert"]
+    #[doc(hidden)]
+    pub extern "C" fn _insert(set: *mut HashSet<String>, val: *mut RustString) -> bool {
+        let val = unsafe { Box::from_raw(val) }.0;
+        unsafe { &mut *set }.insert(val)
+    }
+
+    #[export_name = "__swift_bridge__$HashSet_String$contains"]
+    #[doc(hidden)]
+    pub extern "C" fn _contains(set: *const HashSet<String>, val: *const RustString) -> bool {
+        let val = unsafe { &*val };
+        unsafe { &*set }.contains(&val.0)
+    }
+
+    #[export_name = "__swift_bridge__$HashSet_String$remove"]
+    #[doc(hidden)]
+    pub extern "C" fn _remove(set: *mut HashSet<String>, val: *const RustString) -> bool {
+        let val = unsafe { &*val };
+        unsafe { &mut *set }.remove(&val.0)
+    }
+
+    #[export_name = "__swift_bridge__$HashSet_String$to_vec"]
+    #[doc(hidden)]
+    pub extern "C" fn _to_vec(set: *const HashSet<String>) -> *mut Vec<RustString> {
+        let set = unsafe { &*set };
+        let vec: Vec<RustString> = set.iter().map(|val| RustString(val.clone())).collect();
+        Box::into_raw(Box::new(vec))
+    }
+};
+
+mod macro_ {
+    macro_rules! hash_set_externs {
+        ($ty:ty) => {
+            const _: () = {
+                #[export_name = concat!("__swift_bridge__$HashSet_", stringify!($ty), "$new")]
+                #[doc(hidden)]
+                pub extern "C" fn _new() -> *mut HashSet<$ty> {
+                    Box::into_raw(Box::new(HashSet::new()))
+                }
+
+                #[export_name = concat!("__swift_bridge__$HashSet_", stringify!($ty), "$_free")]
+                #[doc(hidden)]
+                pub extern "C" fn _drop(set: *mut HashSet<$ty>) {
+                    let set = unsafe { Box::from_raw(set) };
+                    drop(set)
+                }
+
+                #[export_name = concat!("__swift_bridge__$HashSet_", stringify!($ty), "$len")]
+                #[doc(hidden)]
+                pub extern "C" fn _len(set: *const HashSet<$ty>) -> usize {
+                    unsafe { &*set }.len()
+                }
+
+                #[export_name = concat!("__swift_bridge__$HashSet_", stringify!($ty), "$insert")]
+                #[doc(hidden)]
+                pub extern "C" fn _insert(set: *mut HashSet<$ty>, val: $ty) -> bool {
+                    unsafe { &mut *set }.insert(val)
+                }
+
+                #[export_name = concat!("__swift_bridge__$HashSet_", stringify!($ty), "$contains")]
+                #[doc(hidden)]
+                pub extern "C" fn _contains(set: *const HashSet<$ty>, val: $ty) -> bool {
+                    unsafe { &*set }.contains(&val)
+                }
+
+                #[export_name = concat!("__swift_bridge__$HashSet_", stringify!($ty), "$remove")]
+                #[doc(hidden)]
+                pub extern "C" fn _remove(set: *mut HashSet<$ty>, val: $ty) -> bool {
+                    unsafe { &mut *set }.remove(&val)
+                }
+
+                #[export_name = concat!("__swift_bridge__$HashSet_", stringify!($ty), "$to_vec")]
+                #[doc(hidden)]
+                pub extern "C" fn _to_vec(set: *const HashSet<$ty>) -> *mut Vec<$ty> {
+                    let set = unsafe { &*set };
+                    Box::into_raw(Box::new(set.iter().copied().collect()))
+                }
+            };
+        };
+    }
+
+    pub(super) use hash_set_externs;
+}
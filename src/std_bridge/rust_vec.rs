@@ -1,4 +1,8 @@
-use macro_::vec_externs;
+use crate::string::RustString;
+use macro_::{vec_externs, vec_of_option_externs, vec_of_vec_externs, vec_sort_externs};
+use std::cmp::Ordering;
+use std::ffi::c_void;
+use std::panic::{self, AssertUnwindSafe};
 
 vec_externs!(u8, OptionU8, 123);
 vec_externs!(u16, OptionU16, 123);
@@ -17,6 +21,172 @@ vec_externs!(f64, OptionF64, 0.123);
 
 vec_externs!(bool, OptionBool, false);
 
+vec_sort_externs!(u8);
+vec_sort_externs!(u16);
+vec_sort_externs!(u32);
+vec_sort_externs!(u64);
+vec_sort_externs!(usize);
+
+vec_sort_externs!(i8);
+vec_sort_externs!(i16);
+vec_sort_externs!(i32);
+vec_sort_externs!(i64);
+vec_sort_externs!(isize);
+
+vec_sort_externs!(f32);
+vec_sort_externs!(f64);
+
+vec_sort_externs!(bool);
+
+/// A Swift `(T, T) -> Bool` closure that returns true if its first argument should be ordered
+/// before its second argument.
+///
+/// `context` points to the Swift closure and is passed back to `are_in_increasing_order`.
+type AreInIncreasingOrder<T> = extern "C" fn(context: *mut c_void, lhs: T, rhs: T) -> bool;
+
+/// Stably sort the slice using a Swift `are_in_increasing_order` closure.
+///
+/// Rust's sorts only ever check whether one element is ordered before another, so the closure is
+/// called once per comparison.
+///
+/// Rust's sorts can panic if the closure isn't a strict weak ordering, such as a `<` that compares
+/// a `Float.nan`. A panic can't unwind into Swift, so it is caught and the slice is left in an
+/// unspecified order.
+fn sort_by<T: Copy>(
+    slice: &mut [T],
+    context: *mut c_void,
+    are_in_increasing_order: AreInIncreasingOrder<T>,
+) {
+    let _ = panic::catch_unwind(AssertUnwindSafe(|| {
+        slice.sort_by(|lhs, rhs| {
+            if are_in_increasing_order(context, *lhs, *rhs) {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            }
+        })
+    }));
+}
+
+fn ordering<T: Copy>(
+    context: *mut c_void,
+    are_in_increasing_order: AreInIncreasingOrder<T>,
+    lhs: T,
+    rhs: T,
+) -> Ordering {
+    if are_in_increasing_order(context, lhs, rhs) {
+        Ordering::Less
+    } else if are_in_increasing_order(context, rhs, lhs) {
+        Ordering::Greater
+    } else {
+        Ordering::Equal
+    }
+}
+
+vec_of_vec_externs!(u8);
+vec_of_vec_externs!(u16);
+vec_of_vec_externs!(u32);
+vec_of_vec_externs!(u64);
+vec_of_vec_externs!(usize);
+
+vec_of_vec_externs!(i8);
+vec_of_vec_externs!(i16);
+vec_of_vec_externs!(i32);
+vec_of_vec_externs!(i64);
+vec_of_vec_externs!(isize);
+
+vec_of_vec_externs!(f32);
+vec_of_vec_externs!(f64);
+
+vec_of_vec_externs!(bool);
+
+vec_of_option_externs!(u8, OptionU8, 123);
+vec_of_option_externs!(u16, OptionU16, 123);
+vec_of_option_externs!(u32, OptionU32, 123);
+vec_of_option_externs!(u64, OptionU64, 123);
+vec_of_option_externs!(usize, OptionUsize, 123);
+
+vec_of_option_externs!(i8, OptionI8, 123);
+vec_of_option_externs!(i16, OptionI16, 123);
+vec_of_option_externs!(i32, OptionI32, 123);
+vec_of_option_externs!(i64, OptionI64, 123);
+vec_of_option_externs!(isize, OptionIsize, 123);
+
+vec_of_option_externs!(f32, OptionF32, 0.123);
+vec_of_option_externs!(f64, OptionF64, 0.123);
+
+vec_of_option_externs!(bool, OptionBool, false);
+
+// Vec<Option<String>>
+//
+// An `Option<String>` is passed over FFI as a nullable pointer to a `RustString`.
+// Swift checks the length of the Vec before calling `pop` or `get`, so a null pointer always
+// means `None`.
+const _: () = {
+    #[export_name = "__swift_bridge__$Vec_Option_String$new"]
+    #[doc(hidden)]
+    pub extern "C" fn _new() -> *mut Vec<Option<String>> {
+        Box::into_raw(Box::new(Vec::new()))
+    }
+
+    #[export_name = "__swift_bridge__$Vec_Option_String$_free"]
+    #[doc(hidden)]
+    pub extern "C" fn _drop(vec: *mut Vec<Option<String>>) {
+        let vec = unsafe { Box::from_raw(vec) };
+        drop(vec)
+    }
+
+    #[export_name = "__swift_bridge__$Vec_Option_String$len"]
+    #[doc(hidden)]
+    pub extern "C" fn _len(vec: *const Vec<Option<String>>) -> usize {
+        unsafe { &*vec }.len()
+    }
+
+    #[export_name = "__swift_bridge__$Vec_Option_String$push"]
+    #[doc(hidden)]
+    pub extern "C" fn _push(vec: *mut Vec<Option<String>>, val: *mut RustString) {
+        let val = if val.is_null() {
+            None
+        } else {
+            Some(unsafe { Box::from_raw(val) }.0)
+        };
+        unsafe { &mut *vec }.push(val);
+    }
+
+    #[export_name = "__swift_bridge__$Vec_Option_String$pop"]
+    #[doc(hidden)]
+    pub extern "C" fn _pop(vec: *mut Vec<Option<String>>) -> *mut RustString {
+        match unsafe { &mut *vec }.pop().flatten() {
+            Some(val) => RustString(val).box_into_raw(),
+            None => std::ptr::null_mut(),
+        }
+    }
+
+    #[export_name = "__swift_bridge__$Vec_Option_String$get"]
+    #[doc(hidden)]
+    pub extern "C" fn _get(vec: *const Vec<Option<String>>, index: usize) -> *const RustString {
+        match unsafe { &*vec }.get(index) {
+            Some(Some(val)) => val as *const String as *const RustString,
+            _ => std::ptr::null(),
+        }
+    }
+
+    #[export_name = "__swift_bridge__$Vec_Option_String$get_mut"]
+    #[doc(hidden)]
+    pub extern "C" fn _get_mut(vec: *mut Vec<Option<String>>, index: usize) -> *mut RustString {
+        match unsafe { &mut *vec }.get_mut(index) {
+            Some(Some(val)) => val as *mut String as *mut RustString,
+            _ => std::ptr::null_mut(),
+        }
+    }
+
+    #[export_name = "__swift_bridge__$Vec_Option_String$as_ptr"]
+    #[doc(hidden)]
+    pub extern "C" fn _as_ptr(vec: *const Vec<Option<String>>) -> *const Option<String> {
+        unsafe { &*vec }.as_ptr()
+    }
+};
+
 mod macro_ {
     macro_rules! vec_externs {
         ($ty:ty, $option_ty:ident, $unused_none:expr) => {
@@ -116,5 +286,212 @@ mod macro_ {
         };
     }
 
+    /// Sorting and searching a Vec<T> where T is a primitive, using a comparator closure that is
+    /// passed from Swift.
+    macro_rules! vec_sort_externs {
+        ($ty:ty) => {
+            const _: () = {
+                #[export_name = concat!("__swift_bridge__$Vec_", stringify!($ty), "$sort_by")]
+                #[doc(hidden)]
+                pub extern "C" fn _sort_by(
+                    vec: *mut Vec<$ty>,
+                    context: *mut c_void,
+                    are_in_increasing_order: AreInIncreasingOrder<$ty>,
+                ) {
+                    let vec = unsafe { &mut *vec };
+                    sort_by(vec, context, are_in_increasing_order);
+                }
+
+                #[export_name = concat!("__swift_bridge__$Vec_", stringify!($ty), "$sorted_by")]
+                #[doc(hidden)]
+                pub extern "C" fn _sorted_by(
+                    vec: *const Vec<$ty>,
+                    context: *mut c_void,
+                    are_in_increasing_order: AreInIncreasingOrder<$ty>,
+                ) -> *mut Vec<$ty> {
+                    let mut sorted = unsafe { &*vec }.clone();
+                    sort_by(&mut sorted, context, are_in_increasing_order);
+                    Box::into_raw(Box::new(sorted))
+                }
+
+                /// Returns true if the value was found. `index` is set to the index of the
+                /// matching element, or to the index that the value could be inserted at.
+                #[export_name = concat!("__swift_bridge__$Vec_", stringify!($ty), "$binary_search_by")]
+                #[doc(hidden)]
+                pub extern "C" fn _binary_search_by(
+                    vec: *const Vec<$ty>,
+                    value: $ty,
+                    context: *mut c_void,
+                    are_in_increasing_order: AreInIncreasingOrder<$ty>,
+                    index: *mut usize,
+                ) -> bool {
+                    let vec = unsafe { &*vec };
+                    let result = vec.binary_search_by(|element| {
+                        ordering(context, are_in_increasing_order, *element, value)
+                    });
+                    let (found, idx) = match result {
+                        Ok(idx) => (true, idx),
+                        Err(idx) => (false, idx),
+                    };
+                    unsafe { *index = idx };
+                    found
+                }
+
+                #[export_name = concat!("__swift_bridge__$Vec_", stringify!($ty), "$contains")]
+                #[doc(hidden)]
+                pub extern "C" fn _contains(vec: *const Vec<$ty>, value: $ty) -> bool {
+                    unsafe { &*vec }.contains(&value)
+                }
+            };
+        };
+    }
+
+    /// Vec<Vec<T>> where T is a primitive.
+    ///
+    /// An inner `Vec<T>` is passed over FFI as a pointer, the same way that a `Vec<T>` that is
+    /// returned from a function is. Swift wraps it in a `RustVec<T>`.
+    macro_rules! vec_of_vec_externs {
+        ($ty:ty) => {
+            const _: () = {
+                #[export_name = concat!("__swift_bridge__$Vec_Vec_", stringify!($ty), "$new")]
+                #[doc(hidden)]
+                pub extern "C" fn _new() -> *mut Vec<Vec<$ty>> {
+                    Box::into_raw(Box::new(Vec::new()))
+                }
+
+                #[export_name = concat!("__swift_bridge__$Vec_Vec_", stringify!($ty), "$_free")]
+                #[doc(hidden)]
+                pub extern "C" fn _drop(vec: *mut Vec<Vec<$ty>>) {
+                    let vec = unsafe { Box::from_raw(vec) };
+                    drop(vec)
+                }
+
+                #[export_name = concat!("__swift_bridge__$Vec_Vec_", stringify!($ty), "$len")]
+                #[doc(hidden)]
+                pub extern "C" fn _len(vec: *const Vec<Vec<$ty>>) -> usize {
+                    unsafe { &*vec }.len()
+                }
+
+                #[export_name = concat!("__swift_bridge__$Vec_Vec_", stringify!($ty), "$push")]
+                #[doc(hidden)]
+                pub extern "C" fn _push(vec: *mut Vec<Vec<$ty>>, val: *mut Vec<$ty>) {
+                    let val = unsafe { Box::from_raw(val) };
+                    unsafe { &mut *vec }.push(*val);
+                }
+
+                #[export_name = concat!("__swift_bridge__$Vec_Vec_", stringify!($ty), "$pop")]
+                #[doc(hidden)]
+                pub extern "C" fn _pop(vec: *mut Vec<Vec<$ty>>) -> *mut Vec<$ty> {
+                    match unsafe { &mut *vec }.pop() {
+                        Some(val) => Box::into_raw(Box::new(val)),
+                        None => std::ptr::null_mut(),
+                    }
+                }
+
+                #[export_name = concat!("__swift_bridge__$Vec_Vec_", stringify!($ty), "$get")]
+                #[doc(hidden)]
+                pub extern "C" fn _get(vec: *const Vec<Vec<$ty>>, index: usize) -> *const Vec<$ty> {
+                    match unsafe { &*vec }.get(index) {
+                        Some(val) => val as *const Vec<$ty>,
+                        None => std::ptr::null(),
+                    }
+                }
+
+                #[export_name = concat!("__swift_bridge__$Vec_Vec_", stringify!($ty), "$get_mut")]
+                #[doc(hidden)]
+                pub extern "C" fn _get_mut(vec: *mut Vec<Vec<$ty>>, index: usize) -> *mut Vec<$ty> {
+                    match unsafe { &mut *vec }.get_mut(index) {
+                        Some(val) => val as *mut Vec<$ty>,
+                        None => std::ptr::null_mut(),
+                    }
+                }
+
+                #[export_name = concat!("__swift_bridge__$Vec_Vec_", stringify!($ty), "$as_ptr")]
+                #[doc(hidden)]
+                pub extern "C" fn _as_ptr(vec: *const Vec<Vec<$ty>>) -> *const Vec<$ty> {
+                    unsafe { &*vec }.as_ptr()
+                }
+            };
+        };
+    }
+
+    /// Vec<Option<T>> where T is a primitive.
+    ///
+    /// Swift checks the length of the Vec before calling `pop` or `get`, so they only need to
+    /// return the element's `Option`.
+    macro_rules! vec_of_option_externs {
+        ($ty:ty, $option_ty:ident, $unused_none:expr) => {
+            const _: () = {
+                #[export_name = concat!("__swift_bridge__$Vec_Option_", stringify!($ty), "$new")]
+                #[doc(hidden)]
+                pub extern "C" fn _new() -> *mut Vec<Option<$ty>> {
+                    Box::into_raw(Box::new(Vec::new()))
+                }
+
+                #[export_name = concat!("__swift_bridge__$Vec_Option_", stringify!($ty), "$_free")]
+                #[doc(hidden)]
+                pub extern "C" fn _drop(vec: *mut Vec<Option<$ty>>) {
+                    let vec = unsafe { Box::from_raw(vec) };
+                    drop(vec)
+                }
+
+                #[export_name = concat!("__swift_bridge__$Vec_Option_", stringify!($ty), "$len")]
+                #[doc(hidden)]
+                pub extern "C" fn _len(vec: *const Vec<Option<$ty>>) -> usize {
+                    unsafe { &*vec }.len()
+                }
+
+                #[export_name = concat!("__swift_bridge__$Vec_Option_", stringify!($ty), "$push")]
+                #[doc(hidden)]
+                pub extern "C" fn _push(vec: *mut Vec<Option<$ty>>, val: crate::option::$option_ty) {
+                    unsafe { &mut *vec }.push(val.is_some.then_some(val.val));
+                }
+
+                #[export_name = concat!("__swift_bridge__$Vec_Option_", stringify!($ty), "$pop")]
+                #[doc(hidden)]
+                pub extern "C" fn _pop(vec: *mut Vec<Option<$ty>>) -> crate::option::$option_ty {
+                    to_ffi_option(unsafe { &mut *vec }.pop().flatten())
+                }
+
+                #[export_name = concat!("__swift_bridge__$Vec_Option_", stringify!($ty), "$get")]
+                #[doc(hidden)]
+                pub extern "C" fn _get(
+                    vec: *const Vec<Option<$ty>>,
+                    index: usize,
+                ) -> crate::option::$option_ty {
+                    to_ffi_option(unsafe { &*vec }.get(index).copied().flatten())
+                }
+
+                #[export_name = concat!("__swift_bridge__$Vec_Option_", stringify!($ty), "$get_mut")]
+                #[doc(hidden)]
+                pub extern "C" fn _get_mut(
+                    vec: *mut Vec<Option<$ty>>,
+                    index: usize,
+                ) -> crate::option::$option_ty {
+                    to_ffi_option(unsafe { &*vec }.get(index).copied().flatten())
+                }
+
+                #[export_name = concat!("__swift_bridge__$Vec_Option_", stringify!($ty), "$as_ptr")]
+                #[doc(hidden)]
+                pub extern "C" fn _as_ptr(vec: *const Vec<Option<$ty>>) -> *const Option<$ty> {
+                    unsafe { &*vec }.as_ptr()
+                }
+
+                fn to_ffi_option(val: Option<$ty>) -> crate::option::$option_ty {
+                    match val {
+                        Some(val) => crate::option::$option_ty { val, is_some: true },
+                        None => crate::option::$option_ty {
+                            val: $unused_none,
+                            is_some: false,
+                        },
+                    }
+                }
+            };
+        };
+    }
+
     pub(super) use vec_externs;
+    pub(super) use vec_of_option_externs;
+    pub(super) use vec_of_vec_externs;
+    pub(super) use vec_sort_externs;
 }
@@ -1,6 +1,8 @@
 //! The corresponding C and Swift code can be found in
 //! crates/swift-bridge-build/src/generate_core/rust_string.{c.h,swift}
 pub use self::ffi::*;
+use crate::bridge_error::BridgeError;
+use std::borrow::Cow;
 
 #[swift_bridge_macro::bridge(swift_bridge_path = crate)]
 mod ffi {
@@ -18,10 +20,17 @@ mod ffi {
         fn as_str(&self) -> &str;
 
         fn trim(&self) -> &str;
+
+        fn is_char_boundary(&self, index: usize) -> bool;
+
+        fn char_count(&self) -> usize;
+
+        fn get(&self, start: usize, end: usize) -> Option<&str>;
     }
 }
 
 #[doc(hidden)]
+#[repr(transparent)]
 pub struct RustString(pub String);
 
 #[doc(hidden)]
@@ -51,6 +60,20 @@ impl RustString {
     fn trim(&self) -> &str {
         self.0.trim()
     }
+
+    fn is_char_boundary(&self, index: usize) -> bool {
+        self.0.is_char_boundary(index)
+    }
+
+    fn char_count(&self) -> usize {
+        self.0.chars().count()
+    }
+
+    /// The bytes `start..end`, or `None` if either index is out of bounds or not on a `char`
+    /// boundary.
+    fn get(&self, start: usize, end: usize) -> Option<&str> {
+        self.0.get(start..end)
+    }
 }
 
 impl RustString {
@@ -67,8 +90,50 @@ impl RustStr {
 
     // TODO: Think through these lifetimes and the implications of them...
     pub fn to_str<'a>(self) -> &'a str {
+        if self.start.is_null() {
+            crate::bridge_error::report(BridgeError::NullPointer { type_name: "str" });
+        }
         let bytes = unsafe { std::slice::from_raw_parts(self.start, self.len) };
-        std::str::from_utf8(bytes).expect("Failed to convert RustStr to &str")
+        match std::str::from_utf8(bytes) {
+            Ok(str) => str,
+            Err(e) => crate::bridge_error::report(BridgeError::InvalidUtf8 {
+                valid_up_to: e.valid_up_to(),
+            }),
+        }
+    }
+
+    /// Like `to_str`, but invalid UTF-8 is replaced with U+FFFD REPLACEMENT CHARACTER.
+    ///
+    /// The string is only copied when the bytes are not valid UTF-8. The generated code borrows
+    /// the returned `Cow` for the duration of the call, so the copy is freed afterwards.
+    pub fn to_cow_lossy<'a>(self) -> Cow<'a, str> {
+        if self.start.is_null() {
+            crate::bridge_error::report(BridgeError::NullPointer { type_name: "str" });
+        }
+        let bytes = unsafe { std::slice::from_raw_parts(self.start, self.len) };
+        String::from_utf8_lossy(bytes)
+    }
+
+    /// Like `to_cow_lossy`, but a null `start` is `None`.
+    pub fn to_optional_cow_lossy<'a>(self) -> Option<Cow<'a, str>> {
+        if self.start.is_null() {
+            None
+        } else {
+            Some(self.to_cow_lossy())
+        }
+    }
+
+    /// Like `to_str`, but the bytes are not checked.
+    ///
+    /// # Safety
+    ///
+    /// The bytes must be valid UTF-8.
+    pub unsafe fn to_str_unchecked<'a>(self) -> &'a str {
+        if self.start.is_null() {
+            crate::bridge_error::report(BridgeError::NullPointer { type_name: "str" });
+        }
+        let bytes = std::slice::from_raw_parts(self.start, self.len);
+        std::str::from_utf8_unchecked(bytes)
     }
 
     pub fn to_string(self) -> String {
@@ -83,6 +148,63 @@ impl RustStr {
     }
 }
 
+/// A `Cow<str>` that is returned to Swift.
+///
+/// A borrowed string is passed as a pointer to its bytes without allocating. An owned string's
+/// buffer is handed over to Swift, which copies it and then frees it using
+/// `__swift_bridge__$CowStr$free`.
+///
+/// A null `start` is used to represent `None`.
+#[doc(hidden)]
+#[repr(C)]
+pub struct FfiCowStr {
+    pub start: *mut u8,
+    pub len: usize,
+    pub capacity: usize,
+    pub is_owned: bool,
+}
+
+impl From<Cow<'_, str>> for FfiCowStr {
+    fn from(cow: Cow<'_, str>) -> Self {
+        match cow {
+            Cow::Borrowed(str) => FfiCowStr {
+                start: str.as_ptr() as *mut u8,
+                len: str.len(),
+                capacity: 0,
+                is_owned: false,
+            },
+            Cow::Owned(string) => {
+                let mut string = std::mem::ManuallyDrop::new(string);
+                FfiCowStr {
+                    start: string.as_mut_ptr(),
+                    len: string.len(),
+                    capacity: string.capacity(),
+                    is_owned: true,
+                }
+            }
+        }
+    }
+}
+
+impl FfiCowStr {
+    pub fn none() -> Self {
+        FfiCowStr {
+            start: std::ptr::null_mut(),
+            len: 0,
+            capacity: 0,
+            is_owned: false,
+        }
+    }
+}
+
+#[export_name = "__swift_bridge__$CowStr$free"]
+#[allow(non_snake_case)]
+pub extern "C" fn __swift_bridge__CowStr_free(cow: FfiCowStr) {
+    if cow.is_owned {
+        drop(unsafe { String::from_raw_parts(cow.start, cow.len, cow.capacity) });
+    }
+}
+
 impl PartialEq for RustStr {
     fn eq(&self, other: &Self) -> bool {
         unsafe {
@@ -0,0 +1,64 @@
+//! The corresponding C and Swift code can be found in
+//! crates/swift-bridge-build/src/generate_core/system_time.swift
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// A `SystemTime` that is passed over FFI as the whole seconds since the Unix epoch and its
+/// fractional nanoseconds.
+///
+/// The seconds are rounded towards negative infinity so that the nanoseconds are never negative.
+/// For example, half a second before the epoch is `{ secs: -1, nanos: 500_000_000 }`.
+///
+/// Swift sees this as a `Date`.
+#[doc(hidden)]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct FfiSystemTime {
+    pub secs: i64,
+    pub nanos: u32,
+}
+
+impl From<SystemTime> for FfiSystemTime {
+    fn from(time: SystemTime) -> Self {
+        match time.duration_since(UNIX_EPOCH) {
+            Ok(since_epoch) => FfiSystemTime {
+                secs: since_epoch.as_secs() as i64,
+                nanos: since_epoch.subsec_nanos(),
+            },
+            Err(err) => {
+                let before_epoch = err.duration();
+
+                if before_epoch.subsec_nanos() == 0 {
+                    FfiSystemTime {
+                        secs: -(before_epoch.as_secs() as i64),
+                        nanos: 0,
+                    }
+                } else {
+                    FfiSystemTime {
+                        secs: -(before_epoch.as_secs() as i64) - 1,
+                        nanos: 1_000_000_000 - before_epoch.subsec_nanos(),
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl From<FfiSystemTime> for SystemTime {
+    /// Swift can round the nanoseconds up to a full second, which `Duration` carries over into the
+    /// seconds.
+    fn from(time: FfiSystemTime) -> Self {
+        if time.secs >= 0 {
+            UNIX_EPOCH + Duration::new(time.secs as u64, time.nanos)
+        } else {
+            UNIX_EPOCH - Duration::from_secs(time.secs.unsigned_abs())
+                + Duration::from_nanos(time.nanos as u64)
+        }
+    }
+}
+
+#[repr(C)]
+#[doc(hidden)]
+pub struct OptionSystemTime {
+    pub val: FfiSystemTime,
+    pub is_some: bool,
+}
@@ -0,0 +1,413 @@
+//! Multidimensional arrays that Rust ML and signal processing code exchanges with Core ML and
+//! Accelerate.
+//!
+//! Enabled by the `tensor` feature. The Swift side is generated when the build script calls
+//! `swift_bridge_build::GeneratedCode::with_tensor_support`.
+//!
+//! ```ignore
+//! use swift_bridge::tensor::{Tensor, TensorDataMut};
+//!
+//! #[swift_bridge::bridge]
+//! mod ffi {
+//!     extern "Rust" {
+//!         // Seen by Swift as a `RustTensor`.
+//!         fn normalize(features: Tensor) -> Tensor;
+//!     }
+//! }
+//!
+//! fn normalize(mut features: Tensor) -> Tensor {
+//!     if let TensorDataMut::F32(data) = features.data_mut() {
+//!         let max = data.iter().cloned().fold(f32::MIN, f32::max);
+//!         data.iter_mut().for_each(|x| *x /= max);
+//!     }
+//!     features
+//! }
+//! ```
+//!
+//! ```swift
+//! let features = normalize(RustTensor(multiArray)!)
+//! let input = try MLDictionaryFeatureProvider(dictionary: ["features": features.makeMLMultiArray()])
+//! let prediction = try model.prediction(from: input)
+//! ```
+//!
+//! `RustTensor.makeMLMultiArray` and `RustTensor.withUnsafeBufferPointer` point at the elements
+//! that Rust allocated instead of copying them, so they can be handed to Core ML or to vDSP
+//! directly. The elements are freed once the `RustTensor` and every `MLMultiArray` that was made
+//! from it have been released.
+//!
+//! Creating a `RustTensor` from an `MLMultiArray` copies the elements into Rust, keeping their
+//! shape and strides.
+//!
+//! The corresponding Swift code can be found in
+//! crates/swift-bridge-build/src/generate_core/tensor_support.swift
+
+use crate::FfiSlice;
+use std::ffi::c_void;
+
+/// A multidimensional array of numbers.
+///
+/// The element at index `[i, j, k]` is stored at `i * strides[0] + j * strides[1] + k * strides[2]`
+/// in the tensor's data.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tensor {
+    shape: Vec<usize>,
+    strides: Vec<usize>,
+    data: TensorData,
+}
+
+/// The elements of a `Tensor`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TensorData {
+    /// Seen by Swift as `Float` elements.
+    F32(Vec<f32>),
+    /// Seen by Swift as `Double` elements.
+    F64(Vec<f64>),
+    /// Seen by Swift as `Int32` elements.
+    I32(Vec<i32>),
+}
+
+/// The type of a `Tensor`'s elements.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum DataType {
+    /// `f32`, which corresponds to `MLMultiArrayDataType.float32`.
+    F32,
+    /// `f64`, which corresponds to `MLMultiArrayDataType.double`.
+    F64,
+    /// `i32`, which corresponds to `MLMultiArrayDataType.int32`.
+    I32,
+}
+
+/// The reason that a `Tensor` could not be created.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TensorError {
+    /// There is not exactly one stride per dimension.
+    StridesDoNotMatchShape {
+        /// The number of dimensions.
+        shape_len: usize,
+        /// The number of strides that were given.
+        strides_len: usize,
+    },
+    /// The data ends before the last element that the shape and strides point to.
+    NotEnoughElements {
+        /// The number of elements that were given.
+        len: usize,
+        /// The number of elements that the shape and strides need.
+        expected_len: usize,
+    },
+}
+
+impl std::fmt::Display for TensorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TensorError::StridesDoNotMatchShape {
+                shape_len,
+                strides_len,
+            } => write!(
+                f,
+                "a tensor with {} dimensions needs {} strides but {} were provided",
+                shape_len, shape_len, strides_len
+            ),
+            TensorError::NotEnoughElements { len, expected_len } => write!(
+                f,
+                "the tensor needs {} elements but only {} were provided",
+                expected_len, len
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TensorError {}
+
+impl DataType {
+    /// The number of bytes that a single element takes up.
+    pub fn size_in_bytes(&self) -> usize {
+        match self {
+            DataType::F32 | DataType::I32 => 4,
+            DataType::F64 => 8,
+        }
+    }
+
+    fn from_ffi_repr(data_type: u8) -> Option<Self> {
+        match data_type {
+            0 => Some(DataType::F32),
+            1 => Some(DataType::F64),
+            2 => Some(DataType::I32),
+            _ => None,
+        }
+    }
+
+    fn to_ffi_repr(self) -> u8 {
+        match self {
+            DataType::F32 => 0,
+            DataType::F64 => 1,
+            DataType::I32 => 2,
+        }
+    }
+}
+
+impl TensorData {
+    /// The type of the elements.
+    pub fn data_type(&self) -> DataType {
+        match self {
+            TensorData::F32(_) => DataType::F32,
+            TensorData::F64(_) => DataType::F64,
+            TensorData::I32(_) => DataType::I32,
+        }
+    }
+
+    /// The number of elements.
+    pub fn len(&self) -> usize {
+        match self {
+            TensorData::F32(data) => data.len(),
+            TensorData::F64(data) => data.len(),
+            TensorData::I32(data) => data.len(),
+        }
+    }
+
+    /// Whether or not there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The elements, if they are `f32`s.
+    pub fn as_f32(&self) -> Option<&[f32]> {
+        match self {
+            TensorData::F32(data) => Some(data),
+            _ => None,
+        }
+    }
+
+    /// The elements, if they are `f64`s.
+    pub fn as_f64(&self) -> Option<&[f64]> {
+        match self {
+            TensorData::F64(data) => Some(data),
+            _ => None,
+        }
+    }
+
+    /// The elements, if they are `i32`s.
+    pub fn as_i32(&self) -> Option<&[i32]> {
+        match self {
+            TensorData::I32(data) => Some(data),
+            _ => None,
+        }
+    }
+
+    fn as_ptr(&self) -> *const c_void {
+        match self {
+            TensorData::F32(data) => data.as_ptr() as *const c_void,
+            TensorData::F64(data) => data.as_ptr() as *const c_void,
+            TensorData::I32(data) => data.as_ptr() as *const c_void,
+        }
+    }
+}
+
+impl From<Vec<f32>> for TensorData {
+    fn from(data: Vec<f32>) -> Self {
+        TensorData::F32(data)
+    }
+}
+
+impl From<Vec<f64>> for TensorData {
+    fn from(data: Vec<f64>) -> Self {
+        TensorData::F64(data)
+    }
+}
+
+impl From<Vec<i32>> for TensorData {
+    fn from(data: Vec<i32>) -> Self {
+        TensorData::I32(data)
+    }
+}
+
+impl Tensor {
+    /// Create a tensor whose elements are contiguous and in row-major order, i.e. the last
+    /// dimension has a stride of 1.
+    pub fn new(shape: Vec<usize>, data: impl Into<TensorData>) -> Result<Self, TensorError> {
+        let mut strides = vec![1; shape.len()];
+        for idx in (0..shape.len().saturating_sub(1)).rev() {
+            strides[idx] = strides[idx + 1] * shape[idx + 1];
+        }
+
+        Self::with_strides(shape, strides, data)
+    }
+
+    /// Create a tensor whose dimensions are the given number of elements apart, such as a
+    /// column-major tensor or one whose rows are padded for alignment.
+    pub fn with_strides(
+        shape: Vec<usize>,
+        strides: Vec<usize>,
+        data: impl Into<TensorData>,
+    ) -> Result<Self, TensorError> {
+        if shape.len() != strides.len() {
+            return Err(TensorError::StridesDoNotMatchShape {
+                shape_len: shape.len(),
+                strides_len: strides.len(),
+            });
+        }
+
+        let data = data.into();
+        let expected_len = required_len(&shape, &strides);
+        if data.len() < expected_len {
+            return Err(TensorError::NotEnoughElements {
+                len: data.len(),
+                expected_len,
+            });
+        }
+
+        Ok(Tensor {
+            shape,
+            strides,
+            data,
+        })
+    }
+
+    /// The number of elements in each dimension.
+    pub fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    /// The number of elements from one index of each dimension to the next.
+    pub fn strides(&self) -> &[usize] {
+        &self.strides
+    }
+
+    /// The type of the tensor's elements.
+    pub fn data_type(&self) -> DataType {
+        self.data.data_type()
+    }
+
+    /// The tensor's elements, laid out according to its strides.
+    pub fn data(&self) -> &TensorData {
+        &self.data
+    }
+
+    /// The tensor's elements, laid out according to its strides.
+    ///
+    /// Elements can be changed but not added or removed, since the shape and strides must
+    /// continue to fit.
+    pub fn data_mut(&mut self) -> TensorDataMut<'_> {
+        match &mut self.data {
+            TensorData::F32(data) => TensorDataMut::F32(data),
+            TensorData::F64(data) => TensorDataMut::F64(data),
+            TensorData::I32(data) => TensorDataMut::I32(data),
+        }
+    }
+
+    /// Take the tensor's elements.
+    pub fn into_data(self) -> TensorData {
+        self.data
+    }
+}
+
+/// The elements of a `Tensor`, which can be changed in place.
+#[derive(Debug)]
+pub enum TensorDataMut<'a> {
+    /// `f32` elements.
+    F32(&'a mut [f32]),
+    /// `f64` elements.
+    F64(&'a mut [f64]),
+    /// `i32` elements.
+    I32(&'a mut [i32]),
+}
+
+/// The number of elements that are needed to reach the last element.
+fn required_len(shape: &[usize], strides: &[usize]) -> usize {
+    if shape.contains(&0) {
+        return 0;
+    }
+
+    1 + shape
+        .iter()
+        .zip(strides)
+        .map(|(dim, stride)| (dim - 1) * stride)
+        .sum::<usize>()
+}
+
+/// What Swift needs to know about a `Tensor` in order to read its elements.
+#[repr(C)]
+struct FfiTensorInfo {
+    data: *const c_void,
+    len: usize,
+    rank: usize,
+    shape: *const usize,
+    strides: *const usize,
+    data_type: u8,
+}
+
+#[export_name = "__swift_bridge__$Tensor$info"]
+#[allow(non_snake_case)]
+extern "C" fn __swift_bridge__tensor_info(tensor: *const Tensor) -> FfiTensorInfo {
+    let tensor = unsafe { &*tensor };
+
+    FfiTensorInfo {
+        data: tensor.data.as_ptr(),
+        len: tensor.data.len(),
+        rank: tensor.shape.len(),
+        shape: tensor.shape.as_ptr(),
+        strides: tensor.strides.as_ptr(),
+        data_type: tensor.data_type().to_ffi_repr(),
+    }
+}
+
+/// Copy elements that Swift created into a new `Tensor`.
+///
+/// Returns null if the shape and strides do not describe the bytes.
+#[export_name = "__swift_bridge__$Tensor$new"]
+#[allow(non_snake_case)]
+extern "C" fn __swift_bridge__tensor_new(
+    data_type: u8,
+    shape: FfiSlice<usize>,
+    strides: FfiSlice<usize>,
+    bytes: FfiSlice<u8>,
+) -> *mut c_void {
+    let data_type = match DataType::from_ffi_repr(data_type) {
+        Some(data_type) => data_type,
+        None => return std::ptr::null_mut(),
+    };
+    let shape = shape.as_slice().to_vec();
+    let strides = strides.as_slice().to_vec();
+    if shape.len() != strides.len() {
+        return std::ptr::null_mut();
+    }
+
+    let len = required_len(&shape, &strides);
+    if bytes.len < len * data_type.size_in_bytes() {
+        return std::ptr::null_mut();
+    }
+
+    let data = unsafe {
+        match data_type {
+            DataType::F32 => TensorData::F32(copy_elements(bytes.start, len)),
+            DataType::F64 => TensorData::F64(copy_elements(bytes.start, len)),
+            DataType::I32 => TensorData::I32(copy_elements(bytes.start, len)),
+        }
+    };
+
+    match Tensor::with_strides(shape, strides, data) {
+        Ok(tensor) => Box::into_raw(Box::new(tensor)) as *mut c_void,
+        Err(_) => std::ptr::null_mut(),
+    }
+}
+
+#[export_name = "__swift_bridge__$Tensor$_free"]
+#[allow(non_snake_case)]
+extern "C" fn __swift_bridge__tensor_free(tensor: *mut Tensor) {
+    drop(unsafe { Box::from_raw(tensor) });
+}
+
+/// Swift's bytes are not necessarily aligned for `T`, so they are copied byte by byte.
+///
+/// # Safety
+/// `bytes` must point to at least `len * size_of::<T>()` bytes.
+unsafe fn copy_elements<T: Copy + Default>(bytes: *const u8, len: usize) -> Vec<T> {
+    let mut elements = vec![T::default(); len];
+    if len > 0 {
+        std::ptr::copy_nonoverlapping(
+            bytes,
+            elements.as_mut_ptr() as *mut u8,
+            len * std::mem::size_of::<T>(),
+        );
+    }
+    elements
+}
@@ -0,0 +1,50 @@
+//! `time::OffsetDateTime`s that Swift sees as a Foundation `Date`.
+//!
+//! Enabled by the `time` feature.
+//!
+//! ```ignore
+//! use time::OffsetDateTime;
+//!
+//! #[swift_bridge::bridge]
+//! mod ffi {
+//!     extern "Rust" {
+//!         // Seen by Swift as a `Date`.
+//!         fn last_synced_at() -> Option<OffsetDateTime>;
+//!         fn set_last_synced_at(time: OffsetDateTime);
+//!     }
+//! }
+//! ```
+//!
+//! An `OffsetDateTime` is passed over FFI the same way as a `std::time::SystemTime`, so the Swift
+//! side of the conversion is always generated and no build script support is needed.
+//!
+//! A `Date` is a point in time without a UTC offset. So an `OffsetDateTime` that Rust passes to
+//! Swift loses its offset, and an `OffsetDateTime` that Swift passes to Rust is always in UTC.
+
+pub use ::time::OffsetDateTime;
+
+use crate::system_time::FfiSystemTime;
+
+impl From<OffsetDateTime> for FfiSystemTime {
+    fn from(time: OffsetDateTime) -> Self {
+        FfiSystemTime {
+            secs: time.unix_timestamp(),
+            nanos: time.nanosecond(),
+        }
+    }
+}
+
+impl From<FfiSystemTime> for OffsetDateTime {
+    /// Swift can round the nanoseconds up to a full second, which is carried over into the
+    /// seconds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the time is outside of the range that an `OffsetDateTime` can represent.
+    fn from(time: FfiSystemTime) -> Self {
+        let nanos = time.secs as i128 * 1_000_000_000 + time.nanos as i128;
+
+        OffsetDateTime::from_unix_timestamp_nanos(nanos)
+            .expect("Date is out of range for a time::OffsetDateTime")
+    }
+}
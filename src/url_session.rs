@@ -0,0 +1,191 @@
+//! Delegate HTTP requests to Apple's `URLSession`.
+//!
+//! Enabled by the `url-session` feature. The Swift side of these functions is generated when the
+//! build script calls `swift_bridge_build::GeneratedCode::with_url_session_support`.
+//!
+//! Rust networking code can be written against the [`HttpTransport`] trait and then be given a
+//! [`UrlSessionTransport`] when running on an Apple platform, so that requests go through the
+//! platform's networking stack (proxies, App Transport Security, cellular settings, etc).
+//!
+//! ```ignore
+//! use swift_bridge::url_session::{HttpRequest, HttpTransport, UrlSessionTransport};
+//!
+//! UrlSessionTransport.send(
+//!     HttpRequest::new("GET", "https://example.com"),
+//!     Box::new(|response| match response {
+//!         Ok(response) => println!("{} {:?}", response.status, response.body),
+//!         Err(err) => eprintln!("{}", err),
+//!     }),
+//! );
+//! ```
+//!
+//! The corresponding Swift code can be found in
+//! crates/swift-bridge-build/src/generate_core/url_session_support.swift
+
+use crate::string::RustStr;
+use crate::FfiSlice;
+use std::ffi::c_void;
+
+/// An HTTP request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpRequest {
+    /// The HTTP method, such as "GET" or "POST".
+    pub method: String,
+    /// The URL to send the request to.
+    pub url: String,
+    /// The header fields, in the order they should be sent.
+    pub headers: Vec<(String, String)>,
+    /// The request body. Empty requests have no body.
+    pub body: Vec<u8>,
+}
+
+impl HttpRequest {
+    /// Create a request that has no headers and no body.
+    pub fn new(method: impl Into<String>, url: impl Into<String>) -> Self {
+        HttpRequest {
+            method: method.into(),
+            url: url.into(),
+            headers: vec![],
+            body: vec![],
+        }
+    }
+}
+
+/// An HTTP response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpResponse {
+    /// The HTTP status code.
+    pub status: u16,
+    /// The response's header fields.
+    pub headers: Vec<(String, String)>,
+    /// The response body.
+    pub body: Vec<u8>,
+}
+
+/// An error that prevented a request from getting a response, such as a timeout or an invalid
+/// URL.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpError {
+    /// A description of the error.
+    pub message: String,
+}
+
+impl std::fmt::Display for HttpError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for HttpError {}
+
+/// Called with the outcome of an HTTP request.
+pub type OnHttpResponse = Box<dyn FnOnce(Result<HttpResponse, HttpError>) + Send>;
+
+/// Sends HTTP requests.
+pub trait HttpTransport {
+    /// Send a request and call `on_complete` with the outcome.
+    ///
+    /// `on_complete` may be called from any thread.
+    fn send(&self, request: HttpRequest, on_complete: OnHttpResponse);
+}
+
+/// An [`HttpTransport`] that sends requests using `URLSession.shared`.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct UrlSessionTransport;
+
+impl HttpTransport for UrlSessionTransport {
+    fn send(&self, request: HttpRequest, on_complete: OnHttpResponse) {
+        let headers: Vec<FfiHttpHeader> = request
+            .headers
+            .iter()
+            .map(|(name, value)| FfiHttpHeader {
+                name: RustStr::from_str(name),
+                value: RustStr::from_str(value),
+            })
+            .collect();
+
+        // Reclaimed by `__swift_bridge__url_session_on_response` or
+        // `__swift_bridge__url_session_on_error`.
+        let on_complete = Box::into_raw(Box::new(on_complete)) as *mut c_void;
+
+        // Swift copies everything that it needs out of the request before returning, so the
+        // request only needs to outlive this call.
+        unsafe {
+            __swift_bridge__url_session_send(
+                RustStr::from_str(&request.method),
+                RustStr::from_str(&request.url),
+                FfiSlice::from_slice(&headers),
+                FfiSlice::from_slice(&request.body),
+                on_complete,
+            )
+        }
+    }
+}
+
+#[repr(C)]
+struct FfiHttpHeader {
+    name: RustStr,
+    value: RustStr,
+}
+
+extern "C" {
+    #[link_name = "__swift_bridge__$url_session$send"]
+    fn __swift_bridge__url_session_send(
+        method: RustStr,
+        url: RustStr,
+        headers: FfiSlice<FfiHttpHeader>,
+        body: FfiSlice<u8>,
+        on_complete: *mut c_void,
+    );
+}
+
+#[export_name = "__swift_bridge__$url_session$response_new"]
+#[allow(non_snake_case)]
+extern "C" fn __swift_bridge__url_session_response_new(status: u16) -> *mut HttpResponse {
+    Box::into_raw(Box::new(HttpResponse {
+        status,
+        headers: vec![],
+        body: vec![],
+    }))
+}
+
+#[export_name = "__swift_bridge__$url_session$response_push_header"]
+#[allow(non_snake_case)]
+extern "C" fn __swift_bridge__url_session_response_push_header(
+    response: *mut HttpResponse,
+    name: RustStr,
+    value: RustStr,
+) {
+    let response = unsafe { &mut *response };
+    response.headers.push((name.to_string(), value.to_string()));
+}
+
+#[export_name = "__swift_bridge__$url_session$response_set_body"]
+#[allow(non_snake_case)]
+extern "C" fn __swift_bridge__url_session_response_set_body(
+    response: *mut HttpResponse,
+    body: FfiSlice<u8>,
+) {
+    let response = unsafe { &mut *response };
+    response.body = body.as_slice().to_vec();
+}
+
+#[export_name = "__swift_bridge__$url_session$on_response"]
+#[allow(non_snake_case)]
+extern "C" fn __swift_bridge__url_session_on_response(
+    on_complete: *mut c_void,
+    response: *mut HttpResponse,
+) {
+    let on_complete = unsafe { Box::from_raw(on_complete as *mut OnHttpResponse) };
+    let response = unsafe { Box::from_raw(response) };
+    on_complete(Ok(*response))
+}
+
+#[export_name = "__swift_bridge__$url_session$on_error"]
+#[allow(non_snake_case)]
+extern "C" fn __swift_bridge__url_session_on_error(on_complete: *mut c_void, message: RustStr) {
+    let on_complete = unsafe { Box::from_raw(on_complete as *mut OnHttpResponse) };
+    on_complete(Err(HttpError {
+        message: message.to_string(),
+    }))
+}
@@ -0,0 +1,132 @@
+//! Read and write the app's preferences in `UserDefaults.standard`.
+//!
+//! Enabled by the `user-defaults` feature. The Swift side of these functions is generated when the
+//! build script calls `swift_bridge_build::GeneratedCode::with_user_defaults_support`.
+//!
+//! ```ignore
+//! swift_bridge::user_defaults::set_i64("launch-count", 3);
+//!
+//! assert_eq!(swift_bridge::user_defaults::get_i64("launch-count"), Some(3));
+//! assert_eq!(swift_bridge::user_defaults::get_string("launch-count"), None);
+//! ```
+//!
+//! Getters return `None` if there is no value for the key or the value has a different type.
+//!
+//! The corresponding Swift code can be found in
+//! crates/swift-bridge-build/src/generate_core/user_defaults_support.swift
+
+use crate::option::{OptionBool, OptionF64, OptionI64};
+use crate::string::{RustStr, RustString};
+use crate::FfiSlice;
+use std::ffi::c_void;
+
+/// Get the `Bool` that is stored for the key.
+pub fn get_bool(key: &str) -> Option<bool> {
+    let val = unsafe { __swift_bridge__user_defaults_get_bool(RustStr::from_str(key)) };
+    val.is_some.then_some(val.val)
+}
+
+/// Store a `Bool` for the key.
+pub fn set_bool(key: &str, value: bool) {
+    unsafe { __swift_bridge__user_defaults_set_bool(RustStr::from_str(key), value) }
+}
+
+/// Get the integer that is stored for the key.
+pub fn get_i64(key: &str) -> Option<i64> {
+    let val = unsafe { __swift_bridge__user_defaults_get_i64(RustStr::from_str(key)) };
+    val.is_some.then_some(val.val)
+}
+
+/// Store an integer for the key.
+pub fn set_i64(key: &str, value: i64) {
+    unsafe { __swift_bridge__user_defaults_set_i64(RustStr::from_str(key), value) }
+}
+
+/// Get the `Double` that is stored for the key.
+pub fn get_f64(key: &str) -> Option<f64> {
+    let val = unsafe { __swift_bridge__user_defaults_get_f64(RustStr::from_str(key)) };
+    val.is_some.then_some(val.val)
+}
+
+/// Store a `Double` for the key.
+pub fn set_f64(key: &str, value: f64) {
+    unsafe { __swift_bridge__user_defaults_set_f64(RustStr::from_str(key), value) }
+}
+
+/// Get the `String` that is stored for the key.
+pub fn get_string(key: &str) -> Option<String> {
+    let string = unsafe { __swift_bridge__user_defaults_get_string(RustStr::from_str(key)) };
+
+    if string.is_null() {
+        None
+    } else {
+        Some(unsafe { Box::from_raw(string as *mut RustString) }.0)
+    }
+}
+
+/// Store a `String` for the key.
+pub fn set_string(key: &str, value: &str) {
+    unsafe {
+        __swift_bridge__user_defaults_set_string(RustStr::from_str(key), RustStr::from_str(value))
+    }
+}
+
+/// Get the `Data` that is stored for the key.
+pub fn get_data(key: &str) -> Option<Vec<u8>> {
+    let vec = unsafe { __swift_bridge__user_defaults_get_data(RustStr::from_str(key)) };
+
+    if vec.is_null() {
+        None
+    } else {
+        Some(unsafe { *Box::from_raw(vec as *mut Vec<u8>) })
+    }
+}
+
+/// Store `Data` for the key.
+pub fn set_data(key: &str, value: &[u8]) {
+    unsafe {
+        __swift_bridge__user_defaults_set_data(RustStr::from_str(key), FfiSlice::from_slice(value))
+    }
+}
+
+/// Remove the value that is stored for the key, if any.
+pub fn remove(key: &str) {
+    unsafe { __swift_bridge__user_defaults_remove(RustStr::from_str(key)) }
+}
+
+extern "C" {
+    #[link_name = "__swift_bridge__$user_defaults$get_bool"]
+    fn __swift_bridge__user_defaults_get_bool(key: RustStr) -> OptionBool;
+
+    #[link_name = "__swift_bridge__$user_defaults$set_bool"]
+    fn __swift_bridge__user_defaults_set_bool(key: RustStr, value: bool);
+
+    #[link_name = "__swift_bridge__$user_defaults$get_i64"]
+    fn __swift_bridge__user_defaults_get_i64(key: RustStr) -> OptionI64;
+
+    #[link_name = "__swift_bridge__$user_defaults$set_i64"]
+    fn __swift_bridge__user_defaults_set_i64(key: RustStr, value: i64);
+
+    #[link_name = "__swift_bridge__$user_defaults$get_f64"]
+    fn __swift_bridge__user_defaults_get_f64(key: RustStr) -> OptionF64;
+
+    #[link_name = "__swift_bridge__$user_defaults$set_f64"]
+    fn __swift_bridge__user_defaults_set_f64(key: RustStr, value: f64);
+
+    // Returns a `*mut RustString`, or null if there is no string for the key.
+    #[link_name = "__swift_bridge__$user_defaults$get_string"]
+    fn __swift_bridge__user_defaults_get_string(key: RustStr) -> *mut c_void;
+
+    #[link_name = "__swift_bridge__$user_defaults$set_string"]
+    fn __swift_bridge__user_defaults_set_string(key: RustStr, value: RustStr);
+
+    // Returns a `*mut Vec<u8>`, or null if there is no data for the key.
+    #[link_name = "__swift_bridge__$user_defaults$get_data"]
+    fn __swift_bridge__user_defaults_get_data(key: RustStr) -> *mut c_void;
+
+    #[link_name = "__swift_bridge__$user_defaults$set_data"]
+    fn __swift_bridge__user_defaults_set_data(key: RustStr, value: FfiSlice<u8>);
+
+    #[link_name = "__swift_bridge__$user_defaults$remove"]
+    fn __swift_bridge__user_defaults_remove(key: RustStr);
+}
@@ -0,0 +1,59 @@
+//! `uuid::Uuid`s that Swift sees as a Foundation `UUID`.
+//!
+//! Enabled by the `uuid` feature. The Swift side of the conversions is generated when the build
+//! script calls `swift_bridge_build::GeneratedCode::with_uuid_support`.
+//!
+//! ```ignore
+//! use uuid::Uuid;
+//!
+//! #[swift_bridge::bridge]
+//! mod ffi {
+//!     extern "Rust" {
+//!         // Seen by Swift as a `UUID`.
+//!         fn new_document_id() -> Uuid;
+//!     }
+//! }
+//!
+//! fn new_document_id() -> Uuid {
+//!     Uuid::new_v4()
+//! }
+//! ```
+//!
+//! A `Uuid` is passed over FFI as its 16 bytes, so no memory is allocated and the bytes are
+//! identical on both sides.
+//!
+//! The corresponding Swift code can be found in
+//! crates/swift-bridge-build/src/generate_core/uuid_support.swift
+
+pub use ::uuid::Uuid;
+
+/// A `Uuid` that is passed over FFI as its 16 bytes.
+///
+/// Swift sees the bytes as a `uuid_t`.
+#[doc(hidden)]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct FfiUuid {
+    pub bytes: [u8; 16],
+}
+
+impl From<Uuid> for FfiUuid {
+    fn from(uuid: Uuid) -> Self {
+        FfiUuid {
+            bytes: uuid.into_bytes(),
+        }
+    }
+}
+
+impl From<FfiUuid> for Uuid {
+    fn from(uuid: FfiUuid) -> Self {
+        Uuid::from_bytes(uuid.bytes)
+    }
+}
+
+#[repr(C)]
+#[doc(hidden)]
+pub struct OptionUuid {
+    pub val: FfiUuid,
+    pub is_some: bool,
+}
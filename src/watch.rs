@@ -0,0 +1,162 @@
+//! Change events that a Rust watcher, such as a file system watcher or a polling loop, hands to
+//! Swift one at a time.
+//!
+//! Enabled by the `watch` feature. The Swift side is generated when the build script calls
+//! `swift_bridge_build::GeneratedCode::with_watch_support`.
+//!
+//! ```ignore
+//! use swift_bridge::watch::Watch;
+//!
+//! #[swift_bridge::bridge]
+//! mod ffi {
+//!     #[swift_bridge(swift_repr = "struct")]
+//!     struct FileChanged {
+//!         path: String,
+//!         removed: bool,
+//!     }
+//!
+//!     extern "Rust" {
+//!         // Seen by Swift as a `RustWatch<FileChanged>`.
+//!         fn watch_directory(path: &str) -> Watch<FileChanged>;
+//!     }
+//! }
+//!
+//! fn watch_directory(path: &str) -> Watch<ffi::FileChanged> {
+//!     let (notifier, watch) = Watch::channel();
+//!
+//!     let mut watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+//!         for changed in to_file_changed_events(event) {
+//!             // Fails once Swift has stopped watching.
+//!             let _ = notifier.notify(changed);
+//!         }
+//!     })
+//!     .unwrap();
+//!     watcher
+//!         .watch(path.as_ref(), notify::RecursiveMode::Recursive)
+//!         .unwrap();
+//!
+//!     // The watcher stops once Swift drops the `RustWatch`.
+//!     watch.keep_alive(watcher)
+//! }
+//! ```
+//!
+//! ```swift
+//! for await changed in watch_directory("/tmp") {
+//!     print(changed.path.toString())
+//! }
+//! ```
+//!
+//! A polling watcher can use `Watch::new` with an iterator that waits for the next change.
+//!
+//! The corresponding Swift code can be found in
+//! crates/swift-bridge-build/src/generate_core/watch_support.swift
+
+use std::any::Any;
+use std::sync::mpsc::{channel, Sender};
+
+/// A sequence of change events that Swift reads one at a time.
+///
+/// The sequence ends once its events run out, such as when every `WatchNotifier` was dropped.
+pub struct Watch<T> {
+    events: Box<dyn Iterator<Item = T> + Send>,
+    guards: Vec<Box<dyn Any + Send>>,
+}
+
+/// Sends the events of a `Watch` that was created with `Watch::channel`.
+///
+/// The watch ends once every notifier is dropped.
+#[derive(Debug)]
+pub struct WatchNotifier<T> {
+    sender: Sender<T>,
+}
+
+/// The `Watch` that a `WatchNotifier` was sending to has been dropped, so nobody will receive the
+/// event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WatchClosed<T>(pub T);
+
+impl<T> std::fmt::Display for WatchClosed<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "the watch was dropped before the event could be sent")
+    }
+}
+
+impl<T: std::fmt::Debug> std::error::Error for WatchClosed<T> {}
+
+impl<T> Watch<T> {
+    /// Create a watch that pulls its events from an iterator.
+    ///
+    /// The iterator is advanced on whichever thread Swift reads the watch from, so it may block
+    /// while it waits for the next change.
+    pub fn new<I>(events: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: Send + 'static,
+    {
+        Watch {
+            events: Box::new(events.into_iter()),
+            guards: vec![],
+        }
+    }
+
+    /// Create a watch whose events are sent by a `WatchNotifier`, such as from a file system
+    /// watcher's callback.
+    ///
+    /// Events are buffered until Swift reads them, so `WatchNotifier::notify` never blocks.
+    pub fn channel() -> (WatchNotifier<T>, Watch<T>)
+    where
+        T: Send + 'static,
+    {
+        let (sender, receiver) = channel();
+        let notifier = WatchNotifier { sender };
+        let watch = Watch::new(receiver);
+
+        (notifier, watch)
+    }
+
+    /// Keep a value alive for as long as the watch, such as the file system watcher that is
+    /// producing its events.
+    pub fn keep_alive(mut self, guard: impl Any + Send) -> Self {
+        self.guards.push(Box::new(guard));
+        self
+    }
+
+    /// The next event, or `None` if the watch has ended.
+    ///
+    /// Blocks until the next event is available.
+    pub fn next_event(&mut self) -> Option<T> {
+        self.events.next()
+    }
+}
+
+impl<T> Iterator for Watch<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_event()
+    }
+}
+
+impl<T> std::fmt::Debug for Watch<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Watch").finish_non_exhaustive()
+    }
+}
+
+impl<T> WatchNotifier<T> {
+    /// Send an event to the watch.
+    ///
+    /// Returns the event if the `Watch` has been dropped, which means that Swift stopped
+    /// watching.
+    pub fn notify(&self, event: T) -> Result<(), WatchClosed<T>> {
+        self.sender.send(event).map_err(|err| WatchClosed(err.0))
+    }
+}
+
+impl<T> Clone for WatchNotifier<T> {
+    fn clone(&self) -> Self {
+        WatchNotifier {
+            sender: self.sender.clone(),
+        }
+    }
+}